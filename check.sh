@@ -0,0 +1,9 @@
+#! /bin/sh
+
+# Checks to run before pushing: the native workspace, and the web build, which cannot spawn
+# threads or touch the file system.
+cargo build --workspace || exit 1
+cargo clippy --workspace --all-targets -- -D warnings || exit 1
+cargo test --workspace || exit 1
+cargo clippy -p dynamo_wasm --target wasm32-unknown-unknown -- -D warnings || exit 1
+cargo clippy -p dynamo_wasm --target wasm32-unknown-unknown --features threads -- -D warnings || exit 1
@@ -0,0 +1,225 @@
+use crate::fractal_tab::FractalTab;
+use dynamo_color::Palette;
+use dynamo_common::prelude::*;
+use dynamo_core::error::{DynamoError, DynamoResult};
+use egui::{Context, Ui};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::path::PathBuf;
+
+const BOOKMARKS_FILE: &str = "bookmarks.toml";
+
+/// A saved fractal, view and parameter that can be reopened later.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark
+{
+    pub name: String,
+    /// Folder of the library the bookmark is filed under, or empty for the top level.
+    #[serde(default)]
+    pub folder: String,
+    /// Tile names leading to the fractal in the sidebar.
+    pub profile: Vec<String>,
+    pub bounds: Bounds,
+    pub parameter: Cplx,
+    pub palette: Palette,
+}
+
+impl Bookmark
+{
+    /// Bookmark the fractal and view shown in a tab, or None if its fractal was not chosen from
+    /// the sidebar.
+    #[must_use]
+    pub fn capture(tab: &FractalTab, name: String, folder: String) -> Option<Self>
+    {
+        let profile = tab.profile.clone()?;
+        let view = tab.interface.view_state().parent;
+        Some(Self {
+            name,
+            folder,
+            profile,
+            bounds: view.bounds,
+            parameter: view.selection,
+            palette: view.palette,
+        })
+    }
+
+    /// Show the bookmarked fractal and view in a tab. Returns false if the sidebar no longer has
+    /// the fractal.
+    pub fn open_in(&self, tab: &mut FractalTab) -> bool
+    {
+        if !tab.set_profile(&self.profile) {
+            return false;
+        }
+        let mut view = tab.interface.view_state();
+        view.parent.bounds = self.bounds.clone();
+        view.parent.selection = self.parameter;
+        view.parent.palette = self.palette;
+        view.child.palette = self.palette;
+        tab.interface.restore_view_state(&view);
+        true
+    }
+}
+
+/// The user's bookmarks, saved in their config directory.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BookmarkLibrary
+{
+    #[serde(default)]
+    pub bookmarks: Vec<Bookmark>,
+}
+
+impl BookmarkLibrary
+{
+    fn path() -> Option<PathBuf>
+    {
+        Some(config_dir()?.join(BOOKMARKS_FILE))
+    }
+
+    /// Load the user's bookmarks, or an empty library if the user has not saved any.
+    pub fn load() -> DynamoResult<Self>
+    {
+        match Self::path() {
+            Some(path) if path.exists() => toml::from_str(&std::fs::read_to_string(path)?)
+                .map_err(|e| DynamoError::Format(e.to_string())),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>>
+    {
+        let path = Self::path().ok_or("No config directory")?;
+        std::fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Names of the folders in the library, in order of first appearance.
+    #[must_use]
+    pub fn folders(&self) -> Vec<&str>
+    {
+        let mut folders: Vec<&str> = Vec::new();
+        for bookmark in &self.bookmarks {
+            if !bookmark.folder.is_empty() && !folders.contains(&bookmark.folder.as_str()) {
+                folders.push(&bookmark.folder);
+            }
+        }
+        folders
+    }
+
+    pub fn in_folder<'a>(&'a self, folder: &'a str) -> impl Iterator<Item = &'a Bookmark>
+    {
+        self.bookmarks
+            .iter()
+            .filter(move |bookmark| bookmark.folder == folder)
+    }
+
+    /// Show a button for each bookmark, with a submenu for each folder. Returns the bookmark
+    /// clicked, if any.
+    pub fn menu_buttons(&self, ui: &mut Ui) -> Option<&Bookmark>
+    {
+        let mut chosen = None;
+        for folder in self.folders() {
+            ui.menu_button(folder, |ui| {
+                for bookmark in self.in_folder(folder) {
+                    if ui.button(&bookmark.name).clicked() {
+                        chosen = Some(bookmark);
+                    }
+                }
+            });
+        }
+        for bookmark in self.in_folder("") {
+            if ui.button(&bookmark.name).clicked() {
+                chosen = Some(bookmark);
+            }
+        }
+        chosen
+    }
+}
+
+/// Window for bookmarking the focused tab and removing saved bookmarks.
+#[derive(Default)]
+pub struct BookmarksWindow
+{
+    name: String,
+    folder: String,
+    status: Option<String>,
+    pub open: bool,
+}
+
+impl BookmarksWindow
+{
+    pub fn show(&mut self, ctx: &Context, library: &mut BookmarkLibrary, tab: Option<&FractalTab>)
+    {
+        if !self.open {
+            self.status = None;
+            return;
+        }
+
+        let mut changed = false;
+        let mut open = self.open;
+        egui::Window::new("Bookmarks")
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("new_bookmark")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Name");
+                        ui.text_edit_singleline(&mut self.name);
+                        ui.end_row();
+                        ui.label("Folder");
+                        ui.text_edit_singleline(&mut self.folder);
+                        ui.end_row();
+                    });
+                let name = self.name.trim().to_owned();
+                let folder = self.folder.trim().to_owned();
+                let can_add = !name.is_empty();
+                let bookmark = tab.and_then(|tab| Bookmark::capture(tab, name, folder));
+                if ui
+                    .add_enabled(
+                        can_add && bookmark.is_some(),
+                        egui::Button::new("Bookmark current view"),
+                    )
+                    .on_disabled_hover_text("Needs a name and a fractal from the sidebar.")
+                    .clicked()
+                {
+                    library.bookmarks.extend(bookmark);
+                    self.name.clear();
+                    changed = true;
+                }
+
+                ui.separator();
+                let mut to_remove = None;
+                egui::ScrollArea::vertical()
+                    .max_height(300.0)
+                    .show(ui, |ui| {
+                        for (i, bookmark) in library.bookmarks.iter().enumerate() {
+                            ui.horizontal(|ui| {
+                                if ui.small_button("Remove").clicked() {
+                                    to_remove = Some(i);
+                                }
+                                if bookmark.folder.is_empty() {
+                                    ui.label(&bookmark.name);
+                                } else {
+                                    ui.label(format!("{} / {}", bookmark.folder, bookmark.name));
+                                }
+                            });
+                        }
+                    });
+                if let Some(i) = to_remove {
+                    library.bookmarks.remove(i);
+                    changed = true;
+                }
+                if let Some(status) = &self.status {
+                    ui.label(status);
+                }
+            });
+        self.open = open;
+
+        if changed {
+            self.status = library
+                .save()
+                .err()
+                .map(|e| format!("Could not save bookmarks: {e}"));
+        }
+    }
+}
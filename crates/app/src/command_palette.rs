@@ -0,0 +1,205 @@
+use crate::sidebar;
+use dynamo_gui::hotkeys::{keymap::Keymap, Hotkey};
+use egui::{Context, Key, Modifiers};
+
+/// Maximum number of matches listed at once.
+const MAX_MATCHES: usize = 50;
+
+#[derive(Clone)]
+pub enum Command
+{
+    /// Perform the action of a hotkey, as if chosen from the menu.
+    Hotkey(&'static Hotkey),
+    /// Show the fractal found by following these tile names through the sidebar.
+    Profile(Vec<String>),
+}
+
+pub struct Entry
+{
+    pub name: String,
+    pub detail: String,
+    pub command: Command,
+}
+
+/// Everything that can be run from the command palette, with human-readable names.
+pub struct Registry
+{
+    entries: Vec<Entry>,
+}
+
+impl Default for Registry
+{
+    fn default() -> Self
+    {
+        let mut entries: Vec<Entry> = Vec::new();
+        for (hotkey, _) in Keymap::default().hotkeys() {
+            let action = hotkey.chosen_action();
+            let is_duplicate = entries.iter().any(|entry| {
+                matches!(entry.command, Command::Hotkey(other)
+                    if other.chosen_action() == action
+                        && other.get_bonus_action() == hotkey.get_bonus_action())
+            });
+            if !is_duplicate {
+                entries.push(Entry {
+                    name: action.short_description(),
+                    detail: action.description(),
+                    command: Command::Hotkey(hotkey),
+                });
+            }
+        }
+        for path in sidebar::create_menu().state.fractal_paths() {
+            entries.push(Entry {
+                name: path.join(" > "),
+                detail: "Show this fractal".to_owned(),
+                command: Command::Profile(path),
+            });
+        }
+        Self { entries }
+    }
+}
+
+impl Registry
+{
+    /// Entries matching `query`, best matches first.
+    #[must_use]
+    pub fn search(&self, query: &str) -> Vec<&Entry>
+    {
+        let mut matches: Vec<(i32, &Entry)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| Some((fuzzy_score(query, &entry.name)?, entry)))
+            .collect();
+        matches.sort_by_key(|(score, _)| -score);
+        matches
+            .into_iter()
+            .take(MAX_MATCHES)
+            .map(|(_, entry)| entry)
+            .collect()
+    }
+}
+
+/// Score how well `text` matches `query`, or None if the characters of `query` do not all
+/// appear in order in `text`. Runs of consecutive characters and matches at the start of words
+/// score highest. Case and whitespace in `query` are ignored.
+#[must_use]
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32>
+{
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut prev_match: Option<usize> = None;
+
+    for c in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let offset = text[pos..].iter().position(|&t| t == c)?;
+        let idx = pos + offset;
+
+        score += 1;
+        if prev_match.is_some_and(|prev| prev + 1 == idx) {
+            score += 4;
+        }
+        if idx == 0 || !text[idx - 1].is_alphanumeric() {
+            score += 3;
+        }
+        prev_match = Some(idx);
+        pos = idx + 1;
+    }
+    Some(score)
+}
+
+/// Modal for searching and running any action or fractal by name.
+#[derive(Default)]
+pub struct CommandPalette
+{
+    /// Built when the palette is first opened.
+    registry: Option<Registry>,
+    query: String,
+    selected: usize,
+    pub open: bool,
+}
+
+impl CommandPalette
+{
+    fn close(&mut self)
+    {
+        self.open = false;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    /// Show the palette if it is open, and return the command chosen by the user, if any. This
+    /// should run before the interfaces handle input, so that keys used to navigate the palette
+    /// are not taken as shortcuts.
+    pub fn show(&mut self, ctx: &Context) -> Option<Command>
+    {
+        if !self.open {
+            return None;
+        }
+        let registry = self.registry.get_or_insert_with(Registry::default);
+        let matches = registry.search(&self.query);
+
+        let (up, down, enter) = ctx.input_mut(|input| {
+            (
+                input.consume_key(Modifiers::NONE, Key::ArrowUp),
+                input.consume_key(Modifiers::NONE, Key::ArrowDown),
+                input.consume_key(Modifiers::NONE, Key::Enter),
+            )
+        });
+        if down {
+            self.selected += 1;
+        }
+        if up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        self.selected = self.selected.min(matches.len().saturating_sub(1));
+        let mut chosen = enter.then_some(self.selected);
+
+        let keymap = Keymap::current(ctx);
+        let modal = egui::Modal::new(egui::Id::new("command_palette")).show(ctx, |ui| {
+            ui.set_width(480.);
+            let search = ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .hint_text("Search actions and fractals")
+                    .desired_width(f32::INFINITY),
+            );
+            search.request_focus();
+            if search.changed() {
+                self.selected = 0;
+            }
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .max_height(360.)
+                .show(ui, |ui| {
+                    if matches.is_empty() {
+                        ui.weak("No matches");
+                    }
+                    for (idx, entry) in matches.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            let label = ui
+                                .selectable_label(idx == self.selected, &entry.name)
+                                .on_hover_text(&entry.detail);
+                            if idx == self.selected && (up || down) {
+                                label.scroll_to_me(None);
+                            }
+                            if label.clicked() {
+                                chosen = Some(idx);
+                            }
+                            if let Command::Hotkey(hotkey) = entry.command {
+                                if let Some(shortcut) = keymap.shortcut_text(hotkey) {
+                                    ui.label(shortcut);
+                                }
+                            }
+                        });
+                    }
+                });
+        });
+
+        let command = chosen
+            .and_then(|idx| matches.get(idx))
+            .map(|entry| entry.command.clone());
+        if command.is_some() || modal.should_close() {
+            self.close();
+        }
+        command
+    }
+}
@@ -1,20 +1,29 @@
+use crate::bookmarks::BookmarkLibrary;
+use crate::preferences::Preferences;
 use crate::sidebar;
-use dynamo_common::prelude::*;
-use dynamo_core::prelude::*;
-use dynamo_gui::hotkeys::{
-    Hotkey, ANNOTATION_HOTKEYS, CYCLES_HOTKEYS, FILE_HOTKEYS, IMAGE_HOTKEYS, INCOLORING_HOTKEYS,
-    OUTCOLORING_HOTKEYS, PALETTE_HOTKEYS, SELECTION_HOTKEYS,
-};
-use dynamo_gui::interface::{Interface, MainInterface};
-use dynamo_profiles::Mandelbrot;
+use dynamo_color::Palette;
+use dynamo_common::point_grid::Bounds;
+use dynamo_gui::actions::Action;
+use dynamo_gui::hotkeys::keymap::{HotkeyGroup, Keymap};
+use dynamo_gui::interface::Interface;
+use dynamo_gui::notifications::Notification;
+use dynamo_gui::pane::id::{PaneID, PaneSelection};
+use dynamo_gui::view_state::ViewState;
 use egui::Ui;
 use egui_dock::{NodeIndex, SurfaceIndex};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[cfg(feature = "scripting")]
 use crate::script_editor::*;
 #[cfg(feature = "scripting")]
+use dynamo_common::prelude::*;
+#[cfg(feature = "scripting")]
 use script_loader::error::ScriptError;
 #[cfg(feature = "scripting")]
+use script_loader::watcher::ScriptWatcher;
+#[cfg(feature = "scripting")]
+use dynamo_core::error::DynamoResult;
+#[cfg(feature = "scripting")]
 use std::path::Path;
 
 #[derive(Clone, Copy, Default, Debug)]
@@ -71,20 +80,152 @@ impl From<TabID> for (SurfaceIndex, NodeIndex)
     }
 }
 
+/// A change to the view of a linked tab, to be made in the other linked tabs as well.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum NavigationEvent
+{
+    Bounds(PaneID, Bounds),
+    Palette(Palette),
+}
+
+/// Shared bus over which tabs with linked navigation broadcast changes to their views, so that
+/// e.g. zooming into a base curve zooms into its marked-cycle covers in other tabs too.
+#[derive(Default)]
+pub struct NavigationBus
+{
+    /// Events published during the current sync, with the serial number of their source tab.
+    events: Vec<(usize, NavigationEvent)>,
+}
+
+impl NavigationBus
+{
+    pub fn publish(&mut self, source: usize, event: NavigationEvent)
+    {
+        self.events.push((source, event));
+    }
+
+    /// Events published by tabs other than `tab`.
+    pub fn events_for(&self, tab: usize) -> impl Iterator<Item = &NavigationEvent>
+    {
+        self.events
+            .iter()
+            .filter(move |(source, _)| *source != tab)
+            .map(|(_, event)| event)
+    }
+
+    /// Make the changes to each linked tab since the last sync in every other linked tab. This
+    /// runs on all tabs, not only the visible ones, so that hidden tabs stay in step.
+    pub fn sync(&mut self, mut tabs: Vec<&mut FractalTab>)
+    {
+        for tab in &mut tabs {
+            tab.publish_navigation(self);
+        }
+        if !self.events.is_empty() {
+            for tab in &mut tabs {
+                tab.receive_navigation(self);
+            }
+        }
+        self.events.clear();
+    }
+}
+
+/// State of a tab's connection to the navigation bus.
+pub struct NavigationLink
+{
+    /// Identifies the tab's events on the bus, since tabs in the same dock node share a `TabID`.
+    serial: usize,
+    pub enabled: bool,
+    /// View at the last sync, to find what has changed since.
+    last_view: Option<ViewState>,
+}
+
+impl Default for NavigationLink
+{
+    fn default() -> Self
+    {
+        static NEXT_SERIAL: AtomicUsize = AtomicUsize::new(0);
+        Self {
+            serial: NEXT_SERIAL.fetch_add(1, Ordering::Relaxed),
+            enabled: false,
+            last_view: None,
+        }
+    }
+}
+
+impl NavigationLink
+{
+    /// Forget the last view, e.g. when the tab starts showing another fractal, so that the
+    /// change is not broadcast.
+    pub fn reset(&mut self)
+    {
+        self.last_view = None;
+    }
+}
+
 pub struct FractalTab
 {
     pub interface: Box<dyn Interface>,
     pub id: TabID,
     pub menu_state: MenuState,
     pub sidebar_menu: sidebar::menu::Menu,
+    /// Tile names leading to the shown fractal in the sidebar, if it was chosen from there.
+    pub profile: Option<Vec<String>>,
+    /// Preferences applied to each fractal chosen in this tab.
+    pub preferences: Preferences,
+    pub link: NavigationLink,
     #[cfg(feature = "scripting")]
     pub popup: Option<Popup>,
     #[cfg(feature = "scripting")]
-    pub error_report: Option<ErrorReport>,
+    pub script_watcher: Option<ScriptWatcher>,
+    /// Period of the marked-cycle cover shown for the loaded script, if any.
+    #[cfg(feature = "scripting")]
+    pub script_cover: Option<Period>,
 }
 
 impl FractalTab
 {
+    /// Create a tab showing the startup fractal from the preferences, or the default fractal if
+    /// the sidebar has no such fractal.
+    #[must_use]
+    pub fn new(preferences: &Preferences) -> Self
+    {
+        let sidebar_menu = sidebar::create_menu();
+        let startup = sidebar_menu
+            .state
+            .find_fractal(&preferences.startup_profile);
+        let (mut interface, profile) = match startup {
+            Some(create_interface) => (create_interface(), preferences.startup_profile.clone()),
+            None => (
+                sidebar::default_interface(),
+                sidebar::DEFAULT_PROFILE.map(str::to_owned).to_vec(),
+            ),
+        };
+        preferences.apply_to(interface.as_mut());
+        if startup.is_none() {
+            interface.notify(Notification::info(format!(
+                "Startup fractal {} not found, showing the default",
+                preferences.startup_profile.join(" > ")
+            )));
+        }
+
+        Self {
+            interface,
+            sidebar_menu,
+            profile: Some(profile),
+            preferences: preferences.clone(),
+            link: NavigationLink::default(),
+            menu_state: MenuState::default(),
+            id: TabID::default(),
+            #[cfg(feature = "scripting")]
+            popup: None,
+            #[cfg(feature = "scripting")]
+            script_watcher: None,
+            #[cfg(feature = "scripting")]
+            script_cover: None,
+        }
+    }
+
     #[must_use]
     pub const fn with_id(mut self, tab_id: TabID) -> Self
     {
@@ -92,7 +233,7 @@ impl FractalTab
         self
     }
 
-    pub fn update(&mut self, ui: &mut Ui)
+    pub fn update(&mut self, ui: &mut Ui, bookmarks: &BookmarkLibrary)
     {
         egui::SidePanel::left("Fractal")
             .default_width(220.)
@@ -100,13 +241,16 @@ impl FractalTab
                 self.sidebar(ui);
             });
 
+        #[cfg(feature = "scripting")]
+        self.watch_user_script(ui.ctx());
+
         if self.should_update_interface() {
             self.interface.update(ui.ctx());
         }
 
         egui::CentralPanel::default().show_inside(ui, |ui| {
             ui.label(self.interface.name());
-            self.show_menu(ui);
+            self.show_menu(ui, bookmarks);
             self.interface.show(ui);
         });
 
@@ -114,11 +258,12 @@ impl FractalTab
         self.show_popup(ui);
     }
 
-    fn show_menu(&mut self, ui: &mut Ui)
+    fn show_menu(&mut self, ui: &mut Ui, bookmarks: &BookmarkLibrary)
     {
         self.menu_state.close();
         egui::menu::bar(ui, |ui| {
             self.file_menu(ui);
+            self.bookmarks_menu(ui, bookmarks);
             self.image_menu(ui);
             self.selection_menu(ui);
             self.annotations_menu(ui);
@@ -133,8 +278,25 @@ impl FractalTab
     {
         ui.menu_button("File", |ui| {
             self.menu_state.open();
-            for hotkey in &FILE_HOTKEYS {
-                self.hotkey_button(ui, hotkey);
+            self.hotkey_buttons(ui, HotkeyGroup::File);
+        });
+    }
+
+    fn bookmarks_menu(&mut self, ui: &mut Ui, bookmarks: &BookmarkLibrary)
+    {
+        ui.menu_button("Bookmarks", |ui| {
+            self.menu_state.open();
+            if ui.button(Action::OpenBookmarks.short_description()).clicked() {
+                self.interface.process_action(&Action::OpenBookmarks);
+                ui.close_menu();
+            }
+            if !bookmarks.bookmarks.is_empty() {
+                ui.separator();
+            }
+            if let Some(bookmark) = bookmarks.menu_buttons(ui) {
+                bookmark.open_in(self);
+                self.interface.consume_click();
+                ui.close_menu();
             }
         });
     }
@@ -144,31 +306,51 @@ impl FractalTab
         use sidebar::menu::Action::ChangeFractal;
         if let Some(action) = self.sidebar_menu.show_and_get_action(ui) {
             match action {
-                ChangeFractal(interface) => {
-                    self.interface = interface;
+                ChangeFractal(interface, profile) => {
+                    self.set_interface(interface);
+                    self.profile = Some(profile);
                 }
             }
         }
     }
 
+    /// Show the fractal found by following `path` through the sidebar. Returns false if there is
+    /// no such fractal.
+    pub fn set_profile(&mut self, path: &[String]) -> bool
+    {
+        let Some(create_interface) = sidebar::create_menu().state.find_fractal(path) else {
+            return false;
+        };
+        self.set_interface(create_interface());
+        self.profile = Some(path.to_vec());
+        true
+    }
+
+    /// Replace the fractal shown in this tab, applying the tab's preferences to it.
+    pub fn set_interface(&mut self, mut interface: Box<dyn Interface>)
+    {
+        self.preferences.apply_to(interface.as_mut());
+        self.interface = interface;
+        self.profile = None;
+        self.link.reset();
+        #[cfg(feature = "scripting")]
+        {
+            self.script_watcher = None;
+        }
+    }
+
     fn coloring_menu(&mut self, ui: &mut Ui)
     {
         ui.menu_button("Coloring", |ui| {
             self.menu_state.open();
             ui.menu_button("Palette", |ui| {
-                for hotkey in &PALETTE_HOTKEYS {
-                    self.hotkey_button(ui, hotkey);
-                }
+                self.hotkey_buttons(ui, HotkeyGroup::Palette);
             });
 
             ui.menu_button("Incoloring", |ui| {
-                for hotkey in &INCOLORING_HOTKEYS {
-                    self.hotkey_button(ui, hotkey);
-                }
+                self.hotkey_buttons(ui, HotkeyGroup::Incoloring);
             });
-            for hotkey in &OUTCOLORING_HOTKEYS {
-                self.hotkey_button(ui, hotkey);
-            }
+            self.hotkey_buttons(ui, HotkeyGroup::Outcoloring);
         });
     }
 
@@ -194,19 +376,75 @@ impl FractalTab
                 ui.close_menu();
             });
 
-            for hotkey in &IMAGE_HOTKEYS {
-                self.hotkey_button(ui, hotkey);
+            self.hotkey_buttons(ui, HotkeyGroup::Image);
+            ui.separator();
+            if ui
+                .checkbox(&mut self.link.enabled, "Link Navigation")
+                .on_hover_text("Zoom, pan and color together with other linked tabs.")
+                .clicked()
+            {
+                self.link.reset();
+                self.interface.consume_click();
             }
         });
     }
 
+    /// Publish the changes to the bounds and palette since the last sync, if navigation is
+    /// linked.
+    fn publish_navigation(&mut self, bus: &mut NavigationBus)
+    {
+        if !self.link.enabled {
+            return;
+        }
+        let view = self.interface.view_state();
+        if let Some(last_view) = &self.link.last_view {
+            let serial = self.link.serial;
+            for (pane_id, pane, last_pane) in [
+                (PaneID::Parent, &view.parent, &last_view.parent),
+                (PaneID::Child, &view.child, &last_view.child),
+            ] {
+                if pane.bounds != last_pane.bounds {
+                    bus.publish(
+                        serial,
+                        NavigationEvent::Bounds(pane_id, pane.bounds.clone()),
+                    );
+                }
+            }
+            if view.parent.palette != last_view.parent.palette {
+                bus.publish(serial, NavigationEvent::Palette(view.parent.palette));
+            }
+        }
+        self.link.last_view = Some(view);
+    }
+
+    /// Make the changes published by other linked tabs.
+    fn receive_navigation(&mut self, bus: &NavigationBus)
+    {
+        if !self.link.enabled {
+            return;
+        }
+        let mut received = false;
+        for event in bus.events_for(self.link.serial) {
+            let action = match event {
+                NavigationEvent::Bounds(pane_id, bounds) => {
+                    Action::SetBounds(PaneSelection::Id(*pane_id), bounds.clone())
+                }
+                NavigationEvent::Palette(palette) => Action::SetPalette(*palette),
+            };
+            self.interface.process_action(&action);
+            received = true;
+        }
+        if received {
+            // Don't echo the changes back to the bus
+            self.link.last_view = Some(self.interface.view_state());
+        }
+    }
+
     fn selection_menu(&mut self, ui: &mut Ui)
     {
         ui.menu_button("Selection", |ui| {
             self.menu_state.open();
-            for hotkey in &SELECTION_HOTKEYS {
-                self.hotkey_button(ui, hotkey);
-            }
+            self.hotkey_buttons(ui, HotkeyGroup::Selection);
         });
     }
 
@@ -215,13 +453,9 @@ impl FractalTab
         ui.menu_button("Annotations", |ui| {
             self.menu_state.open();
             ui.menu_button("Cycles", |ui| {
-                for hotkey in &CYCLES_HOTKEYS {
-                    self.hotkey_button(ui, hotkey);
-                }
+                self.hotkey_buttons(ui, HotkeyGroup::Cycles);
             });
-            for hotkey in &ANNOTATION_HOTKEYS {
-                self.hotkey_button(ui, hotkey);
-            }
+            self.hotkey_buttons(ui, HotkeyGroup::Annotation);
         });
     }
 
@@ -241,6 +475,51 @@ impl FractalTab
                 self.popup = Some(Popup::load());
                 ui.close_menu();
             }
+            self.script_covers_menu(ui);
+        });
+    }
+
+    #[cfg(feature = "scripting")]
+    fn script_covers_menu(&mut self, ui: &mut Ui)
+    {
+        use script_loader::Loader;
+        let Some(toml_path) = self
+            .script_watcher
+            .as_ref()
+            .map(|w| w.toml_path().to_owned())
+        else {
+            return;
+        };
+        let image_height = self.interface.get_image_height();
+        let periods = Loader::new(&toml_path, image_height)
+            .marked_cycle_periods()
+            .unwrap_or_default();
+        if periods.is_empty() {
+            return;
+        }
+
+        ui.menu_button("Marked cycle covers", |ui| {
+            let mut choice = None;
+            if ui
+                .radio(self.script_cover.is_none(), "Parameter plane")
+                .clicked()
+            {
+                choice = Some(None);
+            }
+            for period in periods {
+                if ui
+                    .radio(self.script_cover == Some(period), format!("Per({period})"))
+                    .clicked()
+                {
+                    choice = Some(Some(period));
+                }
+            }
+            if let Some(cover) = choice {
+                if let Err(e) = self.load_user_script(&toml_path, cover) {
+                    self.interface.notify(Notification::error("Error loading cover", &e));
+                }
+                ui.close_menu();
+            }
         });
     }
 
@@ -253,15 +532,13 @@ impl FractalTab
             Close => {
                 self.popup = None;
             }
-            Load(path) => match self.load_user_script(path) {
+            Notify(notification) => self.interface.notify(notification),
+            Load(path) => match self.load_user_script(path, None) {
                 Ok(()) => {
                     self.popup = None;
                 }
                 Err(e) => {
-                    self.error_report = Some(ErrorReport::new(
-                        "Error parsing script".to_owned(),
-                        format!("{e:?}"),
-                    ));
+                    self.interface.notify(Notification::error("Error parsing script", &e));
                 }
             },
         }
@@ -292,25 +569,96 @@ impl FractalTab
     }
 
     #[cfg(feature = "scripting")]
-    fn load_user_script<P: AsRef<Path>>(&mut self, script_path: P) -> Result<(), ScriptError>
+    fn load_user_script<P: AsRef<Path>>(
+        &mut self,
+        script_path: P,
+        cover: Option<Period>,
+    ) -> DynamoResult<()>
+    {
+        let script_path = script_path.as_ref();
+        let image_height = self.interface.get_image_height();
+        let loader = script_loader(script_path, image_height, cover);
+        let rust_path = loader.generated_rust_path().ok();
+        let result = unsafe { loader.run() };
+        self.interface = interpret_if_uncompiled(result, script_path, image_height)?;
+        self.profile = None;
+        self.link.reset();
+        self.script_watcher = Some(ScriptWatcher::new(script_path, rust_path));
+        self.script_cover = cover;
+        Ok(())
+    }
+
+    /// Load a user script from its TOML contents, evaluating it with the bytecode interpreter.
+    #[cfg(feature = "interpreter")]
+    pub fn load_interpreted_script(
+        &mut self,
+        toml_text: &str,
+    ) -> Result<(), script_interpreter::error::InterpreterError>
     {
-        use script_loader::Loader;
         let image_height = self.interface.get_image_height();
-        let loader = Loader::new(script_path.as_ref(), image_height);
-        unsafe {
-            let int = loader.run()?;
-            self.interface = Box::new(int);
+        self.interface = script_interpreter::create_interface(toml_text, image_height)?;
+        self.profile = None;
+        self.link.reset();
+        #[cfg(feature = "scripting")]
+        {
+            self.script_watcher = None;
         }
         Ok(())
     }
 
-    fn hotkey_button(&mut self, ui: &mut Ui, hotkey: &Hotkey)
+    /// Rebuild and swap in the interface if the loaded script, or the Rust generated from it,
+    /// has been modified on disk.
+    #[cfg(feature = "scripting")]
+    fn watch_user_script(&mut self, ctx: &egui::Context)
     {
-        if let Some(action) = hotkey.menu_action() {
+        use script_loader::watcher::{ScriptChange, POLL_INTERVAL};
+
+        if self.popup.is_some() {
+            return;
+        }
+        let Some(watcher) = self.script_watcher.as_mut() else {
+            return;
+        };
+        ctx.request_repaint_after(POLL_INTERVAL);
+        let Some(change) = watcher.poll() else {
+            return;
+        };
+
+        let toml_path = watcher.toml_path().to_owned();
+        let image_height = self.interface.get_image_height();
+        let loader = script_loader(&toml_path, image_height, self.script_cover);
+        let result = unsafe {
+            match change {
+                ScriptChange::Script => loader.run(),
+                ScriptChange::GeneratedRust => loader.rebuild(),
+            }
+        };
+        match interpret_if_uncompiled(result, &toml_path, image_height) {
+            Ok(int) => {
+                self.interface = int;
+            }
+            Err(e) => {
+                self.interface.notify(Notification::error("Error reloading script", &e));
+            }
+        }
+
+        // Reloading rewrites the generated Rust, which should not count as another change.
+        if let Some(watcher) = self.script_watcher.as_mut() {
+            watcher.refresh();
+        }
+    }
+
+    fn hotkey_buttons(&mut self, ui: &mut Ui, group: HotkeyGroup)
+    {
+        let keymap = Keymap::current(ui.ctx());
+        for hotkey in group.hotkeys() {
+            let Some(action) = hotkey.menu_action() else {
+                continue;
+            };
             if ui
                 .add(
                     egui::Button::new(action.short_description())
-                        .shortcut_text(hotkey.shortcut_text().unwrap_or_default()),
+                        .shortcut_text(keymap.shortcut_text(hotkey).unwrap_or_default()),
                 )
                 .clicked()
             {
@@ -324,17 +672,53 @@ impl FractalTab
     #[cfg(feature = "scripting")]
     fn show_popup(&mut self, ui: &mut Ui)
     {
+        if let Some((operation, recovery)) = self.interface.pop_recovery() {
+            if let Some(Popup::Edit(editor)) = self.popup.as_mut() {
+                editor.recover(operation, recovery);
+            }
+        }
         if let Some(popup) = self.popup.as_mut() {
             popup.show(ui.ctx());
             let response = popup.pop_response();
             self.handle_popup_response(response);
         }
-        if let Some(error_report) = self.error_report.as_mut() {
-            error_report.show(ui.ctx());
-            if !error_report.visible {
-                self.error_report = None;
-            }
+    }
+}
+
+#[cfg(feature = "scripting")]
+fn script_loader(
+    script_path: &Path,
+    image_height: usize,
+    cover: Option<Period>,
+) -> script_loader::Loader<'_>
+{
+    let loader = script_loader::Loader::new(script_path, image_height);
+    match cover {
+        Some(period) => loader.with_marked_cycle_cover(period),
+        None => loader,
+    }
+}
+
+/// Fall back to the bytecode interpreter if the script could not be compiled, e.g. on machines
+/// without a Rust toolchain.
+#[cfg(feature = "scripting")]
+fn interpret_if_uncompiled<I: Interface + 'static>(
+    result: Result<I, ScriptError>,
+    script_path: &Path,
+    image_height: usize,
+) -> DynamoResult<Box<dyn Interface>>
+{
+    match result {
+        Ok(int) => Ok(Box::new(int)),
+        Err(ScriptError::CargoCommandFailed(e)) => {
+            let mut interface =
+                script_interpreter::create_interface_from_path(script_path, image_height)?;
+            interface.notify(Notification::info(format!(
+                "Could not compile script ({e}), falling back to the interpreter"
+            )));
+            Ok(interface)
         }
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -342,25 +726,6 @@ impl Default for FractalTab
 {
     fn default() -> Self
     {
-        type Profile = Mandelbrot;
-
-        let height = IMAGE_HEIGHT;
-
-        let parent_plane = Profile::default().with_res_y(height).with_max_iter(1024);
-        let child_plane = JuliaSet::from(parent_plane.clone());
-
-        let interface = Box::new(MainInterface::new(parent_plane, child_plane, height));
-        let sidebar_menu = sidebar::create_menu();
-
-        Self {
-            interface,
-            sidebar_menu,
-            menu_state: MenuState::default(),
-            id: TabID::default(),
-            #[cfg(feature = "scripting")]
-            popup: None,
-            #[cfg(feature = "scripting")]
-            error_report: None,
-        }
+        Self::new(&Preferences::default())
     }
 }
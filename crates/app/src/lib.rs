@@ -2,12 +2,26 @@
 #![allow(dead_code)]
 use egui_dock::{DockArea, DockState, NodeIndex, Style, SurfaceIndex};
 
+pub mod bookmarks;
+pub mod command_palette;
 pub mod fractal_tab;
 pub mod macros;
+pub mod preferences;
+#[cfg(feature = "remote")]
+pub mod remote;
+pub mod render_queue;
 #[cfg(feature = "scripting")]
 pub mod script_editor;
+pub mod shortcut_editor;
 pub mod sidebar;
-use fractal_tab::{FractalTab, TabID};
+use bookmarks::{BookmarkLibrary, BookmarksWindow};
+use command_palette::{Command, CommandPalette};
+use dynamo_gui::notifications::Notification;
+use dynamo_gui::view_state::ViewState;
+use fractal_tab::{FractalTab, NavigationBus, TabID};
+use preferences::{Preferences, PreferencesWindow};
+use render_queue::RenderQueue;
+use shortcut_editor::ShortcutEditor;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub fn run_app() -> Result<(), eframe::Error>
@@ -25,7 +39,11 @@ pub fn run_app() -> Result<(), eframe::Error>
         options,
         Box::new(|cc| {
             egui_extras::install_image_loaders(&cc.egui_ctx);
-            Ok(Box::<FractalApp>::default())
+            #[allow(unused_mut)]
+            let mut app = FractalApp::default();
+            #[cfg(feature = "remote")]
+            app.listen_from_args(&cc.egui_ctx);
+            Ok(Box::new(app))
         }),
     )
 }
@@ -34,6 +52,13 @@ struct TabViewer<'a>
 {
     added_nodes: &'a mut Vec<FractalTab>,
     to_remove: &'a mut Vec<TabID>,
+    open_render_queue: &'a mut bool,
+    open_shortcut_editor: &'a mut bool,
+    open_command_palette: &'a mut bool,
+    open_preferences: &'a mut bool,
+    open_bookmarks: &'a mut bool,
+    preferences: &'a Preferences,
+    bookmarks: &'a BookmarkLibrary,
 }
 
 impl egui_dock::TabViewer for TabViewer<'_>
@@ -42,9 +67,12 @@ impl egui_dock::TabViewer for TabViewer<'_>
 
     fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab)
     {
-        use dynamo_gui::interface::UiMessage::{CloseWindow, DoNothing, NewTab, Quit};
+        use dynamo_gui::interface::UiMessage::{
+            CloseWindow, DoNothing, NewTab, OpenBookmarks, OpenCommandPalette, OpenPreferences,
+            OpenRenderQueue, OpenShortcutEditor, Quit,
+        };
 
-        tab.update(ui);
+        tab.update(ui, self.bookmarks);
         match tab.interface.pop_message() {
             Quit => {
                 std::process::exit(0);
@@ -55,6 +83,21 @@ impl egui_dock::TabViewer for TabViewer<'_>
             NewTab => {
                 self.on_add(tab.id.surface, tab.id.node);
             }
+            OpenRenderQueue => {
+                *self.open_render_queue = true;
+            }
+            OpenShortcutEditor => {
+                *self.open_shortcut_editor = true;
+            }
+            OpenCommandPalette => {
+                *self.open_command_palette = true;
+            }
+            OpenPreferences => {
+                *self.open_preferences = true;
+            }
+            OpenBookmarks => {
+                *self.open_bookmarks = true;
+            }
             DoNothing => {}
         }
     }
@@ -67,7 +110,7 @@ impl egui_dock::TabViewer for TabViewer<'_>
     fn on_add(&mut self, surface: SurfaceIndex, node: NodeIndex)
     {
         let tab_id = TabID { surface, node };
-        let tab = FractalTab::default().with_id(tab_id);
+        let tab = FractalTab::new(self.preferences).with_id(tab_id);
         self.added_nodes.push(tab);
     }
 }
@@ -76,21 +119,200 @@ pub struct FractalApp
 {
     dock_state: DockState<FractalTab>,
     tab_count: usize,
+    render_queue: RenderQueue,
+    shortcut_editor: ShortcutEditor,
+    command_palette: CommandPalette,
+    preferences: Preferences,
+    preferences_window: PreferencesWindow,
+    bookmarks: BookmarkLibrary,
+    bookmarks_window: BookmarksWindow,
+    navigation: NavigationBus,
+    #[cfg(feature = "remote")]
+    remote: Option<remote::RemoteServer>,
 }
 
 impl Default for FractalApp
 {
     fn default() -> Self
     {
-        let tab0 = FractalTab::default();
+        let mut errors = Vec::new();
+        let preferences = Preferences::load().unwrap_or_else(|e| {
+            errors.push(Notification::error("Could not load preferences, using the defaults", &e));
+            Preferences::default()
+        });
+        let bookmarks = BookmarkLibrary::load().unwrap_or_else(|e| {
+            errors.push(Notification::error("Could not load bookmarks", &e));
+            BookmarkLibrary::default()
+        });
+        let mut shortcut_editor = ShortcutEditor::default();
+        if let Some(e) = shortcut_editor.take_load_error() {
+            errors.push(Notification::error("Could not load keymap, using the defaults", &e));
+        }
+        let mut tab0 = FractalTab::new(&preferences);
+        for notification in errors {
+            tab0.interface.notify(notification);
+        }
 
         let dock_state = DockState::new(vec![tab0]);
 
         Self {
             dock_state,
             tab_count: 1,
+            render_queue: RenderQueue::default(),
+            shortcut_editor,
+            command_palette: CommandPalette::default(),
+            preferences,
+            preferences_window: PreferencesWindow::default(),
+            bookmarks,
+            bookmarks_window: BookmarksWindow::default(),
+            navigation: NavigationBus::default(),
+            #[cfg(feature = "remote")]
+            remote: None,
+        }
+    }
+}
+
+impl FractalApp
+{
+    /// The focused tab, or the first tab if none is focused.
+    fn focused_tab_mut(&mut self) -> Option<&mut FractalTab>
+    {
+        if self.dock_state.find_active_focused().is_some() {
+            self.dock_state.find_active_focused().map(|(_, tab)| tab)
+        } else {
+            self.dock_state
+                .iter_all_tabs_mut()
+                .next()
+                .map(|(_, tab)| tab)
+        }
+    }
+
+    /// The sidebar profile and view of the focused tab, or None if its fractal was not chosen
+    /// from the sidebar, e.g. if it comes from a user script.
+    pub fn view_state(&mut self) -> Option<(Vec<String>, ViewState)>
+    {
+        let tab = self.focused_tab_mut()?;
+        let profile = tab.profile.clone()?;
+        Some((profile, tab.interface.view_state()))
+    }
+
+    /// Show the fractal found by following `path` through the sidebar in the focused tab.
+    /// Returns false if there is no such fractal.
+    pub fn set_profile(&mut self, path: &[String]) -> bool
+    {
+        self.focused_tab_mut()
+            .is_some_and(|tab| tab.set_profile(path))
+    }
+
+    pub fn restore_view_state(&mut self, view: &ViewState)
+    {
+        if let Some(tab) = self.focused_tab_mut() {
+            tab.interface.restore_view_state(view);
+        }
+    }
+
+    /// Run a command chosen from the command palette in the focused tab.
+    fn run_command(&mut self, command: Command)
+    {
+        let Some(tab) = self.focused_tab_mut() else {
+            return;
+        };
+        match command {
+            Command::Hotkey(hotkey) => {
+                tab.interface.process_action(hotkey.chosen_action());
+                if let Some(bonus_action) = hotkey.get_bonus_action() {
+                    tab.interface.process_action(bonus_action);
+                }
+            }
+            Command::Profile(path) => {
+                tab.set_profile(&path);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "interpreter")]
+impl FractalApp
+{
+    /// Load a user script from its TOML contents into the focused tab, or the first tab if none
+    /// is focused, evaluating it with the bytecode interpreter.
+    pub fn load_interpreted_script(
+        &mut self,
+        toml_text: &str,
+    ) -> Result<(), script_interpreter::error::InterpreterError>
+    {
+        match self.focused_tab_mut() {
+            Some(tab) => tab.load_interpreted_script(toml_text),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "remote")]
+impl FractalApp
+{
+    /// Start the remote control server if the app was launched with `--remote [address]`.
+    pub fn listen_from_args(&mut self, ctx: &egui::Context)
+    {
+        let args: Vec<String> = std::env::args().collect();
+        let Some(idx) = args.iter().position(|arg| arg == "--remote") else {
+            return;
+        };
+        let address = args
+            .get(idx + 1)
+            .filter(|arg| !arg.starts_with("--"))
+            .cloned()
+            .unwrap_or_else(|| remote::DEFAULT_ADDRESS.to_owned());
+
+        let ctx = ctx.clone();
+        match remote::RemoteServer::start(&address, move || ctx.request_repaint()) {
+            Ok(server) => {
+                eprintln!("Listening for remote commands on ws://{}", server.address());
+                self.remote = Some(server);
+            }
+            Err(e) => {
+                if let Some(tab) = self.focused_tab_mut() {
+                    let title = format!("Could not start remote server on {address}");
+                    tab.interface.notify(Notification::failure(title, e.to_string()));
+                }
+            }
         }
     }
+
+    /// Run at most one remote command per frame, so that each command sees the effects of the
+    /// previous ones, e.g. a requested image reflects newly set bounds.
+    fn handle_remote_command(&mut self, ctx: &egui::Context)
+    {
+        use remote::{Command, Reply};
+
+        let Some(request) = self.remote.as_ref().and_then(remote::RemoteServer::poll) else {
+            return;
+        };
+        let Some(tab) = self.focused_tab_mut() else {
+            request.reply(Reply::Error("No tab is open".to_owned()));
+            return;
+        };
+
+        let reply = match &request.command {
+            Command::Action(action) => {
+                tab.interface.process_action(action);
+                Reply::Ok
+            }
+            Command::SetProfile(path) => {
+                if tab.set_profile(path) {
+                    Reply::Ok
+                } else {
+                    Reply::Error(format!("No fractal found at {path:?}"))
+                }
+            }
+            Command::RequestPng(pane_id) => match tab.interface.encode_png(*pane_id) {
+                Ok(bytes) => Reply::Png(bytes),
+                Err(e) => Reply::Error(e.to_string()),
+            },
+        };
+        request.reply(reply);
+        ctx.request_repaint();
+    }
 }
 
 impl eframe::App for FractalApp
@@ -99,6 +321,11 @@ impl eframe::App for FractalApp
     {
         let mut added_nodes = Vec::new();
         let mut to_remove = Vec::new();
+        self.shortcut_editor.show(ctx);
+        ctx.tessellation_options_mut(|options| options.feathering = self.preferences.antialiasing);
+        if let Some(command) = self.command_palette.show(ctx) {
+            self.run_command(command);
+        }
         DockArea::new(&mut self.dock_state)
             .show_add_buttons(true)
             .style({
@@ -111,8 +338,34 @@ impl eframe::App for FractalApp
                 &mut TabViewer {
                     added_nodes: &mut added_nodes,
                     to_remove: &mut to_remove,
+                    open_render_queue: &mut self.render_queue.open,
+                    open_shortcut_editor: &mut self.shortcut_editor.open,
+                    open_command_palette: &mut self.command_palette.open,
+                    open_preferences: &mut self.preferences_window.open,
+                    open_bookmarks: &mut self.bookmarks_window.open,
+                    preferences: &self.preferences,
+                    bookmarks: &self.bookmarks,
                 },
             );
+        #[cfg(feature = "remote")]
+        self.handle_remote_command(ctx);
+        self.navigation.sync(
+            self.dock_state
+                .iter_all_tabs_mut()
+                .map(|(_, tab)| tab)
+                .collect(),
+        );
+
+        let focused_interface = self
+            .dock_state
+            .find_active_focused()
+            .map(|(_, tab)| &*tab.interface);
+        self.render_queue.show(ctx, focused_interface);
+        let focused_tab = self.dock_state.find_active_focused().map(|(_, tab)| &*tab);
+        self.preferences_window
+            .show(ctx, &mut self.preferences, focused_tab);
+        self.bookmarks_window
+            .show(ctx, &mut self.bookmarks, focused_tab);
         for tab in added_nodes {
             self.dock_state.set_focused_node_and_surface(tab.id.into());
             self.dock_state.push_to_focused_leaf(tab);
@@ -154,4 +407,430 @@ mod tests
             interface.child_mut().process_tasks();
         }
     }
+
+    #[test]
+    fn background_compute()
+    {
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+
+        let height = 384;
+        let parent_plane = dynamo_profiles::Mandelbrot::default()
+            .with_res_y(height)
+            .with_max_iter(256);
+        let child_plane = JuliaSet::from(parent_plane.clone());
+
+        let mut blocking = MainInterface::new(parent_plane.clone(), child_plane.clone(), height);
+        blocking.parent_mut().process_tasks();
+        let expected = &blocking.parent().get_image_frame().image;
+
+        // With a single thread, as on the web, tiles are computed on the calling thread a few at
+        // a time
+        let single_thread = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        for pool in [None, Some(&single_thread)] {
+            let mut background =
+                MainInterface::new(parent_plane.clone(), child_plane.clone(), height);
+            background.parent_mut().set_background_compute(true);
+            let mut run = || {
+                background.parent_mut().process_tasks();
+                while background.parent().is_computing() {
+                    background.parent_mut().process_tasks();
+                }
+            };
+            match pool {
+                Some(pool) => pool.install(run),
+                None => run(),
+            }
+            assert_eq!(&background.parent().get_image_frame().image, expected);
+        }
+    }
+
+    #[test]
+    fn pan_after_zoom()
+    {
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+
+        let height = 64;
+        let parent_plane = dynamo_profiles::Mandelbrot::default()
+            .with_res_y(height)
+            .with_max_iter(64);
+        let child_plane = JuliaSet::from(parent_plane.clone());
+        let mut interface = MainInterface::new(parent_plane, child_plane, height);
+        let pane = interface.parent_mut();
+        pane.process_tasks();
+
+        // Less than half a pixel, so the grid stays put and the rest is carried over
+        let pixel_width = pane.grid().pixel_width();
+        pane.pan((0.4 * pixel_width).into());
+        pane.zoom(0.5, pane.grid().bounds.center());
+        pane.process_tasks();
+
+        // The carried over part was 0.8 pixels of the zoomed grid, and must not be added here
+        let bounds = pane.grid().bounds.clone();
+        pane.pan((0.3 * pane.grid().pixel_width()).into());
+        assert_eq!(pane.grid().bounds, bounds);
+    }
+
+    #[test]
+    fn texture_dirty_rows()
+    {
+        use dynamo_gui::image_frame::ImageFrame;
+        use egui::{CentralPanel, Color32, ColorImage, RawInput};
+
+        let ctx = egui::Context::default();
+        let image = ColorImage::new([8, 6], Color32::BLACK);
+        let mut frame = ImageFrame::new(image.clone());
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| frame.put(ui));
+        });
+        let _ = ctx.tex_manager().write().take_delta();
+
+        // Only the runs of changed rows are uploaded, in place
+        let mut changed = image;
+        changed.pixels[8 * 2..8 * 4].fill(Color32::WHITE);
+        changed.pixels[8 * 5] = Color32::RED;
+        frame.set_image(changed);
+        let uploads: Vec<_> = ctx
+            .tex_manager()
+            .write()
+            .take_delta()
+            .set
+            .into_iter()
+            .map(|(_, delta)| (delta.pos, delta.image.size()))
+            .collect();
+        assert_eq!(uploads, [(Some([0, 2]), [8, 2]), (Some([0, 5]), [8, 1])]);
+
+        // An unchanged image uploads nothing
+        frame.set_image(frame.image.clone());
+        assert!(ctx.tex_manager().write().take_delta().set.is_empty());
+    }
+
+    #[test]
+    fn comparison_pane()
+    {
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::actions::Action;
+        use dynamo_gui::interface::{Interactive, MainInterface, PanePair};
+        use dynamo_gui::pane::id::PaneID;
+
+        let height = 64;
+        let parent_plane = dynamo_profiles::Mandelbrot::default().with_res_y(height);
+        let child_plane = JuliaSet::from(parent_plane.clone());
+        let mut interface = MainInterface::new(parent_plane, child_plane, height);
+
+        interface.process_action(&Action::ToggleComparison);
+        assert!(interface.comparison().is_some());
+
+        // Zooming the child zooms the comparison pane too, and vice versa
+        interface.set_active_pane(Some(PaneID::Child));
+        interface.process_action(&Action::Zoom(0.5));
+        interface.update_panes();
+        let bounds = interface.child().grid().bounds.clone();
+        assert_eq!(interface.comparison().unwrap().grid().bounds, bounds);
+
+        interface.comparison_mut().unwrap().pan_relative(0.25, 0.);
+        interface.update_panes();
+        assert_ne!(interface.child().grid().bounds, bounds);
+        assert_eq!(
+            interface.comparison().unwrap().grid().bounds,
+            interface.child().grid().bounds
+        );
+
+        interface.process_action(&Action::ToggleComparison);
+        assert!(interface.comparison().is_none());
+    }
+
+    #[test]
+    fn linked_navigation()
+    {
+        use crate::fractal_tab::{FractalTab, NavigationBus};
+        use dynamo_gui::actions::Action;
+        use dynamo_gui::pane::id::PaneSelection;
+
+        let mut tabs: Vec<FractalTab> = (0..3).map(|_| FractalTab::default()).collect();
+        tabs[0].link.enabled = true;
+        tabs[1].link.enabled = true;
+        let mut bus = NavigationBus::default();
+        bus.sync(tabs.iter_mut().collect());
+
+        let original_bounds = tabs[2].interface.view_state().parent.bounds;
+        tabs[1].interface.process_action(&Action::Zoom(0.5));
+        tabs[1]
+            .interface
+            .process_action(&Action::SetPaletteWhite(PaneSelection::BothPanes));
+        bus.sync(tabs.iter_mut().collect());
+
+        let linked = tabs[1].interface.view_state();
+        assert_ne!(linked.parent.bounds, original_bounds);
+        assert_eq!(tabs[0].interface.view_state().parent, linked.parent);
+        assert_eq!(
+            tabs[2].interface.view_state().parent.bounds,
+            original_bounds
+        );
+    }
+
+    #[test]
+    fn sidebar_lookup()
+    {
+        let menu = crate::sidebar::create_menu();
+        let create_interface = menu
+            .state
+            .find_fractal(&["Polynomial", "Quadratic Family", "Base Curve"])
+            .unwrap();
+        assert_eq!(create_interface().name(), "Mandelbrot");
+        assert!(menu
+            .state
+            .find_fractal(&["Polynomial", "Quadratic Family"])
+            .is_none());
+        assert!(menu.state.find_fractal(&["Nonexistent"]).is_none());
+    }
+
+    #[test]
+    fn command_palette_search()
+    {
+        use crate::command_palette::{fuzzy_score, Command, Registry};
+
+        assert!(fuzzy_score("rnd", "Random").is_some());
+        assert!(fuzzy_score("xyz", "Random").is_none());
+        assert!(fuzzy_score("zo", "Zoom in") > fuzzy_score("zo", "Draw Orbit"));
+
+        let registry = Registry::default();
+        let best = registry.search("quad base");
+        assert!(matches!(
+            &best[0].command,
+            Command::Profile(path) if path == &["Polynomial", "Quadratic Family", "Base Curve"]
+        ));
+        let best = registry.search("random");
+        assert!(
+            matches!(best[0].command, Command::Hotkey(hotkey) if hotkey.name() == "randomize_palette")
+        );
+    }
+
+    #[test]
+    fn keymap_round_trip()
+    {
+        use dynamo_gui::hotkeys::keymap::{format_shortcut, parse_shortcut, Keymap};
+        use egui::{Key, KeyboardShortcut, Modifiers};
+
+        let default = Keymap::default();
+        let names: std::collections::HashSet<_> =
+            default.hotkeys().map(|(hotkey, _)| hotkey.name()).collect();
+        assert_eq!(names.len(), default.hotkeys().count());
+        assert!(default.conflicts().is_empty());
+        assert!(default.to_toml().is_empty());
+
+        let ctrl_plus = KeyboardShortcut::new(Modifiers::CTRL, Key::Plus);
+        assert_eq!(parse_shortcut(&format_shortcut(ctrl_plus)), Some(ctrl_plus));
+        assert_eq!(
+            parse_shortcut("Ctrl+Shift+P")
+                .map(format_shortcut)
+                .as_deref(),
+            Some("Ctrl+Shift+P")
+        );
+        assert!(parse_shortcut("Hyper+P").is_none());
+
+        // Binding the zoom key to "clear orbit" leaves both hotkeys on the same shortcut
+        let mut keymap = Keymap::default();
+        let key_z = KeyboardShortcut::new(Modifiers::NONE, Key::Z);
+        keymap.bind("clear_orbit", Some(key_z)).unwrap();
+        keymap.bind("toggle_live_mode", None).unwrap();
+        assert!(keymap.bind("not_a_hotkey", None).is_err());
+        assert_eq!(
+            keymap.conflicts(),
+            vec![(key_z, vec!["zoom_in", "clear_orbit"])]
+        );
+
+        let reloaded = Keymap::from_toml(&keymap.to_toml()).unwrap();
+        assert_eq!(reloaded, keymap);
+        assert!(Keymap::from_toml("zoom_in = \"Ctrl+Nonsense\"").is_err());
+    }
+
+    #[test]
+    fn preferences()
+    {
+        use crate::fractal_tab::FractalTab;
+        use crate::preferences::Preferences;
+
+        // Missing fields keep their defaults
+        let preferences: Preferences = toml::from_str(
+            r#"
+            max_iter = 4096
+            startup_profile = ["Polynomial", "Cubic Family", "Odd Cubics", "Base curve"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            preferences.image_height,
+            Preferences::default().image_height
+        );
+        let text = toml::to_string(&preferences).unwrap();
+        assert_eq!(toml::from_str::<Preferences>(&text).unwrap(), preferences);
+
+        let tab = FractalTab::new(&preferences);
+        assert_eq!(tab.interface.name(), "OddCubic");
+        assert_eq!(tab.profile.as_ref(), Some(&preferences.startup_profile));
+
+        // An unknown startup profile falls back to the default fractal
+        let preferences = Preferences {
+            startup_profile: vec!["Nonexistent".to_owned()],
+            ..Preferences::default()
+        };
+        assert_eq!(FractalTab::new(&preferences).interface.name(), "Mandelbrot");
+    }
+
+    #[test]
+    fn bookmarks()
+    {
+        use crate::bookmarks::{Bookmark, BookmarkLibrary};
+        use crate::fractal_tab::FractalTab;
+        use crate::preferences::Preferences;
+        use dynamo_gui::actions::Action;
+
+        let mut tab = FractalTab::new(&Preferences::default());
+        tab.interface.process_action(&Action::Zoom(0.5));
+        let bookmark = Bookmark::capture(&tab, "Zoomed".to_owned(), "Tests".to_owned()).unwrap();
+        let library = BookmarkLibrary {
+            bookmarks: vec![bookmark.clone()],
+        };
+        assert_eq!(library.folders(), vec!["Tests"]);
+        let text = toml::to_string(&library).unwrap();
+        assert_eq!(toml::from_str::<BookmarkLibrary>(&text).unwrap(), library);
+
+        let mut other = FractalTab::new(&Preferences::default());
+        assert!(bookmark.open_in(&mut other));
+        assert_eq!(other.interface.view_state().parent.bounds, bookmark.bounds);
+
+        let mut missing = bookmark;
+        missing.profile = vec!["Nonexistent".to_owned()];
+        assert!(!missing.open_in(&mut other));
+    }
+
+    #[test]
+    fn parameter_trail()
+    {
+        use dynamo_common::types::Cplx;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::actions::Action;
+        use dynamo_gui::interface::{Interactive, MainInterface, PanePair};
+        use dynamo_gui::marked_points::Trail;
+
+        let mut trail = Trail::default();
+        for i in 0..100 {
+            trail.record(Cplx::new(f64::from(i), 0.));
+            trail.record(Cplx::new(f64::from(i), 0.));
+        }
+        assert_eq!(trail.points().len(), 64);
+        assert_eq!(trail.points().back(), Some(&Cplx::new(99., 0.)));
+
+        let height = 64;
+        let parent_plane = dynamo_profiles::Mandelbrot::default().with_res_y(height);
+        let child_plane = JuliaSet::from(parent_plane.clone());
+        let mut interface = MainInterface::new(parent_plane, child_plane, height);
+        interface.process_action(&Action::ToggleTrail);
+        assert!(!interface.parent().marking().trail.visible);
+        interface.process_action(&Action::ClearTrail);
+        assert!(interface.parent().marking().trail.points().is_empty());
+    }
+
+    #[test]
+    fn point_files()
+    {
+        use dynamo_common::types::Cplx;
+        use dynamo_gui::point_file::{parse_csv, parse_json, PointFileError, DEFAULT_COLOR};
+        use egui::Color32;
+
+        let with_header = "re, im, label, color\n-1, 0, basilica, #ff0000\n# comment\n0.25, 0.5,,\n";
+        let points = parse_csv(with_header).unwrap();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].point, Cplx::new(-1., 0.));
+        assert_eq!(points[0].label.as_deref(), Some("basilica"));
+        assert_eq!(points[0].color, Color32::from_rgb(255, 0, 0));
+        assert_eq!(points[1].label, None);
+        assert_eq!(points[1].color, DEFAULT_COLOR);
+
+        let without_header = parse_csv("-2, 0\n0, 1, i").unwrap();
+        assert_eq!(without_header.len(), 2);
+        assert_eq!(without_header[1].label.as_deref(), Some("i"));
+
+        // A first line with numbers in it is data, so a typo there is reported, not skipped
+        assert!(matches!(
+            parse_csv("-1, 0.5x\n0, 1"),
+            Err(PointFileError::Invalid { entry: 1, .. })
+        ));
+        assert!(matches!(
+            parse_csv("re, im\n0, 1\nfoo, bar"),
+            Err(PointFileError::Invalid { entry: 3, .. })
+        ));
+        assert!(parse_csv("0, 1, a, not-a-color").is_err());
+
+        let points = parse_json(
+            r##"[[0.5, -0.5], {"re": -1, "im": 0, "label": "airplane", "color": "#00ff00"}]"##,
+        )
+        .unwrap();
+        assert_eq!(points[0].point, Cplx::new(0.5, -0.5));
+        assert_eq!(points[0].color, DEFAULT_COLOR);
+        assert_eq!(points[1].label.as_deref(), Some("airplane"));
+        assert_eq!(points[1].color, Color32::from_rgb(0, 255, 0));
+        assert!(matches!(
+            parse_json(r#"[{"re": 1}]"#),
+            Err(PointFileError::Invalid { entry: 1, .. })
+        ));
+        assert!(parse_json(r#"{"re": 1, "im": 0}"#).is_err());
+    }
+
+    #[cfg(feature = "remote")]
+    #[test]
+    fn remote_rejects_web_pages()
+    {
+        use crate::remote::RemoteServer;
+        use tungstenite::client::IntoClientRequest;
+
+        let server = RemoteServer::start("127.0.0.1:0", || {}).unwrap();
+        let connect = |origin: Option<&str>| {
+            let mut request = format!("ws://{}", server.address())
+                .into_client_request()
+                .unwrap();
+            if let Some(origin) = origin {
+                request.headers_mut().insert("Origin", origin.parse().unwrap());
+            }
+            tungstenite::connect(request)
+                .map(|_| ())
+                .map_err(|e| match e {
+                    tungstenite::Error::Http(response) => Some(response.status()),
+                    _ => None,
+                })
+        };
+
+        let forbidden = Some(tungstenite::http::StatusCode::FORBIDDEN);
+        assert_eq!(connect(Some("https://example.com")), Err(forbidden));
+
+        // Clients outside the browser send no origin, or that of the server itself
+        assert!(connect(None).is_ok());
+        let own_origin = format!("http://{}", server.address());
+        assert!(connect(Some(&own_origin)).is_ok());
+    }
+
+    #[cfg(feature = "remote")]
+    #[test]
+    fn remote_commands()
+    {
+        use crate::remote::Command;
+        use dynamo_gui::actions::Action;
+
+        let command: Command = serde_json::from_str(
+            r#"{"action": {"SetBounds": ["ActivePane", {"min_x": -2, "max_x": 1, "min_y": -1.5, "max_y": 1.5}]}}"#,
+        )
+        .unwrap();
+        let Command::Action(action) = command else {
+            panic!("expected an action, got {command:?}");
+        };
+        assert!(matches!(*action, Action::SetBounds(..)));
+
+        let command: Command = serde_json::from_str(r#"{"request_png": "Child"}"#).unwrap();
+        assert!(matches!(command, Command::RequestPng(_)));
+    }
 }
@@ -0,0 +1,198 @@
+use crate::fractal_tab::FractalTab;
+use crate::sidebar::DEFAULT_PROFILE;
+use dynamo_color::Palette;
+use dynamo_common::prelude::*;
+use dynamo_core::error::{DynamoError, DynamoResult};
+use dynamo_gui::actions::Action;
+use dynamo_gui::interface::Interface;
+use egui::{Context, Ui};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const PREFERENCES_FILE: &str = "preferences.toml";
+
+/// Settings for newly created tabs and fractals, saved in the user's config directory.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences
+{
+    pub image_height: usize,
+    pub max_iter: IterCount,
+    /// Palette for new fractals, or None to use the default palette of each fractal.
+    pub palette: Option<Palette>,
+    pub antialiasing: bool,
+    pub live_mode: bool,
+    /// Tile names leading to the fractal shown in new tabs.
+    pub startup_profile: Vec<String>,
+}
+
+impl Default for Preferences
+{
+    fn default() -> Self
+    {
+        Self {
+            image_height: IMAGE_HEIGHT,
+            max_iter: MAX_ITER,
+            palette: None,
+            antialiasing: true,
+            live_mode: false,
+            startup_profile: DEFAULT_PROFILE.map(str::to_owned).to_vec(),
+        }
+    }
+}
+
+impl Preferences
+{
+    fn path() -> Option<PathBuf>
+    {
+        Some(config_dir()?.join(PREFERENCES_FILE))
+    }
+
+    /// Load the user's preferences, or the defaults if the user has not saved any.
+    pub fn load() -> DynamoResult<Self>
+    {
+        match Self::path() {
+            Some(path) if path.exists() => toml::from_str(&std::fs::read_to_string(path)?)
+                .map_err(|e| DynamoError::Format(e.to_string())),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self) -> DynamoResult<()>
+    {
+        let path = Self::path().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "No config directory")
+        })?;
+        let contents = toml::to_string(self).map_err(|e| DynamoError::Format(e.to_string()))?;
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Apply the preferences to a newly created interface.
+    pub fn apply_to(&self, interface: &mut dyn Interface)
+    {
+        if interface.get_image_height() != self.image_height {
+            interface.change_height(self.image_height);
+        }
+        interface.set_max_iter(self.max_iter);
+        if let Some(palette) = self.palette {
+            interface.process_action(&Action::SetPalette(palette));
+        }
+        if self.live_mode {
+            interface.process_action(&Action::ToggleLiveMode);
+        }
+    }
+}
+
+/// Window for editing the preferences. Changes take effect for tabs opened after saving.
+#[derive(Default)]
+pub struct PreferencesWindow
+{
+    /// Preferences being edited, copied from the saved ones when the window opens.
+    draft: Option<Preferences>,
+    status: Option<String>,
+    pub open: bool,
+}
+
+impl PreferencesWindow
+{
+    pub fn show(&mut self, ctx: &Context, preferences: &mut Preferences, tab: Option<&FractalTab>)
+    {
+        if !self.open {
+            self.draft = None;
+            self.status = None;
+            return;
+        }
+        let draft = self.draft.get_or_insert_with(|| preferences.clone());
+
+        let mut save = false;
+        let mut open = self.open;
+        egui::Window::new("Preferences")
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("preferences")
+                    .num_columns(2)
+                    .show(ui, |ui| preference_fields(ui, draft, tab));
+                ui.separator();
+                ui.horizontal(|ui| {
+                    save = ui.button("Save").clicked();
+                    if ui.button("Reset to defaults").clicked() {
+                        *draft = Preferences::default();
+                    }
+                });
+                if let Some(status) = &self.status {
+                    ui.label(status);
+                }
+            });
+        self.open = open;
+
+        if save {
+            *preferences = draft.clone();
+            self.status = Some(match preferences.save() {
+                Ok(()) => "Saved. New tabs will use these preferences.".to_owned(),
+                Err(e) => format!("Could not save preferences: {e}"),
+            });
+        }
+    }
+}
+
+fn preference_fields(ui: &mut Ui, draft: &mut Preferences, tab: Option<&FractalTab>)
+{
+    ui.label("Image height");
+    ui.add(egui::DragValue::new(&mut draft.image_height).range(64..=4096));
+    ui.end_row();
+
+    ui.label("Max iterations");
+    ui.add(
+        egui::DragValue::new(&mut draft.max_iter)
+            .range(1..=1 << 24)
+            .speed(16),
+    );
+    ui.end_row();
+
+    ui.label("Palette");
+    ui.horizontal(|ui| {
+        ui.label(if draft.palette.is_some() {
+            "Custom"
+        } else {
+            "Default for each fractal"
+        });
+        if ui
+            .add_enabled(tab.is_some(), egui::Button::new("Use current"))
+            .clicked()
+        {
+            draft.palette = tab.map(|tab| tab.interface.view_state().parent.palette);
+        }
+        if ui
+            .add_enabled(draft.palette.is_some(), egui::Button::new("Clear"))
+            .clicked()
+        {
+            draft.palette = None;
+        }
+    });
+    ui.end_row();
+
+    ui.label("Antialiasing");
+    ui.checkbox(&mut draft.antialiasing, "Smooth curves and points");
+    ui.end_row();
+
+    ui.label("Live mode");
+    ui.checkbox(&mut draft.live_mode, "Start in live Julia mode");
+    ui.end_row();
+
+    ui.label("Startup fractal");
+    ui.horizontal(|ui| {
+        ui.label(draft.startup_profile.join(" > "));
+        let profile = tab.and_then(|tab| tab.profile.as_ref());
+        if ui
+            .add_enabled(profile.is_some(), egui::Button::new("Use current"))
+            .clicked()
+        {
+            if let Some(profile) = profile {
+                draft.startup_profile.clone_from(profile);
+            }
+        }
+    });
+    ui.end_row();
+}
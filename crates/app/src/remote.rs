@@ -0,0 +1,175 @@
+//! Local WebSocket server for driving the app from notebooks or demo scripts.
+//!
+//! Each text message holds one JSON command, and is answered with `{"status": "ok"}` or
+//! `{"status": "error", "message": ...}`, except for `request_png`, which is answered with a
+//! binary message holding the image. For example:
+//!
+//! ```json
+//! {"set_profile": ["Polynomial", "Quadratic Family", "Base Curve"]}
+//! {"action": {"SetBounds": ["ActivePane", {"min_x": -2, "max_x": 1, "min_y": -1.5, "max_y": 1.5}]}}
+//! {"action": {"RandomizePalette": "BothPanes"}}
+//! {"request_png": "Parent"}
+//! ```
+//!
+//! Handshakes from web pages are refused, so that a site open in a browser cannot drive the app.
+use dynamo_gui::actions::Action;
+use dynamo_gui::pane::id::PaneID;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
+use tungstenite::handshake::server::{ErrorResponse, Request as Handshake, Response};
+use tungstenite::http::StatusCode;
+use tungstenite::Message;
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:9002";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Command
+{
+    /// Apply an action to the focused tab, as if by its keyboard shortcut.
+    Action(Box<Action>),
+    /// Show the fractal found by following these tile names through the sidebar.
+    SetProfile(Vec<String>),
+    /// Encode the image shown in a pane as a PNG.
+    RequestPng(PaneID),
+}
+
+#[derive(Debug)]
+pub enum Reply
+{
+    Ok,
+    Png(Vec<u8>),
+    Error(String),
+}
+
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum Status
+{
+    Ok,
+    Error
+    {
+        message: String,
+    },
+}
+
+/// A command received from a client, which is waiting for a reply.
+pub struct Request
+{
+    pub command: Command,
+    reply_to: Sender<Reply>,
+}
+
+impl Request
+{
+    pub fn reply(self, reply: Reply)
+    {
+        // The client may have disconnected in the meantime
+        let _ = self.reply_to.send(reply);
+    }
+}
+
+pub struct RemoteServer
+{
+    requests: Receiver<Request>,
+    address: SocketAddr,
+}
+
+impl RemoteServer
+{
+    /// Listen for clients on `address`. The `wake` callback is run whenever a command arrives,
+    /// so that the UI can process it without waiting for user input.
+    pub fn start<A, F>(address: A, wake: F) -> std::io::Result<Self>
+    where
+        A: ToSocketAddrs,
+        F: Fn() + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind(address)?;
+        let address = listener.local_addr()?;
+        let (sender, requests) = channel();
+        let wake = Arc::new(wake);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let sender = sender.clone();
+                let wake = Arc::clone(&wake);
+                std::thread::spawn(move || {
+                    if let Err(e) = serve(stream, &sender, &*wake) {
+                        eprintln!("Remote connection closed: {e}");
+                    }
+                });
+            }
+        });
+        Ok(Self { requests, address })
+    }
+
+    /// The address the server is listening on.
+    #[must_use]
+    pub const fn address(&self) -> SocketAddr
+    {
+        self.address
+    }
+
+    /// Take the next pending command, if any.
+    #[must_use]
+    pub fn poll(&self) -> Option<Request>
+    {
+        self.requests.try_recv().ok()
+    }
+}
+
+fn serve(
+    stream: TcpStream,
+    requests: &Sender<Request>,
+    wake: &dyn Fn(),
+) -> Result<(), Box<dyn Error>>
+{
+    let mut socket = tungstenite::accept_hdr(stream, reject_web_pages)?;
+    loop {
+        let text = match socket.read()? {
+            Message::Text(text) => text,
+            Message::Close(_) => return Ok(()),
+            _ => continue,
+        };
+
+        let reply = match serde_json::from_str(&text) {
+            Ok(command) => {
+                let (reply_to, reply) = channel();
+                requests.send(Request { command, reply_to })?;
+                wake();
+                reply.recv()?
+            }
+            Err(e) => Reply::Error(format!("Invalid command: {e}")),
+        };
+
+        let message = match reply {
+            Reply::Png(bytes) => Message::Binary(bytes),
+            Reply::Ok => Message::Text(serde_json::to_string(&Status::Ok)?),
+            Reply::Error(message) => {
+                Message::Text(serde_json::to_string(&Status::Error { message })?)
+            }
+        };
+        socket.send(message)?;
+    }
+}
+
+/// Refuse handshakes carrying the `Origin` of a web page. Browsers always send the origin of the
+/// page opening the socket, while clients in notebooks and scripts send none, or one naming the
+/// server itself.
+// The error type is the one tungstenite expects of handshake callbacks
+#[allow(clippy::result_large_err)]
+fn reject_web_pages(request: &Handshake, response: Response) -> Result<Response, ErrorResponse>
+{
+    let header = |name| request.headers().get(name).and_then(|value| value.to_str().ok());
+    let Some(origin) = header("origin") else {
+        return Ok(response);
+    };
+    if header("host").is_some_and(|host| origin == format!("http://{host}")) {
+        return Ok(response);
+    }
+    let mut error = ErrorResponse::new(Some(format!("Connections from {origin} are not allowed")));
+    *error.status_mut() = StatusCode::FORBIDDEN;
+    Err(error)
+}
@@ -0,0 +1,307 @@
+use dynamo_color::Transparency;
+use dynamo_common::prelude::images_dir;
+use dynamo_gui::interface::Interface;
+use dynamo_gui::pane::id::PaneID;
+use dynamo_gui::render_job::RenderJob;
+use egui::{Context, Ui};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
+use std::time::Duration;
+
+const MIN_WIDTH: usize = 16;
+const MAX_WIDTH: usize = 32768;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JobStatus
+{
+    Queued,
+    Rendering,
+    Done,
+    Failed(String),
+    Cancelled,
+}
+impl JobStatus
+{
+    const fn is_pending(&self) -> bool
+    {
+        matches!(self, Self::Queued | Self::Rendering)
+    }
+}
+impl std::fmt::Display for JobStatus
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Queued => write!(f, "Queued"),
+            Self::Rendering => write!(f, "Rendering..."),
+            Self::Done => write!(f, "Done"),
+            Self::Failed(e) => write!(f, "Failed: {e}"),
+            Self::Cancelled => write!(f, "Cancelled"),
+        }
+    }
+}
+
+struct QueuedJob
+{
+    id: usize,
+    cancel: Arc<AtomicBool>,
+    job: RenderJob,
+}
+
+struct QueueEntry
+{
+    id: usize,
+    description: String,
+    path: PathBuf,
+    status: JobStatus,
+    cancel: Arc<AtomicBool>,
+}
+
+struct JobForm
+{
+    pane_id: PaneID,
+    width: usize,
+    filename: String,
+    transparency: Transparency,
+}
+impl Default for JobForm
+{
+    fn default() -> Self
+    {
+        Self {
+            pane_id: PaneID::Parent,
+            width: 4096,
+            filename: "render_0.png".to_owned(),
+            transparency: Transparency::Opaque,
+        }
+    }
+}
+
+/// Images waiting to be rendered and saved in the background.
+///
+/// Jobs run one at a time on a dedicated worker thread, started when the first job is queued;
+/// each job already spreads its computation over the rayon pool, so running several at once would
+/// only compete for cores. The web build cannot spawn threads, so there the jobs are run on the UI
+/// thread instead, one per frame.
+#[derive(Default)]
+pub struct RenderQueue
+{
+    entries: Vec<QueueEntry>,
+    #[cfg(not(target_arch = "wasm32"))]
+    worker: Option<Worker>,
+    #[cfg(target_arch = "wasm32")]
+    pending: std::collections::VecDeque<QueuedJob>,
+    next_id: usize,
+    form: JobForm,
+    pub open: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct Worker
+{
+    jobs: Sender<QueuedJob>,
+    updates: Receiver<(usize, JobStatus)>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Worker
+{
+    fn spawn() -> Self
+    {
+        let (jobs, job_receiver) = channel::<QueuedJob>();
+        let (update_sender, updates) = channel();
+
+        std::thread::spawn(move || {
+            for QueuedJob { id, cancel, job } in job_receiver {
+                if cancel.load(Ordering::Relaxed) {
+                    continue;
+                }
+                if update_sender.send((id, JobStatus::Rendering)).is_err() {
+                    break;
+                }
+                if update_sender.send((id, run_job(job))).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self { jobs, updates }
+    }
+}
+
+fn run_job(job: RenderJob) -> JobStatus
+{
+    match job.run() {
+        Ok(_) => JobStatus::Done,
+        Err(e) => JobStatus::Failed(e.to_string()),
+    }
+}
+
+impl RenderQueue
+{
+    pub fn push(&mut self, job: RenderJob)
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let cancel = Arc::new(AtomicBool::new(false));
+        let description = job.description.clone();
+        let path = job.path.clone();
+        let queued = QueuedJob {
+            id,
+            cancel: Arc::clone(&cancel),
+            job,
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let status = match self.worker.get_or_insert_with(Worker::spawn).jobs.send(queued) {
+            Ok(()) => JobStatus::Queued,
+            Err(e) => JobStatus::Failed(e.to_string()),
+        };
+        #[cfg(target_arch = "wasm32")]
+        let status = {
+            self.pending.push_back(queued);
+            JobStatus::Queued
+        };
+
+        self.entries.push(QueueEntry {
+            id,
+            description,
+            path,
+            status,
+            cancel,
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn poll_updates(&mut self)
+    {
+        let Some(worker) = &self.worker else {
+            return;
+        };
+        let updates: Vec<_> = worker.updates.try_iter().collect();
+        for (id, status) in updates {
+            self.set_status(id, status);
+        }
+    }
+
+    /// Run the next job that has not been cancelled.
+    #[cfg(target_arch = "wasm32")]
+    fn poll_updates(&mut self)
+    {
+        while let Some(QueuedJob { id, cancel, job }) = self.pending.pop_front() {
+            if !cancel.load(Ordering::Relaxed) {
+                self.set_status(id, run_job(job));
+                break;
+            }
+        }
+    }
+
+    fn set_status(&mut self, id: usize, status: JobStatus)
+    {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.status = status;
+        }
+    }
+
+    fn has_pending(&self) -> bool
+    {
+        self.entries.iter().any(|entry| entry.status.is_pending())
+    }
+
+    fn clear_finished(&mut self)
+    {
+        self.entries.retain(|entry| entry.status.is_pending());
+    }
+
+    pub fn show(&mut self, ctx: &Context, interface: Option<&dyn Interface>)
+    {
+        self.poll_updates();
+        if self.has_pending() {
+            ctx.request_repaint_after(Duration::from_millis(250));
+        }
+
+        let mut open = self.open;
+        egui::Window::new("Render Queue")
+            .open(&mut open)
+            .default_width(480.)
+            .show(ctx, |ui| {
+                self.job_form(ui, interface);
+                ui.separator();
+                self.job_list(ui);
+            });
+        self.open = open;
+    }
+
+    fn job_form(&mut self, ui: &mut Ui, interface: Option<&dyn Interface>)
+    {
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut self.form.pane_id, PaneID::Parent, "Parent");
+            ui.radio_value(&mut self.form.pane_id, PaneID::Child, "Child");
+            ui.label("Width:");
+            ui.add(egui::DragValue::new(&mut self.form.width).range(MIN_WIDTH..=MAX_WIDTH));
+        });
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            ui.text_edit_singleline(&mut self.form.filename);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Background:");
+            for transparency in Transparency::ALL {
+                ui.radio_value(&mut self.form.transparency, transparency, transparency.name());
+            }
+        });
+
+        let can_add = interface.is_some() && !self.form.filename.is_empty();
+        if ui
+            .add_enabled(can_add, egui::Button::new("Add to queue"))
+            .clicked()
+        {
+            if let Some(interface) = interface {
+                let mut path = PathBuf::from(&self.form.filename);
+                if path.is_relative() {
+                    path = images_dir().unwrap_or_default().join(path);
+                }
+                let job = interface.render_job(
+                    self.form.pane_id,
+                    self.form.width,
+                    &path,
+                    self.form.transparency,
+                );
+                self.push(job);
+                self.form.filename = format!("render_{}.png", self.next_id);
+            }
+        }
+    }
+
+    fn job_list(&mut self, ui: &mut Ui)
+    {
+        if self.entries.is_empty() {
+            ui.label("No jobs queued.");
+            return;
+        }
+
+        egui::Grid::new("render_queue_jobs")
+            .striped(true)
+            .num_columns(4)
+            .show(ui, |ui| {
+                for entry in &mut self.entries {
+                    ui.label(&entry.description);
+                    ui.label(entry.path.to_string_lossy());
+                    ui.label(entry.status.to_string());
+                    if entry.status == JobStatus::Queued && ui.button("Cancel").clicked() {
+                        entry.cancel.store(true, Ordering::Relaxed);
+                        entry.status = JobStatus::Cancelled;
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if ui.button("Clear finished").clicked() {
+            self.clear_finished();
+        }
+    }
+}
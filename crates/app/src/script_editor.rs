@@ -1,4 +1,8 @@
 use dynamo_common::directories::script_dir;
+use dynamo_core::error::DynamoResult;
+use dynamo_gui::notifications::{FileKind, FileOperation, Notification, Recovery};
+use dynamo_gui::pane::id::PaneSelection;
+use egui_file::FileDialog;
 use script_loader::error::ScriptError;
 use script_loader::parser::UnparsedUserInput;
 use std::path::{Path, PathBuf};
@@ -16,6 +20,8 @@ pub enum Response
     DoNothing,
     Close,
     Load(PathBuf),
+    /// Report the outcome of saving the script.
+    Notify(Notification),
 }
 
 #[derive(Clone, Default, Debug)]
@@ -40,11 +46,15 @@ impl State
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct ScriptEditor
 {
     pub text: String,
     pub state: State,
+    /// Outcome of the last save, until it is reported.
+    notification: Option<Notification>,
+    /// Dialog choosing where to save the script, and whether to run it once saved.
+    save_dialog: Option<(FileDialog, bool)>,
 }
 impl Default for ScriptEditor
 {
@@ -53,6 +63,8 @@ impl Default for ScriptEditor
         Self {
             text: config::DEFAULT_TEXT.clone(),
             state: State::default(),
+            notification: None,
+            save_dialog: None,
         }
     }
 }
@@ -94,6 +106,18 @@ impl ScriptEditor
                     }
                 });
         }
+        if let Some((dialog, run)) = self.save_dialog.as_mut() {
+            dialog.show(ctx);
+            let run = *run;
+            if dialog.selected() {
+                if let Some(path) = dialog.path().map(Path::to_path_buf) {
+                    self.save_to(path, run);
+                }
+                self.save_dialog = None;
+            } else if !dialog.visible() {
+                self.save_dialog = None;
+            }
+        }
     }
 
     #[inline]
@@ -114,38 +138,80 @@ impl ScriptEditor
         self.state = State::Closed;
     }
 
+    /// Save the script to the scripts directory, under its short name.
     fn try_save(&mut self, run: bool)
     {
-        match self.save_script() {
-            Ok(script_path) => {
-                println!("Script saved to {}.", script_path.display());
-                if run {
-                    self.state = State::ReadyToRun(script_path);
-                } else {
-                    self.hide();
-                }
-            }
+        match self.default_path() {
+            Ok(path) => self.save_to(path, run),
             Err(e) => {
-                println!("Error saving script: {e:?}");
+                self.notification = Some(Notification::error("Could not save script", &e));
             }
         }
     }
 
-    fn save_script(&self) -> Result<PathBuf, ScriptError>
+    fn default_path(&self) -> DynamoResult<PathBuf>
     {
         let script_data: UnparsedUserInput =
             toml::from_str(&self.text).map_err(ScriptError::ErrorParsingToml)?;
         let filename = format!("{}.toml", script_data.metadata.short_name);
-        let save_path = script_dir()
+        Ok(script_dir()
             .unwrap_or(SCRIPT_PROJ_DIR.to_path_buf())
-            .join(filename);
+            .join(filename))
+    }
+
+    fn save_to(&mut self, path: PathBuf, run: bool)
+    {
+        match std::fs::write(&path, &self.text) {
+            Ok(()) => {
+                self.notification = Some(Notification::info(format!(
+                    "Script saved to {}",
+                    path.display()
+                )));
+                if run {
+                    self.state = State::ReadyToRun(path);
+                } else {
+                    self.hide();
+                }
+            }
+            Err(e) => {
+                let operation = FileOperation {
+                    selection: PaneSelection::default(),
+                    path,
+                    kind: FileKind::Script { run },
+                };
+                self.notification = Some(
+                    Notification::error("Could not save script", &e.into())
+                        .with_operation(operation),
+                );
+            }
+        }
+    }
 
-        std::fs::write(&save_path, self.text.clone()).map_err(ScriptError::ErrorWritingFile)?;
-        Ok(save_path)
+    /// Repeat a failed save, or choose another file for it.
+    pub fn recover(&mut self, operation: FileOperation, recovery: Recovery)
+    {
+        let FileKind::Script { run } = operation.kind else {
+            return;
+        };
+        match recovery {
+            Recovery::Retry => self.save_to(operation.path, run),
+            Recovery::ChooseAnotherFile => {
+                let mut dialog = FileDialog::save_file(operation.path.parent().map(Path::to_owned))
+                    .title("Save Script")
+                    .show_rename(false)
+                    .show_new_folder(true);
+                dialog.open();
+                let file_name = operation.path.file_name().unwrap_or_default().to_string_lossy();
+                self.save_dialog = Some((dialog.default_filename(file_name), run));
+            }
+        }
     }
 
     fn pop_response(&mut self) -> Response
     {
+        if let Some(notification) = self.notification.take() {
+            return Response::Notify(notification);
+        }
         match self.state.pop_if_ready() {
             Some(path) => Response::Load(path),
             None if self.enabled() => Response::DoNothing,
@@ -98,58 +98,3 @@ impl Popup
         }
     }
 }
-
-#[derive(Clone, Debug)]
-pub struct ErrorReport
-{
-    title: String,
-    text: String,
-    pub visible: bool,
-}
-
-impl ErrorReport
-{
-    #[must_use]
-    pub fn new(title: String, text: String) -> Self
-    {
-        Self {
-            title,
-            text,
-            visible: true,
-        }
-    }
-
-    pub fn show(&mut self, ctx: &egui::Context)
-    {
-        if self.visible {
-            egui::Window::new(self.title.clone())
-                .title_bar(false)
-                .collapsible(false)
-                .auto_sized()
-                .pivot(egui::Align2::CENTER_CENTER)
-                .default_pos(ctx.screen_rect().center())
-                .show(ctx, |ui| {
-                    ui.horizontal(|ui| {
-                        ui.label(self.text.clone());
-                    });
-
-                    if ui.button("OK").clicked() || ctx.input(|i| i.key_pressed(egui::Key::Escape))
-                    {
-                        self.disable();
-                    }
-                });
-        }
-    }
-
-    #[inline]
-    pub fn enable(&mut self)
-    {
-        self.visible = true;
-    }
-
-    #[inline]
-    pub fn disable(&mut self)
-    {
-        self.visible = false;
-    }
-}
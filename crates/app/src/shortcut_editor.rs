@@ -0,0 +1,197 @@
+use dynamo_core::error::DynamoError;
+use dynamo_gui::hotkeys::keymap::{format_shortcut, HotkeyGroup, Keymap};
+use egui::{Context, KeyboardShortcut, Modifiers, RichText, Ui};
+
+/// Window for rebinding keyboard shortcuts, which are saved to the user's keymap file.
+pub struct ShortcutEditor
+{
+    keymap: Keymap,
+    /// Hotkey waiting for the user to press its new shortcut.
+    capturing: Option<&'static str>,
+    /// Whether the keymap has changed since it was last installed in the egui context.
+    changed: bool,
+    status: Option<String>,
+    /// Why the keymap file could not be loaded, if it could not, until it is reported.
+    load_error: Option<DynamoError>,
+    pub open: bool,
+}
+
+impl Default for ShortcutEditor
+{
+    fn default() -> Self
+    {
+        let (keymap, load_error) = match Keymap::load() {
+            Ok(keymap) => (keymap, None),
+            Err(e) => (Keymap::default(), Some(e.into())),
+        };
+        Self {
+            keymap,
+            capturing: None,
+            changed: true,
+            status: None,
+            load_error,
+            open: false,
+        }
+    }
+}
+
+impl ShortcutEditor
+{
+    /// Take the error raised loading the keymap file, in which case the default keymap is used.
+    pub fn take_load_error(&mut self) -> Option<DynamoError>
+    {
+        self.load_error.take()
+    }
+
+    /// Show the window if it is open, and make sure interfaces use the current keymap. This
+    /// should run before the interfaces handle input, so that a shortcut pressed while rebinding
+    /// does not also trigger its action.
+    pub fn show(&mut self, ctx: &Context)
+    {
+        if !self.open {
+            self.capturing = None;
+        }
+        self.capture_shortcut(ctx);
+        if self.changed {
+            self.keymap.install(ctx);
+            self.changed = false;
+        }
+
+        let mut open = self.open;
+        egui::Window::new("Keyboard Shortcuts")
+            .open(&mut open)
+            .default_width(560.)
+            .show(ctx, |ui| {
+                if !self.keymap.conflicts().is_empty() {
+                    ui.colored_label(
+                        ui.visuals().error_fg_color,
+                        "Some shortcuts are bound to several hotkeys; only the first is used.",
+                    );
+                }
+                egui::ScrollArea::vertical()
+                    .max_height(480.)
+                    .show(ui, |ui| {
+                        for group in HotkeyGroup::ALL {
+                            self.group_grid(ui, group);
+                        }
+                    });
+                ui.separator();
+                self.controls(ui);
+            });
+        self.open = open;
+    }
+
+    fn group_grid(&mut self, ui: &mut Ui, group: HotkeyGroup)
+    {
+        egui::CollapsingHeader::new(group.name()).show(ui, |ui| {
+            egui::Grid::new(group.name())
+                .striped(true)
+                .num_columns(3)
+                .show(ui, |ui| {
+                    for hotkey in group.hotkeys() {
+                        let name = hotkey.name();
+                        let shortcut = self.keymap.shortcut(hotkey);
+                        let capturing = self.capturing == Some(name);
+
+                        ui.label(hotkey.get_action().description())
+                            .on_hover_text(name);
+
+                        let mut text = if capturing {
+                            RichText::new("Press a key...").italics()
+                        } else {
+                            RichText::new(shortcut.map(format_shortcut).unwrap_or_default())
+                        };
+                        let others: Vec<&str> = shortcut
+                            .map(|s| self.keymap.bound_to(s))
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter(|other| *other != name)
+                            .collect();
+                        if !others.is_empty() {
+                            text = text.color(ui.visuals().error_fg_color);
+                        }
+                        let mut response =
+                            ui.add(egui::Button::new(text).min_size([120., 0.].into()));
+                        if !others.is_empty() {
+                            response = response
+                                .on_hover_text(format!("Also bound to {}", others.join(", ")));
+                        }
+                        if response.clicked() {
+                            self.capturing = Some(name);
+                        }
+
+                        if capturing {
+                            if ui.button("Cancel").clicked() {
+                                self.capturing = None;
+                            }
+                        } else if ui
+                            .add_enabled(shortcut.is_some(), egui::Button::new("Clear"))
+                            .clicked()
+                        {
+                            self.rebind(name, None);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+
+    fn controls(&mut self, ui: &mut Ui)
+    {
+        ui.horizontal(|ui| {
+            if ui.button("Save").clicked() {
+                self.status = Some(match self.keymap.save() {
+                    Ok(()) => "Saved.".to_owned(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            if ui.button("Reset to defaults").clicked() {
+                self.keymap = Keymap::default();
+                self.capturing = None;
+                self.changed = true;
+                self.status = None;
+            }
+        });
+        if let Some(status) = &self.status {
+            ui.label(status);
+        }
+    }
+
+    /// Bind the hotkey being rebound to the first key pressed this frame, removing the key press
+    /// so that nothing else responds to it.
+    fn capture_shortcut(&mut self, ctx: &Context)
+    {
+        let Some(name) = self.capturing else {
+            return;
+        };
+        let shortcut = ctx.input_mut(|input| {
+            let idx = input
+                .events
+                .iter()
+                .position(|event| matches!(event, egui::Event::Key { pressed: true, .. }))?;
+            let egui::Event::Key { key, modifiers, .. } = input.events.remove(idx) else {
+                return None;
+            };
+            // `command` duplicates either `ctrl` or `mac_cmd`, depending on the platform
+            let modifiers = Modifiers {
+                command: false,
+                ..modifiers
+            };
+            Some(KeyboardShortcut::new(modifiers, key))
+        });
+        if let Some(shortcut) = shortcut {
+            self.rebind(name, Some(shortcut));
+            self.capturing = None;
+        }
+    }
+
+    fn rebind(&mut self, name: &str, shortcut: Option<KeyboardShortcut>)
+    {
+        self.status = self
+            .keymap
+            .bind(name, shortcut)
+            .err()
+            .map(|e| e.to_string());
+        self.changed = true;
+    }
+}
@@ -3,7 +3,8 @@ use egui::{Color32, Ui};
 
 pub enum Action
 {
-    ChangeFractal(Box<dyn Interface>),
+    /// Show a new fractal, found by following the given tile names from the top menu.
+    ChangeFractal(Box<dyn Interface>, Vec<String>),
 }
 
 #[derive(Default)]
@@ -112,6 +113,40 @@ impl State
         self.add_tile(name, item);
     }
 
+    /// Look up a fractal by the names of the tiles leading to it, e.g.
+    /// `["Polynomial", "Quadratic Family", "Base Curve"]`.
+    #[must_use]
+    pub fn find_fractal<S: AsRef<str>>(&self, path: &[S]) -> Option<fn() -> Box<dyn Interface>>
+    {
+        let (name, rest) = path.split_first()?;
+        let tile = self.tiles.iter().find(|tile| tile.name == name.as_ref())?;
+        match &tile.item {
+            Item::ChangeFractal(create_interface) if rest.is_empty() => Some(*create_interface),
+            Item::Submenu(make_menu) => make_menu().find_fractal(rest),
+            _ => None,
+        }
+    }
+
+    /// Paths to all fractals reachable from this menu, in the form accepted by `find_fractal`.
+    #[must_use]
+    pub fn fractal_paths(&self) -> Vec<Vec<String>>
+    {
+        let mut paths = Vec::new();
+        for tile in &self.tiles {
+            match &tile.item {
+                Item::ChangeFractal(_) => paths.push(vec![tile.name.clone()]),
+                Item::Submenu(make_menu) => {
+                    for mut path in make_menu().fractal_paths() {
+                        path.insert(0, tile.name.clone());
+                        paths.push(path);
+                    }
+                }
+                Item::GoToParent => {}
+            }
+        }
+        paths
+    }
+
     fn with_tile(mut self, name: &str, item: Item) -> Self
     {
         let tile = Tile {
@@ -138,7 +173,7 @@ enum NavAction
     #[default]
     DoNothing,
     Ascend,
-    Descend(State),
+    Descend(State, String),
 }
 
 #[derive(Default)]
@@ -146,6 +181,8 @@ pub struct Menu
 {
     pub state: State,
     above: Vec<State>,
+    /// Names of the submenus leading to the current state.
+    path: Vec<String>,
 }
 
 impl Menu
@@ -156,6 +193,7 @@ impl Menu
         Self {
             state,
             above: Vec::new(),
+            path: Vec::new(),
         }
     }
 
@@ -171,9 +209,13 @@ impl Menu
                         nav_action = NavAction::Ascend;
                         break;
                     }
-                    Item::ChangeFractal(cons) => return Some(Action::ChangeFractal(cons())),
+                    Item::ChangeFractal(cons) => {
+                        let mut path = self.path.clone();
+                        path.push(tile.name.clone());
+                        return Some(Action::ChangeFractal(cons(), path));
+                    }
                     Item::Submenu(create_menu) => {
-                        nav_action = NavAction::Descend(create_menu());
+                        nav_action = NavAction::Descend(create_menu(), tile.name.clone());
                         break;
                     }
                 }
@@ -182,10 +224,12 @@ impl Menu
         match nav_action {
             NavAction::Ascend => {
                 self.state = self.above.pop()?;
+                self.path.pop();
             }
-            NavAction::Descend(state) => {
+            NavAction::Descend(state, name) => {
                 let old_state = std::mem::replace(&mut self.state, state);
                 self.above.push(old_state);
+                self.path.push(name);
             }
             NavAction::DoNothing => {}
         }
@@ -5,18 +5,31 @@ use dynamo_core::prelude::*;
 use dynamo_gui::interface::{Interface, MainInterface, PanePair};
 use dynamo_profiles::{
     BiquadraticMult, BiquadraticMultParam, BiquadraticMultSection, BurningShip, Chebyshev,
-    CoshNewton, Cosine, CosineAdd, CubicMarked2Cycle, CubicPer1Lambda, CubicPer1LambdaModuli,
-    CubicPer1LambdaParam, CubicPer1_0, CubicPer1_1, CubicPer2CritMarked, CubicPer2Lambda,
-    CubicPer2LambdaParam, CubicPer3_0, EisensteinMandel, Exponential, GaussianMandel, Gudermannian,
-    Mandelbrot, McMullenFamily, MinsikHanPhi, NewtonCubic, OddCubic, QuadRatPer1Lambda,
-    QuadRatPer1LambdaParam, QuadRatPer1_1, QuadRatPer2, QuadRatPer2InfPuncture, QuadRatPer2Lambda,
-    QuadRatPer2LambdaParam, QuadRatPer3, QuadRatPer4, QuadRatPer5, QuadRatPreper21,
-    QuadRatPreper22, QuadRatSymmetryLocus, RealCubicImagCrit, RealCubicRealCrit, RiemannXi,
-    RiemannXiNewton, Rulkov, Sailboat, SineWander, Tricorne, Unicorn, Unicritical,
+    ChebyshevDyn, CoshNewton, Cosine, CosineAdd, CubicMarked2Cycle, CubicPer1Lambda,
+    CubicPer1LambdaModuli, CubicPer1LambdaParam, CubicPer1_0, CubicPer1_1, CubicPer2CritMarked,
+    CubicPer2Lambda, CubicPer2LambdaParam, CubicPer3_0, EisensteinMandel, Exponential,
+    GaussianMandel, Gudermannian, Henon, HermanRing, Ikeda, Mandelbrot, McMullenFamilyDyn,
+    MinsikHanPhi, NewtonCubic, OddCubic, QuadRatPer1Lambda, QuadRatPer1LambdaParam, QuadRatPer1_1,
+    QuadRatPer2, QuadRatPer2InfPuncture, QuadRatPer2Lambda, QuadRatPer2LambdaParam, QuadRatPer3,
+    QuadRatPer4, QuadRatPer5, QuadRatPer6, QuadRatPer7, QuadRatPreper21, QuadRatPreper22,
+    QuadRatSymmetryLocus, QuadraticSkewProduct, RealCubicImagCrit, RealCubicRealCrit, RiemannXi,
+    RiemannXiNewton, Rulkov, Sailboat, SineWander, StandardMap, Tricorne, Unicorn, Unicritical,
+    UnicriticalDyn,
 };
 use menu::{Menu, State};
 use seq_macro::seq;
 
+/// Tile names leading to the fractal shown in new tabs.
+pub const DEFAULT_PROFILE: [&str; 3] = ["Polynomial", "Quadratic Family", "Base Curve"];
+
+/// Interface for the fractal at `DEFAULT_PROFILE`, built directly so that it is available
+/// however the menu is arranged.
+#[must_use]
+pub fn default_interface() -> Box<dyn Interface>
+{
+    create_interface(Mandelbrot::default, JuliaSet::from)
+}
+
 pub fn create_menu() -> Menu
 {
     let state = State::default()
@@ -218,34 +231,55 @@ fn polynomials_menu() -> State
                 })
         })
         .with_submenu("Unicritical Maps\nz -> c*(1+z/d)^d", || {
-            let mut submenu = State::submenu();
-            submenu.add_submenu("Degree 3", || {
-                State::submenu()
-                    .with_fractal_button("Base curve", interface!(Unicritical<3>))
-                    .with_submenu("Marked Cycle", || {
-                        State::submenu()
-                            .with_fractal_button("Period 1", interface_mc!(Unicritical<3>, 1))
-                            .with_fractal_button("Period 2", interface_mc!(Unicritical<3>, 2))
-                            .with_fractal_button("Period 3", interface_mc!(Unicritical<3>, 3))
-                    })
-                    .with_submenu("Marked Periodic Point", || {
-                        State::submenu()
-                            .with_fractal_button("Period 1", interface_mc!(Unicritical<3>, 1))
-                            .with_fractal_button("Period 2", interface_dyn!(Unicritical<3>, 2))
-                    })
-            });
-            seq!(D in 4..=8 {
-                submenu.add_fractal_button(&format!("Degree {}", D), interface!(Unicritical<D>));
-            });
-            submenu
+            State::submenu()
+                .with_fractal_button("Adjustable degree", interface!(UnicriticalDyn))
+                .with_submenu("Degree 3", || {
+                    State::submenu()
+                        .with_fractal_button("Base curve", interface!(Unicritical<3>))
+                        .with_submenu("Marked Cycle", || {
+                            State::submenu()
+                                .with_fractal_button("Period 1", interface_mc!(Unicritical<3>, 1))
+                                .with_fractal_button("Period 2", interface_mc!(Unicritical<3>, 2))
+                                .with_fractal_button("Period 3", interface_mc!(Unicritical<3>, 3))
+                        })
+                        .with_submenu("Marked Periodic Point", || {
+                            State::submenu()
+                                .with_fractal_button("Period 1", interface_mc!(Unicritical<3>, 1))
+                                .with_fractal_button("Period 2", interface_dyn!(Unicritical<3>, 2))
+                        })
+                })
         })
-        .with_submenu("Chebyshev family\nz -> (-1)^k * c * T_2k(z/2)", || {
-            let mut submenu = State::submenu();
-            seq!(D in 1..=5 {
-                submenu.add_fractal_button(&format!("Degree {}", 2*D), interface!(Chebyshev<D>));
-            });
-            submenu
+        .with_fractal_button(
+            "Chebyshev family\nz -> (-1)^k * c * T_2k(z/2)",
+            interface!(ChebyshevDyn),
+        )
+        .with_fractal_button("Chebyshev-perturbed quadratic\nz -> (c(1-z^2/2))^2 + c", || {
+            create_interface(
+                || {
+                    ComposedFamily::new(Mandelbrot::default(), Chebyshev::<1>::default())
+                        .with_orig_bounds(Bounds {
+                            min_x: -2.25,
+                            max_x: 1.25,
+                            min_y: -1.5,
+                            max_y: 1.5,
+                        })
+                },
+                JuliaSet::from,
+            )
+        })
+        .with_fractal_button("Perturbed quadratic\nz -> z^2 + c + ε·conj(z)", || {
+            create_interface(
+                || {
+                    let perturbation = Perturbation::antiholomorphic(Cplx::new(0.05, 0.));
+                    Perturbed::new(Mandelbrot::default(), perturbation)
+                },
+                JuliaSet::from,
+            )
         })
+        .with_fractal_button(
+            "Quadratic skew product\n(z, w) -> (z^2 + c, w^2 + az)",
+            interface!(SkewBase<QuadraticSkewProduct>, SkewFiber),
+        )
         .with_submenu("Biquadratic Maps", || {
             State::submenu()
                 .with_fractal_button("λ-plane", interface!(BiquadraticMultParam, BiquadraticMult))
@@ -309,6 +343,8 @@ fn rational_maps_menu() -> State
             })
     })
     .with_fractal_button("QuadRat Per(5)", interface!(QuadRatPer5))
+    .with_fractal_button("QuadRat Per(6)", interface!(QuadRatPer6))
+    .with_fractal_button("QuadRat Per(7)", interface!(QuadRatPer7))
         .with_submenu("QuadRat Preper(2, 1)", || {
             State::submenu()
                 .with_fractal_button("Base Curve", interface!(QuadRatPreper21))
@@ -326,20 +362,10 @@ fn rational_maps_menu() -> State
                     interface!(QuadRatPer1LambdaParam, QuadRatPer1Lambda),
                     )
                 .with_fractal_button("λ=1", interface!(QuadRatPer1_1))
-                .with_fractal_button("λ=-1", interface!(QuadRatPer1Lambda, with_param, -ONE))
-                .with_fractal_button("λ=ω", interface!(QuadRatPer1Lambda, with_param, OMEGA))
                 .with_fractal_button(
-                    "λ=i",
-                    interface!(QuadRatPer1Lambda, with_param, Cplx::new(0., 1.)),
+                    "Adjustable λ",
+                    interface!(QuadRatPer1Lambda, with_param, -ONE),
                     )
-                .with_fractal_button(
-                    "λ=exp(φτi)",
-                    interface!(
-                        QuadRatPer1Lambda,
-                        with_param,
-                        Cplx::new(-0.737_368_878_078_320, 0.675_490_294_261_524)
-                        ),
-                        )
         })
     .with_submenu("QuadRat Per(2, λ)", || {
         State::submenu()
@@ -347,38 +373,14 @@ fn rational_maps_menu() -> State
                 "λ-plane",
                 interface!(QuadRatPer2LambdaParam, QuadRatPer2Lambda),
                 )
-            .with_fractal_button("λ=1", interface!(QuadRatPer2Lambda, with_param, ONE))
-            .with_fractal_button(
-                "λ=i",
-                interface!(QuadRatPer2Lambda, with_param, Cplx::new(0., 1.)),
-                )
-            .with_fractal_button(
-                "λ=-3",
-                interface!(QuadRatPer2Lambda, with_param, Cplx::from(-3.)),
-                )
-            .with_fractal_button(
-                "λ=-27",
-                interface!(QuadRatPer2Lambda, with_param, Cplx::from(-27.)),
-                )
+            .with_fractal_button("Adjustable λ", interface!(QuadRatPer2Lambda, with_param, ONE))
     })
     .with_fractal_button("QuadRat Symmetry Locus", interface!(QuadRatSymmetryLocus))
         .with_fractal_button("Newton Cubic", interface!(NewtonCubic))
-        .with_submenu("McMullen Family\nz -> z^m + 1/(c*z^n)", || {
-            let mut submenu = State::submenu();
-            seq!(N in 2..=8 {
-                submenu.add_fractal_button(
-                    &format!("m=2, n={}", N),
-                    interface!(McMullenFamily<2, N>),
-                    );
-            });
-            seq!(M in 2..=8 {
-                submenu.add_fractal_button(
-                    &format!("m={m}, n={m}", m=M),
-                    interface!(McMullenFamily<M, M>),
-                    );
-            });
-            submenu
-        })
+        .with_fractal_button(
+            "McMullen Family\nz -> z^m + 1/(c*z^n)",
+            interface!(McMullenFamilyDyn),
+        )
     .with_submenu("Minsik Han Φ\nz -> az/(z^d+d-1)", || {
         let mut submenu = State::submenu();
         seq!(D in 2..=8 {
@@ -386,6 +388,7 @@ fn rational_maps_menu() -> State
         });
         submenu
     })
+    .with_fractal_button("Herman Ring\nz -> uz^2(z-a)/(1-az)", interface!(HermanRing))
 }
 
 fn transcendental_menu() -> State
@@ -397,10 +400,7 @@ fn transcendental_menu() -> State
         .with_fractal_button("z -> sin(z) + z + τc", interface!(SineWander))
         .with_fractal_button("Cosh Newton", interface!(CoshNewton, CoshNewton))
         .with_fractal_button("z -> λarctan(sinh(z))", interface!(Gudermannian))
-        .with_fractal_button(
-            "Riemann Xi Newton [SLOW!]",
-            interface!(RiemannXi, RiemannXiNewton),
-        )
+        .with_fractal_button("Riemann Xi Newton", interface!(RiemannXi, RiemannXiNewton))
 }
 
 fn non_analytic_menu() -> State
@@ -429,31 +429,18 @@ fn non_analytic_menu() -> State
         })
         .with_fractal_button("Sailboat Param", interface!(BurningShip<2>, Sailboat))
         .with_fractal_button("Rulkov Map", interface!(Rulkov))
+        .with_fractal_button("Hénon Map", interface!(Henon))
+        .with_fractal_button("Ikeda Map", interface!(Ikeda))
+        .with_fractal_button("Standard Map", interface!(StandardMap))
 }
 
 fn arithmetic_menu() -> State
 {
     State::submenu()
-        .with_submenu("Gaussian Int Mandel", || {
-            State::submenu()
-                .with_fractal_button("Mod 2+ω", interface!(GaussianMandel<2, 1>))
-                .with_fractal_button("Mod 5+2ω", interface!(GaussianMandel<5, 2>))
-                .with_fractal_button("Mod 7", interface!(GaussianMandel<7, 0>))
-                .with_fractal_button("Mod 11", interface!(GaussianMandel<11, 0>))
-                .with_fractal_button("Mod 19", interface!(GaussianMandel<19, 0>))
-                .with_fractal_button("Mod 107", interface!(GaussianMandel<107, 0>))
-                .with_fractal_button("Mod 311", interface!(GaussianMandel<311, 0>))
-        })
-        .with_submenu("Eisenstein Int Mandel", || {
-            State::submenu()
-                .with_fractal_button("Mod 2+ω", interface!(EisensteinMandel<2, 1>))
-                .with_fractal_button("Mod 5", interface!(EisensteinMandel<5, 0>))
-                .with_fractal_button("Mod 5+2ω", interface!(EisensteinMandel<5, 2>))
-                .with_fractal_button("Mod 11", interface!(EisensteinMandel<11, 0>))
-                .with_fractal_button("Mod 17", interface!(EisensteinMandel<17, 0>))
-                .with_fractal_button("Mod 107", interface!(EisensteinMandel<107, 0>))
-                .with_fractal_button("Mod 311", interface!(EisensteinMandel<311, 0>))
-        })
+        .with_fractal_button("Gaussian Int Mandel", interface!(GaussianMandel<2>))
+        .with_fractal_button("Eisenstein Int Mandel", interface!(EisensteinMandel<2>))
+        .with_fractal_button("Gaussian Int Cubic", interface!(GaussianMandel<3>))
+        .with_fractal_button("Eisenstein Int Cubic", interface!(EisensteinMandel<3>))
 }
 
 fn create_interface<P, J>(create_parent: fn() -> P, create_child: fn(P) -> J) -> Box<dyn Interface>
@@ -461,12 +448,12 @@ where
     P: Displayable + HasChild<J> + Clone + 'static,
     J: Displayable + Clone + 'static,
 {
-    let max_iters = 1024;
-
-    let parent_plane = create_parent().with_max_iter(max_iters).with_res_y(768);
+    let parent_plane = create_parent()
+        .with_max_iter(MAX_ITER)
+        .with_res_y(IMAGE_HEIGHT);
     let child_plane = create_child(parent_plane.clone());
 
-    let mut interface = MainInterface::new(parent_plane, child_plane, 768);
+    let mut interface = MainInterface::new(parent_plane, child_plane, IMAGE_HEIGHT);
     interface.update_panes();
     Box::new(interface)
 }
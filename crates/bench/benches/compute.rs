@@ -0,0 +1,25 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dynamo_bench::workloads;
+use dynamo_core::prelude::*;
+
+fn bench_compute<P: Computable>(c: &mut Criterion, name: &str, plane: &P)
+{
+    c.bench_function(&format!("compute/{name}"), |b| {
+        b.iter(|| black_box(plane.compute()));
+    });
+}
+
+fn compute(c: &mut Criterion)
+{
+    bench_compute(c, "mandelbrot", &workloads::mandelbrot());
+    bench_compute(c, "biquadratic", &workloads::biquadratic());
+    bench_compute(c, "quad_rat_per_2", &workloads::quad_rat_per_2());
+    bench_compute(c, "mandelbrot_julia", &workloads::mandelbrot_julia());
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = compute
+}
+criterion_main!(benches);
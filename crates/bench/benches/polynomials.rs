@@ -0,0 +1,18 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use dynamo_bench::workloads;
+use dynamo_common::math_utils::polynomial_roots::solve_polynomial;
+
+fn polynomials(c: &mut Criterion)
+{
+    let mut group = c.benchmark_group("solve_polynomial");
+    for degree in workloads::POLYNOMIAL_DEGREES {
+        let coeffs = workloads::polynomial(degree);
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &coeffs, |b, coeffs| {
+            b.iter(|| black_box(solve_polynomial(coeffs.clone())));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, polynomials);
+criterion_main!(benches);
@@ -0,0 +1,20 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dynamo_bench::workloads;
+use dynamo_core::prelude::*;
+
+fn rays(c: &mut Criterion)
+{
+    let plane = workloads::mandelbrot();
+    let julia = workloads::mandelbrot_julia();
+    for angle in workloads::ray_angles() {
+        c.bench_function(&format!("rays/parameter/{angle}"), |b| {
+            b.iter(|| black_box(plane.external_ray(angle)));
+        });
+        c.bench_function(&format!("rays/dynamical/{angle}"), |b| {
+            b.iter(|| black_box(julia.external_ray(angle)));
+        });
+    }
+}
+
+criterion_group!(benches, rays);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dynamo_bench::workloads;
+use dynamo_color::prelude::*;
+
+fn rendering(c: &mut Criterion)
+{
+    let setup = workloads::render_setup();
+    let mut image = setup.iter_plane.render(&setup.coloring);
+
+    c.bench_function("render/render", |b| {
+        b.iter(|| black_box(setup.iter_plane.render(&setup.coloring)));
+    });
+    c.bench_function("render/render_into", |b| {
+        b.iter(|| {
+            setup
+                .iter_plane
+                .render_into(&mut image, &setup.coloring, &setup.layers);
+        });
+    });
+    c.bench_function("render/write_image", |b| {
+        b.iter(|| black_box(setup.iter_plane.write_image(&setup.coloring, &setup.layers)));
+    });
+}
+
+criterion_group!(benches, rendering);
+criterion_main!(benches);
@@ -0,0 +1,204 @@
+//! Timings saved as JSON, keyed by benchmark id, and comparison against a saved baseline.
+//!
+//! Criterion writes its estimates to `target/criterion/<id>/new/estimates.json`; the mean of
+//! each is collected into a single flat map so that baselines are easy to diff and review.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+/// Relative slowdown above which a benchmark counts as a regression.
+pub const DEFAULT_TOLERANCE: f64 = 0.1;
+
+#[derive(Debug)]
+pub enum BaselineError
+{
+    Io(io::Error),
+    Json(serde_json::Error),
+    Format(String),
+}
+
+impl fmt::Display for BaselineError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::Json(e) => write!(f, "invalid JSON: {e}"),
+            Self::Format(msg) => write!(f, "unexpected format: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+impl From<io::Error> for BaselineError
+{
+    fn from(e: io::Error) -> Self
+    {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for BaselineError
+{
+    fn from(e: serde_json::Error) -> Self
+    {
+        Self::Json(e)
+    }
+}
+
+/// Mean time in nanoseconds of each benchmark.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Baselines
+{
+    pub timings: BTreeMap<String, f64>,
+}
+
+impl Baselines
+{
+    pub fn from_json(json: &str) -> Result<Self, BaselineError>
+    {
+        let Value::Object(map) = serde_json::from_str(json)? else {
+            return Err(BaselineError::Format("expected an object".to_owned()));
+        };
+        let timings = map
+            .into_iter()
+            .map(|(name, value)| {
+                value
+                    .as_f64()
+                    .map(|time| (name.clone(), time))
+                    .ok_or_else(|| BaselineError::Format(format!("no timing for {name}")))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { timings })
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> String
+    {
+        let map: Map<String, Value> = self
+            .timings
+            .iter()
+            .map(|(name, &time)| (name.clone(), Value::from(time)))
+            .collect();
+        serde_json::to_string_pretty(&Value::Object(map)).unwrap_or_default()
+    }
+
+    pub fn load(path: &Path) -> Result<Self, BaselineError>
+    {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError>
+    {
+        fs::write(path, self.to_json() + "\n")?;
+        Ok(())
+    }
+
+    /// Collect the latest estimates from a criterion output directory, typically
+    /// `target/criterion`.
+    pub fn from_criterion(dir: &Path) -> Result<Self, BaselineError>
+    {
+        let mut baselines = Self::default();
+        baselines.collect_estimates(dir, dir)?;
+        Ok(baselines)
+    }
+
+    fn collect_estimates(&mut self, root: &Path, dir: &Path) -> Result<(), BaselineError>
+    {
+        let estimates = dir.join("new").join("estimates.json");
+        if estimates.is_file() {
+            let json: Value = serde_json::from_str(&fs::read_to_string(&estimates)?)?;
+            let time = json["mean"]["point_estimate"].as_f64().ok_or_else(|| {
+                BaselineError::Format(format!("no mean in {}", estimates.display()))
+            })?;
+            let name = dir
+                .strip_prefix(root)
+                .unwrap_or(dir)
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            self.timings.insert(name, time);
+            return Ok(());
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            // Criterion keeps its summary reports alongside the benchmarks
+            if path.is_dir() && !path.ends_with("report") {
+                self.collect_estimates(root, &path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Compare against the benchmarks shared with `baseline`, in order of name.
+    #[must_use]
+    pub fn compare(&self, baseline: &Self) -> Vec<Comparison>
+    {
+        self.timings
+            .iter()
+            .filter_map(|(name, &current)| {
+                baseline.timings.get(name).map(|&baseline| Comparison {
+                    name: name.clone(),
+                    baseline,
+                    current,
+                })
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn regressions(&self, baseline: &Self, tolerance: f64) -> Vec<Comparison>
+    {
+        self.compare(baseline)
+            .into_iter()
+            .filter(|comparison| comparison.is_regression(tolerance))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison
+{
+    pub name: String,
+    pub baseline: f64,
+    pub current: f64,
+}
+
+impl Comparison
+{
+    /// Relative change in time; positive values are slowdowns.
+    #[must_use]
+    pub fn change(&self) -> f64
+    {
+        self.current / self.baseline - 1.
+    }
+
+    #[must_use]
+    pub fn is_regression(&self, tolerance: f64) -> bool
+    {
+        self.change() > tolerance
+    }
+}
+
+impl fmt::Display for Comparison
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(
+            f,
+            "{}: {:.3} ms -> {:.3} ms ({:+.1}%)",
+            self.name,
+            self.baseline * 1e-6,
+            self.current * 1e-6,
+            100. * self.change()
+        )
+    }
+}
@@ -0,0 +1,90 @@
+//! Save or check benchmark baselines after running `cargo bench -p dynamo_bench`.
+//!
+//! Usage: `bench_baselines (save|check) [baseline.json] [criterion dir] [tolerance]`
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use dynamo_bench::baselines::{Baselines, DEFAULT_TOLERANCE};
+
+const USAGE: &str =
+    "usage: bench_baselines (save|check) [baseline.json] [criterion dir] [tolerance]";
+
+fn main() -> ExitCode
+{
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(command) = args.first() else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+    let baseline_path = PathBuf::from(args.get(1).map_or("crates/bench/baselines.json", |s| s));
+    let criterion_dir = args.get(2).map_or_else(
+        || {
+            std::env::var("CARGO_TARGET_DIR")
+                .map_or_else(|_| PathBuf::from("target"), PathBuf::from)
+                .join("criterion")
+        },
+        PathBuf::from,
+    );
+    let tolerance = match args.get(3).map(|s| s.parse::<f64>()) {
+        Some(Ok(tolerance)) => tolerance,
+        Some(Err(e)) => {
+            eprintln!("Invalid tolerance: {e}");
+            return ExitCode::FAILURE;
+        }
+        None => DEFAULT_TOLERANCE,
+    };
+
+    let current = match Baselines::from_criterion(&criterion_dir) {
+        Ok(current) => current,
+        Err(e) => {
+            eprintln!("Could not read estimates from {}: {e}", criterion_dir.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match command.as_str() {
+        "save" => {
+            if let Err(e) = current.save(&baseline_path) {
+                eprintln!("Could not save {}: {e}", baseline_path.display());
+                return ExitCode::FAILURE;
+            }
+            println!(
+                "Saved {} timings to {}",
+                current.timings.len(),
+                baseline_path.display()
+            );
+            ExitCode::SUCCESS
+        }
+        "check" => {
+            let baseline = match Baselines::load(&baseline_path) {
+                Ok(baseline) => baseline,
+                Err(e) => {
+                    eprintln!("Could not load {}: {e}", baseline_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+            for comparison in current.compare(&baseline) {
+                println!("{comparison}");
+            }
+            let regressions = current.regressions(&baseline, tolerance);
+            if regressions.is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                eprintln!(
+                    "{} benchmark(s) slowed down by more than {:.0}%:",
+                    regressions.len(),
+                    100. * tolerance
+                );
+                for comparison in regressions {
+                    eprintln!("  {comparison}");
+                }
+                ExitCode::FAILURE
+            }
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Benchmarking entry points for the dynamo crates.
+//!
+//! The [`workloads`] module builds the standard inputs shared by the criterion benchmarks under
+//! `benches/`, so that every performance-oriented change is measured against the same planes,
+//! rays, polynomials and images. The [`baselines`] module records the resulting timings as JSON
+//! and flags regressions against a saved baseline.
+
+pub mod baselines;
+pub mod workloads;
+
+#[cfg(test)]
+mod tests
+{
+    use crate::baselines::*;
+    use crate::workloads;
+    use dynamo_core::prelude::*;
+
+    #[test]
+    fn compare_baselines()
+    {
+        let baseline = Baselines::from_json(r#"{"compute/mandelbrot": 100.0, "rays/1/3": 10.0}"#)
+            .unwrap();
+        let current = Baselines::from_json(
+            r#"{"compute/mandelbrot": 104.0, "rays/1/3": 12.5, "render/render": 7.0}"#,
+        )
+        .unwrap();
+
+        let comparisons = current.compare(&baseline);
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].name, "compute/mandelbrot");
+        assert!((comparisons[0].change() - 0.04).abs() < 1e-12);
+        assert!(!comparisons[0].is_regression(DEFAULT_TOLERANCE));
+        assert!(comparisons[1].is_regression(DEFAULT_TOLERANCE));
+
+        let regressions = current.regressions(&baseline, DEFAULT_TOLERANCE);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].name, "rays/1/3");
+
+        let round_trip = Baselines::from_json(&current.to_json()).unwrap();
+        assert_eq!(round_trip, current);
+    }
+
+    #[test]
+    fn workloads_are_consistent()
+    {
+        let plane = workloads::mandelbrot().with_res_y(16);
+        let iter_plane = plane.compute();
+        assert_eq!(iter_plane.iter_counts.dim(), (16, 16));
+
+        for degree in workloads::POLYNOMIAL_DEGREES {
+            assert_eq!(workloads::polynomial(degree).len(), degree + 1);
+        }
+    }
+}
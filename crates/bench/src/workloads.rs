@@ -0,0 +1,85 @@
+//! Standard inputs for the benchmarks, kept in one place so that timings stay comparable across
+//! changes to the compute, ray tracing and rendering paths.
+
+use dynamo_color::layers::LayerStack;
+use dynamo_color::Coloring;
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use dynamo_profiles::*;
+
+/// Vertical resolution of the benchmarked planes.
+pub const RES_Y: usize = 512;
+
+/// Iteration limit of the benchmarked planes.
+pub const MAX_ITER: IterCount = 1024;
+
+/// Degrees at which `solve_polynomial` is benchmarked.
+pub const POLYNOMIAL_DEGREES: [usize; 4] = [4, 16, 64, 256];
+
+/// Angles of the benchmarked external rays, as (numerator, denominator).
+pub const RAY_ANGLES: [(AngleNum, AngleNum); 3] = [(1, 3), (1, 7), (3, 31)];
+
+pub fn mandelbrot() -> Mandelbrot
+{
+    Mandelbrot::default()
+        .with_res_y(RES_Y)
+        .with_max_iter(MAX_ITER)
+}
+
+pub fn biquadratic() -> Biquadratic
+{
+    Biquadratic::default()
+        .with_res_y(RES_Y)
+        .with_max_iter(MAX_ITER)
+        .with_param((-0.3).into())
+}
+
+pub fn quad_rat_per_2() -> QuadRatPer2
+{
+    QuadRatPer2::default()
+        .with_res_y(RES_Y)
+        .with_max_iter(MAX_ITER)
+}
+
+/// Julia set of a parameter near the main cardioid's boundary, whose slow escape is a worst
+/// case for the per-pixel orbits.
+pub fn mandelbrot_julia() -> JuliaSet<Mandelbrot>
+{
+    JuliaSet::from(mandelbrot()).with_param(Cplx::new(-0.7269, 0.1889))
+}
+
+pub fn ray_angles() -> impl Iterator<Item = RationalAngle>
+{
+    RAY_ANGLES.into_iter().map(|(n, d)| RationalAngle::new(n, d))
+}
+
+/// Coefficients of a polynomial of the given degree, generated deterministically so that
+/// successive runs solve the same problem.
+#[must_use]
+pub fn polynomial(degree: usize) -> Vec<Cplx>
+{
+    (0..=degree)
+        .map(|k| {
+            let t = k as Real;
+            Cplx::new((0.7 * t).sin(), (1.3 * t + 0.5).cos())
+        })
+        .collect()
+}
+
+/// A computed plane together with the coloring and layers with which to render it.
+pub struct RenderSetup
+{
+    pub iter_plane: IterPlane<Cplx>,
+    pub coloring: Coloring,
+    pub layers: LayerStack,
+}
+
+#[must_use]
+pub fn render_setup() -> RenderSetup
+{
+    RenderSetup {
+        iter_plane: mandelbrot().compute(),
+        coloring: Coloring::default(),
+        layers: LayerStack::default(),
+    }
+}
@@ -9,10 +9,11 @@ use serde::{Deserialize, Serialize};
 
 const PERIOD_LUMA_MODIFIER: f32 = 1.0;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum IncoloringAlgorithm
 {
+    #[default]
     PeriodMultiplier,
     Period,
     Solid,
@@ -32,7 +33,19 @@ pub enum IncoloringAlgorithm
     {
         fill_rate: f64,
     },
+    /// Like `PreperiodPeriod`, but with the preperiod interpolated between the iterations at
+    /// which cycles are detected, to avoid banding.
+    PreperiodPeriodSmooth
+    {
+        fill_rate: f64,
+    },
     Multiplier,
+    /// For dynamics on a finite field with `field_size` elements: hue by the length of the cycle
+    /// relative to the size of the field, and brightness by the length of the tail.
+    CycleLength
+    {
+        field_size: f64,
+    },
     // PointBased
     // {
     //     points: Vec<Cplx>,
@@ -52,6 +65,24 @@ impl IncoloringAlgorithm
         }
     }
 
+    /// Cycle lengths are compared to the size of the field on a log scale, using most of the
+    /// color wheel so that fixed points and cycles through the whole field are far apart. Tails
+    /// are compared to the square root of the field size, their typical length for random maps.
+    fn cycle_length_color<T: FromColor>(
+        palette: &Palette,
+        period: IterCountSmooth,
+        preperiod: IterCountSmooth,
+        field_size: f64,
+    ) -> T
+    {
+        let fraction = period.ln() / field_size.ln().max(1.);
+        let hue_id = 0.8 * fraction.clamp(0., 1.) * f64::from(palette.period_coloring.num_colors);
+        let luminosity_modifier = 1. / (1. + preperiod / field_size.sqrt());
+        palette
+            .period_coloring
+            .map(hue_id as f32, luminosity_modifier as f32)
+    }
+
     #[must_use]
     pub fn color_periodic<D, T>(&self, palette: &Palette, point_info: &PointInfoPeriodic<D>) -> T
     where
@@ -59,7 +90,7 @@ impl IncoloringAlgorithm
         T: FromColor,
     {
         match self {
-            Self::Solid => T::from_color32(palette.in_color),
+            Self::Solid => T::from_rgba8(palette.in_color),
             Self::Period => {
                 let hue_id = point_info.period as f32;
                 palette.period_coloring.map(hue_id, PERIOD_LUMA_MODIFIER)
@@ -82,6 +113,13 @@ impl IncoloringAlgorithm
                 let potential = (val * fill_rate / per).tanh();
                 palette.period_coloring.map(per as f32, potential as f32)
             }
+            Self::PreperiodPeriodSmooth { fill_rate } => {
+                let per = IterCountSmooth::from(point_info.period);
+                let val = point_info.smooth_preperiod;
+
+                let potential = (val * fill_rate / per).tanh();
+                palette.period_coloring.map(per as f32, potential as f32)
+            }
             Self::InternalPotential {
                 periodicity_tolerance,
                 crit_degree,
@@ -122,6 +160,12 @@ impl IncoloringAlgorithm
                     .period_coloring
                     .map(point_info.period as f32, luminosity_modifier as f32)
             }
+            Self::CycleLength { field_size } => Self::cycle_length_color(
+                palette,
+                IterCountSmooth::from(point_info.period),
+                point_info.preperiod as IterCountSmooth,
+                *field_size,
+            ),
             Self::Multiplier => Hsv {
                 hue: (point_info.multiplier.arg() / TAU) as f32 + 0.5,
                 saturation: 1.,
@@ -192,7 +236,7 @@ impl IncoloringAlgorithm
     {
         let rescaled_potential = (info.potential.powi(2) / f64::from(info.period)).ln();
         match self {
-            Self::Solid => T::from_color32(palette.in_color),
+            Self::Solid => T::from_rgba8(palette.in_color),
             Self::Period => palette
                 .period_coloring
                 .map(info.period as f32, PERIOD_LUMA_MODIFIER),
@@ -202,7 +246,7 @@ impl IncoloringAlgorithm
                 palette.period_coloring.map(hue_id, luminosity_modifier)
             }
             Self::Preperiod => palette.map(rescaled_potential.floor()),
-            Self::PreperiodPeriod { fill_rate } => {
+            Self::PreperiodPeriod { fill_rate } | Self::PreperiodPeriodSmooth { fill_rate } => {
                 let luma = (rescaled_potential * fill_rate).tanh() as f32;
                 palette.period_coloring.map(info.period as f32, luma)
             }
@@ -217,6 +261,9 @@ impl IncoloringAlgorithm
 
                 palette.period_coloring.map(n as f32, luma)
             }
+            Self::CycleLength { field_size } => {
+                Self::cycle_length_color(palette, f64::from(info.period), 0., *field_size)
+            }
             Self::Multiplier => Hsv {
                 hue: (info.multiplier.arg() / TAU) as f32 + 0.5,
                 saturation: 1.,
@@ -226,11 +273,3 @@ impl IncoloringAlgorithm
         }
     }
 }
-
-impl Default for IncoloringAlgorithm
-{
-    fn default() -> Self
-    {
-        Self::PeriodMultiplier
-    }
-}
@@ -1,16 +1,48 @@
-use crate::Coloring;
+use crate::layers::LayerStack;
+use crate::lighting::Lighting;
+use crate::{Coloring, Transparency};
 use dynamo_common::prelude::*;
-use egui::{Color32, ColorImage};
-use image::{ImageBuffer, Rgb};
+use crate::types::{gamma_u8_from_linear, linear_from_gamma_u8, Rgba8};
+use image::{ImageBuffer, Rgb, RgbaImage};
+#[cfg(feature = "epaint")]
+use {
+    epaint::{Color32, ColorImage},
+    ndarray::s,
+    std::ops::Range,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 pub trait FractalImage
 {
     type Image;
     fn point_grid(&self) -> &PointGrid;
+    #[cfg(feature = "epaint")]
     fn render(&self, coloring: &Coloring) -> ColorImage;
-    fn render_into(&self, image: &mut ColorImage, coloring: &Coloring);
-    fn save(&self, coloring: &Coloring, filename: String);
-    fn write_image(&self, coloring: &Coloring) -> Self::Image;
+    /// Color the image in place, compositing the layers over the base coloring and shading the
+    /// result.
+    #[cfg(feature = "epaint")]
+    fn render_into(&self, image: &mut ColorImage, coloring: &Coloring, layers: &LayerStack);
+    /// Color the points in the given rows of the plane in place, as in `render_into`, e.g. as
+    /// their tiles are computed. Shading depends on the neighboring rows, so is left out.
+    #[cfg(feature = "epaint")]
+    fn render_rows_into(
+        &self,
+        image: &mut ColorImage,
+        coloring: &Coloring,
+        layers: &LayerStack,
+        rows: Range<usize>,
+    );
+    fn write_image(&self, coloring: &Coloring, layers: &LayerStack) -> Self::Image;
+    /// Color the image with an alpha channel, leaving the points selected by `transparency`
+    /// fully transparent.
+    fn write_image_rgba(
+        &self,
+        coloring: &Coloring,
+        layers: &LayerStack,
+        transparency: Transparency,
+    ) -> RgbaImage;
 }
 
 impl<D> FractalImage for IterPlane<D>
@@ -22,6 +54,7 @@ where
     {
         &self.point_grid
     }
+    #[cfg(feature = "epaint")]
     fn render(&self, coloring: &Coloring) -> ColorImage
     {
         let width = self.point_grid().res_x;
@@ -35,43 +68,195 @@ where
             });
         img
     }
-    fn render_into(&self, image: &mut ColorImage, coloring: &Coloring)
+    #[cfg(feature = "epaint")]
+    fn render_into(&self, image: &mut ColorImage, coloring: &Coloring, layers: &LayerStack)
     {
         let width = self.point_grid().res_x;
         let height = self.point_grid().res_y;
 
+        self.render_rows_into(image, coloring, layers, 0..height);
+        if let Some(brightness) = layers.lighting.brightness(self) {
+            brightness.indexed_iter().for_each(|((x, y), &brightness)| {
+                let pixel = &mut image.pixels[x + (height - y - 1) * width];
+                let mut rgb = [pixel.r(), pixel.g(), pixel.b()];
+                Lighting::shade(&mut rgb, brightness);
+                *pixel = Color32::from_rgb(rgb[0], rgb[1], rgb[2]);
+            });
+        }
+    }
+    #[cfg(feature = "epaint")]
+    fn render_rows_into(
+        &self,
+        image: &mut ColorImage,
+        coloring: &Coloring,
+        layers: &LayerStack,
+        rows: Range<usize>,
+    )
+    {
+        let width = self.point_grid().res_x;
+        let height = self.point_grid().res_y;
+        let layered = layers.has_visible_layers();
+
+        let first_row = rows.start;
         self.iter_counts
+            .slice(s![.., rows])
             .indexed_iter()
             .for_each(|((x, y), point_info)| {
-                image.pixels[x + (height - y - 1) * width] = coloring.map(point_info);
+                image.pixels[x + (height - first_row - y - 1) * width] = if layered {
+                    layers.color(coloring, point_info).into()
+                } else {
+                    coloring.map(point_info)
+                };
             });
     }
-    fn save(&self, coloring: &Coloring, filename: String)
+    fn write_image(&self, coloring: &Coloring, layers: &LayerStack) -> Self::Image
     {
         let res_x = u32::try_from(self.point_grid().res_x).unwrap_or(u32::MAX);
         let res_y = u32::try_from(self.point_grid().res_y).unwrap_or(u32::MAX);
         let mut image = ImageBuffer::new(res_x, res_y);
+        let layered = layers.has_visible_layers();
 
         for (x, y, pixel) in image.enumerate_pixels_mut() {
             let iter_count = &self.iter_counts[(x as usize, (res_y - y - 1) as usize)];
-            *pixel = coloring.map::<_, Rgb<u8>>(iter_count);
+            *pixel = if layered {
+                let [r, g, b, _] = layers.color(coloring, iter_count).to_array();
+                Rgb([r, g, b])
+            } else {
+                coloring.map(iter_count)
+            };
         }
-        if let Err(e) = image.save(filename.clone()) {
-            println!("Error saving file: {e:?}");
-        } else {
-            println!("Image saved to {filename}");
+        if let Some(brightness) = layers.lighting.brightness(self) {
+            for (x, y, pixel) in image.enumerate_pixels_mut() {
+                Lighting::shade(&mut pixel.0, brightness[(x as usize, (res_y - y - 1) as usize)]);
+            }
         }
+        image
     }
-    fn write_image(&self, coloring: &Coloring) -> Self::Image
+    fn write_image_rgba(
+        &self,
+        coloring: &Coloring,
+        layers: &LayerStack,
+        transparency: Transparency,
+    ) -> RgbaImage
     {
         let res_x = u32::try_from(self.point_grid().res_x).unwrap_or(u32::MAX);
         let res_y = u32::try_from(self.point_grid().res_y).unwrap_or(u32::MAX);
         let mut image = ImageBuffer::new(res_x, res_y);
+        let layered = layers.has_visible_layers();
 
         for (x, y, pixel) in image.enumerate_pixels_mut() {
             let iter_count = &self.iter_counts[(x as usize, (res_y - y - 1) as usize)];
-            *pixel = coloring.map(iter_count);
+            let mut color = coloring.map_rgba(iter_count, transparency);
+            if layered {
+                let [r, g, b, _] = layers.color(coloring, iter_count).to_array();
+                color.0[..3].copy_from_slice(&[r, g, b]);
+            }
+            *pixel = color;
+        }
+        if let Some(brightness) = layers.lighting.brightness(self) {
+            for (x, y, pixel) in image.enumerate_pixels_mut() {
+                let brightness = brightness[(x as usize, (res_y - y - 1) as usize)];
+                Lighting::shade(&mut pixel.0[..3], brightness);
+            }
         }
         image
     }
 }
+
+/// Simulation of a form of color blindness, applied to rendered images so that figures can be
+/// checked for colors that would be confused.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColorVisionFilter
+{
+    #[default]
+    Normal,
+    Deuteranopia,
+    Protanopia,
+}
+
+impl ColorVisionFilter
+{
+    /// Matrices of Machado, Oliveira and Fernandes (2009) at full severity, acting on linear RGB.
+    const DEUTERANOPIA: [[f32; 3]; 3] = [
+        [0.367_322, 0.860_646, -0.227_968],
+        [0.280_085, 0.672_501, 0.047_413],
+        [-0.011_820, 0.042_940, 0.968_881],
+    ];
+    const PROTANOPIA: [[f32; 3]; 3] = [
+        [0.152_286, 1.052_583, -0.204_868],
+        [0.114_503, 0.786_281, 0.099_216],
+        [-0.003_882, -0.048_116, 1.051_998],
+    ];
+
+    #[must_use]
+    pub const fn next(self) -> Self
+    {
+        match self {
+            Self::Normal => Self::Deuteranopia,
+            Self::Deuteranopia => Self::Protanopia,
+            Self::Protanopia => Self::Normal,
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str
+    {
+        match self {
+            Self::Normal => "Normal vision",
+            Self::Deuteranopia => "Deuteranopia",
+            Self::Protanopia => "Protanopia",
+        }
+    }
+
+    const fn matrix(self) -> Option<[[f32; 3]; 3]>
+    {
+        match self {
+            Self::Normal => None,
+            Self::Deuteranopia => Some(Self::DEUTERANOPIA),
+            Self::Protanopia => Some(Self::PROTANOPIA),
+        }
+    }
+
+    /// The color as seen with this form of color vision.
+    #[must_use]
+    pub fn simulate(self, color: Rgba8) -> Rgba8
+    {
+        let Some(matrix) = self.matrix() else {
+            return color;
+        };
+        let linear = [color.r(), color.g(), color.b()].map(linear_from_gamma_u8);
+        Self::transform(&matrix, linear, color.a())
+    }
+
+    /// Apply the simulation to every pixel of the image.
+    #[cfg(feature = "epaint")]
+    pub fn apply(self, image: &mut ColorImage)
+    {
+        self.apply_to_rows(image, 0..image.height());
+    }
+
+    /// Apply the simulation to the pixels in the given rows of the image.
+    #[cfg(feature = "epaint")]
+    pub fn apply_to_rows(self, image: &mut ColorImage, rows: Range<usize>)
+    {
+        let Some(matrix) = self.matrix() else {
+            return;
+        };
+        let width = image.width();
+        let linear: [f32; 256] = std::array::from_fn(|i| linear_from_gamma_u8(i as u8));
+        for pixel in &mut image.pixels[rows.start * width..rows.end * width] {
+            let rgb = [pixel.r(), pixel.g(), pixel.b()].map(|c| linear[c as usize]);
+            *pixel = Self::transform(&matrix, rgb, pixel.a()).into();
+        }
+    }
+
+    fn transform(matrix: &[[f32; 3]; 3], rgb: [f32; 3], alpha: u8) -> Rgba8
+    {
+        let [r, g, b] = matrix.map(|row| {
+            let value = row[2].mul_add(rgb[2], row[1].mul_add(rgb[1], row[0] * rgb[0]));
+            gamma_u8_from_linear(value.clamp(0., 1.))
+        });
+        Rgba8::from_rgba_premultiplied(r, g, b, alpha)
+    }
+}
@@ -0,0 +1,192 @@
+//! Colorings composited over one another, all applied to the same computed data.
+
+use crate::lighting::Lighting;
+use crate::Coloring;
+use dynamo_common::prelude::*;
+use crate::types::Rgba8;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How the colors of a layer are combined with those beneath it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BlendMode
+{
+    #[default]
+    Normal,
+    /// Darken by the layer, so that white leaves the image unchanged.
+    Multiply,
+    /// Lighten by the layer, so that black leaves the image unchanged.
+    Screen,
+    /// Multiply the dark colors beneath the layer and screen the light ones.
+    Overlay,
+    Darken,
+    Lighten,
+}
+
+impl BlendMode
+{
+    pub const ALL: [Self; 6] = [
+        Self::Normal,
+        Self::Multiply,
+        Self::Screen,
+        Self::Overlay,
+        Self::Darken,
+        Self::Lighten,
+    ];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str
+    {
+        match self {
+            Self::Normal => "Normal",
+            Self::Multiply => "Multiply",
+            Self::Screen => "Screen",
+            Self::Overlay => "Overlay",
+            Self::Darken => "Darken",
+            Self::Lighten => "Lighten",
+        }
+    }
+
+    /// Blend a channel of the layer over one of the color beneath, both between 0 and 1.
+    fn blend_channel(self, below: f32, above: f32) -> f32
+    {
+        match self {
+            Self::Normal => above,
+            Self::Multiply => below * above,
+            Self::Screen => (1. - below).mul_add(-(1. - above), 1.),
+            Self::Overlay if below < 0.5 => 2. * below * above,
+            Self::Overlay => (2. * (1. - below)).mul_add(-(1. - above), 1.),
+            Self::Darken => below.min(above),
+            Self::Lighten => below.max(above),
+        }
+    }
+
+    /// Blend the color of a layer over the color beneath, mixing in the result by `opacity`.
+    #[must_use]
+    pub fn blend(self, below: Rgba8, above: Rgba8, opacity: f32) -> Rgba8
+    {
+        let channel = |b: u8, a: u8| {
+            let b = f32::from(b) / 255.;
+            let blended = self.blend_channel(b, f32::from(a) / 255.);
+            let mixed = opacity.mul_add(blended - b, b);
+            (255. * mixed.clamp(0., 1.)).round() as u8
+        };
+        Rgba8::from_rgb(
+            channel(below.r(), above.r()),
+            channel(below.g(), above.g()),
+            channel(below.b(), above.b()),
+        )
+    }
+}
+
+/// A coloring drawn over the base coloring of a pane.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Layer
+{
+    pub coloring: Coloring,
+    pub blend_mode: BlendMode,
+    /// Fraction of the blended color mixed into the color beneath, between 0 and 1.
+    pub opacity: f32,
+    pub visible: bool,
+}
+
+impl Layer
+{
+    #[must_use]
+    pub const fn new(coloring: Coloring, blend_mode: BlendMode, opacity: f32) -> Self
+    {
+        Self {
+            coloring,
+            blend_mode,
+            opacity,
+            visible: true,
+        }
+    }
+}
+
+/// Layers drawn over the base coloring of a pane, from the bottom up, and the light shading
+/// the result.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LayerStack
+{
+    layers: Vec<Layer>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub lighting: Lighting,
+}
+
+impl LayerStack
+{
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.layers.is_empty()
+    }
+
+    /// True if some layer is visible, so that more than the base coloring is seen.
+    #[must_use]
+    pub fn has_visible_layers(&self) -> bool
+    {
+        self.layers
+            .iter()
+            .any(|layer| layer.visible && layer.opacity > 0.)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize
+    {
+        self.layers.len()
+    }
+
+    /// Add a layer on top of the others.
+    pub fn push(&mut self, layer: Layer)
+    {
+        self.layers.push(layer);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<Layer>
+    {
+        (index < self.layers.len()).then(|| self.layers.remove(index))
+    }
+
+    /// Exchange the layer at `index` with the one above it, if any.
+    pub fn raise(&mut self, index: usize)
+    {
+        if index + 1 < self.layers.len() {
+            self.layers.swap(index, index + 1);
+        }
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.layers.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Layer>
+    {
+        self.layers.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Layer>
+    {
+        self.layers.iter_mut()
+    }
+
+    /// Color of a point under the base coloring, with the visible layers composited over it.
+    #[must_use]
+    pub fn color<D>(&self, base: &Coloring, point_info: &PointInfo<D>) -> Rgba8
+    where
+        D: Polar<Real>,
+    {
+        self.layers
+            .iter()
+            .filter(|layer| layer.visible)
+            .fold(base.map(point_info), |below, layer| {
+                let above = layer.coloring.map(point_info);
+                layer.blend_mode.blend(below, above, layer.opacity)
+            })
+    }
+}
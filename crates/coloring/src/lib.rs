@@ -1,15 +1,24 @@
 #![allow(dead_code)]
 
 use dynamo_common::prelude::*;
+use crate::types::Rgba8;
+use image::{Rgb, Rgba};
 
 pub mod algorithms;
 pub mod fractal_image;
+pub mod layers;
+pub mod lighting;
+pub mod outcoloring;
 pub mod palette;
 pub mod prelude;
+pub mod snapshot;
 pub mod types;
+pub mod wring;
 
 pub use algorithms::IncoloringAlgorithm;
+pub use outcoloring::{CustomOutcoloring, OutcoloringAlgorithm};
 pub use palette::Palette;
+pub use wring::Wring;
 use types::{FromColor, Hsv};
 
 #[cfg(feature = "serde")]
@@ -17,7 +26,7 @@ use serde::{Deserialize, Serialize};
 
 use self::palette::DiscretePalette;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Coloring
 {
@@ -25,7 +34,106 @@ pub struct Coloring
     palette: Palette,
     esc_period: Period,
     do_escape_phase_coloring: bool,
+    /// Fraction of a turn by which the hues of escape phases are rotated.
+    #[cfg_attr(feature = "serde", serde(default))]
+    esc_phase_offset: f32,
+    /// Degree of the first return map near infinity, which determines how a wring shifts the
+    /// potentials of escaping points.
+    #[cfg_attr(feature = "serde", serde(default = "default_escape_degree"))]
+    esc_degree: Real,
+    #[cfg_attr(feature = "serde", serde(default))]
+    wring: Wring,
+    /// Whether escaping points are shaded by the angular sector containing their last iterate.
+    #[cfg_attr(feature = "serde", serde(default))]
+    do_binary_decomposition: bool,
+    /// Number of sectors into which the plane is divided for binary decomposition.
+    #[cfg_attr(feature = "serde", serde(default = "default_decomposition_sectors"))]
+    decomposition_sectors: u32,
+    /// User-defined coloring consulted before the built-in one, stored by name.
+    #[cfg_attr(feature = "serde", serde(default, with = "outcoloring::by_name"))]
+    outcoloring: Option<CustomOutcoloring>,
 }
+
+/// Which points are left fully transparent in exported images, so that they can be laid over
+/// other imagery.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Transparency
+{
+    #[default]
+    Opaque,
+    /// Points escaping to infinity, leaving the filled Julia set or connectedness locus.
+    Escaping,
+    /// Points with bounded orbits, leaving the basins of infinity.
+    Interior,
+}
+
+impl Transparency
+{
+    pub const ALL: [Self; 3] = [Self::Opaque, Self::Escaping, Self::Interior];
+
+    #[must_use]
+    pub const fn next(self) -> Self
+    {
+        match self {
+            Self::Opaque => Self::Escaping,
+            Self::Escaping => Self::Interior,
+            Self::Interior => Self::Opaque,
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str
+    {
+        match self {
+            Self::Opaque => "Opaque",
+            Self::Escaping => "Transparent escaping points",
+            Self::Interior => "Transparent interior",
+        }
+    }
+
+    #[must_use]
+    pub const fn is_transparent<D>(self, point_info: &PointInfo<D>) -> bool
+    {
+        match self {
+            Self::Opaque => false,
+            Self::Escaping => matches!(
+                point_info,
+                PointInfo::Escaping { .. } | PointInfo::DistanceEstimate { .. }
+            ),
+            Self::Interior => matches!(
+                point_info,
+                PointInfo::Periodic(_)
+                    | PointInfo::PeriodicKnownPotential(_)
+                    | PointInfo::Bounded
+                    | PointInfo::MarkedPoint { .. }
+                    | PointInfo::InteriorDistanceEstimate { .. }
+                    | PointInfo::Lyapunov { .. }
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+const fn default_escape_degree() -> Real
+{
+    2.
+}
+
+#[cfg(feature = "serde")]
+const fn default_decomposition_sectors() -> u32
+{
+    2
+}
+
+impl Default for Coloring
+{
+    fn default() -> Self
+    {
+        Self::new(IncoloringAlgorithm::default(), Palette::default())
+    }
+}
+
 impl Coloring
 {
     #[must_use]
@@ -36,6 +144,12 @@ impl Coloring
             palette,
             esc_period: 1,
             do_escape_phase_coloring: false,
+            esc_phase_offset: 0.,
+            esc_degree: 2.,
+            wring: Wring::IDENTITY,
+            do_binary_decomposition: false,
+            decomposition_sectors: 2,
+            outcoloring: None,
         }
     }
 
@@ -46,43 +160,144 @@ impl Coloring
         T: FromColor,
     {
         use PointInfo::{
-            Bounded, DistanceEstimate, Escaping, MarkedPoint, Periodic, PeriodicKnownPotential,
-            Unknown, Wandering,
+            Bounded, DistanceEstimate, EscapeProbability, Escaping, InteriorDistanceEstimate,
+            FastEscaping, Lyapunov, MarkedPoint, Periodic, PeriodicKnownPotential, Unknown,
+            Wandering,
         };
+        if let Some(color) = self
+            .outcoloring
+            .as_ref()
+            .and_then(|outcoloring| outcoloring.color(&self.palette, point_info))
+        {
+            return T::from_rgba8(color);
+        }
         match point_info {
             Escaping {
                 potential,
-                phase: Some(phase),
-            } if self.do_escape_phase_coloring => {
-                self.palette
-                    .map_phase(potential.ln(), *phase, self.esc_period)
+                phase,
+                angle: Some(angle),
+            } if self.do_binary_decomposition => {
+                let color: Rgba8 = self.color_escaping(*potential, *phase);
+                T::from_rgba8(self.decompose(color, *angle))
             }
-            Escaping { potential, .. } => self.palette.map(potential.ln()),
+            Escaping {
+                potential, phase, ..
+            } => self.color_escaping(*potential, *phase),
             Periodic(data) => self.algorithm.color_periodic(&self.palette, data),
             PeriodicKnownPotential(data) => {
                 self.algorithm.color_known_potential(&self.palette, data)
             }
-            Bounded => T::from_color32(self.palette.in_color),
+            Bounded => T::from_rgba8(self.palette.in_color),
             DistanceEstimate { distance, phase } if self.do_escape_phase_coloring => self
                 .palette
-                .map_phase(-distance.ln() / 2., *phase, self.esc_period),
+                .map_phase(
+                -distance.ln() / 2.,
+                *phase,
+                self.esc_period,
+                self.esc_phase_offset,
+            ),
             DistanceEstimate { distance, .. } => self.palette.map(-distance.ln() / 2.),
-            Wandering => T::from_color32(self.palette.wandering_color),
-            Unknown => T::from_color32(self.palette.unknown_color),
-            MarkedPoint {
-                class_id,
-                num_point_classes,
-                ..
-            } => {
-                let hue = (f32::from(*class_id) + 0.5) / (*num_point_classes as f32);
+            InteriorDistanceEstimate { distance, .. } => self.palette.map(-distance.ln() / 2.),
+            Wandering => T::from_rgba8(self.palette.wandering_color),
+            FastEscaping { level } => {
+                // Red-orange, darker for points that take longer to enter the fast escaping set
+                Hsv {
+                    hue: 0.04,
+                    saturation: 0.9,
+                    intensity: (-0.15 * f64::from(*level)).exp() as f32,
+                }
+                .into()
+            }
+            Unknown => T::from_rgba8(self.palette.unknown_color),
+            Lyapunov { exponent } => {
+                // Gold for stable orbits, blue for chaotic ones, brighter the further from zero
+                let hue = if *exponent < 0. { 0.12 } else { 0.62 };
                 Hsv {
                     hue,
-                    saturation: 0.7,
-                    intensity: 0.6,
+                    saturation: 0.8,
+                    intensity: (exponent.abs() * 4.).tanh() as f32,
+                }
+                .into()
+            }
+            EscapeProbability { probability } => {
+                // Dark where noisy orbits stay bounded, passing from blue to red as they become
+                // more likely to escape
+                Hsv {
+                    hue: 0.62 + 0.38 * (*probability as f32),
+                    saturation: 0.8,
+                    intensity: probability.sqrt() as f32,
                 }
                 .into()
             }
+            MarkedPoint {
+                class_id,
+                num_point_classes,
+                ..
+            } => Self::class_color(*class_id, *num_point_classes),
+        }
+    }
+
+    /// Color of the point with an alpha channel, which is zero if the point is transparent
+    /// under the given policy.
+    #[must_use]
+    pub fn map_rgba<D>(&self, point_info: &PointInfo<D>, transparency: Transparency) -> Rgba<u8>
+    where
+        D: Polar<Real>,
+    {
+        let Rgb([r, g, b]) = self.map(point_info);
+        let alpha = if transparency.is_transparent(point_info) {
+            0
+        } else {
+            u8::MAX
+        };
+        Rgba([r, g, b, alpha])
+    }
+
+    fn color_escaping<T: FromColor>(&self, potential: IterCountSmooth, phase: Option<Period>) -> T
+    {
+        let potential = self.wrung_potential(potential);
+        match phase {
+            Some(phase) if self.do_escape_phase_coloring => self.palette.map_phase(
+                potential.ln(),
+                phase,
+                self.esc_period,
+                self.esc_phase_offset,
+            ),
+            _ => self.palette.map(potential.ln()),
+        }
+    }
+
+    /// Darken the color of an escaping point in every other sector of angles, numbered
+    /// counterclockwise from the positive real axis. With two sectors, this is the classic
+    /// binary decomposition by the sign of the imaginary part of the last iterate.
+    fn decompose(&self, color: Rgba8, angle: Real) -> Rgba8
+    {
+        let sectors = self.decomposition_sectors.max(1);
+        let sector = (angle.rem_euclid(TAU) / TAU * Real::from(sectors)) as u32 % sectors;
+        if sector.is_multiple_of(2) {
+            return color;
+        }
+        let darken = |v: u8| (f32::from(v) * 0.35) as u8;
+        Rgba8::from_rgb(darken(color.r()), darken(color.g()), darken(color.b()))
+    }
+
+    /// Color of the points in a class of marked points, such as the basin of an attractor.
+    #[must_use]
+    pub fn class_color<T: FromColor>(class_id: PointClassId, num_point_classes: usize) -> T
+    {
+        let hue = (f32::from(class_id) + 0.5) / (num_point_classes as f32);
+        Hsv {
+            hue,
+            saturation: 0.7,
+            intensity: 0.6,
         }
+        .into()
+    }
+
+    fn wrung_potential(&self, potential: IterCountSmooth) -> IterCountSmooth
+    {
+        self.wring
+            .apply(potential, self.esc_degree, self.esc_period)
     }
 
     pub fn set_palette(&mut self, palette: Palette)
@@ -137,20 +352,103 @@ impl Coloring
         self
     }
 
+    #[must_use]
+    pub const fn with_escape_degree(mut self, esc_degree: Real) -> Self
+    {
+        self.esc_degree = esc_degree;
+        self
+    }
+
+    #[must_use]
+    pub const fn get_wring(&self) -> Wring
+    {
+        self.wring
+    }
+
+    pub fn set_wring(&mut self, wring: Wring)
+    {
+        self.wring = wring;
+    }
+
     pub fn toggle_escape_phase_coloring(&mut self)
     {
         self.do_escape_phase_coloring ^= true;
     }
 
+    #[must_use]
+    pub const fn is_escape_phase_coloring(&self) -> bool
+    {
+        self.do_escape_phase_coloring
+    }
+
+    #[must_use]
+    pub const fn get_escape_period(&self) -> Period
+    {
+        self.esc_period
+    }
+
+    pub fn set_escape_period(&mut self, esc_period: Period)
+    {
+        self.esc_period = esc_period.max(1);
+    }
+
+    #[must_use]
+    pub const fn get_escape_phase_offset(&self) -> f32
+    {
+        self.esc_phase_offset
+    }
+
+    pub fn set_escape_phase_offset(&mut self, offset: f32)
+    {
+        self.esc_phase_offset = offset.rem_euclid(1.);
+    }
+
+    /// Color escape phases by the first return map near infinity of the given period, with the
+    /// hues rotated so that the first color goes to orbits that escape after the given phase.
+    pub fn suggest_escape_phase(&mut self, esc_period: Period, esc_phase: Period)
+    {
+        self.set_escape_period(esc_period);
+        let phase = (esc_phase % self.esc_period) as f32;
+        self.set_escape_phase_offset(-phase / self.esc_period as f32);
+    }
+
+    #[must_use]
+    pub const fn get_outcoloring(&self) -> Option<&CustomOutcoloring>
+    {
+        self.outcoloring.as_ref()
+    }
+
+    /// Consult the given algorithm before the built-in coloring, or stop consulting any if
+    /// `None`.
+    pub fn set_outcoloring(&mut self, outcoloring: Option<CustomOutcoloring>)
+    {
+        self.outcoloring = outcoloring;
+    }
+
+    pub fn toggle_binary_decomposition(&mut self)
+    {
+        self.do_binary_decomposition ^= true;
+    }
+
+    #[must_use]
+    pub const fn get_decomposition_sectors(&self) -> u32
+    {
+        self.decomposition_sectors
+    }
+
+    pub fn set_decomposition_sectors(&mut self, sectors: u32)
+    {
+        self.decomposition_sectors = sectors.max(2);
+    }
+
     #[cfg(feature = "serde")]
-    pub fn save_to_file<P>(&self, filename: P) -> std::io::Result<()>
+    pub fn save_to_file<P>(&self, filename: P) -> Result<(), Box<dyn std::error::Error>>
     where
         P: AsRef<std::path::Path>,
     {
         use std::io::Write;
 
-        let toml_string =
-            toml::to_string(self.get_palette()).expect("Failed to serialize palette.");
+        let toml_string = toml::to_string(self.get_palette())?;
         let mut file = std::fs::File::create(filename)?;
         file.write_all(toml_string.as_bytes())?;
 
@@ -222,4 +520,512 @@ mod tests
         let xyz = Xyz::from(luv);
         dbg!(xyz);
     }
+
+    #[test]
+    fn wring()
+    {
+        use crate::Wring;
+
+        // Quadrupling the escape rate of a quadratic map takes two iterations off the count
+        let shifted = Wring::new(4.).apply(10., 2., 1);
+        dbg!(shifted);
+        assert!((shifted - 8.).abs() < 1e-12);
+
+        // For a first return map of period 3, it takes three times as many
+        let shifted = Wring::new(0.5).apply(10., 2., 3);
+        dbg!(shifted);
+        assert!((shifted - 13.).abs() < 1e-12);
+
+        assert!(Wring::oscillating(0.).is_identity());
+        assert!(Wring::new(1e6).apply(1., 2., 1) > 0.);
+    }
+
+    #[test]
+    fn binary_decomposition()
+    {
+        use crate::Coloring;
+        use dynamo_common::prelude::*;
+        use crate::types::Rgba8;
+
+        let escaping = |angle| PointInfo::<Cplx>::Escaping {
+            potential: 3.,
+            phase: None,
+            angle: Some(angle),
+        };
+        let mut coloring = Coloring::default();
+        let plain: Rgba8 = coloring.map(&escaping(-1.));
+
+        coloring.toggle_binary_decomposition();
+        let upper: Rgba8 = coloring.map(&escaping(1.));
+        let lower: Rgba8 = coloring.map(&escaping(-1.));
+        dbg!(plain, upper, lower);
+        assert_eq!(upper, plain);
+        assert!(lower.r() <= upper.r() && lower.g() <= upper.g() && lower.b() <= upper.b());
+        assert_ne!(lower, upper);
+
+        // With four sectors, the second quadrant is shaded and the third is not
+        coloring.set_decomposition_sectors(4);
+        assert_ne!(coloring.map::<Cplx, Rgba8>(&escaping(2.)), plain);
+        assert_eq!(coloring.map::<Cplx, Rgba8>(&escaping(-2.)), plain);
+    }
+
+    #[test]
+    fn escape_phase_offset()
+    {
+        use crate::Coloring;
+
+        let mut coloring = Coloring::default();
+        coloring.suggest_escape_phase(3, 1);
+        dbg!(coloring.get_escape_period(), coloring.get_escape_phase_offset());
+        assert_eq!(coloring.get_escape_period(), 3);
+        assert!((coloring.get_escape_phase_offset() - 2. / 3.).abs() < 1e-6);
+
+        coloring.set_escape_phase_offset(-0.25);
+        assert!((coloring.get_escape_phase_offset() - 0.75).abs() < 1e-6);
+        coloring.set_escape_period(0);
+        assert_eq!(coloring.get_escape_period(), 1);
+    }
+
+    #[test]
+    fn palette_generator()
+    {
+        use crate::palette::{PaletteConstraints, PaletteGenerator, PaletteSeed};
+        use crate::types::Rgba8;
+
+        // Hue of a color, as a fraction of a turn
+        let hue = |color: Rgba8| {
+            let [r, g, b, _] = color.to_array().map(f64::from);
+            let alpha = r - (g + b) / 2.;
+            let beta = 3_f64.sqrt() / 2. * (g - b);
+            (beta.atan2(alpha) / std::f64::consts::TAU).rem_euclid(1.)
+        };
+
+        let mut generator = PaletteGenerator::default();
+        let first = generator.generate_from_seed(17);
+        let second = generator.generate_from_seed(42);
+        assert_ne!(first, second);
+        assert_eq!(first, generator.generate_from_seed(17));
+
+        let history: Vec<u64> = generator.history().map(|record| record.seed).collect();
+        assert_eq!(history, vec![17, 42, 17]);
+        for _ in 0..2 * PaletteGenerator::HISTORY_LEN {
+            generator.generate();
+        }
+        assert_eq!(generator.history().count(), PaletteGenerator::HISTORY_LEN);
+
+        // Seeds are recorded with their constraints, so they can be recalled after a change
+        generator.constraints.hue_range = (0.9, 0.1);
+        let greens = generator.generate_from_seed(5);
+        generator.constraints = PaletteConstraints::UNCONSTRAINED;
+        let recalled = generator.history().next().map(PaletteSeed::palette);
+        assert_eq!(recalled, Some(greens));
+
+        // Saturated colors of a palette drawn within an arc of reds keep to that arc, up to the
+        // distortion of hues by gamma correction
+        for seed in 0..8 {
+            let palette = PaletteSeed {
+                seed,
+                constraints: PaletteConstraints {
+                    hue_range: (0.9, 0.1),
+                    ..PaletteConstraints::UNCONSTRAINED
+                },
+            }
+            .palette();
+            for k in 0..64 {
+                let color: Rgba8 = palette.map(f64::from(k) * 0.37);
+                let [r, g, b, _] = color.to_array();
+                if r.max(g).max(b) - r.min(g).min(b) < 24 {
+                    continue;
+                }
+                let offset = (hue(color) + 0.13).rem_euclid(1.);
+                assert!(offset <= 0.26, "{color:?} out of range");
+            }
+        }
+
+        let safe = PaletteSeed {
+            seed: 3,
+            constraints: PaletteConstraints {
+                contrast: 0.1,
+                colorblind_safe: true,
+                ..PaletteConstraints::UNCONSTRAINED
+            },
+        }
+        .palette();
+        let brightness = |t: f64| {
+            let color: Rgba8 = safe.map(t);
+            color.to_array()[..3].iter().copied().max().unwrap_or(0)
+        };
+        let samples: Vec<u8> = (0..64).map(|k| brightness(f64::from(k) * 0.37)).collect();
+        dbg!(&samples);
+        let spread = samples.iter().max().unwrap_or(&0) - samples.iter().min().unwrap_or(&0);
+        assert!(spread > 64);
+    }
+
+    #[test]
+    fn period_colors()
+    {
+        use crate::palette::{DiscretePalette, PeriodColors};
+        use crate::types::Hsv;
+        use crate::types::Rgba8;
+
+        let blue = Hsv::new(0.62, 0.8, 0.9);
+        let palette = DiscretePalette::standard().with_period_color(1, blue);
+        let shifted = palette.with_hue_offset(0.3);
+
+        // The fixed color is kept when the palette is perturbed, and dimmed like any other
+        assert_eq!(shifted.map::<Rgba8>(1., 1.), Rgba8::from(blue));
+        assert_eq!(
+            shifted.map::<Rgba8>(1., 0.5),
+            Rgba8::from(Hsv::new(0.62, 0.8, 0.45))
+        );
+        assert_ne!(
+            shifted.map::<Rgba8>(2., 1.),
+            palette.map::<Rgba8>(2., 1.)
+        );
+
+        let mut pinned = DiscretePalette::standard();
+        let before: Vec<Rgba8> = (1..=3).map(|n| pinned.map(n as f32, 1.)).collect();
+        pinned.pin_periods(3);
+        pinned.base_hue = 0.1;
+        let after: Vec<Rgba8> = (1..=3).map(|n| pinned.map(n as f32, 1.)).collect();
+        assert_eq!(before, after);
+
+        let mut colors = PeriodColors::default();
+        for period in 1..=PeriodColors::CAPACITY as u32 {
+            assert!(colors.set(period, blue));
+        }
+        assert!(!colors.set(100, blue));
+        assert!(colors.set(3, Hsv::new(0., 1., 1.)));
+        colors.remove(2);
+        assert_eq!(colors.get(2), None);
+        assert_eq!(colors.get(3).map(|c| c.hue), Some(0.));
+
+        let list: Vec<_> = colors.into();
+        assert_eq!(list.len(), PeriodColors::CAPACITY - 1);
+        assert!(PeriodColors::from(list).iter().eq(colors.iter()));
+    }
+
+    #[test]
+    fn color_vision_filter()
+    {
+        use crate::fractal_image::ColorVisionFilter;
+        use crate::palette::DiscretePalette;
+        use crate::types::Rgba8;
+
+        let distance = |a: Rgba8, b: Rgba8| {
+            let d = |x: u8, y: u8| i32::from(x) - i32::from(y);
+            d(a.r(), b.r()).abs() + d(a.g(), b.g()).abs() + d(a.b(), b.b()).abs()
+        };
+
+        let filters = [ColorVisionFilter::Deuteranopia, ColorVisionFilter::Protanopia];
+        let (red, green) = (Rgba8::from_rgb(220, 80, 60), Rgba8::from_rgb(120, 150, 40));
+        assert_eq!(ColorVisionFilter::Normal.simulate(red), red);
+        for filter in filters {
+            // Grays are seen as they are, while red and green are confused
+            let gray = Rgba8::from_gray(128);
+            assert!(distance(filter.simulate(gray), gray) <= 3);
+            let seen = distance(filter.simulate(red), filter.simulate(green));
+            assert!(seen < distance(red, green) * 2 / 3);
+
+            #[cfg(feature = "epaint")]
+            {
+                let mut image = epaint::ColorImage::new([2, 1], red.into());
+                image.pixels[1] = green.into();
+                filter.apply(&mut image);
+                let seen = [red, green].map(|color| filter.simulate(color).into());
+                assert_eq!(image.pixels, seen);
+            }
+        }
+
+        for (name, palette) in DiscretePalette::COLORBLIND_SAFE {
+            let colors: Vec<Rgba8> = (1..=7).map(|n| palette.map(n as f32, 1.)).collect();
+            for filter in filters {
+                let seen: Vec<Rgba8> = colors.iter().map(|&c| filter.simulate(c)).collect();
+                for (i, a) in seen.iter().enumerate() {
+                    for b in &seen[i + 1..] {
+                        dbg!(name, filter, distance(*a, *b));
+                        assert!(distance(*a, *b) > 40);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transparency()
+    {
+        use crate::{Coloring, Transparency};
+        use dynamo_common::prelude::*;
+        use image::{Rgb, Rgba};
+
+        let coloring = Coloring::default();
+        let escaping = PointInfo::<Cplx>::Escaping {
+            potential: 3.5,
+            phase: None,
+            angle: None,
+        };
+        let bounded = PointInfo::<Cplx>::Bounded;
+
+        for point_info in [&escaping, &bounded] {
+            let Rgb([r, g, b]) = coloring.map(point_info);
+            let Rgba([r_a, g_a, b_a, alpha]) =
+                coloring.map_rgba(point_info, Transparency::Opaque);
+            assert_eq!((r, g, b, u8::MAX), (r_a, g_a, b_a, alpha));
+        }
+
+        let alpha = |point_info, transparency| coloring.map_rgba(point_info, transparency).0[3];
+        assert_eq!(alpha(&escaping, Transparency::Escaping), 0);
+        assert_eq!(alpha(&bounded, Transparency::Escaping), u8::MAX);
+        assert_eq!(alpha(&escaping, Transparency::Interior), u8::MAX);
+        assert_eq!(alpha(&bounded, Transparency::Interior), 0);
+        assert_eq!(alpha(&PointInfo::Unknown, Transparency::Interior), u8::MAX);
+    }
+
+    #[test]
+    fn layers()
+    {
+        use crate::layers::{BlendMode, Layer, LayerStack};
+        use crate::{Coloring, Palette};
+        use dynamo_common::prelude::*;
+        use crate::types::Rgba8;
+
+        let below = Rgba8::from_rgb(200, 100, 50);
+        let above = Rgba8::from_rgb(128, 255, 0);
+        assert_eq!(BlendMode::Normal.blend(below, above, 1.), above);
+        assert_eq!(BlendMode::Normal.blend(below, above, 0.), below);
+        assert_eq!(BlendMode::Multiply.blend(below, Rgba8::WHITE, 1.), below);
+        assert_eq!(BlendMode::Screen.blend(below, Rgba8::BLACK, 1.), below);
+        assert_eq!(
+            BlendMode::Multiply.blend(below, above, 1.),
+            Rgba8::from_rgb(100, 100, 0)
+        );
+        assert_eq!(
+            BlendMode::Darken.blend(below, above, 0.5),
+            Rgba8::from_rgb(164, 100, 25)
+        );
+
+        let base = Coloring::default();
+        let point_info = PointInfo::<Cplx>::Escaping {
+            potential: 3.5,
+            phase: None,
+            angle: None,
+        };
+        let base_color: Rgba8 = base.map(&point_info);
+
+        let mut layers = LayerStack::default();
+        assert!(!layers.has_visible_layers());
+        assert_eq!(layers.color(&base, &point_info), base_color);
+
+        let edges = Coloring::new(base.get_algorithm().clone(), Palette::white(16.));
+        let edge_color: Rgba8 = edges.map(&point_info);
+        layers.push(Layer::new(edges, BlendMode::Multiply, 1.));
+        layers.push(Layer::new(base.clone(), BlendMode::Normal, 0.));
+        assert!(layers.has_visible_layers());
+        assert_eq!(
+            layers.color(&base, &point_info),
+            BlendMode::Multiply.blend(base_color, edge_color, 1.)
+        );
+
+        // Moving the transparent layer beneath the other leaves the colors unchanged, and the top
+        // layer cannot be raised further
+        layers.raise(1);
+        layers.raise(0);
+        assert_eq!(
+            layers.color(&base, &point_info),
+            BlendMode::Multiply.blend(base_color, edge_color, 1.)
+        );
+        layers.iter_mut().for_each(|layer| layer.visible = false);
+        assert!(!layers.has_visible_layers());
+        assert_eq!(layers.color(&base, &point_info), base_color);
+        assert!(layers.remove(2).is_none());
+        assert!(layers.remove(0).is_some());
+        assert_eq!(layers.len(), 1);
+    }
+
+    #[test]
+    fn custom_outcoloring()
+    {
+        use crate::{outcoloring, Coloring, OutcoloringAlgorithm, Palette};
+        use dynamo_common::prelude::*;
+        use crate::types::Rgba8;
+
+        /// Red where the potential is above a threshold, and on attracting cycles with negative
+        /// real multiplier.
+        struct Threshold;
+        impl OutcoloringAlgorithm for Threshold
+        {
+            fn name(&self) -> &'static str
+            {
+                "Threshold"
+            }
+            fn color(&self, _palette: &Palette, point_info: &PointInfo<Cplx>) -> Option<Rgba8>
+            {
+                match point_info {
+                    PointInfo::Escaping { potential, .. } if *potential > 10. => {
+                        Some(Rgba8::RED)
+                    }
+                    PointInfo::Periodic(data) if data.multiplier.re < 0. => Some(Rgba8::RED),
+                    _ => None,
+                }
+            }
+        }
+
+        outcoloring::register(Threshold);
+        let threshold = outcoloring::find("Threshold");
+        assert!(threshold.is_some());
+        assert!(outcoloring::registered().contains(threshold.as_ref().unwrap()));
+
+        let mut coloring = Coloring::default();
+        let built_in = coloring.clone();
+        coloring.set_outcoloring(threshold);
+        assert_ne!(coloring, built_in);
+
+        let escaping = |potential| PointInfo::<Real>::Escaping {
+            potential,
+            phase: None,
+            angle: None,
+        };
+        let periodic = |multiplier| {
+            PointInfo::Periodic(PointInfoPeriodic {
+                preperiod: 10,
+                period: 2,
+                multiplier,
+                final_error: 0.,
+                smooth_preperiod: 10.,
+                cycle_point: ZERO,
+            })
+        };
+        let color = |coloring: &Coloring, point_info: &PointInfo<Real>| -> Rgba8 {
+            coloring.map(point_info)
+        };
+
+        assert_eq!(color(&coloring, &escaping(20.)), Rgba8::RED);
+        assert_eq!(color(&coloring, &periodic(-0.5)), Rgba8::RED);
+        for point_info in [escaping(5.), periodic(0.5), PointInfo::Bounded] {
+            assert_eq!(color(&coloring, &point_info), color(&built_in, &point_info));
+        }
+
+        assert!(outcoloring::unregister("Threshold"));
+        assert!(outcoloring::find("Threshold").is_none());
+    }
+
+    #[test]
+    fn lighting()
+    {
+        use crate::lighting::Lighting;
+        use dynamo_common::prelude::*;
+
+        let bounds = Bounds {
+            min_x: 0.,
+            max_x: 3.,
+            min_y: 0.,
+            max_y: 3.,
+        };
+        // Rising to the right, except for a flat point in the middle
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(3, 3, bounds));
+        for ((x, _), point_info) in iter_plane.iter_counts.indexed_iter_mut() {
+            *point_info = PointInfo::Escaping {
+                potential: (x as f64).exp(),
+                phase: None,
+                angle: None,
+            };
+        }
+        iter_plane.iter_counts[(1, 1)] = PointInfo::Bounded;
+
+        let mut lighting = Lighting::default();
+        assert!(lighting.brightness(&iter_plane).is_none());
+        lighting.enabled = true;
+
+        // Lit from the left, the slope faces the light
+        lighting.azimuth = 180.;
+        let brightness = lighting.brightness(&iter_plane).unwrap();
+        assert!((brightness[(1, 1)] - 1.).abs() < f32::EPSILON);
+        assert!(brightness[(0, 0)] > 1.);
+
+        // Lit from the right, it faces away
+        lighting.azimuth = 0.;
+        let brightness = lighting.brightness(&iter_plane).unwrap();
+        assert!(brightness[(0, 0)] < 1.);
+        assert!(brightness[(0, 0)] >= 1. - lighting.intensity);
+
+        let mut rgb = [100, 200, 0];
+        Lighting::shade(&mut rgb, 1.5);
+        assert_eq!(rgb, [150, 255, 0]);
+    }
+
+    #[cfg(feature = "epaint")]
+    #[test]
+    fn render_rows()
+    {
+        use crate::fractal_image::FractalImage;
+        use crate::layers::LayerStack;
+        use crate::Coloring;
+        use dynamo_common::prelude::*;
+        use epaint::{Color32, ColorImage};
+
+        let bounds = Bounds::centered_square(1.);
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(4, 6, bounds));
+        for ((x, y), point_info) in iter_plane.iter_counts.indexed_iter_mut() {
+            *point_info = PointInfo::Escaping {
+                potential: (x + 4 * y) as f64,
+                phase: None,
+                angle: None,
+            };
+        }
+        let coloring = Coloring::default();
+        let layers = LayerStack::default();
+        let whole = iter_plane.render(&coloring);
+
+        // Rows of the plane count up from the bottom of the image
+        let mut image = ColorImage::new([4, 6], Color32::TRANSPARENT);
+        iter_plane.render_rows_into(&mut image, &coloring, &layers, 0..2);
+        assert_eq!(image.pixels[16..], whole.pixels[16..]);
+        assert!(image.pixels[..16].iter().all(|&pixel| pixel == Color32::TRANSPARENT));
+
+        iter_plane.render_rows_into(&mut image, &coloring, &layers, 2..6);
+        assert_eq!(image.pixels, whole.pixels);
+    }
+
+    #[test]
+    fn snapshot_hashes()
+    {
+        use crate::snapshot::*;
+        use image::{Rgb, RgbImage};
+
+        // Left half dark, right half light
+        let image = RgbImage::from_fn(16, 16, |x, _| {
+            if x < 8 {
+                Rgb([10, 20, 30])
+            } else {
+                Rgb([200, 100, 50])
+            }
+        });
+        let hash = ImageHash::of(&image);
+        assert_eq!(hash.mean, [105, 60, 40]);
+        assert_eq!(hash.bits, [0xf0f0_f0f0_f0f0_f0f0; 3]);
+        assert_eq!(hash.to_string().parse::<ImageHash>(), Ok(hash));
+
+        let mut snapshots = Snapshots::default();
+        snapshots.insert("halves", hash);
+        let snapshots = Snapshots::parse(&format!("# comment\n{snapshots}")).unwrap();
+        assert_eq!(snapshots.check("halves", &hash, SnapshotTolerance::EXACT), Ok(()));
+
+        let mut noisy = image.clone();
+        noisy.put_pixel(0, 0, Rgb([255, 255, 255]));
+        let noisy = ImageHash::of(&noisy);
+        assert_ne!(noisy, hash);
+        assert!(snapshots
+            .check("halves", &noisy, SnapshotTolerance::default())
+            .is_ok());
+
+        let flipped = ImageHash::of(&image::imageops::flip_horizontal(&image));
+        assert!(matches!(
+            snapshots.check("halves", &flipped, SnapshotTolerance::default()),
+            Err(SnapshotError::Mismatch { .. })
+        ));
+        assert_eq!(
+            snapshots.check("missing", &hash, SnapshotTolerance::default()),
+            Err(SnapshotError::Missing("missing".to_owned()))
+        );
+    }
 }
@@ -0,0 +1,102 @@
+//! Shading of the image as a relief lit from one side, which gives it an embossed look.
+
+use dynamo_common::prelude::*;
+use ndarray::Array2;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Width of the image, in pixels, at which the slopes of the relief are its differences in height
+/// between adjacent pixels. Images of other widths are shaded alike.
+const REFERENCE_WIDTH: Real = 768.;
+/// Lowest elevation of the light, in degrees, below which the image is all but dark.
+const MIN_ELEVATION: f32 = 5.;
+
+/// A distant light shining on the image as if it were a relief, whose height is the value of
+/// each point on the palette. Slopes facing the light are brightened and those facing away are
+/// darkened, following Lambert's law, while flat regions keep their colors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lighting
+{
+    pub enabled: bool,
+    /// Direction from which the light comes, in degrees counterclockwise from the right of the
+    /// image.
+    pub azimuth: f32,
+    /// Angle of the light above the image, in degrees.
+    pub elevation: f32,
+    /// Fraction of the color that is shaded, between 0 and 1.
+    pub intensity: f32,
+    /// Factor by which the heights of the relief are exaggerated.
+    pub relief: f32,
+}
+
+impl Default for Lighting
+{
+    fn default() -> Self
+    {
+        Self {
+            enabled: false,
+            azimuth: 135.,
+            elevation: 45.,
+            intensity: 0.7,
+            relief: 40.,
+        }
+    }
+}
+
+impl Lighting
+{
+    /// Height of the relief at a point colored by its potential or distance estimate, which is
+    /// the value mapped to the palette. Other points have no height.
+    #[must_use]
+    pub fn height<D>(point_info: &PointInfo<D>) -> Option<Real>
+    {
+        match point_info {
+            PointInfo::Escaping { potential, .. } => Some(potential.ln()),
+            PointInfo::DistanceEstimate { distance, .. } => Some(-distance.ln() / 2.),
+            _ => None,
+        }
+    }
+
+    /// Factor by which to brighten the color of each point of the plane, indexed like the
+    /// points, or `None` if the light is off. Points without a height are left as they are.
+    #[must_use]
+    pub fn brightness<D>(&self, iter_plane: &IterPlane<D>) -> Option<Array2<f32>>
+    {
+        if !self.enabled {
+            return None;
+        }
+        let scale =
+            Real::from(self.relief) * iter_plane.point_grid.res_x as Real / REFERENCE_WIDTH;
+        let azimuth = Real::from(self.azimuth).to_radians();
+        let elevation = Real::from(self.elevation.clamp(MIN_ELEVATION, 90.)).to_radians();
+        let light = [
+            elevation.cos() * azimuth.cos(),
+            elevation.cos() * azimuth.sin(),
+            elevation.sin(),
+        ];
+        let intensity = self.intensity.clamp(0., 1.);
+
+        Some(iter_plane.gradient(Self::height).map(|gradient| {
+            let Some([dx, dy]) = *gradient else {
+                return 1.;
+            };
+            let normal = [-scale * dx, -scale * dy, 1.];
+            let norm = normal.iter().map(|n| n * n).sum::<Real>().sqrt();
+            let lambert = normal.iter().zip(light).map(|(n, l)| n * l).sum::<Real>() / norm;
+            // Relative to a flat surface, which is lit by the vertical component of the light
+            let shade = (lambert.max(0.) / light[2]) as f32;
+            intensity.mul_add(shade - 1., 1.)
+        }))
+    }
+
+    /// Scale the red, green and blue channels of a color by a brightness, saturating at full
+    /// intensity.
+    pub fn shade(rgb: &mut [u8], brightness: f32)
+    {
+        for channel in rgb {
+            *channel = (f32::from(*channel) * brightness).round().clamp(0., 255.) as u8;
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Colorings of escaping points defined outside this crate, which are consulted before the
+//! built-in coloring of each point.
+
+use crate::Palette;
+use dynamo_common::prelude::*;
+use crate::types::Rgba8;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// A user-defined coloring, such as one written by a downstream crate or a script.
+///
+/// Multipliers of cycles are given as complex numbers, whatever the type of derivative of the
+/// plane, so that a single implementation serves every plane.
+pub trait OutcoloringAlgorithm: Send + Sync
+{
+    /// Name shown in menus, which also identifies the algorithm in the registry.
+    fn name(&self) -> &str;
+
+    /// Color of the point, or `None` to leave it to the built-in coloring.
+    fn color(&self, palette: &Palette, point_info: &PointInfo<Cplx>) -> Option<Rgba8>;
+}
+
+/// An outcoloring algorithm shared between colorings. Two are equal if they have the same name.
+#[derive(Clone)]
+pub struct CustomOutcoloring(pub Arc<dyn OutcoloringAlgorithm>);
+
+impl CustomOutcoloring
+{
+    #[must_use]
+    pub fn name(&self) -> &str
+    {
+        self.0.name()
+    }
+
+    /// Color of the point, if the algorithm colors it.
+    #[must_use]
+    pub fn color<D>(&self, palette: &Palette, point_info: &PointInfo<D>) -> Option<Rgba8>
+    where
+        D: Polar<Real>,
+    {
+        // The norm of a real multiplier keeps its sign, which is already carried by the argument
+        let point_info = point_info.map_multiplier(|m| Cplx::from_polar(m.norm().abs(), m.arg()));
+        self.0.color(palette, &point_info)
+    }
+}
+
+impl PartialEq for CustomOutcoloring
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        Arc::ptr_eq(&self.0, &other.0) || self.name() == other.name()
+    }
+}
+
+impl std::fmt::Debug for CustomOutcoloring
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.debug_tuple("CustomOutcoloring")
+            .field(&self.name())
+            .finish()
+    }
+}
+
+static REGISTRY: RwLock<Vec<CustomOutcoloring>> = RwLock::new(Vec::new());
+
+/// Make an algorithm available to every pane, replacing any registered under the same name.
+pub fn register<A>(algorithm: A)
+where
+    A: OutcoloringAlgorithm + 'static,
+{
+    let algorithm = CustomOutcoloring(Arc::new(algorithm));
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    registry.retain(|other| other.name() != algorithm.name());
+    registry.push(algorithm);
+}
+
+/// Remove the algorithm registered under the given name. Returns false if there is none.
+pub fn unregister(name: &str) -> bool
+{
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    let len = registry.len();
+    registry.retain(|algorithm| algorithm.name() != name);
+    registry.len() < len
+}
+
+/// The algorithms registered so far, in the order of registration.
+#[must_use]
+pub fn registered() -> Vec<CustomOutcoloring>
+{
+    REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+#[must_use]
+pub fn find(name: &str) -> Option<CustomOutcoloring>
+{
+    REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .find(|algorithm| algorithm.name() == name)
+        .cloned()
+}
+
+/// Serialization of the outcoloring of a coloring by the name of its algorithm. Names that are
+/// not registered when the coloring is read are dropped.
+#[cfg(feature = "serde")]
+pub(crate) mod by_name
+{
+    use super::{find, CustomOutcoloring};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(
+        outcoloring: &Option<CustomOutcoloring>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match outcoloring {
+            Some(algorithm) => serializer.serialize_some(algorithm.name()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<CustomOutcoloring>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name: Option<String> = Option::deserialize(deserializer)?;
+        Ok(name.as_deref().and_then(find))
+    }
+}
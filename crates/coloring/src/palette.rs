@@ -4,9 +4,10 @@ use super::Hsv;
 use dynamo_common::consts::TAU;
 use dynamo_common::types::IterCountSmooth;
 use dynamo_common::{symbolic_dynamics::OrbitSchema, types::Period};
-use egui::Color32;
+use crate::types::Rgba8;
 use rand::prelude::*;
 use rand_distr::{ChiSquared, Distribution, Uniform};
+use std::collections::VecDeque;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -28,7 +29,7 @@ pub struct Sinusoid
     phase: f64,
     amplitude: f64,
     midline: f64,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     degree: i32,
 }
 impl Sinusoid
@@ -107,25 +108,25 @@ impl Default for Sinusoid
 
 mod defaults
 {
-    use egui::Color32;
-    pub(super) const fn white() -> Color32
+    use crate::types::Rgba8;
+    pub(super) const fn white() -> Rgba8
     {
-        Color32::WHITE
+        Rgba8::WHITE
     }
 
-    pub(super) const fn black() -> Color32
+    pub(super) const fn black() -> Rgba8
     {
-        Color32::BLACK
+        Rgba8::BLACK
     }
 
-    pub(super) const fn gray() -> Color32
+    pub(super) const fn gray() -> Rgba8
     {
-        Color32::GRAY
+        Rgba8::GRAY
     }
 
-    pub(super) const fn brown() -> Color32
+    pub(super) const fn brown() -> Rgba8
     {
-        Color32::BROWN
+        Rgba8::BROWN
     }
 }
 
@@ -139,11 +140,11 @@ pub struct Palette
     #[cfg_attr(feature = "serde", serde(default = "DiscretePalette::default"))]
     pub period_coloring: DiscretePalette,
     #[cfg_attr(feature = "serde", serde(default = "defaults::black"))]
-    pub in_color: Color32,
+    pub in_color: Rgba8,
     #[cfg_attr(feature = "serde", serde(default = "defaults::brown"))]
-    pub wandering_color: Color32,
+    pub wandering_color: Rgba8,
     #[cfg_attr(feature = "serde", serde(default = "defaults::gray"))]
-    pub unknown_color: Color32,
+    pub unknown_color: Rgba8,
     #[cfg_attr(feature = "serde", serde(default = "CartesianColorSpace::default"))]
     pub color_space: CartesianColorSpace,
 }
@@ -158,9 +159,9 @@ impl Palette
             color_map_g: Sinusoid::new(period_g),
             color_map_b: Sinusoid::new(period_b),
             period_coloring: DiscretePalette::standard(),
-            in_color: Color32::BLACK,
-            wandering_color: Color32::BROWN,
-            unknown_color: Color32::GRAY,
+            in_color: Rgba8::BLACK,
+            wandering_color: Rgba8::BROWN,
+            unknown_color: Rgba8::GRAY,
             color_space: CartesianColorSpace::Rgb,
         }
     }
@@ -174,9 +175,9 @@ impl Palette
             color_map_g: color_map,
             color_map_b: color_map,
             period_coloring: DiscretePalette::standard(),
-            in_color: Color32::BLACK,
-            wandering_color: Color32::BROWN,
-            unknown_color: Color32::GRAY,
+            in_color: Rgba8::BLACK,
+            wandering_color: Rgba8::BROWN,
+            unknown_color: Rgba8::GRAY,
             color_space: CartesianColorSpace::Rgb,
         }
     }
@@ -196,9 +197,9 @@ impl Palette
             color_map_g: color_map,
             color_map_b: color_map,
             period_coloring: DiscretePalette::standard(),
-            in_color: Color32::WHITE,
-            wandering_color: Color32::BROWN,
-            unknown_color: Color32::GRAY,
+            in_color: Rgba8::WHITE,
+            wandering_color: Rgba8::BROWN,
+            unknown_color: Rgba8::GRAY,
             color_space: CartesianColorSpace::Rgb,
         }
     }
@@ -206,16 +207,19 @@ impl Palette
     #[must_use]
     pub fn new_random(contrast: f64, brightness: f64) -> Self
     {
-        let mut rng = thread_rng();
+        Self::random_from(&mut thread_rng(), contrast, brightness)
+    }
 
-        let phase_r = Uniform::new(0., 1.).sample(&mut rng);
-        let phase_g = Uniform::new(0., 1.).sample(&mut rng);
-        let phase_b = Uniform::new(0., 1.).sample(&mut rng);
+    fn random_from<R: Rng>(rng: &mut R, contrast: f64, brightness: f64) -> Self
+    {
+        let phase_r = Uniform::new(0., 1.).sample(rng);
+        let phase_g = Uniform::new(0., 1.).sample(rng);
+        let phase_b = Uniform::new(0., 1.).sample(rng);
 
         ChiSquared::new(4.).map_or(Self::black(16.), |period_dist| {
-            let period_r: f64 = period_dist.sample(&mut rng);
-            let period_g: f64 = period_dist.sample(&mut rng);
-            let period_b: f64 = period_dist.sample(&mut rng);
+            let period_r: f64 = period_dist.sample(rng);
+            let period_g: f64 = period_dist.sample(rng);
+            let period_b: f64 = period_dist.sample(rng);
 
             Self::new(period_r, period_g, period_b)
                 .with_phases(phase_r, phase_g, phase_b)
@@ -224,6 +228,36 @@ impl Palette
         })
     }
 
+    /// A palette oscillating between a light color of hue `light_hue` and a dark color of hue
+    /// `dark_hue`, whose brightnesses are `contrast` apart around `brightness`.
+    fn random_ramp<R: Rng>(
+        rng: &mut R,
+        light_hue: f64,
+        dark_hue: f64,
+        contrast: f64,
+        brightness: f64,
+    ) -> Self
+    {
+        let period: f64 = ChiSquared::new(4.).map_or(8., |period_dist| period_dist.sample(rng));
+        let phase = Uniform::new(0., 1.).sample(rng);
+        let light = hsv_to_rgb(light_hue, 0.75, (brightness + contrast / 2.).min(1.));
+        let dark = hsv_to_rgb(dark_hue, 0.75, (brightness - contrast / 2.).max(0.));
+
+        let ramp = |channel: usize| Sinusoid {
+            period: period.max(0.5),
+            phase,
+            amplitude: (light[channel] - dark[channel]) / 2.,
+            midline: (light[channel] + dark[channel]) / 2.,
+            degree: 1,
+        };
+        Self {
+            color_map_r: ramp(0),
+            color_map_g: ramp(1),
+            color_map_b: ramp(2),
+            ..Self::new(period, period, period)
+        }
+    }
+
     #[must_use]
     pub const fn with_phases(mut self, phase_r: f64, phase_g: f64, phase_b: f64) -> Self
     {
@@ -292,6 +326,7 @@ impl Palette
         t: IterCountSmooth,
         phase: Period,
         esc_period: Period,
+        offset: f32,
     ) -> T
     {
         if esc_period > 1 {
@@ -299,6 +334,7 @@ impl Palette
             let b = self.color_map_b.get_value_f64(t) as f32;
             DiscretePalette::default()
                 .with_num_colors(esc_period as f32)
+                .with_hue_offset(offset)
                 // .map_hsv(phase as f32, 1.0)
                 // .with_intensity(r)
                 // .with_saturation(b)
@@ -326,6 +362,181 @@ impl Palette
     }
 }
 
+/// Channels of a color given by hue, saturation and value, each between 0 and 1.
+fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> [f64; 3]
+{
+    let channel = |n: f64| {
+        let k = (n + hue.rem_euclid(1.) * 6.) % 6.;
+        value - value * saturation * k.min(4. - k).clamp(0., 1.)
+    };
+    [channel(5.), channel(3.), channel(1.)]
+}
+
+/// Constraints on the palettes drawn by a [`PaletteGenerator`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PaletteConstraints
+{
+    /// Arc of hues, as fractions of a turn, running counterclockwise from the first to the
+    /// second and possibly across red. Equal ends allow every hue.
+    pub hue_range: (f64, f64),
+    /// Difference in brightness between the lightest and darkest colors, between 0 and 1.
+    pub contrast: f64,
+    pub brightness: f64,
+    /// Draw only ramps from orange to blue, which remain distinct under the common forms of
+    /// color blindness. This takes precedence over the hue range.
+    pub colorblind_safe: bool,
+}
+
+impl PaletteConstraints
+{
+    pub const UNCONSTRAINED: Self = Self {
+        hue_range: (0., 1.),
+        contrast: 0.45,
+        brightness: 0.38,
+        colorblind_safe: false,
+    };
+
+    /// Smallest contrast used for colorblind-safe palettes, which rely on brightness more than
+    /// hue to tell colors apart.
+    const MIN_SAFE_CONTRAST: f64 = 0.5;
+    const SAFE_LIGHT_HUES: (f64, f64) = (0.06, 0.13);
+    const SAFE_DARK_HUES: (f64, f64) = (0.56, 0.66);
+
+    /// Width of the arc of allowed hues.
+    #[must_use]
+    pub fn hue_width(&self) -> f64
+    {
+        arc_width(self.hue_range)
+    }
+
+    #[must_use]
+    pub fn allows_every_hue(&self) -> bool
+    {
+        !self.colorblind_safe && self.hue_width() >= 1.
+    }
+
+    fn sample_hue<R: Rng>(rng: &mut R, arc: (f64, f64)) -> f64
+    {
+        (arc.0 + rng.gen_range(0.0..arc_width(arc))).rem_euclid(1.)
+    }
+}
+
+/// Width of the arc of hues running counterclockwise from `start` to `end`, taken to be the
+/// whole circle if they are equal.
+fn arc_width((start, end): (f64, f64)) -> f64
+{
+    let width = (end - start).rem_euclid(1.);
+    if width == 0. {
+        1.
+    } else {
+        width
+    }
+}
+
+impl Default for PaletteConstraints
+{
+    fn default() -> Self
+    {
+        Self::UNCONSTRAINED
+    }
+}
+
+/// A seed drawn by a [`PaletteGenerator`], together with the constraints it was drawn under,
+/// from which the palette can be drawn again.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PaletteSeed
+{
+    pub seed: u64,
+    pub constraints: PaletteConstraints,
+}
+
+impl PaletteSeed
+{
+    /// The palette drawn from this seed, which is the same every time.
+    #[must_use]
+    pub fn palette(&self) -> Palette
+    {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let PaletteConstraints {
+            hue_range,
+            contrast,
+            brightness,
+            colorblind_safe,
+        } = self.constraints;
+
+        if colorblind_safe {
+            let light_hue =
+                PaletteConstraints::sample_hue(&mut rng, PaletteConstraints::SAFE_LIGHT_HUES);
+            let dark_hue =
+                PaletteConstraints::sample_hue(&mut rng, PaletteConstraints::SAFE_DARK_HUES);
+            let contrast = contrast.max(PaletteConstraints::MIN_SAFE_CONTRAST);
+            Palette::random_ramp(&mut rng, light_hue, dark_hue, contrast, brightness)
+        } else if self.constraints.allows_every_hue() {
+            Palette::random_from(&mut rng, contrast, brightness)
+        } else {
+            let light_hue = PaletteConstraints::sample_hue(&mut rng, hue_range);
+            let dark_hue = PaletteConstraints::sample_hue(&mut rng, hue_range);
+            Palette::random_ramp(&mut rng, light_hue, dark_hue, contrast, brightness)
+        }
+    }
+}
+
+/// Draws random palettes under a set of constraints, remembering the seeds of the most recent
+/// ones so that a palette found by chance can be recovered.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PaletteGenerator
+{
+    pub constraints: PaletteConstraints,
+    history: VecDeque<PaletteSeed>,
+}
+
+impl PaletteGenerator
+{
+    /// Number of seeds kept in the history.
+    pub const HISTORY_LEN: usize = 16;
+
+    #[must_use]
+    pub fn new(constraints: PaletteConstraints) -> Self
+    {
+        Self {
+            constraints,
+            history: VecDeque::with_capacity(Self::HISTORY_LEN),
+        }
+    }
+
+    /// Draw a palette from a fresh seed, and record the seed.
+    pub fn generate(&mut self) -> Palette
+    {
+        self.generate_from_seed(thread_rng().gen())
+    }
+
+    /// Draw the palette for a given seed under the current constraints, and record the seed.
+    pub fn generate_from_seed(&mut self, seed: u64) -> Palette
+    {
+        let seed = PaletteSeed {
+            seed,
+            constraints: self.constraints,
+        };
+        self.history.push_front(seed);
+        self.history.truncate(Self::HISTORY_LEN);
+        seed.palette()
+    }
+
+    /// Seeds drawn so far, most recent first.
+    pub fn history(&self) -> impl Iterator<Item = &PaletteSeed>
+    {
+        self.history.iter()
+    }
+
+    pub fn clear_history(&mut self)
+    {
+        self.history.clear();
+    }
+}
+
 impl Default for Palette
 {
     fn default() -> Self
@@ -334,6 +545,95 @@ impl Default for Palette
     }
 }
 
+/// A color given to every cycle of a period, in place of the one the palette would give it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PeriodColor
+{
+    pub period: Period,
+    pub color: Hsv,
+}
+
+/// Colors fixed for particular periods. There is room for a fixed number of them, so that
+/// palettes can be copied freely; they are stored as a list in palette files.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(from = "Vec<PeriodColor>", into = "Vec<PeriodColor>")
+)]
+pub struct PeriodColors([Option<PeriodColor>; Self::CAPACITY]);
+
+impl PeriodColors
+{
+    pub const CAPACITY: usize = 16;
+
+    #[must_use]
+    pub fn get(&self, period: Period) -> Option<Hsv>
+    {
+        self.iter().find(|o| o.period == period).map(|o| o.color)
+    }
+
+    /// Fix the color of a period, replacing any color already fixed for it. Returns false if
+    /// there is no room left.
+    pub fn set(&mut self, period: Period, color: Hsv) -> bool
+    {
+        self.remove(period);
+        let Some(slot) = self.0.iter_mut().find(|slot| slot.is_none()) else {
+            return false;
+        };
+        *slot = Some(PeriodColor { period, color });
+        true
+    }
+
+    pub fn remove(&mut self, period: Period)
+    {
+        for slot in &mut self.0 {
+            if slot.is_some_and(|o| o.period == period) {
+                *slot = None;
+            }
+        }
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.0 = [None; Self::CAPACITY];
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PeriodColor>
+    {
+        self.0.iter().flatten()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.iter().next().is_none()
+    }
+}
+
+impl From<Vec<PeriodColor>> for PeriodColors
+{
+    fn from(colors: Vec<PeriodColor>) -> Self
+    {
+        let mut period_colors = Self::default();
+        for PeriodColor { period, color } in colors {
+            period_colors.set(period, color);
+        }
+        period_colors
+    }
+}
+
+impl From<PeriodColors> for Vec<PeriodColor>
+{
+    fn from(period_colors: PeriodColors) -> Self
+    {
+        let mut colors: Self = period_colors.iter().copied().collect();
+        colors.sort_by_key(|o| o.period);
+        colors
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DiscretePalette
@@ -342,6 +642,10 @@ pub struct DiscretePalette
     pub base_hue: f32,
     pub saturation: f32,
     pub luminosity: f32,
+    /// Colors fixed for particular periods, which stay the same when the rest of the palette
+    /// changes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub overrides: PeriodColors,
 }
 
 impl DiscretePalette
@@ -359,6 +663,7 @@ impl DiscretePalette
             base_hue: Self::DEFAULT_BASE_HUE,
             saturation: Self::DEFAULT_SATURATION,
             luminosity: Self::DEFAULT_LUMINOSITY,
+            overrides: PeriodColors([None; PeriodColors::CAPACITY]),
         }
     }
 
@@ -369,9 +674,68 @@ impl DiscretePalette
         self
     }
 
+    /// Rotate the hues of the palette by a fraction of a turn.
+    #[must_use]
+    pub fn with_hue_offset(mut self, offset: f32) -> Self
+    {
+        self.base_hue = (self.base_hue + offset).rem_euclid(1.);
+        self
+    }
+
+    /// Fix the color of every cycle of the given period, replacing any color already fixed.
+    #[must_use]
+    pub fn with_period_color(mut self, period: Period, color: Hsv) -> Self
+    {
+        self.overrides.set(period, color);
+        self
+    }
+
+    /// Fix the color of every cycle of the given period. Returns false if the colors of as
+    /// many periods as there is room for are already fixed.
+    pub fn set_period_color(&mut self, period: Period, color: Hsv) -> bool
+    {
+        self.overrides.set(period, color)
+    }
+
+    pub fn clear_period_color(&mut self, period: Period)
+    {
+        self.overrides.remove(period);
+    }
+
+    /// The color fixed for a period, if any.
+    #[must_use]
+    pub fn period_color(&self, period: Period) -> Option<Hsv>
+    {
+        self.overrides.get(period)
+    }
+
+    /// Fix the colors of the periods up to `max_period` at those the palette currently gives
+    /// them, as far as there is room.
+    pub fn pin_periods(&mut self, max_period: Period)
+    {
+        for period in 1..=max_period {
+            if self.period_color(period).is_none() {
+                let color = self.map_hsv(period as f32, 1.);
+                if !self.overrides.set(period, color) {
+                    break;
+                }
+            }
+        }
+    }
+
     #[must_use]
     fn map_hsv(&self, period: f32, luminosity_modifier: f32) -> Hsv
     {
+        // Only whole periods have fixed colors; other hues index a continuous scale
+        if period.fract() == 0. && period >= 1. {
+            if let Some(color) = self.period_color(period as Period) {
+                return Hsv {
+                    intensity: color.intensity * luminosity_modifier,
+                    ..color
+                };
+            }
+        }
+
         let hue = (period / self.num_colors + self.base_hue) % 1.;
 
         Hsv {
@@ -431,6 +795,7 @@ impl DiscretePalette
             base_hue: Self::DEFAULT_BASE_HUE,
             saturation: 1.,
             luminosity: 0.,
+            overrides: PeriodColors([None; PeriodColors::CAPACITY]),
         }
     }
 
@@ -442,6 +807,65 @@ impl DiscretePalette
             base_hue: Self::DEFAULT_BASE_HUE,
             saturation: 0.,
             luminosity: 1.,
+            overrides: PeriodColors([None; PeriodColors::CAPACITY]),
+        }
+    }
+
+    /// Colors of the scheme of Okabe and Ito, which stay distinct under the common forms of
+    /// color blindness, fixed for periods 1 through 7.
+    #[must_use]
+    pub const fn okabe_ito() -> Self
+    {
+        Self::from_sequence(&[
+            Hsv::new(0.115, 1., 0.902), // orange
+            Hsv::new(0.560, 0.631, 0.914), // sky blue
+            Hsv::new(0.455, 1., 0.620), // bluish green
+            Hsv::new(0.155, 0.725, 0.941), // yellow
+            Hsv::new(0.560, 1., 0.698), // blue
+            Hsv::new(0.074, 1., 0.835), // vermilion
+            Hsv::new(0.908, 0.407, 0.800), // reddish purple
+        ])
+    }
+
+    /// Paul Tol's bright qualitative scheme, which is likewise safe for color blind viewers,
+    /// fixed for periods 1 through 7.
+    #[must_use]
+    pub const fn tol_bright() -> Self
+    {
+        Self::from_sequence(&[
+            Hsv::new(0.583, 0.600, 0.667), // blue
+            Hsv::new(0.979, 0.571, 0.933), // red
+            Hsv::new(0.361, 0.750, 0.533), // green
+            Hsv::new(0.146, 0.667, 0.800), // yellow
+            Hsv::new(0.542, 0.571, 0.933), // cyan
+            Hsv::new(0.905, 0.700, 0.667), // purple
+            Hsv::new(0., 0., 0.733), // grey
+        ])
+    }
+
+    /// Curated palettes whose colors remain distinguishable under color blindness.
+    pub const COLORBLIND_SAFE: [(&'static str, Self); 2] = [
+        ("Okabe-Ito", Self::okabe_ito()),
+        ("Tol bright", Self::tol_bright()),
+    ];
+
+    /// The standard palette, with the given colors fixed for periods 1, 2, 3, and so on.
+    /// Longer periods keep the standard hues.
+    #[allow(clippy::cast_possible_truncation)]
+    const fn from_sequence(colors: &[Hsv]) -> Self
+    {
+        let mut overrides = [None; PeriodColors::CAPACITY];
+        let mut i = 0;
+        while i < colors.len() && i < PeriodColors::CAPACITY {
+            overrides[i] = Some(PeriodColor {
+                period: (i + 1) as Period,
+                color: colors[i],
+            });
+            i += 1;
+        }
+        Self {
+            overrides: PeriodColors(overrides),
+            ..Self::standard()
         }
     }
 }
@@ -1,4 +1,7 @@
 pub use crate::algorithms::IncoloringAlgorithm;
-pub use crate::fractal_image::FractalImage;
+pub use crate::fractal_image::{ColorVisionFilter, FractalImage};
+pub use crate::layers::{BlendMode, Layer, LayerStack};
+pub use crate::lighting::Lighting;
 pub use crate::palette::Palette;
-pub use crate::Coloring;
+pub use crate::types::Rgba8;
+pub use crate::{Coloring, Transparency};
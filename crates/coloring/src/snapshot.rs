@@ -0,0 +1,243 @@
+//! Perceptual hashes of rendered images, stored by name so that changes to the orbit or coloring
+//! pipeline that alter the output are caught by tests.
+//!
+//! Each image is reduced to an [`ImageHash`]: for every color channel, one bit per cell of an
+//! 8x8 grid recording whether the cell is brighter than the channel's mean, along with the mean
+//! color itself. Hashes are compared with a [`SnapshotTolerance`], so that floating-point noise
+//! between platforms does not fail the tests.
+
+use image::{Rgb, RgbImage};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+const GRID_SIZE: u32 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageHash
+{
+    /// Bits of the 8x8 grid in row-major order, for each of the red, green and blue channels.
+    pub bits: [u64; 3],
+    pub mean: [u8; 3],
+}
+
+impl ImageHash
+{
+    #[must_use]
+    pub fn of(image: &RgbImage) -> Self
+    {
+        let (width, height) = image.dimensions();
+        let cells = (GRID_SIZE * GRID_SIZE) as usize;
+        let mut sums = vec![[0_u64; 3]; cells];
+        let mut counts = vec![0_u64; cells];
+
+        for (x, y, Rgb(rgb)) in image.enumerate_pixels() {
+            let cell_x = x * GRID_SIZE / width.max(1);
+            let cell_y = y * GRID_SIZE / height.max(1);
+            let cell = (cell_y * GRID_SIZE + cell_x) as usize;
+            for (sum, &value) in sums[cell].iter_mut().zip(rgb) {
+                *sum += u64::from(value);
+            }
+            counts[cell] += 1;
+        }
+
+        let cell_means: Vec<[f64; 3]> = sums
+            .iter()
+            .zip(&counts)
+            .map(|(sum, &count)| sum.map(|s| s as f64 / count.max(1) as f64))
+            .collect();
+        let mut mean = [0.; 3];
+        for cell_mean in &cell_means {
+            for (m, c) in mean.iter_mut().zip(cell_mean) {
+                *m += c / cells as f64;
+            }
+        }
+
+        let mut bits = [0; 3];
+        for (cell, cell_mean) in cell_means.iter().enumerate() {
+            for channel in 0..3 {
+                if cell_mean[channel] > mean[channel] {
+                    bits[channel] |= 1 << cell;
+                }
+            }
+        }
+
+        Self {
+            bits,
+            mean: mean.map(|m| m.round() as u8),
+        }
+    }
+
+    /// Number of grid cells whose bits differ, and the largest difference in mean color.
+    #[must_use]
+    pub fn distance(&self, other: &Self) -> (u32, u8)
+    {
+        let bits = self
+            .bits
+            .iter()
+            .zip(&other.bits)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum();
+        let color = self
+            .mean
+            .iter()
+            .zip(&other.mean)
+            .map(|(a, b)| a.abs_diff(*b))
+            .max()
+            .unwrap_or(0);
+        (bits, color)
+    }
+}
+
+impl fmt::Display for ImageHash
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let [r, g, b] = self.bits;
+        let [mr, mg, mb] = self.mean;
+        write!(f, "{r:016x}:{g:016x}:{b:016x}:{mr:02x}{mg:02x}{mb:02x}")
+    }
+}
+
+impl FromStr for ImageHash
+{
+    type Err = SnapshotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let invalid = || SnapshotError::Parse(s.to_owned());
+        let parts: Vec<&str> = s.split(':').collect();
+        let [r, g, b, mean] = parts[..] else {
+            return Err(invalid());
+        };
+        let parse_bits = |part: &str| u64::from_str_radix(part, 16).map_err(|_| invalid());
+        let mean = u32::from_str_radix(mean, 16).map_err(|_| invalid())?;
+        if mean > 0xff_ffff {
+            return Err(invalid());
+        }
+        let [_, mr, mg, mb] = mean.to_be_bytes();
+        Ok(Self {
+            bits: [parse_bits(r)?, parse_bits(g)?, parse_bits(b)?],
+            mean: [mr, mg, mb],
+        })
+    }
+}
+
+/// Largest differences between hashes that still count as the same image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapshotTolerance
+{
+    /// Number of differing grid cells, summed over the channels.
+    pub bits: u32,
+    /// Difference in any channel of the mean color.
+    pub color: u8,
+}
+
+impl SnapshotTolerance
+{
+    pub const EXACT: Self = Self { bits: 0, color: 0 };
+}
+
+impl Default for SnapshotTolerance
+{
+    fn default() -> Self
+    {
+        Self { bits: 4, color: 2 }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotError
+{
+    Parse(String),
+    Missing(String),
+    Mismatch
+    {
+        name: String,
+        expected: ImageHash,
+        actual: ImageHash,
+    },
+}
+
+impl fmt::Display for SnapshotError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            Self::Parse(line) => write!(f, "could not parse snapshot: {line}"),
+            Self::Missing(name) => write!(f, "no snapshot stored for {name}"),
+            Self::Mismatch {
+                name,
+                expected,
+                actual,
+            } => write!(f, "{name}: expected {expected}, rendered {actual}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Stored hashes by name, kept as lines of `name hash` with `#` comments.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshots
+{
+    pub hashes: BTreeMap<String, ImageHash>,
+}
+
+impl Snapshots
+{
+    pub fn parse(text: &str) -> Result<Self, SnapshotError>
+    {
+        let hashes = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (name, hash) = line
+                    .split_once(char::is_whitespace)
+                    .ok_or_else(|| SnapshotError::Parse(line.to_owned()))?;
+                Ok((name.to_owned(), hash.trim().parse()?))
+            })
+            .collect::<Result<_, SnapshotError>>()?;
+        Ok(Self { hashes })
+    }
+
+    pub fn insert(&mut self, name: &str, hash: ImageHash)
+    {
+        self.hashes.insert(name.to_owned(), hash);
+    }
+
+    /// Check a rendered image's hash against the one stored under `name`.
+    pub fn check(
+        &self,
+        name: &str,
+        actual: &ImageHash,
+        tolerance: SnapshotTolerance,
+    ) -> Result<(), SnapshotError>
+    {
+        let expected = self
+            .hashes
+            .get(name)
+            .ok_or_else(|| SnapshotError::Missing(name.to_owned()))?;
+        let (bits, color) = expected.distance(actual);
+        if bits > tolerance.bits || color > tolerance.color {
+            return Err(SnapshotError::Mismatch {
+                name: name.to_owned(),
+                expected: *expected,
+                actual: *actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Snapshots
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        for (name, hash) in &self.hashes {
+            writeln!(f, "{name} {hash}")?;
+        }
+        Ok(())
+    }
+}
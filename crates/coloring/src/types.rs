@@ -1,6 +1,5 @@
 use std::f32::consts::TAU;
 
-use egui::Color32;
 use image::{Pixel, Rgb};
 
 #[cfg(feature = "serde")]
@@ -9,6 +8,108 @@ use serde::{Deserialize, Serialize};
 const SIN_60: f32 = 0.866_025_4;
 const TAU_3: f32 = std::f32::consts::TAU / 3.;
 
+/// A color in sRGB with eight bits per channel and premultiplied alpha.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rgba8(pub [u8; 4]);
+
+impl Rgba8
+{
+    pub const TRANSPARENT: Self = Self([0, 0, 0, 0]);
+    pub const BLACK: Self = Self::from_rgb(0, 0, 0);
+    pub const GRAY: Self = Self::from_rgb(160, 160, 160);
+    pub const WHITE: Self = Self::from_rgb(255, 255, 255);
+    pub const BROWN: Self = Self::from_rgb(165, 42, 42);
+    pub const RED: Self = Self::from_rgb(255, 0, 0);
+
+    #[must_use]
+    pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self
+    {
+        Self([r, g, b, 255])
+    }
+
+    #[must_use]
+    pub const fn from_gray(l: u8) -> Self
+    {
+        Self([l, l, l, 255])
+    }
+
+    #[must_use]
+    pub const fn from_rgba_premultiplied(r: u8, g: u8, b: u8, a: u8) -> Self
+    {
+        Self([r, g, b, a])
+    }
+
+    #[must_use]
+    pub const fn r(self) -> u8
+    {
+        self.0[0]
+    }
+
+    #[must_use]
+    pub const fn g(self) -> u8
+    {
+        self.0[1]
+    }
+
+    #[must_use]
+    pub const fn b(self) -> u8
+    {
+        self.0[2]
+    }
+
+    #[must_use]
+    pub const fn a(self) -> u8
+    {
+        self.0[3]
+    }
+
+    #[must_use]
+    pub const fn to_array(self) -> [u8; 4]
+    {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn to_tuple(self) -> (u8, u8, u8, u8)
+    {
+        let [r, g, b, a] = self.0;
+        (r, g, b, a)
+    }
+}
+
+/// Linear intensity in `[0, 1]` of an sRGB channel value.
+#[must_use]
+pub fn linear_from_gamma_u8(s: u8) -> f32
+{
+    if s <= 10 {
+        f32::from(s) / 3294.6
+    } else {
+        ((f32::from(s) + 14.025) / 269.025).powf(2.4)
+    }
+}
+
+/// sRGB channel value of a linear intensity, clamped to `[0, 1]`.
+#[must_use]
+pub fn gamma_u8_from_linear(l: f32) -> u8
+{
+    if l <= 0. {
+        0
+    } else if l <= 0.003_130_8 {
+        round_u8(3294.6 * l)
+    } else if l <= 1. {
+        round_u8(269.025 * l.powf(1. / 2.4) - 14.025)
+    } else {
+        255
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn round_u8(x: f32) -> u8
+{
+    (x + 0.5) as u8
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Hsv
@@ -153,7 +254,7 @@ impl Hsv
         }
     }
 }
-impl From<Hsv> for Color32
+impl From<Hsv> for Rgba8
 {
     fn from(val: Hsv) -> Self
     {
@@ -169,13 +270,13 @@ impl From<Hsv> for Rgb<u8>
         Self(rgb.into())
     }
 }
-impl From<Color32> for Hsv
+impl From<Rgba8> for Hsv
 {
-    fn from(color32: Color32) -> Self
+    fn from(rgba8: Rgba8) -> Self
     {
-        let r = color32.r();
-        let g = color32.g();
-        let b = color32.b();
+        let r = rgba8.r();
+        let g = rgba8.g();
+        let b = rgba8.b();
 
         Self::from_rgb_tuple((r, g, b))
     }
@@ -446,7 +547,7 @@ impl From<Xyz> for RgbLinear
     }
 }
 
-impl From<RgbLinear> for Color32
+impl From<RgbLinear> for Rgba8
 {
     fn from(rgb: RgbLinear) -> Self
     {
@@ -455,7 +556,7 @@ impl From<RgbLinear> for Color32
     }
 }
 
-impl From<Xyz> for Color32
+impl From<Xyz> for Rgba8
 {
     fn from(xyz: Xyz) -> Self
     {
@@ -511,7 +612,7 @@ impl From<Lchab> for Rgb<u8>
     }
 }
 
-impl From<Lchuv> for Color32
+impl From<Lchuv> for Rgba8
 {
     fn from(lch: Lchuv) -> Self
     {
@@ -519,7 +620,7 @@ impl From<Lchuv> for Color32
     }
 }
 
-impl From<Lchab> for Color32
+impl From<Lchab> for Rgba8
 {
     fn from(lch: Lchab) -> Self
     {
@@ -527,40 +628,93 @@ impl From<Lchab> for Color32
     }
 }
 
-pub trait FromColor32
+pub trait FromRgba8
 {
-    fn from_color32(color32: Color32) -> Self;
+    fn from_rgba8(rgba8: Rgba8) -> Self;
 }
-impl FromColor32 for Color32
+impl FromRgba8 for Rgba8
 {
     #[inline]
-    fn from_color32(color32: Color32) -> Self
+    fn from_rgba8(rgba8: Rgba8) -> Self
     {
-        color32
+        rgba8
     }
 }
-impl FromColor32 for Rgb<u8>
+impl FromRgba8 for Rgb<u8>
 {
     #[inline]
-    fn from_color32(color32: Color32) -> Self
+    fn from_rgba8(rgba8: Rgba8) -> Self
     {
-        let [r, g, b, _a] = color32.to_array();
+        let [r, g, b, _a] = rgba8.to_array();
         Self([r, g, b])
     }
 }
-impl FromColor32 for Hsv
+impl FromRgba8 for Hsv
 {
     #[inline]
-    fn from_color32(color32: Color32) -> Self
+    fn from_rgba8(rgba8: Rgba8) -> Self
     {
-        Self::from(color32)
+        Self::from(rgba8)
     }
 }
 
 pub trait FromCartesian: From<RgbLinear> + From<Xyz> {}
 pub trait FromPolar: From<Hsv> + From<Lchab> + From<Lchuv> {}
-pub trait FromColor: FromPolar + FromCartesian + FromColor32 {}
+pub trait FromColor: FromPolar + FromCartesian + FromRgba8 {}
 
 impl<T> FromCartesian for T where T: From<RgbLinear> + From<Xyz> {}
 impl<T> FromPolar for T where T: From<Hsv> + From<Lchuv> + From<Lchab> {}
-impl<T> FromColor for T where T: FromCartesian + FromPolar + FromColor32 {}
+impl<T> FromColor for T where T: FromCartesian + FromPolar + FromRgba8 {}
+
+/// Conversions to the colors drawn by the GUI, so that palettes can be mapped to them directly.
+#[cfg(feature = "epaint")]
+mod epaint_colors
+{
+    use super::{FromRgba8, Hsv, Lchab, Lchuv, Rgba8, RgbLinear, Xyz};
+    use epaint::Color32;
+
+    impl From<Rgba8> for Color32
+    {
+        fn from(Rgba8([r, g, b, a]): Rgba8) -> Self
+        {
+            Self::from_rgba_premultiplied(r, g, b, a)
+        }
+    }
+    impl From<Color32> for Rgba8
+    {
+        fn from(color32: Color32) -> Self
+        {
+            Self(color32.to_array())
+        }
+    }
+    impl From<Color32> for Hsv
+    {
+        fn from(color32: Color32) -> Self
+        {
+            Self::from(Rgba8::from(color32))
+        }
+    }
+    impl FromRgba8 for Color32
+    {
+        #[inline]
+        fn from_rgba8(rgba8: Rgba8) -> Self
+        {
+            Self::from(rgba8)
+        }
+    }
+
+    macro_rules! via_rgba8 {
+        ($($color: ty),*) => {
+            $(
+                impl From<$color> for Color32
+                {
+                    fn from(color: $color) -> Self
+                    {
+                        Self::from(Rgba8::from(color))
+                    }
+                }
+            )*
+        };
+    }
+    via_rgba8!(Hsv, RgbLinear, Xyz, Lchuv, Lchab);
+}
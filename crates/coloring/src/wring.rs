@@ -0,0 +1,71 @@
+use dynamo_common::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Teichmüller wring of the Böttcher coordinate near infinity, which multiplies the escape rates
+/// of all points by `stretch`.
+///
+/// This only deforms the picture, not the dynamics: it is applied to the potentials of escaping
+/// points before palette lookup, so that the level sets of the palette follow the escape rates of
+/// the wrung map.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Wring
+{
+    pub stretch: Real,
+}
+
+impl Wring
+{
+    pub const IDENTITY: Self = Self { stretch: 1. };
+
+    /// Stretch factors range over `[e^-AMPLITUDE, e^AMPLITUDE]` in an animation.
+    const ANIMATION_AMPLITUDE: Real = 1.5;
+    /// Duration of one cycle of an animation, in seconds.
+    const ANIMATION_PERIOD: Real = 6.;
+
+    #[must_use]
+    pub const fn new(stretch: Real) -> Self
+    {
+        Self { stretch }
+    }
+
+    /// The wring at `time` seconds into an animation that oscillates between stretching and
+    /// compressing the escape rates.
+    #[must_use]
+    pub fn oscillating(time: Real) -> Self
+    {
+        let phase = TAU * time / Self::ANIMATION_PERIOD;
+        Self::new((Self::ANIMATION_AMPLITUDE * phase.sin()).exp())
+    }
+
+    #[must_use]
+    pub fn is_identity(&self) -> bool
+    {
+        self.stretch == 1.
+    }
+
+    /// Smooth iteration count after the escape rate is multiplied by the stretch. Since the
+    /// escape rate shrinks by a factor of `degree` over each `esc_period` iterations, this
+    /// amounts to a shift of the count.
+    #[must_use]
+    pub fn apply(&self, potential: IterCountSmooth, degree: Real, esc_period: Period)
+        -> IterCountSmooth
+    {
+        if self.is_identity() || degree <= 1. {
+            return potential;
+        }
+        let shift = self.stretch.ln() / degree.ln() * esc_period.max(1) as Real;
+        // Keep the potential positive, as the palette looks up its logarithm
+        (potential - shift).max(IterCountSmooth::MIN_POSITIVE)
+    }
+}
+
+impl Default for Wring
+{
+    fn default() -> Self
+    {
+        Self::IDENTITY
+    }
+}
@@ -0,0 +1,174 @@
+//! Quantitative measurements of computed images.
+use crate::iter_plane::IterPlane;
+use crate::point_info::PointInfo;
+use crate::types::Real;
+use ndarray::Array2;
+use std::fmt::Display;
+
+/// Boxes must be at most this fraction of the smaller side of the image, so that there are
+/// enough of them to count.
+const MIN_BOXES_PER_SIDE: usize = 4;
+
+/// A measured quantity together with its standard error.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Estimate
+{
+    pub value: Real,
+    pub error: Real,
+}
+
+impl Display for Estimate
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{:.4} ± {:.4}", self.value, self.error)
+    }
+}
+
+/// Pixels within a pixel of the boundary of the escaping set.
+///
+/// Where a distance estimate is available, it decides whether a pixel is close enough. Otherwise
+/// a pixel counts if it escapes while one of its horizontal or vertical neighbors does not, or
+/// the other way around.
+#[must_use]
+pub fn boundary_pixels<D>(iter_plane: &IterPlane<D>) -> Array2<bool>
+{
+    let grid = &iter_plane.point_grid;
+    let pixel_size = grid.pixel_width().max(grid.pixel_height());
+    let counts = &iter_plane.iter_counts;
+    let (width, height) = counts.dim();
+    let escapes = counts.map(escaped);
+
+    Array2::from_shape_fn((width, height), |(x, y)| match counts[(x, y)] {
+        PointInfo::DistanceEstimate { distance, .. }
+        | PointInfo::InteriorDistanceEstimate { distance, .. } => distance < pixel_size,
+        _ => [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ]
+        .into_iter()
+        .filter_map(|idx| escapes.get(idx))
+        .any(|&e| e != escapes[(x, y)]),
+    })
+}
+
+/// Estimate the box-counting dimension of the boundary of the escaping set in view.
+///
+/// The pixels near the boundary are covered by square boxes with sides of successive powers of
+/// two pixels. The dimension is the slope of the logarithm of the number of boxes needed against
+/// the logarithm of the inverse box size, fitted by least squares, and its error is the standard
+/// error of the slope. Returns `None` if the image is too small or has no boundary in view.
+#[must_use]
+pub fn box_counting_dimension<D>(iter_plane: &IterPlane<D>) -> Option<Estimate>
+{
+    let boundary = boundary_pixels(iter_plane);
+    let (width, height) = boundary.dim();
+    let max_size = width.min(height) / MIN_BOXES_PER_SIDE;
+
+    let samples: Vec<(Real, Real)> = std::iter::successors(Some(1_usize), |s| Some(2 * s))
+        .take_while(|&s| s <= max_size)
+        .map(|s| {
+            let num_boxes = count_boxes(&boundary, s);
+            (-(s as Real).ln(), (num_boxes as Real).ln())
+        })
+        .collect();
+
+    if samples.iter().any(|(_, log_count)| !log_count.is_finite()) {
+        return None;
+    }
+    fit_slope(&samples)
+}
+
+/// Area of the pixels that were not found to escape, in the coordinates of the view before any
+/// change of coordinates.
+#[must_use]
+pub fn bounded_area<D>(iter_plane: &IterPlane<D>) -> Real
+{
+    let grid = &iter_plane.point_grid;
+    let num_bounded = iter_plane
+        .iter_counts
+        .iter()
+        .filter(|info| !escaped(info))
+        .count();
+    num_bounded as Real * grid.pixel_width() * grid.pixel_height()
+}
+
+/// Extrapolate measurements `(h, value)`, made with decreasing step sizes `h`, to `h = 0`.
+///
+/// The error of each measurement is assumed to be proportional to a power of `h`, which is
+/// estimated from the last three measurements. If there are only two, or the error does not yet
+/// behave like a power, the error is taken to be linear in `h`. The error of the result is the
+/// size of the correction made to the last measurement.
+#[must_use]
+pub fn richardson_extrapolate(samples: &[(Real, Real)]) -> Option<Estimate>
+{
+    let [.., (h1, a1), (h2, a2)] = *samples else {
+        return None;
+    };
+    let ratio = h1 / h2;
+    let order = match *samples {
+        [.., (_, a0), _, _] => ((a1 - a0) / (a2 - a1)).ln() / ratio.ln(),
+        _ => 1.,
+    };
+    let order = if order.is_finite() && order > 0. { order } else { 1. };
+
+    let correction = (a2 - a1) / (ratio.powf(order) - 1.);
+    Some(Estimate {
+        value: a2 + correction,
+        error: correction.abs(),
+    })
+}
+
+const fn escaped<D>(info: &PointInfo<D>) -> bool
+{
+    matches!(
+        info,
+        PointInfo::Escaping { .. } | PointInfo::DistanceEstimate { .. }
+    )
+}
+
+/// Number of boxes of `size` by `size` pixels, aligned to the grid, containing a marked pixel.
+fn count_boxes(marked: &Array2<bool>, size: usize) -> usize
+{
+    let (width, height) = marked.dim();
+    let mut occupied = Array2::from_elem((width.div_ceil(size), height.div_ceil(size)), false);
+    for ((x, y), &m) in marked.indexed_iter() {
+        if m {
+            occupied[(x / size, y / size)] = true;
+        }
+    }
+    occupied.iter().filter(|&&o| o).count()
+}
+
+/// Least-squares slope of a line through the points, with its standard error. Needs at least
+/// three points.
+fn fit_slope(points: &[(Real, Real)]) -> Option<Estimate>
+{
+    let n = points.len();
+    if n < 3 {
+        return None;
+    }
+    let len = n as Real;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<Real>() / len;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<Real>() / len;
+    let sxx: Real = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let sxy: Real = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+
+    let slope = sxy / sxx;
+    let intercept = slope.mul_add(-mean_x, mean_y);
+    let residuals: Real = points
+        .iter()
+        .map(|(x, y)| (y - slope.mul_add(*x, intercept)).powi(2))
+        .sum();
+    let error = (residuals / (len - 2.) / sxx).sqrt();
+
+    Some(Estimate {
+        value: slope,
+        error,
+    })
+}
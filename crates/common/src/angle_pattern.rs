@@ -0,0 +1,175 @@
+//! Descriptions of sets of angles, for drawing many rays at once.
+use crate::rational_angle::RationalAngle;
+use crate::symbolic_dynamics::{OrbitSchemaWithDegree, ParseAngleError};
+use crate::types::{AngleNum, Period};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::str::FromStr;
+
+/// Largest number of angles a pattern may describe.
+pub const MAX_ANGLES: usize = 4096;
+
+/// A set of angles, given by one of:
+///
+/// * a list of angles in any format accepted by [`RationalAngle::from_str`], separated by commas
+///   or spaces, e.g. `1/7, 2/7, 011p10`;
+/// * `period n`, for all angles of exact period `n` under multiplication by the degree;
+/// * `period <= n`, for all angles of period at most `n`;
+/// * `k/m`, for all angles with denominator dividing `m`, optionally followed by `for k odd` or
+///   `for k even` to keep only those with odd or even numerators.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnglePattern
+{
+    List(Vec<RationalAngle>),
+    Period(Period),
+    PeriodAtMost(Period),
+    Fractions
+    {
+        denom: AngleNum,
+        parity: Option<Parity>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parity
+{
+    Odd,
+    Even,
+}
+
+impl Parity
+{
+    const fn matches(self, k: AngleNum) -> bool
+    {
+        match self {
+            Self::Odd => k % 2 != 0,
+            Self::Even => k % 2 == 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnglePatternError
+{
+    Empty,
+    Angle(ParseAngleError),
+    /// The pattern describes more than [`MAX_ANGLES`] angles.
+    TooMany,
+}
+
+impl std::fmt::Display for AnglePatternError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Empty => write!(f, "No angles given."),
+            Self::Angle(cause) => write!(f, "{cause}"),
+            Self::TooMany => write!(f, "Too many angles; at most {MAX_ANGLES} are allowed."),
+        }
+    }
+}
+
+impl std::error::Error for AnglePatternError {}
+
+impl AnglePattern
+{
+    /// The angles described by the pattern, in increasing order, with periods taken under
+    /// multiplication by `degree`.
+    pub fn angles(&self, degree: AngleNum) -> Result<Vec<RationalAngle>, AnglePatternError>
+    {
+        let mut angles: Vec<RationalAngle> = match *self {
+            Self::List(ref angles) => angles.clone(),
+            Self::Period(period) => periodic_angles(degree, period..=period)?,
+            Self::PeriodAtMost(period) => periodic_angles(degree, 1..=period)?,
+            Self::Fractions { denom, parity } => {
+                if usize::try_from(denom).map_or(true, |denom| denom > MAX_ANGLES) {
+                    return Err(AnglePatternError::TooMany);
+                }
+                (0..denom)
+                    .filter(|&k| parity.is_none_or(|parity| parity.matches(k)))
+                    .map(|k| RationalAngle::new(k, denom))
+                    .collect()
+            }
+        };
+        angles.sort_unstable();
+        angles.dedup();
+        if angles.len() > MAX_ANGLES {
+            return Err(AnglePatternError::TooMany);
+        }
+        Ok(angles)
+    }
+}
+
+/// All angles whose period lies in `periods`. The count is checked before enumerating, since
+/// the natural denominators grow exponentially with the period.
+fn periodic_angles(
+    degree: AngleNum,
+    periods: std::ops::RangeInclusive<Period>,
+) -> Result<Vec<RationalAngle>, AnglePatternError>
+{
+    let mut angles = Vec::new();
+    for period in periods {
+        let too_many = degree
+            .checked_pow(period)
+            .and_then(|n| usize::try_from(n).ok())
+            .is_none_or(|n| angles.len() + n > MAX_ANGLES + 1);
+        if too_many {
+            return Err(AnglePatternError::TooMany);
+        }
+        if period == 1 {
+            angles.push(RationalAngle::new(0, 1));
+        }
+        let schema = OrbitSchemaWithDegree {
+            preperiod: 0,
+            period,
+            degree,
+        };
+        angles.extend(schema.exact_angles());
+    }
+    Ok(angles)
+}
+
+impl FromStr for AnglePattern
+{
+    type Err = AnglePatternError;
+
+    #[allow(clippy::unwrap_used)]
+    fn from_str(text: &str) -> Result<Self, Self::Err>
+    {
+        lazy_static! {
+            static ref PERIOD: Regex = Regex::new(r"^period\s*(<=|≤|=)?\s*(\d+)$").unwrap();
+            static ref FRACTIONS: Regex =
+                Regex::new(r"^k\s*/\s*(\d+)(?:\s+(?:for\s+k\s+)?(odd|even))?$").unwrap();
+        }
+
+        let text = text.trim().to_lowercase();
+
+        if let Some(captures) = PERIOD.captures(&text) {
+            let period = captures[2].parse().map_err(|_| AnglePatternError::TooMany)?;
+            return Ok(match captures.get(1).map(|m| m.as_str()) {
+                Some("<=" | "≤") => Self::PeriodAtMost(period),
+                _ => Self::Period(period),
+            });
+        }
+
+        if let Some(captures) = FRACTIONS.captures(&text) {
+            let denom = captures[1].parse().map_err(|_| AnglePatternError::TooMany)?;
+            let parity = captures.get(2).map(|m| match m.as_str() {
+                "odd" => Parity::Odd,
+                _ => Parity::Even,
+            });
+            return Ok(Self::Fractions { denom, parity });
+        }
+
+        let angles = text
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(RationalAngle::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(AnglePatternError::Angle)?;
+        if angles.is_empty() {
+            return Err(AnglePatternError::Empty);
+        }
+        Ok(Self::List(angles))
+    }
+}
@@ -21,6 +21,15 @@ pub fn palettes_dir() -> Option<PathBuf>
     Some(palettes_dir)
 }
 
+#[must_use]
+pub fn raw_data_dir() -> Option<PathBuf>
+{
+    let proj_dirs = ProjectDirs::from("com", "Zero Ideal", "Dynamo")?;
+    let raw_data_dir = proj_dirs.data_dir().join("data");
+    std::fs::create_dir_all(&raw_data_dir).ok()?;
+    Some(raw_data_dir)
+}
+
 #[must_use]
 pub fn script_dir() -> Option<PathBuf>
 {
@@ -29,3 +38,12 @@ pub fn script_dir() -> Option<PathBuf>
     std::fs::create_dir_all(&scripts_dir).ok()?;
     Some(scripts_dir)
 }
+
+#[must_use]
+pub fn config_dir() -> Option<PathBuf>
+{
+    let proj_dirs = ProjectDirs::from("com", "Zero Ideal", "Dynamo")?;
+    let config_dir = proj_dirs.config_dir().to_owned();
+    std::fs::create_dir_all(&config_dir).ok()?;
+    Some(config_dir)
+}
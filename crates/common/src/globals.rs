@@ -1,4 +1,4 @@
-use crate::types::Real;
+use crate::types::{IterCount, Real};
 
 pub const RAY_DEPTH: u32 = 200;
 pub const RAY_SHARPNESS: u32 = 25;
@@ -12,6 +12,7 @@ pub const NEWTON_MAX_ERR: Real = 1e-5;
 pub const DISPLAY_PREC: usize = 12;
 
 pub const IMAGE_HEIGHT: usize = 768;
+pub const MAX_ITER: IterCount = 1024;
 
 pub const WIN_HEIGHT: f32 = (IMAGE_HEIGHT + 192) as f32;
 pub const WIN_WIDTH: f32 = (IMAGE_HEIGHT * 2 + 320) as f32;
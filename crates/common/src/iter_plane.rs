@@ -1,10 +1,42 @@
 use crate::point_grid::PointGrid;
 
-use crate::point_info::PointInfo;
-use ndarray::Array2;
+use crate::point_info::{PointClassId, PointInfo};
+use crate::traits::Norm;
+use crate::types::{Cplx, IterCount, IterCountSmooth, Period, Real};
+use ndarray::{s, Array2};
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Number of basins that can be told apart by [`IterPlane::classify_basins`].
+const MAX_BASINS: usize = 256;
+/// Number of levels beyond which [`IterPlane::potential_contours`] widens its spacing.
+const MAX_CONTOUR_LEVELS: usize = 512;
+
+/// Midpoint of an edge between two pixel centers, in doubled pixel coordinates.
+type EdgeKey = (isize, isize);
+
+/// A block of pixels, given by its ranges of columns and rows.
+pub type PixelBlock = (Range<usize>, Range<usize>);
+
+/// State of an orbit that ran out of iterations before its fate was known, from which it can be
+/// resumed once more iterations are allowed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrbitCheckpoint<D>
+{
+    pub iter: IterCount,
+    pub z_slow: Cplx,
+    pub z_fast: Cplx,
+    /// Derivatives accumulated along the orbit, left at one by orbits that do not track them.
+    pub multiplier: D,
+    pub dz_dt: D,
+}
+
+/// Distance, in pixels of the new grid, within which a point of the old grid counts as the point
+/// of a pixel of the new one when zooming.
+const PROVENANCE_TOLERANCE: Real = 1e-3;
 
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -13,6 +45,14 @@ pub struct IterPlane<D>
     #[cfg_attr(feature = "serde", serde(skip))]
     pub iter_counts: Array2<PointInfo<D>>,
     pub point_grid: PointGrid,
+    /// Saved states of the orbits of pixels that ran out of iterations, so that raising the
+    /// iteration limit only needs to run them further.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub checkpoints: HashMap<(usize, usize), OrbitCheckpoint<D>>,
+    /// Number of pixels by the iteration limit their orbits were run with, if the limit was
+    /// raised for some of them.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub iteration_limits: BTreeMap<IterCount, usize>,
 }
 
 impl<D> IterPlane<D>
@@ -26,6 +66,706 @@ where
         Self {
             iter_counts,
             point_grid,
+            checkpoints: HashMap::new(),
+            iteration_limits: BTreeMap::new(),
+        }
+    }
+
+    /// Translate the grid by whole pixels, `dx` to the right and `dy` up, shifting the computed
+    /// points along with it. Returns the blocks of pixels that were exposed, whose points are
+    /// left unknown, or `None` if the grid would move by a screenful or more, so that no points
+    /// could be kept.
+    pub fn translate_and_invalidate(&mut self, dx: isize, dy: isize) -> Option<Vec<PixelBlock>>
+    {
+        let (res_x, res_y) = self.point_grid.shape();
+        if dx.unsigned_abs() >= res_x || dy.unsigned_abs() >= res_y {
+            return None;
+        }
+        self.point_grid.translate_pixels(dx, dy);
+        if dx == 0 && dy == 0 {
+            return Some(Vec::new());
+        }
+
+        // The pixel at (x, y) now shows the point that was at (x + dx, y + dy). Each axis splits
+        // into the pixels copied from, those copied to, and those exposed.
+        let split = |shift: isize, len: usize| {
+            let kept = len - shift.unsigned_abs();
+            if shift >= 0 {
+                (shift.unsigned_abs()..len, 0..kept, kept..len)
+            } else {
+                (0..kept, shift.unsigned_abs()..len, 0..shift.unsigned_abs())
+            }
+        };
+        let (src_x, dst_x, exposed_x) = split(dx, res_x);
+        let (src_y, dst_y, exposed_y) = split(dy, res_y);
+
+        let mut iter_counts = Array2::from_elem((res_x, res_y), PointInfo::Unknown);
+        iter_counts
+            .slice_mut(s![dst_x.clone(), dst_y])
+            .assign(&self.iter_counts.slice(s![src_x, src_y]));
+        self.iter_counts = iter_counts;
+        self.checkpoints.clear();
+        self.iteration_limits.clear();
+
+        let mut exposed = Vec::new();
+        if !exposed_x.is_empty() {
+            exposed.push((exposed_x, 0..res_y));
+        }
+        if !exposed_y.is_empty() {
+            exposed.push((dst_x, exposed_y));
+        }
+        Some(exposed)
+    }
+
+    /// Preview of the plane on `point_grid`, usually zoomed in, upsampled from the points computed
+    /// so far. Each pixel takes the value of the nearest pixel of the current grid, or is left
+    /// unknown if it lies outside it. Also returns which pixels are stale, namely all of them
+    /// save those whose point was computed before.
+    #[must_use]
+    pub fn zoom_preview(&self, point_grid: PointGrid) -> (Self, Array2<bool>)
+    {
+        let tolerance = PROVENANCE_TOLERANCE * point_grid.pixel_width();
+        let mut stale = Array2::from_elem(point_grid.shape(), true);
+        let mut iter_counts = Array2::from_elem(point_grid.shape(), PointInfo::Unknown);
+        for ((x, y), point_info) in iter_counts.indexed_iter_mut() {
+            let z = point_grid.map_pixel(x, y);
+            if let Some((i, j)) = self.point_grid.nearest_pixel(z) {
+                point_info.clone_from(&self.iter_counts[(i, j)]);
+                let offset = self.point_grid.map_pixel(i, j) - z;
+                stale[(x, y)] = offset.norm_sqr() > tolerance * tolerance;
+            }
+        }
+        (
+            Self {
+                iter_counts,
+                point_grid,
+                checkpoints: HashMap::new(),
+                iteration_limits: BTreeMap::new(),
+            },
+            stale,
+        )
+    }
+}
+
+/// A connected region of pixels sharing the same key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Component<K>
+{
+    pub key: K,
+    /// Number of pixels in the region.
+    pub size: usize,
+    /// The pixel of the region nearest its centroid. Unlike the centroid itself, this always
+    /// lies inside the region, even if it is not convex.
+    pub center: (usize, usize),
+}
+
+impl<D> IterPlane<D>
+{
+    /// Partition the pixels into regions that are connected horizontally or vertically and
+    /// share the same key. Pixels with no key belong to no region.
+    pub fn connected_components<K, F>(&self, key: F) -> Vec<Component<K>>
+    where
+        K: Clone + PartialEq,
+        F: Fn(&PointInfo<D>) -> Option<K>,
+    {
+        let keys = self.iter_counts.map(key);
+        let mut visited = Array2::from_elem(keys.dim(), false);
+        let mut components = Vec::new();
+        let mut members = Vec::new();
+
+        for ((x, y), k) in keys.indexed_iter() {
+            let Some(k) = k else {
+                continue;
+            };
+            if visited[(x, y)] {
+                continue;
+            }
+            fill_component(&keys, &mut visited, (x, y), &mut members);
+
+            let size = members.len();
+            let (sum_x, sum_y) = members
+                .iter()
+                .fold((0, 0), |(sx, sy), &(i, j)| (sx + i, sy + j));
+            let (mean_x, mean_y) = (sum_x as f64 / size as f64, sum_y as f64 / size as f64);
+            let center = members
+                .iter()
+                .copied()
+                .min_by(|&(i0, j0), &(i1, j1)| {
+                    let d0 = (i0 as f64 - mean_x).powi(2) + (j0 as f64 - mean_y).powi(2);
+                    let d1 = (i1 as f64 - mean_x).powi(2) + (j1 as f64 - mean_y).powi(2);
+                    d0.total_cmp(&d1)
+                })
+                .unwrap_or((x, y));
+
+            components.push(Component {
+                key: k.clone(),
+                size,
+                center,
+            });
+        }
+        components
+    }
+
+    /// Regions of pixels attracted to cycles of the same period. On a parameter plane, these are
+    /// the hyperbolic components visible in the image.
+    #[must_use]
+    pub fn period_components(&self) -> Vec<Component<Period>>
+    {
+        self.connected_components(PointInfo::period)
+    }
+
+    /// Trace the boundary of the region of pixels connected to `seed` that share its key, by
+    /// marching squares through the pixel centers. Each polygon is closed, its last vertex being
+    /// joined to the first, and has the region on its left. There is one polygon for the outside
+    /// of the region and one for each hole in it.
+    pub fn component_boundary<K, F>(&self, seed: (usize, usize), key: F) -> Vec<Vec<Cplx>>
+    where
+        K: PartialEq,
+        F: Fn(&PointInfo<D>) -> Option<K>,
+    {
+        let keys = self.iter_counts.map(key);
+        let mut inside = Array2::from_elem(keys.dim(), false);
+        if keys.get(seed).is_some_and(Option::is_some) {
+            fill_component(&keys, &mut inside, seed, &mut Vec::new());
+        }
+
+        let (width, height) = inside.dim();
+        let is_inside = |i: isize, j: isize| {
+            usize::try_from(i)
+                .ok()
+                .zip(usize::try_from(j).ok())
+                .and_then(|idx| inside.get(idx).copied())
+                .unwrap_or(false)
+        };
+
+        // Each vertex is the midpoint of an edge between two pixel centers, in doubled pixel
+        // coordinates so that it can be hashed exactly. Maps each vertex to the next one along
+        // its polygon.
+        let mut next = HashMap::new();
+        for i in -1..width as isize {
+            for j in -1..height as isize {
+                // Corners of the cell, and the edges following each, counterclockwise
+                let corners = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)]
+                    .map(|(u, v)| is_inside(u, v));
+                let edges = [
+                    (2 * i + 1, 2 * j),
+                    (2 * i + 2, 2 * j + 1),
+                    (2 * i + 1, 2 * j + 2),
+                    (2 * i, 2 * j + 1),
+                ];
+                let leaves = |k: usize| corners[k] && !corners[(k + 1) % 4];
+                let enters = |k: usize| !corners[k] && corners[(k + 1) % 4];
+                for k in (0..4).filter(|&k| leaves(k)) {
+                    // Pair with the nearest entry clockwise, which separates diagonal neighbors
+                    if let Some(m) = (1..4).map(|n| (k + 4 - n) % 4).find(|&m| enters(m)) {
+                        next.insert(edges[k], edges[m]);
+                    }
+                }
+            }
+        }
+
+        let mut polygons = Vec::new();
+        while let Some(&start) = next.keys().next() {
+            let mut polygon = Vec::new();
+            let mut vertex = start;
+            while let Some(following) = next.remove(&vertex) {
+                polygon.push(vertex);
+                vertex = following;
+            }
+            polygons.push(
+                drop_collinear(&polygon)
+                    .map(|(x, y)| {
+                        self.point_grid
+                            .map_fractional_pixel(x as Real / 2., y as Real / 2.)
+                    })
+                    .collect(),
+            );
+        }
+        polygons
+    }
+
+    /// Boundary of the region containing `point` that is attracted to cycles of the same period.
+    /// Empty if the point lies outside the grid or is not attracted to a cycle.
+    #[must_use]
+    pub fn period_component_boundary(&self, point: Cplx) -> Vec<Vec<Cplx>>
+    {
+        let Some((x, y)) = self.point_grid.locate_point_safe(point) else {
+            return Vec::new();
+        };
+        // Rows of the grid run bottom to top, but image rows run top to bottom
+        let seed = (x, self.point_grid.res_y - 1 - y);
+        self.component_boundary(seed, PointInfo::period)
+    }
+}
+
+/// A level curve of the escape potential of an image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contour
+{
+    pub level: IterCountSmooth,
+    /// Points along the curve. A closed curve ends at the point where it starts.
+    pub points: Vec<Cplx>,
+}
+
+impl Contour
+{
+    #[must_use]
+    pub fn is_closed(&self) -> bool
+    {
+        self.points.len() > 2 && self.points.first() == self.points.last()
+    }
+}
+
+impl<D> IterPlane<D>
+{
+    /// Trace the curves on which the potential of escaping points is a multiple of `spacing`,
+    /// which bound the bands of points escaping after equally many iterations when the spacing
+    /// is 1. The curves are found by marching squares through the pixel centers, interpolating
+    /// the potential linearly along the edges. Pixels that do not escape are left out, so curves
+    /// end where they meet them. If there would be too many levels, every few are skipped.
+    #[must_use]
+    pub fn potential_contours(&self, spacing: IterCountSmooth) -> Vec<Contour>
+    {
+        let potentials = self.iter_counts.map(|point_info| match point_info {
+            PointInfo::Escaping { potential, .. } if potential.is_finite() => Some(*potential),
+            _ => None,
+        });
+        let (min, max) = potentials
+            .iter()
+            .flatten()
+            .fold((IterCountSmooth::INFINITY, IterCountSmooth::NEG_INFINITY), |(lo, hi), &p| {
+                (lo.min(p), hi.max(p))
+            });
+        if !(spacing > 0. && min < max) {
+            return Vec::new();
+        }
+        let skip = ((max - min) / spacing / MAX_CONTOUR_LEVELS as IterCountSmooth).ceil();
+        let spacing = spacing * skip.max(1.);
+        let first = (min / spacing).floor() as i64 + 1;
+        let last = (max / spacing).ceil() as i64 - 1;
+
+        let (width, height) = potentials.dim();
+        let mut contours = Vec::new();
+        for k in first..=last {
+            let level = k as IterCountSmooth * spacing;
+            let mut positions = HashMap::new();
+            let mut segments = Vec::new();
+            for i in 0..width.saturating_sub(1) {
+                for j in 0..height.saturating_sub(1) {
+                    // Corners of the cell, and the edges following each, counterclockwise
+                    let corners = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)];
+                    let Some(values) = corners
+                        .iter()
+                        .map(|&idx| potentials[idx])
+                        .collect::<Option<Vec<_>>>()
+                    else {
+                        continue;
+                    };
+                    let above = values.iter().map(|&v| v > level).collect::<Vec<_>>();
+                    let (i, j) = (i as isize, j as isize);
+                    let edges = [
+                        (2 * i + 1, 2 * j),
+                        (2 * i + 2, 2 * j + 1),
+                        (2 * i + 1, 2 * j + 2),
+                        (2 * i, 2 * j + 1),
+                    ];
+                    let crossed: Vec<_> = (0..4)
+                        .filter(|&e| above[e] != above[(e + 1) % 4])
+                        .collect();
+                    for &e in &crossed {
+                        positions.entry(edges[e]).or_insert_with(|| {
+                            let (v0, v1) = (values[e], values[(e + 1) % 4]);
+                            let t = (level - v0) / (v1 - v0);
+                            let (x0, y0) = corners[e];
+                            let (x1, y1) = corners[(e + 1) % 4];
+                            self.point_grid.map_fractional_pixel(
+                                t.mul_add(x1 as Real - x0 as Real, x0 as Real),
+                                t.mul_add(y1 as Real - y0 as Real, y0 as Real),
+                            )
+                        });
+                    }
+                    match crossed[..] {
+                        [e0, e1] => segments.push((edges[e0], edges[e1])),
+                        [_, _, _, _] => {
+                            // At a saddle, the corners on the same side as the center of the
+                            // cell are joined through it, and the other two are cut off
+                            let center = values.iter().sum::<IterCountSmooth>() / 4.;
+                            let cut_off = usize::from((center > level) == above[0]);
+                            for corner in [cut_off, cut_off + 2] {
+                                segments.push((edges[(corner + 3) % 4], edges[corner]));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            contours.extend(join_segments(&segments).into_iter().map(|chain| Contour {
+                level,
+                points: chain.iter().map(|edge| positions[edge]).collect(),
+            }));
+        }
+        contours
+    }
+
+    /// Estimate the gradient of a height given to the pixels, in units of the height per pixel,
+    /// by central differences, or by one-sided differences beside pixels with no height. Pixels
+    /// with no height, or with no neighbors having one along some axis, have no gradient.
+    pub fn gradient<F>(&self, height: F) -> Array2<Option<[Real; 2]>>
+    where
+        F: Fn(&PointInfo<D>) -> Option<Real>,
+    {
+        let heights = self
+            .iter_counts
+            .map(|point_info| height(point_info).filter(|h| h.is_finite()));
+        let at = |i: Option<usize>, j: Option<usize>| heights.get((i?, j?)).copied().flatten();
+        Array2::from_shape_fn(heights.dim(), |(i, j)| {
+            let h = heights[(i, j)]?;
+            let difference = |before: Option<Real>, after: Option<Real>| match (before, after) {
+                (Some(b), Some(a)) => Some((a - b) / 2.),
+                (Some(b), None) => Some(h - b),
+                (None, Some(a)) => Some(a - h),
+                (None, None) => None,
+            };
+            let dx = difference(at(i.checked_sub(1), Some(j)), at(Some(i + 1), Some(j)))?;
+            let dy = difference(at(Some(i), j.checked_sub(1)), at(Some(i), Some(j + 1)))?;
+            Some([dx, dy])
+        })
+    }
+}
+
+/// An attracting cycle told apart from the others in an image, with the extent of its basin.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attractor<D>
+{
+    pub class_id: PointClassId,
+    pub period: Period,
+    /// The point of the cycle by which it is told apart from the others.
+    pub cycle_point: Cplx,
+    /// Multiplier of the cycle, as found from the first pixel of its basin.
+    pub multiplier: D,
+    /// Fraction of the pixels of the image lying in the basin of the cycle.
+    pub basin_fraction: Real,
+}
+
+/// Summary of the results over the pixels of an image.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlaneStatistics
+{
+    /// Number of pixels attracted to cycles of each period.
+    pub periods: BTreeMap<Period, usize>,
+    /// Number of escaping pixels by the iteration at which they escaped.
+    pub escape_iters: BTreeMap<IterCount, usize>,
+    /// Number of pixels found to be attracted to a cycle.
+    pub interior: usize,
+    /// Number of pixels found to escape.
+    pub exterior: usize,
+    pub total: usize,
+    /// Number of pixels by the iteration limit their orbits were run with, if it varied.
+    pub iteration_limits: BTreeMap<IterCount, usize>,
+}
+
+impl PlaneStatistics
+{
+    #[must_use]
+    pub fn interior_fraction(&self) -> Real
+    {
+        self.interior as Real / self.total.max(1) as Real
+    }
+
+    #[must_use]
+    pub fn exterior_fraction(&self) -> Real
+    {
+        self.exterior as Real / self.total.max(1) as Real
+    }
+
+    /// Fraction of pixels that neither escaped nor were found to be periodic within the
+    /// iteration limit.
+    #[must_use]
+    pub fn undetermined_fraction(&self) -> Real
+    {
+        1. - self.interior_fraction() - self.exterior_fraction()
+    }
+
+    /// Iteration limit of the pixels on average, if it varied between them.
+    #[must_use]
+    pub fn mean_iteration_limit(&self) -> Option<Real>
+    {
+        let (sum, count) = self
+            .iteration_limits
+            .iter()
+            .fold((0., 0), |(sum, count), (&limit, &pixels)| {
+                ((limit as Real).mul_add(pixels as Real, sum), count + pixels)
+            });
+        (count > 0).then(|| sum / count as Real)
+    }
+}
+
+impl<D> IterPlane<D>
+{
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn statistics(&self) -> PlaneStatistics
+    {
+        let mut stats = PlaneStatistics {
+            total: self.iter_counts.len(),
+            iteration_limits: self.iteration_limits.clone(),
+            ..PlaneStatistics::default()
+        };
+        for point_info in &self.iter_counts {
+            if let Some(period) = point_info.period() {
+                stats.interior += 1;
+                *stats.periods.entry(period).or_default() += 1;
+                continue;
+            }
+            match point_info {
+                PointInfo::Escaping { potential, .. } => {
+                    stats.exterior += 1;
+                    let iters = potential.max(0.) as IterCount;
+                    *stats.escape_iters.entry(iters).or_default() += 1;
+                }
+                PointInfo::DistanceEstimate { .. } => {
+                    stats.exterior += 1;
+                }
+                _ => {}
+            }
+        }
+        stats
+    }
+}
+
+impl<D> IterPlane<D>
+where
+    D: Default + Norm<Real>,
+{
+    /// Tell apart the attracting cycles found across the plane, clustering the cycle points of
+    /// periodic pixels that lie within `tolerance` of each other, and mark each periodic pixel
+    /// with the class of its cycle. Classes are ordered by the location of their cycles, so that
+    /// they do not depend on the order in which pixels are visited. Repelling cycles, which are
+    /// only found by landing on them exactly, and cycles beyond the number of classes that can be
+    /// told apart are left unmarked. Returns the number of classes.
+    pub fn classify_basins(&mut self, tolerance: Real) -> usize
+    {
+        let mut cycles: Vec<(Period, Cplx)> = Vec::new();
+        let labels = self.iter_counts.map(|point_info| {
+            let (PointInfo::Periodic(data) | PointInfo::MarkedPoint { data, .. }) = point_info
+            else {
+                return None;
+            };
+            if data.multiplier.norm() > 1. {
+                return None;
+            }
+            let found = cycles.iter().position(|&(period, z)| {
+                period == data.period && (z - data.cycle_point).norm() < tolerance
+            });
+            found.or_else(|| {
+                (cycles.len() < MAX_BASINS).then(|| {
+                    cycles.push((data.period, data.cycle_point));
+                    cycles.len() - 1
+                })
+            })
+        });
+
+        let mut order: Vec<usize> = (0..cycles.len()).collect();
+        order.sort_by(|&i, &j| {
+            let (z, w) = (cycles[i].1, cycles[j].1);
+            z.re.total_cmp(&w.re).then(z.im.total_cmp(&w.im))
+        });
+        let mut class_ids = vec![0; cycles.len()];
+        for (class_id, &idx) in order.iter().enumerate() {
+            class_ids[idx] = class_id;
         }
+
+        let num_point_classes = cycles.len();
+        ndarray::Zip::from(&mut self.iter_counts)
+            .and(&labels)
+            .for_each(|point_info, label| {
+                let Some(idx) = label else {
+                    return;
+                };
+                if let PointInfo::Periodic(data) | PointInfo::MarkedPoint { data, .. } =
+                    std::mem::take(point_info)
+                {
+                    *point_info = PointInfo::MarkedPoint {
+                        data,
+                        class_id: class_ids[*idx].into(),
+                        num_point_classes,
+                    };
+                }
+            });
+        num_point_classes
+    }
+}
+
+impl<D: Clone> IterPlane<D>
+{
+    /// The attracting cycles whose basins are marked in the image, such as by
+    /// [`IterPlane::classify_basins`], in order of class.
+    #[must_use]
+    pub fn attractors(&self) -> Vec<Attractor<D>>
+    {
+        let mut attractors: BTreeMap<u8, (Attractor<D>, usize)> = BTreeMap::new();
+        for point_info in &self.iter_counts {
+            let PointInfo::MarkedPoint { data, class_id, .. } = point_info else {
+                continue;
+            };
+            attractors
+                .entry(class_id.0)
+                .or_insert_with(|| {
+                    let attractor = Attractor {
+                        class_id: *class_id,
+                        period: data.period,
+                        cycle_point: data.cycle_point,
+                        multiplier: data.multiplier.clone(),
+                        basin_fraction: 0.,
+                    };
+                    (attractor, 0)
+                })
+                .1 += 1;
+        }
+
+        let total = self.iter_counts.len() as Real;
+        attractors
+            .into_values()
+            .map(|(attractor, count)| Attractor {
+                basin_fraction: count as Real / total,
+                ..attractor
+            })
+            .collect()
+    }
+}
+
+/// Mark the pixels connected horizontally or vertically to `seed` that share its key as visited,
+/// collecting them in `members`.
+fn fill_component<K: PartialEq>(
+    keys: &Array2<Option<K>>,
+    visited: &mut Array2<bool>,
+    seed: (usize, usize),
+    members: &mut Vec<(usize, usize)>,
+)
+{
+    let (width, height) = keys.dim();
+    let key = &keys[seed];
+    let mut stack = vec![seed];
+    visited[seed] = true;
+    members.clear();
+
+    while let Some((i, j)) = stack.pop() {
+        members.push((i, j));
+        let neighbors = [
+            (i.wrapping_sub(1), j),
+            (i + 1, j),
+            (i, j.wrapping_sub(1)),
+            (i, j + 1),
+        ];
+        for (u, v) in neighbors {
+            if u < width && v < height && !visited[(u, v)] && keys[(u, v)] == *key {
+                visited[(u, v)] = true;
+                stack.push((u, v));
+            }
+        }
+    }
+}
+
+/// Join segments sharing endpoints into chains, each of which is either open or ends where it
+/// starts. Every endpoint is shared by at most two segments.
+fn join_segments(segments: &[(EdgeKey, EdgeKey)]) -> Vec<Vec<EdgeKey>>
+{
+    let mut neighbors: HashMap<EdgeKey, Vec<EdgeKey>> = HashMap::new();
+    for &(a, b) in segments {
+        neighbors.entry(a).or_default().push(b);
+        neighbors.entry(b).or_default().push(a);
+    }
+    let take_neighbor = |neighbors: &mut HashMap<EdgeKey, Vec<EdgeKey>>, edge, except| {
+        let adjacent = neighbors.get_mut(&edge)?;
+        let idx = match except {
+            Some(except) => adjacent.iter().position(|&e| e == except)?,
+            None => adjacent.len().checked_sub(1)?,
+        };
+        let next = adjacent.swap_remove(idx);
+        if adjacent.is_empty() {
+            neighbors.remove(&edge);
+        }
+        Some(next)
+    };
+
+    // Open chains must be traced from one of their ends
+    let mut ends: Vec<_> = neighbors
+        .iter()
+        .filter(|(_, adjacent)| adjacent.len() == 1)
+        .map(|(&edge, _)| edge)
+        .collect();
+    let mut chains = Vec::new();
+    loop {
+        let start = match ends.pop() {
+            Some(end) if neighbors.contains_key(&end) => end,
+            Some(_) => continue,
+            None => match neighbors.keys().next() {
+                Some(&edge) => edge,
+                None => break,
+            },
+        };
+        let mut chain = vec![start];
+        let mut edge = start;
+        while let Some(next) = take_neighbor(&mut neighbors, edge, None) {
+            take_neighbor(&mut neighbors, next, Some(edge));
+            chain.push(next);
+            edge = next;
+        }
+        chains.push(chain);
+    }
+    chains
+}
+
+/// The vertices of a closed polygon at which it changes direction.
+fn drop_collinear(polygon: &[(isize, isize)]) -> impl Iterator<Item = (isize, isize)> + '_
+{
+    let n = polygon.len();
+    polygon.iter().enumerate().filter_map(move |(idx, &(x, y))| {
+        let (x0, y0) = polygon[(idx + n - 1) % n];
+        let (x1, y1) = polygon[(idx + 1) % n];
+        ((x - x0) * (y1 - y) != (y - y0) * (x1 - x)).then_some((x, y))
+    })
+}
+
+#[cfg(feature = "serde")]
+impl<D> IterPlane<D>
+{
+    /// Write the point grid together with the raw per-pixel results, so that the plane can later
+    /// be recolored without being recomputed.
+    pub fn save_data<P>(&self, path: P) -> Result<(), Box<dyn std::error::Error>>
+    where
+        P: AsRef<std::path::Path>,
+        D: Serialize,
+    {
+        let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        bincode::serialize_into(file, &(&self.point_grid, &self.iter_counts))?;
+        Ok(())
+    }
+
+    /// Read raw data previously written by [`IterPlane::save_data`].
+    pub fn load_data<P>(path: P) -> Result<Self, Box<dyn std::error::Error>>
+    where
+        P: AsRef<std::path::Path>,
+        D: DeserializeOwned,
+    {
+        let file = std::io::BufReader::new(std::fs::File::open(path)?);
+        let (point_grid, iter_counts): (PointGrid, Array2<PointInfo<D>>) =
+            bincode::deserialize_from(file)?;
+
+        if iter_counts.dim() != point_grid.shape() {
+            return Err(format!(
+                "Data has shape {:?}, but its grid has shape {:?}",
+                iter_counts.dim(),
+                point_grid.shape()
+            )
+            .into());
+        }
+
+        Ok(Self {
+            iter_counts,
+            point_grid,
+            checkpoints: HashMap::new(),
+            iteration_limits: BTreeMap::new(),
+        })
     }
 }
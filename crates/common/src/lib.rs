@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+pub mod analysis;
+pub mod angle_pattern;
 pub mod consts;
 pub mod directories;
 pub mod globals;
@@ -12,6 +14,7 @@ pub mod prelude;
 pub mod rational_angle;
 pub mod symbolic_dynamics;
 pub mod traits;
+pub mod tuning;
 pub mod types;
 
 pub mod cache
@@ -145,6 +148,49 @@ mod tests
         assert!(err2 < 1e-14); // 3.234090342272182e-15
     }
 
+    #[test]
+    fn zeta_riemann_siegel()
+    {
+        use crate::math_utils::riemann_zeta_d2;
+        let cases = [
+            (
+                Cplx::new(0.5, 100.),
+                [
+                    Cplx::new(2.692_619_885_681_324, -0.020_386_029_602_598_162),
+                    Cplx::new(-3.727_312_709_644_648_3, -0.194_228_702_573_743_22),
+                    Cplx::new(9.554_155_226_630_616, 0.556_707_942_604_164_6),
+                ],
+            ),
+            (
+                Cplx::new(-2.5, -500.),
+                [
+                    Cplx::new(-468_577.330_085_865_05, -225_107.607_559_396_25),
+                    Cplx::new(2_029_271.885_869_968_4, 1_010_362.881_422_795_2),
+                    Cplx::new(-8_802_296.771_879_3, -4_505_748.122_463_567),
+                ],
+            ),
+        ];
+        for (s, vals_true) in cases {
+            let vals = riemann_zeta_d2(s);
+            for (val, val_true) in vals.iter().zip(vals_true) {
+                let err = (val - val_true).norm() / val_true.norm();
+                dbg!(s, err);
+                assert!(err < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn riemann_siegel_terms_reused()
+    {
+        use crate::math_utils::riemann_siegel::{riemann_siegel_zeta, RiemannSiegelTerms};
+        let mut terms = RiemannSiegelTerms::new(100.);
+        for sigma in [0.5, 3., -2.5, 0.5] {
+            let vals: [Cplx; 3] = terms.zeta(sigma);
+            assert_eq!(vals, riemann_siegel_zeta(Cplx::new(sigma, 100.)));
+        }
+    }
+
     #[test]
     fn xi_d2()
     {
@@ -170,6 +216,33 @@ mod tests
         assert!(err2 < 5e-14); // 6.368821534010004e-14
     }
 
+    #[test]
+    fn dual_derivative()
+    {
+        use crate::types::{dual::Scalar, Dual};
+        fn f<T: Scalar>(z: T) -> T
+        {
+            (z.sinh().atan() * z + z.exp() / z).powi(3) - z.sqrt() * 2.
+        }
+        let z = Cplx::new(0.7, -0.4);
+        let h = 1e-6;
+        let df_dz = f(Dual::variable(z)).deriv;
+        let df_dz_approx = (f(z + h) - f(z - h)) / (2. * h);
+        let err = (df_dz - df_dz_approx).norm() / df_dz.norm();
+        dbg!(err);
+        assert!(err < 1e-8);
+    }
+
+    #[test]
+    fn riemann_siegel_theta()
+    {
+        use crate::math_utils::riemann_siegel_theta;
+        // The first Gram point, where theta vanishes
+        let theta = riemann_siegel_theta(17.845_599_540_485_34);
+        dbg!(theta);
+        assert!(theta.abs() < 1e-10);
+    }
+
     #[test]
     fn zeta()
     {
@@ -248,6 +321,52 @@ mod tests
         dbg!(angles);
     }
 
+    #[test]
+    fn companion_angles()
+    {
+        let companion = |n, d| RationalAngle::new(n, d).with_degree(2).companion_angle();
+
+        assert_eq!(companion(1, 3), Some(RationalAngle::new(2, 3)));
+        assert_eq!(companion(2, 7), Some(RationalAngle::new(1, 7)));
+        assert_eq!(companion(6, 15), Some(RationalAngle::new(9, 15)));
+        assert_eq!(companion(7, 15), Some(RationalAngle::new(8, 15)));
+        // The airplane component of period 3, and the component of period 6 bifurcating from it
+        assert_eq!(companion(3, 7), Some(RationalAngle::new(4, 7)));
+        assert_eq!(companion(28, 63), Some(RationalAngle::new(35, 63)));
+
+        assert_eq!(companion(0, 1), None);
+        assert_eq!(companion(1, 4), None);
+        assert_eq!(RationalAngle::new(1, 8).with_degree(3).companion_angle(), None);
+    }
+
+    #[test]
+    fn tuning()
+    {
+        use crate::tuning::{renormalize, tune, Renormalization};
+
+        let angle = RationalAngle::new;
+
+        // Tuning by the period 2 component replaces 0 by 01 and 1 by 10
+        assert_eq!(tune(angle(1, 3), angle(1, 7)), Ok(angle(22, 63)));
+        assert_eq!(tune(angle(2, 3), angle(2, 7)), Ok(angle(25, 63)));
+        assert_eq!(tune(angle(1, 3), angle(1, 2)), Ok(angle(7, 12)));
+        assert_eq!(tune(angle(4, 7), angle(1, 3)), Ok(angle(28, 63)));
+        assert!(tune(angle(1, 4), angle(1, 3)).is_err());
+
+        assert_eq!(
+            renormalize(angle(22, 63)),
+            Some(Renormalization {
+                root_angles: (angle(1, 3), angle(2, 3)),
+                period: 2,
+                base_angle: angle(1, 7),
+            })
+        );
+        assert_eq!(renormalize(angle(7, 12)).map(|r| r.base_angle), Some(angle(1, 2)));
+        assert_eq!(renormalize(angle(28, 63)).map(|r| r.period), Some(3));
+        assert_eq!(renormalize(angle(1, 7)), None);
+        assert_eq!(renormalize(angle(1, 3)), None);
+    }
+
     #[test]
     fn fmt_angle()
     {
@@ -259,4 +378,571 @@ mod tests
         let s = format!("{it:>13}");
         assert_eq!(s, "    p01101001");
     }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn iter_plane_raw_data()
+    {
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo, PointInfoPeriodic};
+
+        let bounds = Bounds {
+            min_x: -2.,
+            max_x: 2.,
+            min_y: -1.,
+            max_y: 1.,
+        };
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(4, 2, bounds));
+        iter_plane.iter_counts[(0, 0)] = PointInfo::Escaping {
+            potential: 3.5,
+            phase: Some(1),
+            angle: None,
+        };
+        iter_plane.iter_counts[(3, 1)] = PointInfo::Periodic(PointInfoPeriodic {
+            preperiod: 4,
+            period: 3,
+            multiplier: Cplx::new(0.25, -0.5),
+            final_error: 1e-9,
+            smooth_preperiod: 3.25,
+            cycle_point: Cplx::new(-0.5, 0.125),
+        });
+
+        let path = std::env::temp_dir().join("dynamo_iter_plane_raw_data.bin");
+        iter_plane.save_data(&path).unwrap();
+        let loaded = IterPlane::<Cplx>::load_data(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.point_grid, iter_plane.point_grid);
+        assert_eq!(loaded.iter_counts, iter_plane.iter_counts);
+    }
+    #[test]
+    fn iter_plane_period_components()
+    {
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo, PointInfoKnownPotential};
+
+        let bounds = Bounds {
+            min_x: -1.,
+            max_x: 1.,
+            min_y: -1.,
+            max_y: 1.,
+        };
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(6, 5, bounds));
+        iter_plane.iter_counts.fill(PointInfo::Escaping {
+            potential: 1.,
+            phase: None,
+            angle: None,
+        });
+        let periodic = |period| {
+            PointInfo::PeriodicKnownPotential(PointInfoKnownPotential {
+                period,
+                multiplier: Cplx::new(0., 0.),
+                potential: 0.,
+            })
+        };
+        // An L-shaped region of period 1, which touches a period 2 region only diagonally
+        for idx in [(0, 0), (1, 0), (2, 0), (0, 1), (0, 2)] {
+            iter_plane.iter_counts[idx] = periodic(1);
+        }
+        for idx in [(1, 3), (2, 3), (1, 4)] {
+            iter_plane.iter_counts[idx] = periodic(2);
+        }
+        // A separate region of period 1
+        for idx in [(4, 3), (5, 3), (4, 4), (5, 4)] {
+            iter_plane.iter_counts[idx] = periodic(1);
+        }
+
+        let components = dbg!(iter_plane.period_components());
+        assert_eq!(components.len(), 3);
+        assert_eq!(components.iter().map(|c| c.size).sum::<usize>(), 12);
+        // The centroid of the L lies outside it, between two pixels of the region
+        assert!(components
+            .iter()
+            .any(|c| c.key == 1 && c.size == 5 && [(1, 0), (0, 1)].contains(&c.center)));
+        assert!(components.iter().any(|c| c.key == 2 && c.size == 3));
+        assert!(components.iter().any(|c| c.key == 1 && c.size == 4));
+    }
+    #[test]
+    fn iter_plane_attractors()
+    {
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo, PointInfoPeriodic};
+
+        let bounds = Bounds {
+            min_x: -1.,
+            max_x: 1.,
+            min_y: -1.,
+            max_y: 1.,
+        };
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(4, 4, bounds));
+        let periodic = |period, cycle_point, multiplier| {
+            PointInfo::Periodic(PointInfoPeriodic {
+                preperiod: 10,
+                period,
+                multiplier: Cplx::new(multiplier, 0.),
+                final_error: 0.,
+                smooth_preperiod: 10.,
+                cycle_point,
+            })
+        };
+        // Two fixed points, found to within the tolerance, and a cycle of period 2 through one
+        // of them, which should still be told apart by its period
+        let (z0, z1) = (Cplx::new(1., 0.), Cplx::new(-1., 0.));
+        for x in 0..4 {
+            iter_plane.iter_counts[(x, 0)] = periodic(1, z1 + 1e-6 * x as f64, 0.5);
+            iter_plane.iter_counts[(x, 1)] = periodic(1, z0, 0.5);
+            iter_plane.iter_counts[(x, 2)] = periodic(2, z0, 0.25);
+        }
+        // A repelling fixed point, which is not an attractor
+        iter_plane.iter_counts[(0, 3)] = periodic(1, Cplx::new(0., 0.), 2.);
+
+        assert_eq!(iter_plane.classify_basins(1e-3), 3);
+        let attractors = dbg!(iter_plane.attractors());
+        assert_eq!(attractors.len(), 3);
+        // Classes are ordered by the location of their cycles
+        assert_eq!(attractors[0].cycle_point, z1);
+        assert!(attractors.iter().all(|a| (a.basin_fraction - 0.25).abs() < 1e-9));
+        assert_eq!(attractors[1].period + attractors[2].period, 3);
+        assert!(matches!(iter_plane.iter_counts[(0, 3)], PointInfo::Periodic(_)));
+    }
+
+    #[test]
+    fn iter_plane_component_boundary()
+    {
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo, PointInfoKnownPotential};
+
+        // Pixels of unit size, centered on the integers
+        let bounds = Bounds {
+            min_x: -3.5,
+            max_x: 3.5,
+            min_y: -3.5,
+            max_y: 3.5,
+        };
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(7, 7, bounds));
+        let periodic = |period| {
+            PointInfo::PeriodicKnownPotential(PointInfoKnownPotential {
+                period,
+                multiplier: Cplx::new(0., 0.),
+                potential: 0.,
+            })
+        };
+        for x in 2..=4 {
+            for y in 2..=4 {
+                iter_plane.iter_counts[(x, y)] = periodic(1);
+            }
+        }
+        // Touches the block only diagonally, so is not part of its component
+        iter_plane.iter_counts[(5, 5)] = periodic(1);
+
+        let signed_area = |polygon: &[Cplx]| {
+            polygon
+                .iter()
+                .zip(polygon.iter().cycle().skip(1))
+                .map(|(z0, z1)| z0.re.mul_add(z1.im, -z1.re * z0.im) / 2.)
+                .sum::<f64>()
+        };
+
+        // The square block with its corners cut off
+        let boundary = dbg!(iter_plane.period_component_boundary(Cplx::new(0.2, -0.3)));
+        assert_eq!(boundary.len(), 1);
+        assert_eq!(boundary[0].len(), 8);
+        assert!((signed_area(&boundary[0]) - 8.5).abs() < 1e-12);
+
+        // A hole in the middle runs the other way
+        iter_plane.iter_counts[(3, 3)] = periodic(2);
+        let boundary = iter_plane.period_component_boundary(Cplx::new(-1., 1.));
+        assert_eq!(boundary.len(), 2);
+        let mut areas: Vec<_> = boundary.iter().map(|polygon| signed_area(polygon)).collect();
+        areas.sort_by(f64::total_cmp);
+        assert!((areas[0] + 0.5).abs() < 1e-12);
+        assert!((areas[1] - 8.5).abs() < 1e-12);
+
+        assert!(iter_plane
+            .period_component_boundary(Cplx::new(3., -3.))
+            .is_empty());
+    }
+    #[test]
+    fn iter_plane_potential_contours()
+    {
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo};
+
+        let bounds = Bounds {
+            min_x: -4.5,
+            max_x: 4.5,
+            min_y: -4.5,
+            max_y: 4.5,
+        };
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(9, 9, bounds));
+        // Potential growing with the distance from the center, which does not escape
+        let center = iter_plane.point_grid.map_pixel(4, 4);
+        for x in 0..9 {
+            for y in 0..9 {
+                let z = iter_plane.point_grid.map_pixel(x, y);
+                iter_plane.iter_counts[(x, y)] = PointInfo::Escaping {
+                    potential: (z - center).norm(),
+                    phase: None,
+                    angle: None,
+                };
+            }
+        }
+        iter_plane.iter_counts[(4, 4)] = PointInfo::Bounded;
+
+        let contours = iter_plane.potential_contours(1.);
+        let at_level = |level: f64| {
+            contours
+                .iter()
+                .filter(move |contour| (contour.level - level).abs() < 1e-12)
+        };
+        // Circles around the center, except where they leave the image
+        for level in [2., 3.] {
+            let circles: Vec<_> = at_level(level).collect();
+            assert_eq!(circles.len(), 1);
+            assert!(circles[0].is_closed());
+            assert!(circles[0]
+                .points
+                .iter()
+                .all(|z| ((z - center).norm() - level).abs() < 0.15));
+        }
+        for level in [4., 5.] {
+            assert_eq!(at_level(level).count(), 4);
+            assert!(at_level(level).all(|arc| !arc.is_closed()));
+        }
+        // Cells touching the center are left out
+        assert!(at_level(1.).all(|arc| !arc.is_closed()));
+
+        let mut levels: Vec<_> = iter_plane
+            .potential_contours(2.)
+            .iter()
+            .map(|contour| contour.level)
+            .collect();
+        levels.dedup();
+        assert_eq!(levels, [2., 4.]);
+        assert!(iter_plane.potential_contours(0.).is_empty());
+    }
+    #[test]
+    fn iter_plane_gradient()
+    {
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo};
+
+        let bounds = Bounds {
+            min_x: 0.,
+            max_x: 5.,
+            min_y: 0.,
+            max_y: 5.,
+        };
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(5, 5, bounds));
+        for ((x, y), point_info) in iter_plane.iter_counts.indexed_iter_mut() {
+            *point_info = PointInfo::Escaping {
+                potential: (x + 2 * y) as f64,
+                phase: None,
+                angle: None,
+            };
+        }
+        iter_plane.iter_counts[(2, 2)] = PointInfo::Bounded;
+        let height = |point_info: &PointInfo<Cplx>| match point_info {
+            PointInfo::Escaping { potential, .. } => Some(*potential),
+            _ => None,
+        };
+
+        let gradient = iter_plane.gradient(height);
+        assert_eq!(gradient[(2, 2)], None);
+        // Central differences inside, and one-sided ones at the edges and beside the hole
+        for ((x, y), g) in gradient.indexed_iter() {
+            if (x, y) != (2, 2) {
+                assert_eq!(*g, Some([1., 2.]));
+            }
+        }
+    }
+    #[test]
+    fn iter_plane_statistics()
+    {
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo, PointInfoKnownPotential};
+
+        let bounds = Bounds {
+            min_x: -1.,
+            max_x: 1.,
+            min_y: -1.,
+            max_y: 1.,
+        };
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(5, 2, bounds));
+        let periodic = |period| {
+            PointInfo::PeriodicKnownPotential(PointInfoKnownPotential {
+                period,
+                multiplier: Cplx::new(0., 0.),
+                potential: 0.,
+            })
+        };
+        let escaping = |potential| PointInfo::Escaping {
+            potential,
+            phase: None,
+            angle: None,
+        };
+        iter_plane.iter_counts[(0, 0)] = periodic(2);
+        iter_plane.iter_counts[(1, 0)] = periodic(2);
+        iter_plane.iter_counts[(2, 0)] = periodic(3);
+        iter_plane.iter_counts[(3, 0)] = escaping(4.2);
+        iter_plane.iter_counts[(4, 0)] = escaping(4.9);
+        iter_plane.iter_counts[(0, 1)] = escaping(7.5);
+        iter_plane.iter_counts[(1, 1)] = PointInfo::DistanceEstimate {
+            distance: 0.1,
+            phase: 1,
+        };
+
+        let stats = dbg!(iter_plane.statistics());
+        assert_eq!(stats.total, 10);
+        assert!((stats.interior_fraction() - 0.3).abs() < 1e-12);
+        assert!((stats.exterior_fraction() - 0.4).abs() < 1e-12);
+        assert!((stats.undetermined_fraction() - 0.3).abs() < 1e-12);
+        assert_eq!(stats.periods.into_iter().collect::<Vec<_>>(), [(2, 2), (3, 1)]);
+        assert_eq!(stats.escape_iters.into_iter().collect::<Vec<_>>(), [(4, 2), (7, 1)]);
+    }
+    #[test]
+    fn box_counting_dimension()
+    {
+        use crate::analysis::box_counting_dimension;
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo, PointInfoKnownPotential};
+
+        let bounds = Bounds {
+            min_x: -1.,
+            max_x: 1.,
+            min_y: -1.,
+            max_y: 1.,
+        };
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(PointGrid::new(64, 64, bounds));
+        assert!(box_counting_dimension(&iter_plane).is_none());
+
+        // A straight boundary between escaping and periodic points
+        for ((x, _), info) in iter_plane.iter_counts.indexed_iter_mut() {
+            *info = if x < 32 {
+                PointInfo::Escaping {
+                    potential: 1.,
+                    phase: None,
+                    angle: None,
+                }
+            } else {
+                PointInfo::PeriodicKnownPotential(PointInfoKnownPotential {
+                    period: 1,
+                    multiplier: Cplx::new(0., 0.),
+                    potential: 0.,
+                })
+            };
+        }
+        let dim = dbg!(box_counting_dimension(&iter_plane).unwrap());
+        assert!((dim.value - 1.).abs() < 1e-12);
+        assert!(dim.error < 1e-12);
+
+        // Every point is within a pixel of the boundary
+        iter_plane.iter_counts.fill(PointInfo::DistanceEstimate {
+            distance: 1e-3,
+            phase: 1,
+        });
+        let dim = dbg!(box_counting_dimension(&iter_plane).unwrap());
+        assert!((dim.value - 2.).abs() < 1e-12);
+    }
+    #[test]
+    fn point_grid_row_strips()
+    {
+        use crate::prelude::{Bounds, PointGrid};
+
+        let bounds = Bounds {
+            min_x: -2.,
+            max_x: 2.,
+            min_y: -1.5,
+            max_y: 1.5,
+        };
+        let grid = PointGrid::new(8, 6, bounds);
+
+        for (first_row, num_rows) in [(0, 4), (4, 2)] {
+            let strip = grid.row_strip(first_row, num_rows);
+            assert_eq!(strip.shape(), (8, num_rows));
+            for x in 0..8 {
+                for y in 0..num_rows {
+                    let full_y = grid.res_y - first_row - num_rows + y;
+                    let diff = strip.map_pixel(x, y) - grid.map_pixel(x, full_y);
+                    assert!(diff.norm() < 1e-12);
+                }
+            }
+        }
+    }
+    #[test]
+    fn hyperbolic_distance()
+    {
+        use crate::math_utils::hyperbolic_distance;
+
+        let d = hyperbolic_distance(Cplx::new(0., 0.), Cplx::new(0.5, 0.)).unwrap();
+        assert!((d - 3_f64.ln()).abs() < 1e-12);
+
+        // Invariant under automorphisms of the disk
+        let a = Cplx::new(0.3, -0.4);
+        let mobius = |z: Cplx| (z - a) / (1. - a.conj() * z);
+        let (z, w) = (Cplx::new(-0.2, 0.6), Cplx::new(0.7, 0.1));
+        let d0 = hyperbolic_distance(z, w).unwrap();
+        let d1 = hyperbolic_distance(mobius(z), mobius(w)).unwrap();
+        assert!((d0 - d1).abs() < 1e-12);
+
+        assert!(hyperbolic_distance(z, Cplx::new(1., 0.)).is_none());
+    }
+
+    #[test]
+    fn angle_patterns()
+    {
+        use crate::angle_pattern::{AnglePattern, AnglePatternError};
+
+        let angles = |text: &str| text.parse::<AnglePattern>().unwrap().angles(2);
+
+        let period_3 = angles("period 3").unwrap();
+        assert_eq!(period_3.len(), 6);
+        assert!(period_3.contains(&RationalAngle::new(1, 7)));
+
+        // 1 + 2 + 6 + 12 angles of periods 1 through 4 under doubling
+        assert_eq!(angles("Period <= 4").unwrap().len(), 21);
+        assert_eq!(
+            angles("period ≤ 2").unwrap(),
+            [
+                RationalAngle::new(0, 1),
+                RationalAngle::new(1, 3),
+                RationalAngle::new(2, 3)
+            ]
+        );
+
+        let odd = angles("k/255 for k odd").unwrap();
+        assert_eq!(odd.len(), 127);
+        assert!(odd.contains(&RationalAngle::new(1, 255)));
+        assert_eq!(angles("k/8 even").unwrap().len(), 4);
+
+        let list = angles("1/7, 2/7 011p10 1/7").unwrap();
+        assert_eq!(
+            list,
+            [
+                RationalAngle::new(1, 7),
+                RationalAngle::new(2, 7),
+                RationalAngle::new(11, 24)
+            ]
+        );
+
+        assert!(matches!(angles("period 40"), Err(AnglePatternError::TooMany)));
+        assert!(matches!(
+            "".parse::<AnglePattern>(),
+            Err(AnglePatternError::Empty)
+        ));
+        assert!("1/7, foo".parse::<AnglePattern>().is_err());
+    }
+
+    #[test]
+    fn point_grid_transform()
+    {
+        use crate::prelude::{Bounds, PlaneTransform, PointGrid, ViewTransform};
+
+        let mobius = ViewTransform::Mobius {
+            a: Cplx::new(1., 1.),
+            b: Cplx::new(0., 2.),
+            c: Cplx::new(0.5, 0.),
+            d: Cplx::new(3., -1.),
+        };
+        let inversion = ViewTransform::Inversion {
+            center: Cplx::new(0.5, 0.),
+            radius: 2.,
+        };
+        let transform = mobius.then(inversion);
+
+        let z = Cplx::new(0.3, -0.7);
+        let w = transform.invert(transform.apply(z)).unwrap();
+        assert!((w - z).norm() < 1e-12);
+
+        let grid = PointGrid::new(8, 8, Bounds::centered_square(2.)).with_transform(transform);
+        let [x, y] = grid.locate_point(grid.map_pixel(3, 5)).unwrap();
+        assert!((x - 3.).abs() < 1e-4);
+        assert!((y - 2.).abs() < 1e-4);
+
+        // Folded points are located in the wedge, and points outside it not at all
+        let kaleidoscope = ViewTransform::Kaleidoscope {
+            center: Cplx::new(0., 0.),
+            order: 4,
+        };
+        let inside = Cplx::from_polar(0.5, 0.3);
+        assert_eq!(kaleidoscope.invert(inside), Some(inside));
+        assert_eq!(kaleidoscope.invert(Cplx::from_polar(0.5, 2.)), None);
+        let grid = PointGrid::new(8, 8, Bounds::centered_square(2.)).with_transform(kaleidoscope);
+        assert!(grid.locate_point(Cplx::new(-0.5, -0.5)).is_none());
+        let curve = [0.1, 0.2, 2., 0.3, 0.4].map(|theta| Cplx::from_polar(0.5, theta));
+        let runs = grid.locate_curve(&curve);
+        assert_eq!(runs.iter().map(Vec::len).collect::<Vec<_>>(), [2, 2]);
+    }
+
+    #[test]
+    fn fast_escape_level()
+    {
+        use crate::math_utils::fast_escape_level;
+        use std::f64::consts::TAU;
+
+        let radius = 1e5_f64.sqrt();
+        let exp_orbit = std::iter::successors(Some(Cplx::new(2., 0.)), |z| Some(z.exp())).take(6);
+        assert_eq!(fast_escape_level(exp_orbit, radius), Some(2));
+
+        let shift_orbit = (0..200).map(|k| Cplx::new(radius + TAU * f64::from(k), 0.));
+        assert_eq!(fast_escape_level(shift_orbit, radius), None);
+    }
+
+    #[test]
+    fn translate_and_invalidate()
+    {
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo};
+
+        let grid = PointGrid::new(5, 4, Bounds::centered_square(2.));
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(grid.clone());
+        for ((x, y), point_info) in iter_plane.iter_counts.indexed_iter_mut() {
+            *point_info = PointInfo::Escaping {
+                potential: (x + 10 * y) as f64,
+                phase: None,
+                angle: None,
+            };
+        }
+
+        let (dx, dy) = grid.pixel_offset(Cplx::new(2.1 * grid.pixel_width(), -grid.pixel_height()));
+        assert_eq!((dx, dy), (2, -1));
+        let exposed = iter_plane.translate_and_invalidate(dx, dy).unwrap();
+        assert_eq!(exposed, vec![(3..5, 0..4), (0..3, 0..1)]);
+
+        // Kept pixels show the same points as before
+        let mut translated = grid.clone();
+        translated.translate_pixels(2, -1);
+        assert_eq!(iter_plane.point_grid, translated);
+        assert!((translated.map_pixel(0, 1) - grid.map_pixel(2, 0)).norm() < 1e-12);
+        assert_eq!(
+            iter_plane.iter_counts[(0, 1)],
+            PointInfo::Escaping {
+                potential: 2.,
+                phase: None,
+                angle: None,
+            }
+        );
+        assert_eq!(iter_plane.iter_counts[(3, 2)], PointInfo::Unknown);
+        assert_eq!(iter_plane.iter_counts[(1, 0)], PointInfo::Unknown);
+
+        assert!(iter_plane.translate_and_invalidate(0, 4).is_none());
+        assert_eq!(iter_plane.translate_and_invalidate(0, 0), Some(Vec::new()));
+    }
+
+    #[test]
+    fn zoom_preview()
+    {
+        use crate::prelude::{Bounds, IterPlane, PointGrid, PointInfo};
+
+        let grid = PointGrid::new(8, 8, Bounds::centered_square(2.));
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(grid.clone());
+        for ((x, y), point_info) in iter_plane.iter_counts.indexed_iter_mut() {
+            *point_info = PointInfo::Escaping {
+                potential: (x + 10 * y) as f64,
+                phase: None,
+                angle: None,
+            };
+        }
+
+        // Zooming in by half about a corner keeps every other point along each axis
+        let mut zoomed = grid.clone();
+        zoomed.zoom(0.5, grid.map_pixel(0, 0));
+        let (preview, stale) = iter_plane.zoom_preview(zoomed.clone());
+        assert_eq!(preview.point_grid, zoomed);
+        assert_eq!(stale.iter().filter(|stale| !**stale).count(), 16);
+        assert!(!stale[(2, 4)]);
+        assert_eq!(preview.iter_counts[(2, 4)], iter_plane.iter_counts[(1, 2)]);
+        assert!(stale[(1, 0)]);
+        assert_eq!(preview.iter_counts[(1, 0)], iter_plane.iter_counts[(1, 0)]);
+    }
 }
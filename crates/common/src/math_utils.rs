@@ -1,8 +1,9 @@
 pub use num::integer::binomial;
 
-use crate::consts::{LOG_PI, TAUI, ZERO};
-use crate::types::{Cplx, Real};
+use crate::consts::{LOG_PI, ONE, TAUI, ZERO};
+use crate::types::{Cplx, Period, Real};
 use num_complex::ComplexFloat;
+use riemann_siegel::{riemann_siegel_zeta, theta_d2, use_riemann_siegel};
 pub use spfunc::{
     gamma::{digamma, gamma, polygamma},
     zeta::zeta,
@@ -14,6 +15,7 @@ pub mod arithmetic;
 pub mod contour;
 pub mod newton;
 pub mod polynomial_roots;
+pub mod riemann_siegel;
 pub mod taylor;
 
 #[must_use]
@@ -63,6 +65,42 @@ pub fn slog(x: Real) -> Real
     }
 }
 
+/// Logarithm of the modulus past which orbits can no longer be followed in double precision.
+const FAST_ESCAPE_LOG_CAP: Real = 34.5;
+
+/// Iterate at which an orbit of a transcendental entire map enters its fast escaping set, or
+/// `None` if it never appears to.
+///
+/// Following Eremenko and Lyubich, the orbit is tracked in logarithmic coordinates `t = log|z|`,
+/// in which the maximum modulus of a map of exponential type acts as `t ↦ exp(t)`. The orbit
+/// enters at iterate `level` if from there on it keeps pace with the iterated maximum modulus of
+/// the circle of radius `radius`, until that is too large to follow. Non-finite points count as
+/// having escaped.
+#[must_use]
+pub fn fast_escape_level<I>(orbit: I, radius: Real) -> Option<Period>
+where
+    I: IntoIterator<Item = Cplx>,
+{
+    let mut thresholds = vec![radius.ln()];
+    while let Some(&t) = thresholds.last().filter(|&&t| t < FAST_ESCAPE_LOG_CAP) {
+        thresholds.push(t.exp().min(FAST_ESCAPE_LOG_CAP));
+    }
+    let log_moduli: Vec<Real> = orbit
+        .into_iter()
+        .map(|z| {
+            if z.is_finite() {
+                z.norm().ln()
+            } else {
+                Real::INFINITY
+            }
+        })
+        .collect();
+    log_moduli
+        .windows(thresholds.len())
+        .position(|window| window.iter().zip(&thresholds).all(|(t, s)| t >= s))
+        .and_then(|level| Period::try_from(level).ok())
+}
+
 const fn bernoulli(n: u64) -> f64
 {
     match n {
@@ -112,61 +150,55 @@ fn factorial(n: u64) -> f64
     }
 }
 
-fn zeta_t(k: u64, nf: f64, s: Cplx) -> Cplx
-{
-    let two_k = k + k;
-    let t0 = bernoulli(two_k) / factorial(two_k);
-    let t1 = nf.powc(1. - s - (two_k as f64));
-    let t2: Cplx = (0..two_k - 1).map(|j| s + (j as f64)).product();
-    t0 * t1 * t2
-}
-
-fn zeta_t_d(k: u64, nf: f64, s: Cplx) -> [Cplx; 2]
+/// Correction terms of the Euler–Maclaurin formula for zeta, with their first two derivatives.
+///
+/// Returns the sum over `1 <= k <= m` of `B_2k / (2k)! * n^(-2k) * s(s+1)...(s+2k-2)`, which
+/// is the tail of the formula up to a factor of `n^(1-s)`. Each product is built from the
+/// previous one, so the sum costs O(m) operations rather than O(m^2).
+fn zeta_tail(m: u64, nf: f64, s: Cplx) -> [Cplx; 3]
 {
-    let two_k = k + k;
-    let t0 = bernoulli(two_k) / factorial(two_k);
-    let t1 = nf.powc(1. - s - (two_k as f64));
-    let dt1 = -t1 * nf.ln();
-    let t2: Cplx = (0..two_k - 1).map(|j| s + (j as f64)).product();
-    let dt2: Cplx = (0..two_k - 1).map(|j| t2 / (s + (j as f64))).sum();
-    [t0 * t1 * t2, t0 * (t1 * dt2 + dt1 * t2)]
-}
-
-fn zeta_t_d2(k: u64, nf: f64, s: Cplx) -> [Cplx; 3]
-{
-    let two_k = k + k;
-    let t0 = bernoulli(two_k) / factorial(two_k);
-    let t1d0 = nf.powc(1. - s - (two_k as f64));
-    let t1d1 = -t1d0 * nf.ln();
-    let t1d2 = -t1d1 * nf.ln();
-
-    let t2d0: Cplx = (0..two_k - 1).map(|j| s + (j as f64)).product();
-    let t2d1: Cplx = (0..two_k - 1).map(|j| t2d0 / (s + (j as f64))).sum();
-    let t2d2: Cplx = (0..two_k - 1)
-        .map(|j| {
-            let v = s + (j as f64);
-            (t2d1 * v - t2d0) / (v * v)
-        })
-        .sum();
-    [
-        t0 * t1d0 * t2d0,
-        t0 * (t1d0 * t2d1 + t1d1 * t2d0),
-        t0 * (t1d0 * t2d2 + 2. * t1d1 * t2d1 + t1d2 * t2d0),
-    ]
+    let n_sqr_inv = nf.powi(-2);
+    let mut scale = 1.;
+    let [mut p0, mut p1, mut p2] = [s, ONE, ZERO];
+    let mut sums = [ZERO; 3];
+
+    for k in 1..=m {
+        let two_k = k + k;
+        scale *= n_sqr_inv;
+        let coeff = scale * bernoulli(two_k) / factorial(two_k);
+        sums[0] += coeff * p0;
+        sums[1] += coeff * p1;
+        sums[2] += coeff * p2;
+
+        // Extend the product by (s + 2k - 1)(s + 2k)
+        let a = s + (two_k - 1) as f64;
+        let b = s + two_k as f64;
+        let q0 = a * b;
+        let q1 = a + b;
+        p2 = p2 * q0 + 2. * (p1 * q1 + p0);
+        p1 = p1 * q0 + p0 * q1;
+        p0 *= q0;
+    }
+    sums
 }
 
 // The Riemann zeta function
 #[must_use]
 pub fn riemann_zeta(s: Cplx) -> Cplx
 {
+    if use_riemann_siegel(s) {
+        let [z] = riemann_siegel_zeta(s);
+        return z;
+    }
     let n = 12;
     let m = 12;
     let u = 1. - s;
     let nf = f64::from(n);
     let s0: Cplx = (1..n).map(|j| f64::from(j).powc(-s)).sum();
     let s1 = 0.5 * nf.powc(-s);
-    let s2 = nf.powc(u) / u;
-    let s3: Cplx = (1..=m).map(|k| zeta_t(k, nf, s)).sum();
+    let n_pow_u = nf.powc(u);
+    let s2 = n_pow_u / u;
+    let s3 = n_pow_u * zeta_tail(m, nf, s)[0];
 
     s0 + s1 - s2 + s3
 }
@@ -175,10 +207,14 @@ pub fn riemann_zeta(s: Cplx) -> Cplx
 #[must_use]
 pub fn riemann_zeta_d(s: Cplx) -> [Cplx; 2]
 {
+    if use_riemann_siegel(s) {
+        return riemann_siegel_zeta(s);
+    }
     let n = 12;
     let m = 12;
     let u = 1. - s;
     let nf = f64::from(n);
+    let log_n = nf.ln();
     let [s0, ds0]: [Cplx; 2] = (1..n)
         .map(|j| {
             let jf = f64::from(j);
@@ -187,12 +223,13 @@ pub fn riemann_zeta_d(s: Cplx) -> [Cplx; 2]
         })
         .fold([ZERO, ZERO], |[a, da], [b, db]| [a + b, da + db]);
     let s1 = 0.5 * nf.powc(-s);
-    let ds1 = -s1 * nf.ln();
-    let s2 = nf.powc(u) / u;
-    let ds2 = s2 * (u.inv() - nf.ln());
-    let [s3, ds3]: [Cplx; 2] = (1..=m)
-        .map(|k| zeta_t_d(k, nf, s))
-        .fold([ZERO, ZERO], |[a, da], [b, db]| [a + b, da + db]);
+    let ds1 = -s1 * log_n;
+    let n_pow_u = nf.powc(u);
+    let s2 = n_pow_u / u;
+    let ds2 = s2 * (u.inv() - log_n);
+    let [t0, t1, _] = zeta_tail(m, nf, s);
+    let s3 = n_pow_u * t0;
+    let ds3 = n_pow_u * t1 - s3 * log_n;
 
     [s0 + s1 - s2 + s3, ds0 + ds1 - ds2 + ds3]
 }
@@ -201,6 +238,9 @@ pub fn riemann_zeta_d(s: Cplx) -> [Cplx; 2]
 #[must_use]
 pub fn riemann_zeta_d2(s: Cplx) -> [Cplx; 3]
 {
+    if use_riemann_siegel(s) {
+        return riemann_siegel_zeta(s);
+    }
     let n = 14;
     let m = 10;
     let u = 1. - s;
@@ -223,16 +263,16 @@ pub fn riemann_zeta_d2(s: Cplx) -> [Cplx; 3]
     let s1d2 = -s1d1 * log_n;
 
     let u_inv = u.inv();
-    let s2d0 = nf.powc(u) * u_inv;
-    let alpha = u_inv - nf.ln();
+    let n_pow_u = nf.powc(u);
+    let s2d0 = n_pow_u * u_inv;
+    let alpha = u_inv - log_n;
     let s2d1 = s2d0 * alpha;
     let s2d2 = s2d1 * alpha + s2d0 * u_inv * u_inv;
 
-    let [s3d0, s3d1, s3d2]: [Cplx; 3] = (1..=m)
-        .map(|k| zeta_t_d2(k, nf, s))
-        .fold([ZERO, ZERO, ZERO], |[a0, a1, a2], [b0, b1, b2]| {
-            [a0 + b0, a1 + b1, a2 + b2]
-        });
+    let [t0, t1, t2] = zeta_tail(m, nf, s);
+    let s3d0 = n_pow_u * t0;
+    let s3d1 = n_pow_u * (t1 - t0 * log_n);
+    let s3d2 = n_pow_u * (t2 - (2. * t1 - t0 * log_n) * log_n);
 
     [
         s0d0 + s1d0 - s2d0 + s3d0,
@@ -272,13 +312,23 @@ pub fn riemann_xi_d(s: Cplx) -> [Cplx; 2]
 
 #[must_use]
 pub fn riemann_xi_d2(s: Cplx) -> [Cplx; 3]
+{
+    riemann_xi_d2_with(s, riemann_zeta_d2)
+}
+
+/// Xi and its first two derivatives, taking zeta and its derivatives from `zeta_d2`, for
+/// example from a cache.
+#[must_use]
+pub fn riemann_xi_d2_with<F>(s: Cplx, zeta_d2: F) -> [Cplx; 3]
+where
+    F: FnOnce(Cplx) -> [Cplx; 3],
 {
     if s.re < -5. {
         // avoid underflow issues for large negative s
-        let [z0, z1, z2] = riemann_xi_d2(1.0 - s);
+        let [z0, z1, z2] = riemann_xi_d2_with(1.0 - s, zeta_d2);
         return [z0, -z1, z2];
     }
-    let [z0, z1, z2] = riemann_zeta_d2(s);
+    let [z0, z1, z2] = zeta_d2(s);
 
     let x0 = s - 1.;
     let x1 = 0.5 * s;
@@ -310,6 +360,26 @@ pub fn riemann_xi_d2(s: Cplx) -> [Cplx; 3]
     ]
 }
 
+/// The Riemann–Siegel theta function, computed from its asymptotic expansion.
+/// Accurate to double precision for `t > 20`.
+#[must_use]
+pub fn riemann_siegel_theta(t: Real) -> Real
+{
+    theta_d2(t.into())[0].re
+}
+
+/// Distance between two points of the unit disk in the Poincaré metric, of curvature -1.
+/// Returns `None` unless both points lie in the open disk.
+#[must_use]
+pub fn hyperbolic_distance(z: Cplx, w: Cplx) -> Option<Real>
+{
+    if z.norm_sqr() >= 1. || w.norm_sqr() >= 1. {
+        return None;
+    }
+    let ratio = (z - w).norm() / (1. - w.conj() * z).norm();
+    Some(2. * ratio.atanh())
+}
+
 pub fn roots_of_unity(degree: i32) -> impl Iterator<Item = Cplx>
 {
     let theta = TAUI / f64::from(degree);
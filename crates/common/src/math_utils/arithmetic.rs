@@ -4,17 +4,16 @@ pub use num::{integer::gcd, Integer};
 #[must_use]
 pub const fn div_rem(a: Period, b: Period) -> Option<(Period, Period)>
 {
-    if b == 0 {
-        None
-    } else {
-        Some((a / b, a % b))
+    match (a.checked_div(b), a.checked_rem(b)) {
+        (Some(q), Some(r)) => Some((q, r)),
+        _ => None,
     }
 }
 
 pub fn divisors(n: Period) -> impl Iterator<Item = Period>
 {
     (1..).take_while(move |&x| x * x <= n).flat_map(move |x| {
-        if n % x == 0 {
+        if n.is_multiple_of(x) {
             if x * x == n {
                 vec![x].into_iter()
             } else {
@@ -42,10 +41,10 @@ pub const fn moebius(n: Period) -> SignedPeriod
     let mut n = n;
     let mut i = 2;
     while i * i <= n {
-        if n % i == 0 {
+        if n.is_multiple_of(i) {
             result = -result;
             n /= i;
-            if n % i == 0 {
+            if n.is_multiple_of(i) {
                 return 0;
             }
         }
@@ -57,6 +56,22 @@ pub const fn moebius(n: Period) -> SignedPeriod
     result
 }
 
+#[must_use]
+pub const fn is_prime(n: u64) -> bool
+{
+    if n < 2 {
+        return false;
+    }
+    let mut i = 2;
+    while i <= n / i {
+        if n.is_multiple_of(i) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 fn dirichlet_convolution<F, G>(f: F, g: G, n: Period) -> SignedPeriod
 where
     F: Fn(Period) -> SignedPeriod,
@@ -0,0 +1,391 @@
+//! The Riemann–Siegel formula for zeta and its derivatives, valid for any real part.
+//!
+//! Follows J. Arias de Reyna, "High precision computation of Riemann's zeta function by the
+//! Riemann–Siegel formula, I", Math. Comp. 80 (2011), which writes
+//! `zeta(s) = R(s) + chi(s) conj(R(1 - conj(s)))`, where `R(s)` is a partial sum of the
+//! Dirichlet series plus an asymptotic series of corrections in powers of `sqrt(2 pi / t)`.
+use crate::consts::ZERO;
+use crate::types::{Cplx, Real};
+use std::f64::consts::{PI, TAU};
+
+const I: Cplx = Cplx::new(0., 1.);
+
+/// Height above which the Riemann–Siegel formula is more accurate than Euler–Maclaurin
+/// summation near the critical strip.
+pub const RIEMANN_SIEGEL_HEIGHT: Real = 30.;
+
+/// Most correction terms summed; enough for a relative error around 1e-14 at the switchover.
+const MAX_TERMS: usize = 20;
+
+/// Highest order of derivative supported, plus one.
+const MAX_DERIVS: usize = 3;
+
+/// Taylor coefficients `c_0, c_2, c_4, ...` of the even entire function
+/// `F(z) = (exp(pi i (z^2/2 + 3/8)) - i sqrt(2) cos(pi z / 2)) / (2 cos(pi z))`.
+const KERNEL_COEFFS: [Cplx; 47] = [
+    Cplx::new(1.913_417_161_825_449e-1, -2.451_670_149_309_041_5e-1),
+    Cplx::new(2.186_202_340_387_602_1e-1, -3.693_383_488_496_295_6e-2),
+    Cplx::new(6.618_828_774_017_176e-2, 6.353_439_385_614_602e-2),
+    Cplx::new(-6.802_513_023_837_094e-3, 2.722_391_266_357_006_6e-2),
+    Cplx::new(-6.783_810_985_051_790_5e-3, 1.385_760_877_106_652e-3),
+    Cplx::new(-8.118_626_615_722_327e-4, -1.189_449_446_101_378e-3),
+    Cplx::new(1.485_267_686_668_984_5e-4, -2.126_982_019_289_332_3e-4),
+    Cplx::new(3.971_650_439_760_735e-5, 1.117_132_740_199_015_1e-5),
+    Cplx::new(2.327_806_230_725_225_2e-7, 5.872_858_398_652_07e-6),
+    Cplx::new(-7.163_625_815_477_553e-7, 2.498_212_552_923_518e-7),
+    Cplx::new(-5.177_423_556_156_473e-8, -7.308_700_305_101_552e-8),
+    Cplx::new(6.178_963_541_930_869e-9, -7.536_791_448_164_02e-9),
+    Cplx::new(8.940_541_928_977_453e-10, 4.104_425_797_331_228_4e-10),
+    Cplx::new(-1.695_707_194_963_518e-11, 9.106_559_550_294_084e-11),
+    Cplx::new(-8.163_316_951_282_953e-12, 4.348_099_095_249_618_7e-13),
+    Cplx::new(-1.892_554_659_270_610_3e-13, -6.520_913_261_540_13e-13),
+    Cplx::new(4.663_711_629_600_862_5e-14, -2.574_698_839_194_822e-14),
+    Cplx::new(2.610_921_507_989_068_5e-15, 2.978_335_196_086_287_4e-15),
+    Cplx::new(-1.675_336_536_372_132e-16, 2.241_756_964_196_517e-16),
+    Cplx::new(-1.706_213_261_405_863_2e-17, -7.993_661_378_773_457e-18),
+    Cplx::new(2.875_601_670_716_199_6e-19, -1.176_894_351_646_754_5e-18),
+    Cplx::new(7.447_650_681_605_753e-20, 3.424_141_569_957_982e-21),
+    Cplx::new(6.282_686_358_510_708e-22, 4.354_432_465_678_006e-21),
+    Cplx::new(-2.360_647_625_071_713e-22, 8.042_677_010_875_067e-23),
+    Cplx::new(-6.634_534_681_519_81e-24, -1.187_404_814_328_495e-23),
+    Cplx::new(5.526_719_997_560_709e-25, -4.533_873_522_499_421e-25),
+    Cplx::new(2.749_823_188_763_732_5e-26, 2.362_213_241_963_689_5e-26),
+    Cplx::new(-9.115_688_251_159_012e-28, 1.524_413_235_942_887e-27),
+    Cplx::new(-7.844_701_868_860_44e-29, -3.053_767_696_325_618_7e-29),
+    Cplx::new(7.919_817_544_119_006e-31, -3.781_590_707_554_057_6e-30),
+    Cplx::new(1.717_310_362_718_602e-31, 7.636_679_842_553_593e-33),
+    Cplx::new(8.510_516_750_158_508e-34, 7.372_397_943_589_341e-33),
+    Cplx::new(-2.997_559_652_478_908_3e-34, 8.353_560_735_145_064e-35),
+    Cplx::new(-5.243_841_377_047_226e-36, -1.154_837_971_637_702_6e-35),
+    Cplx::new(4.211_067_589_174_661e-37, -2.749_346_932_854_234_7e-37),
+    Cplx::new(1.292_351_929_885_977_8e-38, 1.448_843_596_340_657e-38),
+    Cplx::new(-4.673_819_687_444_993e-40, 5.610_659_823_239_161_5e-40),
+    Cplx::new(-2.284_709_612_621_850_6e-41, -1.396_692_208_275_691_7e-41),
+    Cplx::new(3.772_798_697_382_695e-43, -8.805_819_031_464_555e-43),
+    Cplx::new(3.230_907_942_792_524_4e-44, 8.689_265_480_319_489e-45),
+    Cplx::new(-1.394_116_437_437_602_3e-46, 1.132_761_726_750_953_8e-45),
+    Cplx::new(-3.804_472_338_043_379e-47, 6.121_338_093_808_064e-49),
+    Cplx::new(-1.890_072_850_162_448_5e-49, -1.225_917_996_210_053e-48),
+    Cplx::new(3.792_813_514_337_617e-50, -1.124_605_124_320_250_4e-50),
+    Cplx::new(5.058_014_301_198_256e-52, 1.126_624_908_936_978_8e-51),
+    Cplx::new(-3.210_106_836_283_472e-53, 1.986_810_051_168_017e-53),
+    Cplx::new(-7.153_223_556_992_548e-55, -8.755_761_001_690_95e-55),
+];
+
+/// The derivatives `F^(0)(0), F^(2)(0), F^(4)(0), ...` of the kernel.
+const KERNEL_DERIVS: [Cplx; KERNEL_COEFFS.len()] = {
+    let mut derivs = KERNEL_COEFFS;
+    let mut factorial = 1.;
+    let mut i = 0;
+    while i < derivs.len() {
+        derivs[i] = Cplx::new(derivs[i].re * factorial, derivs[i].im * factorial);
+        factorial *= ((2 * i + 1) * (2 * i + 2)) as Real;
+        i += 1;
+    }
+    derivs
+};
+
+/// Whether to compute zeta at `s` by the Riemann–Siegel formula rather than by Euler–Maclaurin
+/// summation, which converges faster far to the right of the critical strip.
+#[must_use]
+pub fn use_riemann_siegel(s: Cplx) -> bool
+{
+    s.im.abs() > 5.0f64.mul_add((s.re - 3.).max(0.), RIEMANN_SIEGEL_HEIGHT)
+}
+
+/// The Riemann–Siegel theta function of a complex argument, with its first two derivatives,
+/// from the asymptotic expansion. Accurate to double precision for `|w| > 20`.
+#[must_use]
+pub fn theta_d2(w: Cplx) -> [Cplx; 3]
+{
+    let w_inv = w.inv();
+    let w_inv_sqr = w_inv * w_inv;
+    let log_w = (w / TAU).ln();
+    let series = |c: [Real; 4]| c[0] + w_inv_sqr * (c[1] + w_inv_sqr * (c[2] + w_inv_sqr * c[3]));
+    [
+        0.5 * w * (log_w - 1.) - PI / 8.
+            + w_inv * series([1. / 48., 7. / 5760., 31. / 80640., 381. / 1_290_240.]),
+        0.5 * log_w
+            - w_inv_sqr * series([1. / 48., 21. / 5760., 155. / 80640., 2667. / 1_290_240.]),
+        w_inv * (0.5 + w_inv_sqr * series([1. / 24., 7. / 480., 31. / 2688., 889. / 53760.])),
+    ]
+}
+
+/// Derivatives of the kernel `F` at a point, computed as they are needed.
+#[derive(Clone, Debug)]
+struct Kernel
+{
+    /// `p^j / j!`, for the Taylor expansion of each derivative about zero.
+    powers: [Real; 2 * KERNEL_COEFFS.len()],
+    derivs: Vec<Cplx>,
+}
+
+impl Kernel
+{
+    fn new(p: Real) -> Self
+    {
+        let mut powers = [1.; 2 * KERNEL_COEFFS.len()];
+        for j in 1..powers.len() {
+            powers[j] = powers[j - 1] * p / j as Real;
+        }
+        Self {
+            powers,
+            derivs: Vec::with_capacity(3 * MAX_TERMS),
+        }
+    }
+
+    fn derivative(&mut self, order: usize) -> Cplx
+    {
+        while self.derivs.len() <= order {
+            let value = self.evaluate(self.derivs.len());
+            self.derivs.push(value);
+        }
+        self.derivs[order]
+    }
+
+    fn evaluate(&self, order: usize) -> Cplx
+    {
+        // Only the even derivatives at zero are nonzero
+        let first = order.div_ceil(2);
+        KERNEL_DERIVS
+            .iter()
+            .enumerate()
+            .skip(first)
+            .map(|(i, d)| d * self.powers[2 * i - order])
+            .sum()
+    }
+}
+
+/// Coefficients `d_k^(n)` of the correction terms and their derivatives in `s`, indexed by
+/// `[n][k][derivative]`.
+struct CorrectionCoeffs
+{
+    sigma: Real,
+    num_derivs: usize,
+    rows: Vec<Vec<[Real; MAX_DERIVS]>>,
+}
+
+impl CorrectionCoeffs
+{
+    fn new(sigma: Real, num_derivs: usize) -> Self
+    {
+        Self {
+            sigma,
+            num_derivs,
+            rows: Vec::with_capacity(MAX_TERMS),
+        }
+    }
+
+    fn get(&self, n: usize, k: isize, deriv: usize) -> Real
+    {
+        usize::try_from(k)
+            .ok()
+            .and_then(|k| self.rows.get(n)?.get(k))
+            .map_or(0., |d| d[deriv])
+    }
+
+    /// Compute the coefficients of the next correction term.
+    fn push_row(&mut self)
+    {
+        let n = self.rows.len();
+        if n == 0 {
+            self.rows.push(vec![[1., 0., 0.]]);
+            return;
+        }
+        let ps = 2.0f64.mul_add(-self.sigma, 1.);
+        let mut row = vec![[0.; MAX_DERIVS]; 3 * n / 2 + 1];
+        for k in 0..row.len() {
+            let ki = k as isize;
+            let m = (3 * n - 2 * k) as Real;
+            row[k][0] = if m == 0. {
+                // Sum of (-1)^(j+1) (2j)!/j! d_(k-j) over 1 <= j <= k
+                let mut ratio = 1.;
+                let mut sum = 0.;
+                for j in 1..=k {
+                    ratio *= (4 * j - 2) as Real;
+                    let sign = if j % 2 == 0 { -1. } else { 1. };
+                    sum += sign * ratio * row[k - j][0];
+                }
+                sum
+            } else {
+                -(m + 1.) * self.get(n - 1, ki - 2, 0)
+                    + self.get(n - 1, ki, 0) / (4. * m)
+                    + ps * self.get(n - 1, ki - 1, 0) / (2. * m)
+            };
+            let derivs = row[k].iter_mut().enumerate().take(self.num_derivs);
+            for (deriv, d) in derivs.skip(1) {
+                let d_mu = deriv as Real;
+                let older = if n >= 2 {
+                    2.0f64.mul_add(d_mu, -2.) * self.get_signed(n - 2, ki - 3, deriv, 2)
+                        + 2. * (self.sigma + (n as Real) - 2.)
+                            * self.get_signed(n - 2, ki - 3, deriv, 1)
+                } else {
+                    0.
+                };
+                *d = older - self.get(n - 1, ki - 1, deriv - 1);
+            }
+        }
+        self.rows.push(row);
+    }
+
+    /// The coefficient for `deriv - lower` derivatives, or zero if that is negative.
+    fn get_signed(&self, n: usize, k: isize, deriv: usize, lower: usize) -> Real
+    {
+        deriv
+            .checked_sub(lower)
+            .map_or(0., |deriv| self.get(n, k, deriv))
+    }
+}
+
+/// The asymptotic series of corrections in `R(s)` and its first `num_derivs - 1` derivatives,
+/// up to the factor `(-1)^(N-1) a^(-sigma) U`.
+fn correction_sum(
+    sigma: Real,
+    a: Real,
+    kernel: &mut Kernel,
+    num_derivs: usize,
+) -> [Cplx; MAX_DERIVS]
+{
+    // Derivatives of a^(-s) bring down powers of -ln(a), with binomial weights
+    let log_a_inv = -a.ln();
+    let weights = [[1., 0., 0.], [log_a_inv, 1., 0.], [
+        log_a_inv * log_a_inv,
+        2. * log_a_inv,
+        1.,
+    ]];
+    let mut coeffs = CorrectionCoeffs::new(sigma, num_derivs);
+    let mut sums = [ZERO; MAX_DERIVS];
+    let mut a_pow = 1.;
+    let mut pi_pow = 1.;
+    // The series is asymptotic, so stop once two terms in a row are negligible
+    let mut negligible_terms = 0;
+
+    for n in 0..MAX_TERMS {
+        coeffs.push_row();
+        let mut terms = [ZERO; MAX_DERIVS];
+        // 1 / (pi^(2n - k) (2i)^k)
+        let mut scale = Cplx::new(pi_pow, 0.);
+        for (k, d) in coeffs.rows[n].iter().enumerate() {
+            let f = kernel.derivative(3 * n - 2 * k) * scale;
+            for (term, weight) in terms.iter_mut().zip(weights).take(num_derivs) {
+                let t_coeff: Real = weight.iter().zip(d).map(|(w, d)| w * d).sum();
+                *term += t_coeff * f;
+            }
+            scale *= Cplx::new(0., -0.5 * PI);
+        }
+        pi_pow /= PI * PI;
+
+        let mut negligible = true;
+        for (sum, term) in sums.iter_mut().zip(terms).take(num_derivs) {
+            let term = term * a_pow;
+            *sum += term;
+            negligible &= term.norm() <= Real::EPSILON * sum.norm();
+        }
+        negligible_terms = if negligible { negligible_terms + 1 } else { 0 };
+        if negligible_terms == 2 {
+            break;
+        }
+        a_pow /= a;
+    }
+    sums
+}
+
+/// The Riemann zeta function and its first `D - 1` derivatives, for `D <= 3`, by the
+/// Riemann–Siegel formula. Inaccurate for small imaginary part; see [`use_riemann_siegel`].
+#[must_use]
+pub fn riemann_siegel_zeta<const D: usize>(s: Cplx) -> [Cplx; D]
+{
+    if s.im < 0. {
+        return riemann_siegel_zeta(s.conj()).map(|z: Cplx| z.conj());
+    }
+    RiemannSiegelTerms::new(s.im).zeta(s.re)
+}
+
+/// The parts of the Riemann–Siegel formula that depend only on the height `t`: the rotations of
+/// the terms of the Dirichlet series and the derivatives of the kernel. Points of equal height
+/// can share them, such as the pixels in a row.
+#[derive(Clone, Debug)]
+pub struct RiemannSiegelTerms
+{
+    height: Real,
+    a: Real,
+    phase: Real,
+    sign: Real,
+    kernel: Kernel,
+    /// `(ln j, exp(-i t ln j))` for each term `j` of the Dirichlet series.
+    rotations: Vec<(Real, Cplx)>,
+}
+
+impl RiemannSiegelTerms
+{
+    /// Prepare the terms at height `t >= 0`.
+    #[must_use]
+    pub fn new(t: Real) -> Self
+    {
+        debug_assert!(t >= 0.);
+        let a = (t / TAU).sqrt();
+        let n = a.floor();
+        let p = 2.0f64.mul_add(n - a, 1.);
+        let rotations = (1..=(n as u32))
+            .map(|j| {
+                let log_j = f64::from(j).ln();
+                (log_j, Cplx::cis(-t * log_j))
+            })
+            .collect();
+        Self {
+            height: t,
+            a,
+            phase: (0.5 * t).mul_add((t / TAU).ln() - 1., -PI / 8.),
+            sign: if n % 2. == 0. { -1. } else { 1. },
+            kernel: Kernel::new(p),
+            rotations,
+        }
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> Real
+    {
+        self.height
+    }
+
+    /// Zeta and its first `D - 1` derivatives, for `D <= 3`, at `sigma + it`.
+    pub fn zeta<const D: usize>(&mut self, sigma: Real) -> [Cplx; D]
+    {
+        debug_assert!((1..=MAX_DERIVS).contains(&D));
+        let t = self.height;
+        let a = self.a;
+        let x_corr = correction_sum(sigma, a, &mut self.kernel, D);
+        let y_corr = correction_sum(1. - sigma, a, &mut self.kernel, D);
+        let x_scale = self.sign * a.powf(-sigma) * Cplx::cis(-self.phase);
+        let y_scale = self.sign * a.powf(sigma - 1.) * Cplx::cis(-self.phase);
+
+        // R(s) and conj(R(1 - conj(s))), with their derivatives
+        let mut x = [ZERO; MAX_DERIVS];
+        let mut y = [ZERO; MAX_DERIVS];
+        for &(log_j, rotation) in &self.rotations {
+            let mut x_term = rotation * (-sigma * log_j).exp();
+            let mut y_term = rotation * ((sigma - 1.) * log_j).exp();
+            for deriv in 0..D {
+                x[deriv] += x_term;
+                y[deriv] += y_term;
+                x_term *= -log_j;
+                y_term *= -log_j;
+            }
+        }
+        for deriv in 0..D {
+            x[deriv] += x_scale * x_corr[deriv];
+            y[deriv] = (y[deriv] + y_scale * y_corr[deriv]).conj();
+        }
+
+        // chi(s) = exp(-2i theta(-i(s - 1/2)))
+        let [theta, dtheta, d2theta] = theta_d2(Cplx::new(t, 0.5 - sigma));
+        let chi = (-2. * I * theta).exp();
+        let zeta = [
+            x[0] + chi * y[0],
+            x[1] - chi * (y[1] + 2. * dtheta * y[0]),
+            x[2] + chi
+                * (y[2] + 4. * dtheta * y[1] + (4. * dtheta * dtheta + 2. * I * d2theta) * y[0]),
+        ];
+        std::array::from_fn(|i| zeta[i])
+    }
+}
@@ -141,6 +141,159 @@ impl Default for Bounds
     }
 }
 
+/// A change of coordinates applied to each point of the view before it is passed on to
+/// `param_map`, so that pixels sample `self.apply(z)` rather than `z` itself.
+///
+/// Slices and vectors of transforms are themselves transforms, applied front to back.
+pub trait PlaneTransform
+{
+    fn apply(&self, z: Cplx) -> Cplx;
+
+    /// A point of the view that maps to `z`, if the transform can be undone.
+    fn invert(&self, _z: Cplx) -> Option<Cplx>
+    {
+        None
+    }
+}
+
+impl<T: PlaneTransform> PlaneTransform for [T]
+{
+    fn apply(&self, z: Cplx) -> Cplx
+    {
+        self.iter().fold(z, |z, transform| transform.apply(z))
+    }
+
+    fn invert(&self, z: Cplx) -> Option<Cplx>
+    {
+        self.iter()
+            .rev()
+            .try_fold(z, |z, transform| transform.invert(z))
+    }
+}
+
+impl<T: PlaneTransform> PlaneTransform for Vec<T>
+{
+    fn apply(&self, z: Cplx) -> Cplx
+    {
+        self.as_slice().apply(z)
+    }
+
+    fn invert(&self, z: Cplx) -> Option<Cplx>
+    {
+        self.as_slice().invert(z)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ViewTransform
+{
+    #[default]
+    Identity,
+    /// z -> (az + b)/(cz + d)
+    Mobius
+    {
+        a: Cplx,
+        b: Cplx,
+        c: Cplx,
+        d: Cplx,
+    },
+    /// Inversion in the circle of the given center and radius.
+    Inversion
+    {
+        center: Cplx,
+        radius: Real,
+    },
+    /// Fold the plane onto a wedge of angle pi/order about `center`, giving the view the
+    /// symmetry of a kaleidoscope with `order` mirrors.
+    Kaleidoscope
+    {
+        center: Cplx,
+        order: u32,
+    },
+    Compose(Vec<ViewTransform>),
+}
+
+impl ViewTransform
+{
+    #[inline]
+    #[must_use]
+    pub const fn is_identity(&self) -> bool
+    {
+        matches!(self, Self::Identity)
+    }
+
+    /// Apply `self`, then `other`.
+    #[must_use]
+    pub fn then(self, other: Self) -> Self
+    {
+        match (self, other) {
+            (Self::Identity, t) | (t, Self::Identity) => t,
+            (Self::Compose(mut first), Self::Compose(second)) => {
+                first.extend(second);
+                Self::Compose(first)
+            }
+            (Self::Compose(mut first), t) => {
+                first.push(t);
+                Self::Compose(first)
+            }
+            (t, Self::Compose(mut second)) => {
+                second.insert(0, t);
+                Self::Compose(second)
+            }
+            (s, t) => Self::Compose(vec![s, t]),
+        }
+    }
+}
+
+impl PlaneTransform for ViewTransform
+{
+    fn apply(&self, z: Cplx) -> Cplx
+    {
+        match self {
+            Self::Identity => z,
+            Self::Mobius { a, b, c, d } => (a * z + b) / (c * z + d),
+            Self::Inversion { center, radius } => center + radius * radius / (z - center).conj(),
+            Self::Kaleidoscope { center, order } => {
+                if *order == 0 {
+                    return z;
+                }
+                let wedge = std::f64::consts::PI / Real::from(*order);
+                let w = z - center;
+                let mut theta = w.arg().rem_euclid(2. * wedge);
+                if theta > wedge {
+                    theta = 2. * wedge - theta;
+                }
+                center + Cplx::from_polar(w.norm(), theta)
+            }
+            Self::Compose(transforms) => transforms.apply(z),
+        }
+    }
+
+    fn invert(&self, z: Cplx) -> Option<Cplx>
+    {
+        match self {
+            Self::Identity | Self::Inversion { .. } => Some(self.apply(z)),
+            Self::Mobius { a, b, c, d } => {
+                if a * d == b * c {
+                    return None;
+                }
+                Some((d * z - b) / (a - c * z))
+            }
+            Self::Kaleidoscope { center, order } => {
+                // Every point of the wedge has a preimage in each sector; take the one in the
+                // wedge itself. Points outside the wedge are not in the image at all.
+                if *order == 0 {
+                    return Some(z);
+                }
+                let wedge = std::f64::consts::PI / Real::from(*order);
+                (0. ..=wedge).contains(&(z - center).arg()).then_some(z)
+            }
+            Self::Compose(transforms) => transforms.invert(z),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PointGrid
@@ -148,6 +301,8 @@ pub struct PointGrid
     pub res_x: usize,
     pub res_y: usize,
     pub bounds: Bounds,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub transform: ViewTransform,
 }
 
 impl PointGrid
@@ -159,6 +314,7 @@ impl PointGrid
             res_x,
             res_y,
             bounds,
+            transform: ViewTransform::Identity,
         }
     }
 
@@ -218,38 +374,65 @@ impl PointGrid
 
     #[inline]
     #[must_use]
-    pub const fn with_same_height(self, bounds: Bounds) -> Self
+    pub fn with_same_height(self, bounds: Bounds) -> Self
     {
-        Self::new_by_res_y(self.res_y, bounds)
+        let res_x = Self::infer_width(self.res_y, &bounds);
+        Self {
+            res_x,
+            bounds,
+            ..self
+        }
     }
 
     #[inline]
     #[must_use]
-    pub const fn with_same_width(self, bounds: Bounds) -> Self
+    pub fn with_same_width(self, bounds: Bounds) -> Self
     {
-        Self::new_by_res_x(self.res_x, bounds)
+        let res_y = Self::infer_height(self.res_x, &bounds);
+        Self {
+            res_y,
+            bounds,
+            ..self
+        }
     }
 
     #[inline]
     #[must_use]
-    pub const fn with_width(self, res_x: usize) -> Self
+    pub fn with_width(self, res_x: usize) -> Self
     {
-        Self::new_by_res_x(res_x, self.bounds)
+        let res_y = Self::infer_height(res_x, &self.bounds);
+        Self {
+            res_x,
+            res_y,
+            ..self
+        }
     }
 
     #[inline]
     #[must_use]
-    pub const fn with_height(self, res_y: usize) -> Self
+    pub fn with_height(self, res_y: usize) -> Self
     {
-        Self::new_by_res_y(res_y, self.bounds)
+        let res_x = Self::infer_width(res_y, &self.bounds);
+        Self {
+            res_x,
+            res_y,
+            ..self
+        }
     }
 
     #[must_use]
     pub fn map_pixel(&self, pixel_x: usize, pixel_y: usize) -> Cplx
     {
-        let re = (pixel_x as Real).mul_add(self.pixel_width(), self.bounds.min_x);
-        let im = (pixel_y as Real).mul_add(self.pixel_height(), self.bounds.min_y);
-        Cplx::new(re, im)
+        self.map_fractional_pixel(pixel_x as Real, pixel_y as Real)
+    }
+
+    /// Like [`PointGrid::map_pixel`], for positions between pixels.
+    #[must_use]
+    pub fn map_fractional_pixel(&self, pixel_x: Real, pixel_y: Real) -> Cplx
+    {
+        let re = pixel_x.mul_add(self.pixel_width(), self.bounds.min_x);
+        let im = pixel_y.mul_add(self.pixel_height(), self.bounds.min_y);
+        self.transform.apply(Cplx::new(re, im))
     }
 
     #[must_use]
@@ -289,23 +472,55 @@ impl PointGrid
         (self.res_x, self.res_y)
     }
 
+    /// Position of `z` in pixels, or `None` if no point of the view maps to `z` under the
+    /// transform.
     #[must_use]
-    pub fn locate_point(&self, z: Cplx) -> [f32; 2]
+    pub fn locate_point(&self, z: Cplx) -> Option<[f32; 2]>
     {
+        let z = self.transform.invert(z)?;
         let x = (z.re - self.bounds.min_x) / (self.pixel_width());
         let y = (z.im - self.bounds.min_y) / (self.pixel_height());
 
-        [x as f32, self.res_y as f32 - 1. - y as f32]
+        Some([x as f32, self.res_y as f32 - 1. - y as f32])
+    }
+
+    /// Positions of the points of a curve in pixels, split into runs wherever a point cannot be
+    /// located, so that the curve is broken there rather than joined across the gap.
+    #[must_use]
+    pub fn locate_curve(&self, zs: &[Cplx]) -> Vec<Vec<[f32; 2]>>
+    {
+        let located: Vec<_> = zs.iter().map(|z| self.locate_point(*z)).collect();
+        located
+            .split(Option::is_none)
+            .filter(|run| !run.is_empty())
+            .map(|run| run.iter().flatten().copied().collect())
+            .collect()
+    }
+
+    /// The pixel whose point is nearest to `z`, indexed as in [`PointGrid::map_pixel`], or `None`
+    /// if `z` lies outside the grid or no point of the view maps to it.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub fn nearest_pixel(&self, z: Cplx) -> Option<(usize, usize)>
+    {
+        let z = self.transform.invert(z)?;
+        let x = ((z.re - self.bounds.min_x) / self.pixel_width()).round();
+        let y = ((z.im - self.bounds.min_y) / self.pixel_height()).round();
+        if x < 0. || y < 0. || x >= self.res_x as Real || y >= self.res_y as Real {
+            return None;
+        }
+        Some((x as usize, y as usize))
     }
 
     #[must_use]
     #[allow(clippy::cast_sign_loss)]
     pub fn locate_point_safe(&self, z: Cplx) -> Option<(usize, usize)>
     {
+        let z = self.transform.invert(z)?;
         if z.re >= self.bounds.max_x
             || z.re < self.bounds.min_x
             || z.im >= self.bounds.max_y
-            || z.re < self.bounds.min_y
+            || z.im < self.bounds.min_y
         {
             return None;
         }
@@ -325,6 +540,29 @@ impl PointGrid
         Cplx::new(re, im)
     }
 
+    /// The translation, in whole pixels to the right and up, nearest to `translation`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn pixel_offset(&self, translation: Cplx) -> (isize, isize)
+    {
+        (
+            (translation.re / self.pixel_width()).round() as isize,
+            (translation.im / self.pixel_height()).round() as isize,
+        )
+    }
+
+    /// Translate the grid by whole pixels, `dx` to the right and `dy` up, so that each pixel
+    /// lands on the point of another. Returns the translation.
+    pub fn translate_pixels(&mut self, dx: isize, dy: isize) -> Cplx
+    {
+        let translation = Cplx::new(
+            dx as Real * self.pixel_width(),
+            dy as Real * self.pixel_height(),
+        );
+        self.translate(translation);
+        translation
+    }
+
     pub fn recenter(&mut self, new_center: Cplx)
     {
         let old_center = self.center();
@@ -337,6 +575,32 @@ impl PointGrid
         self.bounds = new_bounds;
     }
 
+    /// The band of `num_rows` image rows starting `first_row` rows below the top edge,
+    /// with the same pixel size as `self`.
+    #[must_use]
+    pub fn row_strip(&self, first_row: usize, num_rows: usize) -> Self
+    {
+        let pixel_height = self.pixel_height();
+        let max_y = (first_row as Real).mul_add(-pixel_height, self.bounds.max_y);
+        let min_y = (num_rows as Real).mul_add(-pixel_height, max_y);
+        let bounds = Bounds {
+            min_y,
+            max_y,
+            ..self.bounds
+        };
+        Self {
+            res_y: num_rows,
+            bounds,
+            ..self.clone()
+        }
+    }
+
+    #[must_use]
+    pub fn with_transform(self, transform: ViewTransform) -> Self
+    {
+        Self { transform, ..self }
+    }
+
     #[inline]
     pub fn resize_x(&mut self, res_x: usize)
     {
@@ -360,7 +624,7 @@ impl PointGrid
         points.indexed_iter_mut().for_each(|((i, j), value)| {
             let re = (i as Real).mul_add(pixel_width, self.bounds.min_x);
             let im = (j as Real).mul_add(pixel_height, self.bounds.min_y);
-            *value = Cplx::new(re, im);
+            *value = self.transform.apply(Cplx::new(re, im));
         });
         points
     }
@@ -386,6 +650,7 @@ impl Default for PointGrid
             res_x: 256,
             res_y: 256,
             bounds: Bounds::default(),
+            transform: ViewTransform::Identity,
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::globals::DISPLAY_PREC;
-use crate::types::{IterCount, IterCountSmooth, Period, Real};
+use crate::types::{Cplx, IterCount, IterCountSmooth, Period, Real};
 use std::fmt::Display;
 
 #[cfg(feature = "serde")]
@@ -13,12 +13,20 @@ pub enum PointInfo<D>
     {
         potential: IterCountSmooth,
         phase: Option<Period>,
+        /// Argument of the last iterate, outside the escape radius, used to decompose the basin
+        /// of infinity into angular sectors.
+        #[cfg_attr(feature = "serde", serde(default))]
+        angle: Option<Real>,
     },
     Periodic(PointInfoPeriodic<D>),
     PeriodicKnownPotential(PointInfoKnownPotential<D>),
     #[default]
     Bounded,
     Wandering,
+    FastEscaping
+    {
+        level: Period,
+    },
     MarkedPoint
     {
         data: PointInfoPeriodic<D>,
@@ -30,9 +38,105 @@ pub enum PointInfo<D>
         distance: Real,
         phase: Period,
     },
+    InteriorDistanceEstimate
+    {
+        distance: Real,
+        period: Period,
+    },
+    /// Bounded orbit, classified by the largest Lyapunov exponent along it: negative on stable
+    /// cycles and positive on chaotic attractors.
+    Lyapunov
+    {
+        exponent: Real,
+    },
+    /// Fraction of the orbits that escaped among those run with random noise added at every
+    /// iteration.
+    EscapeProbability
+    {
+        probability: Real,
+    },
     Unknown,
 }
 
+impl<D> PointInfo<D>
+{
+    /// Period of the attracting cycle found for this point, if any.
+    #[must_use]
+    pub const fn period(&self) -> Option<Period>
+    {
+        match self {
+            Self::Periodic(data) | Self::MarkedPoint { data, .. } => Some(data.period),
+            Self::PeriodicKnownPotential(data) => Some(data.period),
+            Self::InteriorDistanceEstimate { period, .. } => Some(*period),
+            _ => None,
+        }
+    }
+
+    /// The same information, with the multipliers of cycles transformed by `f`.
+    #[must_use]
+    pub fn map_multiplier<E>(&self, f: impl Fn(&D) -> E) -> PointInfo<E>
+    {
+        match self {
+            Self::Escaping {
+                potential,
+                phase,
+                angle,
+            } => PointInfo::Escaping {
+                potential: *potential,
+                phase: *phase,
+                angle: *angle,
+            },
+            Self::Periodic(data) => PointInfo::Periodic(data.map_multiplier(f)),
+            Self::PeriodicKnownPotential(data) => {
+                PointInfo::PeriodicKnownPotential(PointInfoKnownPotential {
+                    period: data.period,
+                    multiplier: f(&data.multiplier),
+                    potential: data.potential,
+                })
+            }
+            Self::Bounded => PointInfo::Bounded,
+            Self::Wandering => PointInfo::Wandering,
+            Self::FastEscaping { level } => PointInfo::FastEscaping { level: *level },
+            Self::MarkedPoint {
+                data,
+                class_id,
+                num_point_classes,
+            } => PointInfo::MarkedPoint {
+                data: data.map_multiplier(f),
+                class_id: *class_id,
+                num_point_classes: *num_point_classes,
+            },
+            Self::DistanceEstimate { distance, phase } => PointInfo::DistanceEstimate {
+                distance: *distance,
+                phase: *phase,
+            },
+            Self::InteriorDistanceEstimate { distance, period } => {
+                PointInfo::InteriorDistanceEstimate {
+                    distance: *distance,
+                    period: *period,
+                }
+            }
+            Self::Lyapunov { exponent } => PointInfo::Lyapunov {
+                exponent: *exponent,
+            },
+            Self::EscapeProbability { probability } => PointInfo::EscapeProbability {
+                probability: *probability,
+            },
+            Self::Unknown => PointInfo::Unknown,
+        }
+    }
+
+    /// Record the argument of the last iterate of an escaping orbit.
+    #[must_use]
+    pub fn with_escape_angle(mut self, final_angle: Real) -> Self
+    {
+        if let Self::Escaping { angle, .. } = &mut self {
+            *angle = final_angle.is_finite().then_some(final_angle);
+        }
+        self
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PointInfoPeriodic<D>
@@ -41,6 +145,29 @@ pub struct PointInfoPeriodic<D>
     pub period: Period,
     pub multiplier: D,
     pub final_error: Real,
+    /// Iteration at which the orbit came within the periodicity tolerance of its cycle,
+    /// interpolated between the iterations where cycles can be detected.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub smooth_preperiod: IterCountSmooth,
+    /// A point of the cycle, used to tell apart the basins of distinct cycles of equal period.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cycle_point: Cplx,
+}
+impl<D> PointInfoPeriodic<D>
+{
+    /// The same cycle, with its multiplier transformed by `f`.
+    #[must_use]
+    pub fn map_multiplier<E>(&self, f: impl Fn(&D) -> E) -> PointInfoPeriodic<E>
+    {
+        PointInfoPeriodic {
+            preperiod: self.preperiod,
+            period: self.period,
+            multiplier: f(&self.multiplier),
+            final_error: self.final_error,
+            smooth_preperiod: self.smooth_preperiod,
+            cycle_point: self.cycle_point,
+        }
+    }
 }
 impl<D> std::fmt::Display for PointInfoPeriodic<D>
 where
@@ -1,6 +1,12 @@
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::{cmp::Ordering, collections::VecDeque, error::Error, num::ParseIntError, str::FromStr};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, VecDeque},
+    error::Error,
+    num::ParseIntError,
+    str::FromStr,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -491,6 +497,19 @@ impl AngleWithDegree
         self.itinerary_given_orbit_schema(orbit_schema, partition)
     }
 
+    /// The other parameter angle landing at the root of the same hyperbolic component of the
+    /// Mandelbrot set, found with Lavaurs' algorithm. Only available for periodic angles under
+    /// doubling, with period between 2 and [`MAX_COMPANION_PERIOD`].
+    #[must_use]
+    pub fn companion_angle(&self) -> Option<RationalAngle>
+    {
+        let OrbitSchema { preperiod, period } = self.orbit_schema();
+        if self.degree != 2 || preperiod != 0 || !(2..=MAX_COMPANION_PERIOD).contains(&period) {
+            return None;
+        }
+        lavaurs_partners(period).get(&self.angle).copied()
+    }
+
     #[must_use]
     pub fn to_angle_info(self) -> AngleInfo
     {
@@ -718,6 +737,40 @@ impl std::error::Error for ParseOrbitSchemaError
     }
 }
 
+/// Longest period for which companion angles are computed. Lavaurs' algorithm needs every chord of
+/// lower period, and there are about `2^period` of them.
+pub const MAX_COMPANION_PERIOD: Period = 12;
+
+/// Each periodic angle under doubling of period at most `max_period`, mapped to the angle landing
+/// with it on the Mandelbrot set. For each period in turn, the smallest unpaired angle is joined
+/// by a chord to the next one whose chord crosses none of those drawn so far.
+fn lavaurs_partners(max_period: Period) -> BTreeMap<RationalAngle, RationalAngle>
+{
+    // A new chord crosses an old one exactly when some endpoint between its own endpoints has a
+    // partner outside them.
+    let mut partners = BTreeMap::new();
+    for period in 2..=max_period {
+        let schema = OrbitSchemaWithDegree {
+            preperiod: 0,
+            period,
+            degree: 2,
+        };
+        let mut unpaired = schema.exact_angles();
+        while let Some(a) = unpaired.pop_front() {
+            let partner = unpaired.iter().position(|&b| {
+                partners
+                    .range(a..b)
+                    .all(|(_, &other)| a < other && other < b)
+            });
+            if let Some(b) = partner.and_then(|i| unpaired.remove(i)) {
+                partners.insert(a, b);
+                partners.insert(b, a);
+            }
+        }
+    }
+    partners
+}
+
 /// Sort a VecDeque assuming it is circularly ordered.
 pub(crate) fn sort_circularly_ordered<T: PartialOrd>(angles: &mut VecDeque<T>)
 {
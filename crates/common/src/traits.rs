@@ -262,6 +262,7 @@ pub trait Derivative:
     + MulAssign
     + Display
     + Into<Cplx>
+    + Storable
 {
 }
 
@@ -291,9 +292,22 @@ impl<D> Derivative for D where
         + MulAssign
         + Display
         + Into<Cplx>
+        + Storable
 {
 }
 
+/// Types that can be written to and read back from raw data files.
+/// Trivially satisfied when the `serde` feature is disabled.
+#[cfg(feature = "serde")]
+pub trait Storable: serde::Serialize + serde::de::DeserializeOwned {}
+#[cfg(feature = "serde")]
+impl<T> Storable for T where T: serde::Serialize + serde::de::DeserializeOwned {}
+
+#[cfg(not(feature = "serde"))]
+pub trait Storable {}
+#[cfg(not(feature = "serde"))]
+impl<T> Storable for T {}
+
 macro_rules! impl_polar {
     ($t: ty) => {
         impl Norm<Real> for $t
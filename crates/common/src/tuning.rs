@@ -0,0 +1,150 @@
+//! Douady-Hubbard tuning of external angles of the Mandelbrot set.
+//!
+//! A hyperbolic component of period `p` whose root is the landing point of the parameter rays at
+//! angles `.(a)` and `.(b)`, with `a < b` words of `p` binary digits, carries a small copy of the
+//! Mandelbrot set. The tuning map sends the parameter with external angle `.x1 x2 x3...` to the
+//! one in the small copy with angle found by replacing each digit 0 by `a` and each 1 by `b`.
+use crate::rational_angle::RationalAngle;
+use crate::symbolic_dynamics::{OrbitSchema, MAX_COMPANION_PERIOD};
+use crate::types::{AngleNum, Period};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TuningError
+{
+    /// The angle given for the component is not periodic with a known companion angle.
+    NotARootAngle(RationalAngle),
+    /// The tuned angle has too many digits to be represented exactly.
+    Overflow,
+}
+
+impl std::fmt::Display for TuningError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::NotARootAngle(angle) => write!(
+                f,
+                "{angle} is not a root angle of a component of period 2 to {MAX_COMPANION_PERIOD}"
+            ),
+            Self::Overflow => write!(f, "The tuned angle has too many binary digits"),
+        }
+    }
+}
+
+impl std::error::Error for TuningError {}
+
+/// The small copy of the Mandelbrot set containing an angle, found by [`renormalize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Renormalization
+{
+    /// Root angles of the hyperbolic component carrying the small copy, in increasing order.
+    pub root_angles: (RationalAngle, RationalAngle),
+    pub period: Period,
+    /// The angle of the corresponding parameter in the Mandelbrot set itself.
+    pub base_angle: RationalAngle,
+}
+
+/// Binary digits of an angle under doubling, split into its preperiodic and periodic parts.
+#[must_use]
+pub fn binary_digits(angle: RationalAngle) -> (Vec<bool>, Vec<bool>)
+{
+    let OrbitSchema { preperiod, period } = angle.with_degree(2).orbit_schema();
+    let mut theta = angle;
+    let mut next_digit = || {
+        let digit = 2 * theta.numer() >= *theta.denom();
+        theta *= 2;
+        digit
+    };
+    let preperiodic = (0..preperiod).map(|_| next_digit()).collect();
+    let periodic = (0..period).map(|_| next_digit()).collect();
+    (preperiodic, periodic)
+}
+
+/// The angle with the given binary digits, or `None` if its denominator would overflow.
+#[must_use]
+pub fn from_binary_digits(preperiodic: &[bool], periodic: &[bool]) -> Option<RationalAngle>
+{
+    let to_int = |digits: &[bool]| -> Option<AngleNum> {
+        digits.iter().try_fold(0 as AngleNum, |n, &d| {
+            n.checked_mul(2)?.checked_add(AngleNum::from(d))
+        })
+    };
+    let pow2 = |k: usize| -> Option<AngleNum> { 2_i64.checked_pow(u32::try_from(k).ok()?) };
+
+    let head = to_int(preperiodic)?;
+    let tail = to_int(periodic)?;
+    let cycle = pow2(periodic.len())?.checked_sub(1)?.max(1);
+    let numer = head.checked_mul(cycle)?.checked_add(tail)?;
+    let denom = pow2(preperiodic.len())?.checked_mul(cycle)?;
+    Some(RationalAngle::new(numer, denom))
+}
+
+/// Both root angles of the component with the given root angle, in increasing order.
+fn root_pair(root_angle: RationalAngle) -> Result<(RationalAngle, RationalAngle), TuningError>
+{
+    let companion = root_angle
+        .with_degree(2)
+        .companion_angle()
+        .ok_or(TuningError::NotARootAngle(root_angle))?;
+    Ok((root_angle.min(companion), root_angle.max(companion)))
+}
+
+/// The external angle of the parameter in the small copy of the Mandelbrot set carried by the
+/// component with root angle `root_angle`, corresponding to the parameter with angle `angle`.
+pub fn tune(root_angle: RationalAngle, angle: RationalAngle) -> Result<RationalAngle, TuningError>
+{
+    let (lower, upper) = root_pair(root_angle)?;
+    let (_, lower_word) = binary_digits(lower);
+    let (_, upper_word) = binary_digits(upper);
+    let substitute = |digits: Vec<bool>| -> Vec<bool> {
+        digits
+            .into_iter()
+            .flat_map(|d| if d { &upper_word } else { &lower_word })
+            .copied()
+            .collect()
+    };
+    let (preperiodic, periodic) = binary_digits(angle);
+    from_binary_digits(&substitute(preperiodic), &substitute(periodic)).ok_or(TuningError::Overflow)
+}
+
+/// Find the largest small copy of the Mandelbrot set, carried by a component of period at most
+/// [`MAX_COMPANION_PERIOD`], whose external angles include `angle`. This undoes [`tune`].
+#[must_use]
+pub fn renormalize(angle: RationalAngle) -> Option<Renormalization>
+{
+    let (preperiodic, periodic) = binary_digits(angle);
+    let max_period = MAX_COMPANION_PERIOD.min(periodic.len() as Period);
+
+    (2..=max_period).find_map(|period| {
+        let p = period as usize;
+        if periodic.len() % p != 0 || preperiodic.len() % p != 0 {
+            return None;
+        }
+        // The copy must be proper, so the angle cannot be a root angle of the component itself
+        if preperiodic.is_empty() && periodic.len() == p {
+            return None;
+        }
+
+        let block = preperiodic.get(..p).unwrap_or(&periodic[..p]);
+        let root_angle = from_binary_digits(&[], block)?;
+        let root_angles = root_pair(root_angle).ok()?;
+        let (_, lower_word) = binary_digits(root_angles.0);
+        let (_, upper_word) = binary_digits(root_angles.1);
+        let restore = |digits: &[bool]| -> Option<Vec<bool>> {
+            digits
+                .chunks(p)
+                .map(|block| match block {
+                    _ if block == lower_word => Some(false),
+                    _ if block == upper_word => Some(true),
+                    _ => None,
+                })
+                .collect()
+        };
+        let base_angle = from_binary_digits(&restore(&preperiodic)?, &restore(&periodic)?)?;
+        Some(Renormalization {
+            root_angles,
+            period,
+            base_angle,
+        })
+    })
+}
@@ -3,8 +3,10 @@ use num_rational::Ratio;
 
 pub mod variables;
 pub use variables::*;
+pub mod dual;
+pub use dual::Dual;
 pub mod param_stack;
-pub use param_stack::{NoParam, ParamList, ParamStack};
+pub use param_stack::{HasSliders, MetaParamSlider, MetaParamText, NoParam, ParamList, ParamStack};
 
 pub type Real = f64;
 pub type Cplx = Complex<Real>;
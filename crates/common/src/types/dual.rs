@@ -0,0 +1,371 @@
+use super::{Cplx, Real};
+use crate::consts::{ONE, ZERO};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Complex numbers, or numbers carrying derivatives along with them. Maps written generically
+/// over this trait can be differentiated automatically by evaluating them on [`Dual`] numbers.
+pub trait Scalar:
+    Copy
+    + From<Cplx>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Add<Cplx, Output = Self>
+    + Sub<Cplx, Output = Self>
+    + Mul<Cplx, Output = Self>
+    + Div<Cplx, Output = Self>
+    + Mul<Real, Output = Self>
+    + Div<Real, Output = Self>
+{
+    /// The underlying complex value.
+    fn value(self) -> Cplx;
+    fn inv(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, exponent: Real) -> Self;
+    fn powc(self, exponent: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn atan(self) -> Self;
+}
+
+impl Scalar for Cplx
+{
+    #[inline]
+    fn value(self) -> Cplx
+    {
+        self
+    }
+    #[inline]
+    fn inv(self) -> Self
+    {
+        Self::inv(&self)
+    }
+    #[inline]
+    fn powi(self, n: i32) -> Self
+    {
+        Self::powi(&self, n)
+    }
+    #[inline]
+    fn powf(self, exponent: Real) -> Self
+    {
+        Self::powf(self, exponent)
+    }
+    #[inline]
+    fn powc(self, exponent: Self) -> Self
+    {
+        Self::powc(self, exponent)
+    }
+    #[inline]
+    fn exp(self) -> Self
+    {
+        Self::exp(self)
+    }
+    #[inline]
+    fn ln(self) -> Self
+    {
+        Self::ln(self)
+    }
+    #[inline]
+    fn sqrt(self) -> Self
+    {
+        Self::sqrt(self)
+    }
+    #[inline]
+    fn sin(self) -> Self
+    {
+        Self::sin(self)
+    }
+    #[inline]
+    fn cos(self) -> Self
+    {
+        Self::cos(self)
+    }
+    #[inline]
+    fn tan(self) -> Self
+    {
+        Self::tan(self)
+    }
+    #[inline]
+    fn sinh(self) -> Self
+    {
+        Self::sinh(self)
+    }
+    #[inline]
+    fn cosh(self) -> Self
+    {
+        Self::cosh(self)
+    }
+    #[inline]
+    fn tanh(self) -> Self
+    {
+        Self::tanh(self)
+    }
+    #[inline]
+    fn atan(self) -> Self
+    {
+        Self::atan(self)
+    }
+}
+
+/// A value together with its derivative with respect to a single chosen variable, for
+/// forward-mode automatic differentiation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dual
+{
+    pub value: Cplx,
+    pub deriv: Cplx,
+}
+
+impl Dual
+{
+    #[must_use]
+    pub const fn new(value: Cplx, deriv: Cplx) -> Self
+    {
+        Self { value, deriv }
+    }
+
+    #[must_use]
+    pub const fn constant(value: Cplx) -> Self
+    {
+        Self::new(value, ZERO)
+    }
+
+    #[must_use]
+    pub const fn variable(value: Cplx) -> Self
+    {
+        Self::new(value, ONE)
+    }
+
+    /// Apply a function, given its value and derivative at `self.value`, by the chain rule.
+    #[inline]
+    fn chain(self, value: Cplx, deriv: Cplx) -> Self
+    {
+        Self::new(value, deriv * self.deriv)
+    }
+}
+
+impl From<Cplx> for Dual
+{
+    #[inline]
+    fn from(value: Cplx) -> Self
+    {
+        Self::constant(value)
+    }
+}
+
+impl Scalar for Dual
+{
+    #[inline]
+    fn value(self) -> Cplx
+    {
+        self.value
+    }
+    #[inline]
+    fn inv(self) -> Self
+    {
+        let w = self.value.inv();
+        self.chain(w, -w * w)
+    }
+    #[inline]
+    fn powi(self, n: i32) -> Self
+    {
+        if n == 0 {
+            return Self::constant(ONE);
+        }
+        let prev = self.value.powi(n - 1);
+        self.chain(prev * self.value, Real::from(n) * prev)
+    }
+    #[inline]
+    fn powf(self, exponent: Real) -> Self
+    {
+        let w = self.value.powf(exponent);
+        self.chain(w, exponent * w / self.value)
+    }
+    #[inline]
+    fn powc(self, exponent: Self) -> Self
+    {
+        let value = self.value.powc(exponent.value);
+        let log = self.value.ln();
+        let deriv = value * (exponent.deriv * log + exponent.value * self.deriv / self.value);
+        Self::new(value, deriv)
+    }
+    #[inline]
+    fn exp(self) -> Self
+    {
+        let w = self.value.exp();
+        self.chain(w, w)
+    }
+    #[inline]
+    fn ln(self) -> Self
+    {
+        self.chain(self.value.ln(), self.value.inv())
+    }
+    #[inline]
+    fn sqrt(self) -> Self
+    {
+        let w = self.value.sqrt();
+        self.chain(w, (2. * w).inv())
+    }
+    #[inline]
+    fn sin(self) -> Self
+    {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+    #[inline]
+    fn cos(self) -> Self
+    {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+    #[inline]
+    fn tan(self) -> Self
+    {
+        let c = self.value.cos();
+        self.chain(self.value.tan(), (c * c).inv())
+    }
+    #[inline]
+    fn sinh(self) -> Self
+    {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+    #[inline]
+    fn cosh(self) -> Self
+    {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+    #[inline]
+    fn tanh(self) -> Self
+    {
+        let c = self.value.cosh();
+        self.chain(self.value.tanh(), (c * c).inv())
+    }
+    #[inline]
+    fn atan(self) -> Self
+    {
+        let z = self.value;
+        self.chain(z.atan(), (ONE + z * z).inv())
+    }
+}
+
+impl Add for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self
+    {
+        Self::new(self.value + rhs.value, self.deriv + rhs.deriv)
+    }
+}
+
+impl Sub for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self
+    {
+        Self::new(self.value - rhs.value, self.deriv - rhs.deriv)
+    }
+}
+
+impl Mul for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self
+    {
+        Self::new(
+            self.value * rhs.value,
+            self.deriv * rhs.value + self.value * rhs.deriv,
+        )
+    }
+}
+
+impl Div for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self
+    {
+        let inv = rhs.value.inv();
+        let value = self.value * inv;
+        Self::new(value, (self.deriv - value * rhs.deriv) * inv)
+    }
+}
+
+impl Neg for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self
+    {
+        Self::new(-self.value, -self.deriv)
+    }
+}
+
+impl Add<Cplx> for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Cplx) -> Self
+    {
+        Self::new(self.value + rhs, self.deriv)
+    }
+}
+
+impl Sub<Cplx> for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Cplx) -> Self
+    {
+        Self::new(self.value - rhs, self.deriv)
+    }
+}
+
+impl Mul<Cplx> for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Cplx) -> Self
+    {
+        Self::new(self.value * rhs, self.deriv * rhs)
+    }
+}
+
+impl Div<Cplx> for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Cplx) -> Self
+    {
+        Self::new(self.value / rhs, self.deriv / rhs)
+    }
+}
+
+impl Mul<Real> for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Real) -> Self
+    {
+        Self::new(self.value * rhs, self.deriv * rhs)
+    }
+}
+
+impl Div<Real> for Dual
+{
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Real) -> Self
+    {
+        Self::new(self.value / rhs, self.deriv / rhs)
+    }
+}
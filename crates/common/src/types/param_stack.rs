@@ -1,4 +1,4 @@
-use super::Cplx;
+use super::{Cplx, Real};
 use crate::{
     prelude::DescriptionConf,
     traits::{Describe, Summarize},
@@ -87,6 +87,81 @@ impl ParamList for NoParam
     }
 }
 
+/// A real or complex meta-parameter that can be adjusted live from the UI.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetaParamSlider
+{
+    pub name: String,
+    pub value: Cplx,
+    pub min: Real,
+    pub max: Real,
+    /// If false, only the real part is adjustable.
+    pub complex: bool,
+    /// If true, the real part is an integer, adjusted with a spin box rather than a slider.
+    pub integer: bool,
+}
+
+/// A meta-parameter that is entered as text rather than with a slider.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetaParamText
+{
+    pub name: String,
+    pub value: String,
+    /// Explanation of the accepted input, shown when editing.
+    pub prompt: String,
+}
+
+/// Meta-parameters exposing some of their values as sliders.
+pub trait HasSliders
+{
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        vec![]
+    }
+
+    /// Set the value of the slider at index `idx` in `sliders`.
+    fn set_slider(&mut self, _idx: usize, _value: Cplx) {}
+
+    /// Value that is edited through a text dialog, if any.
+    fn text_entry(&self) -> Option<MetaParamText>
+    {
+        None
+    }
+
+    /// Parse and apply the text entered for `text_entry`, explaining any rejection.
+    fn set_text_entry(&mut self, _text: &str) -> Result<(), String>
+    {
+        Err("This parameter cannot be edited as text.".to_owned())
+    }
+}
+
+impl HasSliders for NoParam {}
+impl HasSliders for Cplx {}
+impl HasSliders for i32 {}
+
+impl<M: HasSliders, P> HasSliders for (M, P)
+{
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        self.0.sliders()
+    }
+
+    fn set_slider(&mut self, idx: usize, value: Cplx)
+    {
+        self.0.set_slider(idx, value);
+    }
+
+    fn text_entry(&self) -> Option<MetaParamText>
+    {
+        self.0.text_entry()
+    }
+
+    fn set_text_entry(&mut self, text: &str) -> Result<(), String>
+    {
+        self.0.set_text_entry(text)
+    }
+}
+
 #[derive(Clone, Display, Default)]
 #[display("[{meta_params}, {local_param}]")]
 pub struct ParamStack<T, H>
@@ -169,3 +244,29 @@ where
         self.local_param
     }
 }
+
+impl<T, H> HasSliders for ParamStack<T, H>
+where
+    T: Clone + Default + Summarize + HasSliders,
+    H: Clone + Default + PartialEq + Summarize,
+{
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        self.meta_params.sliders()
+    }
+
+    fn set_slider(&mut self, idx: usize, value: Cplx)
+    {
+        self.meta_params.set_slider(idx, value);
+    }
+
+    fn text_entry(&self) -> Option<MetaParamText>
+    {
+        self.meta_params.text_entry()
+    }
+
+    fn set_text_entry(&mut self, text: &str) -> Result<(), String>
+    {
+        self.meta_params.set_text_entry(text)
+    }
+}
@@ -251,7 +251,62 @@ impl Describe for Bicomplex
     }
 }
 
+/// A point `(z, w)` of the total space of a skew product, with base coordinate `z` and fiber
+/// coordinate `w`. Fiber planes show the fiber coordinate, which is what it converts to and from.
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Add, Sub)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[display("({z}, {w})")]
+pub struct FiberPoint
+{
+    pub z: Cplx,
+    pub w: Cplx,
+}
+
+impl From<Cplx> for FiberPoint
+{
+    fn from(w: Cplx) -> Self
+    {
+        Self { z: ZERO, w }
+    }
+}
+impl From<FiberPoint> for Cplx
+{
+    fn from(value: FiberPoint) -> Self
+    {
+        value.w
+    }
+}
+impl Norm<Real> for FiberPoint
+{
+    fn norm(&self) -> Real
+    {
+        self.norm_sqr().sqrt()
+    }
+    fn norm_sqr(&self) -> Real
+    {
+        self.z.norm_sqr() + self.w.norm_sqr()
+    }
+}
+impl MaybeNan for FiberPoint
+{
+    fn is_nan(&self) -> bool
+    {
+        self.z.is_nan() || self.w.is_nan()
+    }
+}
+impl Describe for FiberPoint
+{
+    fn describe(&self, desc_conf: &DescriptionConf) -> Option<String>
+    {
+        desc_conf.is_enabled.then(|| {
+            let prec = desc_conf.precision;
+            format!("({:.prec$}, {:.prec$})", self.z, self.w)
+        })
+    }
+}
+
 #[derive(Clone, Copy, Default, Debug, Hash, PartialEq, Eq, Add, Sub, AddAssign, SubAssign)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EisensteinInteger
 {
     pub a: i64,
@@ -483,6 +538,7 @@ impl crate::traits::Named for EisensteinInteger
 }
 
 #[derive(Clone, Copy, Default, Debug, Hash, PartialEq, Eq, Add, Sub, AddAssign, SubAssign)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GaussianInteger
 {
     pub a: i64,
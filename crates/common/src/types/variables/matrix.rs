@@ -113,6 +113,15 @@ impl Matrix2x2
     {
         Self::diag(1., 1.)
     }
+    /// Image of the vector `v` under the linear map.
+    #[must_use]
+    pub fn apply(&self, v: Point) -> Point
+    {
+        Point {
+            x: self.v0.x.mul_add(v.x, self.v1.x * v.y),
+            y: self.v0.y.mul_add(v.x, self.v1.y * v.y),
+        }
+    }
     fn det(&self) -> Real
     {
         self.v0.x.mul_add(self.v1.y, -self.v0.y * self.v1.x)
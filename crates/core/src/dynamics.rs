@@ -1,4 +1,5 @@
 use dynamo_color::{Coloring, IncoloringAlgorithm};
+use dynamo_common::analysis::{self, Estimate};
 use dynamo_common::math_utils::contour::{Contour, IntegralCurveParams, LevelCurveParams};
 use dynamo_common::math_utils::newton::error::{Error::NanEncountered, NewtonResult};
 use dynamo_common::math_utils::{
@@ -9,23 +10,74 @@ use dynamo_common::prelude::*;
 use dynamo_common::symbolic_dynamics::OrbitSchema;
 use num_traits::{One, Zero};
 
-use ndarray::{Array2, Axis};
+use ndarray::{s, Array2, ArrayViewMut2, Axis};
 use num_cpus;
-use rayon::iter::{ParallelBridge, ParallelIterator};
-use std::{cell::RefCell, f64::consts::TAU};
+use rayon::iter::{IntoParallelIterator, ParallelBridge, ParallelIterator};
+use std::{any::type_name, cell::RefCell, collections::BTreeMap, f64::consts::TAU};
 use thread_local::ThreadLocal;
 
+pub mod autodiff;
+pub mod composition;
 pub mod covering_maps;
+pub mod critical_relations;
+pub mod equilibrium;
+pub mod escape_test;
+pub mod flow;
+pub mod inverse_iteration;
 pub mod julia;
+pub mod misiurewicz;
+pub mod multiplier_locus;
 pub mod newton;
+pub mod pcf;
+pub mod perturbation;
+pub mod ray_landing;
+pub mod real_plane;
+pub mod simd;
+pub mod skew_product;
 
 use crate::error::{FindPointError, FindPointResult};
 use crate::orbit::{self, EscapeResult, Orbit, Potential};
+use escape_test::EscapeTest;
 use julia::JuliaSet;
+use misiurewicz::MisiurewiczSpiral;
+use multiplier_locus::Bifurcation;
+use pcf::{LandedParameter, PcfParameter};
+use simd::BatchResult;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Default bound on the norm-squared of escaping orbits.
+const DEFAULT_ESCAPE_RADIUS: Real = 1e12;
+
+/// Bound on the norm-squared of escaping orbits, in the coordinate in which the first return map
+/// at infinity is monic, beyond which the potential is accurate to near machine precision.
+const NORMALIZED_ESCAPE_RADIUS: Real = 1e26;
+
+/// Largest log norm-squared allowed for the iterate after an orbit escapes, leaving headroom
+/// below overflow for lower order terms.
+const MAX_LOG_NORM_SQR: Real = 650.;
+
+/// Escape radius for a map that behaves like $az^d$ near infinity, with $d$ = `degree` and
+/// $a$ = `coeff`. See [`InfinityFirstReturnMap::calibrated_escape_radius`].
+#[inline]
+#[must_use]
+pub fn escape_radius_for(degree: Real, coeff: Cplx) -> Real
+{
+    let degree = degree.abs();
+    if degree.is_nan() || degree <= 1. {
+        return DEFAULT_ESCAPE_RADIUS;
+    }
+    let log_coeff = coeff.norm_sqr().ln();
+    let log_coeff = if log_coeff.is_finite() { log_coeff } else { 0. };
+
+    // With w = a^(1/(d-1)) z, the map is w -> w^d + ...
+    let normalized = NORMALIZED_ESCAPE_RADIUS.ln() - log_coeff / (degree - 1.);
+    // The next iterate has norm-squared about |a|^2 R^d
+    let no_overflow = (MAX_LOG_NORM_SQR - log_coeff) / degree;
+    normalized.min(no_overflow).exp()
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PlaneType
@@ -43,34 +95,97 @@ impl PlaneType
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+/// Largest factor by which [`ComputeMode::AdaptiveIterations`] raises the iteration limit.
+pub const ADAPTIVE_MAX_FACTOR: IterCount = 16;
+
+/// Distance, in pixels, from the boundary within which [`ComputeMode::AdaptiveIterations`] runs
+/// orbits further.
+const ADAPTIVE_BOUNDARY_PIXELS: Real = 2.;
+
+/// Orbit run for each pixel of a plane, as created by [`ComputeMode::create_orbit`].
+pub type PixelOrbit<'a, D> =
+    RefCell<Box<dyn Orbit<Outcome = PointInfo<D>, Checkpoint = OrbitCheckpoint<D>> + 'a>>;
+
+/// Checkpoints of the orbits of some pixels, by pixel.
+type PixelCheckpoints<D> = Vec<((usize, usize), OrbitCheckpoint<D>)>;
+
+/// Pixels whose orbits are to be run again, with the checkpoints to resume them from if any.
+type ResumedPixels<D> = Vec<((usize, usize), Option<OrbitCheckpoint<D>>)>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ComputeMode
 {
     #[default]
     SmoothPotential,
     DistanceEstimation,
+    /// Outline the Julia set by inverse iteration, as a cheap preview on dynamical planes whose
+    /// families implement [`inverse_iteration::InverseMap`]. Elsewhere, orbits are computed as
+    /// for smooth potentials.
+    InverseIteration,
+    /// Color bounded orbits by their largest Lyapunov exponent, for families implementing
+    /// [`real_plane::RealPlane2D`]. Elsewhere, orbits are computed as for smooth potentials.
+    Lyapunov,
+    /// Tell apart the distinct attracting cycles found across the plane, and mark each bounded
+    /// pixel with the basin of its cycle rather than only its period. Escaping orbits are
+    /// computed as for smooth potentials.
+    Basins,
+    /// Add complex Gaussian noise of amplitude `sigma` at every iteration, and color each pixel
+    /// by the fraction of `trials` such noisy orbits that escape.
+    Stochastic
+    {
+        sigma: Real,
+        trials: u32,
+    },
+    /// On dynamical planes, color each point as its image under the time-`time` flow of the map,
+    /// which interpolates between its iterates. See [`flow::ContinuousIteration`].
+    Flow
+    {
+        time: Real,
+    },
+    /// Follow orbits in logarithmic coordinates, for transcendental families that lift their maps
+    /// there with [`DynamicalFamily::log_lift`]. Elsewhere, orbits are computed as for smooth
+    /// potentials. See [`orbit::Logarithmic`].
+    Logarithmic,
+    /// Estimate distances as for [`ComputeMode::DistanceEstimation`], then run the orbits that ran
+    /// out of iterations further wherever a neighboring pixel is estimated to lie near the
+    /// boundary, doubling the limit for them up to [`ADAPTIVE_MAX_FACTOR`] times. See
+    /// [`Computable::raise_iterations_near_boundary`].
+    AdaptiveIterations,
 }
 impl ComputeMode
 {
     pub fn cycle(&mut self)
     {
         match self {
-            Self::DistanceEstimation => *self = Self::SmoothPotential,
+            Self::DistanceEstimation
+            | Self::InverseIteration
+            | Self::Lyapunov
+            | Self::Basins
+            | Self::Stochastic { .. }
+            | Self::Flow { .. }
+            | Self::Logarithmic
+            | Self::AdaptiveIterations => {
+                *self = Self::SmoothPotential;
+            }
             Self::SmoothPotential => *self = Self::DistanceEstimation,
         }
     }
 
-    pub fn create_orbit<'a, P: EscapeEncoding>(
-        &self,
-        family: &'a P,
-    ) -> RefCell<Box<dyn Orbit<Outcome = PointInfo<P::Deriv>> + 'a>>
+    pub fn create_orbit<'a, P: EscapeEncoding>(&self, family: &'a P) -> PixelOrbit<'a, P::Deriv>
     {
         match self {
-            Self::SmoothPotential => RefCell::new(Box::new(orbit::CycleDetected::new(family))),
-            Self::DistanceEstimation => {
+            Self::SmoothPotential | Self::InverseIteration | Self::Lyapunov | Self::Basins => {
+                RefCell::new(Box::new(orbit::CycleDetected::new(family)))
+            }
+            Self::DistanceEstimation | Self::AdaptiveIterations => {
                 RefCell::new(Box::new(orbit::DistanceEstimation::new(family)))
             }
+            Self::Stochastic { sigma, trials } => {
+                RefCell::new(Box::new(orbit::Stochastic::new(family, *sigma, *trials)))
+            }
+            Self::Flow { time } => RefCell::new(Box::new(orbit::Flowed::new(family, *time))),
+            Self::Logarithmic => RefCell::new(Box::new(orbit::Logarithmic::new(family))),
         }
     }
 }
@@ -79,7 +194,7 @@ pub trait DynamicalFamily: Sync + Send
 {
     type Var: Variable;
     type Param: Parameter;
-    type MetaParam: ParamList + Clone + Send + Sync + Default + Summarize;
+    type MetaParam: ParamList + Clone + Send + Sync + Default + Summarize + HasSliders;
     type Deriv: Derivative;
 
     fn point_grid(&self) -> &PointGrid;
@@ -131,20 +246,6 @@ pub trait DynamicalFamily: Sync + Send
         *self.compute_mode_mut() = compute_mode;
     }
 
-    fn name(&self) -> String;
-    fn long_name(&self) -> String
-    {
-        let short_name = self.name();
-        self.get_param().summarize().map_or_else(
-            || self.name(),
-            |param_desc| format!("{short_name}: {param_desc}"),
-        )
-    }
-    fn description(&self) -> String
-    {
-        String::new()
-    }
-
     /// The map defining the dynamical system.
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var;
 
@@ -160,6 +261,57 @@ pub trait DynamicalFamily: Sync + Send
         (fz, df_dz, Self::Deriv::one())
     }
 
+    /// All preimages of `z` under the map, listed with multiplicity. Families that cannot invert
+    /// their maps return none, which disables features such as the equilibrium measure; see
+    /// [`inverse_iteration::InverseMap`].
+    fn preimages(&self, _z: Self::Var, _c: &Self::Param) -> Vec<Self::Var>
+    {
+        vec![]
+    }
+
+    /// Largest Lyapunov exponent along the orbit of `start`, or the iteration at which it
+    /// escapes. Families of maps of the real plane compute it with [`real_plane::RealPlane2D`];
+    /// others return none.
+    fn lyapunov_info(&self, _start: Self::Var, _c: &Self::Param) -> Option<PointInfo<Self::Deriv>>
+    {
+        None
+    }
+
+    /// The map in logarithmic coordinates `w`, with `z = exp(w)`: a lift `F` with
+    /// `exp(F(w)) = f(exp(w))`. Transcendental families provide one so that their orbits can be
+    /// followed far out in the tracts without overflow; others return none.
+    fn log_lift(&self, _w: Cplx, _c: &Self::Param) -> Option<Cplx>
+    {
+        None
+    }
+
+    /// A point in the basin of an attractor, whose forward orbit samples the attractor. Families
+    /// without a meaningful attractor return none.
+    fn attractor_seed(&self, _c: &Self::Param) -> Option<Self::Var>
+    {
+        None
+    }
+
+    /// Escape results for the orbits of a batch of points, computed several at a time with SIMD
+    /// instructions. Returns `None` if the family has no SIMD path; see [`simd::SimdMap`].
+    fn escape_batch(&self, _points: &[Cplx]) -> Option<BatchResult<Self::Var, Self::Deriv>>
+    {
+        None
+    }
+
+    /// As [`escape_batch`](Self::escape_batch), but for orbits with the given start points and
+    /// parameters, iterated within the given limits.
+    fn iterate_batch(
+        &self,
+        _starts: &[Self::Var],
+        _params: &[Self::Param],
+        _min_iter: IterCount,
+        _max_iter: IterCount,
+    ) -> Option<BatchResult<Self::Var, Self::Deriv>>
+    {
+        None
+    }
+
     /// If certain regions in parameter space are known (e.g. the main cardioid in the Mandelbrot set), we can
     /// avoid having to compute orbits for parameters in those regions.
     ///
@@ -190,11 +342,20 @@ pub trait DynamicalFamily: Sync + Send
     /// beyond which an orbit is considered to have escaped.
     ///
     /// Only relevant for maps with an attracting or parabolic cycle containing infinity. In the
-    /// latter case, it is recommended to set escape_radius to a much smaller value.
+    /// latter case, it is recommended to set escape_radius to a much smaller value. In the former,
+    /// [`InfinityFirstReturnMap::calibrated_escape_radius`] picks a value suited to the map.
     #[inline]
     fn escape_radius(&self) -> Real
     {
-        1e12
+        DEFAULT_ESCAPE_RADIUS
+    }
+
+    /// Criterion by which orbits are found to escape. By default, the norm-squared of the
+    /// variable is compared to [`escape_radius`](Self::escape_radius).
+    #[inline]
+    fn escape_test(&self) -> EscapeTest
+    {
+        EscapeTest::NormSqr(self.escape_radius())
     }
 
     #[inline]
@@ -205,15 +366,7 @@ pub trait DynamicalFamily: Sync + Send
         iter: IterCount,
     ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
     {
-        let r = z.norm_sqr();
-        if r > self.escape_radius() || z.is_nan() {
-            Some(EscapeResult::Escaped {
-                iters: iter,
-                final_value: z,
-            })
-        } else {
-            None
-        }
+        self.escape_test().check(z, iter)
     }
 
     #[inline]
@@ -431,6 +584,77 @@ pub trait DynamicalFamily: Sync + Send
         find_root_newton(diff, start_point).map_err(FindPointError::NewtonError)
     }
 
+    /// Find the Misiurewicz parameter near `start_point` with the given orbit schema, together
+    /// with the self-similarity of the parameter plane about it.
+    fn misiurewicz_spiral(
+        &self,
+        start_point: Cplx,
+        orbit_schema: OrbitSchema,
+    ) -> Result<MisiurewiczSpiral, FindPointError<Cplx>>
+    {
+        let center = self.find_nearby_preperiodic_point(start_point, orbit_schema)?;
+
+        let c = self.param_map(center);
+        let mut z = self.start_point(center, &c);
+        for _ in 0..orbit_schema.preperiod {
+            z = self.map(z, &c);
+        }
+        let mut multiplier = ONE;
+        for _ in 0..orbit_schema.period {
+            let (w, dz) = self.map_and_multiplier(z, &c);
+            multiplier *= dz.into();
+            z = w;
+        }
+
+        if multiplier.norm_sqr() <= 1. || !multiplier.is_finite() {
+            return Err(FindPointError::NotRepelling);
+        }
+        Ok(MisiurewiczSpiral {
+            center,
+            orbit_schema,
+            multiplier,
+        })
+    }
+
+    /// Trace the boundary of the hyperbolic component of period `period` containing
+    /// `start_point`, as the locus along which the multiplier of its cycle runs around the unit
+    /// circle, starting and ending at the given bifurcation.
+    fn multiplier_locus(
+        &self,
+        start_point: Cplx,
+        period: Period,
+        bifurcation: Bifurcation,
+    ) -> Result<Vec<Cplx>, FindPointError<Cplx>>
+    {
+        multiplier_locus::trace(self, start_point, period, bifurcation)
+    }
+
+    /// Find a parameter near `start_point` at which a cycle of period `period` has the given
+    /// multiplier, e.g. a parabolic parameter for a root of unity or a Siegel parameter for an
+    /// irrational rotation.
+    fn find_nearby_multiplier(
+        &self,
+        start_point: Cplx,
+        period: Period,
+        multiplier: Cplx,
+    ) -> FindPointResult<Cplx>
+    {
+        multiplier_locus::find(self, start_point, period, multiplier)
+    }
+
+    /// Postcritically finite parameters in the current view with preperiod at most
+    /// `max_preperiod` and period at most `max_period`, found by Newton's method from a grid of
+    /// `seeds_per_axis` by `seeds_per_axis` starting points.
+    fn pcf_parameters(
+        &self,
+        max_preperiod: Period,
+        max_period: Period,
+        seeds_per_axis: usize,
+    ) -> Vec<PcfParameter>
+    {
+        pcf::search(self, max_preperiod, max_period, seeds_per_axis)
+    }
+
     fn run_point(&self, selection: Cplx) -> EscapeResult<Self::Var, Self::Deriv>
     where
         Self: Clone,
@@ -459,6 +683,29 @@ pub trait DynamicalFamily: Sync + Send
         )
     }
 
+    /// The points of `orbit` under the map with parameter `c`, with the derivatives of the map
+    /// and of its iterates along it.
+    fn orbit_steps(&self, orbit: &[Self::Var], c: &Self::Param) -> Vec<orbit::OrbitStep>
+    {
+        let mut derivative = ONE;
+        orbit
+            .iter()
+            .enumerate()
+            .map(|(iter, z)| {
+                let (_, multiplier) = self.map_and_multiplier(*z, c);
+                let multiplier: Cplx = multiplier.into();
+                let step = orbit::OrbitStep {
+                    iter: iter as IterCount,
+                    z: (*z).into(),
+                    multiplier,
+                    derivative,
+                };
+                derivative *= multiplier;
+                step
+            })
+            .collect()
+    }
+
     fn get_orbit_vec(&self, point: Cplx) -> Vec<Self::Var>
     {
         let param = self.param_map(point);
@@ -494,6 +741,60 @@ pub trait DynamicalFamily: Sync + Send
         PlaneType::Parameter
     }
 
+    /// Optional map for superimposed contours
+    fn auxiliary_value(&self, _t: Cplx) -> Option<(Cplx, Cplx)>
+    {
+        None
+    }
+}
+
+impl std::fmt::Display for PlaneType
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Parameter => write!(f, "parameter"),
+            Self::Dynamical => write!(f, "dynamical"),
+        }
+    }
+}
+
+/// How a family is presented in the interface: its name, the region shown when it is opened, and
+/// the colorings it prefers. None of this is needed to compute with the family.
+pub trait DisplayDefaults: DynamicalFamily + InfinityFirstReturnMap
+{
+    /// Short name of the family, by default the name of the type.
+    fn name(&self) -> String
+    {
+        let full_struct_name = type_name::<Self>();
+        full_struct_name
+            .split("::")
+            .last()
+            .unwrap_or("Unknown")
+            .to_owned()
+    }
+
+    fn long_name(&self) -> String
+    {
+        let short_name = self.name();
+        self.get_param().summarize().map_or_else(
+            || self.name(),
+            |param_desc| format!("{short_name}: {param_desc}"),
+        )
+    }
+
+    fn description(&self) -> String
+    {
+        String::new()
+    }
+
+    /// Names of the real and imaginary parts of the variable, used to label plots of orbits
+    /// against time.
+    fn trace_labels(&self) -> [&'static str; 2]
+    {
+        ["Re(z)", "Im(z)"]
+    }
+
     /// Define a custom fill rate for perperiod based coloring.
     fn preperiod_coloring(&self) -> IncoloringAlgorithm
     {
@@ -521,26 +822,6 @@ pub trait DynamicalFamily: Sync + Send
         }
     }
 
-    /// Optional map for superimposed contours
-    fn auxiliary_value(&self, _t: Cplx) -> Option<(Cplx, Cplx)>
-    {
-        None
-    }
-}
-
-impl std::fmt::Display for PlaneType
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
-    {
-        match self {
-            Self::Parameter => write!(f, "parameter"),
-            Self::Dynamical => write!(f, "dynamical"),
-        }
-    }
-}
-
-pub trait FamilyDefaults: DynamicalFamily + InfinityFirstReturnMap
-{
     /// Default bounds for this plane
     fn default_bounds(&self) -> Bounds;
 
@@ -554,11 +835,22 @@ pub trait FamilyDefaults: DynamicalFamily + InfinityFirstReturnMap
     /// Default coloring algorithm to apply when loading the parameter plane.
     fn default_coloring(&self) -> Coloring
     {
-        let mut coloring = Coloring::default().with_escape_period(self.escaping_period());
+        let mut coloring = Coloring::default()
+            .with_escape_period(self.escaping_period())
+            .with_escape_degree(self.degree_real());
         coloring.set_interior_algorithm(IncoloringAlgorithm::PeriodMultiplier);
         coloring
     }
 
+    /// Default coloring algorithm to apply when loading a Julia set spawned from this plane.
+    fn default_coloring_child(&self) -> Coloring
+    {
+        Coloring::default()
+            .with_interior_algorithm(self.internal_potential_coloring())
+            .with_escape_period(1)
+            .with_escape_degree(self.degree_real())
+    }
+
     #[must_use]
     fn with_default_bounds(self) -> Self
     where
@@ -586,14 +878,6 @@ pub trait HasJulia: DynamicalFamily + InfinityFirstReturnMap
         Bounds::centered_square(2.2)
     }
 
-    /// Default coloring algorithm to apply when loading the Julia set.
-    fn default_coloring_child(&self) -> Coloring
-    {
-        Coloring::default()
-            .with_interior_algorithm(self.internal_potential_coloring())
-            .with_escape_period(1)
-    }
-
     /// Map points in the image to dynamical variables. Used for multivariable systems or covering maps
     /// over existing dynamical planes.
     ///
@@ -611,9 +895,32 @@ pub trait HasJulia: DynamicalFamily + InfinityFirstReturnMap
     }
 }
 
+/// How the point selected on a parent plane determines the plane shown next to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PaneLink
+{
+    /// The point is a parameter, and the child shows the dynamics of the map it selects.
+    #[default]
+    Parameter,
+    /// The point lies in the base of a skew product, and the child shows the fiber over it.
+    Fiber,
+}
+
 pub trait HasChild<C: DynamicalFamily>: DynamicalFamily
 {
     fn to_child_param(param: Self::Param) -> <C::MetaParam as ParamList>::Param;
+
+    #[inline]
+    fn pane_link(&self) -> PaneLink
+    {
+        PaneLink::Parameter
+    }
+
+    /// Propagate this plane's meta-parameters to a child spawned from it, e.g. after they have
+    /// been changed by a slider.
+    #[inline]
+    fn update_child_meta_params(&self, _child: &mut C) {}
 }
 
 impl<T: HasJulia> HasChild<JuliaSet<T>> for T
@@ -624,6 +931,14 @@ impl<T: HasJulia> HasChild<JuliaSet<T>> for T
     {
         param
     }
+
+    #[inline]
+    fn update_child_meta_params(&self, child: &mut JuliaSet<T>)
+    {
+        let meta_params = self.get_meta_params();
+        child.parent.set_meta_param(meta_params.clone());
+        child.meta_params = meta_params;
+    }
 }
 
 pub trait MarkedPoints: DynamicalFamily
@@ -708,6 +1023,14 @@ pub trait MarkedPoints: DynamicalFamily
         0
     }
 
+    /// Curve through `t0` in a parameter plane along which the `steps`th iterate of the critical
+    /// point keeps its distance from the nearest critical, co-critical or marked point; see
+    /// [`critical_relations`].
+    fn critical_relation_curve(&self, t0: Cplx, steps: Period) -> Vec<Cplx>
+    {
+        critical_relations::trace(self, t0, steps)
+    }
+
     /// Lower bound on norm-squared, below which an orbit is considered to have reached a marked
     /// point.
     #[inline]
@@ -721,9 +1044,12 @@ pub trait MarkedPoints: DynamicalFamily
         &self,
         z: Self::Var,
         c: &Self::Param,
-        info: PointInfoPeriodic<Self::Deriv>,
+        mut info: PointInfoPeriodic<Self::Deriv>,
     ) -> PointInfo<Self::Deriv>
     {
+        if self.compute_mode() == ComputeMode::Basins {
+            info.cycle_point = cycle_representative(self, z, c, info.period);
+        }
         let marked_points = self.get_marked_points(c);
         for (zi, class_id) in &marked_points {
             if z.dist_sqr(*zi) < self.marked_point_tolerance() {
@@ -811,12 +1137,38 @@ pub trait InfinityFirstReturnMap: DynamicalFamily
         (ONE, ZERO)
     }
 
+    /// Escape radius suited to the degree and leading coefficient of the first return map at
+    /// infinity, for use in place of a fixed constant in `escape_radius`.
+    ///
+    /// Orbits escape at the same radius in the coordinate in which the map is monic, so that
+    /// potentials are equally accurate whatever the leading coefficient, unless the next iterate
+    /// could then overflow. As for external rays, assumes that `escape_coeff` is constant.
+    #[inline]
+    fn calibrated_escape_radius(&self) -> Real
+    {
+        escape_radius_for(self.degree_real(), self.escape_coeff(&self.param_map(ONE)))
+    }
+
+    /// Difference between twice the Green's function and the log norm-squared of points near
+    /// infinity, coming from the leading coefficient $a$ of the first return map:
+    /// $2G(z) \approx \log|z|^2 + \log|a|^2/(d-1)$.
+    #[inline]
+    fn potential_offset(&self, c: &Self::Param) -> Real
+    {
+        let log_coeff = self.escape_coeff(c).norm_sqr().ln();
+        if log_coeff == 0. {
+            // Also avoids 0/0 for monic maps of degree 1
+            return 0.;
+        }
+        log_coeff / (self.degree_real() - 1.)
+    }
+
     /// Evaluate Green's function given the escape time and final value
     fn smooth_iter_count(&self, iters: IterCount, z: Self::Var, c: &Self::Param) -> Real
     {
         let u = self.escape_radius().ln();
         let v = z.norm_sqr().ln();
-        let q = self.escape_coeff(c).norm().ln();
+        let q = self.potential_offset(c);
         let residual = ((u + q) / (v + q)).log(self.degree_real()) as IterCountSmooth;
         residual.mul_add(
             IterCountSmooth::from(self.escaping_period()),
@@ -955,6 +1307,28 @@ pub trait ExternalRays: DynamicalFamily + InfinityFirstReturnMap
             None
         }
     }
+
+    /// The postcritically finite parameter at which the parameter ray at `angle` lands, if it
+    /// can be traced and refined to one.
+    fn land_parameter_ray(&self, angle: RationalAngle) -> Option<PcfParameter>
+    {
+        pcf::land_ray(self, angle)
+    }
+
+    /// All centers and Misiurewicz parameters reached by parameter rays whose angles have
+    /// preperiod and period adding up to at most `max_len`, with the angles landing at each.
+    fn pcf_catalog(&self, max_len: Period) -> Vec<LandedParameter>
+    {
+        pcf::catalog(self, max_len)
+    }
+
+    /// Where the rays at every angle of period `period` land, computed together: the center of
+    /// the component at whose root each ray lands on parameter planes, and the landing point on
+    /// dynamical planes, where rays in the same cycle share their tracing.
+    fn periodic_ray_landings(&self, period: Period) -> BTreeMap<RationalAngle, Cplx>
+    {
+        ray_landing::land_periodic_rays(self, period)
+    }
 }
 
 pub trait Equipotential: DynamicalFamily
@@ -1071,7 +1445,9 @@ pub trait EscapeEncoding: DynamicalFamily + InfinityFirstReturnMap + MarkedPoint
     {
         match result {
             EscapeResult::Escaped { iters, final_value } => {
+                let w: Cplx = final_value.into();
                 self.encode_escaping_point(iters, final_value, c)
+                    .with_escape_angle(w.arg())
             }
             EscapeResult::Periodic { info, final_value } => {
                 self.identify_marked_points(final_value, c, info)
@@ -1096,6 +1472,7 @@ pub trait EscapeEncoding: DynamicalFamily + InfinityFirstReturnMap + MarkedPoint
             return PointInfo::Escaping {
                 potential: (iters as IterCountSmooth).exp(),
                 phase: None,
+                angle: None,
             };
         }
 
@@ -1103,10 +1480,59 @@ pub trait EscapeEncoding: DynamicalFamily + InfinityFirstReturnMap + MarkedPoint
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
 
+/// A band of consecutive rows of a plane's iteration counts, which can be computed independently
+/// of the rest of the plane, e.g. by a background worker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TileJob
+{
+    pub first_row: usize,
+    pub num_rows: usize,
+}
+
+impl TileJob
+{
+    /// Split the rows of a grid into tiles of at most `rows_per_tile` rows each.
+    #[must_use]
+    pub fn split(point_grid: &PointGrid, rows_per_tile: usize) -> Vec<Self>
+    {
+        let rows_per_tile = rows_per_tile.max(1);
+        (0..point_grid.res_y)
+            .step_by(rows_per_tile)
+            .map(|first_row| Self {
+                first_row,
+                num_rows: rows_per_tile.min(point_grid.res_y - first_row),
+            })
+            .collect()
+    }
+}
+
+/// The iteration counts of the rows of a `TileJob`.
+#[derive(Clone, Debug)]
+pub struct ComputedTile<D>
+{
+    pub job: TileJob,
+    pub iter_counts: Array2<PointInfo<D>>,
+}
+
+impl<D: Clone> ComputedTile<D>
+{
+    /// Copy the tile into its rows of `iter_plane`, which must have the shape of the grid that
+    /// the tile was computed from.
+    pub fn paste_into(&self, iter_plane: &mut IterPlane<D>)
+    {
+        let rows = self.job.first_row..self.job.first_row + self.job.num_rows;
+        iter_plane
+            .iter_counts
+            .slice_mut(s![.., rows])
+            .assign(&self.iter_counts);
+    }
+}
+
 pub trait Computable: DynamicalFamily
 {
     fn compute(&self) -> IterPlane<Self::Deriv>
@@ -1118,6 +1544,83 @@ pub trait Computable: DynamicalFamily
 
     fn compute_into(&self, iter_plane: &mut IterPlane<Self::Deriv>);
 
+    /// Compute only the rows of the point grid covered by `tile`, on the current thread.
+    fn compute_tile(&self, tile: TileJob) -> ComputedTile<Self::Deriv>;
+
+    /// Compute the points of a block of pixels of `iter_plane`, such as one exposed by
+    /// [`IterPlane::translate_and_invalidate`], leaving the rest of the plane as it is.
+    fn compute_block_into(&self, iter_plane: &mut IterPlane<Self::Deriv>, block: PixelBlock);
+
+    /// Compute the points of the pixels of `iter_plane` marked in `stale`, such as those of a
+    /// preview from [`IterPlane::zoom_preview`], leaving the rest of the plane as it is.
+    fn compute_stale_into(&self, iter_plane: &mut IterPlane<Self::Deriv>, stale: &Array2<bool>);
+
+    /// Run the orbits of the pixels of `iter_plane` that ran out of iterations further, after the
+    /// limit was raised. Orbits are resumed from the checkpoints saved when they were computed,
+    /// and those that were not saved are run again from the start.
+    fn resume_into(&self, iter_plane: &mut IterPlane<Self::Deriv>);
+
+    /// Run the orbits of the pixels of `iter_plane` that ran out of iterations further where a
+    /// neighboring pixel is estimated to lie near the boundary, doubling the iteration limit for
+    /// them until none are left or the limit reaches [`ADAPTIVE_MAX_FACTOR`] times its value.
+    /// Pixels away from the boundary, where more iterations are unlikely to change the picture,
+    /// are left as they are. The limits the pixels were run with are recorded in `iter_plane`.
+    fn raise_iterations_near_boundary(&self, iter_plane: &mut IterPlane<Self::Deriv>)
+    where
+        Self: Clone;
+
+    /// Estimate the area of the points in view that do not escape. The view is computed at
+    /// `levels` resolutions, doubling from a width of `base_width` pixels, and the areas covered
+    /// by bounded pixels are extrapolated to infinite resolution.
+    fn estimate_bounded_area(&self, base_width: usize, levels: u32) -> Option<Estimate>
+    where
+        Self: Clone,
+    {
+        let samples: Vec<_> = (0..levels)
+            .map(|k| {
+                let iter_plane = self.clone().with_res_x(base_width << k).compute();
+                let pixel_width = iter_plane.point_grid.pixel_width();
+                (pixel_width, analysis::bounded_area(&iter_plane))
+            })
+            .collect();
+        analysis::richardson_extrapolate(&samples)
+    }
+
+    /// Approximate the measure of maximal entropy on the Julia set in view by the density of a
+    /// random backward orbit of `num_points` points; see [`equilibrium`].
+    fn equilibrium_density(&self, num_points: usize) -> Option<Array2<u32>>
+    {
+        if !self.plane_type().is_dynamical() {
+            return None;
+        }
+        equilibrium::density(self, num_points)
+    }
+
+    /// Tree of preimages of `start` up to order `depth`, pruning branches that come within
+    /// `min_separation` of each other; see [`inverse_iteration::preimage_tree`].
+    fn preimage_tree(
+        &self,
+        start: Cplx,
+        depth: usize,
+        min_separation: Real,
+    ) -> Option<Vec<Vec<inverse_iteration::PreimageNode>>>
+    {
+        if !self.plane_type().is_dynamical() {
+            return None;
+        }
+        inverse_iteration::preimage_tree(self, start, depth, min_separation)
+    }
+
+    /// Approximate the natural measure on the attractor in view by the density of a forward
+    /// orbit of `num_points` points; see [`real_plane`].
+    fn attractor_density(&self, num_points: usize) -> Option<Array2<u32>>
+    {
+        if !self.plane_type().is_dynamical() {
+            return None;
+        }
+        real_plane::attractor_density(self, num_points)
+    }
+
     fn get_orbit_and_info(
         &self,
         point: Cplx,
@@ -1154,51 +1657,332 @@ where
             })
             .collect();
         let result = self.encode_escape_result(final_state.unwrap_or_default(), start, &param);
+
+        // Cycle detection stops once the orbit has settled onto its cycle, so its last points
+        // approximate the cycle (in reverse order, which does not affect the renormalization)
+        let renormalization = match &result {
+            PointInfo::Periodic(data) | PointInfo::MarkedPoint { data, .. }
+                if !self.plane_type().is_dynamical() =>
+            {
+                let cycle: Vec<Cplx> = trajectory
+                    .iter()
+                    .rev()
+                    .take(data.period as usize)
+                    .map(|&z| z.into())
+                    .collect();
+                orbit::renormalization_periods(&cycle, start.into())
+            }
+            _ => Vec::new(),
+        };
+
         orbit::OrbitAndInfo {
             orbit: trajectory,
             info: orbit::Info {
                 param,
                 start,
                 result,
+                renormalization,
             },
         }
     }
 
     fn compute_into(&self, iter_plane: &mut IterPlane<Self::Deriv>)
     {
+        iter_plane.checkpoints.clear();
+        iter_plane.iteration_limits.clear();
         if self.point_grid().is_nan() {
             return;
         }
+        if self.compute_mode() == ComputeMode::InverseIteration
+            && self.plane_type().is_dynamical()
+            && inverse_iteration::fill(self, iter_plane)
+        {
+            return;
+        }
+        if self.compute_mode() == ComputeMode::Lyapunov && real_plane::fill(self, iter_plane) {
+            return;
+        }
 
         let orbits = ThreadLocal::new();
 
-        let chunk_size = self.point_grid().res_y / num_cpus::get();
+        let chunk_size = (self.point_grid().res_y / num_cpus::get()).max(1);
 
-        iter_plane
+        iter_plane.checkpoints = iter_plane
+            .iter_counts
+            .axis_chunks_iter_mut(Axis(1), chunk_size)
+            .enumerate()
+            .par_bridge()
+            .flat_map_iter(|(chunk_idx, chunk)| {
+                let orbit = orbits.get_or(|| self.compute_mode().create_orbit(self));
+                compute_rows(self, orbit, 0, chunk_idx * chunk_size, chunk)
+            })
+            .collect();
+
+        if self.compute_mode() == ComputeMode::Basins {
+            iter_plane.classify_basins(self.periodicity_tolerance().powf(0.25));
+        }
+    }
+
+    fn compute_tile(&self, tile: TileJob) -> ComputedTile<Self::Deriv>
+    {
+        let shape = (self.point_grid().res_x, tile.num_rows);
+        let mut iter_counts = Array2::from_elem(shape, PointInfo::Bounded);
+        if !self.point_grid().is_nan() {
+            // Tiles are not kept whole, so their checkpoints are dropped
+            let orbit = self.compute_mode().create_orbit(self);
+            compute_rows(self, &orbit, 0, tile.first_row, iter_counts.view_mut());
+        }
+        ComputedTile {
+            job: tile,
+            iter_counts,
+        }
+    }
+
+    fn compute_block_into(&self, iter_plane: &mut IterPlane<Self::Deriv>, block: PixelBlock)
+    {
+        if self.point_grid().is_nan() {
+            return;
+        }
+        let (columns, rows) = block;
+        let (first_column, first_row) = (columns.start, rows.start);
+        let orbits = ThreadLocal::new();
+        let chunk_size = (rows.len() / num_cpus::get()).max(1);
+
+        let checkpoints: Vec<_> = iter_plane
             .iter_counts
+            .slice_mut(s![columns, rows])
             .axis_chunks_iter_mut(Axis(1), chunk_size)
             .enumerate()
             .par_bridge()
-            .for_each(|(chunk_idx, mut chunk)| {
-                chunk.indexed_iter_mut().for_each(|((x, local_y), count)| {
-                    let y = chunk_idx * chunk_size + local_y;
-                    let mut orbit = orbits
-                        .get_or(|| self.compute_mode().create_orbit(self))
-                        .borrow_mut();
-
-                    let point = self.point_grid().map_pixel(x, y);
+            .flat_map_iter(|(chunk_idx, chunk)| {
+                let orbit = orbits.get_or(|| self.compute_mode().create_orbit(self));
+                let row = first_row + chunk_idx * chunk_size;
+                compute_rows(self, orbit, first_column, row, chunk)
+            })
+            .collect();
+        iter_plane.checkpoints.extend(checkpoints);
+    }
+
+    fn compute_stale_into(&self, iter_plane: &mut IterPlane<Self::Deriv>, stale: &Array2<bool>)
+    {
+        if self.point_grid().is_nan() {
+            return;
+        }
+        let orbits = ThreadLocal::new();
+        let chunk_size = (self.point_grid().res_y / num_cpus::get()).max(1);
+
+        let checkpoints: Vec<_> = iter_plane
+            .iter_counts
+            .axis_chunks_iter_mut(Axis(1), chunk_size)
+            .zip(stale.axis_chunks_iter(Axis(1), chunk_size))
+            .enumerate()
+            .par_bridge()
+            .flat_map_iter(|(chunk_idx, (mut chunk, stale))| {
+                let mut orbit = orbits
+                    .get_or(|| self.compute_mode().create_orbit(self))
+                    .borrow_mut();
+                let first_row = chunk_idx * chunk_size;
+                let mut checkpoints = Vec::new();
+                for ((x, y), count) in chunk.indexed_iter_mut() {
+                    if stale[(x, y)] {
+                        orbit.reset(self.point_grid().map_pixel(x, first_row + y));
+                        *count = orbit.run_until_complete();
+                        if let Some(checkpoint) = orbit.checkpoint() {
+                            checkpoints.push(((x, first_row + y), checkpoint));
+                        }
+                    }
+                }
+                checkpoints
+            })
+            .collect();
+        iter_plane.checkpoints.extend(checkpoints);
+    }
+
+    fn resume_into(&self, iter_plane: &mut IterPlane<Self::Deriv>)
+    {
+        if self.point_grid().is_nan() {
+            return;
+        }
+        iter_plane.iteration_limits.clear();
+        let mut checkpoints = std::mem::take(&mut iter_plane.checkpoints);
+        let pixels: Vec<_> = iter_plane
+            .iter_counts
+            .indexed_iter()
+            .filter_map(|(pixel, point_info)| {
+                let checkpoint = checkpoints.remove(&pixel);
+                let unfinished = matches!(
+                    point_info,
+                    PointInfo::Bounded
+                        | PointInfo::Unknown
+                        | PointInfo::Wandering
+                        | PointInfo::FastEscaping { .. }
+                );
+                (checkpoint.is_some() || unfinished).then_some((pixel, checkpoint))
+            })
+            .collect();
+        resume_pixels(self, iter_plane, pixels);
+    }
+
+    fn raise_iterations_near_boundary(&self, iter_plane: &mut IterPlane<Self::Deriv>)
+    where
+        Self: Clone,
+    {
+        let pixel_size = self.point_grid().pixel_width();
+        let base = self.max_iter();
+        let mut limits = Array2::from_elem(iter_plane.iter_counts.dim(), base);
+        let mut family = self.clone();
+        while family.max_iter() < base.saturating_mul(ADAPTIVE_MAX_FACTOR) {
+            let pixels: Vec<_> = iter_plane
+                .checkpoints
+                .keys()
+                .copied()
+                .filter(|&pixel| near_boundary(iter_plane, pixel, pixel_size))
+                .collect();
+            if pixels.is_empty() {
+                break;
+            }
+            let limit = family.max_iter_mut();
+            *limit = limit.saturating_mul(2);
+            let pixels = pixels
+                .into_iter()
+                .map(|pixel| {
+                    limits[pixel] = family.max_iter();
+                    (pixel, iter_plane.checkpoints.remove(&pixel))
+                })
+                .collect();
+            resume_pixels(&family, iter_plane, pixels);
+        }
+        iter_plane.iteration_limits = BTreeMap::new();
+        for &limit in &limits {
+            *iter_plane.iteration_limits.entry(limit).or_default() += 1;
+        }
+    }
+}
+
+/// Run the orbits of `pixels` of `iter_plane` again, from their checkpoints where given and
+/// from the start otherwise, and store their outcomes and new checkpoints.
+fn resume_pixels<P>(
+    plane: &P,
+    iter_plane: &mut IterPlane<P::Deriv>,
+    pixels: ResumedPixels<P::Deriv>,
+) where
+    P: EscapeEncoding,
+{
+    let orbits = ThreadLocal::new();
+    let outcomes: Vec<_> = pixels
+        .into_par_iter()
+        .map(|((x, y), checkpoint)| {
+            let mut orbit = orbits
+                .get_or(|| plane.compute_mode().create_orbit(plane))
+                .borrow_mut();
+            let point = plane.point_grid().map_pixel(x, y);
+            let resumed = checkpoint.is_some_and(|checkpoint| orbit.resume(point, &checkpoint));
+            if !resumed {
+                orbit.reset(point);
+            }
+            ((x, y), orbit.run_until_complete(), orbit.checkpoint())
+        })
+        .collect();
+
+    for (pixel, outcome, checkpoint) in outcomes {
+        iter_plane.iter_counts[pixel] = outcome;
+        if let Some(checkpoint) = checkpoint {
+            iter_plane.checkpoints.insert(pixel, checkpoint);
+        }
+    }
+}
+
+/// Whether a pixel next to `(x, y)` has its distance to the boundary estimated to be within
+/// [`ADAPTIVE_BOUNDARY_PIXELS`] pixels of size `pixel_size`.
+fn near_boundary<D>(iter_plane: &IterPlane<D>, (x, y): (usize, usize), pixel_size: Real) -> bool
+{
+    let (width, height) = iter_plane.iter_counts.dim();
+    let columns = x.saturating_sub(1)..=(x + 1).min(width - 1);
+    let rows = y.saturating_sub(1)..=(y + 1).min(height - 1);
+    iter_plane
+        .iter_counts
+        .slice(s![columns, rows])
+        .iter()
+        .any(|point_info| match point_info {
+            PointInfo::DistanceEstimate { distance, .. }
+            | PointInfo::InteriorDistanceEstimate { distance, .. } => {
+                *distance < ADAPTIVE_BOUNDARY_PIXELS * pixel_size
+            }
+            _ => false,
+        })
+}
+
+/// The point of the cycle through `z` that is least in a generic direction, so that every orbit
+/// attracted to the cycle picks out the same point, whichever point of the cycle it reached.
+fn cycle_representative<P>(plane: &P, mut z: P::Var, c: &P::Param, period: Period) -> Cplx
+where
+    P: DynamicalFamily + ?Sized,
+{
+    const SLOPE: Real = 0.618_033_988_749_895;
+    let key = |w: Cplx| SLOPE.mul_add(w.im, w.re);
+
+    let mut best: Cplx = z.into();
+    for _ in 1..period {
+        z = plane.map(z, c);
+        let w: Cplx = z.into();
+        if key(w) < key(best) {
+            best = w;
+        }
+    }
+    best
+}
+
+/// Fill `rows`, the block of the point grid whose first column and row are `first_column` and
+/// `first_row`, with the outcomes of their orbits. Returns the checkpoints of the orbits that ran
+/// out of iterations, by pixel.
+fn compute_rows<P>(
+    plane: &P,
+    orbit: &PixelOrbit<'_, P::Deriv>,
+    first_column: usize,
+    first_row: usize,
+    mut rows: ArrayViewMut2<PointInfo<P::Deriv>>,
+) -> PixelCheckpoints<P::Deriv>
+where
+    P: EscapeEncoding,
+{
+    let mut checkpoints = Vec::new();
+    let mut orbit = orbit.borrow_mut();
+    // Distance estimation needs derivatives along the orbit, so only smooth potentials are
+    // batched
+    let batched = plane.compute_mode() == ComputeMode::SmoothPotential;
+    for (local_y, mut row) in rows.axis_iter_mut(Axis(1)).enumerate() {
+        let y = first_row + local_y;
+        let points: Vec<Cplx> = (0..row.len())
+            .map(|x| plane.point_grid().map_pixel(first_column + x, y))
+            .collect();
+        let mut escaped = batched.then(|| plane.escape_batch(&points)).flatten();
+        for (x, count) in row.iter_mut().enumerate() {
+            let point = points[x];
+            *count = match escaped.as_mut().and_then(|escaped| escaped[x].take()) {
+                Some(result) => {
+                    let param = plane.param_map(point);
+                    let start = plane.start_point(point, &param);
+                    plane.encode_escape_result(result, start, &param)
+                }
+                None => {
                     orbit.reset(point);
-                    *count = orbit.run_until_complete();
-                });
-            });
+                    let outcome = orbit.run_until_complete();
+                    if let Some(checkpoint) = orbit.checkpoint() {
+                        checkpoints.push(((first_column + x, y), checkpoint));
+                    }
+                    outcome
+                }
+            };
+        }
     }
+    checkpoints
 }
 
 pub trait Displayable:
-    DynamicalFamily + FamilyDefaults + ExternalRays + Equipotential + Computable + MarkedPoints
+    DynamicalFamily + DisplayDefaults + ExternalRays + Equipotential + Computable + MarkedPoints
 {
 }
 impl<P> Displayable for P where
-    P: DynamicalFamily + FamilyDefaults + ExternalRays + Equipotential + Computable + MarkedPoints
+    P: DynamicalFamily + DisplayDefaults + ExternalRays + Equipotential + Computable + MarkedPoints
 {
 }
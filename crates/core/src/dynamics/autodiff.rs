@@ -0,0 +1,54 @@
+use dynamo_common::prelude::*;
+use dynamo_common::types::dual::Scalar;
+
+/// A family whose map is written generically over [`Scalar`], so that its derivatives can be
+/// computed by automatic differentiation rather than by hand.
+///
+/// Every such family implements [`AutoDiff`]; use [`auto_diff_impl`] in its `DynamicalFamily`
+/// implementation to take the map and its derivatives from there.
+///
+/// [`auto_diff_impl`]: crate::macros::auto_diff_impl
+pub trait GenericMap
+{
+    fn map_generic<T: Scalar>(&self, z: T, c: T) -> T;
+}
+
+/// The map of a family together with its derivatives, obtained by evaluating it on dual numbers.
+pub trait AutoDiff
+{
+    fn map_ad(&self, z: Cplx, c: Cplx) -> Cplx;
+
+    /// The map and its derivative in `z`.
+    fn map_and_multiplier_ad(&self, z: Cplx, c: Cplx) -> (Cplx, Cplx);
+
+    /// The map and its derivatives in `z` and `c`.
+    fn gradient_ad(&self, z: Cplx, c: Cplx) -> (Cplx, Cplx, Cplx);
+}
+
+impl<F> AutoDiff for F
+where
+    F: GenericMap,
+{
+    #[inline]
+    fn map_ad(&self, z: Cplx, c: Cplx) -> Cplx
+    {
+        self.map_generic(z, c)
+    }
+
+    #[inline]
+    fn map_and_multiplier_ad(&self, z: Cplx, c: Cplx) -> (Cplx, Cplx)
+    {
+        let f = self.map_generic(Dual::variable(z), Dual::constant(c));
+        (f.value, f.deriv)
+    }
+
+    #[inline]
+    fn gradient_ad(&self, z: Cplx, c: Cplx) -> (Cplx, Cplx, Cplx)
+    {
+        let (f, df_dz) = self.map_and_multiplier_ad(z, c);
+        let df_dc = self
+            .map_generic(Dual::constant(z), Dual::variable(c))
+            .deriv;
+        (f, df_dz, df_dc)
+    }
+}
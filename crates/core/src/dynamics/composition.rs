@@ -0,0 +1,555 @@
+use super::{
+    DisplayDefaults, DynamicalFamily, EscapeEncoding, ExternalRays, HasJulia,
+    InfinityFirstReturnMap, MarkedPoints,
+};
+use dynamo_common::prelude::*;
+
+/// The Möbius transformation `z -> (az + b)/(cz + d)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mobius
+{
+    pub a: Cplx,
+    pub b: Cplx,
+    pub c: Cplx,
+    pub d: Cplx,
+}
+
+impl Default for Mobius
+{
+    fn default() -> Self
+    {
+        Self::IDENTITY
+    }
+}
+
+impl Mobius
+{
+    pub const IDENTITY: Self = Self {
+        a: ONE,
+        b: ZERO,
+        c: ZERO,
+        d: ONE,
+    };
+
+    /// The affine map `z -> scale * z + shift`.
+    #[must_use]
+    pub const fn affine(scale: Cplx, shift: Cplx) -> Self
+    {
+        Self {
+            a: scale,
+            b: shift,
+            c: ZERO,
+            d: ONE,
+        }
+    }
+
+    /// Whether the map fixes infinity.
+    #[must_use]
+    pub fn is_affine(&self) -> bool
+    {
+        self.c == ZERO
+    }
+
+    #[must_use]
+    pub fn determinant(&self) -> Cplx
+    {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// The inverse map, up to scaling of the coefficients.
+    #[must_use]
+    pub fn inverse(&self) -> Self
+    {
+        Self {
+            a: self.d,
+            b: -self.b,
+            c: -self.c,
+            d: self.a,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn apply(&self, z: Cplx) -> Cplx
+    {
+        (self.a * z + self.b) / (self.c * z + self.d)
+    }
+
+    /// The image of `z` together with the derivative of the map there.
+    #[inline]
+    #[must_use]
+    pub fn apply_d(&self, z: Cplx) -> (Cplx, Cplx)
+    {
+        let denom = self.c * z + self.d;
+        ((self.a * z + self.b) / denom, self.determinant() / (denom * denom))
+    }
+}
+
+/// A family built from two existing ones with a shared parameter: the composition `f_c∘g_c` of an
+/// outer family `F` with an inner family `G`, optionally conjugated by a Möbius map `h` to give
+/// `h∘f_c∘g_c∘h⁻¹`. A family can be conjugated on its own with [`ComposedFamily::conjugate`].
+///
+/// The parameter map, meta-parameters, iteration limit and compute mode are those of the outer
+/// family; the inner family keeps the meta-parameters it was created with. Orbits on the
+/// parameter plane start at the critical point of the inner family.
+///
+/// Infinity is fixed by the composite map if it is fixed by both families and by `h`, in which
+/// case the escape data are chained from those of the two families. A conjugacy that moves
+/// infinity turns the escaping region into the basin of the finite point `h(∞)`, which is then
+/// colored as an attracting cycle, and external rays are unavailable.
+#[derive(Clone)]
+pub struct ComposedFamily<F, G>
+where
+    F: DynamicalFamily,
+    G: DynamicalFamily,
+{
+    outer: F,
+    inner: Option<G>,
+    conjugacy: Option<Mobius>,
+    point_grid: PointGrid,
+    orig_bounds: Bounds,
+}
+
+impl<F, G> ComposedFamily<F, G>
+where
+    F: DynamicalFamily + DisplayDefaults,
+    G: DynamicalFamily,
+{
+    /// The composition `f∘g`, shown on the default bounds of `outer`.
+    #[must_use]
+    pub fn new(outer: F, inner: G) -> Self
+    {
+        let point_grid = outer.point_grid().clone();
+        let orig_bounds = outer.default_bounds();
+        Self {
+            outer,
+            inner: Some(inner),
+            conjugacy: None,
+            point_grid,
+            orig_bounds,
+        }
+    }
+
+    #[must_use]
+    pub fn with_orig_bounds(mut self, bounds: Bounds) -> Self
+    {
+        self.point_grid = self.point_grid.new_with_same_height(bounds.clone());
+        self.orig_bounds = bounds;
+        self
+    }
+
+    /// Conjugate the family by `conjugacy`, replacing each map `f` by `h∘f∘h⁻¹`.
+    #[must_use]
+    pub fn conjugated_by(mut self, conjugacy: Mobius) -> Self
+    {
+        self.conjugacy = Some(conjugacy);
+        self
+    }
+}
+
+impl<F> ComposedFamily<F, F>
+where
+    F: DynamicalFamily + DisplayDefaults,
+{
+    /// The conjugate `h∘f∘h⁻¹` of a single family by the Möbius map `h`.
+    #[must_use]
+    pub fn conjugate(family: F, conjugacy: Mobius) -> Self
+    {
+        let point_grid = family.point_grid().clone();
+        let orig_bounds = family.default_bounds();
+        Self {
+            outer: family,
+            inner: None,
+            conjugacy: Some(conjugacy),
+            point_grid,
+            orig_bounds,
+        }
+    }
+}
+
+impl<F, G> Default for ComposedFamily<F, G>
+where
+    F: DynamicalFamily + DisplayDefaults + Default,
+    G: DynamicalFamily + Default,
+{
+    fn default() -> Self
+    {
+        Self::new(F::default(), G::default())
+    }
+}
+
+impl<F, G> ComposedFamily<F, G>
+where
+    F: DynamicalFamily<Var = Cplx, Deriv = Cplx>,
+    G: DynamicalFamily<Var = Cplx, Param = F::Param, Deriv = Cplx>,
+{
+    /// The composite map `f∘g` before conjugation, with its derivatives in the variable and in
+    /// the parameter.
+    #[inline]
+    fn composite_gradient(&self, z: Cplx, c: &F::Param) -> (Cplx, Cplx, Cplx)
+    {
+        let Some(inner) = &self.inner else {
+            return self.outer.gradient(z, c);
+        };
+        let (u, du_dz, du_dc) = inner.gradient(z, c);
+        let (w, dw_du, dw_dc) = self.outer.gradient(u, c);
+        (w, dw_du * du_dz, dw_du * du_dc + dw_dc)
+    }
+
+    #[inline]
+    fn composite_map_and_multiplier(&self, z: Cplx, c: &F::Param) -> (Cplx, Cplx)
+    {
+        let Some(inner) = &self.inner else {
+            return self.outer.map_and_multiplier(z, c);
+        };
+        let (u, du_dz) = inner.map_and_multiplier(z, c);
+        let (w, dw_du) = self.outer.map_and_multiplier(u, c);
+        (w, dw_du * du_dz)
+    }
+
+    /// Whether infinity is a fixed point of the composite map.
+    fn fixes_infinity(&self) -> bool
+    where
+        F: InfinityFirstReturnMap,
+        G: InfinityFirstReturnMap,
+    {
+        self.conjugacy.as_ref().is_none_or(Mobius::is_affine)
+            && self.outer.escaping_period() == 1
+            && self
+                .inner
+                .as_ref()
+                .is_none_or(|inner| inner.escaping_period() == 1)
+    }
+}
+
+impl<F, G> DynamicalFamily for ComposedFamily<F, G>
+where
+    F: DynamicalFamily<Var = Cplx, Deriv = Cplx>,
+    G: DynamicalFamily<Var = Cplx, Param = F::Param, Deriv = Cplx>,
+{
+    type Var = Cplx;
+    type Param = F::Param;
+    type MetaParam = F::MetaParam;
+    type Deriv = Cplx;
+
+    fn point_grid(&self) -> &PointGrid
+    {
+        &self.point_grid
+    }
+
+    fn point_grid_mut(&mut self) -> &mut PointGrid
+    {
+        &mut self.point_grid
+    }
+
+    fn with_point_grid(mut self, point_grid: PointGrid) -> Self
+    {
+        self.point_grid = point_grid;
+        self
+    }
+
+    fn compute_mode(&self) -> super::ComputeMode
+    {
+        self.outer.compute_mode()
+    }
+
+    fn compute_mode_mut(&mut self) -> &mut super::ComputeMode
+    {
+        self.outer.compute_mode_mut()
+    }
+
+    fn set_compute_mode(&mut self, compute_mode: super::ComputeMode)
+    {
+        self.outer.set_compute_mode(compute_mode);
+    }
+
+    #[inline]
+    fn max_iter(&self) -> IterCount
+    {
+        self.outer.max_iter()
+    }
+
+    #[inline]
+    fn max_iter_mut(&mut self) -> &mut IterCount
+    {
+        self.outer.max_iter_mut()
+    }
+
+    #[inline]
+    fn set_max_iter(&mut self, new_max_iter: IterCount)
+    {
+        self.outer.set_max_iter(new_max_iter);
+    }
+
+    fn with_max_iter(mut self, max_iter: IterCount) -> Self
+    {
+        self.set_max_iter(max_iter);
+        self
+    }
+
+    #[inline]
+    fn param_map(&self, t: Cplx) -> F::Param
+    {
+        self.outer.param_map(t)
+    }
+
+    #[inline]
+    fn param_map_d(&self, t: Cplx) -> (F::Param, Cplx)
+    {
+        self.outer.param_map_d(t)
+    }
+
+    #[inline]
+    fn start_point(&self, t: Cplx, c: &F::Param) -> Cplx
+    {
+        self.start_point_d(t, c).0
+    }
+
+    fn start_point_d(&self, t: Cplx, c: &F::Param) -> (Cplx, Cplx, Cplx)
+    {
+        let (z, dz_dt, dz_dc) = self.inner.as_ref().map_or_else(
+            || self.outer.start_point_d(t, c),
+            |inner| inner.start_point_d(t, c),
+        );
+        let Some(h) = &self.conjugacy else {
+            return (z, dz_dt, dz_dc);
+        };
+        let (w, dw_dz) = h.apply_d(z);
+        (w, dw_dz * dz_dt, dw_dz * dz_dc)
+    }
+
+    #[inline]
+    fn map(&self, z: Cplx, c: &F::Param) -> Cplx
+    {
+        if self.conjugacy.is_some() {
+            return self.map_and_multiplier(z, c).0;
+        }
+        match &self.inner {
+            Some(inner) => self.outer.map(inner.map(z, c), c),
+            None => self.outer.map(z, c),
+        }
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Cplx, c: &F::Param) -> (Cplx, Cplx)
+    {
+        let Some(h) = &self.conjugacy else {
+            return self.composite_map_and_multiplier(z, c);
+        };
+        let (u, du_dz) = h.inverse().apply_d(z);
+        let (v, dv_du) = self.composite_map_and_multiplier(u, c);
+        let (w, dw_dv) = h.apply_d(v);
+        (w, dw_dv * dv_du * du_dz)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Cplx, c: &F::Param) -> (Cplx, Cplx, Cplx)
+    {
+        let Some(h) = &self.conjugacy else {
+            return self.composite_gradient(z, c);
+        };
+        let (u, du_dz) = h.inverse().apply_d(z);
+        let (v, dv_du, dv_dc) = self.composite_gradient(u, c);
+        let (w, dw_dv) = h.apply_d(v);
+        (w, dw_dv * dv_du * du_dz, dw_dv * dv_dc)
+    }
+
+    fn preimages(&self, z: Cplx, c: &F::Param) -> Vec<Cplx>
+    {
+        let z = self.conjugacy.map_or(z, |h| h.inverse().apply(z));
+        let mut preimages = self.outer.preimages(z, c);
+        if let Some(inner) = &self.inner {
+            preimages = preimages
+                .into_iter()
+                .flat_map(|u| inner.preimages(u, c))
+                .collect();
+        }
+        if let Some(h) = &self.conjugacy {
+            preimages.iter_mut().for_each(|u| *u = h.apply(*u));
+        }
+        preimages
+    }
+
+    #[inline]
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        self.outer.get_meta_params()
+    }
+
+    #[inline]
+    fn set_meta_param(&mut self, value: Self::MetaParam)
+    {
+        self.outer.set_meta_param(value);
+    }
+
+    #[inline]
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.outer.get_param()
+    }
+
+    #[inline]
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {
+        self.outer.set_param(value);
+    }
+
+    #[inline]
+    fn plane_type(&self) -> super::PlaneType
+    {
+        self.outer.plane_type()
+    }
+
+    #[inline]
+    fn periodicity_tolerance(&self) -> Real
+    {
+        self.outer.periodicity_tolerance()
+    }
+}
+
+impl<F, G> DisplayDefaults for ComposedFamily<F, G>
+where
+    F: DisplayDefaults<Var = Cplx, Deriv = Cplx>,
+    G: DisplayDefaults<Var = Cplx, Param = F::Param, Deriv = Cplx>,
+{
+    fn name(&self) -> String
+    {
+        let composite = match &self.inner {
+            Some(inner) => format!("{}∘{}", self.outer.name(), inner.name()),
+            None => self.outer.name(),
+        };
+        if self.conjugacy.is_some() {
+            format!("Conjugate of {composite}")
+        } else {
+            composite
+        }
+    }
+
+    fn description(&self) -> String
+    {
+        let mut description = match &self.inner {
+            Some(inner) => format!(
+                "The composition f∘g of the maps f of {} with the maps g of {}, sharing their \
+                    parameter.",
+                self.outer.name(),
+                inner.name()
+            ),
+            None => format!("The maps of {}.", self.outer.name()),
+        };
+        if let Some(Mobius { a, b, c, d }) = &self.conjugacy {
+            description.push_str(&format!(
+                " Conjugated by the Möbius map h(z) = ({a}z + {b})/({c}z + {d})."
+            ));
+        }
+        description
+    }
+
+    #[inline]
+    fn default_bounds(&self) -> Bounds
+    {
+        self.orig_bounds.clone()
+    }
+
+    #[inline]
+    fn default_selection(&self) -> Cplx
+    {
+        self.outer.default_selection()
+    }
+}
+
+impl<F, G> HasJulia for ComposedFamily<F, G>
+where
+    F: DisplayDefaults<Var = Cplx, Deriv = Cplx> + HasJulia,
+    G: DisplayDefaults<Var = Cplx, Param = F::Param, Deriv = Cplx>,
+{
+    #[inline]
+    fn default_bounds_child(&self, t: Cplx, c: &Self::Param) -> Bounds
+    {
+        self.outer.default_bounds_child(t, c)
+    }
+
+    #[inline]
+    fn default_max_iter_child(&self) -> IterCount
+    {
+        self.outer.default_max_iter_child()
+    }
+}
+
+impl<F, G> MarkedPoints for ComposedFamily<F, G>
+where
+    F: MarkedPoints<Var = Cplx, Deriv = Cplx>,
+    G: MarkedPoints<Var = Cplx, Param = F::Param, Deriv = Cplx>,
+{
+    /// The critical points of `g`, together with the preimages under `g` of those of `f`. Inner
+    /// families that cannot list their preimages only contribute their own critical points.
+    fn critical_points_child(&self, c: &F::Param) -> Vec<Cplx>
+    {
+        let mut points = self.outer.critical_points_child(c);
+        if let Some(inner) = &self.inner {
+            let mut inner_points = inner.critical_points_child(c);
+            inner_points.extend(points.into_iter().flat_map(|u| inner.preimages(u, c)));
+            points = inner_points;
+        }
+        if let Some(h) = &self.conjugacy {
+            points.iter_mut().for_each(|u| *u = h.apply(*u));
+        }
+        points
+    }
+}
+
+impl<F, G> InfinityFirstReturnMap for ComposedFamily<F, G>
+where
+    F: InfinityFirstReturnMap<Var = Cplx, Deriv = Cplx>,
+    G: InfinityFirstReturnMap<Var = Cplx, Param = F::Param, Deriv = Cplx>,
+{
+    fn degree_real(&self) -> Real
+    {
+        if !self.fixes_infinity() {
+            return Real::NAN;
+        }
+        let inner_degree = self.inner.as_ref().map_or(1., G::degree_real);
+        self.outer.degree_real() * inner_degree
+    }
+
+    #[inline]
+    fn escaping_phase(&self) -> Period
+    {
+        self.inner
+            .as_ref()
+            .map_or_else(|| self.outer.escaping_phase(), G::escaping_phase)
+    }
+
+    /// If `f` and `g` behave like `a z^d` and `b z^e` near infinity, then `f∘g` behaves like
+    /// `a b^d z^(de)`, and its conjugate by `z -> αz + β` like `a b^d α^(1-de) z^(de)`.
+    fn escape_coeff_d(&self, c: &Self::Param) -> (Cplx, Cplx)
+    {
+        let (mut coeff, mut coeff_d) = self.outer.escape_coeff_d(c);
+        if let Some(inner) = &self.inner {
+            let d = self.outer.degree_real();
+            let (b, db) = inner.escape_coeff_d(c);
+            let b_pow = b.powf(d - 1.);
+            coeff_d = (coeff_d * b + coeff * d * db) * b_pow;
+            coeff *= b_pow * b;
+        }
+        if let Some(h) = &self.conjugacy {
+            let scale = (h.a / h.d).powf(1. - self.degree_real());
+            coeff *= scale;
+            coeff_d *= scale;
+        }
+        (coeff, coeff_d)
+    }
+}
+
+impl<F, G> EscapeEncoding for ComposedFamily<F, G>
+where
+    F: EscapeEncoding<Var = Cplx, Deriv = Cplx>,
+    G: EscapeEncoding<Var = Cplx, Param = F::Param, Deriv = Cplx>,
+{
+}
+
+impl<F, G> ExternalRays for ComposedFamily<F, G>
+where
+    F: ExternalRays<Var = Cplx, Deriv = Cplx>,
+    G: ExternalRays<Var = Cplx, Param = F::Param, Deriv = Cplx>,
+{
+}
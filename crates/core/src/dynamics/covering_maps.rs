@@ -1,6 +1,6 @@
 use super::julia::JuliaSet;
 use super::{
-    DynamicalFamily, EscapeEncoding, ExternalRays, FamilyDefaults, HasChild, HasJulia,
+    DisplayDefaults, DynamicalFamily, EscapeEncoding, ExternalRays, HasChild, HasJulia,
     InfinityFirstReturnMap, MarkedPoints,
 };
 use crate::orbit::EscapeResult;
@@ -188,21 +188,21 @@ where
     }
 
     #[inline]
-    fn get_meta_params(&self) -> Self::MetaParam
+    fn preimages(&self, z: Self::Var, c: &Self::Param) -> Vec<Self::Var>
     {
-        self.base_curve.get_meta_params()
+        self.base_curve.preimages(z, c)
     }
 
     #[inline]
-    fn set_meta_param(&mut self, value: Self::MetaParam)
+    fn get_meta_params(&self) -> Self::MetaParam
     {
-        self.base_curve.set_meta_param(value);
+        self.base_curve.get_meta_params()
     }
 
     #[inline]
-    fn name(&self) -> String
+    fn set_meta_param(&mut self, value: Self::MetaParam)
     {
-        format!("Cover over {}", self.base_curve.name())
+        self.base_curve.set_meta_param(value);
     }
 
     #[inline]
@@ -211,15 +211,6 @@ where
         self.base_curve.plane_type()
     }
 
-    fn description(&self) -> String
-    {
-        format!(
-            "A dynamical cover over {}. Description of base curve: \n{}",
-            self.base_curve.name(),
-            self.base_curve.description()
-        )
-    }
-
     #[inline]
     fn periodicity_tolerance(&self) -> Real
     {
@@ -251,10 +242,25 @@ where
     }
 }
 
-impl<C> FamilyDefaults for CoveringMap<C>
+impl<C> DisplayDefaults for CoveringMap<C>
 where
-    C: FamilyDefaults,
+    C: DisplayDefaults,
 {
+    #[inline]
+    fn name(&self) -> String
+    {
+        format!("Cover over {}", self.base_curve.name())
+    }
+
+    fn description(&self) -> String
+    {
+        format!(
+            "A dynamical cover over {}. Description of base curve: \n{}",
+            self.base_curve.name(),
+            self.base_curve.description()
+        )
+    }
+
     #[inline]
     fn default_bounds(&self) -> Bounds
     {
@@ -266,9 +272,17 @@ where
     {
         self.base_curve.default_coloring()
     }
+
+    #[inline]
+    fn default_coloring_child(&self) -> Coloring
+    {
+        self.base_curve.default_coloring_child()
+    }
 }
 
-impl<C: HasJulia> HasJulia for CoveringMap<C>
+impl<C> HasJulia for CoveringMap<C>
+where
+    C: HasJulia,
 {
     #[inline]
     fn default_bounds_child(&self, t: Cplx, c: &Self::Param) -> Bounds
@@ -281,12 +295,6 @@ impl<C: HasJulia> HasJulia for CoveringMap<C>
     {
         self.base_curve.default_max_iter_child()
     }
-
-    #[inline]
-    fn default_coloring_child(&self) -> Coloring
-    {
-        self.base_curve.default_coloring_child()
-    }
 }
 
 impl<C> MarkedPoints for CoveringMap<C>
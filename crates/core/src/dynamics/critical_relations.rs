@@ -0,0 +1,110 @@
+//! Curves in parameter planes along which an iterate of the critical orbit keeps its distance,
+//! on a logarithmic scale, from a critical, co-critical or marked point of the map.
+//!
+//! The curves surround the parameters at which the orbit lands on the point, where critical
+//! orbits collide, such as the centers of hyperbolic components where the orbit returns to its own
+//! critical point. Those through escaping parameters show how the critical orbits organize the
+//! escape region, e.g. in slices of cubic parameter space.
+//!
+//! The co-critical points of a critical point `c` are the other preimages of its critical value
+//! `f(c)`. Like critical points, they move with the parameter, so a curve follows the point that
+//! was nearest to the iterate where it started.
+use std::cell::Cell;
+
+use dynamo_common::math_utils::contour::{Contour, LevelCurveParams};
+use dynamo_common::prelude::*;
+
+use super::{DynamicalFamily, MarkedPoints};
+
+const MAX_STEPS: usize = 5000;
+/// Preimages of a critical value this close to the critical point are the critical point itself.
+const COLLISION_TOLERANCE: Real = 1e-6;
+/// Step, relative to the width of a pixel, used to estimate how the targets move with the
+/// parameter.
+const DIFF_STEP: Real = 1e-3;
+
+/// Critical, co-critical and marked points of the map with parameter `c`. Co-critical points
+/// are only found for maps with known inverse branches.
+pub fn critical_targets<P>(plane: &P, c: &P::Param) -> Vec<Cplx>
+where
+    P: DynamicalFamily + MarkedPoints + ?Sized,
+{
+    let critical_points = plane.critical_points_child(c);
+    let mut targets: Vec<Cplx> = critical_points.iter().map(|&z| z.into()).collect();
+    for z in critical_points {
+        let z_cplx: Cplx = z.into();
+        targets.extend(
+            plane
+                .preimages(plane.map(z, c), c)
+                .into_iter()
+                .map(Into::into)
+                .filter(|w: &Cplx| (w - z_cplx).norm() > COLLISION_TOLERANCE),
+        );
+    }
+    targets.extend(
+        plane
+            .get_marked_points(c)
+            .into_iter()
+            .map(|(z, _)| z.into()),
+    );
+    targets.retain(|w| w.is_finite());
+    targets
+}
+
+/// The target of the map with parameter `c` nearest to `point`.
+fn nearest_target<P>(plane: &P, c: &P::Param, point: Cplx) -> Option<Cplx>
+where
+    P: DynamicalFamily + MarkedPoints + ?Sized,
+{
+    critical_targets(plane, c)
+        .into_iter()
+        .min_by(|w0, w1| (w0 - point).norm_sqr().total_cmp(&(w1 - point).norm_sqr()))
+}
+
+/// Curve through the parameter `t0` along which the `steps`th iterate of the critical point
+/// followed by the plane keeps its distance, on a logarithmic scale, from the target nearest to
+/// it at `t0`. Empty on dynamical planes.
+#[must_use]
+pub fn trace<P>(plane: &P, t0: Cplx, steps: Period) -> Vec<Cplx>
+where
+    P: DynamicalFamily + MarkedPoints + ?Sized,
+{
+    if plane.plane_type().is_dynamical() {
+        return Vec::new();
+    }
+    let pixel_width = plane.point_grid().pixel_width();
+    let diff_step = pixel_width * DIFF_STEP;
+    let target = Cell::new(None);
+
+    let relation_d = |t: Cplx| {
+        let (c, dc_dt) = plane.param_map_d(t);
+        let (mut z, mut dz_dt, dz_dc) = plane.start_point_d(t, &c);
+        dz_dt += dz_dc * dc_dt;
+        for _ in 0..steps {
+            let (f, df_dz, df_dc) = plane.gradient(z, &c);
+            dz_dt = dz_dt * df_dz + df_dc * dc_dt;
+            z = f;
+        }
+        let (z, dz_dt): (Cplx, Cplx) = (z.into(), dz_dt.into());
+
+        let w = nearest_target(plane, &c, target.get().unwrap_or(z))?;
+        target.set(Some(w));
+        let (c_shifted, _) = plane.param_map_d(t + diff_step);
+        let dw_dt = (nearest_target(plane, &c_shifted, w)? - w) / diff_step;
+
+        let mu = z - w;
+        let dmu = dz_dt - dw_dt;
+        if mu.norm_sqr() == 0. || !dmu.is_finite() {
+            return None;
+        }
+        Some((mu.norm().ln(), (dmu / mu).conj()))
+    };
+
+    LevelCurveParams::default()
+        .step_size(1e-2)
+        .return_radius(pixel_width.powi(2) * 100.0)
+        .max_steps(MAX_STEPS)
+        .contour(relation_d)
+        .init_seed(t0)
+        .compute()
+}
@@ -0,0 +1,73 @@
+//! Approximation of the measure of maximal entropy of a rational map, which is the equilibrium
+//! measure of its Julia set for polynomials, by random backward iteration.
+//!
+//! The preimages of almost any point equidistribute with respect to this measure, and a backward
+//! orbit that chooses among the preimages uniformly at random samples it.
+use dynamo_common::prelude::*;
+use ndarray::Array2;
+
+use super::DynamicalFamily;
+
+/// A generic point from which backward orbits start; it must not be exceptional for the map.
+pub(super) const START: Cplx = Cplx::new(0.217_531, 0.513_782);
+/// Number of initial steps of the backward orbit left out, while it approaches the Julia set.
+const BURN_IN: usize = 64;
+
+/// Minimal xorshift generator, to choose preimages reproducibly and without further dependencies.
+struct XorShift(u64);
+
+impl XorShift
+{
+    fn next(&mut self) -> u64
+    {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn choose<T: Clone>(&mut self, items: &[T]) -> T
+    {
+        items[(self.next() % items.len() as u64) as usize].clone()
+    }
+}
+
+/// Number of points of a random backward orbit of `num_points` steps landing in each pixel of the
+/// plane, indexed by column and row as in the image. `None` if the plane cannot invert its map.
+#[must_use]
+pub fn density<P>(plane: &P, num_points: usize) -> Option<Array2<u32>>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let point_grid = plane.point_grid();
+    let param = plane.param_map(point_grid.center());
+    let start = P::Var::from(START);
+    if plane.preimages(start, &param).is_empty() {
+        return None;
+    }
+
+    let mut counts = Array2::zeros(point_grid.shape());
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+    let mut z = start;
+    for step in 0..BURN_IN + num_points {
+        let preimages = plane.preimages(z, &param);
+        if preimages.is_empty() {
+            return None;
+        }
+        z = rng.choose(&preimages);
+        if z.is_nan() {
+            z = start;
+            continue;
+        }
+        if step < BURN_IN {
+            continue;
+        }
+        if let Some(pixel) = point_grid.locate_point_safe(z.into()) {
+            counts[pixel] += 1;
+        }
+    }
+    Some(counts)
+}
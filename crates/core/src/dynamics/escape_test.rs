@@ -0,0 +1,79 @@
+use dynamo_common::prelude::*;
+
+use crate::orbit::EscapeResult;
+
+/// Outcome of an [`EscapeTest`] at one step of an orbit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscapeVerdict
+{
+    Running,
+    Escaped,
+    /// The orbit has left the region where it can be computed accurately, without escaping.
+    Unknown,
+}
+
+/// Criterion by which an orbit is found to escape, checked at each iteration.
+///
+/// Most families escape through a neighborhood of infinity and use the norm of the variable, but
+/// e.g. trigonometric maps escape through the ends of vertical strips.
+#[derive(Clone, Copy, Debug)]
+pub enum EscapeTest
+{
+    /// The norm-squared of the variable exceeds the bound, or the variable is NaN.
+    NormSqr(Real),
+    /// The imaginary part exceeds `bound` in absolute value. Once the real part exceeds
+    /// `overflow` in absolute value, the outcome is unknown.
+    ImagPart
+    {
+        bound: Real,
+        overflow: Real,
+    },
+    /// Orbits never escape, e.g. for maps of finite rings.
+    Never,
+    /// A predicate on the variable, for families whose escape regions fit none of the above.
+    Custom(fn(Cplx) -> EscapeVerdict),
+}
+
+impl EscapeTest
+{
+    #[must_use]
+    pub fn verdict<V: Variable>(&self, z: V) -> EscapeVerdict
+    {
+        match self {
+            Self::NormSqr(bound) => {
+                if z.norm_sqr() > *bound || z.is_nan() {
+                    EscapeVerdict::Escaped
+                } else {
+                    EscapeVerdict::Running
+                }
+            }
+            Self::ImagPart { bound, overflow } => {
+                let z: Cplx = z.into();
+                if z.im.abs() > *bound {
+                    EscapeVerdict::Escaped
+                } else if z.re.abs() > *overflow {
+                    EscapeVerdict::Unknown
+                } else {
+                    EscapeVerdict::Running
+                }
+            }
+            Self::Never => EscapeVerdict::Running,
+            Self::Custom(predicate) => predicate(z.into()),
+        }
+    }
+
+    /// The result of an orbit at `z` after `iter` iterations, if it stops there.
+    #[inline]
+    #[must_use]
+    pub fn check<V: Variable, D>(&self, z: V, iter: IterCount) -> Option<EscapeResult<V, D>>
+    {
+        match self.verdict(z) {
+            EscapeVerdict::Running => None,
+            EscapeVerdict::Escaped => Some(EscapeResult::Escaped {
+                iters: iter,
+                final_value: z,
+            }),
+            EscapeVerdict::Unknown => Some(EscapeResult::Unknown),
+        }
+    }
+}
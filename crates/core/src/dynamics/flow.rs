@@ -0,0 +1,310 @@
+use super::InfinityFirstReturnMap;
+use dynamo_common::prelude::*;
+use num_traits::One;
+
+/// Norm-squared beyond which the Böttcher coordinate at infinity is taken to be linear.
+const FLOW_ESCAPE_RADIUS: Real = 1e16;
+
+/// Distance-squared from an attracting fixed point within which its Kœnigs coordinate is taken
+/// to be linear.
+const KOENIGS_RADIUS: Real = 1e-16;
+
+/// Distance from a superattracting fixed point at which its local degree is estimated.
+const LOCAL_BOTTCHER_RADIUS: Real = 1e-3;
+
+/// Multipliers below this norm are treated as superattracting.
+const SUPERATTRACTING: Real = 1e-12;
+
+/// Steps into which the flow is divided when following it from the point by continuation.
+const FLOW_STEPS: usize = 16;
+
+const NEWTON_STEPS: usize = 12;
+
+/// Local model of the dynamics near the attractor reached by an orbit, in which the map is a
+/// power map or a linear map, and so embeds in a flow.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FlowChart
+{
+    /// Near infinity, where `f(w) ≈ a w^d` is conjugated to `w -> w^d` by the Böttcher
+    /// coordinate `w -> αw`, with `α^(d-1) = a`. The winding picks out the branch of the log of
+    /// the coordinate that pulls back to the branch matching the argument of the start point.
+    Infinity
+    {
+        scale: Cplx,
+        degree: Real,
+        winding: Real,
+    },
+    /// Near an attracting fixed point `z*` with multiplier `0 < |λ| < 1`, on which the Kœnigs
+    /// coordinate is `w -> w - z*`.
+    Attracting
+    {
+        fixed_point: Cplx,
+        multiplier: Cplx,
+    },
+    /// Near a superattracting fixed point, where `f(w) ≈ z* + a (w - z*)^k` is conjugated to
+    /// `w -> w^k` by the local Böttcher coordinate `w -> α(w - z*)`, with `α^(k-1) = a`.
+    Superattracting
+    {
+        fixed_point: Cplx,
+        scale: Cplx,
+        degree: Real,
+        winding: Real,
+    },
+}
+
+impl FlowChart
+{
+    /// Time-`t` flow of the model map from `w`, with the branch of the flow that is continuous
+    /// in `t` from `w` at `t = 0`.
+    #[must_use]
+    pub fn flow(&self, w: Cplx, t: Real) -> Cplx
+    {
+        match *self {
+            Self::Infinity {
+                scale,
+                degree,
+                winding,
+            } => (degree.powf(t) * winding_log(scale * w, winding)).exp() / scale,
+            Self::Attracting {
+                fixed_point,
+                multiplier,
+            } => fixed_point + (t * multiplier.ln()).exp() * (w - fixed_point),
+            Self::Superattracting {
+                fixed_point,
+                scale,
+                degree,
+                winding,
+            } => {
+                let log = winding_log(scale * (w - fixed_point), winding);
+                fixed_point + (degree.powf(t) * log).exp() / scale
+            }
+        }
+    }
+}
+
+fn winding_log(w: Cplx, winding: Real) -> Cplx
+{
+    w.ln() + Cplx::new(0., TAU * winding)
+}
+
+/// Number of turns to add to the principal log of the coordinate `scale * (w_n - center)` of the
+/// last point of an orbit `w_0, ..., w_n`, so that its `d^n`-th root is the branch found by
+/// taking `d`-th roots back along the orbit, each time the one closest in argument to the
+/// coordinate of the point itself. This is exact where the coordinate is the identity, and
+/// consistent elsewhere.
+fn winding(orbit: &[Cplx], center: Cplx, scale: Cplx, degree: Real) -> Real
+{
+    let Some((&last, rest)) = orbit.split_last() else {
+        return 0.;
+    };
+    let d = degree.round();
+    if (degree - d).abs() > 1e-9 || d < 2. {
+        return 0.;
+    }
+
+    let end = (scale * (last - center)).ln();
+    let mut log = end;
+    for &w in rest.iter().rev() {
+        let target = (scale * (w - center)).arg();
+        let offset = |u: &Cplx| (u.im - target + PI).rem_euclid(TAU) - PI;
+        let root = (0..d as usize)
+            .map(|m| (log + Cplx::new(0., TAU * m as Real)) / d)
+            .min_by(|a, b| offset(a).abs().total_cmp(&offset(b).abs()))
+            .unwrap_or(log / d);
+        // Shifting by whole turns keeps the same set of roots at the next step
+        log = Cplx::new(root.re, target + offset(&root));
+    }
+    let turns = (d.powi(rest.len() as i32) * log.im - end.im) / TAU;
+    turns.round()
+}
+
+/// Fractional iterates `f^t` of the maps of a family, on the basins of infinity and of attracting
+/// fixed points, where `f` embeds in the flow conjugated to the flow of the power map by the
+/// Böttcher coordinate, or to the flow of a linear map by the Kœnigs coordinate.
+///
+/// To find `f^t(z)`, the orbit of `z` is followed until `w = f^n(z)` is close enough to the
+/// attractor for the coordinate to be given by its leading term, and `f^t(z)` is the solution of
+/// `f^n(u) = w_t`, where `w_t` is the time-`t` flow from `w` in that coordinate. The solution is
+/// followed from `u = z` at `t = 0` by Newton's method, which picks out the branch of the flow.
+///
+/// Points in the basins of cycles of higher period have no flow, since `f` permutes the
+/// components of these basins, and neither do points of the Julia set.
+///
+/// Only meaningful for families of maps of a single complex variable.
+pub trait ContinuousIteration: InfinityFirstReturnMap
+{
+    /// Iterate `z` until it is close to its attractor, returning the number of iterations, the
+    /// point reached, and the chart in which the map embeds in a flow near the attractor.
+    fn flow_chart(&self, z: Self::Var, c: &Self::Param) -> Option<(usize, Cplx, FlowChart)>
+    {
+        let mut w: Cplx = z.into();
+        let mut fixed_point: Option<Cplx> = None;
+        let mut orbit = Vec::new();
+
+        for n in 0..self.max_iter() as usize {
+            orbit.push(w);
+            if w.norm_sqr() > FLOW_ESCAPE_RADIUS {
+                let degree = self.degree_real();
+                if self.escaping_period() != 1 || degree.is_nan() || degree <= 1. {
+                    return None;
+                }
+                let scale = self.escape_coeff(c).powf(1. / (degree - 1.));
+                let chart = FlowChart::Infinity {
+                    scale,
+                    degree,
+                    winding: winding(&orbit, ZERO, scale, degree),
+                };
+                return Some((n, w, chart));
+            }
+
+            let next: Cplx = self.map(w.into(), c).into();
+            if let Some(z_star) = fixed_point {
+                let (_, multiplier) = self.map_and_multiplier(z_star.into(), c);
+                let multiplier: Cplx = multiplier.into();
+                if multiplier.norm_sqr() < SUPERATTRACTING {
+                    let e = w - z_star;
+                    if e.norm() < LOCAL_BOTTCHER_RADIUS {
+                        let mut chart =
+                            self.superattracting_chart(z_star, e, next - z_star, c)?;
+                        if let FlowChart::Superattracting {
+                            scale,
+                            degree,
+                            winding: turns,
+                            ..
+                        } = &mut chart
+                        {
+                            *turns = winding(&orbit, z_star, *scale, *degree);
+                        }
+                        return Some((n, w, chart));
+                    }
+                } else if (w - z_star).norm_sqr() < KOENIGS_RADIUS {
+                    let chart = FlowChart::Attracting {
+                        fixed_point: z_star,
+                        multiplier,
+                    };
+                    return Some((n, w, chart));
+                }
+            } else if (next - w).norm_sqr() < self.periodicity_tolerance().sqrt() {
+                // Orbits may linger near repelling fixed points, which are then ignored
+                fixed_point = self.refine_fixed_point(next, c);
+            }
+            w = next;
+        }
+        None
+    }
+
+    /// Refine an approximate attracting fixed point by Newton's method.
+    fn refine_fixed_point(&self, mut z: Cplx, c: &Self::Param) -> Option<Cplx>
+    {
+        for _ in 0..NEWTON_STEPS {
+            let (w, dw) = self.map_and_multiplier(z.into(), c);
+            let (w, dw): (Cplx, Cplx) = (w.into(), dw.into());
+            let step = (w - z) / (dw - 1.);
+            z -= step;
+            if step.norm_sqr() < 1e-28 {
+                break;
+            }
+        }
+        let multiplier: Cplx = self.map_and_multiplier(z.into(), c).1.into();
+        (z.is_finite() && multiplier.norm_sqr() < 1.).then_some(z)
+    }
+
+    /// The local degree `k` and leading coefficient `a` of `f(w) ≈ z* + a (w - z*)^k`, read off
+    /// from the displacements `e0` and `e1 = f(e0)` from the fixed point and the next one.
+    fn superattracting_chart(
+        &self,
+        fixed_point: Cplx,
+        e0: Cplx,
+        e1: Cplx,
+        c: &Self::Param,
+    ) -> Option<FlowChart>
+    {
+        let e2: Cplx = self.map((fixed_point + e1).into(), c).into() - fixed_point;
+        let degree = ((e2.norm().ln() - e1.norm().ln()) / (e1.norm().ln() - e0.norm().ln()))
+            .round()
+            .max(2.);
+        if !degree.is_finite() || e1 == ZERO {
+            return None;
+        }
+        let coeff = e1 / e0.powf(degree);
+        let scale = coeff.powf(1. / (degree - 1.));
+        Some(FlowChart::Superattracting {
+            fixed_point,
+            scale,
+            degree,
+            winding: 0.,
+        })
+    }
+
+    /// Solve `f^n(u) = target` by Newton's method, starting from `u`. Long orbits contracting
+    /// onto an attractor leave a floor of rounding error in the steps, at which the iteration
+    /// stops once they no longer shrink.
+    fn pull_back(&self, mut u: Cplx, target: Cplx, n: usize, c: &Self::Param) -> Option<Cplx>
+    {
+        let mut last_step = Real::INFINITY;
+        for _ in 0..NEWTON_STEPS {
+            let mut w = u;
+            let mut dw = Cplx::one();
+            for _ in 0..n {
+                let (next, df) = self.map_and_multiplier(w.into(), c);
+                w = next.into();
+                dw *= df.into();
+            }
+            let step = (w - target) / dw;
+            u -= step;
+            if !u.is_finite() {
+                return None;
+            }
+            let size = step.norm_sqr() / u.norm_sqr().max(1.);
+            if size <= 1e-24 || (size >= last_step && size <= 1e-14) {
+                return Some(u);
+            }
+            last_step = size;
+        }
+        None
+    }
+
+    /// The fractional iterate `f^t(z)` for `t >= 0`, or `None` if `z` lies in no basin in which
+    /// the map embeds in a flow.
+    fn fractional_iterate(&self, z: Self::Var, c: &Self::Param, t: Real) -> Option<Self::Var>
+    {
+        if t.is_nan() || t < 0. {
+            return None;
+        }
+        let whole = t.floor();
+        let frac = t - whole;
+
+        let mut u: Cplx = z.into();
+        if frac > 0. {
+            let (n, w, chart) = self.flow_chart(z, c)?;
+            for step in 1..=FLOW_STEPS {
+                let s = frac * step as Real / FLOW_STEPS as Real;
+                u = self.pull_back(u, chart.flow(w, s), n, c)?;
+            }
+        }
+        let mut u = Self::Var::from(u);
+        for _ in 0..whole as usize {
+            u = self.map(u, c);
+        }
+        Some(u)
+    }
+
+    /// Samples of the path `t -> f^t(z)` for `t` in `[0, 1]`, from `z` to `f(z)`.
+    fn flow_path(&self, z: Self::Var, c: &Self::Param, num_points: usize) -> Option<Vec<Cplx>>
+    {
+        let (n, w, chart) = self.flow_chart(z, c)?;
+        let mut u: Cplx = z.into();
+        let mut path = vec![u];
+        let substeps = FLOW_STEPS.div_ceil(num_points.max(1)).max(1);
+        for i in 1..=num_points {
+            for j in 1..=substeps {
+                let s = ((i - 1) * substeps + j) as Real / (num_points * substeps) as Real;
+                u = self.pull_back(u, chart.flow(w, s), n, c)?;
+            }
+            path.push(u);
+        }
+        Some(path)
+    }
+}
+
+impl<P: InfinityFirstReturnMap + ?Sized> ContinuousIteration for P {}
@@ -0,0 +1,164 @@
+//! Drawing of Julia sets by the modified inverse iteration method (MIIM).
+//!
+//! The preimages of a point on the Julia set stay on it, so a tree of preimages outlines the
+//! Julia set without iterating every pixel to escape. Branches through pixels that have already
+//! been hit often are pruned, so the sparse parts of the Julia set are reached too. This works
+//! even where escape-time rendering struggles, e.g. for parabolic parameters.
+use dynamo_common::prelude::*;
+use ndarray::Array2;
+
+use super::equilibrium::START;
+use super::DynamicalFamily;
+
+/// Number of steps along the first inverse branch before the search, to reach the Julia set.
+const BURN_IN: usize = 64;
+/// Number of times a pixel is visited before the preimages through it are no longer followed.
+const MAX_HITS: u32 = 4;
+/// Number of consecutive preimages outside the view that are followed, in case they come back.
+const MAX_OFFSCREEN: u8 = 6;
+/// Bound on the number of points visited, relative to the number of pixels.
+const STEPS_PER_PIXEL: usize = 16;
+/// Bound on the number of points in each level of a tree of preimages.
+const MAX_TREE_WIDTH: usize = 4096;
+
+/// Families with known inverse branches. Enable inverse iteration, and the other features that
+/// need preimages, with [`inverse_map_impl`] in the `DynamicalFamily` implementation.
+///
+/// [`inverse_map_impl`]: crate::macros::inverse_map_impl
+pub trait InverseMap: DynamicalFamily
+{
+    /// All preimages of `z` under the map, listed with multiplicity.
+    fn inverse_branches(&self, z: Self::Var, c: &Self::Param) -> Vec<Self::Var>;
+}
+
+/// Number of visits to each pixel of the plane by the tree of preimages, indexed as the
+/// iteration counts of the plane. `None` if the plane cannot invert its map.
+#[must_use]
+pub fn hit_counts<P>(plane: &P) -> Option<Array2<u32>>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let point_grid = plane.point_grid();
+    let param = plane.param_map(point_grid.center());
+    let mut z = P::Var::from(START);
+    for _ in 0..BURN_IN {
+        z = *plane.preimages(z, &param).first()?;
+    }
+    if z.is_nan() {
+        return None;
+    }
+
+    let (res_x, res_y) = point_grid.shape();
+    let mut hits = Array2::zeros((res_x, res_y));
+    let mut stack = vec![(z, 0_u8)];
+    let mut budget = STEPS_PER_PIXEL * res_x * res_y;
+    while let Some((z, offscreen)) = stack.pop() {
+        if budget == 0 {
+            break;
+        }
+        budget -= 1;
+
+        let offscreen = match point_grid.locate_point_safe(z.into()) {
+            Some((x, row)) => {
+                let count = &mut hits[(x, res_y - 1 - row)];
+                if *count >= MAX_HITS {
+                    continue;
+                }
+                *count += 1;
+                0
+            }
+            None if offscreen < MAX_OFFSCREEN => offscreen + 1,
+            None => continue,
+        };
+        stack.extend(
+            plane
+                .preimages(z, &param)
+                .into_iter()
+                .filter(|w| !w.is_nan())
+                .map(|w| (w, offscreen)),
+        );
+    }
+    Some(hits)
+}
+
+/// Fill the iteration counts of the plane with the pixels hit by inverse iteration, which are
+/// marked as bounded, and leave the rest unknown. Returns false if the plane cannot invert its
+/// map, in which case the iteration counts are unchanged.
+pub fn fill<P>(plane: &P, iter_plane: &mut IterPlane<P::Deriv>) -> bool
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let Some(hits) = hit_counts(plane) else {
+        return false;
+    };
+    iter_plane
+        .iter_counts
+        .zip_mut_with(&hits, |point_info, &count| {
+            *point_info = if count > 0 {
+                PointInfo::Bounded
+            } else {
+                PointInfo::Unknown
+            };
+        });
+    true
+}
+
+/// A point of a tree of preimages, with the index of its image in the level before.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PreimageNode
+{
+    pub point: Cplx,
+    pub image: usize,
+}
+
+/// Levels of the tree of preimages of `start`, the `k`th holding its preimages of order `k`, for
+/// `k` up to `depth`. A preimage within `min_separation` of another one of the same order is
+/// pruned, together with the branch above it, and so are those beyond a bound on the size of a
+/// level. `None` if the plane cannot invert its map.
+#[must_use]
+pub fn preimage_tree<P>(
+    plane: &P,
+    start: Cplx,
+    depth: usize,
+    min_separation: Real,
+) -> Option<Vec<Vec<PreimageNode>>>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let param = plane.param_map(plane.point_grid().center());
+    if plane.preimages(P::Var::from(START), &param).is_empty() {
+        return None;
+    }
+
+    let mut levels = vec![vec![PreimageNode {
+        point: start,
+        image: 0,
+    }]];
+    for _ in 0..depth {
+        let Some(last) = levels.last() else {
+            break;
+        };
+        let mut level: Vec<PreimageNode> = Vec::new();
+        'images: for (image, node) in last.iter().enumerate() {
+            for w in plane.preimages(P::Var::from(node.point), &param) {
+                if level.len() == MAX_TREE_WIDTH {
+                    break 'images;
+                }
+                let point: Cplx = w.into();
+                if point.is_nan()
+                    || level
+                        .iter()
+                        .any(|other| (other.point - point).norm() < min_separation)
+                {
+                    continue;
+                }
+                level.push(PreimageNode { point, image });
+            }
+        }
+        if level.is_empty() {
+            break;
+        }
+        levels.push(level);
+    }
+    Some(levels)
+}
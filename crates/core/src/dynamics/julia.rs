@@ -1,4 +1,5 @@
-use super::{ComputeMode, DynamicalFamily, FamilyDefaults, HasJulia, MarkedPoints};
+use super::escape_test::EscapeTest;
+use super::{ComputeMode, DisplayDefaults, DynamicalFamily, HasJulia, MarkedPoints};
 use crate::macros::basic_plane_impl;
 use crate::orbit::EscapeResult;
 use dynamo_color::{Coloring, IncoloringAlgorithm};
@@ -8,6 +9,7 @@ use dynamo_common::prelude::*;
 use dynamo_common::symbolic_dynamics::OrbitSchema;
 use num_traits::{One, Zero};
 
+use super::simd::BatchResult;
 use super::{EscapeEncoding, ExternalRays, InfinityFirstReturnMap, PlaneType};
 
 #[derive(Clone)]
@@ -65,7 +67,7 @@ where
 
 impl<T> From<T> for JuliaSet<T>
 where
-    T: FamilyDefaults + HasJulia,
+    T: DisplayDefaults + HasJulia,
 {
     fn from(parent: T) -> Self
     {
@@ -104,12 +106,50 @@ where
         (f, df_dz, Self::Deriv::zero())
     }
 
+    #[inline]
+    fn preimages(&self, z: Self::Var, _c: &Self::Param) -> Vec<Self::Var>
+    {
+        self.parent.preimages(z, &self.local_param)
+    }
+
+    fn lyapunov_info(&self, start: Self::Var, _c: &Self::Param) -> Option<PointInfo<Self::Deriv>>
+    {
+        self.parent.lyapunov_info(start, &self.local_param)
+    }
+
+    fn log_lift(&self, w: Cplx, _c: &Self::Param) -> Option<Cplx>
+    {
+        self.parent.log_lift(w, &self.local_param)
+    }
+
+    fn attractor_seed(&self, _c: &Self::Param) -> Option<Self::Var>
+    {
+        self.parent.attractor_seed(&self.local_param)
+    }
+
     #[inline]
     fn escape_radius(&self) -> Real
     {
         self.parent.escape_radius()
     }
 
+    #[inline]
+    fn escape_test(&self) -> EscapeTest
+    {
+        self.parent.escape_test()
+    }
+
+    fn escape_batch(&self, points: &[Cplx]) -> Option<BatchResult<Self::Var, Self::Deriv>>
+    {
+        let starts: Vec<T::Var> = points
+            .iter()
+            .map(|&point| self.start_point(point, &NoParam))
+            .collect();
+        let params = vec![self.local_param.clone(); points.len()];
+        self.parent
+            .iterate_batch(&starts, &params, self.min_iter, self.max_iter)
+    }
+
     #[inline]
     fn min_iter(&self) -> IterCount
     {
@@ -171,6 +211,7 @@ where
         }: Self::MetaParam,
     )
     {
+        self.parent.set_meta_param(meta_params.clone());
         self.meta_params = meta_params;
         self.local_param = local_param;
     }
@@ -202,40 +243,12 @@ where
     //     None
     // }
 
-    #[inline]
-    fn name(&self) -> String
-    {
-        let parent_name = self.parent.name();
-
-        format!("Julia({parent_name})")
-    }
-
-    fn description(&self) -> String
-    {
-        self.parent.description()
-    }
-
     #[inline]
     fn periodicity_tolerance(&self) -> Real
     {
         self.parent.periodicity_tolerance()
     }
 
-    fn internal_potential_coloring(&self) -> IncoloringAlgorithm
-    {
-        self.parent.internal_potential_coloring()
-    }
-
-    fn potential_and_period_coloring(&self) -> IncoloringAlgorithm
-    {
-        self.parent.potential_and_period_coloring()
-    }
-
-    fn preperiod_coloring(&self) -> IncoloringAlgorithm
-    {
-        self.parent.preperiod_coloring()
-    }
-
     #[inline]
     fn plane_type(&self) -> PlaneType
     {
@@ -401,10 +414,43 @@ where
     // }
 }
 
-impl<T> FamilyDefaults for JuliaSet<T>
+impl<T> DisplayDefaults for JuliaSet<T>
 where
-    T: HasJulia,
+    T: DisplayDefaults + HasJulia,
 {
+    #[inline]
+    fn name(&self) -> String
+    {
+        let parent_name = self.parent.name();
+
+        format!("Julia({parent_name})")
+    }
+
+    fn description(&self) -> String
+    {
+        self.parent.description()
+    }
+
+    fn trace_labels(&self) -> [&'static str; 2]
+    {
+        self.parent.trace_labels()
+    }
+
+    fn internal_potential_coloring(&self) -> IncoloringAlgorithm
+    {
+        self.parent.internal_potential_coloring()
+    }
+
+    fn potential_and_period_coloring(&self) -> IncoloringAlgorithm
+    {
+        self.parent.potential_and_period_coloring()
+    }
+
+    fn preperiod_coloring(&self) -> IncoloringAlgorithm
+    {
+        self.parent.preperiod_coloring()
+    }
+
     #[inline]
     fn default_selection(&self) -> Cplx
     {
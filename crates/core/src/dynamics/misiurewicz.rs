@@ -0,0 +1,76 @@
+use dynamo_common::prelude::*;
+use std::f64::consts::TAU;
+use std::fmt::Display;
+
+/// The asymptotic self-similarity of a parameter plane about a Misiurewicz parameter.
+///
+/// At such a parameter, the critical orbit lands on a repelling cycle with multiplier λ. Near
+/// the parameter, the bifurcation locus looks like the Julia set near the landing point, which
+/// the first return map of the cycle carries onto itself. Zooming in by a factor of |λ| and
+/// rotating by arg λ about the parameter therefore maps the picture nearly onto itself.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MisiurewiczSpiral
+{
+    pub center: Cplx,
+    pub orbit_schema: OrbitSchema,
+    pub multiplier: Cplx,
+}
+
+impl MisiurewiczSpiral
+{
+    /// Factor by which the picture must be magnified to repeat.
+    #[must_use]
+    pub fn scale(&self) -> Real
+    {
+        self.multiplier.norm()
+    }
+
+    /// Rotation accompanying the magnification, in turns.
+    #[must_use]
+    pub fn rotation(&self) -> Real
+    {
+        self.multiplier.arg() / TAU
+    }
+
+    /// The point corresponding to `point` after zooming in `n` times.
+    #[must_use]
+    pub fn image(&self, point: Cplx, n: i32) -> Cplx
+    {
+        self.center + (point - self.center) * self.multiplier.powi(-n)
+    }
+
+    /// The logarithmic spiral from `point` into the center that is carried onto itself by the
+    /// self-similarity, sampled `samples` times between successive images of `point`.
+    #[must_use]
+    pub fn spiral(&self, point: Cplx, num_images: usize, samples: usize) -> Vec<Cplx>
+    {
+        let log_mult = self.multiplier.ln();
+        let offset = point - self.center;
+        (0..=num_images * samples)
+            .map(|i| {
+                let s = i as Real / samples as Real;
+                self.center + offset * (-s * log_mult).exp()
+            })
+            .collect()
+    }
+}
+
+impl Display for MisiurewiczSpiral
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(
+            f,
+            "Misiurewicz parameter: {center:.DISPLAY_PREC$}\n\
+                Preperiod: {preperiod}, period: {period}\n\
+                Multiplier: {multiplier:.DISPLAY_PREC$}\n\
+                Self-similar under zoom by {scale:.4} and rotation by {degrees:.2}°",
+            center = self.center,
+            preperiod = self.orbit_schema.preperiod,
+            period = self.orbit_schema.period,
+            multiplier = self.multiplier,
+            scale = self.scale(),
+            degrees = 360. * self.rotation(),
+        )
+    }
+}
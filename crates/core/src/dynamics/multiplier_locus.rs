@@ -0,0 +1,256 @@
+use dynamo_common::math_utils::arithmetic::divisors;
+use dynamo_common::math_utils::newton::error::Error as NewtonError;
+use dynamo_common::prelude::*;
+use std::f64::consts::TAU;
+use std::fmt::Display;
+
+use super::DynamicalFamily;
+use crate::error::{FindPointError, FindPointResult};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Iterations of the critical orbit used to approach the attracting cycle at the start point.
+const SEED_ITERS: usize = 2000;
+/// Steps taken to move the multiplier from its value at the start point to the bifurcation.
+const APPROACH_STEPS: usize = 64;
+/// Points sampled along the locus, as the multiplier runs once around the unit circle.
+const LOCUS_STEPS: usize = 1024;
+/// Samples that may be skipped in a row along the locus. The equations are singular where the
+/// cycle collides with a cycle of lower period, as at the roots of satellite components.
+const MAX_SKIPPED: usize = 4;
+const NEWTON_ITERS: usize = 24;
+const NEWTON_TOLERANCE: Real = 1e-10;
+/// Relative step used to estimate the partial derivatives of the cycle equations.
+const DIFF_STEP: Real = 1e-7;
+/// Solutions returning this close to themselves before `period` iterations are rejected as
+/// points of lower period.
+const LOWER_PERIOD_TOLERANCE: Real = 1e-6;
+
+/// Point on the boundary of a hyperbolic component from which its multiplier locus is traced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Bifurcation
+{
+    /// The root of the component, where the cycle has multiplier 1.
+    Root,
+    /// The point where the cycle doubles its period, with multiplier -1.
+    PeriodDoubling,
+}
+
+impl Bifurcation
+{
+    /// Argument of the multiplier at the bifurcation, in turns.
+    #[must_use]
+    pub const fn turn(self) -> Real
+    {
+        match self {
+            Self::Root => 0.,
+            Self::PeriodDoubling => 0.5,
+        }
+    }
+
+    #[must_use]
+    pub fn multiplier(self) -> Cplx
+    {
+        Cplx::from_polar(1., TAU * self.turn())
+    }
+}
+
+impl Display for Bifurcation
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Root => write!(f, "root"),
+            Self::PeriodDoubling => write!(f, "period doubling"),
+        }
+    }
+}
+
+/// Displacement $f^n(z) - z$ and multiplier $(f^n)'(z)$ of the point `z` under the map at the
+/// plane coordinate `t`.
+fn cycle_equations<P>(plane: &P, z: Cplx, t: Cplx, period: Period) -> (Cplx, Cplx)
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let c = plane.param_map(t);
+    let mut w = P::Var::from(z);
+    let mut multiplier = ONE;
+    for _ in 0..period {
+        let (fw, dw) = plane.map_and_multiplier(w, &c);
+        w = fw;
+        multiplier *= dw.into();
+    }
+    (w.into() - z, multiplier)
+}
+
+/// Whether `z` returns to itself under fewer than `period` iterations of the map at `t`.
+fn has_lower_period<P>(plane: &P, z: Cplx, t: Cplx, period: Period) -> bool
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let tolerance = LOWER_PERIOD_TOLERANCE * (1. + z.norm());
+    divisors(period)
+        .filter(|&d| d < period)
+        .any(|d| cycle_equations(plane, z, t, d).0.norm() < tolerance)
+}
+
+/// Solve the system $f^n(z) = z$, $(f^n)'(z) = λ$ for the point `z` and the plane coordinate `t`
+/// by Newton's method, starting from `(z, t)`.
+fn solve<P>(
+    plane: &P,
+    z: Cplx,
+    t: Cplx,
+    period: Period,
+    multiplier: Cplx,
+) -> Option<(Cplx, Cplx)>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let (mut z, mut t) = (z, t);
+    for _ in 0..NEWTON_ITERS {
+        let (g, m) = cycle_equations(plane, z, t, period);
+        let h = multiplier - m;
+
+        // Both equations are holomorphic, so central differences along the real axis give their
+        // complex derivatives.
+        let hz = DIFF_STEP * (1. + z.norm());
+        let ht = DIFF_STEP * (1. + t.norm());
+        let (g_zp, m_zp) = cycle_equations(plane, z + hz, t, period);
+        let (g_zm, m_zm) = cycle_equations(plane, z - hz, t, period);
+        let (g_tp, m_tp) = cycle_equations(plane, z, t + ht, period);
+        let (g_tm, m_tm) = cycle_equations(plane, z, t - ht, period);
+        let g_z = (g_zp - g_zm) / (2. * hz);
+        let g_t = (g_tp - g_tm) / (2. * ht);
+        let m_z = (m_zp - m_zm) / (2. * hz);
+        let m_t = (m_tp - m_tm) / (2. * ht);
+
+        // Cramer's rule for the Newton step, solving for the zero of (g, m - multiplier)
+        let det = g_z * m_t - g_t * m_z;
+        let dz = (g * m_t + h * g_t) / det;
+        let dt = -(g_z * h + m_z * g) / det;
+        z -= dz;
+        t -= dt;
+
+        if !(z.is_finite() && t.is_finite()) {
+            return None;
+        }
+        if dz.norm() + dt.norm() < NEWTON_TOLERANCE * (1. + t.norm()) {
+            return Some((z, t));
+        }
+    }
+    None
+}
+
+const fn failed(start_point: Cplx) -> FindPointError<Cplx>
+{
+    FindPointError::NewtonError(NewtonError::FailedToConverge(start_point))
+}
+
+/// Continue a cycle of period `period` at `start_point`, attracting if there is one, in both
+/// the dynamical variable and the parameter until its multiplier is one step away from `target`.
+/// Returns a point of the cycle and the plane coordinate reached.
+///
+/// The last step is left to the caller, since the equations may be singular at the target.
+fn approach<P>(
+    plane: &P,
+    start_point: Cplx,
+    period: Period,
+    target: Cplx,
+) -> Result<(Cplx, Cplx), FindPointError<Cplx>>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    if period == 0 {
+        return Err(FindPointError::PeriodIsZero);
+    }
+
+    let c = plane.param_map(start_point);
+    let mut z = plane.start_point(start_point, &c);
+    for _ in 0..SEED_ITERS {
+        z = plane.map(z, &c);
+    }
+
+    // Settle onto the cycle while the parameter is held fixed
+    let mut z: Cplx = z.into();
+    for _ in 0..NEWTON_ITERS {
+        let (g, m) = cycle_equations(plane, z, start_point, period);
+        z -= g / (m - ONE);
+    }
+    let (_, initial_multiplier) = cycle_equations(plane, z, start_point, period);
+    if !(z.is_finite() && initial_multiplier.is_finite()) {
+        return Err(failed(start_point));
+    }
+
+    let mut t = start_point;
+    for k in 1..APPROACH_STEPS {
+        let s = k as Real / APPROACH_STEPS as Real;
+        let multiplier = initial_multiplier + s * (target - initial_multiplier);
+        (z, t) = solve(plane, z, t, period, multiplier).ok_or(failed(start_point))?;
+    }
+    Ok((z, t))
+}
+
+/// Find a parameter near `start_point` at which a cycle of period `period` has the given
+/// multiplier, by continuation from the cycle attracting at `start_point`.
+pub(super) fn find<P>(
+    plane: &P,
+    start_point: Cplx,
+    period: Period,
+    multiplier: Cplx,
+) -> FindPointResult<Cplx>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let (z, t) = approach(plane, start_point, period, multiplier)?;
+    solve(plane, z, t, period, multiplier)
+        .map(|(_, t)| t)
+        .ok_or(failed(start_point))
+}
+
+/// Trace the curve along which the cycle of period `period` attracting at `start_point` has
+/// multiplier on the unit circle, i.e. the boundary of its hyperbolic component.
+///
+/// The cycle is continued in both the dynamical variable and the parameter, first until its
+/// multiplier reaches that of `bifurcation`, then once around the unit circle from there.
+pub(super) fn trace<P>(
+    plane: &P,
+    start_point: Cplx,
+    period: Period,
+    bifurcation: Bifurcation,
+) -> Result<Vec<Cplx>, FindPointError<Cplx>>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let (mut z, mut t) = approach(plane, start_point, period, bifurcation.multiplier())?;
+
+    let mut locus = Vec::with_capacity(LOCUS_STEPS + 1);
+    let (mut dz, mut dt) = (ZERO, ZERO);
+    let mut skipped = 0;
+    for k in 0..=LOCUS_STEPS {
+        let turn = bifurcation.turn() + k as Real / LOCUS_STEPS as Real;
+        let multiplier = Cplx::from_polar(1., TAU * turn);
+
+        // Extrapolating from the last step carries the cycle through collisions with cycles of
+        // lower period, where Newton's method would otherwise stay on the wrong cycle.
+        let scale = (skipped + 1) as Real;
+        let (z_guess, t_guess) = (z + scale * dz, t + scale * dt);
+        if let Some((z_new, t_new)) = solve(plane, z_guess, t_guess, period, multiplier)
+            .filter(|&(z_new, t_new)| !has_lower_period(plane, z_new, t_new, period))
+        {
+            (dz, dt) = ((z_new - z) / scale, (t_new - t) / scale);
+            (z, t) = (z_new, t_new);
+            locus.push(t);
+            skipped = 0;
+        } else if skipped < MAX_SKIPPED {
+            skipped += 1;
+        } else {
+            break;
+        }
+    }
+    if locus.is_empty() {
+        return Err(failed(start_point));
+    }
+    Ok(locus)
+}
@@ -0,0 +1,233 @@
+use dynamo_common::math_utils::arithmetic::divisors;
+use dynamo_common::prelude::*;
+use dynamo_common::symbolic_dynamics::{Itinerary, OrbitSchema, OrbitSchemaWithDegree};
+use rayon::prelude::*;
+use std::fmt::Display;
+
+use super::{DynamicalFamily, ExternalRays};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Parameters closer than this, relative to their size, are taken to be the same.
+const DUPLICATE_TOLERANCE: Real = 1e-8;
+/// Points of the critical orbit closer than this, relative to their size, are taken to coincide
+/// when checking its orbit schema. Errors grow along the orbit as it follows repelling cycles.
+const ORBIT_TOLERANCE: Real = 1e-6;
+
+/// A parameter at which the free critical point has a finite orbit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PcfParameter
+{
+    pub point: Cplx,
+    pub orbit_schema: OrbitSchema,
+}
+
+impl Display for PcfParameter
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{}: {:.DISPLAY_PREC$}", self.orbit_schema, self.point)
+    }
+}
+
+/// A postcritically finite parameter with the external parameter rays landing at it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LandedParameter
+{
+    pub parameter: PcfParameter,
+    pub angles: Vec<RationalAngle>,
+    /// Kneading sequence shared by the angles, standing in for the address of the parameter.
+    pub kneading_sequence: Itinerary,
+}
+
+/// Orbit schema of the free critical point at the plane coordinate `t`, if its orbit repeats
+/// within `max_len` iterations.
+fn critical_orbit_schema<P>(plane: &P, t: Cplx, max_len: Period) -> Option<OrbitSchema>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let c = plane.param_map(t);
+    let mut z = plane.start_point(t, &c);
+    let mut orbit: Vec<Cplx> = Vec::with_capacity(max_len as usize + 1);
+    orbit.push(z.into());
+    for _ in 0..max_len {
+        z = plane.map(z, &c);
+        let w: Cplx = z.into();
+        if let Some(preperiod) = orbit
+            .iter()
+            .position(|u| (w - u).norm() < ORBIT_TOLERANCE * (1. + u.norm()))
+        {
+            return Some(OrbitSchema {
+                preperiod: preperiod as Period,
+                period: (orbit.len() - preperiod) as Period,
+            });
+        }
+        orbit.push(w);
+    }
+    None
+}
+
+/// Search the current view for postcritically finite parameters with preperiod at most
+/// `max_preperiod` and period at most `max_period`, by running Newton's method from a grid of
+/// `seeds_per_axis` by `seeds_per_axis` points for each orbit schema.
+///
+/// Only parameters within the view whose critical orbit has exactly the schema sought are kept,
+/// each once, sorted by orbit schema.
+pub(super) fn search<P>(
+    plane: &P,
+    max_preperiod: Period,
+    max_period: Period,
+    seeds_per_axis: usize,
+) -> Vec<PcfParameter>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let grid = plane.point_grid();
+    let (res_x, res_y) = (grid.res_x as Real, grid.res_y as Real);
+    let seeds: Vec<Cplx> = (0..seeds_per_axis)
+        .flat_map(|i| (0..seeds_per_axis).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let x = (i as Real + 0.5) * res_x / seeds_per_axis as Real;
+            let y = (j as Real + 0.5) * res_y / seeds_per_axis as Real;
+            grid.map_fractional_pixel(x, y)
+        })
+        .collect();
+
+    let schemas = (0..=max_preperiod).flat_map(|preperiod| {
+        (1..=max_period).map(move |period| OrbitSchema { period, preperiod })
+    });
+
+    let mut found = Vec::new();
+    for orbit_schema in schemas {
+        let mut points: Vec<Cplx> = seeds
+            .par_iter()
+            .filter_map(|&seed| plane.find_nearby_preperiodic_point(seed, orbit_schema).ok())
+            .filter(|&t| grid.locate_point_safe(t).is_some())
+            .filter(|&t| {
+                let max_len = orbit_schema.preperiod + orbit_schema.period;
+                critical_orbit_schema(plane, t, max_len) == Some(orbit_schema)
+            })
+            .collect();
+
+        points.sort_by(|u, v| u.re.total_cmp(&v.re).then(u.im.total_cmp(&v.im)));
+        let mut distinct: Vec<Cplx> = Vec::new();
+        for t in points {
+            let tolerance = DUPLICATE_TOLERANCE * (1. + t.norm());
+            if !distinct.iter().any(|u| (t - u).norm() < tolerance) {
+                distinct.push(t);
+            }
+        }
+        found.extend(
+            distinct
+                .into_iter()
+                .map(|point| PcfParameter { point, orbit_schema }),
+        );
+    }
+    found
+}
+
+/// Angles of all external parameter rays with preperiod and period adding up to at most
+/// `max_len` under multiplication by `degree`, including the angle 0.
+#[must_use]
+pub fn catalog_angles(degree: AngleNum, max_len: Period) -> Vec<RationalAngle>
+{
+    let schemas = (0..max_len).flat_map(|preperiod| {
+        (1..=max_len - preperiod).map(move |period| OrbitSchemaWithDegree {
+            preperiod,
+            period,
+            degree,
+        })
+    });
+    std::iter::once(RationalAngle::ZERO)
+        .chain(schemas.flat_map(|o| o.exact_angles()))
+        .collect()
+}
+
+/// The postcritically finite parameter at which the parameter ray at `angle` lands, found by
+/// Newton's method from the end of the ray.
+///
+/// Periodic rays land at the roots of hyperbolic components, from which Newton's method
+/// usually converges to their centers. Preperiodic rays land at Misiurewicz parameters, whose
+/// critical value has the preperiod of the angle and a period dividing its period, so that the
+/// critical point itself takes one more step to become periodic. Points whose critical orbit
+/// does not check out are discarded.
+pub(super) fn land_ray<P>(plane: &P, angle: RationalAngle) -> Option<PcfParameter>
+where
+    P: ExternalRays + ?Sized,
+{
+    let landing_point = *plane.external_ray(angle)?.last()?;
+    let OrbitSchema { preperiod, period } = angle.with_degree(plane.degree()).orbit_schema();
+    let preperiod = if preperiod == 0 { 0 } else { preperiod + 1 };
+    let periods: Vec<Period> = if preperiod == 0 {
+        vec![period]
+    } else {
+        divisors(period).collect()
+    };
+
+    periods
+        .into_iter()
+        .map(|period| OrbitSchema { period, preperiod })
+        .filter_map(|orbit_schema| {
+            let point = plane
+                .find_nearby_preperiodic_point(landing_point, orbit_schema)
+                .ok()?;
+            let max_len = orbit_schema.preperiod + orbit_schema.period;
+            (critical_orbit_schema(plane, point, max_len) == Some(orbit_schema))
+                .then_some(PcfParameter { point, orbit_schema })
+        })
+        .min_by(|u, v| {
+            let du = (u.point - landing_point).norm();
+            let dv = (v.point - landing_point).norm();
+            du.total_cmp(&dv)
+        })
+}
+
+/// Gather the landing points of parameter rays, listing each parameter once with all the
+/// angles landing at it. Parameters are sorted by the length of their critical orbit, then by
+/// their first angle.
+#[must_use]
+pub fn collect_catalog(
+    landed: Vec<(RationalAngle, PcfParameter)>,
+    degree: AngleNum,
+) -> Vec<LandedParameter>
+{
+    let mut catalog: Vec<LandedParameter> = Vec::new();
+    for (angle, parameter) in landed {
+        let tolerance = DUPLICATE_TOLERANCE * (1. + parameter.point.norm());
+        if let Some(entry) = catalog.iter_mut().find(|entry| {
+            entry.parameter.orbit_schema == parameter.orbit_schema
+                && (entry.parameter.point - parameter.point).norm() < tolerance
+        }) {
+            entry.angles.push(angle);
+        } else {
+            catalog.push(LandedParameter {
+                parameter,
+                angles: vec![angle],
+                kneading_sequence: angle.with_degree(degree).to_angle_info().kneading_sequence,
+            });
+        }
+    }
+    for entry in &mut catalog {
+        entry.angles.sort_unstable();
+    }
+    catalog.sort_by_key(|entry| {
+        let OrbitSchema { preperiod, period } = entry.parameter.orbit_schema;
+        (preperiod + period, preperiod, period, entry.angles[0])
+    });
+    catalog
+}
+
+/// Land every parameter ray whose angle has preperiod and period adding up to at most
+/// `max_len`, in parallel, and gather the parameters reached.
+pub(super) fn catalog<P>(plane: &P, max_len: Period) -> Vec<LandedParameter>
+where
+    P: ExternalRays + ?Sized,
+{
+    let landed = catalog_angles(plane.degree(), max_len)
+        .into_par_iter()
+        .filter_map(|angle| Some((angle, land_ray(plane, angle)?)))
+        .collect();
+    collect_catalog(landed, plane.degree())
+}
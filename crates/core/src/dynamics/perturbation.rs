@@ -0,0 +1,526 @@
+use super::escape_test::EscapeTest;
+use super::{
+    ComputeMode, DisplayDefaults, DynamicalFamily, EscapeEncoding, ExternalRays, HasJulia,
+    InfinityFirstReturnMap, MarkedPoints, PlaneType,
+};
+use crate::orbit::EscapeResult;
+use dynamo_color::Coloring;
+use dynamo_common::prelude::*;
+
+/// Small term added to each map of a [`Perturbed`] family, scaled by `epsilon`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Perturbation
+{
+    pub epsilon: Cplx,
+    /// If true, the perturbing term is `ε·ξ(z)` for a pseudo-random `ξ(z)` in the unit disk, and
+    /// otherwise it is the anti-holomorphic term `ε·z̄`.
+    pub noise: bool,
+}
+
+impl Perturbation
+{
+    /// Bound on the size of `ε` offered by its slider.
+    const MAX_EPSILON: Real = 0.25;
+
+    #[must_use]
+    pub const fn antiholomorphic(epsilon: Cplx) -> Self
+    {
+        Self {
+            epsilon,
+            noise: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn noise(epsilon: Cplx) -> Self
+    {
+        Self {
+            epsilon,
+            noise: true,
+        }
+    }
+
+    /// The perturbing term at `z`.
+    #[inline]
+    #[must_use]
+    pub fn term(&self, z: Cplx) -> Cplx
+    {
+        if self.epsilon == ZERO {
+            ZERO
+        } else if self.noise {
+            self.epsilon * noise(z)
+        } else {
+            self.epsilon * z.conj()
+        }
+    }
+}
+
+/// Pseudo-random point of the unit disk, determined by the bits of `z`. The noise is the same
+/// each time a point is visited, so that the perturbed map is still a function of `z` and images
+/// are reproducible.
+fn noise(z: Cplx) -> Cplx
+{
+    // Finalizer of the SplitMix64 generator
+    let mut h = z.re.to_bits() ^ z.im.to_bits().rotate_left(32);
+    h ^= h >> 30;
+    h = h.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    h ^= h >> 27;
+    h = h.wrapping_mul(0x94d0_49bb_1331_11eb);
+    h ^= h >> 31;
+
+    let unit = |bits: u64| bits as Real / (1_u64 << 32) as Real;
+    Cplx::from_polar(unit(h & 0xffff_ffff).sqrt(), TAU * unit(h >> 32))
+}
+
+impl std::fmt::Display for Perturbation
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        if self.noise {
+            write!(f, "ε = {} (noise)", self.epsilon)
+        } else {
+            write!(f, "ε = {}", self.epsilon)
+        }
+    }
+}
+
+/// Meta-parameters of a [`Perturbed`] family: the perturbation, followed by those of the
+/// unperturbed family.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PerturbedParams<M>
+{
+    pub perturbation: Perturbation,
+    pub inner: M,
+}
+
+impl<M: std::fmt::Display> std::fmt::Display for PerturbedParams<M>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "[{}, {}]", self.perturbation, self.inner)
+    }
+}
+
+impl<M: Summarize> Summarize for PerturbedParams<M>
+{
+    fn summarize(&self) -> Option<String>
+    {
+        let perturbation = self.perturbation.to_string();
+        match self.inner.summarize() {
+            Some(inner) => Some(format!("[{perturbation}, {inner}]")),
+            None => Some(perturbation),
+        }
+    }
+}
+
+impl<M: ParamList> ParamList for PerturbedParams<M>
+{
+    type Param = M::Param;
+
+    fn local_param(&self) -> &Self::Param
+    {
+        self.inner.local_param()
+    }
+    fn into_local_param(self) -> Self::Param
+    {
+        self.inner.into_local_param()
+    }
+}
+
+impl<M: HasSliders> HasSliders for PerturbedParams<M>
+{
+    /// Sliders for `ε` and the choice of perturbing term, followed by those of the unperturbed
+    /// family.
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        let mut sliders = vec![
+            MetaParamSlider {
+                name: "ε".to_owned(),
+                value: self.perturbation.epsilon,
+                min: -Perturbation::MAX_EPSILON,
+                max: Perturbation::MAX_EPSILON,
+                complex: true,
+                integer: false,
+            },
+            MetaParamSlider {
+                name: "noise".to_owned(),
+                value: Real::from(u8::from(self.perturbation.noise)).into(),
+                min: 0.,
+                max: 1.,
+                complex: false,
+                integer: true,
+            },
+        ];
+        sliders.extend(self.inner.sliders());
+        sliders
+    }
+
+    fn set_slider(&mut self, idx: usize, value: Cplx)
+    {
+        match idx {
+            0 => self.perturbation.epsilon = value,
+            1 => self.perturbation.noise = value.re >= 0.5,
+            _ => self.inner.set_slider(idx - 2, value),
+        }
+    }
+
+    fn text_entry(&self) -> Option<MetaParamText>
+    {
+        self.inner.text_entry()
+    }
+
+    fn set_text_entry(&mut self, text: &str) -> Result<(), String>
+    {
+        self.inner.set_text_entry(text)
+    }
+}
+
+/// A family whose maps `f` are replaced by `f + ε·z̄` or by `f + ε·ξ`, where `ξ` is a bounded
+/// pseudo-random term, in order to see which of its features survive small non-holomorphic
+/// perturbations.
+///
+/// Since the perturbed maps are not holomorphic, the derivatives reported are those of the
+/// unperturbed maps, so that multipliers and distance estimates are only approximate.
+#[derive(Clone, Debug, Default)]
+pub struct Perturbed<P>
+{
+    family: P,
+    perturbation: Perturbation,
+}
+
+impl<P> Perturbed<P>
+{
+    #[must_use]
+    pub const fn new(family: P, perturbation: Perturbation) -> Self
+    {
+        Self {
+            family,
+            perturbation,
+        }
+    }
+
+    #[must_use]
+    pub const fn perturbation(&self) -> Perturbation
+    {
+        self.perturbation
+    }
+}
+
+impl<P> DynamicalFamily for Perturbed<P>
+where
+    P: DynamicalFamily<Var = Cplx, Deriv = Cplx>,
+{
+    type Var = Cplx;
+    type Param = P::Param;
+    type MetaParam = PerturbedParams<P::MetaParam>;
+    type Deriv = Cplx;
+
+    #[inline]
+    fn point_grid(&self) -> &PointGrid
+    {
+        self.family.point_grid()
+    }
+
+    #[inline]
+    fn point_grid_mut(&mut self) -> &mut PointGrid
+    {
+        self.family.point_grid_mut()
+    }
+
+    fn with_point_grid(mut self, point_grid: PointGrid) -> Self
+    {
+        self.family = self.family.with_point_grid(point_grid);
+        self
+    }
+
+    #[inline]
+    fn compute_mode(&self) -> ComputeMode
+    {
+        self.family.compute_mode()
+    }
+
+    #[inline]
+    fn compute_mode_mut(&mut self) -> &mut ComputeMode
+    {
+        self.family.compute_mode_mut()
+    }
+
+    #[inline]
+    fn set_compute_mode(&mut self, compute_mode: ComputeMode)
+    {
+        self.family.set_compute_mode(compute_mode);
+    }
+
+    #[inline]
+    fn max_iter(&self) -> IterCount
+    {
+        self.family.max_iter()
+    }
+
+    #[inline]
+    fn max_iter_mut(&mut self) -> &mut IterCount
+    {
+        self.family.max_iter_mut()
+    }
+
+    #[inline]
+    fn set_max_iter(&mut self, new_max_iter: IterCount)
+    {
+        self.family.set_max_iter(new_max_iter);
+    }
+
+    fn with_max_iter(mut self, max_iter: IterCount) -> Self
+    {
+        self.set_max_iter(max_iter);
+        self
+    }
+
+    #[inline]
+    fn map(&self, z: Cplx, c: &P::Param) -> Cplx
+    {
+        self.family.map(z, c) + self.perturbation.term(z)
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Cplx, c: &P::Param) -> (Cplx, Cplx)
+    {
+        let (w, dw_dz) = self.family.map_and_multiplier(z, c);
+        (w + self.perturbation.term(z), dw_dz)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Cplx, c: &P::Param) -> (Cplx, Cplx, Cplx)
+    {
+        let (w, dw_dz, dw_dc) = self.family.gradient(z, c);
+        (w + self.perturbation.term(z), dw_dz, dw_dc)
+    }
+
+    /// Preimages are only known for the unperturbed maps.
+    fn preimages(&self, z: Cplx, c: &P::Param) -> Vec<Cplx>
+    {
+        if self.perturbation.epsilon == ZERO {
+            self.family.preimages(z, c)
+        } else {
+            vec![]
+        }
+    }
+
+    #[inline]
+    fn min_iter(&self) -> IterCount
+    {
+        self.family.min_iter()
+    }
+
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        self.family.escape_radius()
+    }
+
+    #[inline]
+    fn escape_test(&self) -> EscapeTest
+    {
+        self.family.escape_test()
+    }
+
+    #[inline]
+    fn extra_stop_condition(
+        &self,
+        z: Cplx,
+        c: &P::Param,
+        iter: IterCount,
+    ) -> Option<EscapeResult<Cplx, Cplx>>
+    {
+        self.family.extra_stop_condition(z, c, iter)
+    }
+
+    #[inline]
+    fn periodicity_tolerance(&self) -> Real
+    {
+        self.family.periodicity_tolerance()
+    }
+
+    #[inline]
+    fn start_point(&self, point: Cplx, c: &P::Param) -> Cplx
+    {
+        self.family.start_point(point, c)
+    }
+
+    #[inline]
+    fn start_point_d(&self, point: Cplx, c: &P::Param) -> (Cplx, Cplx, Cplx)
+    {
+        self.family.start_point_d(point, c)
+    }
+
+    #[inline]
+    fn param_map(&self, point: Cplx) -> P::Param
+    {
+        self.family.param_map(point)
+    }
+
+    #[inline]
+    fn param_map_d(&self, point: Cplx) -> (P::Param, Cplx)
+    {
+        self.family.param_map_d(point)
+    }
+
+    #[inline]
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        PerturbedParams {
+            perturbation: self.perturbation,
+            inner: self.family.get_meta_params(),
+        }
+    }
+
+    #[inline]
+    fn set_meta_param(&mut self, PerturbedParams { perturbation, inner }: Self::MetaParam)
+    {
+        self.perturbation = perturbation;
+        self.family.set_meta_param(inner);
+    }
+
+    #[inline]
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.family.get_param()
+    }
+
+    #[inline]
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {
+        self.family.set_param(value);
+    }
+
+    #[inline]
+    fn cycle_active_plane(&mut self)
+    {
+        self.family.cycle_active_plane();
+    }
+
+    #[inline]
+    fn plane_type(&self) -> PlaneType
+    {
+        self.family.plane_type()
+    }
+}
+
+impl<P> DisplayDefaults for Perturbed<P>
+where
+    P: DisplayDefaults<Var = Cplx, Deriv = Cplx>,
+{
+    fn name(&self) -> String
+    {
+        format!("Perturbed {}", self.family.name())
+    }
+
+    fn description(&self) -> String
+    {
+        format!(
+            "The maps of {} with a small term ε·z̄ added, or with ε·ξ(z) for a bounded \
+                pseudo-random ξ if noise is enabled, to test the structural stability of their \
+                dynamics. {}",
+            self.family.name(),
+            self.family.description()
+        )
+    }
+
+    #[inline]
+    fn trace_labels(&self) -> [&'static str; 2]
+    {
+        self.family.trace_labels()
+    }
+
+    #[inline]
+    fn default_bounds(&self) -> Bounds
+    {
+        self.family.default_bounds()
+    }
+
+    #[inline]
+    fn default_selection(&self) -> Cplx
+    {
+        self.family.default_selection()
+    }
+
+    #[inline]
+    fn default_coloring(&self) -> Coloring
+    {
+        self.family.default_coloring()
+    }
+}
+
+impl<P> HasJulia for Perturbed<P>
+where
+    P: HasJulia<Var = Cplx, Deriv = Cplx>,
+{
+    #[inline]
+    fn default_max_iter_child(&self) -> IterCount
+    {
+        self.family.default_max_iter_child()
+    }
+
+    #[inline]
+    fn default_bounds_child(&self, point: Cplx, c: &Self::Param) -> Bounds
+    {
+        self.family.default_bounds_child(point, c)
+    }
+
+    #[inline]
+    fn dynam_map(&self, point: Cplx) -> Cplx
+    {
+        self.family.dynam_map(point)
+    }
+
+    #[inline]
+    fn dynam_map_d(&self, point: Cplx) -> (Cplx, Cplx)
+    {
+        self.family.dynam_map_d(point)
+    }
+}
+
+impl<P> MarkedPoints for Perturbed<P>
+where
+    P: MarkedPoints<Var = Cplx, Deriv = Cplx>,
+{
+    /// Critical points of the unperturbed maps.
+    #[inline]
+    fn critical_points_child(&self, c: &Self::Param) -> Vec<Cplx>
+    {
+        self.family.critical_points_child(c)
+    }
+}
+
+/// The perturbing terms grow at most linearly, so they do not change the behavior of maps of
+/// degree at least 2 near infinity.
+impl<P> InfinityFirstReturnMap for Perturbed<P>
+where
+    P: InfinityFirstReturnMap<Var = Cplx, Deriv = Cplx>,
+{
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        self.family.degree_real()
+    }
+
+    #[inline]
+    fn escaping_period(&self) -> Period
+    {
+        self.family.escaping_period()
+    }
+
+    #[inline]
+    fn escaping_phase(&self) -> Period
+    {
+        self.family.escaping_phase()
+    }
+
+    #[inline]
+    fn escape_coeff_d(&self, c: &Self::Param) -> (Cplx, Cplx)
+    {
+        self.family.escape_coeff_d(c)
+    }
+}
+
+impl<P> EscapeEncoding for Perturbed<P> where P: EscapeEncoding<Var = Cplx, Deriv = Cplx> {}
+impl<P> ExternalRays for Perturbed<P> where P: ExternalRays<Var = Cplx, Deriv = Cplx> {}
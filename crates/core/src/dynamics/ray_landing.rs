@@ -0,0 +1,189 @@
+use dynamo_common::math_utils::arithmetic::divisors;
+use dynamo_common::prelude::*;
+use dynamo_common::symbolic_dynamics::{OrbitSchema, OrbitSchemaWithDegree};
+use rayon::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{DynamicalFamily, ExternalRays};
+
+/// The escape coefficient counts as real and positive if its argument is below this.
+const MONIC_TOLERANCE: Real = 1e-12;
+
+/// All angles of exact period `period` under multiplication by `degree`, including 0 for
+/// period 1.
+fn periodic_angles(degree: AngleNum, period: Period) -> Vec<RationalAngle>
+{
+    let angles = OrbitSchemaWithDegree {
+        preperiod: 0,
+        period,
+        degree,
+    }
+    .exact_angles();
+    let zero = (period == 1).then_some(RationalAngle::ZERO);
+    zero.into_iter().chain(angles).collect()
+}
+
+/// Whether the map of a dynamical plane sends the ray at each angle onto the ray at `degree`
+/// times the angle, so that the landing points of a cycle of rays form an orbit. This holds
+/// where infinity is fixed and the leading coefficient of the map is real and positive.
+fn rays_follow_angle_map<P>(plane: &P) -> bool
+where
+    P: ExternalRays + ?Sized,
+{
+    if plane.escaping_period() != 1 {
+        return false;
+    }
+    let a = plane.escape_coeff(&plane.param_map(ZERO));
+    a.re > 0. && a.arg().abs() < MONIC_TOLERANCE
+}
+
+/// The angles of period `period`, split into cycles under multiplication by `degree`, each
+/// listed in the order of the angle map.
+fn angle_cycles(degree: AngleNum, period: Period) -> Vec<Vec<RationalAngle>>
+{
+    let mut seen = BTreeSet::new();
+    let mut cycles = Vec::new();
+    for angle in periodic_angles(degree, period) {
+        if seen.contains(&angle) {
+            continue;
+        }
+        let mut theta = angle;
+        let cycle: Vec<RationalAngle> = (0..period)
+            .map(|_| {
+                let current = theta;
+                theta *= degree;
+                current
+            })
+            .collect();
+        seen.extend(cycle.iter().copied());
+        cycles.push(cycle);
+    }
+    cycles
+}
+
+/// The periodic point nearest to `end` among those found by Newton's method from it with
+/// periods dividing `period`, or `end` itself if there are none. Rays of period `period` land
+/// at such points on dynamical planes, but their ends only approach them to within a fraction of
+/// a pixel.
+fn refine_landing_point<P>(plane: &P, end: Cplx, period: Period) -> Cplx
+where
+    P: ExternalRays + ?Sized,
+{
+    divisors(period)
+        .filter_map(|period| {
+            let orbit_schema = OrbitSchema {
+                preperiod: 0,
+                period,
+            };
+            plane.find_nearby_preperiodic_point(end, orbit_schema).ok()
+        })
+        .min_by(|u, v| (u - end).norm().total_cmp(&(v - end).norm()))
+        .unwrap_or(end)
+}
+
+/// Where the external rays at every angle of period `period` land, computed in parallel.
+/// Angles whose rays cannot be traced are left out.
+///
+/// On parameter planes, the rays land at the roots of hyperbolic components of period
+/// `period`, which are parabolic, so the ends of the rays converge to them too slowly to tell
+/// which rays land together. Each angle is mapped instead to the center of the component, found
+/// by Newton's method from the end of its ray.
+///
+/// On dynamical planes, each angle is mapped to its landing point, refined from the end of its
+/// ray by Newton's method. Where the map sends rays onto
+/// rays, only one ray of each cycle is traced. The rays at the other angles of the cycle are its
+/// images under the map, so their landing points are found by iterating the map from its
+/// landing point rather than by pulling back each ray from the escaping region separately.
+pub(super) fn land_periodic_rays<P>(plane: &P, period: Period) -> BTreeMap<RationalAngle, Cplx>
+where
+    P: ExternalRays + ?Sized,
+{
+    let degree = plane.degree();
+    if period == 0 || degree < 2 {
+        return BTreeMap::new();
+    }
+
+    if !plane.plane_type().is_dynamical() {
+        let orbit_schema = OrbitSchema {
+            preperiod: 0,
+            period,
+        };
+        return periodic_angles(degree, period)
+            .into_par_iter()
+            .filter_map(|angle| {
+                let end = *plane.external_ray(angle)?.last()?;
+                let center = plane.find_nearby_preperiodic_point(end, orbit_schema).ok()?;
+                Some((angle, center))
+            })
+            .collect();
+    }
+
+    if !rays_follow_angle_map(plane) {
+        return periodic_angles(degree, period)
+            .into_par_iter()
+            .filter_map(|angle| {
+                let end = *plane.external_ray(angle)?.last()?;
+                Some((angle, refine_landing_point(plane, end, period)))
+            })
+            .collect();
+    }
+
+    angle_cycles(degree, period)
+        .into_par_iter()
+        .filter_map(|cycle| {
+            let end = *plane.external_ray(cycle[0])?.last()?;
+            let landing_point = refine_landing_point(plane, end, period);
+            let param = plane.param_map(landing_point);
+            let mut z = P::Var::from(landing_point);
+            let orbit: Vec<(RationalAngle, Cplx)> = cycle
+                .into_iter()
+                .map(|angle| {
+                    let point = z.into();
+                    z = plane.map(z, &param);
+                    (angle, point)
+                })
+                .collect();
+            Some(orbit)
+        })
+        .flatten()
+        .collect()
+}
+
+/// Group the angles whose rays land within `tolerance` of one another, such as the pairs of
+/// angles landing at the roots of hyperbolic components. Each group is sorted, and the groups
+/// are sorted by their least angle.
+#[must_use]
+pub fn landing_classes(
+    landings: &BTreeMap<RationalAngle, Cplx>,
+    tolerance: Real,
+) -> Vec<Vec<RationalAngle>>
+{
+    let mut classes: Vec<(Vec<Cplx>, Vec<RationalAngle>)> = Vec::new();
+    for (&angle, &point) in landings {
+        let close: Vec<usize> = classes
+            .iter()
+            .enumerate()
+            .filter(|(_, (points, _))| points.iter().any(|w| (point - w).norm() < tolerance))
+            .map(|(i, _)| i)
+            .collect();
+
+        // A point close to several classes joins them together
+        let mut merged = (vec![point], vec![angle]);
+        for &i in close.iter().rev() {
+            let (points, angles) = classes.swap_remove(i);
+            merged.0.extend(points);
+            merged.1.extend(angles);
+        }
+        classes.push(merged);
+    }
+
+    let mut classes: Vec<Vec<RationalAngle>> = classes
+        .into_iter()
+        .map(|(_, mut angles)| {
+            angles.sort_unstable();
+            angles
+        })
+        .collect();
+    classes.sort_unstable();
+    classes
+}
@@ -0,0 +1,150 @@
+//! Maps of the real plane that are not holomorphic, such as the Hénon and Ikeda maps.
+//!
+//! Their variable and parameter are points of the plane, and their derivatives are Jacobian
+//! matrices. Rather than Julia sets, such maps are studied through their attractors: the density
+//! of a long forward orbit approximates the natural measure on the attractor, and the largest
+//! Lyapunov exponent along it tells stable cycles from chaos.
+use dynamo_common::prelude::*;
+use dynamo_common::types::variables::{Matrix2x2, Point};
+use ndarray::Array2;
+use rayon::prelude::*;
+
+use super::escape_test::EscapeVerdict;
+use super::DynamicalFamily;
+
+/// Number of iterations before the orbit is sampled, while it approaches the attractor.
+const TRANSIENT: usize = 256;
+/// Number of iterations over which the Lyapunov exponent is averaged.
+const LYAPUNOV_ITERS: usize = 1024;
+
+/// Families of maps of the real plane. Implement the map and its Jacobians here, and fill in
+/// the `DynamicalFamily` implementation with [`real_plane_2d_impl`].
+///
+/// [`real_plane_2d_impl`]: crate::macros::real_plane_2d_impl
+pub trait RealPlane2D: DynamicalFamily<Var = Point, Param = Point, Deriv = Matrix2x2>
+{
+    fn map_2d(&self, z: Point, c: &Point) -> Point;
+
+    /// Jacobian matrix of the map with respect to `z`, with the partial derivatives in `x` and
+    /// `y` as its columns.
+    fn jacobian(&self, z: Point, c: &Point) -> Matrix2x2;
+
+    /// Jacobian matrix of the map with respect to the parameters.
+    fn param_jacobian(&self, z: Point, c: &Point) -> Matrix2x2;
+
+    /// A point in the basin of the attractor, from which orbits on the parameter plane start.
+    fn seed(&self, _c: &Point) -> Point
+    {
+        Point { x: 0.1, y: 0.1 }
+    }
+}
+
+/// Largest Lyapunov exponent along the orbit of `start`, as a [`PointInfo::Lyapunov`], or the
+/// iteration at which the orbit escapes.
+#[must_use]
+pub fn lyapunov_info<P>(plane: &P, start: Point, c: &Point) -> PointInfo<Matrix2x2>
+where
+    P: RealPlane2D + ?Sized,
+{
+    let escape_test = plane.escape_test();
+    let escaped = |z: Point, iter: usize| {
+        (escape_test.verdict(z) == EscapeVerdict::Escaped).then_some(PointInfo::Escaping {
+            potential: iter as IterCountSmooth,
+            phase: None,
+            angle: None,
+        })
+    };
+
+    let mut z = start;
+    for iter in 0..TRANSIENT {
+        z = plane.map_2d(z, c);
+        if let Some(info) = escaped(z, iter) {
+            return info;
+        }
+    }
+
+    // Follow a tangent vector along the orbit, renormalizing it at each step
+    let mut v = Point { x: 1., y: 0. };
+    let mut sum = 0.;
+    for iter in TRANSIENT..TRANSIENT + LYAPUNOV_ITERS {
+        v = plane.jacobian(z, c).apply(v);
+        let norm = v.norm();
+        if norm == 0. {
+            return PointInfo::Lyapunov {
+                exponent: Real::NEG_INFINITY,
+            };
+        }
+        sum += norm.ln();
+        v = Point {
+            x: v.x / norm,
+            y: v.y / norm,
+        };
+        z = plane.map_2d(z, c);
+        if let Some(info) = escaped(z, iter) {
+            return info;
+        }
+    }
+    PointInfo::Lyapunov {
+        exponent: sum / LYAPUNOV_ITERS as Real,
+    }
+}
+
+/// Number of points of a forward orbit of `num_points` steps landing in each pixel of the plane,
+/// indexed by column and row as in the image. `None` if the family has no attractor to sample.
+#[must_use]
+pub fn attractor_density<P>(plane: &P, num_points: usize) -> Option<Array2<u32>>
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let point_grid = plane.point_grid();
+    let param = plane.param_map(point_grid.center());
+    let start = plane.attractor_seed(&param)?;
+
+    let mut counts = Array2::zeros(point_grid.shape());
+    let mut z = start;
+    for step in 0..TRANSIENT + num_points {
+        z = plane.map(z, &param);
+        if plane.escape_test().verdict(z) == EscapeVerdict::Escaped {
+            z = start;
+            continue;
+        }
+        if step < TRANSIENT {
+            continue;
+        }
+        if let Some(pixel) = point_grid.locate_point_safe(z.into()) {
+            counts[pixel] += 1;
+        }
+    }
+    Some(counts)
+}
+
+/// Color each pixel of the plane by the Lyapunov exponent of its orbit. Returns false, leaving
+/// the plane untouched, if the family does not compute Lyapunov exponents.
+pub fn fill<P>(plane: &P, iter_plane: &mut IterPlane<P::Deriv>) -> bool
+where
+    P: DynamicalFamily + ?Sized,
+{
+    let point_grid = plane.point_grid();
+    let center = point_grid.center();
+    let param = plane.param_map(center);
+    if plane
+        .lyapunov_info(plane.start_point(center, &param), &param)
+        .is_none()
+    {
+        return false;
+    }
+
+    iter_plane
+        .iter_counts
+        .indexed_iter_mut()
+        .par_bridge()
+        .for_each(|((x, y), point_info)| {
+            let point = point_grid.map_pixel(x, y);
+            let param = plane.param_map(point);
+            let start = plane.start_point(point, &param);
+            if let Some(info) = plane.lyapunov_info(start, &param) {
+                *point_info = info;
+            }
+        });
+    true
+}
@@ -0,0 +1,346 @@
+use super::DynamicalFamily;
+use crate::orbit::EscapeResult;
+use dynamo_common::prelude::*;
+use std::ops::{Add, Mul, Sub};
+use wide::{f64x4, CmpGe, CmpLe, CmpLt};
+
+/// Number of orbits iterated together by a [`SimdMap`].
+pub const LANES: usize = 4;
+
+/// The escape result of each orbit in a batch, or `None` for those that did not escape.
+pub type BatchResult<V, D> = Vec<Option<EscapeResult<V, D>>>;
+
+/// Several complex numbers, one per lane, stored as vectors of real and imaginary parts.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CplxLanes
+{
+    pub re: f64x4,
+    pub im: f64x4,
+}
+
+impl CplxLanes
+{
+    pub const ZERO: Self = Self {
+        re: f64x4::ZERO,
+        im: f64x4::ZERO,
+    };
+
+    #[inline]
+    #[must_use]
+    pub fn splat(z: Cplx) -> Self
+    {
+        Self {
+            re: f64x4::splat(z.re),
+            im: f64x4::splat(z.im),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn norm_sqr(self) -> f64x4
+    {
+        self.re.mul_add(self.re, self.im * self.im)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn square(self) -> Self
+    {
+        Self {
+            re: self.re.mul_sub(self.re, self.im * self.im),
+            im: 2. * self.re * self.im,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn lane(self, i: usize) -> Cplx
+    {
+        Cplx::new(self.re.to_array()[i], self.im.to_array()[i])
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_lane(self, i: usize, z: Cplx) -> Self
+    {
+        Self {
+            re: with_lane(self.re, i, z.re),
+            im: with_lane(self.im, i, z.im),
+        }
+    }
+}
+
+/// Number of steps between checks for periodic orbits, which cost more than the map itself.
+const CHECK_INTERVAL: usize = 4;
+
+/// Masks selecting a single lane. Blending with these avoids a round trip through memory.
+const LANE_MASKS: [f64x4; LANES] = {
+    let ones = f64::from_bits(u64::MAX);
+    [
+        f64x4::new([ones, 0., 0., 0.]),
+        f64x4::new([0., ones, 0., 0.]),
+        f64x4::new([0., 0., ones, 0.]),
+        f64x4::new([0., 0., 0., ones]),
+    ]
+};
+
+#[inline]
+fn with_lane(x: f64x4, i: usize, value: Real) -> f64x4
+{
+    LANE_MASKS[i].blend(f64x4::splat(value), x)
+}
+
+impl From<[Cplx; LANES]> for CplxLanes
+{
+    fn from(z: [Cplx; LANES]) -> Self
+    {
+        Self {
+            re: f64x4::new(z.map(|z| z.re)),
+            im: f64x4::new(z.map(|z| z.im)),
+        }
+    }
+}
+
+impl Add for CplxLanes
+{
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self
+    {
+        Self {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl Sub for CplxLanes
+{
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self
+    {
+        Self {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl Mul for CplxLanes
+{
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self
+    {
+        Self {
+            re: self.re.mul_sub(rhs.re, self.im * rhs.im),
+            im: self.re.mul_add(rhs.im, self.im * rhs.re),
+        }
+    }
+}
+
+impl Mul<Real> for CplxLanes
+{
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Real) -> Self
+    {
+        Self {
+            re: self.re * rhs,
+            im: self.im * rhs,
+        }
+    }
+}
+
+/// Families whose map can be applied to several orbits at once with SIMD instructions, to
+/// speed up the computation of escaping points.
+///
+/// Only for families with complex variable and parameter that escape by the norm of the
+/// variable, as in the default [`EscapeTest`](super::escape_test::EscapeTest).
+/// Points that do not escape are still computed one at a time, so that cycles can be detected.
+/// Enable the SIMD path with [`simd_impl`] in the `DynamicalFamily` implementation.
+///
+/// [`simd_impl`]: crate::macros::simd_impl
+pub trait SimdMap
+{
+    /// The map defining the dynamical system, applied to each lane.
+    fn map_simd(&self, z: CplxLanes, c: CplxLanes) -> CplxLanes;
+}
+
+/// The state of the orbits in each lane of a batch.
+struct Lanes
+{
+    z: CplxLanes,
+    c: CplxLanes,
+    iters: f64x4,
+    // Brent's cycle detection: compare against the orbit at the last power of two
+    saved: CplxLanes,
+    next_save: f64x4,
+    // Index of the orbit in each lane, or None once there are no orbits left
+    orbits: [Option<usize>; LANES],
+    active: i32,
+}
+
+impl Lanes
+{
+    const fn new() -> Self
+    {
+        Self {
+            z: CplxLanes::ZERO,
+            c: CplxLanes::ZERO,
+            iters: f64x4::ZERO,
+            saved: CplxLanes::ZERO,
+            next_save: f64x4::ZERO,
+            orbits: [None; LANES],
+            active: 0,
+        }
+    }
+
+    /// Start the next orbits from the queue in the lanes marked by `vacant`.
+    fn refill(
+        &mut self,
+        vacant: i32,
+        queue: &mut impl Iterator<Item = usize>,
+        starts: &[Cplx],
+        params: &[Cplx],
+    )
+    {
+        for (i, orbit) in self.orbits.iter_mut().enumerate() {
+            if vacant & (1 << i) == 0 {
+                continue;
+            }
+            *orbit = queue.next();
+            if let Some(k) = *orbit {
+                self.z = self.z.with_lane(i, starts[k]);
+                self.c = self.c.with_lane(i, params[k]);
+                self.saved = self.saved.with_lane(i, starts[k]);
+                self.iters = with_lane(self.iters, i, 0.);
+                self.next_save = with_lane(self.next_save, i, 2.);
+                self.active |= 1 << i;
+            } else {
+                self.active &= !(1 << i);
+            }
+        }
+    }
+
+    /// Apply the map once in every lane, returning a mask of the active lanes whose orbits have
+    /// escaped.
+    #[inline]
+    fn step<P: SimdMap>(&mut self, family: &P, limits: &Limits) -> i32
+    {
+        self.z = family.map_simd(self.z, self.c);
+        self.iters += f64x4::ONE;
+        self.escaped(limits).move_mask() & self.active
+    }
+
+    #[inline]
+    fn escaped(&self, limits: &Limits) -> f64x4
+    {
+        // Also true for NaN
+        let escaped = !self.z.norm_sqr().cmp_le(limits.escape_radius);
+        escaped & self.iters.cmp_ge(limits.min_iter)
+    }
+
+    /// Returns a mask of the active lanes whose orbits appear to be periodic or are close to the
+    /// iteration limit, and so should be left to the scalar path.
+    #[inline]
+    fn check_cycles(&mut self, limits: &Limits) -> i32
+    {
+        let stopped = (self.z - self.saved).norm_sqr().cmp_lt(limits.tolerance)
+            | self.iters.cmp_ge(limits.last_check);
+
+        let save = self.iters.cmp_ge(self.next_save);
+        self.saved = CplxLanes {
+            re: save.blend(self.z.re, self.saved.re),
+            im: save.blend(self.z.im, self.saved.im),
+        };
+        self.next_save = save.blend(self.next_save * 2., self.next_save);
+        stopped.move_mask() & self.active
+    }
+
+    /// Record the results of the orbits in the lanes marked by `done` that escaped.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn finish(&self, done: i32, limits: &Limits, results: &mut BatchResult<Cplx, Cplx>)
+    {
+        let escaped = self.escaped(limits).move_mask() & done;
+        if escaped == 0 {
+            return;
+        }
+        let iters = self.iters.to_array();
+        for (i, orbit) in self.orbits.iter().enumerate() {
+            if let Some(k) = orbit.filter(|_| escaped & (1 << i) != 0) {
+                results[k] = Some(EscapeResult::Escaped {
+                    iters: iters[i] as IterCount,
+                    final_value: self.z.lane(i),
+                });
+            }
+        }
+    }
+}
+
+/// Escape and stopping conditions, in every lane.
+struct Limits
+{
+    escape_radius: f64x4,
+    tolerance: f64x4,
+    min_iter: f64x4,
+    // Orbits still running at this iteration could pass `max_iter` before the next check
+    last_check: f64x4,
+}
+
+/// Iterate the orbits of `starts` under the maps with parameters `params`, several at a time,
+/// returning the escape result of each orbit that escapes. Orbits that appear to be periodic or
+/// exceed `max_iter` are left for cycle detection on the scalar path.
+///
+/// Each lane moves on to the next orbit as soon as its current one finishes, so that lanes are
+/// not left idle while a slow orbit runs. Two groups of lanes are stepped together so that one
+/// can make progress while the other waits on the result of the map.
+pub fn iterate_batch<P>(
+    family: &P,
+    starts: &[Cplx],
+    params: &[Cplx],
+    min_iter: IterCount,
+    max_iter: IterCount,
+) -> BatchResult<Cplx, Cplx>
+where
+    P: DynamicalFamily + SimdMap,
+{
+    let limits = Limits {
+        escape_radius: f64x4::splat(family.escape_radius()),
+        tolerance: f64x4::splat(family.periodicity_tolerance()),
+        min_iter: f64x4::splat(min_iter as Real),
+        last_check: f64x4::splat(max_iter as Real - CHECK_INTERVAL as Real),
+    };
+
+    let mut results = vec![None; starts.len()];
+    let mut queue = 0..starts.len();
+    let mut groups = [Lanes::new(), Lanes::new()];
+    let mut done = [(1 << LANES) - 1; 2];
+
+    loop {
+        for (lanes, done) in groups.iter_mut().zip(done) {
+            if done != 0 {
+                lanes.finish(done, &limits, &mut results);
+                lanes.refill(done, &mut queue, starts, params);
+            }
+        }
+        if groups.iter().all(|lanes| lanes.active == 0) {
+            break;
+        }
+        done = loop {
+            let mut escaped = [0; 2];
+            for _ in 0..CHECK_INTERVAL {
+                escaped = groups.each_mut().map(|lanes| lanes.step(family, &limits));
+                if escaped != [0; 2] {
+                    break;
+                }
+            }
+            let stopped = groups.each_mut().map(|lanes| lanes.check_cycles(&limits));
+            if escaped != [0; 2] || stopped != [0; 2] {
+                break [escaped[0] | stopped[0], escaped[1] | stopped[1]];
+            }
+        };
+    }
+    results
+}
@@ -0,0 +1,423 @@
+//! Holomorphic skew products `(z, w) -> (f(z), g(z, w))` of the plane, whose base map `f` carries
+//! each vertical line, or fiber, into another.
+//!
+//! The base dynamics of `f` are shown on a [`SkewBase`] plane, on which the selected point is a
+//! base point rather than a parameter. Next to it, a [`SkewFiber`] plane shows the fiber over the
+//! base point: each of its points `w` is colored by the orbit of the pair `(z, w)`, which is run
+//! on both coordinates at once through the [`FiberPoint`] variable, so that the cycles detected
+//! are those of the skew product itself. The multipliers reported are those of the fiber maps,
+//! composed along the cycle.
+use dynamo_common::prelude::*;
+
+use super::{
+    ComputeMode, DisplayDefaults, DynamicalFamily, EscapeEncoding, ExternalRays, HasChild,
+    InfinityFirstReturnMap, MarkedPoints, PaneLink, PlaneType,
+};
+use crate::macros::basic_plane_impl;
+
+/// A skew product, given by its base map and fiber maps. Both are assumed to be polynomials that
+/// are monic at infinity, of the degrees given.
+pub trait SkewProduct: Clone + Default + Send + Sync
+{
+    /// The base map `f`, together with its derivative.
+    fn base_map(&self, z: Cplx) -> (Cplx, Cplx);
+
+    /// The map `g(z, ·)` from the fiber over `z` to the fiber over `f(z)`, together with its
+    /// derivative in `w`.
+    fn fiber_map(&self, z: Cplx, w: Cplx) -> (Cplx, Cplx);
+
+    fn base_critical_points(&self) -> Vec<Cplx>;
+
+    /// Critical points of the fiber map over `z`.
+    fn fiber_critical_points(&self, z: Cplx) -> Vec<Cplx>;
+
+    #[inline]
+    fn base_degree(&self) -> Real
+    {
+        2.
+    }
+
+    #[inline]
+    fn fiber_degree(&self) -> Real
+    {
+        2.
+    }
+
+    fn name(&self) -> String;
+
+    fn description(&self) -> String
+    {
+        String::new()
+    }
+
+    fn base_bounds(&self) -> Bounds;
+
+    fn fiber_bounds(&self, _z: Cplx) -> Bounds
+    {
+        Bounds::centered_square(2.5)
+    }
+}
+
+/// The dynamical plane of the base map of a skew product. Its parameter map sends each point to
+/// itself, so that the selected point becomes the base point of the fiber shown in its child.
+#[derive(Clone)]
+pub struct SkewBase<S>
+{
+    pub skew: S,
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+}
+
+impl<S: SkewProduct> SkewBase<S>
+{
+    #[must_use]
+    pub fn new(skew: S) -> Self
+    {
+        let point_grid = PointGrid::new_by_res_y(1024, skew.base_bounds());
+        Self {
+            skew,
+            point_grid,
+            compute_mode: ComputeMode::default(),
+            max_iter: 1024,
+        }
+    }
+}
+
+impl<S: SkewProduct> Default for SkewBase<S>
+{
+    fn default() -> Self
+    {
+        Self::new(S::default())
+    }
+}
+
+impl<S: SkewProduct> DynamicalFamily for SkewBase<S>
+{
+    type Var = Cplx;
+    type Param = Cplx;
+    type MetaParam = NoParam;
+    type Deriv = Cplx;
+
+    basic_plane_impl!();
+
+    #[inline]
+    fn map(&self, z: Cplx, _base_point: &Cplx) -> Cplx
+    {
+        self.skew.base_map(z).0
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Cplx, _base_point: &Cplx) -> (Cplx, Cplx)
+    {
+        self.skew.base_map(z)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Cplx, _base_point: &Cplx) -> (Cplx, Cplx, Cplx)
+    {
+        let (fz, df_dz) = self.skew.base_map(z);
+        (fz, df_dz, ZERO)
+    }
+
+    #[inline]
+    fn start_point(&self, point: Cplx, _base_point: &Cplx) -> Cplx
+    {
+        point
+    }
+
+    #[inline]
+    fn start_point_d(&self, point: Cplx, _base_point: &Cplx) -> (Cplx, Cplx, Cplx)
+    {
+        (point, ONE, ZERO)
+    }
+
+    #[inline]
+    fn param_map(&self, point: Cplx) -> Cplx
+    {
+        point
+    }
+
+    #[inline]
+    fn plane_type(&self) -> PlaneType
+    {
+        PlaneType::Dynamical
+    }
+}
+
+impl<S: SkewProduct> InfinityFirstReturnMap for SkewBase<S>
+{
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        self.skew.base_degree()
+    }
+
+    #[inline]
+    fn escaping_phase(&self) -> Period
+    {
+        0
+    }
+}
+
+impl<S: SkewProduct> DisplayDefaults for SkewBase<S>
+{
+    fn name(&self) -> String
+    {
+        self.skew.name()
+    }
+
+    fn description(&self) -> String
+    {
+        self.skew.description()
+    }
+
+    fn trace_labels(&self) -> [&'static str; 2]
+    {
+        ["Re(z)", "Im(z)"]
+    }
+
+    #[inline]
+    fn default_bounds(&self) -> Bounds
+    {
+        self.skew.base_bounds()
+    }
+
+    /// The fiber over a critical point of the base map is shown first.
+    fn default_selection(&self) -> Cplx
+    {
+        self.skew
+            .base_critical_points()
+            .first()
+            .copied()
+            .unwrap_or(ZERO)
+    }
+}
+
+impl<S: SkewProduct> MarkedPoints for SkewBase<S>
+{
+    #[inline]
+    fn critical_points_child(&self, _base_point: &Cplx) -> Vec<Cplx>
+    {
+        self.skew.base_critical_points()
+    }
+}
+
+impl<S: SkewProduct> EscapeEncoding for SkewBase<S> {}
+impl<S: SkewProduct> ExternalRays for SkewBase<S> {}
+
+impl<S: SkewProduct> HasChild<SkewFiber<S>> for SkewBase<S>
+{
+    #[inline]
+    fn to_child_param(base_point: Cplx) -> Cplx
+    {
+        base_point
+    }
+
+    #[inline]
+    fn pane_link(&self) -> PaneLink
+    {
+        PaneLink::Fiber
+    }
+}
+
+/// The fiber of a skew product over a base point `z`, on which each point `w` is colored by the
+/// orbit of `(z, w)`. The base point plays the role of the parameter of a Julia set.
+#[derive(Clone)]
+pub struct SkewFiber<S>
+{
+    pub skew: S,
+    pub base_point: Cplx,
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+}
+
+impl<S: SkewProduct> SkewFiber<S>
+{
+    #[must_use]
+    pub fn new(skew: S, base_point: Cplx) -> Self
+    {
+        let point_grid = PointGrid::new_by_res_y(1024, skew.fiber_bounds(base_point));
+        Self {
+            skew,
+            base_point,
+            point_grid,
+            compute_mode: ComputeMode::default(),
+            max_iter: 1024,
+        }
+    }
+}
+
+impl<S: SkewProduct> From<SkewBase<S>> for SkewFiber<S>
+{
+    fn from(base: SkewBase<S>) -> Self
+    {
+        let base_point = base.default_selection();
+        let point_grid = base
+            .point_grid()
+            .new_with_same_height(base.skew.fiber_bounds(base_point));
+        Self::new(base.skew, base_point).with_point_grid(point_grid)
+    }
+}
+
+impl<S: SkewProduct> DynamicalFamily for SkewFiber<S>
+{
+    type Var = FiberPoint;
+    type Param = NoParam;
+    type MetaParam = Cplx;
+    type Deriv = Cplx;
+
+    basic_plane_impl!();
+
+    #[inline]
+    fn map(&self, FiberPoint { z, w }: FiberPoint, _c: &NoParam) -> FiberPoint
+    {
+        FiberPoint {
+            z: self.skew.base_map(z).0,
+            w: self.skew.fiber_map(z, w).0,
+        }
+    }
+
+    #[inline]
+    fn map_and_multiplier(
+        &self,
+        FiberPoint { z, w }: FiberPoint,
+        _c: &NoParam,
+    ) -> (FiberPoint, Cplx)
+    {
+        let (gw, dg_dw) = self.skew.fiber_map(z, w);
+        let point = FiberPoint {
+            z: self.skew.base_map(z).0,
+            w: gw,
+        };
+        (point, dg_dw)
+    }
+
+    #[inline]
+    fn gradient(&self, point: FiberPoint, c: &NoParam) -> (FiberPoint, Cplx, Cplx)
+    {
+        let (image, dg_dw) = self.map_and_multiplier(point, c);
+        (image, dg_dw, ZERO)
+    }
+
+    #[inline]
+    fn start_point(&self, point: Cplx, _c: &NoParam) -> FiberPoint
+    {
+        FiberPoint {
+            z: self.base_point,
+            w: point,
+        }
+    }
+
+    #[inline]
+    fn start_point_d(&self, point: Cplx, c: &NoParam) -> (FiberPoint, Cplx, Cplx)
+    {
+        (self.start_point(point, c), ONE, ZERO)
+    }
+
+    #[inline]
+    fn param_map(&self, _point: Cplx) -> NoParam
+    {
+        NoParam
+    }
+
+    #[inline]
+    fn get_meta_params(&self) -> Cplx
+    {
+        self.base_point
+    }
+
+    #[inline]
+    fn set_meta_param(&mut self, base_point: Cplx)
+    {
+        self.base_point = base_point;
+    }
+
+    #[inline]
+    fn get_param(&self) -> Cplx
+    {
+        self.base_point
+    }
+
+    #[inline]
+    fn set_param(&mut self, base_point: Cplx)
+    {
+        self.base_point = base_point;
+    }
+
+    #[inline]
+    fn plane_type(&self) -> PlaneType
+    {
+        PlaneType::Dynamical
+    }
+}
+
+impl<S: SkewProduct> InfinityFirstReturnMap for SkewFiber<S>
+{
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        self.skew.fiber_degree()
+    }
+
+    #[inline]
+    fn escaping_phase(&self) -> Period
+    {
+        0
+    }
+}
+
+impl<S: SkewProduct> DisplayDefaults for SkewFiber<S>
+{
+    fn name(&self) -> String
+    {
+        format!("Fiber({})", self.skew.name())
+    }
+
+    fn long_name(&self) -> String
+    {
+        format!("{} over z = {:.DISPLAY_PREC$}", self.name(), self.base_point)
+    }
+
+    fn description(&self) -> String
+    {
+        self.skew.description()
+    }
+
+    fn trace_labels(&self) -> [&'static str; 2]
+    {
+        ["Re(w)", "Im(w)"]
+    }
+
+    #[inline]
+    fn default_bounds(&self) -> Bounds
+    {
+        self.skew.fiber_bounds(self.base_point)
+    }
+
+    fn default_selection(&self) -> Cplx
+    {
+        self.skew
+            .fiber_critical_points(self.base_point)
+            .first()
+            .copied()
+            .unwrap_or(ZERO)
+    }
+}
+
+impl<S: SkewProduct> MarkedPoints for SkewFiber<S>
+{
+    fn critical_points_child(&self, _c: &NoParam) -> Vec<FiberPoint>
+    {
+        let z = self.base_point;
+        self.skew
+            .fiber_critical_points(z)
+            .into_iter()
+            .map(|w| FiberPoint { z, w })
+            .collect()
+    }
+}
+
+impl<S: SkewProduct> EscapeEncoding for SkewFiber<S> {}
+impl<S: SkewProduct> ExternalRays for SkewFiber<S> {}
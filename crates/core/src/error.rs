@@ -5,6 +5,95 @@ pub enum FindPointError<T>
 {
     PeriodIsZero,
     NewtonError(NewtonError<T>),
+    /// The point found does not land on a repelling cycle.
+    NotRepelling,
 }
 
 pub type FindPointResult<T> = Result<T, FindPointError<T>>;
+
+impl<T> std::fmt::Display for FindPointError<T>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::PeriodIsZero => write!(f, "the period must be positive"),
+            Self::NewtonError(NewtonError::FailedToConverge(_)) => {
+                write!(f, "Newton's method did not converge")
+            }
+            Self::NewtonError(NewtonError::NanEncountered) => {
+                write!(f, "Newton's method left the domain of the map")
+            }
+            Self::NotRepelling => write!(f, "the point found does not land on a repelling cycle"),
+        }
+    }
+}
+
+/// An error raised while saving or loading files, or while loading a user script, that should
+/// be reported to the user rather than abort the program.
+#[derive(Debug)]
+pub enum DynamoError
+{
+    Io(std::io::Error),
+    /// The file was read, but its contents could not be parsed or serialized.
+    Format(String),
+    /// The image could not be encoded or written.
+    Image(String),
+    /// The user script could not be compiled or loaded.
+    Script(String),
+}
+
+impl DynamoError
+{
+    /// Whether repeating the same operation might succeed, e.g. once a locked or missing
+    /// directory becomes available.
+    #[must_use]
+    pub const fn can_retry(&self) -> bool
+    {
+        matches!(self, Self::Io(_) | Self::Image(_))
+    }
+}
+
+impl std::fmt::Display for DynamoError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Format(msg) => write!(f, "invalid file contents: {msg}"),
+            Self::Image(msg) => write!(f, "could not write image: {msg}"),
+            Self::Script(msg) => write!(f, "could not load script: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DynamoError
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)>
+    {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DynamoError
+{
+    fn from(err: std::io::Error) -> Self
+    {
+        Self::Io(err)
+    }
+}
+
+impl From<Box<dyn std::error::Error>> for DynamoError
+{
+    fn from(err: Box<dyn std::error::Error>) -> Self
+    {
+        match err.downcast::<std::io::Error>() {
+            Ok(io_err) => Self::Io(*io_err),
+            Err(err) => Self::Format(err.to_string()),
+        }
+    }
+}
+
+pub type DynamoResult<T> = Result<T, DynamoError>;
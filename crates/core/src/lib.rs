@@ -87,4 +87,75 @@ mod tests
         assert_eq!(out2, val2);
         assert_eq!(out3, val3);
     }
+
+    #[test]
+    fn escape_radius_calibration()
+    {
+        use crate::dynamics::escape_radius_for;
+        use dynamo_common::prelude::*;
+
+        let radius = escape_radius_for(2., ONE);
+        assert!((radius / 1e26 - 1.).abs() < 1e-12);
+
+        // Same radius in the coordinate where the map is monic
+        let radius = escape_radius_for(2., Cplx::new(0.5, 0.));
+        assert!((0.25 * radius / 1e26 - 1.).abs() < 1e-12);
+
+        // The next iterate must not overflow for high degree maps
+        let radius = escape_radius_for(100., ONE);
+        assert!(radius.powf(100.).is_finite());
+        assert!(radius > 1e2);
+    }
+
+    #[test]
+    fn escape_tests()
+    {
+        use crate::dynamics::escape_test::{EscapeTest, EscapeVerdict};
+        use dynamo_common::prelude::*;
+
+        let norm = EscapeTest::NormSqr(4.);
+        assert_eq!(norm.verdict(Cplx::new(1., 1.)), EscapeVerdict::Running);
+        assert_eq!(norm.verdict(Cplx::new(2., 1.)), EscapeVerdict::Escaped);
+        assert_eq!(norm.verdict(Cplx::new(Real::NAN, 0.)), EscapeVerdict::Escaped);
+
+        let strip = EscapeTest::ImagPart {
+            bound: 10.,
+            overflow: 1e6,
+        };
+        assert_eq!(strip.verdict(Cplx::new(1e5, 1.)), EscapeVerdict::Running);
+        assert_eq!(strip.verdict(Cplx::new(0., -11.)), EscapeVerdict::Escaped);
+        assert_eq!(strip.verdict(Cplx::new(1e7, 1.)), EscapeVerdict::Unknown);
+
+        assert_eq!(EscapeTest::Never.verdict(Cplx::new(1e300, 0.)), EscapeVerdict::Running);
+
+        let right = EscapeTest::Custom(|z| {
+            if z.re > 1. {
+                EscapeVerdict::Escaped
+            } else {
+                EscapeVerdict::Running
+            }
+        });
+        assert_eq!(right.verdict(Cplx::new(2., 0.)), EscapeVerdict::Escaped);
+        assert!(right.check::<Cplx, Cplx>(Cplx::new(0., 5.), 3).is_none());
+    }
+
+    #[test]
+    fn dynamo_error_conversions()
+    {
+        use crate::error::DynamoError;
+        use std::error::Error;
+        use std::io;
+
+        let boxed: Box<dyn Error> = Box::new(io::Error::new(io::ErrorKind::NotFound, "gone"));
+        let err = DynamoError::from(boxed);
+        assert!(matches!(&err, DynamoError::Io(e) if e.kind() == io::ErrorKind::NotFound));
+        assert!(err.can_retry());
+        assert!(err.source().is_some());
+
+        let boxed: Box<dyn Error> = "expected a color".into();
+        let err = DynamoError::from(boxed);
+        assert!(matches!(&err, DynamoError::Format(msg) if msg == "expected a color"));
+        assert!(!err.can_retry());
+        assert_eq!(err.to_string(), "invalid file contents: expected a color");
+    }
 }
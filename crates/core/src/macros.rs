@@ -131,22 +131,6 @@ macro_rules! param_map {
     };
 }
 
-#[macro_export]
-macro_rules! default_name {
-    () => {
-        fn name(&self) -> String
-        {
-            let full_struct_name = type_name::<Self>();
-            let parts: Vec<&str> = full_struct_name.split("::").collect();
-            if let Some(struct_name) = parts.last() {
-                format!("{struct_name}")
-            } else {
-                "Unknown".to_owned()
-            }
-        }
-    };
-}
-
 #[macro_export]
 macro_rules! default_bounds {
     () => {
@@ -180,13 +164,13 @@ macro_rules! default_bounds {
 #[macro_export]
 macro_rules! default_bounds_impl {
     ($struct: ty) => {
-        impl FamilyDefaults for $struct
+        impl DisplayDefaults for $struct
         {
             default_bounds!();
         }
     };
     ($struct: ty $(,$args:expr)*) => {
-        impl FamilyDefaults for $struct
+        impl DisplayDefaults for $struct
         {
             default_bounds!($($args),*);
         }
@@ -207,6 +191,7 @@ macro_rules! basic_escape_encoding {
                 return PointInfo::Escaping {
                     potential: iters as IterCountSmooth - 1.,
                     phase: None,
+                    angle: None,
                 };
             }
 
@@ -217,6 +202,7 @@ macro_rules! basic_escape_encoding {
             PointInfo::Escaping {
                 potential,
                 phase: None,
+                angle: None,
             }
         }
     };
@@ -233,6 +219,7 @@ macro_rules! basic_escape_encoding {
                 return PointInfo::Escaping {
                     potential: (iters - $period) as IterCountSmooth,
                     phase,
+                    angle: None,
                 };
             }
 
@@ -241,7 +228,11 @@ macro_rules! basic_escape_encoding {
             let residual = (v / u).log2();
             let potential = ($period as IterCountSmooth)
                 .mul_add(-IterCountSmooth::from(residual), (iters as IterCountSmooth));
-            PointInfo::Escaping { potential, phase }
+            PointInfo::Escaping {
+                potential,
+                phase,
+                angle: None,
+            }
         }
     };
     ($degree: expr, $period: expr) => {
@@ -256,6 +247,7 @@ macro_rules! basic_escape_encoding {
                 return PointInfo::Escaping {
                     potential: IterCountSmooth::from(iters - $period),
                     phase: Some(iters % $period),
+                    angle: None,
                 };
             }
 
@@ -267,12 +259,158 @@ macro_rules! basic_escape_encoding {
             PointInfo::Escaping {
                 potential,
                 phase: Some(iters % $period),
+                angle: None,
+            }
+        }
+    };
+}
+
+/// Take `map`, `map_and_multiplier` and `gradient` from a [`GenericMap`] implementation, with
+/// the derivatives computed by automatic differentiation.
+///
+/// [`GenericMap`]: crate::dynamics::autodiff::GenericMap
+#[macro_export]
+macro_rules! auto_diff_impl {
+    () => {
+        #[inline]
+        fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+        {
+            $crate::dynamics::autodiff::AutoDiff::map_ad(self, z, *c)
+        }
+
+        #[inline]
+        fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+        {
+            $crate::dynamics::autodiff::AutoDiff::map_and_multiplier_ad(self, z, *c)
+        }
+
+        #[inline]
+        fn gradient(&self, z: Self::Var, c: &Self::Param)
+            -> (Self::Var, Self::Deriv, Self::Deriv)
+        {
+            $crate::dynamics::autodiff::AutoDiff::gradient_ad(self, z, *c)
+        }
+    };
+}
+
+/// Provide the preimages of points through an [`InverseMap`] implementation, which enables
+/// inverse iteration and the equilibrium measure.
+///
+/// [`InverseMap`]: crate::dynamics::inverse_iteration::InverseMap
+#[macro_export]
+macro_rules! inverse_map_impl {
+    () => {
+        fn preimages(&self, z: Self::Var, c: &Self::Param) -> Vec<Self::Var>
+        {
+            $crate::dynamics::inverse_iteration::InverseMap::inverse_branches(self, z, c)
+        }
+    };
+}
+
+/// Fill in a `DynamicalFamily` implementation from a [`RealPlane2D`] implementation: the
+/// variable, parameter and derivative types, the map and its derivatives, and the Lyapunov
+/// exponents and attractors of the family.
+///
+/// [`RealPlane2D`]: crate::dynamics::real_plane::RealPlane2D
+#[macro_export]
+macro_rules! real_plane_2d_impl {
+    () => {
+        type Var = Point;
+        type Param = Point;
+        type Deriv = Matrix2x2;
+
+        #[inline]
+        fn param_map(&self, point: Cplx) -> Self::Param
+        {
+            point.into()
+        }
+
+        #[inline]
+        fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+        {
+            $crate::dynamics::real_plane::RealPlane2D::map_2d(self, z, c)
+        }
+
+        #[inline]
+        fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+        {
+            use $crate::dynamics::real_plane::RealPlane2D;
+            (self.map_2d(z, c), self.jacobian(z, c))
+        }
+
+        #[inline]
+        fn gradient(&self, z: Self::Var, c: &Self::Param)
+            -> (Self::Var, Self::Deriv, Self::Deriv)
+        {
+            use $crate::dynamics::real_plane::RealPlane2D;
+            (self.map_2d(z, c), self.jacobian(z, c), self.param_jacobian(z, c))
+        }
+
+        #[inline]
+        fn start_point(&self, _point: Cplx, c: &Self::Param) -> Self::Var
+        {
+            $crate::dynamics::real_plane::RealPlane2D::seed(self, c)
+        }
+
+        fn lyapunov_info(&self, start: Self::Var, c: &Self::Param)
+            -> Option<PointInfo<Self::Deriv>>
+        {
+            Some($crate::dynamics::real_plane::lyapunov_info(self, start, c))
+        }
+
+        fn attractor_seed(&self, c: &Self::Param) -> Option<Self::Var>
+        {
+            Some($crate::dynamics::real_plane::RealPlane2D::seed(self, c))
+        }
+    };
+}
+
+/// Iterate escaping orbits several at a time with SIMD instructions, using a [`SimdMap`]
+/// implementation. For families with complex variable and parameter.
+///
+/// [`SimdMap`]: crate::dynamics::simd::SimdMap
+#[macro_export]
+macro_rules! simd_impl {
+    () => {
+        fn escape_batch(&self, points: &[Cplx])
+            -> Option<$crate::dynamics::simd::BatchResult<Cplx, Cplx>>
+        {
+            // Points with a known outcome are cheaper to compute one at a time
+            let (indices, (starts, params)): (Vec<usize>, (Vec<Cplx>, Vec<Cplx>)) = points
+                .iter()
+                .enumerate()
+                .filter_map(|(i, &point)| {
+                    let param = self.param_map(point);
+                    let start = self.start_point(point, &param);
+                    self.early_bailout(start, &param)
+                        .is_none()
+                        .then_some((i, (start, param)))
+                })
+                .unzip();
+            let batch = self.iterate_batch(&starts, &params, self.min_iter(), self.max_iter())?;
+            let mut results = vec![None; points.len()];
+            for (i, result) in indices.into_iter().zip(batch) {
+                results[i] = result;
             }
+            Some(results)
+        }
+
+        fn iterate_batch(
+            &self,
+            starts: &[Cplx],
+            params: &[Cplx],
+            min_iter: IterCount,
+            max_iter: IterCount,
+        ) -> Option<$crate::dynamics::simd::BatchResult<Cplx, Cplx>>
+        {
+            Some($crate::dynamics::simd::iterate_batch(
+                self, starts, params, min_iter, max_iter,
+            ))
         }
     };
 }
 
 pub use {
-    basic_escape_encoding, basic_plane_impl, default_bounds, default_bounds_impl, default_name,
-    fractal_impl, param_map, point_grid_getters,
+    auto_diff_impl, basic_escape_encoding, basic_plane_impl, default_bounds, default_bounds_impl,
+    fractal_impl, inverse_map_impl, param_map, point_grid_getters, real_plane_2d_impl, simd_impl,
 };
@@ -1,5 +1,5 @@
-use super::{EscapeResult, Orbit};
-use crate::dynamics::EscapeEncoding;
+use super::{exact_cplx, smooth_preperiod, EscapeResult, Orbit};
+use crate::dynamics::{EscapeEncoding, PlaneType};
 use dynamo_common::prelude::*;
 use num_traits::One;
 
@@ -95,11 +95,22 @@ impl<'a, P: EscapeEncoding> DistanceEstimation<'a, P>
             if let Some((period, multiplier)) =
                 self.compute_period(self.periodicity_tolerance.powf(0.75), self.iter as usize)
             {
+                let smooth_preperiod = smooth_preperiod(
+                    self.family,
+                    &self.param,
+                    self.z_slow,
+                    self.z_fast,
+                    self.iter,
+                    period,
+                    self.periodicity_tolerance,
+                );
                 let info = PointInfoPeriodic {
                     preperiod: self.iter,
                     period,
                     multiplier,
                     final_error: error,
+                    smooth_preperiod,
+                    cycle_point: self.z_fast.into(),
                 };
                 self.state = Some(EscapeResult::Periodic {
                     info,
@@ -123,10 +134,53 @@ impl<'a, P: EscapeEncoding> DistanceEstimation<'a, P>
         }
         None
     }
+
+    /// Second derivatives of the map with respect to `z` twice, and to `z` and the parameter,
+    /// from central differences of the first derivatives given by `gradient`.
+    fn second_derivatives(&self, z: Cplx) -> (Cplx, Cplx)
+    {
+        let h = 1e-5 * z.norm().max(1.);
+        let (_, dz_plus, dc_plus) = self.family.gradient((z + h).into(), &self.param);
+        let (_, dz_minus, dc_minus) = self.family.gradient((z - h).into(), &self.param);
+        let scale = 0.5 / h;
+        (
+            (dz_plus.into() - dz_minus.into()) * scale,
+            (dc_plus.into() - dc_minus.into()) * scale,
+        )
+    }
+
+    /// Estimate the distance from the parameter to the bifurcation locus, given a point on the
+    /// attracting cycle its orbit has converged to.
+    ///
+    /// Along with the multiplier of the cycle, we track its derivative with respect to the
+    /// parameter, accounting for the motion of the cycle point. The distance is then roughly
+    /// (1 - |λ|²)/|dλ/dt|, by analogy with the escape distance estimate.
+    #[allow(clippy::similar_names)]
+    fn interior_distance(&self, z: P::Var, period: Period) -> Real
+    {
+        let dc_dt: Cplx = self.dc_dt.into();
+        let mut z: Cplx = z.into();
+        let (mut dz, mut dt, mut dzdz, mut dtdz) = (ONE, ZERO, ZERO, ZERO);
+        for _ in 0..period {
+            let (f, df_dz, df_dc) = self.family.gradient(z.into(), &self.param);
+            let (d2f_dz2, d2f_dzdc) = self.second_derivatives(z);
+            let df_dz: Cplx = df_dz.into();
+            let df_dt = df_dc.into() * dc_dt;
+
+            dtdz = d2f_dz2 * dt * dz + d2f_dzdc * dc_dt * dz + df_dz * dtdz;
+            dzdz = d2f_dz2 * dz * dz + df_dz * dzdz;
+            dt = df_dz * dt + df_dt;
+            dz *= df_dz;
+            z = f.into();
+        }
+        let dmult_dt = dtdz + dzdz * dt / (ONE - dz);
+        (1. - dz.norm_sqr()) / dmult_dt.norm()
+    }
 }
 impl<P: EscapeEncoding> Orbit for DistanceEstimation<'_, P>
 {
     type Outcome = PointInfo<P::Deriv>;
+    type Checkpoint = OrbitCheckpoint<P::Deriv>;
 
     fn reset(&mut self, selection: Cplx)
     {
@@ -145,6 +199,40 @@ impl<P: EscapeEncoding> Orbit for DistanceEstimation<'_, P>
         self.iter = 0;
     }
 
+    fn checkpoint(&self) -> Option<Self::Checkpoint>
+    {
+        if !matches!(self.state, Some(EscapeResult::Bounded(_))) {
+            return None;
+        }
+        Some(OrbitCheckpoint {
+            iter: self.iter,
+            z_slow: exact_cplx(self.z_slow)?,
+            z_fast: exact_cplx(self.z_fast)?,
+            multiplier: self.multiplier,
+            dz_dt: self.dz_dt,
+        })
+    }
+
+    /// The derivative with respect to the parameter of the pixel is found anew, and the others
+    /// are restored along with the orbit.
+    fn resume(&mut self, selection: Cplx, checkpoint: &Self::Checkpoint) -> bool
+    {
+        self.reset(selection);
+        self.iter = checkpoint.iter;
+        self.z_slow = checkpoint.z_slow.into();
+        self.z_fast = checkpoint.z_fast.into();
+        self.multiplier = checkpoint.multiplier;
+        self.dz_dt = checkpoint.dz_dt;
+        // The orbit stopped before its last iterate was checked for escape or, on even
+        // iterations, for a cycle
+        if self.iter % 2 == 1 {
+            self.enforce_stop_condition();
+        } else {
+            self.check_periodicity();
+        }
+        true
+    }
+
     fn run_until_complete(&mut self) -> Self::Outcome
     {
         if let Some(res) = self.family.early_bailout(self.z_fast, &self.param) {
@@ -172,6 +260,18 @@ impl<P: EscapeEncoding> Orbit for DistanceEstimation<'_, P>
             };
         }
 
+        if let Some(EscapeResult::Periodic { info, final_value }) = &self.state {
+            if self.family.plane_type() == PlaneType::Parameter {
+                let distance = self.interior_distance(*final_value, info.period);
+                if distance.is_finite() && distance > 0. {
+                    return PointInfo::InteriorDistanceEstimate {
+                        distance,
+                        period: info.period,
+                    };
+                }
+            }
+        }
+
         #[allow(clippy::unwrap_used)]
         self.family
             .encode_escape_result(self.state.clone().unwrap(), self.z_init, &self.param)
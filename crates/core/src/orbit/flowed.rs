@@ -0,0 +1,51 @@
+use super::{CycleDetected, Orbit};
+use crate::dynamics::flow::ContinuousIteration;
+use crate::dynamics::EscapeEncoding;
+use dynamo_common::prelude::*;
+
+/// Runs the orbit of the image of each point under the time-`time` flow of the map, so that the
+/// picture of a dynamical plane is iterated continuously as the time increases. Points at which
+/// the map embeds in no flow, such as those of the Julia set, are left in place.
+pub struct Flowed<'a, P: EscapeEncoding>
+{
+    family: &'a P,
+    time: Real,
+    orbit: CycleDetected<'a, P>,
+}
+
+impl<'a, P: EscapeEncoding> Flowed<'a, P>
+{
+    pub fn new(family: &'a P, time: Real) -> Self
+    {
+        Self {
+            family,
+            time,
+            orbit: CycleDetected::new(family),
+        }
+    }
+}
+
+impl<P: EscapeEncoding> Orbit for Flowed<'_, P>
+{
+    type Outcome = PointInfo<P::Deriv>;
+    type Checkpoint = OrbitCheckpoint<P::Deriv>;
+
+    fn reset(&mut self, selection: Cplx)
+    {
+        self.orbit.reset(selection);
+        if !self.family.plane_type().is_dynamical() {
+            return;
+        }
+        if let Some(z) = self
+            .family
+            .fractional_iterate(self.orbit.z_init, &self.orbit.param, self.time)
+        {
+            self.orbit.restart_from(z);
+        }
+    }
+
+    fn run_until_complete(&mut self) -> Self::Outcome
+    {
+        self.orbit.run_until_complete()
+    }
+}
@@ -1,4 +1,4 @@
-use super::{EscapeResult, Orbit};
+use super::{exact_cplx, smooth_preperiod, EscapeResult, Orbit};
 use crate::{dynamics::EscapeEncoding, prelude::DynamicalFamily};
 use dynamo_common::prelude::*;
 use num_traits::One;
@@ -47,6 +47,16 @@ impl<'a, P: DynamicalFamily> CycleDetected<'a, P>
         self
     }
 
+    /// Start the orbit over from the point `z`, keeping the parameter.
+    pub fn restart_from(&mut self, z: P::Var)
+    {
+        self.state = None;
+        self.z_init = z;
+        self.z_slow = z;
+        self.z_fast = z;
+        self.iter = 0;
+    }
+
     #[inline]
     fn apply_map_to_slow(&mut self)
     {
@@ -84,11 +94,22 @@ impl<'a, P: DynamicalFamily> CycleDetected<'a, P>
             if let Some((period, multiplier)) =
                 self.compute_period(self.periodicity_tolerance.powf(0.75), self.iter as usize)
             {
+                let smooth_preperiod = smooth_preperiod(
+                    self.family,
+                    &self.param,
+                    self.z_slow,
+                    self.z_fast,
+                    self.iter,
+                    period,
+                    self.periodicity_tolerance,
+                );
                 let info = PointInfoPeriodic {
                     preperiod: self.iter,
                     period,
                     multiplier,
                     final_error: error,
+                    smooth_preperiod,
+                    cycle_point: self.z_fast.into(),
                 };
                 self.state = Some(EscapeResult::Periodic {
                     info,
@@ -117,6 +138,7 @@ impl<'a, P: DynamicalFamily> CycleDetected<'a, P>
 impl<P: EscapeEncoding> Orbit for CycleDetected<'_, P>
 {
     type Outcome = PointInfo<P::Deriv>;
+    type Checkpoint = OrbitCheckpoint<P::Deriv>;
 
     fn run_until_complete(&mut self) -> Self::Outcome
     {
@@ -152,6 +174,36 @@ impl<P: EscapeEncoding> Orbit for CycleDetected<'_, P>
         self.z_fast = z;
         self.iter = 0;
     }
+
+    fn checkpoint(&self) -> Option<Self::Checkpoint>
+    {
+        if !matches!(self.state, Some(EscapeResult::Bounded(_))) {
+            return None;
+        }
+        Some(OrbitCheckpoint {
+            iter: self.iter,
+            z_slow: exact_cplx(self.z_slow)?,
+            z_fast: exact_cplx(self.z_fast)?,
+            multiplier: P::Deriv::one(),
+            dz_dt: P::Deriv::one(),
+        })
+    }
+
+    fn resume(&mut self, selection: Cplx, checkpoint: &Self::Checkpoint) -> bool
+    {
+        self.reset(selection);
+        self.iter = checkpoint.iter;
+        self.z_slow = checkpoint.z_slow.into();
+        self.z_fast = checkpoint.z_fast.into();
+        // The orbit stopped before its last iterate was checked for escape or, on even
+        // iterations, for a cycle
+        if self.iter % 2 == 1 {
+            self.enforce_stop_condition();
+        } else {
+            self.check_periodicity();
+        }
+        true
+    }
 }
 
 impl<P: DynamicalFamily> Iterator for CycleDetected<'_, P>
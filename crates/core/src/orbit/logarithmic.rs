@@ -0,0 +1,106 @@
+use super::{CycleDetected, EscapeResult, Orbit};
+use crate::dynamics::EscapeEncoding;
+use dynamo_common::math_utils::slog;
+use dynamo_common::prelude::*;
+
+/// Real part of logarithmic coordinates past which orbits escape.
+const LOG_ESCAPE_BOUND: Real = 250.;
+
+/// Imaginary part of logarithmic coordinates past which the argument of the point, and so its
+/// image, is lost to precision.
+const LOG_OVERFLOW: Real = 1e15;
+
+/// Real part of logarithmic coordinates below which the point is negligible, whatever its
+/// argument.
+const LOG_UNDERFLOW: Real = -50.;
+
+/// Runs orbits in logarithmic coordinates `w = log z` through the lift of the map given by
+/// [`DynamicalFamily::log_lift`](crate::dynamics::DynamicalFamily::log_lift).
+///
+/// Points deep in the tracts of the map, whose moduli overflow, have logarithmic coordinates of
+/// moderate size, so their orbits can be followed until they are far enough out for the
+/// potential to be read off from `Re w`. The hairs of the Cantor bouquet are then resolved where
+/// ordinary orbits give up. Once a cycle is detected, the orbit is handed back to the usual
+/// coordinates to find its period and multiplier.
+pub struct Logarithmic<'a, P: EscapeEncoding>
+{
+    family: &'a P,
+    periodicity_tolerance: Real,
+    orbit: CycleDetected<'a, P>,
+}
+
+impl<'a, P: EscapeEncoding> Logarithmic<'a, P>
+{
+    pub fn new(family: &'a P) -> Self
+    {
+        Self {
+            family,
+            periodicity_tolerance: family.periodicity_tolerance(),
+            orbit: CycleDetected::new(family),
+        }
+    }
+
+    /// Escaping point whose orbit reached the logarithmic coordinate `w` after `iter` iterations.
+    /// The potential is found as from `z = exp(w)`, using `slog(exp(x)) = 1 + slog(x)`.
+    fn escaping(&self, iter: IterCount, w: Cplx) -> PointInfo<P::Deriv>
+    {
+        let residual = 1. + slog(2. * w.re) - slog(self.family.escape_radius());
+        PointInfo::Escaping {
+            potential: (iter as IterCountSmooth) - residual,
+            phase: None,
+            angle: None,
+        }
+    }
+}
+
+impl<P: EscapeEncoding> Orbit for Logarithmic<'_, P>
+{
+    type Outcome = PointInfo<P::Deriv>;
+    type Checkpoint = OrbitCheckpoint<P::Deriv>;
+
+    fn reset(&mut self, selection: Cplx)
+    {
+        self.orbit.reset(selection);
+    }
+
+    fn run_until_complete(&mut self) -> Self::Outcome
+    {
+        let param = self.orbit.param.clone();
+        let start = self.orbit.z_init;
+        let z_init: Cplx = start.into();
+        let mut w_slow = z_init.ln();
+        if self.family.log_lift(w_slow, &param).is_none() {
+            return self.orbit.run_until_complete();
+        }
+        if let Some(res) = self.family.early_bailout(start, &param) {
+            return res;
+        }
+
+        let lift = |w: Cplx| {
+            self.family
+                .log_lift(w, &param)
+                .unwrap_or(Cplx::new(Real::NAN, Real::NAN))
+        };
+        let mut w_fast = w_slow;
+        for iter in 1..=self.family.max_iter() {
+            if iter % 2 == 1 {
+                w_slow = lift(w_slow);
+            }
+            w_fast = lift(w_fast);
+
+            if w_fast.re > LOG_ESCAPE_BOUND {
+                return self.escaping(iter, w_fast);
+            }
+            if w_fast.is_nan() || (w_fast.re >= LOG_UNDERFLOW && w_fast.im.abs() > LOG_OVERFLOW) {
+                return PointInfo::Unknown;
+            }
+            if iter % 2 == 0 && w_fast.dist_sqr(w_slow) < self.periodicity_tolerance {
+                self.orbit.restart_from(w_fast.exp().into());
+                self.orbit.iter = iter;
+                return self.orbit.run_until_complete();
+            }
+        }
+        self.family
+            .encode_escape_result(EscapeResult::Bounded(w_fast.exp().into()), start, &param)
+    }
+}
@@ -4,13 +4,19 @@ use num_traits::One;
 
 pub mod distance_estimation;
 pub mod floyd;
+pub mod flowed;
+pub mod logarithmic;
 pub mod potential;
 pub mod simple;
+pub mod stochastic;
 
 pub use distance_estimation::DistanceEstimation;
 pub use floyd::CycleDetected;
+pub use flowed::Flowed;
+pub use logarithmic::Logarithmic;
 pub use potential::Potential;
 pub use simple::Simple;
+pub use stochastic::Stochastic;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -34,6 +40,60 @@ pub enum EscapeResult<V, D>
     Unknown,
 }
 
+/// Estimate the fractional iteration at which the orbit came within `tolerance` of its cycle,
+/// given the slow and fast points at the iteration `iter` where the cycle was detected.
+///
+/// The distance between the slow and fast points is only small when they lie near the same
+/// point of the cycle, so cycles are detected at most once every `2 * period` iterations. To
+/// interpolate between detections, we follow the log error one and two periods further, fit a
+/// parabola through the three samples, and run it back to where it crosses the tolerance. A
+/// linear fit cannot follow the changing rate of convergence, and leaves visible bands.
+pub(crate) fn smooth_preperiod<P: DynamicalFamily + ?Sized>(
+    family: &P,
+    param: &P::Param,
+    mut z_slow: P::Var,
+    mut z_fast: P::Var,
+    iter: IterCount,
+    period: Period,
+    tolerance: Real,
+) -> IterCountSmooth
+{
+    let mut log_errors = [0.; 3];
+    for (i, log_error) in log_errors.iter_mut().enumerate() {
+        if i > 0 {
+            for _ in 0..period {
+                z_slow = family.map(z_slow, param);
+                z_fast = family.map(family.map(z_fast, param), param);
+            }
+        }
+        *log_error = z_fast.dist_sqr(z_slow).ln();
+    }
+    let [l0, l1, l2] = log_errors;
+
+    // Parabola a t^2 + b t + c through the samples at t = 0, 1, 2, less the log tolerance
+    let a = 0.5 * (l2 - 2. * l1 + l0);
+    let b = l1 - l0 - a;
+    let c = l0 - tolerance.ln();
+
+    let linear = -c / (l1 - l0);
+    let disc = b.mul_add(b, -4. * a * c);
+    let t = if a.abs() <= 1e-8 * b.abs() || disc < 0. {
+        linear
+    } else {
+        // Numerically stable roots; take the one nearest the linear estimate
+        let q = -0.5 * (b + disc.sqrt().copysign(b));
+        let (r0, r1) = (q / a, c / q);
+        if (r0 - linear).abs() <= (r1 - linear).abs() {
+            r0
+        } else {
+            r1
+        }
+    };
+    let t = if t.is_finite() { t.clamp(-1., 0.) } else { 0. };
+
+    t.mul_add(2. * Real::from(period), iter as IterCountSmooth)
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Info<P, V, D>
@@ -41,6 +101,47 @@ pub struct Info<P, V, D>
     pub param: P,
     pub start: V,
     pub result: PointInfo<D>,
+    /// Periods of the nested small copies of the parameter plane containing the parameter, as
+    /// found by [`renormalization_periods`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub renormalization: Vec<Period>,
+}
+
+/// Periods of the renormalizations visible in an attracting cycle, in increasing order, each
+/// dividing the next and the period of the cycle.
+///
+/// The return map of period `p` is taken to be renormalizable when the points of the cycle visited
+/// every `p` steps from the one nearest the critical point are all nearer to the critical point
+/// than the others, so that they lie in a small Julia set about it.
+#[must_use]
+pub fn renormalization_periods(cycle: &[Cplx], critical_point: Cplx) -> Vec<Period>
+{
+    let n = cycle.len();
+    let distance = |i: usize| (cycle[i % n] - critical_point).norm();
+    let Some(nearest) = (0..n).min_by(|&i, &j| distance(i).total_cmp(&distance(j))) else {
+        return Vec::new();
+    };
+
+    let mut periods = Vec::new();
+    let mut last = 1;
+    for p in 2..n {
+        if !n.is_multiple_of(p) || !p.is_multiple_of(last) {
+            continue;
+        }
+        let inner = (0..n)
+            .step_by(p)
+            .map(|k| distance(nearest + k))
+            .fold(0., Real::max);
+        let outer = (0..n)
+            .filter(|k| !k.is_multiple_of(p))
+            .map(|k| distance(nearest + k))
+            .fold(Real::INFINITY, Real::min);
+        if inner < outer {
+            periods.push(p as Period);
+            last = p;
+        }
+    }
+    periods
 }
 
 #[derive(Clone, Debug)]
@@ -50,6 +151,18 @@ pub struct OrbitAndInfo<P, V, D>
     pub info: Info<P, V, D>,
 }
 
+/// A point of an orbit, with the derivative of the map at the point and the derivative of the
+/// iterate carrying the first point of the orbit to it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OrbitStep
+{
+    pub iter: IterCount,
+    pub z: Cplx,
+    pub multiplier: Cplx,
+    pub derivative: Cplx,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct OrbitSummaryConf
 {
@@ -94,11 +207,6 @@ where
 {
     pub fn summary(&self, conf: &OrbitSummaryConf) -> String
     {
-        use PointInfo::{
-            Bounded, DistanceEstimate, Escaping, MarkedPoint, Periodic, PeriodicKnownPotential,
-            Unknown, Wandering,
-        };
-
         let param_desc = self
             .param
             .describe(&conf.parameter_conf())
@@ -110,40 +218,115 @@ where
             .map(|d| format!("Start: {d}\n"))
             .unwrap_or_default();
 
-        let result_summary = match &self.result {
+        format!(
+            "{start_desc}\
+            {param_desc}\
+            {result_summary}",
+            result_summary = self.classification(),
+        )
+    }
+
+    /// The period of the cycle factored through the small copies containing the parameter, on a
+    /// line of its own, e.g. `6 = 2 × 3, in a small copy of period 2`.
+    fn describe_renormalization(&self, period: Period) -> String
+    {
+        let Some(&innermost) = self.renormalization.last() else {
+            return String::new();
+        };
+        let mut factors = vec![];
+        let mut last = 1;
+        for &p in self.renormalization.iter().chain([&period]) {
+            factors.push((p / last).to_string());
+            last = p;
+        }
+        let copies = if self.renormalization.len() == 1 {
+            format!("in a small copy of period {innermost}")
+        } else {
+            let periods: Vec<_> = self.renormalization.iter().map(Period::to_string).collect();
+            format!("in small copies of periods {}", periods.join(", "))
+        };
+        format!("\nRenormalized: {period} = {}, {copies}", factors.join(" × "))
+    }
+
+    /// Description of what became of the orbit.
+    pub fn classification(&self) -> String
+    {
+        use PointInfo::{
+            Bounded, DistanceEstimate, EscapeProbability, Escaping, InteriorDistanceEstimate,
+            FastEscaping, Lyapunov, MarkedPoint, Periodic, PeriodicKnownPotential, Unknown,
+            Wandering,
+        };
+
+        match &self.result {
             Escaping {
                 potential,
                 phase: None,
+                ..
             } => format!("Escaped, potential: {potential:.DISPLAY_PREC$}"),
             Escaping {
                 potential,
                 phase: Some(p),
+                ..
             } => format!("Escaped with phase {p}, potential: {potential:.DISPLAY_PREC$}"),
             DistanceEstimate { distance, phase } => {
                 format!("Escaped with phase {phase}, est. distance: {distance:.DISPLAY_PREC$}")
             }
-            Periodic(data) | MarkedPoint { data, .. } => data.to_string(),
-            PeriodicKnownPotential(data) => data.to_string(),
+            InteriorDistanceEstimate { distance, period } => {
+                format!("Periodic with period {period}, est. distance: {distance:.DISPLAY_PREC$}")
+            }
+            Periodic(data) | MarkedPoint { data, .. } => {
+                format!("{data}{}", self.describe_renormalization(data.period))
+            }
+            PeriodicKnownPotential(data) => {
+                format!("{data}{}", self.describe_renormalization(data.period))
+            }
             Bounded => "Bounded (no cycle detected or period too high)".to_owned(),
             Wandering => "Wandering (appears to escape very slowly)".to_owned(),
+            FastEscaping { level } => {
+                format!("Fast escaping, enters the fast escaping set at iterate {level}")
+            }
+            Lyapunov { exponent } => {
+                let kind = if *exponent > 0. { "Chaotic" } else { "Stable" };
+                format!("{kind}, Lyapunov exponent: {exponent:.DISPLAY_PREC$}")
+            }
+            EscapeProbability { probability } => {
+                format!("Noisy orbits escaped with probability {probability:.DISPLAY_PREC$}")
+            }
             Unknown => {
                 "Unknown result, likely due to insufficient floting-point precision".to_owned()
             }
-        };
-        format!(
-            "{start_desc}\
-            {param_desc}\
-            {result_summary}",
-        )
+        }
     }
 }
 
 pub trait Orbit: Send
 {
     type Outcome;
+    type Checkpoint;
 
     /// Re-initialize aan orbit.
     fn reset(&mut self, selection: Cplx);
 
     fn run_until_complete(&mut self) -> Self::Outcome;
+
+    /// State of the orbit last run, if it ran out of iterations and can be resumed later.
+    fn checkpoint(&self) -> Option<Self::Checkpoint>
+    {
+        None
+    }
+
+    /// Re-initialize the orbit of `selection` at a state saved by [`Orbit::checkpoint`]. Returns
+    /// false if the orbit cannot be resumed, in which case it should be reset instead.
+    fn resume(&mut self, _selection: Cplx, _checkpoint: &Self::Checkpoint) -> bool
+    {
+        false
+    }
+}
+
+/// The value of `z` as a complex number, if it can be restored from one exactly, so that orbits
+/// through it can be checkpointed.
+fn exact_cplx<V: Variable>(z: V) -> Option<Cplx>
+{
+    let w: Cplx = z.into();
+    (V::from(w).dist_sqr(z) == 0.).then_some(w)
 }
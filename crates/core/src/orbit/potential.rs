@@ -1,6 +1,6 @@
 use std::f64::consts::LN_2;
 
-use super::{EscapeResult, Orbit};
+use super::{smooth_preperiod, EscapeResult, Orbit};
 use crate::dynamics::InfinityFirstReturnMap;
 use dynamo_common::prelude::*;
 use num_traits::One;
@@ -117,11 +117,22 @@ impl<'a, P: InfinityFirstReturnMap + ?Sized> Potential<'a, P>
             if let Some((period, multiplier)) =
                 self.compute_period(self.periodicity_tolerance.powf(0.75), self.iter as usize)
             {
+                let smooth_preperiod = smooth_preperiod(
+                    self.family,
+                    &self.param,
+                    self.z_slow,
+                    self.z_fast,
+                    self.iter,
+                    period,
+                    self.periodicity_tolerance,
+                );
                 let info = PointInfoPeriodic {
                     preperiod: self.iter,
                     period,
                     multiplier,
                     final_error: error,
+                    smooth_preperiod,
+                    cycle_point: self.z_fast.into(),
                 };
                 self.state = Some(EscapeResult::Periodic {
                     info,
@@ -264,6 +275,7 @@ impl<'a, P: InfinityFirstReturnMap + ?Sized> Potential<'a, P>
 impl<P: InfinityFirstReturnMap + ?Sized> Orbit for Potential<'_, P>
 {
     type Outcome = Option<(Real, Cplx)>;
+    type Checkpoint = OrbitCheckpoint<P::Deriv>;
 
     fn reset(&mut self, selection: Cplx)
     {
@@ -0,0 +1,114 @@
+use super::{EscapeResult, Orbit};
+use crate::dynamics::{DynamicalFamily, EscapeEncoding};
+use dynamo_common::prelude::*;
+
+/// Noise amplitude used when stochastic iteration is first switched on.
+pub const DEFAULT_NOISE: Real = 0.01;
+
+/// Number of noisy orbits run from each point by default.
+pub const DEFAULT_TRIALS: u32 = 16;
+
+/// Pseudo-random generator for the noise added along stochastic orbits. It is seeded by the
+/// point whose orbits are run, so that images are reproducible and do not depend on how the
+/// plane is split into tiles.
+#[derive(Clone, Copy, Debug)]
+pub struct NoiseSource(u64);
+
+impl NoiseSource
+{
+    #[must_use]
+    pub const fn seeded_by(point: Cplx) -> Self
+    {
+        Self(point.re.to_bits() ^ point.im.to_bits().rotate_left(32))
+    }
+
+    /// Next output of the SplitMix64 generator.
+    pub fn next_u64(&mut self) -> u64
+    {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut h = self.0;
+        h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        h ^ (h >> 31)
+    }
+
+    /// Uniform sample of (0, 1].
+    fn next_unit(&mut self) -> Real
+    {
+        ((self.next_u64() >> 11) + 1) as Real / (1_u64 << 53) as Real
+    }
+
+    /// Standard complex Gaussian, with mean 0 and `E|ξ|^2 = 1`, by the Box-Muller transform.
+    pub fn next_gaussian(&mut self) -> Cplx
+    {
+        let radius = (-self.next_unit().ln()).sqrt();
+        Cplx::from_polar(radius, TAU * self.next_unit())
+    }
+}
+
+/// Runs `trials` orbits from each point, adding independent complex Gaussian noise of amplitude
+/// `sigma` after every application of the map, and reports the fraction of them that escape.
+pub struct Stochastic<'a, P: DynamicalFamily>
+{
+    family: &'a P,
+    sigma: Real,
+    trials: u32,
+    noise: NoiseSource,
+    pub param: P::Param,
+    pub z_init: P::Var,
+}
+
+impl<'a, P: DynamicalFamily> Stochastic<'a, P>
+{
+    pub fn new(family: &'a P, sigma: Real, trials: u32) -> Self
+    {
+        Self {
+            family,
+            sigma,
+            trials: trials.max(1),
+            noise: NoiseSource(0),
+            param: P::Param::default(),
+            z_init: P::Var::default(),
+        }
+    }
+
+    /// Whether a single noisy orbit from the start point escapes.
+    fn run_trial(&mut self) -> bool
+    {
+        let mut z = self.z_init;
+        let mut iter = 0;
+        loop {
+            iter += 1;
+            // Variables are only known to be closed under subtraction, so the noise is added by
+            // subtracting its negative
+            let noise = -self.sigma * self.noise.next_gaussian();
+            z = self.family.map(z, &self.param) - P::Var::from(noise);
+            match self.family.stop_condition(z, &self.param, iter) {
+                Some(EscapeResult::Escaped { .. }) => return true,
+                Some(_) => return false,
+                None => {}
+            }
+        }
+    }
+}
+
+impl<P: EscapeEncoding> Orbit for Stochastic<'_, P>
+{
+    type Outcome = PointInfo<P::Deriv>;
+    type Checkpoint = OrbitCheckpoint<P::Deriv>;
+
+    fn reset(&mut self, selection: Cplx)
+    {
+        self.param = self.family.param_map(selection);
+        self.z_init = self.family.start_point(selection, &self.param);
+        self.noise = NoiseSource::seeded_by(selection);
+    }
+
+    fn run_until_complete(&mut self) -> Self::Outcome
+    {
+        let escaped = (0..self.trials).filter(|_| self.run_trial()).count();
+        PointInfo::EscapeProbability {
+            probability: escaped as Real / Real::from(self.trials),
+        }
+    }
+}
@@ -1,5 +1,17 @@
+pub use crate::dynamics::autodiff::{AutoDiff, GenericMap};
+pub use crate::dynamics::composition::{ComposedFamily, Mobius};
 pub use crate::dynamics::covering_maps::{CoveringMap, HasDynamicalCovers};
+pub use crate::dynamics::escape_test::{EscapeTest, EscapeVerdict};
+pub use crate::dynamics::flow::{ContinuousIteration, FlowChart};
+pub use crate::dynamics::inverse_iteration::{InverseMap, PreimageNode};
+pub use crate::dynamics::real_plane::RealPlane2D;
 pub use crate::dynamics::julia::JuliaSet;
+pub use crate::dynamics::misiurewicz::MisiurewiczSpiral;
+pub use crate::dynamics::multiplier_locus::Bifurcation;
+pub use crate::dynamics::pcf::{LandedParameter, PcfParameter};
+pub use crate::dynamics::perturbation::{Perturbation, Perturbed, PerturbedParams};
+pub use crate::dynamics::simd::{CplxLanes, SimdMap};
+pub use crate::dynamics::skew_product::{SkewBase, SkewFiber, SkewProduct};
 pub use crate::dynamics::*;
 pub use crate::macros::*;
 pub use crate::orbit::{self, EscapeResult, Orbit};
@@ -1,5 +1,6 @@
 use crate::{marked_points::ContourType, pane::id::PaneSelection};
-use dynamo_color::{IncoloringAlgorithm, Palette};
+use dynamo_color::{IncoloringAlgorithm, Palette, Transparency};
+use dynamo_common::point_grid::Bounds;
 use dynamo_common::types::{IterCountSmooth, Period};
 
 #[cfg(feature = "serde")]
@@ -8,61 +9,129 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
 pub enum Action
 {
     // UI control
     Quit,
     Close,
     NewTab,
+    OpenRenderQueue,
+    OpenShortcutEditor,
+    OpenCommandPalette,
+    OpenPreferences,
+    OpenBookmarks,
     SaveImage(PaneSelection),
+    SaveTransparentImage(PaneSelection, Transparency),
     SavePalette(PaneSelection),
     LoadPalette(PaneSelection),
+    SaveData(PaneSelection),
+    OpenData(PaneSelection),
+    SaveOrbit(PaneSelection),
+    SaveRays(PaneSelection),
+    SaveBandContours(PaneSelection),
+    LoadRays(PaneSelection),
+    LoadPoints(PaneSelection),
+    ExportPcfCatalog,
     // Annotation toggles
     ToggleSelectionMarker,
     ToggleCritical,
     ToggleMarked(PaneSelection),
     ToggleCycles(PaneSelection, Period),
+    TogglePeriodLabels(PaneSelection),
+    ToggleBandContours(PaneSelection),
     // Dynamics
     FindPeriodicPoint,
+    FindMultiplier,
+    AnalyzeMisiurewicz,
+    TraceMultiplierLocus,
+    Tune,
     MapSelection,
     EnterCoordinates,
     DrawOrbit,
     ClearOrbit,
+    ToggleOrbitPlayback,
+    PlayPauseOrbit,
+    StepOrbit(isize),
+    TogglePreimageTree,
     DrawExternalRay
     {
         include_orbit: bool,
         select_landing_point: bool,
     },
     DrawRaysOfPeriod,
+    DrawRayBatch,
+    OpenAngleCalculator,
     DrawContour(ContourType),
     DrawAuxContours,
+    DrawCriticalCurves,
+    DrawComponentBoundary,
+    EditLabel,
+    ToggleRuler,
     ClearRays,
     ClearEquipotentials,
     ClearCurves,
+    ClearLoadedPoints,
+    ClearLabels,
     StopFollowing,
     ResetSelection,
     ResetView,
+    ToggleTrail,
+    ShowTrail,
+    ClearTrail,
     // Image controls
     ToggleLiveMode,
+    ToggleComparison,
+    ToggleMinimap,
+    ToggleStatistics(PaneSelection),
+    ToggleAttractorInventory(PaneSelection),
+    TogglePcfDatabase(PaneSelection),
+    ToggleOrbitTooltip,
+    ToggleOrbitTrace(PaneSelection),
+    ToggleEquilibriumMeasure(PaneSelection),
+    ToggleAttractorDensity(PaneSelection),
+    EstimateDimension,
+    EstimateArea,
     CycleActivePlane,
+    ChangeCoordinates,
     PromptImageHeight,
     Pan(f64, f64),
     Zoom(f64),
     CenterOnSelection,
+    SetBounds(PaneSelection, Bounds),
     ScaleMaxIter(IterCountSmooth),
     // Coloring
-    RandomizePalette,
+    RandomizePalette(PaneSelection),
+    TogglePaletteSync,
+    TogglePaletteGenerator,
+    TogglePinnedPeriodColors,
+    CycleColorVisionFilter,
+    ToggleLayers(PaneSelection),
+    ToggleLighting(PaneSelection),
     SetPalette(Palette),
-    SetPaletteWhite,
-    SetPaletteBlack,
+    SetPaletteWhite(PaneSelection),
+    SetPaletteBlack(PaneSelection),
     SetColoring(IncoloringAlgorithm),
     SetColoringInternalPotential,
     SetColoringPotentialPeriod,
     SetColoringPreperiodPeriod,
     ScalePalettePeriod(f64),
     ShiftPalettePhase(f64),
+    ToggleColorCycling(PaneSelection),
     ToggleEscapePhaseColoring,
+    ToggleBinaryDecomposition,
+    ScaleDecompositionSectors(f64),
+    CycleOutcoloring,
+    ToggleWringAnimation,
     CycleComputeMode(PaneSelection, ChangeBoolean),
+    ToggleInverseIteration(PaneSelection),
+    ToggleLyapunov(PaneSelection),
+    ToggleBasins(PaneSelection),
+    ToggleStochastic(PaneSelection),
+    ScaleNoise(f64),
+    ToggleFlow(PaneSelection),
+    ToggleLogCoordinates(PaneSelection),
+    ToggleAdaptiveIterations(PaneSelection),
 }
 impl Action
 {
@@ -75,9 +144,56 @@ impl Action
             Self::Quit => "Exit the application.".to_owned(),
             Self::Close => "Close the current tab.".to_owned(),
             Self::NewTab => "Open a new tab.".to_owned(),
+            Self::OpenRenderQueue => {
+                "Show the queue of images being rendered in the background.".to_owned()
+            }
+            Self::OpenShortcutEditor => "Rebind the keyboard shortcuts.".to_owned(),
+            Self::OpenCommandPalette => "Search for an action or fractal to run.".to_owned(),
+            Self::OpenPreferences => "Edit the defaults used for new tabs.".to_owned(),
+            Self::OpenBookmarks => "Bookmark the current view, or manage saved bookmarks.".to_owned(),
             Self::SaveImage(pane_id) => format!("Save the {pane_id} image to a file."),
+            Self::SaveTransparentImage(pane_id, transparency) => {
+                let points = match transparency {
+                    Transparency::Opaque => "no",
+                    Transparency::Escaping => "escaping",
+                    Transparency::Interior => "interior",
+                };
+                format!(
+                    "Save the {pane_id} image to a PNG file with {points} points transparent, \
+                        to be laid over other images."
+                )
+            }
             Self::SavePalette(pane_id) => format!("Save the {pane_id} palette to a file."),
             Self::LoadPalette(pane_id) => format!("Load palette for {pane_id} from file"),
+            Self::SaveData(pane_id) => {
+                format!("Save the raw {pane_id} iteration data to a file for later recoloring.")
+            }
+            Self::OpenData(pane_id) => {
+                format!("Open raw iteration data in the {pane_id} image without recomputing.")
+            }
+            Self::SaveOrbit(pane_id) => {
+                format!("Save the orbit of the {pane_id} selection, with derivatives, to a file.")
+            }
+            Self::SaveRays(pane_id) => {
+                format!("Save the rays shown on the {pane_id} image to a file.")
+            }
+            Self::SaveBandContours(pane_id) => {
+                format!(
+                    "Save the boundaries between bands of escaping points on the {pane_id} image \
+                    as vector graphics."
+                )
+            }
+            Self::LoadRays(pane_id) => {
+                format!("Show rays saved to a file on the {pane_id} image without retracing them.")
+            }
+            Self::LoadPoints(pane_id) => {
+                format!("Mark points listed in a CSV or JSON file on the {pane_id} image.")
+            }
+            Self::ExportPcfCatalog => {
+                "Write the centers and Misiurewicz parameters reached by parameter rays of bounded \
+                    preperiod and period to a CSV file, with their angles."
+                    .to_owned()
+            }
 
             // Annotation Toggles
             Self::ToggleSelectionMarker => "Toggle selection marker on active image.".to_owned(),
@@ -88,12 +204,39 @@ impl Action
             Self::ToggleCycles(pane_id, period) => {
                 format!("Toggle known cycles (or component centers) of period {period} on {pane_id} image.")
             }
+            Self::TogglePeriodLabels(pane_id) => {
+                format!("Label each hyperbolic component with its period on {pane_id} image.")
+            }
+            Self::ToggleBandContours(pane_id) => {
+                format!(
+                    "Draw the boundaries between bands of escaping points as curves on {pane_id} \
+                    image."
+                )
+            }
 
             // Dynamics
             Self::FindPeriodicPoint => {
                 "Find and select a nearby preperiodic/periodic/pcf point on the active image."
                     .to_owned()
             }
+            Self::FindMultiplier => {
+                "Find and select a nearby parameter where a cycle of given period has a given \
+                    multiplier."
+                    .to_owned()
+            }
+            Self::Tune => {
+                "Jump into a small copy of the Mandelbrot set, or out of one, by external angle."
+                    .to_owned()
+            }
+            Self::AnalyzeMisiurewicz => {
+                "Select a nearby Misiurewicz point and draw the spiral of its self-similarity."
+                    .to_owned()
+            }
+            Self::TraceMultiplierLocus => {
+                "Trace the boundary of the hyperbolic component containing the selection, along \
+                    which its cycle has multiplier of modulus 1."
+                    .to_owned()
+            }
             Self::EnterCoordinates => {
                 "Enter coordinates to select a point on active image.".to_owned()
             }
@@ -104,6 +247,24 @@ impl Action
                 "Draw the orbit of currently selected point on dynamical plane.".to_owned()
             }
             Self::ClearOrbit => "Hide orbit from dynamical plane.".to_owned(),
+            Self::ToggleOrbitPlayback => {
+                "Draw the orbit one iterate at a time, highlighting the iterate reached, or all at \
+                    once."
+                    .to_owned()
+            }
+            Self::PlayPauseOrbit => "Play or pause stepping through the orbit.".to_owned(),
+            Self::TogglePreimageTree => {
+                "Mark the tree of preimages of the selected point on dynamical plane, which \
+                    accumulates on the Julia set."
+                    .to_owned()
+            }
+            Self::StepOrbit(steps) => {
+                if *steps < 0 {
+                    "Step back to the previous iterate of the orbit.".to_owned()
+                } else {
+                    "Step forward to the next iterate of the orbit.".to_owned()
+                }
+            }
             Self::DrawExternalRay {
                 include_orbit,
                 select_landing_point,
@@ -118,6 +279,10 @@ impl Action
                 }
             }
             Self::DrawRaysOfPeriod => "Draw all rays of a given period and preperiod.".to_owned(),
+            Self::DrawRayBatch => "Draw the rays at a list or pattern of angles.".to_owned(),
+            Self::OpenAngleCalculator => {
+                "Show the orbit and kneading sequence of an angle, and draw its rays.".to_owned()
+            }
             Self::DrawContour(contour_type) => match contour_type {
                 ContourType::Equipotential => "Draw equipotential through selection.".to_owned(),
                 ContourType::Multiplier(..) => {
@@ -129,21 +294,85 @@ impl Action
                 ContourType::InwardRay => {
                     "Try to draw an external ray inwards from the selection.".to_owned()
                 }
+                ContourType::CriticalRelation(steps) => format!(
+                    "Draw the curve through the selection on which iterate {steps} of the \
+                        critical point stays as far from the nearest critical or co-critical \
+                        point."
+                ),
             },
             Self::DrawAuxContours => "Draw contours for the multiplier map a dynamical variety.".to_owned(),
+            Self::DrawCriticalCurves => {
+                "Draw the curves through the selection on which the first few iterates of the \
+                    critical point stay as far from the nearest critical or co-critical point."
+                    .to_owned()
+            }
+            Self::DrawComponentBoundary => {
+                "Outline the hyperbolic component containing the selection, as seen in the image."
+                    .to_owned()
+            }
+            Self::EditLabel => "Write, edit or remove a text label at the selection.".to_owned(),
+            Self::ToggleRuler => {
+                "Measure the distance and angle between two points picked by clicking.".to_owned()
+            }
             Self::ClearRays => "Clear all external rays on active image.".to_owned(),
             Self::ClearEquipotentials => "Clear all equipotentials on active image.".to_owned(),
             Self::ClearCurves => "Clear all curves on active image.".to_owned(),
+            Self::ClearLoadedPoints => "Clear points loaded from files on active image.".to_owned(),
+            Self::ClearLabels => "Clear all text labels on active image.".to_owned(),
             Self::StopFollowing => "Stop following points around.".to_owned(),
             Self::ResetSelection => "Reset selection to default on active image.".to_owned(),
             Self::ResetView => "Reset bounds and selection to default on active image.".to_owned(),
+            Self::ToggleTrail => "Toggle markers at previously selected parameters.".to_owned(),
+            Self::ShowTrail => "List previously selected parameters to revisit them.".to_owned(),
+            Self::ClearTrail => "Forget previously selected parameters.".to_owned(),
 
             // Image Controls
             Self::ToggleLiveMode => {
                 "Toggle \"live Julia mode\", in which child plane changes with cursor movement."
                     .to_owned()
             }
+            Self::ToggleComparison => {
+                "Compare the child plane with that of a second parameter, chosen by shift-clicking."
+                    .to_owned()
+            }
+            Self::ToggleMinimap => {
+                "Show an overview of each image in its corner. Click on it to jump there.".to_owned()
+            }
+            Self::EstimateDimension => {
+                "Estimate the box-counting dimension of the boundary visible in the active image."
+                    .to_owned()
+            }
+            Self::EstimateArea => {
+                "Estimate the area of the points in view that do not escape on the active image."
+                    .to_owned()
+            }
+            Self::ToggleStatistics(pane_id) => {
+                format!("Show histograms of periods and escape times on {pane_id} image.")
+            }
+            Self::ToggleAttractorInventory(pane_id) => {
+                format!("List the attracting cycles whose basins are colored on {pane_id} image.")
+            }
+            Self::TogglePcfDatabase(pane_id) => {
+                format!("List the postcritically finite parameters in view on {pane_id} image.")
+            }
+            Self::ToggleOrbitTooltip => {
+                "Summarize the orbit of the point under the pointer on the parent plane after a \
+                    short pause."
+                    .to_owned()
+            }
+            Self::ToggleOrbitTrace(pane_id) => {
+                format!("Plot the selected orbit against time on {pane_id} image.")
+            }
+            Self::ToggleEquilibriumMeasure(pane_id) => {
+                format!("Show the equilibrium measure of the Julia set on {pane_id} image.")
+            }
+            Self::ToggleAttractorDensity(pane_id) => {
+                format!("Show the density of a long orbit on the attractor on {pane_id} image.")
+            }
             Self::CycleActivePlane => "Cycle through different planes of the fractal.".to_owned(),
+            Self::ChangeCoordinates => {
+                "Apply a Mobius change of coordinates to the active image.".to_owned()
+            }
             Self::PromptImageHeight => "Prompt to set the height of the fractal image.".to_owned(),
             Self::Pan(x, y) => {
                 if *x == 0. {
@@ -166,6 +395,9 @@ impl Action
                 format!("Zoom {} (scale: {:.2})", in_or_out(*scale), *scale)
             }
             Self::CenterOnSelection => "Center view on selected point.".to_owned(),
+            Self::SetBounds(pane_selection, _) => {
+                format!("Set the bounds of the{pane_selection} image.")
+            }
             Self::ScaleMaxIter(scale) => {
                 format!(
                     "{} max iterations on active image (factor: {scale})",
@@ -174,12 +406,37 @@ impl Action
             }
 
             // Coloring
-            Self::RandomizePalette => "Randomize the color palette.".to_owned(),
+            Self::RandomizePalette(pane_id) => {
+                format!("Randomize the color palette on{pane_id}.")
+            }
+            Self::TogglePaletteSync => {
+                "Keep the palettes of both panes the same, or let them be changed separately."
+                    .to_owned()
+            }
+            Self::TogglePaletteGenerator => {
+                "Show the constraints on random palettes, and the seeds of recent ones.".to_owned()
+            }
+            Self::TogglePinnedPeriodColors => {
+                "Fix the colors of the first periods, so that they stay the same when the palette \
+                    changes, or free them if they are fixed."
+                    .to_owned()
+            }
+            Self::CycleColorVisionFilter => {
+                "Preview the images as seen with deuteranopia, protanopia, or normal vision, in \
+                    turn."
+                    .to_owned()
+            }
+            Self::ToggleLayers(pane_id) => {
+                format!("Edit the colorings layered over {pane_id} image.")
+            }
+            Self::ToggleLighting(pane_id) => {
+                format!("Shade {pane_id} image as a relief lit from one side.")
+            }
             Self::SetPalette(_) => "Set the color palette.".to_owned(),
-            Self::SetPaletteWhite => "Use black on white palette.".to_owned(),
-            Self::SetPaletteBlack => "Use white on black palette.".to_owned(),
+            Self::SetPaletteWhite(pane_id) => format!("Use black on white palette on{pane_id}."),
+            Self::SetPaletteBlack(pane_id) => format!("Use white on black palette on{pane_id}."),
             Self::SetColoring(algorithm) => {
-                use IncoloringAlgorithm::{InternalPotential, Multiplier, Period, PeriodMultiplier, PotentialAndPeriod, Preperiod, PreperiodPeriod, Solid};
+                use IncoloringAlgorithm::{CycleLength, InternalPotential, Multiplier, Period, PeriodMultiplier, PotentialAndPeriod, Preperiod, PreperiodPeriod, PreperiodPeriodSmooth, Solid};
                 let desc = match algorithm {
                     Solid => "Color bounded components black.",
                     Period => "Color bounded components by period",
@@ -192,9 +449,15 @@ impl Action
                     PreperiodPeriod { .. } => {
                         "Color bounded components by period and convergence time"
                     }
+                    PreperiodPeriodSmooth { .. } => {
+                        "Color bounded components by period and smoothed convergence time"
+                    }
                     PotentialAndPeriod { .. } => {
                         "Color bounded components by period and internal potential"
                     }
+                    CycleLength { .. } => {
+                        "Color bounded components by cycle length relative to the field size"
+                    }
                 };
                 desc.to_owned()
             }
@@ -211,14 +474,74 @@ impl Action
                 format!("{} the period of the color palette.", inc_or_dec(*scale))
             }
             Self::ShiftPalettePhase(_) => "Shift the phase of the color palette.".to_owned(),
+            Self::ToggleColorCycling(pane_id) => {
+                format!("Cycle the colors of the palette on{pane_id} continuously.")
+            }
             Self::ToggleEscapePhaseColoring => {
                 "Toggle coloring based on phase at time of escape.".to_owned()
             }
+            Self::ToggleBinaryDecomposition => {
+                "Toggle shading escaping points by the angular sector of their last iterate, \
+                    which traces out the external angles."
+                    .to_owned()
+            }
+            Self::CycleOutcoloring => {
+                "Color the active image by the next user-defined coloring registered, or by the \
+                    built-in coloring after the last one."
+                    .to_owned()
+            }
+            Self::ScaleDecompositionSectors(scale) => {
+                format!(
+                    "{} the number of sectors in the binary decomposition (factor: {scale})",
+                    inc_or_dec(*scale)
+                )
+            }
+            Self::ToggleWringAnimation => {
+                "Animate a wring of the exterior coloring, which alternately stretches and \
+                    compresses the escape rates (experimental)."
+                    .to_owned()
+            }
             Self::CycleComputeMode(_, change) => match change {
                 ChangeBoolean::Enable => "Use distance estimation to color escape regions".to_owned(),
                 ChangeBoolean::Disable => "Use Green's function to color escape regions".to_owned(),
                 ChangeBoolean::Toggle => "Cycle between exterior coloring modes (smooth potential and distance estimate).".to_owned(),
             },
+            Self::ToggleInverseIteration(pane_id) => {
+                format!("Outline the Julia set on {pane_id} image by inverse iteration.")
+            }
+            Self::ToggleLyapunov(pane_id) => {
+                format!("Color {pane_id} image by the Lyapunov exponents of orbits.")
+            }
+            Self::ToggleBasins(pane_id) => {
+                format!("Color the basin of each attracting cycle on {pane_id} image distinctly.")
+            }
+            Self::ToggleStochastic(pane_id) => {
+                format!(
+                    "Color {pane_id} image by the probability that orbits escape when random \
+                        noise is added at each iteration."
+                )
+            }
+            Self::ScaleNoise(scale) => {
+                format!(
+                    "{} the noise added to orbits on active image (factor: {scale})",
+                    inc_or_dec(*scale)
+                )
+            }
+            Self::ToggleFlow(pane_id) => {
+                format!(
+                    "Iterate {pane_id} image continuously, by the flow interpolating between \
+                        iterates of the map."
+                )
+            }
+            Self::ToggleLogCoordinates(pane_id) => {
+                format!("Follow orbits on {pane_id} image in logarithmic coordinates.")
+            }
+            Self::ToggleAdaptiveIterations(pane_id) => {
+                format!(
+                    "Estimate distances on {pane_id} image, raising the iteration limit only for \
+                        pixels near the boundary."
+                )
+            }
         }
     }
 
@@ -230,22 +553,56 @@ impl Action
             Self::Quit => "Exit".to_owned(),
             Self::Close => "Close Tab".to_owned(),
             Self::NewTab => "New Tab".to_owned(),
+            Self::OpenRenderQueue => "Render Queue...".to_owned(),
+            Self::OpenShortcutEditor => "Keyboard Shortcuts...".to_owned(),
+            Self::OpenCommandPalette => "Command Palette...".to_owned(),
+            Self::OpenPreferences => "Preferences...".to_owned(),
+            Self::OpenBookmarks => "Bookmarks...".to_owned(),
             Self::SaveImage(pane_selection) => format!("Save{pane_selection:#}..."),
+            Self::SaveTransparentImage(pane_selection, transparency) => {
+                format!("Save{pane_selection:#} ({})...", transparency.name())
+            }
             Self::SavePalette(pane_selection) => format!("Save{pane_selection:#} Palette..."),
             Self::LoadPalette(pane_selection) => format!("Load{pane_selection:#} Palette..."),
+            Self::SaveData(pane_selection) => format!("Save{pane_selection:#} Data..."),
+            Self::OpenData(pane_selection) => format!("Open{pane_selection:#} Data..."),
+            Self::SaveOrbit(pane_selection) => format!("Save{pane_selection:#} Orbit..."),
+            Self::SaveRays(pane_selection) => format!("Save{pane_selection:#} Rays..."),
+            Self::SaveBandContours(pane_selection) => {
+                format!("Save{pane_selection:#} Band Contours...")
+            }
+            Self::LoadRays(pane_selection) => format!("Load{pane_selection:#} Rays..."),
+            Self::LoadPoints(pane_selection) => format!("Load{pane_selection:#} Points..."),
+            Self::ExportPcfCatalog => "Export PCF Catalog...".to_owned(),
 
             // Annotation Toggles
             Self::ToggleSelectionMarker => "Toggle Selection".to_owned(),
             Self::ToggleCritical => "Toggle Critical".to_owned(),
             Self::ToggleMarked(_) => "Toggle Marked pts".to_owned(),
             Self::ToggleCycles(_, p) => format!("Toggle {p}-cycles"),
+            Self::TogglePeriodLabels(_) => "Toggle Period Labels".to_owned(),
+            Self::ToggleBandContours(_) => "Toggle Band Contours".to_owned(),
 
             // Dynamics
             Self::FindPeriodicPoint => "Find Point...".to_owned(),
+            Self::FindMultiplier => "Find Multiplier...".to_owned(),
+            Self::AnalyzeMisiurewicz => "Misiurewicz Spiral...".to_owned(),
+            Self::TraceMultiplierLocus => "Multiplier Locus...".to_owned(),
+            Self::Tune => "Tuning...".to_owned(),
             Self::EnterCoordinates => "Enter Point...".to_owned(),
             Self::MapSelection => "Map Selection".to_owned(),
             Self::DrawOrbit => "Draw Orbit".to_owned(),
             Self::ClearOrbit => "Clear Orbit".to_owned(),
+            Self::ToggleOrbitPlayback => "Orbit Playback".to_owned(),
+            Self::PlayPauseOrbit => "Play/Pause Orbit".to_owned(),
+            Self::TogglePreimageTree => "Preimage Tree".to_owned(),
+            Self::StepOrbit(steps) => {
+                if *steps < 0 {
+                    "Previous Iterate".to_owned()
+                } else {
+                    "Next Iterate".to_owned()
+                }
+            }
             Self::DrawExternalRay {
                 include_orbit,
                 select_landing_point,
@@ -259,38 +616,70 @@ impl Action
                 }
             }
             Self::DrawRaysOfPeriod => "Rays of Period".to_owned(),
+            Self::DrawRayBatch => "Batch of Rays".to_owned(),
+            Self::OpenAngleCalculator => "Angle Calculator".to_owned(),
             Self::DrawContour(contour_type) => match contour_type {
                 ContourType::Equipotential => "Equipotential".to_owned(),
                 ContourType::Multiplier(..) => "Multiplier Contour".to_owned(),
                 ContourType::ExtendRay => "Extend Ray".to_owned(),
                 ContourType::InwardRay => "Inward Ray".to_owned(),
+                ContourType::CriticalRelation(1) => "Critical Value Curve".to_owned(),
+                ContourType::CriticalRelation(steps) => format!("Critical Curve ({steps})"),
             },
             Self::DrawAuxContours => "Multiplier Contours".to_owned(),
+            Self::DrawCriticalCurves => "Critical Curves".to_owned(),
+            Self::DrawComponentBoundary => "Component Boundary".to_owned(),
+            Self::EditLabel => "Edit Label...".to_owned(),
+            Self::ToggleRuler => "Toggle Ruler".to_owned(),
             Self::ClearRays => "Clear Rays".to_owned(),
             Self::ClearEquipotentials => "Clear Equipotentials".to_owned(),
             Self::ClearCurves => "Clear Curves".to_owned(),
+            Self::ClearLoadedPoints => "Clear Loaded Points".to_owned(),
+            Self::ClearLabels => "Clear Labels".to_owned(),
             Self::StopFollowing => "Stop Following".to_owned(),
             Self::ResetSelection => "Reset Selection".to_owned(),
             Self::ResetView => "Reset View".to_owned(),
+            Self::ToggleTrail => "Toggle Trail".to_owned(),
+            Self::ShowTrail => "Visited Parameters...".to_owned(),
+            Self::ClearTrail => "Clear Trail".to_owned(),
 
             // Image Controls
             Self::ToggleLiveMode => "Toggle Live Mode".to_owned(),
+            Self::ToggleComparison => "Toggle Comparison".to_owned(),
+            Self::ToggleMinimap => "Toggle Minimap".to_owned(),
+            Self::ToggleStatistics(_) => "Statistics".to_owned(),
+            Self::ToggleAttractorInventory(_) => "Attractor Inventory".to_owned(),
+            Self::TogglePcfDatabase(_) => "PCF Parameters".to_owned(),
+            Self::ToggleOrbitTooltip => "Orbit Tooltip".to_owned(),
+            Self::ToggleOrbitTrace(_) => "Orbit Trace".to_owned(),
+            Self::ToggleEquilibriumMeasure(_) => "Equilibrium Measure".to_owned(),
+            Self::ToggleAttractorDensity(_) => "Attractor Density".to_owned(),
+            Self::EstimateDimension => "Boundary Dimension".to_owned(),
+            Self::EstimateArea => "Bounded Area".to_owned(),
             Self::CycleActivePlane => "Cycle Plane".to_owned(),
+            Self::ChangeCoordinates => "Change Coordinates...".to_owned(),
             Self::PromptImageHeight => "Set Height".to_owned(),
             Self::Pan(_, _) => "Pan View".to_owned(),
             Self::Zoom(scale) => format!("Zoom {}", in_or_out(*scale)),
             Self::CenterOnSelection => "Center View".to_owned(),
+            Self::SetBounds(..) => "Set Bounds".to_owned(),
             Self::ScaleMaxIter(scale) => format!("{} iters", inc_or_dec(*scale)),
 
             // Coloring
-            Self::RandomizePalette => "Random".to_owned(),
+            Self::RandomizePalette(pane_selection) => format!("Randomize{pane_selection:#}"),
+            Self::TogglePaletteSync => "Sync Palettes".to_owned(),
+            Self::TogglePaletteGenerator => "Random Palette Settings".to_owned(),
+            Self::TogglePinnedPeriodColors => "Pin Period Colors".to_owned(),
+            Self::CycleColorVisionFilter => "Color Blindness Preview".to_owned(),
+            Self::ToggleLayers(_) => "Coloring Layers".to_owned(),
+            Self::ToggleLighting(_) => "Toggle Lighting".to_owned(),
             Self::SetPalette(_) => "Custom".to_owned(),
-            Self::SetPaletteWhite => "White".to_owned(),
-            Self::SetPaletteBlack => "Black".to_owned(),
+            Self::SetPaletteWhite(_) => "White".to_owned(),
+            Self::SetPaletteBlack(_) => "Black".to_owned(),
             Self::SetColoring(algorithm) => {
                 use IncoloringAlgorithm::{
-                    InternalPotential, Multiplier, Period, PeriodMultiplier, PotentialAndPeriod,
-                    Preperiod, PreperiodPeriod, Solid,
+                    CycleLength, InternalPotential, Multiplier, Period, PeriodMultiplier,
+                    PotentialAndPeriod, Preperiod, PreperiodPeriod, PreperiodPeriodSmooth, Solid,
                 };
                 let desc = match algorithm {
                     Solid => "Black",
@@ -300,7 +689,9 @@ impl Action
                     Preperiod => "Convergence time",
                     InternalPotential { .. } => "Internal Potential",
                     PreperiodPeriod { .. } => "Period + Conv. Time",
+                    PreperiodPeriodSmooth { .. } => "Period + Smooth Conv. Time",
                     PotentialAndPeriod { .. } => "Period + Potential",
+                    CycleLength { .. } => "Cycle Length",
                 };
                 desc.to_owned()
             }
@@ -309,12 +700,25 @@ impl Action
             Self::SetColoringPreperiodPeriod => "Period + Conv. Time".to_owned(),
             Self::ScalePalettePeriod(scale) => format!("{} density", inc_or_dec(1.0 / scale)),
             Self::ShiftPalettePhase(_) => "Adjust Phase".to_owned(),
+            Self::ToggleColorCycling(_) => "Color Cycling".to_owned(),
             Self::ToggleEscapePhaseColoring => "Phase Coloring".to_owned(),
+            Self::ToggleBinaryDecomposition => "Binary Decomposition".to_owned(),
+            Self::ScaleDecompositionSectors(scale) => format!("{} sectors", inc_or_dec(*scale)),
+            Self::CycleOutcoloring => "Custom Coloring".to_owned(),
+            Self::ToggleWringAnimation => "Wring Animation".to_owned(),
             Self::CycleComputeMode(_, change) => match change {
                 ChangeBoolean::Enable => "Distance Estimation".to_owned(),
                 ChangeBoolean::Disable => "External Potential".to_owned(),
                 ChangeBoolean::Toggle => "Cycle Outcoloring".to_owned(),
             },
+            Self::ToggleInverseIteration(_) => "Inverse Iteration".to_owned(),
+            Self::ToggleLyapunov(_) => "Lyapunov Exponents".to_owned(),
+            Self::ToggleBasins(_) => "Basins of Attraction".to_owned(),
+            Self::ToggleStochastic(_) => "Stochastic Iteration".to_owned(),
+            Self::ScaleNoise(scale) => format!("{} noise", inc_or_dec(*scale)),
+            Self::ToggleFlow(_) => "Continuous Iteration".to_owned(),
+            Self::ToggleLogCoordinates(_) => "Logarithmic Coordinates".to_owned(),
+            Self::ToggleAdaptiveIterations(_) => "Adaptive Iterations".to_owned(),
         }
     }
 }
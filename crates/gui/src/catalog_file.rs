@@ -0,0 +1,40 @@
+//! Writing catalogs of postcritically finite parameters to CSV files.
+//!
+//! Each line holds a parameter, the orbit schema of its critical point, the external angles
+//! landing at it separated by spaces, and their kneading sequence:
+//!
+//! ```text
+//! re,im,preperiod,period,angles,kneading_sequence
+//! -1e0,0e0,0,2,1/3 2/3,p0*
+//! ```
+use std::io::Write;
+use std::path::Path;
+
+use dynamo_core::prelude::LandedParameter;
+
+pub fn save(path: &Path, catalog: &[LandedParameter]) -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    write_csv(&mut file, catalog)?;
+    file.flush()?;
+    Ok(())
+}
+
+fn write_csv(writer: &mut impl Write, catalog: &[LandedParameter]) -> std::io::Result<()>
+{
+    writeln!(writer, "re,im,preperiod,period,angles,kneading_sequence")?;
+    for entry in catalog {
+        let angles: Vec<String> = entry.angles.iter().map(ToString::to_string).collect();
+        writeln!(
+            writer,
+            "{:e},{:e},{},{},{},{}",
+            entry.parameter.point.re,
+            entry.parameter.point.im,
+            entry.parameter.orbit_schema.preperiod,
+            entry.parameter.orbit_schema.period,
+            angles.join(" "),
+            entry.kneading_sequence,
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use dynamo_common::types::{Cplx, Real};
+use egui::{pos2, vec2, Color32, Id, Pos2, Rect, Response, Sense, Stroke, Ui, Widget};
+
+/// How the two drag values of a [`ComplexInput`] describe the number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ComplexInputMode
+{
+    #[default]
+    Rectangular,
+    Polar,
+}
+
+impl ComplexInputMode
+{
+    const fn toggled(self) -> Self
+    {
+        match self {
+            Self::Rectangular => Self::Polar,
+            Self::Polar => Self::Rectangular,
+        }
+    }
+
+    const fn label(self) -> &'static str
+    {
+        match self {
+            Self::Rectangular => "xy",
+            Self::Polar => "rθ",
+        }
+    }
+}
+
+/// Editor for a complex number: drag values for its rectangular or polar coordinates, and a
+/// small Argand plot on which the number can be dragged around.
+///
+/// The mode is remembered per `id_salt`, so several inputs may be shown at once.
+pub struct ComplexInput<'a>
+{
+    value: &'a mut Cplx,
+    id_salt: Id,
+    plot_radius: Real,
+    speed: Option<f64>,
+}
+
+impl<'a> ComplexInput<'a>
+{
+    #[must_use]
+    pub fn new(value: &'a mut Cplx, id_salt: impl std::hash::Hash) -> Self
+    {
+        Self {
+            value,
+            id_salt: Id::new(id_salt),
+            plot_radius: 2.,
+            speed: None,
+        }
+    }
+
+    /// Half the width of the region shown on the Argand plot, centered at the origin.
+    #[must_use]
+    pub fn plot_radius(mut self, radius: Real) -> Self
+    {
+        if radius.is_finite() && radius > 0. {
+            self.plot_radius = radius;
+        }
+        self
+    }
+
+    /// Change in the coordinates per pixel dragged; by default proportional to the plot radius.
+    #[must_use]
+    pub const fn speed(mut self, speed: f64) -> Self
+    {
+        self.speed = Some(speed);
+        self
+    }
+
+    fn show_drag_values(&mut self, ui: &mut Ui, mode: ComplexInputMode) -> bool
+    {
+        let speed = self.speed.unwrap_or(self.plot_radius * 1e-3);
+        match mode {
+            ComplexInputMode::Rectangular => {
+                let re = ui.add(egui::DragValue::new(&mut self.value.re).speed(speed));
+                let im = ui.add(
+                    egui::DragValue::new(&mut self.value.im)
+                        .speed(speed)
+                        .suffix("i"),
+                );
+                re.changed() || im.changed()
+            }
+            ComplexInputMode::Polar => {
+                let (mut modulus, arg) = self.value.to_polar();
+                let mut degrees = arg.to_degrees();
+                let modulus_changed = ui
+                    .add(
+                        egui::DragValue::new(&mut modulus)
+                            .speed(speed)
+                            .range(0.0..=Real::INFINITY)
+                            .prefix("r = "),
+                    )
+                    .changed();
+                let arg_changed = ui
+                    .add(
+                        egui::DragValue::new(&mut degrees)
+                            .speed(0.5)
+                            .prefix("θ = ")
+                            .suffix("°"),
+                    )
+                    .changed();
+                // Only rebuild the number when edited, to avoid drift from the round trip
+                let changed = modulus_changed || arg_changed;
+                if changed {
+                    *self.value = Cplx::from_polar(modulus, degrees.to_radians());
+                }
+                changed
+            }
+        }
+    }
+
+    fn show_argand_plot(&mut self, ui: &mut Ui) -> Response
+    {
+        let size = 2. * ui.spacing().interact_size.y;
+        let (rect, mut response) =
+            ui.allocate_exact_size(vec2(size, size), Sense::click_and_drag());
+        let radius = self.plot_radius;
+        let scale = 0.5 * rect.width() / radius as f32;
+        let center = rect.center();
+
+        let to_screen = |z: Cplx| -> Pos2 {
+            pos2(
+                (z.re as f32).mul_add(scale, center.x),
+                (z.im as f32).mul_add(-scale, center.y),
+            )
+        };
+
+        if let Some(pos) = response.interact_pointer_pos() {
+            if response.dragged() || response.clicked() {
+                let pos = rect.clamp(pos);
+                *self.value = Cplx::new(
+                    f64::from((pos.x - center.x) / scale),
+                    f64::from((center.y - pos.y) / scale),
+                );
+                response.mark_changed();
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 2., ui.visuals().extreme_bg_color);
+
+            let axis_stroke = Stroke::new(1., ui.visuals().weak_text_color());
+            painter.hline(rect.x_range(), center.y, axis_stroke);
+            painter.vline(center.x, rect.y_range(), axis_stroke);
+            painter.circle_stroke(center, scale, Stroke::new(1., Color32::from_gray(96)));
+
+            let point = to_screen(*self.value);
+            if rect.contains(point) {
+                painter.circle_filled(point, 3., visuals.fg_stroke.color);
+            } else if self.value.is_finite() {
+                // Mark the direction of a number outside the plot on its boundary
+                let edge = Rect::from_center_size(center, rect.size() - vec2(4., 4.));
+                painter.circle_stroke(edge.clamp(point), 2., visuals.fg_stroke);
+            }
+            painter.rect_stroke(rect, 2., visuals.bg_stroke);
+        }
+
+        response.on_hover_text(format!("{:.6}", self.value))
+    }
+}
+
+impl Widget for ComplexInput<'_>
+{
+    fn ui(mut self, ui: &mut Ui) -> Response
+    {
+        let mode_id = ui.make_persistent_id(self.id_salt);
+        let mut mode: ComplexInputMode =
+            ui.data_mut(|data| *data.get_temp_mut_or_default(mode_id));
+
+        let inner = ui.horizontal(|ui| {
+            if ui
+                .small_button(mode.label())
+                .on_hover_text("Switch between rectangular and polar coordinates")
+                .clicked()
+            {
+                mode = mode.toggled();
+            }
+            let dragged = self.show_drag_values(ui, mode);
+            let plotted = self.show_argand_plot(ui).changed();
+            dragged || plotted
+        });
+
+        ui.data_mut(|data| data.insert_temp(mode_id, mode));
+
+        let mut response = inner.response;
+        if inner.inner {
+            response.mark_changed();
+        }
+        response
+    }
+}
@@ -0,0 +1,65 @@
+//! Saving level curves of the escape potential as vector graphics.
+//!
+//! The curves are written to an SVG file the size of the image in pixels, so that they can be
+//! laid over a saved image of the same view. Each curve is a path whose `data-level` attribute
+//! holds its level:
+//!
+//! ```xml
+//! <path data-level="12" d="M 10.5 3.25 L 11.5 3.75 ... Z"/>
+//! ```
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::Path;
+
+use dynamo_common::prelude::{Contour, PointGrid};
+
+type Error = Box<dyn std::error::Error>;
+
+const STROKE_COLOR: &str = "black";
+const STROKE_WIDTH: f32 = 1.0;
+
+pub fn save(path: &Path, contours: &[Contour], grid: &PointGrid) -> Result<(), Error>
+{
+    let (width, height) = grid.shape();
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+            viewBox=\"0 0 {width} {height}\">\n\
+            <g fill=\"none\" stroke=\"{STROKE_COLOR}\" stroke-width=\"{STROKE_WIDTH}\" \
+            stroke-linejoin=\"round\">\n"
+    );
+    for contour in contours {
+        let points = if contour.is_closed() {
+            &contour.points[..contour.points.len() - 1]
+        } else {
+            &contour.points[..]
+        };
+        // A closed contour stays closed only if none of it is lost to the view transform
+        let runs = grid.locate_curve(points);
+        let closed = contour.is_closed() && runs.len() == 1 && runs[0].len() == points.len();
+        let mut data = String::new();
+        for run in runs {
+            let Some(([x, y], rest)) = run.split_first() else {
+                continue;
+            };
+            if !data.is_empty() {
+                data.push(' ');
+            }
+            write!(data, "M {x} {y}")?;
+            for [x, y] in rest {
+                write!(data, " L {x} {y}")?;
+            }
+        }
+        if data.is_empty() {
+            continue;
+        }
+        if closed {
+            data.push_str(" Z");
+        }
+        writeln!(svg, "<path data-level=\"{}\" d=\"{data}\"/>", contour.level)?;
+    }
+    svg.push_str("</g>\n</svg>\n");
+
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    file.write_all(svg.as_bytes())?;
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use std::collections::VecDeque;
 
+use dynamo_color::Transparency;
 use dynamo_common::rational_angle::RationalAngle;
 use dynamo_common::symbolic_dynamics::{AngleInfo, OrbitSchemaWithDegree};
+use dynamo_common::types::{AngleNum, Cplx, Period, Real};
 use egui::{self, Key, RichText, WidgetText};
 use egui::{vec2, Window};
 use egui_file::FileDialog;
@@ -10,6 +12,7 @@ use std::fmt::Write;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::complex_input::ComplexInput;
 use crate::pane::id::{PaneID, PaneSelection};
 use crate::pane::tasks::SelectOrFollow;
 
@@ -23,6 +26,7 @@ pub enum ToggleKey
     FollowPoint,
     DrawOrbit,
     PrefixAngles,
+    PeriodDoubling,
 }
 
 #[derive(Clone, Debug)]
@@ -92,8 +96,27 @@ impl<'a> IntoIterator for &'a mut ToggleMap
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SaveFileType
 {
-    Image,
+    /// Image with the given points left transparent.
+    Image(Transparency),
     Palette,
+    Data,
+    Orbit,
+    Rays,
+    /// Boundaries between bands of escaping points, as vector graphics.
+    BandContours,
+    /// Centers and Misiurewicz parameters with preperiod and period adding up to at most the
+    /// given length.
+    PcfCatalog(Period),
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LoadFileType
+{
+    Palette,
+    Data,
+    Points,
+    Rays,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
@@ -130,10 +153,15 @@ pub enum Dialog
     {
         pane_selection: PaneSelection,
         file_dialog: FileDialog,
+        file_type: LoadFileType,
     },
     Text(StructuredTextDialog),
     ConfirmRay(ConfirmationDialog<RayParams>),
     ConfirmActiveRays(ConfirmationDialog<AllActiveRayParams>),
+    Trail(TrailDialog),
+    AngleCalculator(AngleCalculator),
+    Point(PointDialog),
+    Info(ConfirmationDialog<()>),
 }
 
 pub enum State
@@ -158,14 +186,40 @@ pub enum TextInputType
     {
         pane_id: PaneID
     },
+    RayBatch
+    {
+        pane_id: PaneID
+    },
     FindPeriodic
     {
         pane_id: PaneID
     },
-    Coordinates
+    MisiurewiczSpiral
+    {
+        pane_id: PaneID
+    },
+    MultiplierLocus
+    {
+        pane_id: PaneID
+    },
+    FindMultiplier
+    {
+        pane_id: PaneID
+    },
+    PcfCatalog,
+    CoordinateChange
+    {
+        pane_id: PaneID
+    },
+    Label
     {
         pane_id: PaneID
     },
+    Tuning
+    {
+        pane_id: PaneID
+    },
+    MetaParam,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -185,6 +239,7 @@ pub struct TextDialogBuilder
     input_type: TextInputType,
     title: String,
     prompt: WidgetText,
+    initial_text: String,
     toggles: ToggleMap,
 }
 
@@ -211,6 +266,60 @@ pub struct ConfirmationDialog<D>
     data: D,
 }
 
+/// Lists previously selected parameters, most recent first, so that one may be selected again.
+pub struct TrailDialog
+{
+    pub title: String,
+    pub state: State,
+    points: Vec<Cplx>,
+    chosen: Option<Cplx>,
+}
+
+/// Edits the coordinates of a point to select, either with a [`ComplexInput`] or as text.
+pub struct PointDialog
+{
+    pub title: String,
+    pub prompt: WidgetText,
+    pub pane_id: PaneID,
+    pub state: State,
+    point: Cplx,
+    /// Fixed when the dialog opens, so that the plot does not rescale while the point is dragged.
+    plot_radius: Real,
+    /// Text form of the point, kept in sync with the input widget.
+    text: String,
+}
+
+/// Rays that the angle calculator was asked to draw.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct RayRequest
+{
+    pub do_parent: bool,
+    pub do_child: bool,
+    pub angles: Vec<RationalAngle>,
+}
+
+/// Shows the orbit and symbolic dynamics of an angle as it is typed, with buttons to draw the
+/// corresponding rays. Stays open after drawing, so that several angles can be explored in turn.
+pub struct AngleCalculator
+{
+    pub state: State,
+    degree: AngleNum,
+    input: String,
+    do_parent: bool,
+    do_child: bool,
+    /// The input that `summary` describes, to avoid recomputing it each frame.
+    summarized_input: Option<String>,
+    summary: Option<AngleSummary>,
+    request: Option<RayRequest>,
+}
+
+struct AngleSummary
+{
+    info: AngleInfo,
+    orbit: Vec<RationalAngle>,
+    companion: Option<RationalAngle>,
+}
+
 impl State
 {
     const fn is_open(&self) -> bool
@@ -228,6 +337,7 @@ impl TextDialogBuilder
             input_type,
             title: String::new(),
             prompt: WidgetText::default(),
+            initial_text: String::new(),
             toggles: ToggleMap::new(),
         }
     }
@@ -245,6 +355,14 @@ impl TextDialogBuilder
         self
     }
 
+    /// Text already entered when the dialog opens.
+    #[must_use]
+    pub fn initial_text(mut self, text: String) -> Self
+    {
+        self.initial_text = text;
+        self
+    }
+
     #[must_use]
     pub fn add_toggle(mut self, key: ToggleKey, name: String) -> Self
     {
@@ -308,7 +426,8 @@ impl TextDialogBuilder
     #[must_use]
     pub fn build(self) -> StructuredTextDialog
     {
-        let dialog = TextDialog::new(self.title, self.prompt, self.toggles);
+        let mut dialog = TextDialog::new(self.title, self.prompt, self.toggles);
+        dialog.user_input = self.initial_text;
 
         StructuredTextDialog {
             input_type: self.input_type,
@@ -512,6 +631,313 @@ impl<T> ConfirmationDialog<T>
         }
     }
 }
+impl TrailDialog
+{
+    #[must_use]
+    pub fn new(title: String, points: Vec<Cplx>) -> Self
+    {
+        Self {
+            title,
+            state: State::JustOpened,
+            points,
+            chosen: None,
+        }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context)
+    {
+        if self.visible() {
+            Window::new(self.title.clone())
+                .collapsible(false)
+                .fixed_size(vec2(320.0, 250.0))
+                .pivot(egui::Align2::CENTER_CENTER)
+                .default_pos(ctx.screen_rect().center())
+                .show(ctx, |ui| {
+                    if self.points.is_empty() {
+                        ui.label("No parameters have been selected yet.");
+                    }
+                    egui::ScrollArea::vertical()
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            for (i, point) in self.points.iter().rev().enumerate() {
+                                let text = RichText::from(format!("{:>3}. {point:.6}", i + 1))
+                                    .monospace();
+                                if ui.selectable_label(false, text).clicked() {
+                                    self.chosen = Some(*point);
+                                    self.state = State::Completed;
+                                }
+                            }
+                        });
+
+                    if ui.button("Close").clicked() || ctx.input(|i| i.key_pressed(Key::Escape))
+                    {
+                        self.disable();
+                    }
+                });
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn visible(&self) -> bool
+    {
+        self.state.is_open()
+    }
+
+    #[inline]
+    pub fn disable(&mut self)
+    {
+        self.state = State::Closed;
+    }
+
+    pub fn get_response(&mut self) -> Response<Cplx>
+    {
+        match self.state {
+            State::InProgress | State::JustOpened => Response::InProgress,
+            State::Closed => Response::Cancelled,
+            State::Completed => self
+                .chosen
+                .take()
+                .map_or(Response::Cancelled, |data| Response::Complete { data }),
+        }
+    }
+}
+
+impl PointDialog
+{
+    #[must_use]
+    pub fn new(title: String, prompt: impl Into<WidgetText>, pane_id: PaneID, point: Cplx) -> Self
+    {
+        Self {
+            title,
+            prompt: prompt.into(),
+            pane_id,
+            state: State::JustOpened,
+            point,
+            plot_radius: point.norm().max(2.),
+            text: point.to_string(),
+        }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context)
+    {
+        if !self.visible() {
+            return;
+        }
+        Window::new(self.title.clone())
+            .collapsible(false)
+            .resizable(false)
+            .pivot(egui::Align2::CENTER_CENTER)
+            .default_pos(ctx.screen_rect().center())
+            .show(ctx, |ui| {
+                ui.label(self.prompt.clone());
+                let input = ComplexInput::new(&mut self.point, "point_dialog")
+                    .plot_radius(self.plot_radius);
+                if ui.add(input).changed() {
+                    self.text = self.point.to_string();
+                }
+
+                let response = ui.text_edit_singleline(&mut self.text);
+                if matches!(self.state, State::JustOpened) {
+                    response.request_focus();
+                    self.state = State::InProgress;
+                }
+                if response.changed() {
+                    if let Ok(point) = self.text.trim().parse::<Cplx>() {
+                        self.point = point;
+                    }
+                }
+
+                ui.horizontal(|ui| {
+                    if ui.button("OK").clicked() || ctx.input(|i| i.key_pressed(Key::Enter)) {
+                        self.state = State::Completed;
+                    } else if ui.button("Cancel").clicked()
+                        || ctx.input(|i| i.key_pressed(Key::Escape))
+                    {
+                        self.disable();
+                    }
+                });
+            });
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn visible(&self) -> bool
+    {
+        self.state.is_open()
+    }
+
+    #[inline]
+    pub fn disable(&mut self)
+    {
+        self.state = State::Closed;
+    }
+
+    pub fn get_response(&mut self) -> Response<Cplx>
+    {
+        match self.state {
+            State::InProgress | State::JustOpened => Response::InProgress,
+            State::Closed => Response::Cancelled,
+            State::Completed => Response::Complete { data: self.point },
+        }
+    }
+}
+
+impl AngleCalculator
+{
+    #[must_use]
+    pub const fn new(degree: AngleNum) -> Self
+    {
+        Self {
+            state: State::JustOpened,
+            degree,
+            input: String::new(),
+            do_parent: true,
+            do_child: true,
+            summarized_input: None,
+            summary: None,
+            request: None,
+        }
+    }
+
+    fn update_summary(&mut self)
+    {
+        if self.summarized_input.as_ref() == Some(&self.input) {
+            return;
+        }
+        self.summary = self.input.trim().parse::<RationalAngle>().ok().map(|angle| {
+            let angle = angle.with_degree(self.degree);
+            let info = angle.to_angle_info();
+            let orbit = info.orbit(self.degree).collect();
+            AngleSummary {
+                info,
+                orbit,
+                companion: angle.companion_angle(),
+            }
+        });
+        self.summarized_input = Some(self.input.clone());
+    }
+
+    fn describe(&self, summary: &AngleSummary) -> String
+    {
+        let degree = self.degree;
+        let binary = |angle: RationalAngle| format!("{}", angle.with_degree(degree));
+        let AngleInfo {
+            angle,
+            orbit_schema,
+            kneading_sequence,
+        } = &summary.info;
+
+        let mut text = String::new();
+        let _ = writeln!(text, "Angle:      {angle} = {}", binary(*angle));
+        let _ = writeln!(
+            text,
+            "Preperiod:  {}, period: {}",
+            orbit_schema.preperiod, orbit_schema.period
+        );
+        let _ = writeln!(text, "Kneading:   {kneading_sequence}");
+        if let Some(companion) = summary.companion {
+            let _ = writeln!(text, "Companion:  {companion} = {}", binary(companion));
+        }
+        let _ = writeln!(text, "Orbit under multiplication by {degree}:");
+        for theta in &summary.orbit {
+            let _ = writeln!(text, "  {theta:>12}  {}", binary(*theta));
+        }
+        text
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context)
+    {
+        if !self.visible() {
+            return;
+        }
+        self.update_summary();
+        Window::new("Angle calculator")
+            .collapsible(false)
+            .default_width(360.0)
+            .pivot(egui::Align2::CENTER_CENTER)
+            .default_pos(ctx.screen_rect().center())
+            .show(ctx, |ui| {
+                ui.label("Angle, e.g. <15/56>, <110>, <p011>, <001p010>:");
+                let response = ui.text_edit_singleline(&mut self.input);
+                if matches!(self.state, State::JustOpened) {
+                    response.request_focus();
+                    self.state = State::InProgress;
+                }
+
+                match &self.summary {
+                    Some(summary) => {
+                        let text = RichText::from(self.describe(summary)).monospace();
+                        egui::ScrollArea::vertical()
+                            .max_height(240.0)
+                            .show(ui, |ui| ui.label(text));
+                    }
+                    None if self.input.trim().is_empty() => {}
+                    None => {
+                        ui.label("Unrecognized angle.");
+                    }
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("Draw on");
+                    ui.checkbox(&mut self.do_parent, "Parent");
+                    ui.checkbox(&mut self.do_child, "Child");
+                });
+
+                let mut angles = None;
+                ui.horizontal(|ui| {
+                    let Some(summary) = &self.summary else {
+                        return;
+                    };
+                    if ui.button("Draw ray").clicked() {
+                        angles = Some(vec![summary.info.angle]);
+                    }
+                    if ui.button("Draw orbit").clicked() {
+                        angles = Some(summary.orbit.clone());
+                    }
+                    if let Some(companion) = summary.companion {
+                        if ui.button("Draw ray pair").clicked() {
+                            angles = Some(vec![summary.info.angle, companion]);
+                        }
+                    }
+                });
+                self.request = angles.map(|angles| RayRequest {
+                    do_parent: self.do_parent,
+                    do_child: self.do_child,
+                    angles,
+                });
+
+                if ui.button("Close").clicked() || ctx.input(|i| i.key_pressed(Key::Escape)) {
+                    self.disable();
+                }
+            });
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn visible(&self) -> bool
+    {
+        self.state.is_open()
+    }
+
+    #[inline]
+    pub fn disable(&mut self)
+    {
+        self.state = State::Closed;
+    }
+
+    pub fn get_response(&mut self) -> Response<RayRequest>
+    {
+        match self.state {
+            State::Closed => Response::Cancelled,
+            _ => self
+                .request
+                .take()
+                .map_or(Response::InProgress, |data| Response::Complete { data }),
+        }
+    }
+}
+
 impl Dialog
 {
     pub fn show(&mut self, ctx: &egui::Context)
@@ -529,6 +955,18 @@ impl Dialog
             Self::ConfirmActiveRays(conf_dialog) => {
                 conf_dialog.show(ctx);
             }
+            Self::Trail(trail_dialog) => {
+                trail_dialog.show(ctx);
+            }
+            Self::AngleCalculator(calculator) => {
+                calculator.show(ctx);
+            }
+            Self::Point(point_dialog) => {
+                point_dialog.show(ctx);
+            }
+            Self::Info(info_dialog) => {
+                info_dialog.show(ctx);
+            }
         }
     }
 
@@ -542,9 +980,19 @@ impl Dialog
             Self::Text(text_dialog) => text_dialog.visible(),
             Self::ConfirmRay(conf_dialog) => conf_dialog.visible(),
             Self::ConfirmActiveRays(conf_dialog) => conf_dialog.visible(),
+            Self::Trail(trail_dialog) => trail_dialog.visible(),
+            Self::AngleCalculator(calculator) => calculator.visible(),
+            Self::Point(point_dialog) => point_dialog.visible(),
+            Self::Info(info_dialog) => info_dialog.visible(),
         }
     }
 
+    #[must_use]
+    pub fn info(title: String, text: impl Into<WidgetText>) -> Self
+    {
+        Self::Info(ConfirmationDialog::new(title, text, ()))
+    }
+
     #[must_use]
     pub fn confirm_ray(ray_params: RayParams) -> Self
     {
@@ -115,6 +115,8 @@ pub const KEY_RIGHT: KeyboardShortcut = key_press!(Key::ArrowRight);
 pub const KEY_SPACE: KeyboardShortcut = key_press!(Key::Space);
 pub const KEY_MINUS: KeyboardShortcut = key_press!(Key::Minus);
 pub const KEY_EQUALS: KeyboardShortcut = key_press!(Key::Equals);
+pub const KEY_COMMA: KeyboardShortcut = key_press!(Key::Comma);
+pub const KEY_PERIOD: KeyboardShortcut = key_press!(Key::Period);
 pub const KEY_INSERT: KeyboardShortcut = key_press!(Key::Insert);
 pub const KEY_HOME: KeyboardShortcut = key_press!(Key::Home);
 pub const KEY_ESC: KeyboardShortcut = key_press!(Key::Escape);
@@ -164,6 +166,7 @@ pub const SHIFT_RIGHT: KeyboardShortcut = shift!(Key::ArrowRight);
 pub const SHIFT_SPACE: KeyboardShortcut = shift!(Key::Space);
 
 pub const CTRL_SHIFT_E: KeyboardShortcut = ctrl_shift!(Key::E);
+pub const CTRL_SHIFT_P: KeyboardShortcut = ctrl_shift!(Key::P);
 pub const CTRL_SHIFT_0: KeyboardShortcut = ctrl_shift!(Key::Num0);
 pub const CTRL_SHIFT_1: KeyboardShortcut = ctrl_shift!(Key::Num1);
 pub const CTRL_SHIFT_2: KeyboardShortcut = ctrl_shift!(Key::Num2);
@@ -0,0 +1,363 @@
+//! Runtime binding of hotkeys to keyboard shortcuts, which users can change from the defaults in
+//! a config file, e.g. to suit a non-US keyboard layout:
+//!
+//! ```toml
+//! zoom_in = "Ctrl+Equals"
+//! zoom_out = "Ctrl+Minus"
+//! toggle_live_mode = ""  # unbound
+//! ```
+//!
+//! Only hotkeys that differ from their default are written to the file.
+use super::{
+    Hotkey, ANNOTATION_HOTKEYS, CYCLES_HOTKEYS, FILE_HOTKEYS, IMAGE_HOTKEYS, INCOLORING_HOTKEYS,
+    OUTCOLORING_HOTKEYS, PALETTE_HOTKEYS, SELECTION_HOTKEYS,
+};
+use dynamo_common::prelude::config_dir;
+use dynamo_core::error::DynamoError;
+use egui::{Context, Key, KeyboardShortcut, ModifierNames, Modifiers, RichText};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const KEYMAP_FILE: &str = "keymap.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HotkeyGroup
+{
+    File,
+    Image,
+    Annotation,
+    Cycles,
+    Selection,
+    Incoloring,
+    Outcoloring,
+    Palette,
+}
+impl HotkeyGroup
+{
+    /// All groups, in the order in which their shortcuts are checked.
+    pub const ALL: [Self; 8] = [
+        Self::File,
+        Self::Image,
+        Self::Annotation,
+        Self::Cycles,
+        Self::Selection,
+        Self::Incoloring,
+        Self::Outcoloring,
+        Self::Palette,
+    ];
+
+    #[must_use]
+    pub fn hotkeys(self) -> &'static [Hotkey]
+    {
+        match self {
+            Self::File => &FILE_HOTKEYS,
+            Self::Image => &IMAGE_HOTKEYS,
+            Self::Annotation => &ANNOTATION_HOTKEYS,
+            Self::Cycles => &CYCLES_HOTKEYS,
+            Self::Selection => &SELECTION_HOTKEYS,
+            Self::Incoloring => &INCOLORING_HOTKEYS,
+            Self::Outcoloring => &OUTCOLORING_HOTKEYS,
+            Self::Palette => &PALETTE_HOTKEYS,
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str
+    {
+        match self {
+            Self::File => "File",
+            Self::Image => "Image",
+            Self::Annotation => "Annotations",
+            Self::Cycles => "Cycles",
+            Self::Selection => "Selection",
+            Self::Incoloring => "Incoloring",
+            Self::Outcoloring => "Outcoloring",
+            Self::Palette => "Palette",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum KeymapError
+{
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    UnknownHotkey(String),
+    InvalidShortcut
+    {
+        name: String,
+        shortcut: String,
+    },
+}
+
+impl std::fmt::Display for KeymapError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Io(e) => write!(f, "Could not access keymap file: {e}"),
+            Self::Toml(e) => write!(f, "Invalid keymap file: {e}"),
+            Self::UnknownHotkey(name) => write!(f, "Unknown hotkey: {name}"),
+            Self::InvalidShortcut { name, shortcut } => {
+                write!(f, "Invalid shortcut for {name}: {shortcut:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {}
+
+impl From<KeymapError> for DynamoError
+{
+    fn from(e: KeymapError) -> Self
+    {
+        match e {
+            KeymapError::Io(e) => Self::Io(e),
+            e => Self::Format(e.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for KeymapError
+{
+    fn from(e: std::io::Error) -> Self
+    {
+        Self::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for KeymapError
+{
+    fn from(e: toml::de::Error) -> Self
+    {
+        Self::Toml(e)
+    }
+}
+
+/// The shortcut bound to each hotkey, keyed by hotkey name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keymap
+{
+    bindings: HashMap<&'static str, Option<KeyboardShortcut>>,
+}
+
+impl Default for Keymap
+{
+    fn default() -> Self
+    {
+        let bindings = Self::all_hotkeys()
+            .map(|hotkey| (hotkey.name, hotkey.shortcut))
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl Keymap
+{
+    fn all_hotkeys() -> impl Iterator<Item = &'static Hotkey>
+    {
+        HotkeyGroup::ALL.into_iter().flat_map(HotkeyGroup::hotkeys)
+    }
+
+    #[must_use]
+    pub fn shortcut(&self, hotkey: &Hotkey) -> Option<KeyboardShortcut>
+    {
+        self.bindings
+            .get(hotkey.name)
+            .copied()
+            .unwrap_or(hotkey.shortcut)
+    }
+
+    #[must_use]
+    pub fn shortcut_text(&self, hotkey: &Hotkey) -> Option<RichText>
+    {
+        Some(
+            RichText::new(self.shortcut(hotkey)?.format(&ModifierNames::NAMES, true))
+                .strong()
+                .color(epaint::Color32::LIGHT_GRAY),
+        )
+    }
+
+    /// All hotkeys with their current shortcuts, in the order in which they should be checked.
+    pub fn hotkeys(&self)
+        -> impl Iterator<Item = (&'static Hotkey, Option<KeyboardShortcut>)> + '_
+    {
+        Self::all_hotkeys().map(|hotkey| (hotkey, self.shortcut(hotkey)))
+    }
+
+    /// Bind a hotkey to a new shortcut, or unbind it if `shortcut` is None.
+    pub fn bind(
+        &mut self,
+        name: &str,
+        shortcut: Option<KeyboardShortcut>,
+    ) -> Result<(), KeymapError>
+    {
+        let binding = self
+            .bindings
+            .get_mut(name)
+            .ok_or_else(|| KeymapError::UnknownHotkey(name.to_owned()))?;
+        *binding = shortcut;
+        Ok(())
+    }
+
+    /// Names of the hotkeys bound to `shortcut`.
+    #[must_use]
+    pub fn bound_to(&self, shortcut: KeyboardShortcut) -> Vec<&'static str>
+    {
+        self.hotkeys()
+            .filter(|(_, s)| *s == Some(shortcut))
+            .map(|(hotkey, _)| hotkey.name)
+            .collect()
+    }
+
+    /// Shortcuts bound to more than one hotkey, with the names of those hotkeys. Only the first
+    /// of these hotkeys is triggered by the shortcut.
+    #[must_use]
+    pub fn conflicts(&self) -> Vec<(KeyboardShortcut, Vec<&'static str>)>
+    {
+        let mut conflicts: Vec<(KeyboardShortcut, Vec<&'static str>)> = Vec::new();
+        for (hotkey, shortcut) in self.hotkeys() {
+            let Some(shortcut) = shortcut else {
+                continue;
+            };
+            match conflicts.iter_mut().find(|(s, _)| *s == shortcut) {
+                Some((_, names)) => names.push(hotkey.name),
+                None => conflicts.push((shortcut, vec![hotkey.name])),
+            }
+        }
+        conflicts.retain(|(_, names)| names.len() > 1);
+        conflicts
+    }
+
+    /// Parse a keymap from TOML, starting from the defaults.
+    pub fn from_toml(text: &str) -> Result<Self, KeymapError>
+    {
+        let table: toml::Table = text.parse()?;
+        let mut keymap = Self::default();
+        for (name, value) in table {
+            let invalid = || KeymapError::InvalidShortcut {
+                name: name.clone(),
+                shortcut: value.to_string(),
+            };
+            let text = value.as_str().ok_or_else(invalid)?;
+            let shortcut = if text.is_empty() {
+                None
+            } else {
+                Some(parse_shortcut(text).ok_or_else(invalid)?)
+            };
+            keymap.bind(&name, shortcut)?;
+        }
+        Ok(keymap)
+    }
+
+    /// Serialize the bindings that differ from the defaults as TOML.
+    #[must_use]
+    pub fn to_toml(&self) -> String
+    {
+        let mut table = toml::Table::new();
+        for (hotkey, shortcut) in self.hotkeys() {
+            if shortcut != hotkey.shortcut {
+                let text = shortcut.map(format_shortcut).unwrap_or_default();
+                table.insert(hotkey.name.to_owned(), text.into());
+            }
+        }
+        table.to_string()
+    }
+
+    fn path() -> Option<PathBuf>
+    {
+        Some(config_dir()?.join(KEYMAP_FILE))
+    }
+
+    /// Load the user's keymap, or the default keymap if the user has not saved one.
+    pub fn load() -> Result<Self, KeymapError>
+    {
+        match Self::path() {
+            Some(path) if path.exists() => Self::from_toml(&std::fs::read_to_string(path)?),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), KeymapError>
+    {
+        let path = Self::path().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "No config directory")
+        })?;
+        std::fs::write(path, self.to_toml())?;
+        Ok(())
+    }
+
+    fn id() -> egui::Id
+    {
+        egui::Id::new("dynamo_keymap")
+    }
+
+    /// The keymap used by interfaces shown in this context, or the default keymap if none has
+    /// been installed.
+    #[must_use]
+    pub fn current(ctx: &Context) -> Arc<Self>
+    {
+        ctx.data_mut(|data| Arc::clone(data.get_temp_mut_or_default::<Arc<Self>>(Self::id())))
+    }
+
+    /// Use this keymap for all interfaces shown in this context.
+    pub fn install(&self, ctx: &Context)
+    {
+        ctx.data_mut(|data| data.insert_temp(Self::id(), Arc::new(self.clone())));
+    }
+}
+
+/// Format a shortcut as e.g. `Ctrl+Shift+P`, independently of the platform.
+#[must_use]
+pub fn format_shortcut(shortcut: KeyboardShortcut) -> String
+{
+    let Modifiers {
+        alt,
+        ctrl,
+        shift,
+        mac_cmd,
+        command,
+    } = shortcut.modifiers;
+    let mut text = String::new();
+    for (pressed, name) in [
+        (ctrl, "Ctrl"),
+        (alt, "Alt"),
+        (shift, "Shift"),
+        (mac_cmd, "Cmd"),
+        (command && !ctrl && !mac_cmd, "Command"),
+    ] {
+        if pressed {
+            text.push_str(name);
+            text.push('+');
+        }
+    }
+    text.push_str(shortcut.logical_key.name());
+    text
+}
+
+/// Parse a shortcut written as by [`format_shortcut`].
+#[must_use]
+pub fn parse_shortcut(text: &str) -> Option<KeyboardShortcut>
+{
+    // The key itself may be `+`
+    let (modifier_names, key_name) = match text.strip_suffix("++") {
+        Some(rest) => (rest, "+"),
+        None if text == "+" => ("", "+"),
+        None => text.rsplit_once('+').unwrap_or(("", text)),
+    };
+    let logical_key = Key::from_name(key_name.trim())?;
+
+    let mut modifiers = Modifiers::NONE;
+    for name in modifier_names.split('+').filter(|name| !name.is_empty()) {
+        match name.trim() {
+            "Ctrl" => modifiers.ctrl = true,
+            "Alt" => modifiers.alt = true,
+            "Shift" => modifiers.shift = true,
+            "Cmd" => modifiers.mac_cmd = true,
+            "Command" => modifiers.command = true,
+            _ => return None,
+        }
+    }
+    Some(KeyboardShortcut::new(modifiers, logical_key))
+}
@@ -1,4 +1,5 @@
 pub mod keyboard_shortcuts;
+pub mod keymap;
 use crate::{
     actions::{Action, ChangeBoolean},
     marked_points::ContourType,
@@ -7,22 +8,26 @@ use crate::{
         PaneSelection::{ActivePane, BothPanes, Id},
     },
 };
-use dynamo_color::IncoloringAlgorithm;
+use dynamo_color::{IncoloringAlgorithm, Transparency};
 use keyboard_shortcuts::{
-    CTRL_1, CTRL_2, CTRL_3, CTRL_4, CTRL_5, CTRL_6, CTRL_E, CTRL_F, CTRL_K, CTRL_L, CTRL_P, CTRL_Q,
-    CTRL_S, CTRL_SHIFT_1, CTRL_SHIFT_2, CTRL_SHIFT_3, CTRL_SHIFT_4, CTRL_SHIFT_5, CTRL_SHIFT_6,
-    CTRL_T, CTRL_V, CTRL_W, CTRL_X, CTRL_Z, KEY_0, KEY_1, KEY_2, KEY_3, KEY_4, KEY_5, KEY_6, KEY_7,
-    KEY_B, KEY_C, KEY_D, KEY_DOWN, KEY_E, KEY_EQUALS, KEY_ESC, KEY_F, KEY_G, KEY_HOME, KEY_I,
-    KEY_INSERT, KEY_J, KEY_L, KEY_LEFT, KEY_M, KEY_MINUS, KEY_O, KEY_P, KEY_R, KEY_RIGHT,
-    KEY_SPACE, KEY_UP, KEY_V, KEY_W, KEY_Y, KEY_Z, SHIFT_C, SHIFT_DOWN, SHIFT_E, SHIFT_LEFT,
-    SHIFT_M, SHIFT_O, SHIFT_P, SHIFT_R, SHIFT_RIGHT, SHIFT_SPACE, SHIFT_T, SHIFT_UP,
+    CTRL_1, CTRL_2, CTRL_3, CTRL_4, CTRL_5, CTRL_6, CTRL_B, CTRL_E, CTRL_F, CTRL_K, CTRL_L, CTRL_O,
+    CTRL_P, CTRL_Q, CTRL_S, CTRL_SHIFT_1, CTRL_SHIFT_2, CTRL_SHIFT_3, CTRL_SHIFT_4, CTRL_SHIFT_5,
+    CTRL_SHIFT_6, CTRL_SHIFT_P, CTRL_T, CTRL_V, CTRL_W, CTRL_X, CTRL_Z, KEY_0, KEY_1, KEY_2, KEY_3,
+    KEY_4, KEY_5, KEY_6, KEY_7, KEY_8, KEY_B, KEY_C, KEY_COMMA, KEY_D, KEY_DOWN, KEY_E, KEY_EQUALS,
+    KEY_ESC, KEY_F, KEY_G, KEY_H, KEY_HOME, KEY_I, KEY_INSERT, KEY_J, KEY_K, KEY_L, KEY_LEFT, KEY_M,
+    KEY_MINUS, KEY_N, KEY_O, KEY_P, KEY_PERIOD, KEY_R, KEY_RIGHT, KEY_SPACE, KEY_T, KEY_UP, KEY_V,
+    KEY_W, KEY_Y, KEY_Z, SHIFT_C, SHIFT_DOWN, SHIFT_E, SHIFT_LEFT, SHIFT_M, SHIFT_O, SHIFT_P,
+    SHIFT_R, SHIFT_RIGHT, SHIFT_SPACE, SHIFT_T, SHIFT_UP,
 };
 use seq_macro::seq;
 
-use egui::{KeyboardShortcut, ModifierNames, RichText};
+use egui::KeyboardShortcut;
 
 pub struct Hotkey
 {
+    /// Stable identifier, used to rebind the hotkey in the user's keymap.
+    pub(super) name: &'static str,
+    /// Shortcut used unless the user's keymap rebinds it.
     pub(super) shortcut: Option<KeyboardShortcut>,
     pub(super) action: Action,
     pub(super) bonus_action: Option<Action>,
@@ -34,9 +39,10 @@ pub struct Hotkey
 impl Hotkey
 {
     #[must_use]
-    pub const fn new(action: Action) -> Self
+    pub const fn new(name: &'static str, action: Action) -> Self
     {
         Self {
+            name,
             shortcut: None,
             action,
             bonus_action: None,
@@ -79,181 +85,365 @@ impl Hotkey
     #[must_use]
     pub fn menu_action(&self) -> Option<&Action>
     {
-        if self.show_in_menu {
-            self.menu_action_override.as_ref().or(Some(&self.action))
-        } else {
-            None
-        }
+        self.show_in_menu.then(|| self.chosen_action())
+    }
+    /// Action to perform if chosen from a menu or the command palette, even if hidden in menus.
+    #[must_use]
+    pub fn chosen_action(&self) -> &Action
+    {
+        self.menu_action_override.as_ref().unwrap_or(&self.action)
+    }
+    #[must_use]
+    pub const fn name(&self) -> &'static str
+    {
+        self.name
+    }
+    #[must_use]
+    pub const fn default_shortcut(&self) -> Option<KeyboardShortcut>
+    {
+        self.shortcut
+    }
+    #[must_use]
+    pub const fn get_action(&self) -> &Action
+    {
+        &self.action
     }
     #[must_use]
-    pub fn shortcut_text(&self) -> Option<RichText>
+    pub const fn get_bonus_action(&self) -> Option<&Action>
     {
-        Some(
-            RichText::new(self.shortcut?.format(&ModifierNames::NAMES, true))
-                .strong()
-                .color(epaint::Color32::LIGHT_GRAY),
-        )
+        self.bonus_action.as_ref()
     }
 }
 
 use Action::{
-    CenterOnSelection, ClearCurves, ClearOrbit, Close, CycleActivePlane, CycleComputeMode,
-    DrawAuxContours, DrawContour, DrawExternalRay, DrawOrbit, DrawRaysOfPeriod, EnterCoordinates,
-    FindPeriodicPoint, LoadPalette, MapSelection, NewTab, Pan, Quit, RandomizePalette,
-    ResetSelection, ResetView, SaveImage, SavePalette, ScaleMaxIter, ScalePalettePeriod,
-    SetColoring, SetColoringInternalPotential, SetColoringPotentialPeriod,
-    SetColoringPreperiodPeriod, SetPaletteBlack, SetPaletteWhite, ShiftPalettePhase, StopFollowing,
-    ToggleCritical, ToggleCycles, ToggleEscapePhaseColoring, ToggleLiveMode, ToggleMarked,
-    ToggleSelectionMarker, Zoom,
+    AnalyzeMisiurewicz, CenterOnSelection, ChangeCoordinates, ClearCurves, ClearEquipotentials,
+    ClearLabels, ClearLoadedPoints, ClearOrbit, ClearRays, ClearTrail, Close, CycleActivePlane,
+    CycleColorVisionFilter, CycleComputeMode, CycleOutcoloring, DrawAuxContours,
+    DrawComponentBoundary, DrawContour, DrawCriticalCurves, DrawExternalRay, DrawOrbit,
+    DrawRayBatch, DrawRaysOfPeriod, EditLabel, EnterCoordinates, EstimateArea, EstimateDimension,
+    ExportPcfCatalog, FindMultiplier, FindPeriodicPoint, LoadPalette, LoadPoints, LoadRays,
+    MapSelection, NewTab, OpenAngleCalculator, OpenBookmarks, OpenCommandPalette, OpenData,
+    OpenPreferences, OpenRenderQueue, OpenShortcutEditor, Pan, PlayPauseOrbit, Quit,
+    RandomizePalette, ResetSelection, ResetView, SaveBandContours, SaveData, SaveImage, SaveOrbit,
+    SavePalette, SaveRays, SaveTransparentImage, ScaleDecompositionSectors, ScaleMaxIter,
+    ScaleNoise, ScalePalettePeriod, SetColoring, SetColoringInternalPotential,
+    SetColoringPotentialPeriod, SetColoringPreperiodPeriod, SetPaletteBlack, SetPaletteWhite,
+    ShiftPalettePhase, ShowTrail, StepOrbit, StopFollowing, ToggleAdaptiveIterations,
+    ToggleAttractorDensity, ToggleAttractorInventory, ToggleBandContours, ToggleBasins,
+    ToggleBinaryDecomposition, ToggleColorCycling, ToggleComparison, ToggleCritical, ToggleCycles,
+    ToggleEquilibriumMeasure, ToggleEscapePhaseColoring, ToggleFlow, ToggleInverseIteration,
+    ToggleLayers, ToggleLighting, ToggleLiveMode, ToggleLogCoordinates, ToggleLyapunov,
+    ToggleMarked, ToggleMinimap, ToggleOrbitPlayback, ToggleOrbitTooltip, ToggleOrbitTrace,
+    TogglePaletteGenerator, TogglePaletteSync, TogglePcfDatabase, TogglePeriodLabels,
+    TogglePinnedPeriodColors, TogglePreimageTree, ToggleRuler, ToggleSelectionMarker,
+    ToggleStatistics, ToggleStochastic, ToggleTrail, ToggleWringAnimation, TraceMultiplierLocus,
+    Tune, Zoom,
 };
 
-pub const FILE_HOTKEYS: [Hotkey; 6] = [
-    Hotkey::new(Quit).shortcut(CTRL_Q),
-    Hotkey::new(Close).shortcut(CTRL_W),
-    Hotkey::new(NewTab).shortcut(CTRL_T),
-    Hotkey::new(SaveImage(ActivePane))
+pub static FILE_HOTKEYS: [Hotkey; 31] = [
+    Hotkey::new("quit", Quit).shortcut(CTRL_Q),
+    Hotkey::new("close_tab", Close).shortcut(CTRL_W),
+    Hotkey::new("new_tab", NewTab).shortcut(CTRL_T),
+    Hotkey::new("save_image", SaveImage(ActivePane))
         .shortcut(CTRL_S)
         .hide_in_menu(),
-    Hotkey::new(SaveImage(Id(Parent))),
-    Hotkey::new(SaveImage(Id(Child))),
+    Hotkey::new("save_parent_image", SaveImage(Id(Parent))),
+    Hotkey::new("save_child_image", SaveImage(Id(Child))),
+    Hotkey::new(
+        "save_parent_image_transparent_escaping",
+        SaveTransparentImage(Id(Parent), Transparency::Escaping),
+    ),
+    Hotkey::new(
+        "save_child_image_transparent_escaping",
+        SaveTransparentImage(Id(Child), Transparency::Escaping),
+    ),
+    Hotkey::new(
+        "save_parent_image_transparent_interior",
+        SaveTransparentImage(Id(Parent), Transparency::Interior),
+    ),
+    Hotkey::new(
+        "save_child_image_transparent_interior",
+        SaveTransparentImage(Id(Child), Transparency::Interior),
+    ),
+    Hotkey::new("save_parent_data", SaveData(Id(Parent))),
+    Hotkey::new("save_child_data", SaveData(Id(Child))),
+    Hotkey::new("save_parent_orbit", SaveOrbit(Id(Parent))),
+    Hotkey::new("save_child_orbit", SaveOrbit(Id(Child))),
+    Hotkey::new("save_parent_rays", SaveRays(Id(Parent))),
+    Hotkey::new("save_child_rays", SaveRays(Id(Child))),
+    Hotkey::new("save_parent_band_contours", SaveBandContours(Id(Parent))),
+    Hotkey::new("save_child_band_contours", SaveBandContours(Id(Child))),
+    Hotkey::new("load_parent_rays", LoadRays(Id(Parent))),
+    Hotkey::new("load_child_rays", LoadRays(Id(Child))),
+    Hotkey::new("open_data", OpenData(ActivePane))
+        .shortcut(CTRL_O)
+        .hide_in_menu(),
+    Hotkey::new("open_parent_data", OpenData(Id(Parent))),
+    Hotkey::new("open_child_data", OpenData(Id(Child))),
+    Hotkey::new("load_parent_points", LoadPoints(Id(Parent))),
+    Hotkey::new("load_child_points", LoadPoints(Id(Child))),
+    Hotkey::new("export_pcf_catalog", ExportPcfCatalog),
+    Hotkey::new("render_queue", OpenRenderQueue),
+    Hotkey::new("edit_shortcuts", OpenShortcutEditor),
+    Hotkey::new("preferences", OpenPreferences),
+    Hotkey::new("bookmarks", OpenBookmarks).shortcut(CTRL_B),
+    Hotkey::new("command_palette", OpenCommandPalette).shortcut(CTRL_SHIFT_P),
 ];
 
-pub const PALETTE_HOTKEYS: [Hotkey; 9] = [
-    Hotkey::new(SavePalette(ActivePane)).shortcut(CTRL_K),
-    Hotkey::new(LoadPalette(BothPanes)).shortcut(CTRL_L),
-    Hotkey::new(SetPaletteBlack).shortcut(KEY_B),
-    Hotkey::new(SetPaletteWhite).shortcut(KEY_W),
-    Hotkey::new(RandomizePalette).shortcut(KEY_R),
-    Hotkey::new(ScalePalettePeriod(1.25)).shortcut(KEY_UP),
-    Hotkey::new(ScalePalettePeriod(0.8)).shortcut(KEY_DOWN),
-    Hotkey::new(ShiftPalettePhase(-0.02)).shortcut(KEY_LEFT),
-    Hotkey::new(ShiftPalettePhase(0.02)).shortcut(KEY_RIGHT),
+pub static PALETTE_HOTKEYS: [Hotkey; 18] = [
+    Hotkey::new("save_palette", SavePalette(ActivePane)).shortcut(CTRL_K),
+    Hotkey::new("load_palette", LoadPalette(BothPanes)).shortcut(CTRL_L),
+    Hotkey::new("palette_black", SetPaletteBlack(ActivePane)).shortcut(KEY_B),
+    Hotkey::new("palette_white", SetPaletteWhite(ActivePane)).shortcut(KEY_W),
+    Hotkey::new("randomize_palette", RandomizePalette(ActivePane)).shortcut(KEY_R),
+    Hotkey::new("randomize_parent_palette", RandomizePalette(Id(Parent))),
+    Hotkey::new("randomize_child_palette", RandomizePalette(Id(Child))),
+    Hotkey::new("sync_palettes", TogglePaletteSync),
+    Hotkey::new("palette_generator", TogglePaletteGenerator),
+    Hotkey::new("pin_period_colors", TogglePinnedPeriodColors),
+    Hotkey::new("color_vision_filter", CycleColorVisionFilter),
+    Hotkey::new("toggle_layers", ToggleLayers(ActivePane)),
+    Hotkey::new("toggle_lighting", ToggleLighting(ActivePane)),
+    Hotkey::new("increase_palette_period", ScalePalettePeriod(1.25)).shortcut(KEY_UP),
+    Hotkey::new("decrease_palette_period", ScalePalettePeriod(0.8)).shortcut(KEY_DOWN),
+    Hotkey::new("shift_palette_left", ShiftPalettePhase(-0.02)).shortcut(KEY_LEFT),
+    Hotkey::new("shift_palette_right", ShiftPalettePhase(0.02)).shortcut(KEY_RIGHT),
+    Hotkey::new("color_cycling", ToggleColorCycling(ActivePane)),
 ];
 
 seq!(n in 1..=6 {
-pub const CYCLES_HOTKEYS: [Hotkey; 12] = [
+pub static CYCLES_HOTKEYS: [Hotkey; 12] = [
     #(
-        Hotkey::new(ToggleCycles(Id(Child), n)).shortcut(CTRL_~n),
-        Hotkey::new(ToggleCycles(Id(Parent), n)).shortcut(CTRL_SHIFT_~n).hide_in_menu(),
+        Hotkey::new(concat!("toggle_child_cycles_", n), ToggleCycles(Id(Child), n))
+            .shortcut(CTRL_~n),
+        Hotkey::new(concat!("toggle_parent_cycles_", n), ToggleCycles(Id(Parent), n))
+            .shortcut(CTRL_SHIFT_~n)
+            .hide_in_menu(),
     )*
 ];
 });
 
-pub const ANNOTATION_HOTKEYS: [Hotkey; 17] = [
+pub static ANNOTATION_HOTKEYS: [Hotkey; 37] = [
     // External ray
-    Hotkey::new(DrawExternalRay {
-        include_orbit: false,
-        select_landing_point: false,
-    })
+    Hotkey::new(
+        "draw_ray",
+        DrawExternalRay {
+            include_orbit: false,
+            select_landing_point: false,
+        },
+    )
     .shortcut(KEY_E),
     // External ray to point
-    Hotkey::new(DrawExternalRay {
-        include_orbit: false,
-        select_landing_point: true,
-    })
+    Hotkey::new(
+        "ray_to_point",
+        DrawExternalRay {
+            include_orbit: false,
+            select_landing_point: true,
+        },
+    )
     .shortcut(KEY_Y),
     // External ray to point
-    Hotkey::new(DrawExternalRay {
-        include_orbit: false,
-        select_landing_point: true,
-    })
+    Hotkey::new(
+        "ray_to_point_alt",
+        DrawExternalRay {
+            include_orbit: false,
+            select_landing_point: true,
+        },
+    )
     .shortcut(CTRL_X)
     .hide_in_menu(),
     // Ray orbit
-    Hotkey::new(DrawExternalRay {
-        include_orbit: true,
-        select_landing_point: false,
-    })
+    Hotkey::new(
+        "ray_orbit",
+        DrawExternalRay {
+            include_orbit: true,
+            select_landing_point: false,
+        },
+    )
     .shortcut(SHIFT_O),
     // Rays of exact period
-    Hotkey::new(DrawRaysOfPeriod).shortcut(CTRL_E),
+    Hotkey::new("rays_of_period", DrawRaysOfPeriod).shortcut(CTRL_E),
+    // Rays at a list or pattern of angles
+    Hotkey::new("ray_batch", DrawRayBatch),
+    // Symbolic dynamics of an angle
+    Hotkey::new("angle_calculator", OpenAngleCalculator),
     // Equipotential
-    Hotkey::new(DrawContour(ContourType::Equipotential)).shortcut(KEY_G),
+    Hotkey::new("equipotential", DrawContour(ContourType::Equipotential)).shortcut(KEY_G),
     // Multiplier contour
-    Hotkey::new(DrawContour(ContourType::multiplier_auto())).shortcut(KEY_M),
+    Hotkey::new(
+        "multiplier_contour",
+        DrawContour(ContourType::multiplier_auto()),
+    )
+    .shortcut(KEY_M),
     // Many multiplier contours
-    Hotkey::new(DrawAuxContours).shortcut(SHIFT_M),
+    Hotkey::new("multiplier_contours", DrawAuxContours).shortcut(SHIFT_M),
+    // Where the critical value keeps its distance from critical and co-critical points
+    Hotkey::new("critical_value_curve", DrawContour(ContourType::CriticalRelation(1))),
+    // The same for the first few iterates of the critical point
+    Hotkey::new("critical_curves", DrawCriticalCurves),
+    // Outline of the hyperbolic component containing the selection
+    Hotkey::new("component_boundary", DrawComponentBoundary),
+    // Text written at the selection
+    Hotkey::new("edit_label", EditLabel),
+    // Measure between two clicked points
+    Hotkey::new("toggle_ruler", ToggleRuler),
     // Extend Ray
-    Hotkey::new(DrawContour(ContourType::ExtendRay)).shortcut(SHIFT_E),
+    Hotkey::new("extend_ray", DrawContour(ContourType::ExtendRay)).shortcut(SHIFT_E),
     // Inward Ray
-    Hotkey::new(DrawContour(ContourType::InwardRay)).shortcut(SHIFT_R),
+    Hotkey::new("inward_ray", DrawContour(ContourType::InwardRay)).shortcut(SHIFT_R),
+    // Self-similarity about a Misiurewicz point
+    Hotkey::new("misiurewicz_spiral", AnalyzeMisiurewicz),
+    // Boundary of a hyperbolic component, where its cycle has multiplier of modulus 1
+    Hotkey::new("multiplier_locus", TraceMultiplierLocus),
     // Bidirectional Ray
-    Hotkey::new(DrawContour(ContourType::ExtendRay))
+    Hotkey::new("bidirectional_ray", DrawContour(ContourType::ExtendRay))
         .bonus_action(DrawContour(ContourType::InwardRay))
         .shortcut(SHIFT_T)
         .hide_in_menu(),
-    Hotkey::new(ToggleCritical).shortcut(KEY_P),
-    Hotkey::new(ToggleMarked(ActivePane))
+    Hotkey::new("toggle_critical", ToggleCritical).shortcut(KEY_P),
+    Hotkey::new("toggle_marked", ToggleMarked(ActivePane))
         .shortcut(SHIFT_P)
         .hide_in_menu(),
-    Hotkey::new(DrawOrbit).shortcut(KEY_O),
-    Hotkey::new(StopFollowing).shortcut(KEY_ESC).hide_in_menu(),
-    Hotkey::new(ClearOrbit).shortcut(KEY_C),
-    Hotkey::new(ClearCurves).shortcut(SHIFT_C),
+    Hotkey::new("toggle_period_labels", TogglePeriodLabels(ActivePane)),
+    Hotkey::new("toggle_band_contours", ToggleBandContours(ActivePane)),
+    Hotkey::new("draw_orbit", DrawOrbit).shortcut(KEY_O),
+    Hotkey::new("orbit_playback", ToggleOrbitPlayback),
+    Hotkey::new("play_pause_orbit", PlayPauseOrbit),
+    Hotkey::new("previous_iterate", StepOrbit(-1)).shortcut(KEY_COMMA),
+    Hotkey::new("next_iterate", StepOrbit(1)).shortcut(KEY_PERIOD),
+    Hotkey::new("preimage_tree", TogglePreimageTree),
+    Hotkey::new("stop_following", StopFollowing)
+        .shortcut(KEY_ESC)
+        .hide_in_menu(),
+    Hotkey::new("clear_orbit", ClearOrbit).shortcut(KEY_C),
+    Hotkey::new("clear_curves", ClearCurves).shortcut(SHIFT_C),
+    Hotkey::new("clear_rays", ClearRays),
+    Hotkey::new("clear_equipotentials", ClearEquipotentials),
+    Hotkey::new("clear_loaded_points", ClearLoadedPoints),
+    Hotkey::new("clear_labels", ClearLabels),
 ];
 
-pub const SELECTION_HOTKEYS: [Hotkey; 5] = [
-    Hotkey::new(ToggleSelectionMarker).shortcut(KEY_I),
-    Hotkey::new(EnterCoordinates).shortcut(KEY_INSERT),
+pub static SELECTION_HOTKEYS: [Hotkey; 12] = [
+    Hotkey::new("toggle_selection_marker", ToggleSelectionMarker).shortcut(KEY_I),
+    Hotkey::new("enter_coordinates", EnterCoordinates).shortcut(KEY_INSERT),
     // Apply map on dynamical plane
-    Hotkey::new(MapSelection).shortcut(KEY_F),
+    Hotkey::new("map_selection", MapSelection).shortcut(KEY_F),
     // Find nearby periodic point
-    Hotkey::new(FindPeriodicPoint).shortcut(CTRL_F),
-    Hotkey::new(ResetSelection).shortcut(SHIFT_SPACE),
+    Hotkey::new("find_periodic_point", FindPeriodicPoint).shortcut(CTRL_F),
+    // Find a nearby parameter where a cycle has a prescribed multiplier
+    Hotkey::new("find_multiplier", FindMultiplier),
+    // Navigate into or out of small copies of the Mandelbrot set
+    Hotkey::new("tuning", Tune),
+    Hotkey::new("reset_selection", ResetSelection).shortcut(SHIFT_SPACE),
+    Hotkey::new("toggle_trail", ToggleTrail).shortcut(KEY_T),
+    Hotkey::new("show_trail", ShowTrail).shortcut(KEY_H),
+    Hotkey::new("clear_trail", ClearTrail),
+    Hotkey::new("toggle_orbit_tooltip", ToggleOrbitTooltip),
+    Hotkey::new("toggle_orbit_trace", ToggleOrbitTrace(ActivePane)),
 ];
 
-pub const IMAGE_HOTKEYS: [Hotkey; 14] = [
+pub static IMAGE_HOTKEYS: [Hotkey; 24] = [
     // Hotkey {
     //     shortcut: Some(KEY_H),
     //     action: PromptImageHeight,
     //     show_in_menu: true,
     //     menu_action_override: None,
     // },
-    Hotkey::new(ToggleLiveMode).shortcut(KEY_L),
-    Hotkey::new(ScaleMaxIter(2.0)).shortcut(KEY_EQUALS),
-    Hotkey::new(ScaleMaxIter(0.5)).shortcut(KEY_MINUS),
-    Hotkey::new(Pan(-0.01, 0.))
+    Hotkey::new("toggle_live_mode", ToggleLiveMode).shortcut(KEY_L),
+    Hotkey::new("toggle_comparison", ToggleComparison).shortcut(KEY_K),
+    Hotkey::new("toggle_minimap", ToggleMinimap).shortcut(KEY_N),
+    Hotkey::new("toggle_statistics", ToggleStatistics(ActivePane)),
+    Hotkey::new("toggle_attractor_inventory", ToggleAttractorInventory(ActivePane)),
+    Hotkey::new("toggle_pcf_database", TogglePcfDatabase(ActivePane)),
+    Hotkey::new("toggle_equilibrium_measure", ToggleEquilibriumMeasure(ActivePane)),
+    Hotkey::new("toggle_attractor_density", ToggleAttractorDensity(ActivePane)),
+    Hotkey::new("estimate_dimension", EstimateDimension),
+    Hotkey::new("estimate_area", EstimateArea),
+    Hotkey::new("increase_max_iter", ScaleMaxIter(2.0)).shortcut(KEY_EQUALS),
+    Hotkey::new("decrease_max_iter", ScaleMaxIter(0.5)).shortcut(KEY_MINUS),
+    Hotkey::new("pan_left", Pan(-0.01, 0.))
         .shortcut(SHIFT_LEFT)
         .hide_in_menu()
         .menu_action_override(Pan(-0.1, 0.)),
-    Hotkey::new(Pan(0.01, 0.))
+    Hotkey::new("pan_right", Pan(0.01, 0.))
         .shortcut(SHIFT_RIGHT)
         .hide_in_menu()
         .menu_action_override(Pan(0.1, 0.)),
-    Hotkey::new(Pan(0., 0.01))
+    Hotkey::new("pan_up", Pan(0., 0.01))
         .shortcut(SHIFT_UP)
         .hide_in_menu()
         .menu_action_override(Pan(0., 0.1)),
-    Hotkey::new(Pan(0., -0.01))
+    Hotkey::new("pan_down", Pan(0., -0.01))
         .shortcut(SHIFT_DOWN)
         .hide_in_menu()
         .menu_action_override(Pan(0., -0.1)),
-    Hotkey::new(Zoom(0.8)).shortcut(KEY_Z),
-    Hotkey::new(Zoom(0.125)).shortcut(CTRL_Z),
-    Hotkey::new(Zoom(1.25)).shortcut(KEY_V),
-    Hotkey::new(Zoom(8.)).shortcut(CTRL_V),
-    Hotkey::new(CenterOnSelection).shortcut(KEY_SPACE),
-    Hotkey::new(CycleActivePlane).shortcut(CTRL_P),
-    Hotkey::new(ResetView).shortcut(KEY_HOME),
+    Hotkey::new("zoom_in", Zoom(0.8)).shortcut(KEY_Z),
+    Hotkey::new("zoom_in_far", Zoom(0.125)).shortcut(CTRL_Z),
+    Hotkey::new("zoom_out", Zoom(1.25)).shortcut(KEY_V),
+    Hotkey::new("zoom_out_far", Zoom(8.)).shortcut(CTRL_V),
+    Hotkey::new("center_on_selection", CenterOnSelection).shortcut(KEY_SPACE),
+    Hotkey::new("cycle_active_plane", CycleActivePlane).shortcut(CTRL_P),
+    Hotkey::new("reset_view", ResetView).shortcut(KEY_HOME),
+    Hotkey::new("change_coordinates", ChangeCoordinates),
 ];
 
-pub const INCOLORING_HOTKEYS: [Hotkey; 8] = [
-    Hotkey::new(SetColoring(IncoloringAlgorithm::Solid)).shortcut(KEY_0),
-    Hotkey::new(SetColoring(IncoloringAlgorithm::Period)).shortcut(KEY_1),
-    Hotkey::new(SetColoring(IncoloringAlgorithm::PeriodMultiplier)).shortcut(KEY_2),
-    Hotkey::new(SetColoring(IncoloringAlgorithm::Multiplier)).shortcut(KEY_3),
-    Hotkey::new(SetColoring(IncoloringAlgorithm::Preperiod)).shortcut(KEY_4),
-    Hotkey::new(SetColoringInternalPotential).shortcut(KEY_5),
-    Hotkey::new(SetColoringPreperiodPeriod).shortcut(KEY_6),
-    Hotkey::new(SetColoringPotentialPeriod).shortcut(KEY_7),
+pub static INCOLORING_HOTKEYS: [Hotkey; 10] = [
+    Hotkey::new("coloring_solid", SetColoring(IncoloringAlgorithm::Solid)).shortcut(KEY_0),
+    Hotkey::new("coloring_period", SetColoring(IncoloringAlgorithm::Period)).shortcut(KEY_1),
+    Hotkey::new(
+        "coloring_period_multiplier",
+        SetColoring(IncoloringAlgorithm::PeriodMultiplier),
+    )
+    .shortcut(KEY_2),
+    Hotkey::new(
+        "coloring_multiplier",
+        SetColoring(IncoloringAlgorithm::Multiplier),
+    )
+    .shortcut(KEY_3),
+    Hotkey::new(
+        "coloring_preperiod",
+        SetColoring(IncoloringAlgorithm::Preperiod),
+    )
+    .shortcut(KEY_4),
+    Hotkey::new("coloring_internal_potential", SetColoringInternalPotential).shortcut(KEY_5),
+    Hotkey::new("coloring_preperiod_period", SetColoringPreperiodPeriod).shortcut(KEY_6),
+    Hotkey::new("coloring_potential_period", SetColoringPotentialPeriod).shortcut(KEY_7),
+    Hotkey::new(
+        "coloring_preperiod_period_smooth",
+        SetColoring(IncoloringAlgorithm::PreperiodPeriodSmooth { fill_rate: 0.02 }),
+    )
+    .shortcut(KEY_8),
+    Hotkey::new("toggle_basins", ToggleBasins(ActivePane)),
 ];
 
-pub const OUTCOLORING_HOTKEYS: [Hotkey; 4] = [
-    Hotkey::new(ToggleEscapePhaseColoring).shortcut(KEY_J),
-    Hotkey::new(CycleComputeMode(ActivePane, ChangeBoolean::Toggle))
-        .shortcut(KEY_D)
-        .hide_in_menu()
-        .menu_action_override(CycleComputeMode(ActivePane, ChangeBoolean::Enable)),
-    Hotkey::new(CycleComputeMode(BothPanes, ChangeBoolean::Disable)),
-    Hotkey::new(CycleComputeMode(BothPanes, ChangeBoolean::Enable)),
+pub static OUTCOLORING_HOTKEYS: [Hotkey; 17] = [
+    Hotkey::new("toggle_escape_phase", ToggleEscapePhaseColoring).shortcut(KEY_J),
+    Hotkey::new("toggle_binary_decomposition", ToggleBinaryDecomposition),
+    Hotkey::new("more_decomposition_sectors", ScaleDecompositionSectors(2.0)),
+    Hotkey::new("fewer_decomposition_sectors", ScaleDecompositionSectors(0.5)),
+    Hotkey::new("cycle_custom_coloring", CycleOutcoloring),
+    Hotkey::new("toggle_wring_animation", ToggleWringAnimation),
+    Hotkey::new(
+        "toggle_compute_mode",
+        CycleComputeMode(ActivePane, ChangeBoolean::Toggle),
+    )
+    .shortcut(KEY_D)
+    .hide_in_menu()
+    .menu_action_override(CycleComputeMode(ActivePane, ChangeBoolean::Enable)),
+    Hotkey::new(
+        "disable_distance_estimate",
+        CycleComputeMode(BothPanes, ChangeBoolean::Disable),
+    ),
+    Hotkey::new(
+        "enable_distance_estimate",
+        CycleComputeMode(BothPanes, ChangeBoolean::Enable),
+    ),
+    Hotkey::new("toggle_inverse_iteration", ToggleInverseIteration(ActivePane)),
+    Hotkey::new("toggle_lyapunov", ToggleLyapunov(ActivePane)),
+    Hotkey::new("toggle_stochastic", ToggleStochastic(ActivePane)),
+    Hotkey::new("increase_noise", ScaleNoise(2.0)),
+    Hotkey::new("decrease_noise", ScaleNoise(0.5)),
+    Hotkey::new("toggle_flow", ToggleFlow(ActivePane)),
+    Hotkey::new("toggle_log_coordinates", ToggleLogCoordinates(ActivePane)),
+    Hotkey::new("toggle_adaptive_iterations", ToggleAdaptiveIterations(ActivePane)),
 ];
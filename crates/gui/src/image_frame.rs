@@ -1,6 +1,8 @@
 use egui::containers::Frame;
 use egui::{Pos2, Rect, TextureOptions, Ui, Vec2};
 use epaint::{ColorImage, Stroke, TextureHandle};
+use std::error::Error;
+use std::ops::Range;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -132,10 +134,95 @@ impl ImageFrame
     {
         self.region.min + local_pos
     }
+    /// Encode the image as an RGBA PNG.
+    pub fn encode_png(&self) -> Result<Vec<u8>, Box<dyn Error>>
+    {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(
+            &mut bytes,
+            u32::try_from(self.width())?,
+            u32::try_from(self.height())?,
+        );
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let rgba: Vec<u8> = self
+            .image
+            .pixels
+            .iter()
+            .flat_map(|color| color.to_srgba_unmultiplied())
+            .collect();
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&rgba)?;
+        writer.finish()?;
+        Ok(bytes)
+    }
+    /// Upload the whole image to the texture. While the size of the image is unchanged, the
+    /// texture is overwritten in place, so that the renderer keeps its GPU buffer rather than
+    /// allocating a new one.
     pub fn update_texture(&mut self)
     {
-        if let Some(handle) = self.texture_id.as_mut() {
+        let Some(handle) = self.texture_id.as_mut() else {
+            return;
+        };
+        if handle.size() == self.image.size {
+            handle.set_partial([0, 0], self.image.clone(), TextureOptions::default());
+        } else {
             handle.set(self.image.clone(), TextureOptions::default());
         }
     }
+    /// Replace the image, uploading to the texture only the rows that changed.
+    pub fn set_image(&mut self, image: ColorImage)
+    {
+        let previous = std::mem::replace(&mut self.image, image);
+        self.update_texture_since(&previous);
+    }
+    /// Upload the rows of the image that differ from `previous`, the image last uploaded.
+    pub fn update_texture_since(&mut self, previous: &ColorImage)
+    {
+        let width = self.image.width();
+        if previous.size != self.image.size || width == 0 {
+            self.update_texture();
+            return;
+        }
+        let changed: Vec<bool> = self
+            .image
+            .pixels
+            .chunks_exact(width)
+            .zip(previous.pixels.chunks_exact(width))
+            .map(|(row, old_row)| row != old_row)
+            .collect();
+        let mut first_changed = None;
+        for (row, &changed) in changed.iter().chain([&false]).enumerate() {
+            match first_changed {
+                None if changed => first_changed = Some(row),
+                Some(first) if !changed => {
+                    self.update_texture_rows(first..row);
+                    first_changed = None;
+                }
+                _ => {}
+            }
+        }
+    }
+    /// Upload only the given rows of the image to the texture, e.g. those of tiles that have
+    /// just been computed.
+    pub fn update_texture_rows(&mut self, rows: Range<usize>)
+    {
+        let Some(handle) = self.texture_id.as_mut() else {
+            return;
+        };
+        if handle.size() != self.image.size {
+            self.update_texture();
+            return;
+        }
+        let width = self.image.width();
+        let rows = rows.start..rows.end.min(self.image.height());
+        if rows.is_empty() {
+            return;
+        }
+        let strip = ColorImage {
+            size: [width, rows.len()],
+            pixels: self.image.pixels[rows.start * width..rows.end * width].to_vec(),
+        };
+        handle.set_partial([0, rows.start], strip, TextureOptions::default());
+    }
 }
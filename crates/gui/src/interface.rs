@@ -1,28 +1,42 @@
-use egui::{Context, CursorIcon, InputState, Ui};
+use egui::{Context, CursorIcon, InputState, MultiTouchInfo, Ui};
 use egui_extras::{Column, TableBuilder};
 use egui_file::FileDialog;
 
-use dynamo_color::{IncoloringAlgorithm, Palette};
+use dynamo_color::palette::{DiscretePalette, PaletteGenerator};
+use dynamo_color::{IncoloringAlgorithm, Palette, Transparency, Wring};
+use dynamo_common::angle_pattern::AnglePattern;
 use dynamo_common::prelude::*;
-use dynamo_core::{dynamics::Displayable, prelude::HasChild};
+use dynamo_common::symbolic_dynamics::MAX_COMPANION_PERIOD;
+use dynamo_common::tuning;
+use dynamo_core::error::DynamoResult;
+use dynamo_core::{dynamics::{Displayable, PaneLink}, prelude::{Bifurcation, HasChild}};
 
 use crate::{
     actions::Action,
+    complex_input::ComplexInput,
     dialog::{
-        AllActiveRayParams, Dialog, RayParams, SaveFileType, TextDialogBuilder, TextInputType,
-        ToggleKey, ToggleMap,
-    },
-    hotkeys::{
-        keyboard_shortcuts::shortcut_used, Hotkey, ANNOTATION_HOTKEYS, CYCLES_HOTKEYS,
-        FILE_HOTKEYS, IMAGE_HOTKEYS, INCOLORING_HOTKEYS, OUTCOLORING_HOTKEYS, PALETTE_HOTKEYS,
-        SELECTION_HOTKEYS,
+        AllActiveRayParams, AngleCalculator, Dialog, LoadFileType, PointDialog, RayParams,
+        SaveFileType, TextDialogBuilder, TextInputType, ToggleKey, ToggleMap, TrailDialog,
     },
+    hotkeys::{keyboard_shortcuts::shortcut_used, keymap::Keymap},
+    marked_points::OrbitPlayback,
+    notifications::{FileKind, FileOperation, Notification, Notifications, Recovery},
     pane::{
+        comparison::ComparisonPane,
         id::{PaneID, PaneSelection},
-        tasks::{ChildTask, FollowState, SelectOrFollow},
+        preimages::PreimageExplorer,
+        preview::JuliaPreview,
+        tasks::{ChildTask, ColorCycle, FollowState, SelectOrFollow},
         Pane, WindowPane,
     },
+    render_job::RenderJob,
+    view_state::ViewState,
 };
+use std::error::Error;
+use std::path::Path;
+
+/// Width in pixels of the images saved from the panes.
+const EXPORT_WIDTH: usize = 4096;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -37,6 +51,11 @@ pub enum UiMessage
     CloseWindow,
     Quit,
     NewTab,
+    OpenRenderQueue,
+    OpenShortcutEditor,
+    OpenCommandPalette,
+    OpenPreferences,
+    OpenBookmarks,
 }
 impl UiMessage
 {
@@ -54,7 +73,12 @@ pub trait PanePair
     fn parent_mut(&mut self) -> &mut dyn Pane;
     fn child(&self) -> &dyn Pane;
     fn child_mut(&mut self) -> &mut dyn Pane;
-    fn randomize_palette(&mut self);
+    /// The pane showing the dynamics for a second parameter next to the child, if any.
+    fn comparison(&self) -> Option<&dyn Pane>;
+    fn comparison_mut(&mut self) -> Option<&mut dyn Pane>;
+    /// Give the selected panes a new random palette each, or one palette shared by both panes
+    /// while their palettes are synced.
+    fn randomize_palette(&mut self, panes: PaneSelection);
     fn set_palette(&mut self, palette: Palette);
     fn set_coloring_algorithm(&mut self, coloring_algorithm: IncoloringAlgorithm);
 
@@ -64,9 +88,23 @@ pub trait PanePair
     fn get_active_pane(&self) -> Option<&dyn Pane>;
     fn get_active_pane_mut(&mut self) -> Option<&mut dyn Pane>;
     fn get_selected_pane_ids(&self, selection: PaneSelection) -> Vec<PaneID>;
-    fn prompt_save_image(&mut self, panes: PaneSelection);
+    /// Choose a file to which to save the images of the selected panes, leaving the points
+    /// selected by `transparency` transparent.
+    fn prompt_save_image(&mut self, panes: PaneSelection, transparency: Transparency);
     fn prompt_save_palette(&mut self, panes: PaneSelection);
     fn prompt_load_palette(&mut self, panes: PaneSelection);
+    fn prompt_save_data(&mut self, panes: PaneSelection);
+    fn prompt_save_orbit(&mut self, panes: PaneSelection);
+    fn prompt_save_rays(&mut self, panes: PaneSelection);
+    /// Choose a file to which to save the boundaries between bands of escaping points as
+    /// vector graphics.
+    fn prompt_save_band_contours(&mut self, panes: PaneSelection);
+    /// Choose a file to which to export the catalog of postcritically finite parameters of the
+    /// parent plane.
+    fn prompt_save_pcf_catalog(&mut self, max_len: Period);
+    fn prompt_load_rays(&mut self, panes: PaneSelection);
+    fn prompt_open_data(&mut self, panes: PaneSelection);
+    fn prompt_load_points(&mut self, panes: PaneSelection);
     fn prompt_text(&mut self, input_type: TextInputType);
 
     /// Updates the state of both the parent and child panes.
@@ -88,8 +126,135 @@ pub trait Interactive
     fn name(&self) -> String;
     fn get_image_height(&self) -> usize;
     fn change_height(&mut self, new_height: usize);
+    fn set_max_iter(&mut self, max_iter: IterCount);
     fn show(&mut self, ui: &mut Ui);
     fn process_action(&mut self, action: &Action);
+    /// Capture the current state of a pane as an export that can run in the background.
+    fn render_job(
+        &self,
+        pane_id: PaneID,
+        img_width: usize,
+        filename: &Path,
+        transparency: Transparency,
+    ) -> RenderJob;
+    /// Encode the image currently shown in a pane as a PNG.
+    fn encode_png(&self, pane_id: PaneID) -> Result<Vec<u8>, Box<dyn Error>>;
+    fn view_state(&self) -> ViewState;
+    /// Show a view previously returned by `view_state`, e.g. one decoded from a shared link.
+    fn restore_view_state(&mut self, view: &ViewState);
+    /// Show a message in the corner of the window, e.g. to report an error.
+    fn notify(&mut self, notification: Notification);
+    /// Take the recovery chosen for a failed operation that the interface cannot repeat itself,
+    /// such as saving a script.
+    fn pop_recovery(&mut self) -> Option<(FileOperation, Recovery)>;
+}
+
+/// Time in seconds that a finger must rest on the screen to select a point.
+const LONG_PRESS_DURATION: f64 = 0.5;
+/// Distance in points that a finger may move during a long press.
+const LONG_PRESS_TOLERANCE: f32 = 8.;
+/// Longest critical orbit, as preperiod plus period, for which a catalog can be exported. The
+/// number of rays to land grows exponentially with it.
+const MAX_CATALOG_LEN: Period = 14;
+
+/// Show how many of the rays being traced on a pane have been traced so far, and likewise for
+/// the parameter rays being landed for a catalog.
+fn show_ray_progress(ui: &mut Ui, pane: &dyn Pane)
+{
+    if let Some((done, total)) = pane.ray_progress() {
+        let fraction = done as f32 / total.max(1) as f32;
+        ui.add(egui::ProgressBar::new(fraction).text(format!("Tracing rays: {done}/{total}")));
+    }
+    if let Some((done, total)) = pane.catalog_progress() {
+        let fraction = done as f32 / total.max(1) as f32;
+        let text = format!("Landing rays for catalog: {done}/{total}");
+        ui.add(egui::ProgressBar::new(fraction).text(text));
+    }
+}
+
+/// With a root angle of a hyperbolic component and another angle, jump to the parameter of the
+/// small copy of the Mandelbrot set carried by the component that corresponds to the other angle.
+/// With a single angle, jump out of the small copy containing it to the corresponding parameter of
+/// the whole set. Returns a description of the jump.
+fn navigate_tuning(pane: &mut dyn Pane, text: &str) -> Result<String, String>
+{
+    if pane.degree() != 2 || pane.plane_type().is_dynamical() {
+        return Err("Tuning is only available on the Mandelbrot set.".to_owned());
+    }
+    let angles = text
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(str::parse::<RationalAngle>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    let untraced = |angle| format!("The ray at angle {angle} could not be traced.");
+
+    match *angles.as_slice() {
+        [root_angle, angle] => {
+            let tuned = tuning::tune(root_angle, angle).map_err(|e| e.to_string())?;
+            let target = pane
+                .select_ray_landing_point(tuned)
+                .ok_or_else(|| untraced(tuned))?;
+            // The small copy is about as large as the distance from its root to the target
+            if let Some(root) = pane.ray_landing_point(root_angle) {
+                let size = 4. * (target - root).norm();
+                if size > 0. {
+                    pane.grid_mut().recenter(target);
+                    pane.zoom(size / pane.grid().range_x(), target);
+                }
+            }
+            Ok(format!(
+                "In the small copy at the component with root angle {root_angle},\n\
+                angle {angle} corresponds to angle {tuned}.\n\
+                Selected {target:.6}."
+            ))
+        }
+        [angle] => {
+            let renormalization = tuning::renormalize(angle).ok_or_else(|| {
+                format!(
+                    "Angle {angle} does not lie in a small copy of the Mandelbrot set\n\
+                    at a component of period at most {MAX_COMPANION_PERIOD}."
+                )
+            })?;
+            let (lower, upper) = renormalization.root_angles;
+            let base_angle = renormalization.base_angle;
+            pane.reset();
+            let target = pane
+                .select_ray_landing_point(base_angle)
+                .ok_or_else(|| untraced(base_angle))?;
+            Ok(format!(
+                "Angle {angle} lies in the small copy at the component of period {period}\n\
+                with root angles {lower} and {upper}, where it corresponds to angle {base_angle}.\n\
+                Selected {target:.6}.",
+                period = renormalization.period,
+            ))
+        }
+        _ => Err("Enter a root angle and an angle to tune, or a single angle.".to_owned()),
+    }
+}
+
+/// Select a parameter near the selection at which a cycle has the multiplier given in `text`,
+/// either as a complex number or as an internal angle p/q standing for e^(2πi p/q). Returns a
+/// description of the parameter found.
+fn find_multiplier(pane: &mut dyn Pane, text: &str) -> Result<String, String>
+{
+    let usage = || "Enter a period and a multiplier, e.g. <3, 1/5> or <2, 0.5+0.5i>.".to_owned();
+    let (period, target) = text.split_once(',').ok_or_else(usage)?;
+    let period = period.trim().parse::<Period>().map_err(|_| usage())?;
+    let target = target.trim();
+    let multiplier = if target.contains('/') {
+        let angle = target.parse::<RationalAngle>().map_err(|e| e.to_string())?;
+        Cplx::from_polar(1., std::f64::consts::TAU * Real::from(angle))
+    } else {
+        target.parse::<Cplx>().map_err(|_| usage())?
+    };
+    let found = pane
+        .select_nearby_multiplier(period, multiplier)
+        .map_err(|e| format!("No parameter found: {e:?}"))?;
+    Ok(format!(
+        "Selected {found:.DISPLAY_PREC$},\n\
+        where a cycle of period {period} has multiplier {multiplier:.DISPLAY_PREC$}."
+    ))
 }
 
 /// The main interface structure that holds the parent and child panes along with UI state.
@@ -101,13 +266,42 @@ where
 {
     parent: WindowPane<P>,
     child: WindowPane<J>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    comparison: Option<ComparisonPane<J>>,
     image_height: usize,
     active_pane: Option<PaneID>,
     live_mode: bool,
+    /// How the point selected on the parent determines the child, as declared by the parent.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    link: PaneLink,
+    /// Julia set for the parameter under the pointer, shown while scanning the parent pane in
+    /// live mode.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    julia_preview: JuliaPreview,
+    /// Pane whose coloring is being wrung by an animation, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    wring_pane: Option<PaneID>,
+    /// Draws random palettes, and remembers their seeds so that they can be recovered.
+    #[cfg_attr(feature = "serde", serde(default))]
+    palette_generator: PaletteGenerator,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    show_palette_generator: bool,
+    /// Pane to which palettes chosen in the palette generator are applied, or both if `None`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    palette_generator_pane: Option<PaneID>,
+    /// Whether changes to the palette of either pane are made to both.
+    #[cfg_attr(feature = "serde", serde(default))]
+    sync_palettes: bool,
     #[cfg_attr(feature = "serde", serde(skip))]
     dialog: Option<Dialog>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    notifications: Notifications,
+    /// Recovery chosen for an operation owned by the app, until the app takes it.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    recovery: Option<(FileOperation, Recovery)>,
     // save_task: SaveTask,
     click_used: bool,
+    long_press_used: bool,
     pub message: UiMessage,
 }
 
@@ -119,14 +313,26 @@ where
     /// Constructs a new `MainInterface` with the given parent and child panes and image height.
     pub fn new(parent: P, child: J, image_height: usize) -> Self
     {
+        let link = parent.pane_link();
         Self {
             parent: parent.into(),
             child: child.into(),
+            comparison: None,
             image_height,
             active_pane: Some(PaneID::Parent),
             live_mode: false,
+            link,
+            julia_preview: JuliaPreview::default(),
+            wring_pane: None,
+            palette_generator: PaletteGenerator::default(),
+            show_palette_generator: false,
+            palette_generator_pane: None,
+            sync_palettes: false,
             dialog: None,
+            notifications: Notifications::default(),
+            recovery: None,
             click_used: false,
+            long_press_used: false,
             message: UiMessage::default(),
         }
     }
@@ -138,6 +344,11 @@ where
         let old_default_center = self.child.plane.default_bounds().center();
 
         if self.child.set_param(P::to_child_param(new_param)) {
+            if self.link == PaneLink::Fiber {
+                // Fibers over nearby base points lie in the same region, so the view is kept
+                self.child.schedule_recompute();
+                return;
+            }
             let mut new_bounds = self.child.plane.default_bounds();
 
             // Set the new center to equal the old center plus whatever deviation the user has created
@@ -158,6 +369,324 @@ where
         }
     }
 
+    /// Show the Julia set for a second parameter next to the child, starting from the current
+    /// parameter, or stop doing so.
+    fn toggle_comparison(&mut self)
+    {
+        self.comparison = match self.comparison {
+            Some(_) => None,
+            None => Some(ComparisonPane::new(
+                &self.child,
+                self.parent.get_selection(),
+            )),
+        };
+    }
+
+    /// Show the dynamics for the parameter at `point` in the comparison pane.
+    fn set_comparison_param(&mut self, point: Cplx)
+    {
+        let param = P::to_child_param(self.parent.plane.param_map(point));
+        if let Some(comparison) = &mut self.comparison {
+            comparison.set_param(point, param);
+        }
+    }
+
+    /// Show the Julia set for the parameter at `point` of the parent plane next to the pointer.
+    fn show_julia_preview(&mut self, ctx: &Context, pointer_pos: egui::Pos2, point: Cplx)
+    {
+        let point = self.parent.grid().transform.apply(point);
+        let param = P::to_child_param(self.parent.plane.param_map(point));
+        self.julia_preview.update(&self.child, point, param);
+        self.julia_preview.put(ctx, pointer_pos);
+    }
+
+    /// Whether the pointer position lies in the comparison pane.
+    fn comparison_contains_pixel(&self, pointer_pos: egui::Pos2) -> bool
+    {
+        self.comparison
+            .as_ref()
+            .is_some_and(|comparison| comparison.pane.frame_contains_pixel(pointer_pos))
+    }
+
+    /// Sets new meta-parameters for the parent plane, propagating them to the child plane.
+    fn set_meta_params(&mut self, meta_params: P::MetaParam)
+    {
+        self.parent.plane.set_meta_param(meta_params);
+        self.parent
+            .plane
+            .update_child_meta_params(&mut self.child.plane);
+        self.parent.schedule_recompute();
+        self.parent.invalidate_minimap();
+        self.child.invalidate_minimap();
+        if let Some(comparison) = &mut self.comparison {
+            self.parent
+                .plane
+                .update_child_meta_params(&mut comparison.pane.plane);
+            comparison.pane.schedule_recompute();
+            comparison.pane.invalidate_minimap();
+            let point = comparison.parent_point;
+            self.set_comparison_param(point);
+        }
+
+        // The parameter map may depend on the meta-parameters
+        let parent_selection = self.parent.get_selection();
+        let new_child_param = self.parent.plane.param_map(parent_selection);
+        self.set_child_param(new_child_param);
+        self.child.schedule_recompute();
+    }
+
+    /// Shows a slider for each adjustable meta-parameter of the parent plane, e.g. those declared
+    /// by user scripts, and a button to edit any meta-parameter entered as text.
+    fn show_meta_param_sliders(&mut self, ui: &mut Ui)
+    {
+        let mut meta_params = self.parent.plane.get_meta_params();
+        let sliders = meta_params.sliders();
+        let text_entry = meta_params.text_entry();
+        if sliders.is_empty() && text_entry.is_none() {
+            return;
+        }
+
+        let mut changed = false;
+        let mut edit_text = false;
+        ui.horizontal_wrapped(|ui| {
+            if let Some(entry) = text_entry {
+                ui.label(format!("{}: {}", entry.name, entry.value));
+                edit_text = ui.button("Edit").clicked();
+            }
+            for (idx, slider) in sliders.into_iter().enumerate() {
+                let mut value = slider.value;
+                ui.label(&slider.name);
+                let response = if slider.integer {
+                    let mut n = value.re.round() as i32;
+                    let range = slider.min as i32..=slider.max as i32;
+                    let response = ui.add(egui::DragValue::new(&mut n).range(range));
+                    value = Cplx::from(Real::from(n));
+                    response
+                } else if slider.complex {
+                    let radius = slider.min.abs().max(slider.max.abs());
+                    let input = ComplexInput::new(&mut value, ("meta_param", idx));
+                    ui.add(input.plot_radius(radius))
+                } else {
+                    ui.add(egui::Slider::new(&mut value.re, slider.min..=slider.max))
+                };
+                changed |= response.changed();
+                meta_params.set_slider(idx, value);
+            }
+        });
+
+        if changed {
+            self.set_meta_params(meta_params);
+        }
+        if edit_text {
+            self.prompt_text(TextInputType::MetaParam);
+        }
+    }
+
+    /// Shows a slider for the time by which each continuously iterated pane is flowed, so that
+    /// dragging it animates the image between iterates of the map.
+    fn show_flow_sliders(&mut self, ui: &mut Ui)
+    {
+        const MAX_FLOW_TIME: Real = 4.;
+
+        let panes: [&mut dyn Pane; 2] = [&mut self.parent, &mut self.child];
+        for pane in panes {
+            let Some(mut time) = pane.flow_time() else {
+                continue;
+            };
+            ui.horizontal(|ui| {
+                ui.label(format!("Iterate {} by t =", pane.name()));
+                let slider = egui::Slider::new(&mut time, 0.0..=MAX_FLOW_TIME);
+                if ui.add(slider).changed() {
+                    pane.set_flow_time(time);
+                }
+            });
+        }
+    }
+
+    /// Shows the period and hue offset of the escape phase coloring on each pane that uses it.
+    fn show_escape_phase_controls(&mut self, ui: &mut Ui)
+    {
+        const MAX_ESCAPE_PERIOD: Period = 64;
+
+        let panes: [&mut dyn Pane; 2] = [&mut self.parent, &mut self.child];
+        for pane in panes {
+            let name = pane.name();
+            let coloring = pane.get_coloring_mut();
+            if !coloring.is_escape_phase_coloring() {
+                continue;
+            }
+            let mut period = coloring.get_escape_period();
+            let mut offset = coloring.get_escape_phase_offset();
+            let mut changed = false;
+            ui.horizontal(|ui| {
+                ui.label(format!("Escape phases of {name}: period"));
+                let period_input = egui::DragValue::new(&mut period).range(1..=MAX_ESCAPE_PERIOD);
+                changed |= ui.add(period_input).changed();
+                ui.label("offset");
+                changed |= ui.add(egui::Slider::new(&mut offset, 0.0..=1.0)).changed();
+            });
+            if changed {
+                coloring.set_escape_period(period);
+                coloring.set_escape_phase_offset(offset);
+                pane.schedule_redraw();
+            }
+        }
+    }
+
+    /// Shows the cycling rate of each pane whose palette is cycling.
+    fn show_color_cycle_controls(&mut self, ui: &mut Ui)
+    {
+        let max_rate = ColorCycle::MAX_RATE;
+        let panes: [&mut dyn Pane; 2] = [&mut self.parent, &mut self.child];
+        for pane in panes {
+            if !pane.is_color_cycling() {
+                continue;
+            }
+            let name = pane.name();
+            let rate = &mut pane.tasks_mut().color_cycle.rate;
+            ui.horizontal(|ui| {
+                ui.label(format!("Color cycling of {name}: periods per second"));
+                ui.add(egui::Slider::new(rate, -max_rate..=max_rate));
+            });
+        }
+    }
+
+    /// Shows the iterate reached in playback of the orbit on the child pane, with buttons to step
+    /// through it and the playback speed.
+    fn show_orbit_playback_controls(&mut self, ui: &mut Ui)
+    {
+        let marking = self.child.marking_mut();
+        let playback = marking.orbit_playback();
+        if !playback.enabled {
+            return;
+        }
+        let mut playing = playback.playing;
+        let mut speed = playback.speed;
+        let mut steps = 0;
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "Orbit iterate {} of {}",
+                playback.step(),
+                playback.last_index()
+            ));
+            if ui.button("⏮").clicked() {
+                steps = -1;
+            }
+            if ui.button(if playing { "⏸" } else { "▶" }).clicked() {
+                playing ^= true;
+            }
+            if ui.button("⏭").clicked() {
+                steps = 1;
+            }
+            ui.label("Iterates per second");
+            ui.add(egui::Slider::new(&mut speed, 0.5..=OrbitPlayback::MAX_SPEED).logarithmic(true));
+        });
+        if playing != playback.playing {
+            marking.set_orbit_playing(playing);
+        }
+        marking.set_orbit_playback_speed(speed);
+        if steps != 0 {
+            marking.step_orbit(steps);
+        }
+    }
+
+    /// Shows the depth and pruning of the tree of preimages on the child pane, if it is shown.
+    fn show_preimage_controls(&mut self, ui: &mut Ui)
+    {
+        let Some(explorer) = self.child.preimage_explorer_mut() else {
+            return;
+        };
+        ui.horizontal(|ui| {
+            ui.label("Preimage tree: depth");
+            let depth = egui::DragValue::new(&mut explorer.depth)
+                .range(1..=PreimageExplorer::MAX_DEPTH);
+            ui.add(depth);
+            ui.label("prune within");
+            let max_pixels = PreimageExplorer::MAX_PRUNE_PIXELS;
+            ui.add(egui::Slider::new(&mut explorer.prune_pixels, 0.0..=max_pixels).suffix(" px"));
+        });
+    }
+
+    /// Shows the constraints on random palettes, and a button for each recent seed that restores
+    /// its palette on the chosen panes.
+    fn show_palette_generator(&mut self, ui: &mut Ui)
+    {
+        if !self.show_palette_generator {
+            return;
+        }
+        let mut recalled = None;
+        let mut curated = None;
+        let mut cycle_filter = false;
+        let mut toggle_sync = false;
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Apply to");
+            ui.add_enabled_ui(!self.sync_palettes, |ui| {
+                let target = &mut self.palette_generator_pane;
+                ui.selectable_value(target, None, "Both panes");
+                ui.selectable_value(target, Some(PaneID::Parent), "Parent");
+                ui.selectable_value(target, Some(PaneID::Child), "Child");
+            });
+            let mut sync = self.sync_palettes;
+            toggle_sync = ui
+                .checkbox(&mut sync, "Sync palettes")
+                .on_hover_text(Action::TogglePaletteSync.description())
+                .changed();
+        });
+        let generator = &mut self.palette_generator;
+        ui.horizontal_wrapped(|ui| {
+            let constraints = &mut generator.constraints;
+            ui.label("Hues from");
+            ui.add(egui::Slider::new(&mut constraints.hue_range.0, 0.0..=1.0));
+            ui.label("to");
+            ui.add(egui::Slider::new(&mut constraints.hue_range.1, 0.0..=1.0));
+            ui.label("Contrast");
+            ui.add(egui::Slider::new(&mut constraints.contrast, 0.0..=1.0));
+            ui.label("Brightness");
+            ui.add(egui::Slider::new(&mut constraints.brightness, 0.0..=1.0));
+            ui.checkbox(&mut constraints.colorblind_safe, "Colorblind-safe");
+        });
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Recent seeds:");
+            for record in generator.history() {
+                if ui.button(format!("{:016x}", record.seed)).clicked() {
+                    recalled = Some(record.palette());
+                }
+            }
+        });
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Colorblind-safe period colors:");
+            for (name, period_coloring) in DiscretePalette::COLORBLIND_SAFE {
+                if ui.button(name).clicked() {
+                    curated = Some(period_coloring);
+                }
+            }
+            let filter = self.parent.get_color_vision_filter();
+            cycle_filter = ui
+                .button(format!("Preview: {}", filter.name()))
+                .on_hover_text("Show the images as seen with a form of color blindness")
+                .clicked();
+        });
+        if toggle_sync {
+            self.toggle_palette_sync();
+        }
+        let selection = self
+            .palette_generator_pane
+            .map_or(PaneSelection::BothPanes, PaneSelection::Id);
+        if let Some(palette) = recalled {
+            self.set_palette_keeping_period_colors(selection, palette);
+        }
+        if let Some(period_coloring) = curated {
+            for pane_id in self.get_palette_pane_ids(selection) {
+                let pane = self.get_pane_mut(pane_id);
+                *pane.get_coloring_mut().get_period_coloring_mut() = period_coloring;
+                pane.schedule_redraw();
+            }
+        }
+        if cycle_filter {
+            self.cycle_color_vision_filter();
+        }
+    }
+
     /// Closes the currently active dialog, if any.
     #[inline]
     fn close_dialog(&mut self)
@@ -172,55 +701,164 @@ where
         file_type: SaveFileType,
     )
     {
-        use SaveFileType::{Image, Palette};
-
         // Ensure file selection was confirmed
         if !file_dialog.selected() {
             return;
         }
 
-        let Some(path) = file_dialog.path() else {
-            return;
-        };
-
-        let pane_ids = self.get_selected_pane_ids(selection);
+        if let Some(path) = file_dialog.path() {
+            self.save_file(selection, path, file_type);
+        }
+    }
 
-        match file_type {
-            Image => {
-                let image_width: usize = 4096; // You can make this dynamic as per your requirement
-                pane_ids
-                    .into_iter()
-                    .for_each(|pane_id| self.get_pane_mut(pane_id).save_image(image_width, path));
-            }
-            Palette => {
-                pane_ids
-                    .into_iter()
-                    .for_each(|pane_id| self.get_pane_mut(pane_id).save_palette(path));
-            }
+    /// Save the selected panes to `path`, reporting the outcome in a notification.
+    fn save_file(&mut self, selection: PaneSelection, path: &Path, file_type: SaveFileType)
+    {
+        use SaveFileType::{BandContours, Data, Image, Orbit, Palette, PcfCatalog, Rays};
+
+        let name = path.to_string_lossy().into_owned();
+        for pane_id in self.get_selected_pane_ids(selection) {
+            let pane = self.get_pane_mut(pane_id);
+            let result = match file_type {
+                Image(transparency) => pane
+                    .save_image(EXPORT_WIDTH, path, transparency)
+                    .map(|()| format!("Image saved to {name}")),
+                Palette => pane.save_palette(path).map(|()| format!("Palette saved to {name}")),
+                Data => pane.save_data(path).map(|()| format!("Data saved to {name}")),
+                Orbit => pane.save_orbit(path).map(|()| format!("Orbit saved to {name}")),
+                Rays => pane
+                    .save_rays(path)
+                    .map(|count| format!("{count} rays saved to {name}")),
+                BandContours => pane
+                    .save_band_contours(path)
+                    .map(|count| format!("{count} contours saved to {name}")),
+                PcfCatalog(max_len) => {
+                    // Runs in the background, and reports when it finishes.
+                    pane.export_pcf_catalog(max_len, path);
+                    continue;
+                }
+            };
+            let operation = FileOperation {
+                selection: PaneSelection::Id(pane_id),
+                path: path.to_owned(),
+                kind: FileKind::Save(file_type),
+            };
+            self.report(result, "Could not save file", operation);
         }
         self.set_active_pane(None);
     }
 
-    fn handle_load_dialog(&mut self, file_dialog: &FileDialog, pane_selection: PaneSelection)
+    fn handle_load_dialog(
+        &mut self,
+        file_dialog: &FileDialog,
+        pane_selection: PaneSelection,
+        file_type: LoadFileType,
+    )
     {
         // Ensure file selection was confirmed
         if !file_dialog.selected() {
             return;
         }
 
-        let Some(path) = file_dialog.path() else {
-            return;
-        };
-
-        self.get_selected_pane_ids(pane_selection)
-            .into_iter()
-            .for_each(|pane_id| {
-                self.get_pane_mut(pane_id).load_palette(path);
-            });
+        if let Some(path) = file_dialog.path() {
+            self.load_file(pane_selection, path, file_type);
+        }
+    }
 
+    /// Load a file into the selected panes, reporting the outcome in a notification.
+    fn load_file(&mut self, selection: PaneSelection, path: &Path, file_type: LoadFileType)
+    {
+        let name = path.to_string_lossy().into_owned();
+        for pane_id in self.get_selected_pane_ids(selection) {
+            let pane = self.get_pane_mut(pane_id);
+            let result = match file_type {
+                LoadFileType::Palette => pane
+                    .load_palette(path)
+                    .map(|()| format!("Palette loaded from {name}")),
+                LoadFileType::Data => pane
+                    .load_data(path)
+                    .map(|()| format!("Data loaded from {name}")),
+                LoadFileType::Points => pane
+                    .load_points(path)
+                    .map(|count| format!("{count} points loaded from {name}")),
+                LoadFileType::Rays => pane
+                    .load_rays(path)
+                    .map(|count| format!("{count} rays loaded from {name}")),
+            };
+            let operation = FileOperation {
+                selection: PaneSelection::Id(pane_id),
+                path: path.to_owned(),
+                kind: FileKind::Load(file_type),
+            };
+            self.report(result, "Could not load file", operation);
+        }
         self.set_active_pane(None);
     }
 
+    /// Notify the user of the outcome of a file operation, offering to recover if it failed.
+    fn report(&mut self, result: DynamoResult<String>, title: &str, operation: FileOperation)
+    {
+        let notification = match result {
+            Ok(text) => Notification::info(text),
+            Err(e) => Notification::error(title, &e).with_operation(operation),
+        };
+        self.notifications.push(notification);
+    }
+
+    fn recover(&mut self, operation: FileOperation, recovery: Recovery)
+    {
+        let FileOperation {
+            selection,
+            path,
+            kind,
+        } = operation;
+        match (recovery, kind) {
+            // Scripts are saved from the app, which takes the recovery from here.
+            (_, FileKind::Script { .. }) => {
+                self.recovery = Some((
+                    FileOperation {
+                        selection,
+                        path,
+                        kind,
+                    },
+                    recovery,
+                ));
+            }
+            (Recovery::Retry, FileKind::Save(file_type)) => {
+                self.save_file(selection, &path, file_type);
+            }
+            (Recovery::Retry, FileKind::Load(file_type)) => {
+                self.load_file(selection, &path, file_type);
+            }
+            (Recovery::ChooseAnotherFile, FileKind::Save(file_type)) => {
+                let mut file_dialog = FileDialog::save_file(path.parent().map(Path::to_owned))
+                    .title("Save As")
+                    .show_rename(false)
+                    .show_new_folder(true);
+                file_dialog.open();
+                let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+                let file_dialog = file_dialog.default_filename(file_name);
+                self.dialog = Some(Dialog::Save {
+                    pane_selection: selection,
+                    file_dialog,
+                    file_type,
+                });
+            }
+            (Recovery::ChooseAnotherFile, FileKind::Load(file_type)) => {
+                let mut file_dialog = FileDialog::open_file(path.parent().map(Path::to_owned))
+                    .title("Open")
+                    .show_rename(false)
+                    .show_new_folder(false);
+                file_dialog.open();
+                self.dialog = Some(Dialog::Load {
+                    pane_selection: selection,
+                    file_dialog,
+                    file_type,
+                });
+            }
+        }
+    }
+
     fn process_text_dialog_input(
         &mut self,
         input_type: TextInputType,
@@ -228,9 +866,12 @@ where
         toggle_map: &ToggleMap,
     )
     {
-        use crate::dialog::TextInputType::{ActiveRays, Coordinates, ExternalRay, FindPeriodic};
+        use crate::dialog::TextInputType::{
+            ActiveRays, CoordinateChange, ExternalRay, FindMultiplier, FindPeriodic, Label,
+            MetaParam, MisiurewiczSpiral, MultiplierLocus, PcfCatalog, RayBatch, Tuning,
+        };
         use crate::dialog::ToggleKey::{
-            DoChild, DoParent, DrawOrbit, FollowPoint, PrefixAngles, SelectPoint,
+            DoChild, DoParent, DrawOrbit, FollowPoint, PeriodDoubling, PrefixAngles, SelectPoint,
         };
         match input_type {
             ExternalRay { .. } => {
@@ -278,13 +919,95 @@ where
                     self.dialog = Some(dialog);
                 }
             }
-            Coordinates { pane_id } => {
-                if let Ok(point) = text.parse::<Cplx>() {
-                    let pane = self.get_pane_mut(pane_id);
-                    pane.select_point(point);
-                    pane.stop_following();
+            FindMultiplier { pane_id } => {
+                let result = find_multiplier(self.get_pane_mut(pane_id), text);
+                if pane_id == PaneID::Parent {
                     self.process_child_task();
                 }
+                let dialog = match result {
+                    Ok(description) => Dialog::info("Multiplier found".to_owned(), description),
+                    Err(e) => Dialog::info("Multiplier not found".to_owned(), e),
+                };
+                self.dialog = Some(dialog);
+            }
+            PcfCatalog => match text.trim().parse::<Period>() {
+                Ok(max_len) if (1..=MAX_CATALOG_LEN).contains(&max_len) => {
+                    self.prompt_save_pcf_catalog(max_len);
+                }
+                _ => {
+                    let text = format!("Enter a length between 1 and {MAX_CATALOG_LEN}.");
+                    self.dialog = Some(Dialog::info("Invalid length".to_owned(), text));
+                }
+            },
+            Tuning { pane_id } => {
+                let result = navigate_tuning(self.get_pane_mut(pane_id), text);
+                if pane_id == PaneID::Parent {
+                    self.process_child_task();
+                }
+                let dialog = match result {
+                    Ok(description) => Dialog::info("Tuning".to_owned(), description),
+                    Err(e) => Dialog::info("Tuning failed".to_owned(), e),
+                };
+                self.dialog = Some(dialog);
+            }
+            MetaParam => {
+                let mut meta_params = self.parent.plane.get_meta_params();
+                match meta_params.set_text_entry(text) {
+                    Ok(()) => {
+                        self.set_meta_params(meta_params);
+                        // The new value may change the scale of both planes
+                        self.parent.reset();
+                        self.process_child_task();
+                        self.child.reset();
+                    }
+                    Err(e) => {
+                        self.dialog = Some(Dialog::info("Invalid input".to_owned(), e));
+                    }
+                }
+            }
+            RayBatch { .. } => {
+                let mut pane_ids = Vec::new();
+                if toggle_map.get(DoParent) {
+                    pane_ids.push(PaneID::Parent);
+                }
+                if toggle_map.get(DoChild) {
+                    pane_ids.push(PaneID::Child);
+                }
+                let traced = text.parse::<AnglePattern>().and_then(|pattern| {
+                    for pane_id in pane_ids {
+                        let pane = self.get_pane_mut(pane_id);
+                        pane.trace_rays(pattern.angles(pane.degree())?);
+                    }
+                    Ok(())
+                });
+                if let Err(e) = traced {
+                    self.dialog = Some(Dialog::info("Invalid angles".to_owned(), e.to_string()));
+                }
+            }
+            CoordinateChange { pane_id } => {
+                let transform = if text.trim().is_empty() {
+                    Ok(ViewTransform::Identity)
+                } else {
+                    let coeffs: Result<Vec<Cplx>, _> =
+                        text.split(',').map(|s| s.trim().parse::<Cplx>()).collect();
+                    match coeffs.as_deref() {
+                        Ok(&[a, b, c, d]) if a * d != b * c => {
+                            Ok(ViewTransform::Mobius { a, b, c, d })
+                        }
+                        Ok(&[_, _, _, _]) => Err("The map is degenerate: ad - bc = 0.".to_owned()),
+                        Ok(_) => Err("Expected four coefficients a, b, c, d.".to_owned()),
+                        Err(_) => Err(format!("Could not read complex numbers from `{text}`.")),
+                    }
+                };
+                match transform {
+                    Ok(transform) => self.get_pane_mut(pane_id).set_coordinate_change(transform),
+                    Err(e) => {
+                        self.dialog = Some(Dialog::info("Invalid coordinate change".to_owned(), e));
+                    }
+                }
+            }
+            Label { pane_id } => {
+                self.get_pane_mut(pane_id).edit_label_at_selection(text);
             }
             FindPeriodic { pane_id } => {
                 if let Ok(orbit_schema) = text.parse::<OrbitSchema>() {
@@ -305,6 +1028,39 @@ where
                     }
                 }
             }
+            MisiurewiczSpiral { pane_id } => {
+                if let Ok(orbit_schema) = text.parse::<OrbitSchema>() {
+                    let pane = self.get_pane_mut(pane_id);
+                    match pane.analyze_misiurewicz(orbit_schema) {
+                        Ok(spiral) => {
+                            pane.stop_following();
+                            self.process_child_task();
+                            let title = "Misiurewicz spiral".to_owned();
+                            self.dialog = Some(Dialog::info(title, spiral.to_string()));
+                        }
+                        Err(e) => {
+                            let title = "No Misiurewicz point found";
+                            self.notifications
+                                .push(Notification::failure(title, e.to_string()));
+                        }
+                    }
+                }
+            }
+            MultiplierLocus { pane_id } => {
+                if let Ok(period) = text.trim().parse::<Period>() {
+                    let bifurcation = if toggle_map.get(PeriodDoubling) {
+                        Bifurcation::PeriodDoubling
+                    } else {
+                        Bifurcation::Root
+                    };
+                    let pane = self.get_pane_mut(pane_id);
+                    if let Err(e) = pane.trace_multiplier_locus(period, bifurcation) {
+                        let title = "No hyperbolic component found";
+                        self.notifications
+                            .push(Notification::failure(title, e.to_string()));
+                    }
+                }
+            }
         }
     }
 
@@ -331,10 +1087,109 @@ where
         }
     }
 
+    /// Returns true once per touch, when a finger has rested on the screen without moving for
+    /// long enough. Taps report no pointer position, so this is how points are selected on touch
+    /// screens.
+    fn take_long_press(&mut self, ctx: &Context) -> bool
+    {
+        let (touching, held_for, moved) = ctx.input(|i| {
+            let held_for = i.pointer.press_start_time().map(|start| i.time - start);
+            let moved = i
+                .pointer
+                .press_origin()
+                .zip(i.pointer.latest_pos())
+                .map_or(0., |(origin, pos)| origin.distance(pos));
+            (i.any_touches() && i.pointer.primary_down(), held_for, moved)
+        });
+
+        if !touching {
+            self.long_press_used = false;
+            return false;
+        }
+        if self.long_press_used {
+            return false;
+        }
+        if moved > LONG_PRESS_TOLERANCE {
+            // The touch is a drag, so it should not select a point even if it comes to rest
+            self.long_press_used = true;
+            return false;
+        }
+        match held_for {
+            Some(held_for) if held_for >= LONG_PRESS_DURATION => {
+                self.long_press_used = true;
+                true
+            }
+            Some(held_for) => {
+                let remaining = LONG_PRESS_DURATION - held_for;
+                ctx.request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Pinch to zoom about the midpoint of the fingers, and move two fingers to pan, in the pane
+    /// where the gesture takes place.
+    fn handle_multi_touch(&mut self, touch: &MultiTouchInfo)
+    {
+        let pane_id = if self.parent.frame_contains_pixel(touch.center_pos) {
+            PaneID::Parent
+        } else if self.child.frame_contains_pixel(touch.center_pos) {
+            PaneID::Child
+        } else {
+            return;
+        };
+        self.set_active_pane(Some(pane_id));
+        let pane = self.get_pane_mut(pane_id);
+
+        if touch.translation_delta != egui::Vec2::ZERO {
+            let offset = pane.grid().map_vec2(touch.translation_delta.into());
+            pane.pan(-offset);
+        }
+        if (touch.zoom_delta - 1.0).abs() > f32::EPSILON {
+            let center = pane.map_pixel(touch.center_pos);
+            pane.zoom((1. / touch.zoom_delta).into(), center);
+        }
+    }
+
+    /// Returns true if the pointer is over the minimap of a pane, in which case a click centers
+    /// that pane on the point under the pointer.
+    fn handle_minimap_pointer(&mut self, pointer_pos: egui::Pos2, clicked: bool) -> bool
+    {
+        let comparison = self
+            .comparison
+            .as_mut()
+            .map(|comparison| &mut comparison.pane);
+        let panes = [
+            Some(&mut self.parent as &mut dyn Pane),
+            Some(&mut self.child as &mut dyn Pane),
+            comparison.map(|pane| pane as &mut dyn Pane),
+        ];
+        for pane in panes.into_iter().flatten() {
+            if let Some(point) = pane.minimap_point(pointer_pos) {
+                if clicked {
+                    pane.grid_mut().recenter(point);
+                    pane.schedule_recompute();
+                }
+                return true;
+            }
+        }
+        false
+    }
+
     /// Handles mouse input, updating the state of the panes accordingly.
     fn handle_mouse(&mut self, ctx: &Context)
     {
-        let clicked = ctx.input(|i| i.pointer.any_click()) && !self.click_used;
+        if let Some(touch) = ctx.multi_touch() {
+            // Single-pointer panning and zooming would follow only one of the fingers
+            self.long_press_used = true;
+            self.reset_click();
+            self.handle_multi_touch(&touch);
+            return;
+        }
+
+        let long_pressed = self.take_long_press(ctx);
+        let clicked = (ctx.input(|i| i.pointer.any_click()) && !self.click_used) || long_pressed;
         let zoom_factor = ctx.input(InputState::zoom_delta);
 
         self.reset_click();
@@ -352,34 +1207,76 @@ where
                 } else if self.child().frame_contains_pixel(origin) {
                     let offset = self.child.grid().map_vec2((delta).into());
                     self.child.pan(-offset);
+                } else if let Some(comparison) = self
+                    .comparison
+                    .as_mut()
+                    .filter(|comparison| comparison.pane.frame_contains_pixel(origin))
+                {
+                    let offset = comparison.pane.grid().map_vec2((delta).into());
+                    comparison.pane.pan(-offset);
                 }
             }
         }
 
-        if self.parent().frame_contains_pixel(pointer_pos) {
+        if self.handle_minimap_pointer(pointer_pos, clicked) {
+            ctx.set_cursor_icon(CursorIcon::PointingHand);
+            if clicked {
+                self.consume_click();
+            }
+        } else if self.parent().frame_contains_pixel(pointer_pos) {
             ctx.set_cursor_icon(CursorIcon::Crosshair);
             self.set_active_pane(Some(PaneID::Parent));
-            let reselect_point = self.live_mode || clicked;
+            // Shift-clicking picks the parameter for the comparison pane instead of the child
+            let compare = clicked && self.comparison.is_some() && ctx.input(|i| i.modifiers.shift);
+            let reselect_point = (self.live_mode || clicked) && !compare;
             let pointer_value = self.parent().map_pixel(pointer_pos);
             self.parent_mut()
-                .process_mouse_input(pointer_value, zoom_factor, reselect_point);
+                .process_mouse_input(pointer_value, zoom_factor, reselect_point, clicked);
             self.process_child_task();
+            if self.live_mode && !compare {
+                self.show_julia_preview(ctx, pointer_pos, pointer_value);
+            }
+            self.parent
+                .show_orbit_tooltip(ctx, pointer_value, pointer_pos);
+            if compare {
+                let point = self.parent.grid().transform.apply(pointer_value);
+                self.set_comparison_param(point);
+            }
 
             if clicked {
                 self.consume_click();
                 self.parent_mut().marking_mut().enable_selection();
+                if !compare {
+                    let selection = self.parent.get_selection();
+                    self.parent.marking.trail.record(selection);
+                }
             }
         } else if self.child().frame_contains_pixel(pointer_pos) {
             ctx.set_cursor_icon(CursorIcon::Crosshair);
             self.set_active_pane(Some(PaneID::Child));
             let pointer_value = self.child().map_pixel(pointer_pos);
             self.child_mut()
-                .process_mouse_input(pointer_value, zoom_factor, clicked);
+                .process_mouse_input(pointer_value, zoom_factor, clicked, clicked);
 
             if clicked {
                 self.consume_click();
                 self.child_mut().marking_mut().enable_selection();
             }
+        } else if self.comparison_contains_pixel(pointer_pos) {
+            ctx.set_cursor_icon(CursorIcon::Crosshair);
+            self.set_active_pane(None);
+            if let Some(comparison) = &mut self.comparison {
+                let pane = &mut comparison.pane;
+                pane.frame_mut().select();
+                let pointer_value = pane.map_pixel(pointer_pos);
+                pane.process_mouse_input(pointer_value, zoom_factor, clicked, clicked);
+                if clicked {
+                    pane.marking_mut().enable_selection();
+                }
+            }
+            if clicked {
+                self.consume_click();
+            }
         } else {
             ctx.set_cursor_icon(CursorIcon::Default);
         }
@@ -403,6 +1300,36 @@ where
         self.message = UiMessage::NewTab;
     }
 
+    /// Schedules a message to show the render queue.
+    fn schedule_open_render_queue(&mut self)
+    {
+        self.message = UiMessage::OpenRenderQueue;
+    }
+
+    /// Schedules a message to show the keyboard shortcut editor.
+    fn schedule_open_shortcut_editor(&mut self)
+    {
+        self.message = UiMessage::OpenShortcutEditor;
+    }
+
+    /// Schedules a message to show the command palette.
+    fn schedule_open_command_palette(&mut self)
+    {
+        self.message = UiMessage::OpenCommandPalette;
+    }
+
+    /// Schedules a message to show the preferences window.
+    fn schedule_open_preferences(&mut self)
+    {
+        self.message = UiMessage::OpenPreferences;
+    }
+
+    /// Schedules a message to show the bookmarks window.
+    fn schedule_open_bookmarks(&mut self)
+    {
+        self.message = UiMessage::OpenBookmarks;
+    }
+
     /// Toggles the live mode state of the interface.
     fn toggle_live_mode(&mut self)
     {
@@ -415,11 +1342,108 @@ where
         }
     }
 
+    /// Starts cycling the palettes of the selected panes, or stops them if any is cycling already.
+    /// Panes with synced palettes cycle together.
+    fn toggle_color_cycling(&mut self, selection: PaneSelection)
+    {
+        let pane_ids = self.get_palette_pane_ids(selection);
+        let running = !pane_ids
+            .iter()
+            .any(|pane_id| self.get_pane(*pane_id).is_color_cycling());
+        for pane_id in pane_ids {
+            self.get_pane_mut(pane_id).set_color_cycling(running);
+        }
+    }
+
+    /// Starts animating a wring of the coloring of the active pane, or stops the animation and
+    /// restores the original coloring.
+    fn toggle_wring_animation(&mut self)
+    {
+        if let Some(pane_id) = self.wring_pane.take() {
+            let pane = self.get_pane_mut(pane_id);
+            pane.get_coloring_mut().set_wring(Wring::IDENTITY);
+            pane.schedule_redraw();
+        } else {
+            self.wring_pane = self.active_pane;
+        }
+    }
+
+    /// Advances the wring animation, if one is running, to the current frame.
+    fn step_wring_animation(&mut self, ctx: &Context)
+    {
+        if let Some(pane_id) = self.wring_pane {
+            let time = ctx.input(|i| i.time);
+            let pane = self.get_pane_mut(pane_id);
+            pane.get_coloring_mut().set_wring(Wring::oscillating(time));
+            pane.schedule_redraw();
+            ctx.request_repaint();
+        }
+    }
+
     /// Checks if there is a visible dialog currently active.
     fn has_visible_dialog(&self) -> bool
     {
         self.dialog.as_ref().is_some_and(Dialog::visible)
     }
+
+    /// Panes whose palettes are changed by a change aimed at the selected panes, which is both
+    /// of them while their palettes are synced.
+    fn get_palette_pane_ids(&self, selection: PaneSelection) -> Vec<PaneID>
+    {
+        if self.sync_palettes {
+            return vec![PaneID::Parent, PaneID::Child];
+        }
+        self.get_selected_pane_ids(selection)
+    }
+
+    /// Sets a new color palette for the selected panes, keeping the colors fixed for periods on
+    /// each.
+    fn set_palette_keeping_period_colors(&mut self, selection: PaneSelection, palette: Palette)
+    {
+        for pane_id in self.get_palette_pane_ids(selection) {
+            let pane = self.get_pane_mut(pane_id);
+            let mut palette = palette;
+            palette.period_coloring.overrides = pane.get_coloring().get_period_coloring().overrides;
+            pane.change_palette(palette);
+        }
+    }
+
+    /// Fixes the colors of the first periods on both panes at those the palette gives them now,
+    /// or frees them all if they are already fixed.
+    fn toggle_pinned_period_colors(&mut self)
+    {
+        const PINNED_PERIODS: Period = 12;
+
+        let panes: [&mut dyn Pane; 2] = [&mut self.parent, &mut self.child];
+        for pane in panes {
+            let period_coloring = pane.get_coloring_mut().get_period_coloring_mut();
+            if period_coloring.overrides.is_empty() {
+                period_coloring.pin_periods(PINNED_PERIODS);
+            } else {
+                period_coloring.overrides.clear();
+            }
+            pane.schedule_redraw();
+        }
+    }
+
+    /// Keeps the palettes of both panes the same from now on, starting from that of the active
+    /// pane, or lets them be changed separately again.
+    fn toggle_palette_sync(&mut self)
+    {
+        self.sync_palettes ^= true;
+        if self.sync_palettes {
+            let pane_id = self.active_pane.unwrap_or_default();
+            let palette = *self.get_pane(pane_id).get_coloring().get_palette();
+            self.set_palette(palette);
+        }
+    }
+
+    /// Previews both panes as seen with the next form of color blindness in turn.
+    fn cycle_color_vision_filter(&mut self)
+    {
+        self.parent.cycle_color_vision_filter();
+        self.child.cycle_color_vision_filter();
+    }
 }
 
 /// Implementation of `PanePair` for `MainInterface`, providing access to parent and child panes.
@@ -448,18 +1472,38 @@ where
     {
         &mut self.child
     }
-    /// Randomizes the color palette for both the parent and child panes.
-    fn randomize_palette(&mut self)
+    fn comparison(&self) -> Option<&dyn Pane>
     {
-        let palette = Palette::new_random(0.45, 0.38);
-        self.parent.change_palette(palette);
-        self.child.change_palette(palette);
+        self.comparison
+            .as_ref()
+            .map(|comparison| &comparison.pane as &dyn Pane)
+    }
+    fn comparison_mut(&mut self) -> Option<&mut dyn Pane>
+    {
+        self.comparison
+            .as_mut()
+            .map(|comparison| &mut comparison.pane as &mut dyn Pane)
+    }
+    fn randomize_palette(&mut self, panes: PaneSelection)
+    {
+        if self.sync_palettes {
+            let palette = self.palette_generator.generate();
+            self.set_palette_keeping_period_colors(panes, palette);
+            return;
+        }
+        for pane_id in self.get_selected_pane_ids(panes) {
+            let palette = self.palette_generator.generate();
+            self.set_palette_keeping_period_colors(PaneSelection::Id(pane_id), palette);
+        }
     }
 
     /// Prompt for text input for a specified purpose.
     fn prompt_text(&mut self, input_type: TextInputType)
     {
-        use TextInputType::{ActiveRays, Coordinates, ExternalRay, FindPeriodic};
+        use TextInputType::{
+            ActiveRays, CoordinateChange, ExternalRay, FindMultiplier, FindPeriodic, Label,
+            MetaParam, MisiurewiczSpiral, MultiplierLocus, PcfCatalog, RayBatch, Tuning,
+        };
         let text_dialog = match input_type {
             ExternalRay {
                 pane_id,
@@ -507,6 +1551,18 @@ where
                     )
                     .build()
             }
+            RayBatch { pane_id } => {
+                let prompt = concat!(
+                    "Input angles to draw their rays.\n",
+                    "Formats: a list such as <1/7, 2/7, 011p10>, <period 5>, <period <= 6>,\n",
+                    "<k/255>, or <k/255 for k odd>"
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Draw batch of rays")
+                    .prompt(prompt)
+                    .pane_toggles("Draw on", pane_id)
+                    .build()
+            }
             FindPeriodic { pane_id, .. } => {
                 let pane = self.get_pane(pane_id);
                 let prompt = format!(
@@ -531,69 +1587,296 @@ where
                     builder.build()
                 }
             }
-            Coordinates { pane_id } => {
+            MisiurewiczSpiral { pane_id } => {
                 let pane = self.get_pane(pane_id);
                 let prompt = format!(
-                    "Enter the coordinates of the point to select on {pane_name}",
+                    concat!(
+                        "Input the orbit type of a Misiurewicz point near the selection\n",
+                        "on {pane_name}.\n",
+                        "Format: <preperiod, period>"
+                    ),
                     pane_name = pane.name()
                 );
                 TextDialogBuilder::new(input_type)
-                    .title("Input coordinates")
+                    .title("Misiurewicz spiral")
                     .prompt(prompt)
                     .build()
             }
+            MultiplierLocus { pane_id } => {
+                let pane = self.get_pane(pane_id);
+                let prompt = format!(
+                    concat!(
+                        "Input the period of the hyperbolic component containing the selection\n",
+                        "on {pane_name}, to trace its boundary.\n",
+                        "Format: <period>"
+                    ),
+                    pane_name = pane.name()
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Multiplier locus")
+                    .prompt(prompt)
+                    .add_toggle_with_default(
+                        ToggleKey::PeriodDoubling,
+                        "Start from period doubling (multiplier -1)".to_owned(),
+                        false,
+                    )
+                    .build()
+            }
+            CoordinateChange { pane_id } => {
+                let pane = self.get_pane(pane_id);
+                let prompt = format!(
+                    concat!(
+                        "Enter the coefficients of a Mobius map z -> (az + b)/(cz + d)\n",
+                        "to use as coordinates on {pane_name}.\n",
+                        "Format: <a, b, c, d>, e.g. <0, 1, 1, 0> to view a neighborhood of infinity.\n",
+                        "Leave blank to restore the original coordinates."
+                    ),
+                    pane_name = pane.name()
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Change coordinates")
+                    .prompt(prompt)
+                    .build()
+            }
+            Tuning { .. } => {
+                let prompt = concat!(
+                    "Enter a root angle of a hyperbolic component and an angle, e.g. <3/7, 1/3>,\n",
+                    "to jump to the corresponding parameter in the small copy of the Mandelbrot\n",
+                    "set at the component. Enter a single angle to jump out of the small copy\n",
+                    "containing it."
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Tuning")
+                    .prompt(prompt)
+                    .build()
+            }
+            FindMultiplier { pane_id } => {
+                let pane = self.get_pane(pane_id);
+                let prompt = format!(
+                    concat!(
+                        "Input a period and a multiplier to find a nearby parameter\n",
+                        "on {pane_name} where a cycle of that period has that multiplier.\n",
+                        "Format: <period, multiplier>, with the multiplier a complex number\n",
+                        "or an internal angle p/q standing for e^(2πi p/q)"
+                    ),
+                    pane_name = pane.name()
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Find multiplier")
+                    .prompt(prompt)
+                    .build()
+            }
+            Label { pane_id } => {
+                let pane = self.get_pane(pane_id);
+                let prompt = format!(
+                    concat!(
+                        "Enter the text of the label at the selection on {pane_name}.\n",
+                        "Leave blank to remove the label."
+                    ),
+                    pane_name = pane.name()
+                );
+                let text = pane.label_at_selection().unwrap_or_default();
+                TextDialogBuilder::new(input_type)
+                    .title("Edit label")
+                    .prompt(prompt)
+                    .initial_text(text)
+                    .build()
+            }
+            PcfCatalog => {
+                let prompt = concat!(
+                    "Input a length N to export the centers and Misiurewicz parameters\n",
+                    "reached by parameter rays with preperiod + period at most N"
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Export PCF catalog")
+                    .prompt(prompt)
+                    .initial_text("6".to_owned())
+                    .build()
+            }
+            MetaParam => {
+                let Some(entry) = self.parent.plane.get_meta_params().text_entry() else {
+                    return;
+                };
+                TextDialogBuilder::new(input_type)
+                    .title(&format!("Edit {}", entry.name.to_lowercase()))
+                    .prompt(entry.prompt)
+                    .initial_text(entry.value)
+                    .build()
+            }
         };
         let dialog = Dialog::Text(text_dialog);
         self.dialog = Some(dialog);
     }
 
-    /// Open a dialog prompt to save an image.
-    fn prompt_save_image(&mut self, pane_selection: PaneSelection)
+    /// Open a dialog prompt to save an image.
+    fn prompt_save_image(&mut self, pane_selection: PaneSelection, transparency: Transparency)
+    {
+        let mut file_dialog = FileDialog::save_file(images_dir())
+            .title("Save Image")
+            .show_rename(false)
+            .show_new_folder(true);
+        file_dialog.open();
+        let file_dialog = file_dialog.default_filename(format!("{}.png", self.parent.long_name()));
+        self.dialog = Some(Dialog::Save {
+            pane_selection,
+            file_dialog,
+            file_type: SaveFileType::Image(transparency),
+        });
+    }
+
+    fn prompt_save_palette(&mut self, panes: PaneSelection)
+    {
+        let mut file_dialog = FileDialog::save_file(palettes_dir())
+            .title("Save Palette")
+            .show_rename(false)
+            .show_new_folder(true);
+        file_dialog.open();
+        let file_dialog = file_dialog.default_filename("palette.toml");
+        self.dialog = Some(Dialog::Save {
+            pane_selection: panes,
+            file_dialog,
+            file_type: SaveFileType::Palette,
+        });
+    }
+
+    fn prompt_load_palette(&mut self, pane_selection: PaneSelection)
+    {
+        let mut file_dialog = FileDialog::open_file(palettes_dir())
+            .title("Load Palette")
+            .show_rename(false)
+            .show_new_folder(false);
+        file_dialog.open();
+        self.dialog = Some(Dialog::Load {
+            pane_selection,
+            file_dialog,
+            file_type: LoadFileType::Palette,
+        });
+    }
+
+    fn prompt_save_data(&mut self, pane_selection: PaneSelection)
+    {
+        let mut file_dialog = FileDialog::save_file(raw_data_dir())
+            .title("Save Data")
+            .show_rename(false)
+            .show_new_folder(true);
+        file_dialog.open();
+        let file_dialog = file_dialog.default_filename(format!("{}.dat", self.parent.long_name()));
+        self.dialog = Some(Dialog::Save {
+            pane_selection,
+            file_dialog,
+            file_type: SaveFileType::Data,
+        });
+    }
+
+    fn prompt_save_orbit(&mut self, pane_selection: PaneSelection)
+    {
+        let mut file_dialog = FileDialog::save_file(raw_data_dir())
+            .title("Save Orbit")
+            .show_rename(false)
+            .show_new_folder(true);
+        file_dialog.open();
+        let file_dialog = file_dialog.default_filename("orbit.csv");
+        self.dialog = Some(Dialog::Save {
+            pane_selection,
+            file_dialog,
+            file_type: SaveFileType::Orbit,
+        });
+    }
+
+    fn prompt_save_rays(&mut self, pane_selection: PaneSelection)
+    {
+        let mut file_dialog = FileDialog::save_file(raw_data_dir())
+            .title("Save Rays")
+            .show_rename(false)
+            .show_new_folder(true);
+        file_dialog.open();
+        let file_dialog = file_dialog.default_filename("rays.json");
+        self.dialog = Some(Dialog::Save {
+            pane_selection,
+            file_dialog,
+            file_type: SaveFileType::Rays,
+        });
+    }
+
+    fn prompt_save_band_contours(&mut self, pane_selection: PaneSelection)
     {
         let mut file_dialog = FileDialog::save_file(images_dir())
-            .title("Save Image")
+            .title("Save Band Contours")
             .show_rename(false)
             .show_new_folder(true);
         file_dialog.open();
-        let file_dialog = file_dialog.default_filename(format!("{}.png", self.parent.long_name()));
+        let filename = format!("{}_contours.svg", self.parent.long_name());
+        let file_dialog = file_dialog.default_filename(filename);
         self.dialog = Some(Dialog::Save {
             pane_selection,
             file_dialog,
-            file_type: SaveFileType::Image,
+            file_type: SaveFileType::BandContours,
         });
     }
 
-    fn prompt_save_palette(&mut self, panes: PaneSelection)
+    fn prompt_save_pcf_catalog(&mut self, max_len: Period)
     {
-        let mut file_dialog = FileDialog::save_file(palettes_dir())
-            .title("Save Palette")
+        let mut file_dialog = FileDialog::save_file(raw_data_dir())
+            .title("Export PCF Catalog")
             .show_rename(false)
             .show_new_folder(true);
         file_dialog.open();
-        let file_dialog = file_dialog.default_filename("palette.toml");
+        let file_dialog = file_dialog.default_filename(format!("pcf_{max_len}.csv"));
         self.dialog = Some(Dialog::Save {
-            pane_selection: panes,
+            pane_selection: PaneSelection::Id(PaneID::Parent),
             file_dialog,
-            file_type: SaveFileType::Palette,
+            file_type: SaveFileType::PcfCatalog(max_len),
         });
     }
 
-    fn prompt_load_palette(&mut self, pane_selection: PaneSelection)
+    fn prompt_load_rays(&mut self, pane_selection: PaneSelection)
     {
-        let mut file_dialog = FileDialog::open_file(palettes_dir())
-            .title("Load Palette")
+        let mut file_dialog = FileDialog::open_file(raw_data_dir())
+            .title("Load Rays")
+            .show_rename(false)
+            .show_new_folder(false);
+        file_dialog.open();
+        self.dialog = Some(Dialog::Load {
+            pane_selection,
+            file_dialog,
+            file_type: LoadFileType::Rays,
+        });
+    }
+
+    fn prompt_open_data(&mut self, pane_selection: PaneSelection)
+    {
+        let mut file_dialog = FileDialog::open_file(raw_data_dir())
+            .title("Open Data")
+            .show_rename(false)
+            .show_new_folder(false);
+        file_dialog.open();
+        self.dialog = Some(Dialog::Load {
+            pane_selection,
+            file_dialog,
+            file_type: LoadFileType::Data,
+        });
+    }
+
+    fn prompt_load_points(&mut self, pane_selection: PaneSelection)
+    {
+        let mut file_dialog = FileDialog::open_file(raw_data_dir())
+            .title("Load Points")
             .show_rename(false)
             .show_new_folder(false);
         file_dialog.open();
         self.dialog = Some(Dialog::Load {
             pane_selection,
             file_dialog,
+            file_type: LoadFileType::Points,
         });
     }
 
     fn set_active_pane(&mut self, pane_id: Option<PaneID>)
     {
         self.active_pane = pane_id;
+        if let Some(comparison) = self.comparison_mut() {
+            comparison.frame_mut().deselect();
+        }
         match pane_id {
             None => {
                 self.child_mut().frame_mut().deselect();
@@ -678,8 +1961,23 @@ where
 
     fn update_panes(&mut self)
     {
+        if let Some(comparison) = &mut self.comparison {
+            comparison.sync_with(&mut self.child);
+            comparison.pane.process_tasks();
+        }
         self.parent.process_tasks();
         self.child.process_tasks();
+
+        for pane_id in [PaneID::Parent, PaneID::Child] {
+            if let Some(save) = self.get_pane_mut(pane_id).take_finished_save() {
+                let operation = FileOperation {
+                    selection: PaneSelection::Id(pane_id),
+                    path: save.path,
+                    kind: FileKind::Save(save.file_type),
+                };
+                self.report(save.result, "Could not save file", operation);
+            }
+        }
     }
 
     // fn descend(self) -> Box<dyn PanePair>
@@ -700,31 +1998,23 @@ where
     /// Handles user input and updates the state of the interface accordingly.
     fn handle_input(&mut self, ctx: &Context)
     {
+        self.step_wring_animation(ctx);
+
         // Don't process input if the user is in a dialog
         if self.has_visible_dialog() {
             ctx.set_cursor_icon(CursorIcon::Default);
             return;
         }
-        for Hotkey {
-            shortcut,
-            action,
-            bonus_action,
-            ..
-        } in FILE_HOTKEYS
-            .iter()
-            .chain(IMAGE_HOTKEYS.iter())
-            .chain(ANNOTATION_HOTKEYS.iter())
-            .chain(CYCLES_HOTKEYS.iter())
-            .chain(SELECTION_HOTKEYS.iter())
-            .chain(INCOLORING_HOTKEYS.iter())
-            .chain(OUTCOLORING_HOTKEYS.iter())
-            .chain(PALETTE_HOTKEYS.iter())
-        {
-            if let Some(s) = shortcut.as_ref() {
-                if shortcut_used!(ctx, s) {
-                    self.process_action(action);
-                    if let Some(bonus_action) = bonus_action {
-                        self.process_action(bonus_action);
+        // Keys typed into a text field, e.g. in the command palette, are not shortcuts
+        if !ctx.wants_keyboard_input() {
+            let keymap = Keymap::current(ctx);
+            for (hotkey, shortcut) in keymap.hotkeys() {
+                if let Some(s) = shortcut.as_ref() {
+                    if shortcut_used!(ctx, s) {
+                        self.process_action(&hotkey.action);
+                        if let Some(bonus_action) = &hotkey.bonus_action {
+                            self.process_action(bonus_action);
+                        }
                     }
                 }
             }
@@ -748,7 +2038,8 @@ where
                 Dialog::Load {
                     file_dialog,
                     pane_selection,
-                } => self.handle_load_dialog(file_dialog, *pane_selection),
+                    file_type,
+                } => self.handle_load_dialog(file_dialog, *pane_selection, *file_type),
                 Dialog::Text(text_dialog) => {
                     if let crate::dialog::Response::Complete { data } = text_dialog.get_response() {
                         let (text, toggle_map) = data;
@@ -778,6 +2069,41 @@ where
                         }
                     }
                 }
+                Dialog::Trail(trail_dialog) => {
+                    if let crate::dialog::Response::Complete { data } = trail_dialog.get_response()
+                    {
+                        self.parent.select_point(data);
+                        self.process_child_task();
+                    }
+                }
+                Dialog::AngleCalculator(calculator) => {
+                    if let crate::dialog::Response::Complete { data } = calculator.get_response() {
+                        let mut draw_rays = |pane_id| {
+                            let pane = self.get_pane_mut(pane_id);
+                            for angle in &data.angles {
+                                pane.marking_mut().enable_ray(*angle);
+                            }
+                            pane.schedule_redraw();
+                        };
+
+                        if data.do_child {
+                            draw_rays(PaneID::Child);
+                        }
+                        if data.do_parent {
+                            draw_rays(PaneID::Parent);
+                        }
+                    }
+                }
+                Dialog::Point(point_dialog) => {
+                    if let crate::dialog::Response::Complete { data } = point_dialog.get_response()
+                    {
+                        let pane = self.get_pane_mut(point_dialog.pane_id);
+                        pane.select_point(data);
+                        pane.stop_following();
+                        self.process_child_task();
+                    }
+                }
+                Dialog::Info(_) => {}
             }
 
             if dialog.visible() {
@@ -823,14 +2149,81 @@ where
         self.image_height = new_height;
         self.parent.change_height(new_height);
         self.child.change_height(new_height);
+        if let Some(comparison) = self.comparison_mut() {
+            comparison.change_height(new_height);
+        }
+    }
+
+    fn set_max_iter(&mut self, max_iter: IterCount)
+    {
+        self.parent.set_max_iter(max_iter);
+        self.child.set_max_iter(max_iter);
+    }
+
+    fn render_job(
+        &self,
+        pane_id: PaneID,
+        img_width: usize,
+        filename: &Path,
+        transparency: Transparency,
+    ) -> RenderJob
+    {
+        self.get_pane(pane_id)
+            .render_job(img_width, filename, transparency)
+    }
+
+    fn encode_png(&self, pane_id: PaneID) -> Result<Vec<u8>, Box<dyn Error>>
+    {
+        self.get_pane(pane_id).get_image_frame().encode_png()
+    }
+
+    fn view_state(&self) -> ViewState
+    {
+        ViewState {
+            parent: self.parent.view(),
+            child: self.child.view(),
+        }
+    }
+
+    /// The child is restored after the parent, since selecting a new parameter moves the child's
+    /// bounds.
+    fn restore_view_state(&mut self, view: &ViewState)
+    {
+        self.parent.restore_view(&view.parent);
+        self.process_child_task();
+        self.child.restore_view(&view.child);
+    }
+
+    fn notify(&mut self, notification: Notification)
+    {
+        self.notifications.push(notification);
+    }
+
+    fn pop_recovery(&mut self) -> Option<(FileOperation, Recovery)>
+    {
+        self.recovery.take()
     }
 
     /// Renders the UI elements of the main interface, which consist of the parent plane, child
     /// plane, plane names, and orbit descriptions. The menus are handled by the parent struct `app::FracalTab`.
     fn show(&mut self, ui: &mut Ui)
     {
-        TableBuilder::new(ui)
-            .column(Column::exact(self.parent.get_image_frame().width() as f32))
+        if let Some((operation, recovery)) = self.notifications.show(ui.ctx()) {
+            self.recover(operation, recovery);
+        }
+        self.show_meta_param_sliders(ui);
+        self.show_flow_sliders(ui);
+        self.show_escape_phase_controls(ui);
+        self.show_color_cycle_controls(ui);
+        self.show_orbit_playback_controls(ui);
+        self.show_preimage_controls(ui);
+        self.show_palette_generator(ui);
+        let mut table = TableBuilder::new(ui)
+            .column(Column::exact(self.parent.get_image_frame().width() as f32));
+        if self.comparison.is_some() {
+            table = table.column(Column::exact(self.child.get_image_frame().width() as f32));
+        }
+        table
             .column(Column::remainder())
             .vscroll(false)
             .stick_to_bottom(true)
@@ -841,6 +2234,11 @@ where
                 header.col(|ui| {
                     ui.heading(self.child().long_name());
                 });
+                if let Some(comparison) = self.comparison() {
+                    header.col(|ui| {
+                        ui.heading(comparison.long_name());
+                    });
+                }
             })
             .body(|mut body| {
                 body.row(self.parent.get_image_frame().height() as f32, |mut row| {
@@ -848,22 +2246,61 @@ where
                         self.parent.get_image_frame_mut().put(ui);
                         self.parent.put_marked_curves(ui);
                         self.parent.put_marked_points(ui);
+                        self.parent.put_minimap(ui);
                     });
                     row.col(|ui| {
                         self.child.get_image_frame_mut().put(ui);
                         self.child.put_marked_curves(ui);
                         self.child.put_marked_points(ui);
+                        self.child.put_minimap(ui);
                     });
+                    if let Some(comparison) = self.comparison_mut() {
+                        row.col(|ui| {
+                            comparison.get_image_frame_mut().put(ui);
+                            comparison.put_marked_curves(ui);
+                            comparison.put_marked_points(ui);
+                            comparison.put_minimap(ui);
+                        });
+                    }
                 });
                 body.row(80., |mut row| {
                     row.col(|ui| {
                         ui.label(self.parent.state_info());
+                        show_ray_progress(ui, &self.parent);
                     });
                     row.col(|ui| {
                         ui.label(self.child.state_info());
+                        show_ray_progress(ui, &self.child);
                     });
+                    if let Some(comparison) = self.comparison() {
+                        row.col(|ui| {
+                            ui.label(comparison.state_info());
+                            show_ray_progress(ui, comparison);
+                        });
+                    }
                 });
             });
+
+        let ctx = ui.ctx().clone();
+        self.parent.show_statistics(&ctx);
+        self.child.show_statistics(&ctx);
+        self.parent.show_attractor_inventory(&ctx);
+        self.child.show_attractor_inventory(&ctx);
+        self.parent.show_layers(&ctx);
+        self.child.show_layers(&ctx);
+        if self.parent.show_pcf_database(&ctx) {
+            self.process_child_task();
+        }
+        self.child.show_pcf_database(&ctx);
+        self.parent.show_orbit_trace(&ctx);
+        self.child.show_orbit_trace(&ctx);
+        if let Some(comparison) = self.comparison_mut() {
+            comparison.show_statistics(&ctx);
+            comparison.show_attractor_inventory(&ctx);
+            comparison.show_layers(&ctx);
+            comparison.show_pcf_database(&ctx);
+            comparison.show_orbit_trace(&ctx);
+        }
     }
 
     #[allow(clippy::too_many_lines)]
@@ -874,9 +2311,32 @@ where
             Action::Quit => self.schedule_quit(),
             Action::Close => self.schedule_close(),
             Action::NewTab => self.schedule_new_tab(),
-            Action::SaveImage(panes) => self.prompt_save_image(*panes),
+            Action::OpenRenderQueue => self.schedule_open_render_queue(),
+            Action::OpenShortcutEditor => self.schedule_open_shortcut_editor(),
+            Action::OpenCommandPalette => self.schedule_open_command_palette(),
+            Action::OpenPreferences => self.schedule_open_preferences(),
+            Action::OpenBookmarks => self.schedule_open_bookmarks(),
+            Action::SaveImage(panes) => self.prompt_save_image(*panes, Transparency::Opaque),
+            Action::SaveTransparentImage(panes, transparency) => {
+                self.prompt_save_image(*panes, *transparency);
+            }
             Action::SavePalette(panes) => self.prompt_save_palette(*panes),
-            Action::LoadPalette(panes) => self.prompt_load_palette(*panes),
+            Action::LoadPalette(panes) => {
+                let panes = if self.sync_palettes {
+                    PaneSelection::BothPanes
+                } else {
+                    *panes
+                };
+                self.prompt_load_palette(panes);
+            }
+            Action::SaveData(panes) => self.prompt_save_data(*panes),
+            Action::OpenData(panes) => self.prompt_open_data(*panes),
+            Action::SaveOrbit(panes) => self.prompt_save_orbit(*panes),
+            Action::SaveRays(panes) => self.prompt_save_rays(*panes),
+            Action::SaveBandContours(panes) => self.prompt_save_band_contours(*panes),
+            Action::ExportPcfCatalog => self.prompt_text(TextInputType::PcfCatalog),
+            Action::LoadRays(panes) => self.prompt_load_rays(*panes),
+            Action::LoadPoints(panes) => self.prompt_load_points(*panes),
             Action::ToggleSelectionMarker => {
                 if let Some(pane) = self.get_active_pane_mut() {
                     pane.marking_mut().toggle_selection();
@@ -906,15 +2366,65 @@ where
                         pane.schedule_redraw();
                     });
             }
+            Action::TogglePeriodLabels(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_period_labels());
+            }
+            Action::ToggleBandContours(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_band_contours());
+            }
+            Action::Tune => {
+                if let Some(pane_id) = self.active_pane {
+                    let input_type = TextInputType::Tuning { pane_id };
+                    self.prompt_text(input_type);
+                }
+            }
             Action::FindPeriodicPoint => {
                 if let Some(pane_id) = self.active_pane {
                     let input_type = TextInputType::FindPeriodic { pane_id };
                     self.prompt_text(input_type);
                 }
             }
+            Action::FindMultiplier => {
+                if let Some(pane_id) = self.active_pane {
+                    let input_type = TextInputType::FindMultiplier { pane_id };
+                    self.prompt_text(input_type);
+                }
+            }
+            Action::AnalyzeMisiurewicz => {
+                if let Some(pane_id) = self.active_pane {
+                    let input_type = TextInputType::MisiurewiczSpiral { pane_id };
+                    self.prompt_text(input_type);
+                }
+            }
+            Action::TraceMultiplierLocus => {
+                if let Some(pane_id) = self.active_pane {
+                    let input_type = TextInputType::MultiplierLocus { pane_id };
+                    self.prompt_text(input_type);
+                }
+            }
             Action::EnterCoordinates => {
                 if let Some(pane_id) = self.active_pane {
-                    let input_type = TextInputType::Coordinates { pane_id };
+                    let pane = self.get_pane(pane_id);
+                    let prompt = format!(
+                        "Enter the coordinates of the point to select on {pane_name}",
+                        pane_name = pane.name()
+                    );
+                    let dialog = PointDialog::new(
+                        "Input coordinates".to_owned(),
+                        prompt,
+                        pane_id,
+                        pane.get_selection(),
+                    );
+                    self.dialog = Some(Dialog::Point(dialog));
+                }
+            }
+            Action::EditLabel => {
+                if let Some(pane_id) = self.active_pane {
+                    let input_type = TextInputType::Label { pane_id };
                     self.prompt_text(input_type);
                 }
             }
@@ -933,6 +2443,26 @@ where
             Action::ClearOrbit => {
                 self.child_mut().clear_marked_orbit();
             }
+            Action::ToggleOrbitPlayback => {
+                self.child_mut().marking_mut().toggle_orbit_playback();
+            }
+            Action::PlayPauseOrbit => {
+                let marking = self.child_mut().marking_mut();
+                let playing = marking.orbit_playback().playing;
+                marking.set_orbit_playing(!playing);
+            }
+            Action::StepOrbit(steps) => {
+                self.child_mut().marking_mut().step_orbit(*steps);
+            }
+            Action::TogglePreimageTree => {
+                if !self.child.toggle_preimage_tree() {
+                    let title = format!("Preimage Tree on {}", self.child.name());
+                    let text = "Preimages can only be traced on dynamical planes of maps with \
+                        known inverse branches."
+                        .to_owned();
+                    self.dialog = Some(Dialog::info(title, text));
+                }
+            }
             Action::DrawExternalRay {
                 include_orbit,
                 select_landing_point,
@@ -952,6 +2482,16 @@ where
                     self.prompt_text(input_type);
                 }
             }
+            Action::OpenAngleCalculator => {
+                let calculator = AngleCalculator::new(self.child.degree());
+                self.dialog = Some(Dialog::AngleCalculator(calculator));
+            }
+            Action::DrawRayBatch => {
+                if let Some(pane_id) = self.active_pane {
+                    let input_type = TextInputType::RayBatch { pane_id };
+                    self.prompt_text(input_type);
+                }
+            }
             Action::DrawContour(contour_type) => {
                 if let Some(p) = self.get_active_pane_mut() {
                     p.draw_contour(*contour_type);
@@ -960,6 +2500,12 @@ where
             Action::DrawAuxContours => {
                 self.get_active_pane_mut().map(Pane::draw_aux_contours);
             }
+            Action::DrawCriticalCurves => {
+                self.get_active_pane_mut().map(Pane::draw_critical_curves);
+            }
+            Action::DrawComponentBoundary => {
+                self.get_active_pane_mut().map(Pane::mark_component_boundary);
+            }
             Action::ClearRays => {
                 self.get_active_pane_mut().map(Pane::clear_marked_rays);
             }
@@ -969,6 +2515,15 @@ where
             Action::ClearCurves => {
                 self.get_active_pane_mut().map(Pane::clear_curves);
             }
+            Action::ClearLoadedPoints => {
+                self.get_active_pane_mut().map(Pane::clear_loaded_points);
+            }
+            Action::ClearLabels => {
+                self.get_active_pane_mut().map(Pane::clear_labels);
+            }
+            Action::ToggleRuler => {
+                self.get_active_pane_mut().map(Pane::toggle_ruler);
+            }
             Action::ResetSelection => match self.active_pane {
                 Some(PaneID::Parent) => self.parent.reset_selection(),
                 Some(PaneID::Child) => {
@@ -979,10 +2534,103 @@ where
             Action::ResetView => {
                 self.get_active_pane_mut().map(Pane::reset);
             }
+            Action::ToggleTrail => {
+                self.parent.marking.trail.visible ^= true;
+            }
+            Action::ShowTrail => {
+                let points = self.parent.marking.trail.points().iter().copied().collect();
+                let dialog = TrailDialog::new("Visited parameters".to_owned(), points);
+                self.dialog = Some(Dialog::Trail(dialog));
+            }
+            Action::ClearTrail => self.parent.marking.trail.clear(),
             Action::ToggleLiveMode => self.toggle_live_mode(),
+            Action::ToggleComparison => self.toggle_comparison(),
+            Action::EstimateDimension => {
+                if let Some(pane) = self.get_active_pane() {
+                    let title = format!("Dimension on {}", pane.name());
+                    let text = pane.box_counting_dimension().map_or_else(
+                        || "Too little of the boundary is in view to estimate.".to_owned(),
+                        |dim| format!("Box-counting dimension of the boundary in view:\n{dim}"),
+                    );
+                    self.dialog = Some(Dialog::info(title, text));
+                }
+            }
+            Action::EstimateArea => {
+                if let Some(pane) = self.get_active_pane() {
+                    let title = format!("Area on {}", pane.name());
+                    let text = pane.estimate_area().map_or_else(
+                        || "Could not estimate the area.".to_owned(),
+                        |area| format!("Area of the bounded points in view:\n{area}"),
+                    );
+                    self.dialog = Some(Dialog::info(title, text));
+                }
+            }
+            Action::ToggleStatistics(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_statistics());
+            }
+            Action::ToggleAttractorInventory(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| {
+                        self.get_pane_mut(pane_id).toggle_attractor_inventory();
+                    });
+            }
+            Action::TogglePcfDatabase(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_pcf_database());
+            }
+            Action::ToggleOrbitTooltip => self.parent_mut().toggle_orbit_tooltip(),
+            Action::ToggleOrbitTrace(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_orbit_trace());
+            }
+            Action::ToggleEquilibriumMeasure(selection) => {
+                for pane_id in self.get_selected_pane_ids(*selection) {
+                    let pane = self.get_pane_mut(pane_id);
+                    if !pane.toggle_equilibrium_measure() {
+                        let title = format!("Equilibrium Measure on {}", pane.name());
+                        let text = "The equilibrium measure can only be sampled on dynamical \
+                            planes of maps with known inverse branches."
+                            .to_owned();
+                        self.dialog = Some(Dialog::info(title, text));
+                    }
+                }
+            }
+            Action::ToggleAttractorDensity(selection) => {
+                for pane_id in self.get_selected_pane_ids(*selection) {
+                    let pane = self.get_pane_mut(pane_id);
+                    if !pane.toggle_attractor_density() {
+                        let title = format!("Attractor Density on {}", pane.name());
+                        let text = "Attractors can only be sampled on dynamical planes of maps \
+                            of the real plane."
+                            .to_owned();
+                        self.dialog = Some(Dialog::info(title, text));
+                    }
+                }
+            }
+            Action::ToggleMinimap => {
+                self.parent_mut().toggle_minimap();
+                self.child_mut().toggle_minimap();
+                if let Some(comparison) = self.comparison_mut() {
+                    comparison.toggle_minimap();
+                }
+            }
             Action::CycleActivePlane => {
                 self.parent_mut().cycle_active_plane();
                 self.child_mut().cycle_active_plane();
+                if let Some(comparison) = self.comparison_mut() {
+                    comparison.cycle_active_plane();
+                }
+            }
+            Action::ChangeCoordinates => {
+                if let Some(pane_id) = self.active_pane {
+                    let input_type = TextInputType::CoordinateChange { pane_id };
+                    self.prompt_text(input_type);
+                }
             }
             Action::PromptImageHeight => {
                 // TODO: Fill in with actual handling
@@ -1004,22 +2652,47 @@ where
                     pane.schedule_recompute();
                 }
             }
+            Action::SetBounds(selection, bounds) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| {
+                        let pane = self.get_pane_mut(pane_id);
+                        pane.grid_mut().change_bounds(bounds.clone());
+                        pane.schedule_recompute();
+                    });
+            }
             Action::ScaleMaxIter(factor) => {
                 if let Some(p) = self.get_active_pane_mut() {
                     p.scale_max_iter(*factor);
                 }
             }
-            Action::RandomizePalette => self.randomize_palette(),
+            Action::RandomizePalette(panes) => self.randomize_palette(*panes),
+            Action::TogglePaletteSync => self.toggle_palette_sync(),
+            Action::TogglePaletteGenerator => self.show_palette_generator ^= true,
+            Action::TogglePinnedPeriodColors => self.toggle_pinned_period_colors(),
+            Action::CycleColorVisionFilter => self.cycle_color_vision_filter(),
+            Action::ToggleLayers(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_layers());
+            }
+            Action::ToggleLighting(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_lighting());
+            }
             Action::SetPalette(palette) => {
                 self.set_palette(*palette);
             }
-            Action::SetPaletteWhite => {
-                let white_palette = Palette::white(16.);
-                self.set_palette(white_palette);
+            Action::SetPaletteWhite(panes) => {
+                for pane_id in self.get_palette_pane_ids(*panes) {
+                    self.get_pane_mut(pane_id).change_palette(Palette::white(16.));
+                }
             }
-            Action::SetPaletteBlack => {
-                let black_palette = Palette::black(16.);
-                self.set_palette(black_palette);
+            Action::SetPaletteBlack(panes) => {
+                for pane_id in self.get_palette_pane_ids(*panes) {
+                    self.get_pane_mut(pane_id).change_palette(Palette::black(16.));
+                }
             }
             Action::SetColoring(algorithm) => {
                 if let Some(p) = self.get_active_pane_mut() {
@@ -1039,18 +2712,38 @@ where
                     .map(Pane::select_preperiod_period_smooth_coloring);
             }
             Action::ScalePalettePeriod(factor) => {
-                if let Some(p) = self.get_active_pane_mut() {
-                    p.scale_palette(*factor);
+                for pane_id in self.get_palette_pane_ids(PaneSelection::ActivePane) {
+                    self.get_pane_mut(pane_id).scale_palette(*factor);
                 }
             }
             Action::ShiftPalettePhase(phase) => {
-                if let Some(p) = self.get_active_pane_mut() {
-                    p.shift_palette(*phase);
+                for pane_id in self.get_palette_pane_ids(PaneSelection::ActivePane) {
+                    self.get_pane_mut(pane_id).shift_palette(*phase);
                 }
             }
+            Action::ToggleColorCycling(selection) => self.toggle_color_cycling(*selection),
+            Action::ToggleWringAnimation => self.toggle_wring_animation(),
             Action::ToggleEscapePhaseColoring => {
                 if let Some(p) = self.get_active_pane_mut() {
-                    p.get_coloring_mut().toggle_escape_phase_coloring();
+                    p.toggle_escape_phase_coloring();
+                }
+            }
+            Action::ToggleBinaryDecomposition => {
+                if let Some(p) = self.get_active_pane_mut() {
+                    p.get_coloring_mut().toggle_binary_decomposition();
+                    p.schedule_redraw();
+                }
+            }
+            Action::CycleOutcoloring => {
+                if let Some(p) = self.get_active_pane_mut() {
+                    p.cycle_outcoloring();
+                }
+            }
+            Action::ScaleDecompositionSectors(factor) => {
+                if let Some(p) = self.get_active_pane_mut() {
+                    let coloring = p.get_coloring_mut();
+                    let sectors = f64::from(coloring.get_decomposition_sectors()) * factor;
+                    coloring.set_decomposition_sectors(sectors.round() as u32);
                     p.schedule_redraw();
                 }
             }
@@ -1062,6 +2755,73 @@ where
                         pane.change_compute_mode(*change);
                     });
             }
+            Action::ToggleInverseIteration(selection) => {
+                for pane_id in self.get_selected_pane_ids(*selection) {
+                    let pane = self.get_pane_mut(pane_id);
+                    if !pane.toggle_inverse_iteration() {
+                        let title = format!("Inverse Iteration on {}", pane.name());
+                        let text = "Inverse iteration is only available on dynamical planes of \
+                            maps with known inverse branches."
+                            .to_owned();
+                        self.dialog = Some(Dialog::info(title, text));
+                    }
+                }
+            }
+            Action::ToggleBasins(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_basins());
+            }
+            Action::ToggleAdaptiveIterations(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_adaptive_iterations());
+            }
+            Action::ToggleStochastic(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_stochastic());
+            }
+            Action::ToggleFlow(selection) => {
+                for pane_id in self.get_selected_pane_ids(*selection) {
+                    let pane = self.get_pane_mut(pane_id);
+                    if !pane.toggle_flow() {
+                        let title = format!("Continuous Iteration on {}", pane.name());
+                        let text = "Continuous iteration is only available on dynamical planes."
+                            .to_owned();
+                        self.dialog = Some(Dialog::info(title, text));
+                    }
+                }
+            }
+            Action::ToggleLogCoordinates(selection) => {
+                for pane_id in self.get_selected_pane_ids(*selection) {
+                    let pane = self.get_pane_mut(pane_id);
+                    if !pane.toggle_log_coordinates() {
+                        let title = format!("Logarithmic Coordinates on {}", pane.name());
+                        let text = "Logarithmic coordinates are only available for \
+                            transcendental maps of exponential type."
+                            .to_owned();
+                        self.dialog = Some(Dialog::info(title, text));
+                    }
+                }
+            }
+            Action::ScaleNoise(factor) => {
+                if let Some(p) = self.get_active_pane_mut() {
+                    p.scale_noise(*factor);
+                }
+            }
+            Action::ToggleLyapunov(selection) => {
+                for pane_id in self.get_selected_pane_ids(*selection) {
+                    let pane = self.get_pane_mut(pane_id);
+                    if !pane.toggle_lyapunov() {
+                        let title = format!("Lyapunov Exponents on {}", pane.name());
+                        let text = "Lyapunov exponents are only available for maps of the real \
+                            plane."
+                            .to_owned();
+                        self.dialog = Some(Dialog::info(title, text));
+                    }
+                }
+            }
         }
     }
 }
@@ -1082,5 +2842,13 @@ where
         self.handle_input(ctx);
         self.show_dialog(ctx);
         self.update_panes();
+        if self.parent().is_computing()
+            || self.child().is_computing()
+            || self.comparison().is_some_and(Pane::is_computing)
+            || self.parent().is_animating()
+            || self.child().is_animating()
+        {
+            ctx.request_repaint();
+        }
     }
 }
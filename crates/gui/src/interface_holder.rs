@@ -1,6 +1,13 @@
 use crate::interface::{Interactive, Interface, UiMessage};
+use crate::notifications::{FileOperation, Notification, Recovery};
+use crate::pane::id::PaneID;
+use crate::render_job::RenderJob;
+use crate::view_state::ViewState;
+use dynamo_color::Transparency;
 use egui::{Context, Ui};
 use libloading::Library;
+use std::error::Error;
+use std::path::Path;
 
 pub struct InterfaceHolder<'i>
 {
@@ -57,6 +64,10 @@ impl Interactive for InterfaceHolder<'_>
     {
         self.interface.change_height(new_height);
     }
+    fn set_max_iter(&mut self, max_iter: dynamo_common::types::IterCount)
+    {
+        self.interface.set_max_iter(max_iter);
+    }
     fn get_image_height(&self) -> usize
     {
         self.interface.get_image_height()
@@ -65,6 +76,37 @@ impl Interactive for InterfaceHolder<'_>
     {
         self.interface.process_action(action);
     }
+    fn render_job(
+        &self,
+        pane_id: PaneID,
+        img_width: usize,
+        filename: &Path,
+        transparency: Transparency,
+    ) -> RenderJob
+    {
+        self.interface
+            .render_job(pane_id, img_width, filename, transparency)
+    }
+    fn encode_png(&self, pane_id: PaneID) -> Result<Vec<u8>, Box<dyn Error>>
+    {
+        self.interface.encode_png(pane_id)
+    }
+    fn view_state(&self) -> ViewState
+    {
+        self.interface.view_state()
+    }
+    fn restore_view_state(&mut self, view: &ViewState)
+    {
+        self.interface.restore_view_state(view);
+    }
+    fn notify(&mut self, notification: Notification)
+    {
+        self.interface.notify(notification);
+    }
+    fn pop_recovery(&mut self) -> Option<(FileOperation, Recovery)>
+    {
+        self.interface.pop_recovery()
+    }
 }
 
 impl Interface for InterfaceHolder<'_>
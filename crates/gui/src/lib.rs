@@ -1,12 +1,22 @@
 #![allow(dead_code)]
 pub mod actions;
+pub mod catalog_file;
 pub mod colors;
+pub mod complex_input;
+pub mod contour_file;
 pub mod dialog;
 pub mod hotkeys;
 pub mod image_frame;
 pub mod interface;
 pub mod marked_points;
+pub mod notifications;
+pub mod orbit_file;
 pub mod pane;
+pub mod point_file;
+pub mod ray_file;
+pub mod render_job;
+pub mod tiled_export;
+pub mod view_state;
 
 #[cfg(feature = "scripting")]
 pub mod interface_holder;
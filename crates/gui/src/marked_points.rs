@@ -1,20 +1,27 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, VecDeque};
+use std::path::Path;
 
-use egui::{Color32, Painter};
+use ab_glyph::FontRef;
+use egui::{Align2, Color32, FontId, Painter};
 use epaint::{CircleShape, PathShape, Pos2, Stroke};
 use image::{ImageBuffer, Rgb};
 use imageproc::drawing::{
-    draw_antialiased_line_segment_mut, draw_filled_circle_mut, draw_polygon_mut,
+    draw_antialiased_line_segment_mut, draw_filled_circle_mut, draw_hollow_circle_mut,
+    draw_polygon_mut, draw_text_mut, text_size,
 };
 use imageproc::pixelops::interpolate;
 use itertools::Itertools;
+use web_time::Instant;
 
 use dynamo_color::palette::DiscretePalette;
 use dynamo_common::prelude::*;
-use dynamo_core::dynamics::Displayable;
+use dynamo_core::dynamics::{
+    inverse_iteration::PreimageNode, multiplier_locus::Bifurcation, Displayable,
+};
 
 use crate::image_frame::ImageFrame;
+use crate::point_file::{self, LabeledPoint, PointFileError};
 
 use self::hashing::HashedReal;
 
@@ -22,10 +29,31 @@ use self::hashing::HashedReal;
 use serde::{self, Deserialize, Serialize};
 
 const POINT_RADIUS: f32 = 3.5;
+/// Width of the outline of periodic points that are not attracting.
+const GLYPH_STROKE: f32 = 1.5;
+/// Number of segments approximating the filled half of an indifferent periodic point.
+const GLYPH_SEGMENTS: usize = 12;
+/// Cycles whose multiplier is this close to the unit circle are drawn as indifferent.
+const INDIFFERENCE_TOLERANCE: Real = 1e-4;
 const CURVE_THICKNESS: f32 = 1.4;
+const TRAIL_LENGTH: usize = 64;
+const TRAIL_RADIUS: f32 = 2.5;
+/// Radius of the iterate reached in orbit playback, relative to that of other points.
+const ITERATE_HIGHLIGHT_SCALE: f32 = 1.6;
+const PREIMAGE_COLOR: Color32 = Color32::from_rgb(255, 170, 60);
+const PREIMAGE_RADIUS: f32 = 1.5;
+/// Number of successive images of the base point covered by a Misiurewicz spiral, and number
+/// of samples between each.
+const SPIRAL_IMAGES: usize = 8;
+const SPIRAL_SAMPLES: usize = 64;
+const BOUNDARY_COLOR: Color32 = Color32::WHITE;
+const POINT_LABEL_SIZE: f32 = 12.;
+const LABEL_SIZE: f32 = 16.;
+const LABEL_COLOR: Color32 = Color32::WHITE;
 
 type Curve = Vec<Cplx>;
 
+#[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColoredPoint
 {
@@ -33,6 +61,83 @@ pub struct ColoredPoint
     pub color: Color32,
 }
 
+/// Whether a cycle attracts or repels nearby orbits, as given by the modulus of its multiplier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stability
+{
+    Attracting,
+    Indifferent,
+    Repelling,
+}
+impl Stability
+{
+    #[must_use]
+    pub fn of_multiplier(multiplier: Cplx) -> Self
+    {
+        let modulus = multiplier.norm();
+        if (modulus - 1.).abs() < INDIFFERENCE_TOLERANCE {
+            Self::Indifferent
+        } else if modulus < 1. {
+            Self::Attracting
+        } else {
+            Self::Repelling
+        }
+    }
+
+    const fn describe(self) -> &'static str
+    {
+        match self {
+            Self::Attracting => "Attracting",
+            Self::Indifferent => "Indifferent",
+            Self::Repelling => "Repelling",
+        }
+    }
+}
+
+/// A point on a cycle, together with the multiplier of the cycle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PeriodicPoint
+{
+    pub point: Cplx,
+    pub multiplier: Cplx,
+}
+impl PeriodicPoint
+{
+    #[must_use]
+    pub fn stability(&self) -> Stability
+    {
+        Stability::of_multiplier(self.multiplier)
+    }
+}
+impl std::fmt::Display for PeriodicPoint
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(
+            f,
+            "{stability} periodic point\n\
+                z = {point:.DISPLAY_PREC$}\n\
+                Multiplier: {multiplier:.DISPLAY_PREC$}\n\
+                |Multiplier|: {modulus:.DISPLAY_PREC$}",
+            stability = self.stability().describe(),
+            point = self.point,
+            multiplier = self.multiplier,
+            modulus = self.multiplier.norm(),
+        )
+    }
+}
+
+/// Text written next to a point of the plane, so that it follows the point as the view changes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Label
+{
+    pub point: Cplx,
+    pub text: String,
+    pub color: Color32,
+}
+
 pub trait ObjectKey: Clone + std::hash::Hash + std::cmp::Eq + std::fmt::Debug
 {
     type Object;
@@ -50,7 +155,6 @@ pub enum PointSetKey
     SelectedPoint,
     CriticalPoints,
     MiscMarkedPoints,
-    PeriodicPoints(Period),
     PreperiodicPoints(OrbitSchema),
 }
 impl ObjectKey for PointSetKey
@@ -62,7 +166,6 @@ impl ObjectKey for PointSetKey
             Self::SelectedPoint => Color32::WHITE,
             Self::CriticalPoints => Color32::RED,
             Self::MiscMarkedPoints => Color32::from_rgb(255, 0, 64),
-            Self::PeriodicPoints(period) => palette.map(*period as f32, 1.),
             Self::PreperiodicPoints(o) => palette.map_preperiodic(*o),
         }
     }
@@ -77,14 +180,45 @@ impl ObjectKey for PointSetKey
                 .map(Into::into)
                 .collect(),
             Self::MiscMarkedPoints => plane.other_marked_points(),
-            Self::PeriodicPoints(period) => {
-                plane.cycles(*period).into_iter().map(Into::into).collect()
-            }
             Self::PreperiodicPoints(o) => plane.precycles(*o).into_iter().map(Into::into).collect(),
         }
     }
 }
 
+/// Key of the periodic points of a given period. Unlike other point sets, these carry the
+/// multipliers of their cycles, so that they can be drawn according to their stability.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CycleKey(Period);
+impl ObjectKey for CycleKey
+{
+    type Object = Vec<PeriodicPoint>;
+    fn color_with(&self, palette: &DiscretePalette, _degree: AngleNum) -> Color32
+    {
+        palette.map(self.0 as f32, 1.)
+    }
+
+    fn compute<P: Displayable>(&self, plane: &P, _selection: Cplx) -> Vec<PeriodicPoint>
+    {
+        let param = plane.param_map(plane.point_grid().center());
+        plane
+            .cycles(self.0)
+            .into_iter()
+            .map(|start| {
+                let point = start.into();
+                let mut z = start;
+                let mut multiplier = Cplx::new(1., 0.);
+                for _ in 0..self.0 {
+                    let (fz, df) = plane.map_and_multiplier(z, &param);
+                    z = fz;
+                    multiplier *= df.into();
+                }
+                PeriodicPoint { point, multiplier }
+            })
+            .collect()
+    }
+}
+
 #[non_exhaustive]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -94,6 +228,9 @@ pub enum ContourType
     Multiplier(Option<HashedReal>),
     ExtendRay,
     InwardRay,
+    /// Curve along which the given iterate of the critical point keeps its distance from the
+    /// nearest critical, co-critical or marked point.
+    CriticalRelation(Period),
 }
 impl ContourType
 {
@@ -115,6 +252,7 @@ impl ContourType
             Self::Equipotential => Color32::YELLOW,
             Self::Multiplier(_) => Color32::from_rgb(255, 160, 122),
             Self::ExtendRay | Self::InwardRay => Color32::RED, //Color32::from_rgb(127, 127, 127),
+            Self::CriticalRelation(_) => Color32::from_rgb(120, 200, 255),
         }
     }
 }
@@ -129,6 +267,8 @@ enum CurveKey
     Orbit,
     Ray(RationalAngle),
     Contour(ContourType, hashing::HashedCplx),
+    Spiral(OrbitSchema, hashing::HashedCplx),
+    MultiplierLocus(Period, Bifurcation, hashing::HashedCplx),
 }
 impl ObjectKey for CurveKey
 {
@@ -142,6 +282,8 @@ impl ObjectKey for CurveKey
                 palette.map_preperiodic(o)
             }
             Self::Contour(ctype, _) => ctype.color(),
+            Self::Spiral(o, _) => palette.map_preperiodic(*o),
+            Self::MultiplierLocus(period, ..) => palette.map(*period as f32, 1.),
         }
     }
 
@@ -161,7 +303,20 @@ impl ObjectKey for CurveKey
                 ContourType::Multiplier(_) => plane.aux_contour(Cplx::from(*point)).compute(),
                 ContourType::ExtendRay => plane.extend_ray(Cplx::from(*point)).compute(),
                 ContourType::InwardRay => plane.inward_ray(Cplx::from(*point)).compute(),
+                ContourType::CriticalRelation(steps) => {
+                    plane.critical_relation_curve(Cplx::from(*point), *steps)
+                }
             },
+            Self::Spiral(o, point) => {
+                let point = Cplx::from(*point);
+                plane
+                    .misiurewicz_spiral(point, *o)
+                    .map(|spiral| spiral.spiral(point, SPIRAL_IMAGES, SPIRAL_SAMPLES))
+                    .unwrap_or_default()
+            }
+            Self::MultiplierLocus(period, bifurcation, point) => plane
+                .multiplier_locus(Cplx::from(*point), *period, *bifurcation)
+                .unwrap_or_default(),
         }
     }
 }
@@ -360,7 +515,32 @@ where
 pub struct Marking
 {
     point_sets: MarkedObjectStore<PointSetKey, Vec<Cplx>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    periodic_points: MarkedObjectStore<CycleKey, Vec<PeriodicPoint>>,
     curves: MarkedObjectStore<CurveKey, Curve>,
+    /// Boundaries of components traced from a computed image. Unlike other curves, they cannot be
+    /// recomputed from the plane, so they are kept as they are until cleared.
+    #[cfg_attr(feature = "serde", serde(default))]
+    boundaries: Vec<ColoredMaybeHidden<Curve>>,
+    /// Points read from files, such as parameters computed by other programs.
+    #[cfg_attr(feature = "serde", serde(default))]
+    loaded_points: Vec<LabeledPoint>,
+    /// Postcritically finite parameters overlaid from a search of the view.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pcf_parameters: Vec<ColoredPoint>,
+    /// Attracting cycles picked out from a computed image. Like boundaries, they are kept as they
+    /// are until cleared.
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycles: Vec<ColoredMaybeHidden<Curve>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    labels: Vec<Label>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub trail: Trail,
+    #[cfg_attr(feature = "serde", serde(default))]
+    orbit_playback: OrbitPlayback,
+    /// Levels of a tree of preimages, each point of which is joined to its image.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    preimage_tree: Vec<Vec<PreimageNode>>,
     #[cfg_attr(feature = "serde", serde(skip))]
     path_cache: RefCell<PathCache>,
 }
@@ -370,6 +550,7 @@ impl Marking
     pub const fn with_degree(mut self, degree: AngleNum) -> Self
     {
         self.point_sets.degree = degree;
+        self.periodic_points.degree = degree;
         self.curves.degree = degree;
         self
     }
@@ -408,8 +589,7 @@ impl Marking
 
     pub fn toggle_cycles_of_period(&mut self, period: Period)
     {
-        self.point_sets
-            .sched_toggle(PointSetKey::PeriodicPoints(period));
+        self.periodic_points.sched_toggle(CycleKey(period));
     }
 
     pub fn toggle_ray(&mut self, angle: RationalAngle)
@@ -431,15 +611,40 @@ impl Marking
         self.path_cache.borrow_mut().set_stale();
     }
 
+    /// Toggle the logarithmic spiral from `base_point` into the nearby Misiurewicz parameter
+    /// with the given orbit schema.
+    pub fn toggle_spiral(&mut self, orbit_schema: OrbitSchema, base_point: Cplx)
+    {
+        self.curves
+            .sched_toggle(CurveKey::Spiral(orbit_schema, base_point.into()));
+        self.path_cache.borrow_mut().set_stale();
+    }
+
+    /// Toggle the boundary of the hyperbolic component of period `period` containing
+    /// `base_point`, traced from the given bifurcation.
+    pub fn toggle_multiplier_locus(
+        &mut self,
+        period: Period,
+        bifurcation: Bifurcation,
+        base_point: Cplx,
+    )
+    {
+        self.curves
+            .sched_toggle(CurveKey::MultiplierLocus(period, bifurcation, base_point.into()));
+        self.path_cache.borrow_mut().set_stale();
+    }
+
     pub fn sched_recompute_all(&mut self)
     {
         self.point_sets.sched_recompute_all();
+        self.periodic_points.sched_recompute_all();
         self.curves.sched_recompute_all();
         self.path_cache.borrow_mut().set_stale();
     }
     pub fn sched_recolor_all(&mut self)
     {
         self.point_sets.sched_recolor_all();
+        self.periodic_points.sched_recolor_all();
         self.curves.sched_recolor_all();
     }
 
@@ -451,7 +656,12 @@ impl Marking
     pub fn remove_all_annotations(&mut self)
     {
         self.point_sets.disable_all();
+        self.periodic_points.disable_all();
         self.curves.disable_all();
+        self.boundaries.clear();
+        self.loaded_points.clear();
+        self.pcf_parameters.clear();
+        self.labels.clear();
         self.path_cache.borrow_mut().set_stale();
     }
 
@@ -468,13 +678,17 @@ impl Marking
             palette,
         };
         self.point_sets.process_all_tasks(&env);
+        self.periodic_points.process_all_tasks(&env);
         self.curves.process_all_tasks(&env);
     }
 
+    /// Mark an orbit, keeping the place reached in playback if the orbit starts where the
+    /// previous one did.
     pub fn mark_orbit_manually(&mut self, orbit: Curve, color: Color32)
     {
+        self.orbit_playback.load(orbit);
         let col_obj = ColoredMaybeHidden {
-            object: orbit,
+            object: self.orbit_playback.shown().to_vec(),
             color,
             visible: true,
         };
@@ -482,6 +696,206 @@ impl Marking
         self.path_cache.borrow_mut().set_stale();
     }
 
+    #[must_use]
+    pub const fn orbit_playback(&self) -> &OrbitPlayback
+    {
+        &self.orbit_playback
+    }
+
+    /// Show the marked orbit one iterate at a time, or all at once again.
+    pub fn toggle_orbit_playback(&mut self)
+    {
+        self.orbit_playback.toggle();
+        self.refresh_orbit();
+    }
+
+    pub fn set_orbit_playing(&mut self, playing: bool)
+    {
+        self.orbit_playback.set_playing(playing);
+    }
+
+    pub fn set_orbit_playback_speed(&mut self, speed: f64)
+    {
+        self.orbit_playback.speed = speed;
+    }
+
+    /// Move `steps` iterates forward or back along the marked orbit, pausing playback.
+    pub fn step_orbit(&mut self, steps: isize)
+    {
+        self.orbit_playback.step_by(steps);
+        self.refresh_orbit();
+    }
+
+    /// Advance orbit playback to the present. Returns whether another iterate was reached.
+    pub fn advance_orbit_playback(&mut self) -> bool
+    {
+        let advanced = self.orbit_playback.advance();
+        if advanced {
+            self.refresh_orbit();
+        }
+        advanced
+    }
+
+    /// Redraw the marked orbit up to the iterate reached in playback.
+    fn refresh_orbit(&mut self)
+    {
+        if let Some(orbit) = self.curves.objects.get_mut(&CurveKey::Orbit) {
+            orbit.object = self.orbit_playback.shown().to_vec();
+            self.path_cache.borrow_mut().set_stale();
+        }
+    }
+
+    /// Show a tree of preimages, replacing the one shown before.
+    pub fn set_preimage_tree(&mut self, levels: Vec<Vec<PreimageNode>>)
+    {
+        self.preimage_tree = levels;
+    }
+
+    /// Preimages in the tree other than its root, each with its image, fading as their order
+    /// grows.
+    fn iter_preimage_edges(&self) -> impl Iterator<Item = (ColoredPoint, Cplx)> + '_
+    {
+        let depth = self.preimage_tree.len() as f32;
+        self.preimage_tree
+            .iter()
+            .tuple_windows()
+            .enumerate()
+            .flat_map(move |(k, (images, level))| {
+                let opacity = 0.7f32.mul_add(1. - k as f32 / depth, 0.3);
+                let color = PREIMAGE_COLOR.gamma_multiply(opacity);
+                level.iter().map(move |node| {
+                    let point = ColoredPoint {
+                        point: node.point,
+                        color,
+                    };
+                    (point, images[node.image].point)
+                })
+            })
+    }
+
+    /// Index and position of the iterate reached in playback, with the color of the orbit, if
+    /// the orbit is shown.
+    fn current_iterate(&self) -> Option<(usize, ColoredPoint)>
+    {
+        let orbit = self.curves.objects.get(&CurveKey::Orbit)?;
+        if !orbit.visible {
+            return None;
+        }
+        let (index, point) = self.orbit_playback.current()?;
+        Some((
+            index,
+            ColoredPoint {
+                point,
+                color: orbit.color,
+            },
+        ))
+    }
+
+    /// Show the closed polygons bounding a component of the image.
+    pub fn mark_boundary(&mut self, polygons: Vec<Curve>)
+    {
+        self.boundaries
+            .extend(polygons.into_iter().map(|mut polygon| {
+                if let Some(&first) = polygon.first() {
+                    polygon.push(first);
+                }
+                ColoredMaybeHidden {
+                    object: polygon,
+                    color: BOUNDARY_COLOR,
+                    visible: true,
+                }
+            }));
+        self.path_cache.borrow_mut().set_stale();
+    }
+
+    /// Mark the points listed in a file, in addition to those loaded before. Returns the number
+    /// of points read.
+    pub fn load_points(&mut self, path: &Path) -> Result<usize, PointFileError>
+    {
+        let points = point_file::load(path)?;
+        let count = points.len();
+        self.loaded_points.extend(points);
+        Ok(count)
+    }
+
+    pub fn clear_loaded_points(&mut self)
+    {
+        self.loaded_points.clear();
+    }
+
+    /// Replace the overlaid postcritically finite parameters.
+    pub fn set_pcf_parameters(&mut self, points: Vec<ColoredPoint>)
+    {
+        self.pcf_parameters = points;
+    }
+
+    /// Mark the points of a cycle, or unmark them if the cycle through `cycle[0]` is marked.
+    pub fn toggle_cycle(&mut self, cycle: Curve, color: Color32)
+    {
+        let Some(&point) = cycle.first() else {
+            return;
+        };
+        if self.cycle_marked(point) {
+            self.cycles.retain(|marked| !marked.object.contains(&point));
+        } else {
+            self.cycles.push(ColoredMaybeHidden {
+                object: cycle,
+                color,
+                visible: true,
+            });
+        }
+    }
+
+    /// Whether a marked cycle passes through `point`.
+    #[must_use]
+    pub fn cycle_marked(&self, point: Cplx) -> bool
+    {
+        self.cycles.iter().any(|marked| marked.object.contains(&point))
+    }
+
+    /// The label closest to `point`, if it is within `radius` of it.
+    #[must_use]
+    pub fn label_near(&self, point: Cplx, radius: Real) -> Option<&Label>
+    {
+        self.label_index_near(point, radius)
+            .map(|i| &self.labels[i])
+    }
+
+    fn label_index_near(&self, point: Cplx, radius: Real) -> Option<usize>
+    {
+        self.labels
+            .iter()
+            .map(|label| (label.point - point).norm())
+            .enumerate()
+            .filter(|(_, dist)| *dist <= radius)
+            .min_by(|(_, d0), (_, d1)| d0.total_cmp(d1))
+            .map(|(i, _)| i)
+    }
+
+    /// Change the text of the label closest to `point` within `radius`, or write a new label at
+    /// `point` if there is none. Empty text removes the label.
+    pub fn edit_label(&mut self, point: Cplx, radius: Real, text: &str)
+    {
+        let text = text.trim();
+        match self.label_index_near(point, radius) {
+            Some(i) if text.is_empty() => {
+                self.labels.remove(i);
+            }
+            Some(i) => text.clone_into(&mut self.labels[i].text),
+            None if text.is_empty() => {}
+            None => self.labels.push(Label {
+                point,
+                text: text.to_owned(),
+                color: LABEL_COLOR,
+            }),
+        }
+    }
+
+    pub fn clear_labels(&mut self)
+    {
+        self.labels.clear();
+    }
+
     pub fn disable_orbit(&mut self)
     {
         self.curves.disable(&CurveKey::Orbit);
@@ -521,11 +935,16 @@ impl Marking
     pub fn disable_all_points(&mut self)
     {
         self.point_sets.disable_all();
+        self.periodic_points.disable_all();
+        self.loaded_points.clear();
+        self.pcf_parameters.clear();
+        self.cycles.clear();
     }
 
     pub fn disable_all_curves(&mut self)
     {
         self.curves.disable_all();
+        self.boundaries.clear();
         self.path_cache.borrow_mut().set_stale();
     }
 
@@ -547,11 +966,93 @@ impl Marking
                     })
                 },
             )
+            .chain(self.loaded_points.iter().map(|p| ColoredPoint {
+                point: p.point,
+                color: p.color,
+            }))
+            .chain(self.pcf_parameters.iter().copied())
+            .chain(self.cycles.iter().filter(|o| o.visible).flat_map(|marked| {
+                marked.object.iter().map(|&point| ColoredPoint {
+                    point,
+                    color: marked.color,
+                })
+            }))
+    }
+
+    /// Periodic points currently shown, with the colors of their periods.
+    fn iter_periodic_points(&self) -> impl Iterator<Item = (PeriodicPoint, Color32)> + '_
+    {
+        self.periodic_points
+            .objects
+            .values()
+            .filter(|o| o.visible)
+            .flat_map(|marked| marked.object.iter().map(|&point| (point, marked.color)))
+    }
+
+    /// The marked periodic point closest to `point`, if it is within `radius` of it.
+    #[must_use]
+    pub fn periodic_point_near(&self, point: Cplx, radius: Real) -> Option<PeriodicPoint>
+    {
+        self.iter_periodic_points()
+            .map(|(periodic, _)| (periodic, (periodic.point - point).norm()))
+            .filter(|(_, dist)| *dist <= radius)
+            .min_by(|(_, d0), (_, d1)| d0.total_cmp(d1))
+            .map(|(periodic, _)| periodic)
     }
 
     fn iter_visible_curves(&self) -> impl Iterator<Item = ColoredMaybeHidden<Curve>> + '_
     {
-        self.curves.objects.values().filter(|o| o.visible).cloned()
+        self.curves
+            .objects
+            .values()
+            .chain(&self.boundaries)
+            .filter(|o| o.visible)
+            .cloned()
+    }
+
+    /// Angles of the rays currently shown, in increasing order.
+    pub fn visible_rays(&self) -> Vec<RationalAngle>
+    {
+        let mut angles: Vec<_> = self
+            .curves
+            .objects
+            .iter()
+            .filter(|(_, o)| o.visible)
+            .filter_map(|(key, _)| match key {
+                CurveKey::Ray(angle) => Some(*angle),
+                _ => None,
+            })
+            .collect();
+        angles.sort_unstable();
+        angles
+    }
+
+    /// Angles and traced points of the rays currently shown, in increasing order of angle.
+    pub fn traced_rays(&self) -> Vec<(RationalAngle, Curve)>
+    {
+        self.visible_rays()
+            .into_iter()
+            .filter_map(|angle| {
+                let col_ray = self.curves.objects.get(&CurveKey::Ray(angle))?;
+                Some((angle, col_ray.object.clone()))
+            })
+            .collect()
+    }
+
+    /// Show rays traced elsewhere, such as in another session, without tracing them again.
+    pub fn import_rays(&mut self, rays: Vec<(RationalAngle, Curve)>)
+    {
+        for (angle, points) in rays {
+            let key = CurveKey::Ray(angle);
+            let col_obj = ColoredMaybeHidden {
+                object: points,
+                color: Color32::WHITE,
+                visible: true,
+            };
+            self.curves.objects.insert(key, col_obj);
+            self.curves.sched_recolor(key);
+        }
+        self.path_cache.borrow_mut().set_stale();
     }
 
     pub fn ray_landing_point(&self, angle: RationalAngle) -> Option<Cplx>
@@ -566,21 +1067,20 @@ impl Marking
         self.path_cache
             .borrow_mut()
             .paths
-            .extend(self.iter_visible_curves().map(
+            .extend(self.iter_visible_curves().flat_map(
                 |ColoredMaybeHidden {
                      object: zs, color, ..
                  }| {
-                    let points = zs
-                        .iter()
-                        .map(|z| {
-                            let pt = grid.locate_point(*z);
-                            frame.to_global_coords(pt.into())
-                        })
-                        .collect();
-                    Colored {
-                        object: points,
-                        color,
-                    }
+                    grid.locate_curve(&zs).into_iter().map(move |run| {
+                        let points = run
+                            .into_iter()
+                            .map(|pt| frame.to_global_coords(pt.into()))
+                            .collect();
+                        Colored {
+                            object: points,
+                            color,
+                        }
+                    })
                 },
             ));
 
@@ -589,11 +1089,85 @@ impl Marking
 
     pub fn draw_points(&self, painter: &Painter, grid: &PointGrid, frame: &ImageFrame)
     {
+        let locate = |z: Cplx| grid.locate_point(z).map(|pt| frame.to_global_coords(pt.into()));
+        for (ColoredPoint { point, color }, image) in self.iter_preimage_edges() {
+            let (Some(point), Some(image)) = (locate(point), locate(image)) else {
+                continue;
+            };
+            painter.line_segment([point, image], Stroke::new(0.5, color));
+            painter.add(CircleShape::filled(point, PREIMAGE_RADIUS, color));
+        }
+        if self.trail.visible {
+            for ColoredPoint { point: z, color } in self.trail.iter_faded() {
+                let Some(point) = locate(z) else {
+                    continue;
+                };
+                painter.add(CircleShape::filled(point, TRAIL_RADIUS, color));
+            }
+        }
         for ColoredPoint { point: z, color } in self.iter_points() {
-            let point = frame.to_global_coords(grid.locate_point(z).into());
+            let Some(point) = locate(z) else {
+                continue;
+            };
             let patch = CircleShape::filled(point, POINT_RADIUS, color);
             painter.add(patch);
         }
+        for (periodic, color) in self.iter_periodic_points() {
+            let Some(center) = locate(periodic.point) else {
+                continue;
+            };
+            let stroke = Stroke::new(GLYPH_STROKE, color);
+            let outline = CircleShape::stroke(center, POINT_RADIUS, stroke);
+            match periodic.stability() {
+                Stability::Attracting => {
+                    painter.add(CircleShape::filled(center, POINT_RADIUS, color));
+                }
+                Stability::Indifferent => {
+                    let half = half_disk([center.x, center.y], POINT_RADIUS)
+                        .map(|[x, y]| Pos2::new(x, y))
+                        .collect();
+                    painter.add(PathShape::convex_polygon(half, color, Stroke::NONE));
+                    painter.add(outline);
+                }
+                Stability::Repelling => {
+                    painter.add(outline);
+                }
+            }
+        }
+        for LabeledPoint { point, label, color } in &self.loaded_points {
+            let (Some(label), Some(pos)) = (label, locate(*point)) else {
+                continue;
+            };
+            painter.text(
+                pos + egui::vec2(POINT_RADIUS + 2., 0.),
+                Align2::LEFT_CENTER,
+                label,
+                FontId::proportional(POINT_LABEL_SIZE),
+                *color,
+            );
+        }
+        for Label { point, text, color } in &self.labels {
+            let Some(pos) = locate(*point) else {
+                continue;
+            };
+            let font = FontId::proportional(LABEL_SIZE);
+            painter.text(pos, Align2::LEFT_CENTER, text, font, *color);
+        }
+        if let Some((index, ColoredPoint { point, color })) = self.current_iterate() {
+            let Some(pos) = locate(point) else {
+                return;
+            };
+            let radius = POINT_RADIUS * ITERATE_HIGHLIGHT_SCALE;
+            painter.add(CircleShape::filled(pos, radius, color));
+            painter.add(CircleShape::stroke(pos, radius, Stroke::new(1., Color32::BLACK)));
+            painter.text(
+                pos + egui::vec2(radius + 2., 0.),
+                Align2::LEFT_CENTER,
+                index.to_string(),
+                FontId::proportional(POINT_LABEL_SIZE),
+                color,
+            );
+        }
     }
 
     pub fn draw_curves(&self, painter: &Painter, grid: &PointGrid, frame: &ImageFrame)
@@ -637,20 +1211,294 @@ impl Marking
     }
     fn draw_points_to_image(&self, grid: &PointGrid, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
     {
+        let preimage_radius = PREIMAGE_RADIUS * (image.width() as f32) / 768.;
+        for (ColoredPoint { point, color }, target) in self.iter_preimage_edges() {
+            let (red, grn, blu, _alp) = color.to_tuple();
+            let color = Rgb([red, grn, blu]);
+            let (Some([x0, y0]), Some([x1, y1])) =
+                (grid.locate_point(point), grid.locate_point(target))
+            else {
+                continue;
+            };
+            let (p0, p1) = ((x0 as i32, y0 as i32), (x1 as i32, y1 as i32));
+            draw_antialiased_line_segment_mut(image, p0, p1, color, interpolate);
+            draw_filled_circle_mut(image, p0, preimage_radius as i32, color);
+        }
         let radius = POINT_RADIUS * (image.width() as f32) / 768.;
         self.iter_points()
             .for_each(|ColoredPoint { point, color }| {
                 let (red, grn, blu, _alp) = color.to_tuple();
                 let color = Rgb([red, grn, blu]);
-                let [x, y] = grid.locate_point(point);
+                let Some([x, y]) = grid.locate_point(point) else {
+                    return;
+                };
                 let center = (x as i32, y as i32);
                 draw_filled_circle_mut(image, center, radius as i32, color);
             });
+        for (periodic, color) in self.iter_periodic_points() {
+            let (red, grn, blu, _alp) = color.to_tuple();
+            let color = Rgb([red, grn, blu]);
+            let Some([x, y]) = grid.locate_point(periodic.point) else {
+                continue;
+            };
+            let center = (x as i32, y as i32);
+            match periodic.stability() {
+                Stability::Attracting => {
+                    draw_filled_circle_mut(image, center, radius as i32, color);
+                }
+                Stability::Indifferent => {
+                    let half: Vec<_> = half_disk([x, y], radius)
+                        .map(|[x, y]| imageproc::point::Point::new(x as i32, y as i32))
+                        .dedup()
+                        .collect();
+                    if half.len() > 2 {
+                        draw_polygon_mut(image, &half, color);
+                    }
+                    draw_hollow_circle_mut(image, center, radius as i32, color);
+                }
+                Stability::Repelling => {
+                    draw_hollow_circle_mut(image, center, radius as i32, color);
+                }
+            }
+        }
+        if let Some((_, ColoredPoint { point, color })) = self.current_iterate() {
+            let (red, grn, blu, _alp) = color.to_tuple();
+            let color = Rgb([red, grn, blu]);
+            let Some([x, y]) = grid.locate_point(point) else {
+                return;
+            };
+            let radius = radius * ITERATE_HIGHLIGHT_SCALE;
+            draw_filled_circle_mut(image, (x as i32, y as i32), radius as i32, color);
+        }
+    }
+    fn draw_labels_to_image(&self, grid: &PointGrid, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
+    {
+        let Ok(font) = FontRef::try_from_slice(epaint_default_fonts::UBUNTU_LIGHT) else {
+            return;
+        };
+        let scale = LABEL_SIZE * (image.width() as f32) / 768.;
+        for Label { point, text, color } in &self.labels {
+            let (red, grn, blu, _alp) = color.to_tuple();
+            let Some([x, y]) = grid.locate_point(*point) else {
+                continue;
+            };
+            let (_, height) = text_size(scale, &font, text);
+            let top = y as i32 - height as i32 / 2;
+            draw_text_mut(image, Rgb([red, grn, blu]), x as i32, top, scale, &font, text);
+        }
     }
+
     pub fn mark_image(&self, grid: &PointGrid, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
     {
         self.draw_curves_to_image(grid, image);
         self.draw_points_to_image(grid, image);
+        self.draw_labels_to_image(grid, image);
+    }
+}
+
+/// Boundary of the left half of the disk with the given center and radius, traversed from top
+/// to bottom.
+fn half_disk(center: [f32; 2], radius: f32) -> impl Iterator<Item = [f32; 2]>
+{
+    let [x, y] = center;
+    (0..=GLYPH_SEGMENTS).map(move |i| {
+        let theta = std::f32::consts::PI * (0.5 + i as f32 / GLYPH_SEGMENTS as f32);
+        [radius.mul_add(theta.cos(), x), radius.mul_add(-theta.sin(), y)]
+    })
+}
+
+/// Points visited in the past, most recent last. Unlike other markings, the trail survives
+/// clearing annotations.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Trail
+{
+    points: VecDeque<Cplx>,
+    pub visible: bool,
+}
+impl Default for Trail
+{
+    fn default() -> Self
+    {
+        Self {
+            points: VecDeque::new(),
+            visible: true,
+        }
+    }
+}
+
+impl Trail
+{
+    /// Append a point, forgetting the oldest one if the trail is full.
+    pub fn record(&mut self, point: Cplx)
+    {
+        if self.points.back() == Some(&point) {
+            return;
+        }
+        if self.points.len() == TRAIL_LENGTH {
+            self.points.pop_front();
+        }
+        self.points.push_back(point);
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.points.clear();
+    }
+
+    #[must_use]
+    pub fn points(&self) -> &VecDeque<Cplx>
+    {
+        &self.points
+    }
+
+    /// Points of the trail, fading from white as they age.
+    fn iter_faded(&self) -> impl Iterator<Item = ColoredPoint> + '_
+    {
+        let len = self.points.len() as f32;
+        self.points.iter().enumerate().map(move |(i, &point)| {
+            let opacity = 0.6f32.mul_add((i + 1) as f32 / len, 0.15);
+            ColoredPoint {
+                point,
+                color: Color32::WHITE.gamma_multiply(opacity),
+            }
+        })
+    }
+}
+
+/// Step-through playback of the marked orbit. While enabled, the orbit is drawn only up to the
+/// iterate reached, which is highlighted along with its index.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OrbitPlayback
+{
+    pub enabled: bool,
+    pub playing: bool,
+    /// Iterates reached per second while playing.
+    pub speed: f64,
+    /// Iterates of the orbit in order, so that the `i`th segment joins iterates `i` and `i + 1`.
+    iterates: Curve,
+    step: usize,
+    /// Fraction of the way from the iterate reached to the next one.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    progress: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_advance: Option<Instant>,
+}
+impl Default for OrbitPlayback
+{
+    fn default() -> Self
+    {
+        Self {
+            enabled: false,
+            playing: false,
+            speed: Self::DEFAULT_SPEED,
+            iterates: Vec::new(),
+            step: 0,
+            progress: 0.,
+            last_advance: None,
+        }
+    }
+}
+
+impl OrbitPlayback
+{
+    pub const DEFAULT_SPEED: f64 = 4.;
+    pub const MAX_SPEED: f64 = 60.;
+
+    /// Replace the orbit, starting again from its first iterate if it starts at a new point.
+    fn load(&mut self, orbit: Curve)
+    {
+        if self.iterates.first() != orbit.first() {
+            self.step = 0;
+            self.progress = 0.;
+        }
+        self.iterates = orbit;
+        self.step = self.step.min(self.last_index());
+    }
+
+    /// Index of the last iterate of the orbit.
+    #[must_use]
+    pub fn last_index(&self) -> usize
+    {
+        self.iterates.len().saturating_sub(1)
+    }
+
+    /// Index of the iterate reached.
+    #[must_use]
+    pub const fn step(&self) -> usize
+    {
+        self.step
+    }
+
+    /// Iterates to draw: those up to the one reached during playback, and all of them otherwise.
+    fn shown(&self) -> &[Cplx]
+    {
+        if self.enabled {
+            &self.iterates[..(self.step + 1).min(self.iterates.len())]
+        } else {
+            &self.iterates
+        }
+    }
+
+    fn current(&self) -> Option<(usize, Cplx)>
+    {
+        if !self.enabled {
+            return None;
+        }
+        self.iterates.get(self.step).map(|&z| (self.step, z))
+    }
+
+    /// Enable playback from the first iterate, or disable it.
+    fn toggle(&mut self)
+    {
+        self.enabled ^= true;
+        self.step = 0;
+        self.progress = 0.;
+        self.set_playing(self.enabled);
+    }
+
+    fn set_playing(&mut self, playing: bool)
+    {
+        self.playing = playing && self.enabled;
+        if self.playing && self.step == self.last_index() {
+            self.step = 0;
+        }
+        self.last_advance = None;
+    }
+
+    fn step_by(&mut self, steps: isize)
+    {
+        self.set_playing(false);
+        self.progress = 0.;
+        self.step = self
+            .step
+            .saturating_add_signed(steps)
+            .min(self.last_index());
+    }
+
+    /// Reach the iterate due at the present time, stopping at the end of the orbit. Returns
+    /// whether another iterate was reached.
+    fn advance(&mut self) -> bool
+    {
+        if !self.playing {
+            return false;
+        }
+        let now = Instant::now();
+        let Some(last) = self.last_advance.replace(now) else {
+            return false;
+        };
+        self.progress += self.speed * now.duration_since(last).as_secs_f64();
+        let steps = self.progress.floor();
+        self.progress -= steps;
+        let step = self
+            .step
+            .saturating_add(steps as usize)
+            .min(self.last_index());
+        if step == self.last_index() {
+            self.playing = false;
+        }
+        std::mem::replace(&mut self.step, step) != step
     }
 }
 
@@ -762,11 +1610,10 @@ impl CurveDrawJob<'_>
 {
     pub fn draw_thick(self, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
     {
-        self.curve
+        self.grid
+            .locate_curve(self.curve)
             .iter()
-            .copied()
-            .map(|z| self.grid.locate_point(z))
-            .tuple_windows()
+            .flat_map(|run| run.iter().copied().tuple_windows())
             .for_each(|([x0, y0], [x1, y1])| {
                 let normal_x = y1 - y0;
                 let normal_y = x0 - x1;
@@ -807,12 +1654,10 @@ impl CurveDrawJob<'_>
 
     fn draw_thin(self, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
     {
-        self.curve
+        self.grid
+            .locate_curve(self.curve)
             .iter()
-            .copied()
-            .map(|z| self.grid.locate_point(z))
-            .map(|[x, y]| (x as i32, y as i32))
-            .tuple_windows()
+            .flat_map(|run| run.iter().map(|&[x, y]| (x as i32, y as i32)).tuple_windows())
             .for_each(|(p0, p1)| {
                 draw_antialiased_line_segment_mut(image, p0, p1, self.color, interpolate);
             });
@@ -0,0 +1,194 @@
+//! Messages about finished saves and loads, stacked in the corner of the window.
+//!
+//! Successes fade after a few seconds. Errors stay until dismissed, and when they come from a
+//! file operation they offer to try the operation again or to pick another file.
+use std::path::PathBuf;
+
+use dynamo_core::error::{DynamoError, DynamoResult};
+use egui::{Align2, Color32, Context, Id, RichText};
+use web_time::{Duration, Instant};
+
+use crate::dialog::{LoadFileType, SaveFileType};
+use crate::pane::id::PaneSelection;
+
+/// How long a notification about a success stays on screen.
+const INFO_DURATION: Duration = Duration::from_secs(4);
+const MAX_WIDTH: f32 = 360.;
+
+#[derive(Clone, Copy, Debug)]
+pub enum FileKind
+{
+    Save(SaveFileType),
+    Load(LoadFileType),
+    /// A user script saved from the script editor, and whether it runs once saved. These are
+    /// recovered by the app, which owns the editor, so the pane selection is ignored.
+    Script
+    {
+        run: bool,
+    },
+}
+
+/// A save or load of one pane, kept so that it can be repeated after a failure.
+#[derive(Clone, Debug)]
+pub struct FileOperation
+{
+    pub selection: PaneSelection,
+    pub path: PathBuf,
+    pub kind: FileKind,
+}
+
+/// The outcome of a save that ran in the background, with the message to show if it succeeded.
+#[derive(Debug)]
+pub struct FinishedSave
+{
+    pub path: PathBuf,
+    pub file_type: SaveFileType,
+    pub result: DynamoResult<String>,
+}
+
+/// Ways offered to recover from a failed file operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Recovery
+{
+    Retry,
+    ChooseAnotherFile,
+}
+
+#[derive(Clone, Debug)]
+pub struct Notification
+{
+    title: String,
+    /// Explanation shown below the title, for errors.
+    detail: Option<String>,
+    /// When the notification disappears by itself, if ever.
+    expires: Option<Instant>,
+    operation: Option<FileOperation>,
+    can_retry: bool,
+}
+
+impl Notification
+{
+    #[must_use]
+    pub fn info(text: impl Into<String>) -> Self
+    {
+        Self {
+            title: text.into(),
+            detail: None,
+            expires: Some(Instant::now() + INFO_DURATION),
+            operation: None,
+            can_retry: false,
+        }
+    }
+
+    #[must_use]
+    pub fn error(title: impl Into<String>, error: &DynamoError) -> Self
+    {
+        Self {
+            title: title.into(),
+            detail: Some(error.to_string()),
+            expires: None,
+            operation: None,
+            can_retry: error.can_retry(),
+        }
+    }
+
+    /// A failure unrelated to files, e.g. a search that found nothing, explained by `detail`.
+    #[must_use]
+    pub fn failure(title: impl Into<String>, detail: impl Into<String>) -> Self
+    {
+        Self {
+            title: title.into(),
+            detail: Some(detail.into()),
+            expires: None,
+            operation: None,
+            can_retry: false,
+        }
+    }
+
+    /// Offer to repeat `operation` or to choose another file for it.
+    #[must_use]
+    pub fn with_operation(mut self, operation: FileOperation) -> Self
+    {
+        self.operation = Some(operation);
+        self
+    }
+
+    #[must_use]
+    pub const fn is_error(&self) -> bool
+    {
+        self.detail.is_some()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Notifications
+{
+    entries: Vec<Notification>,
+}
+
+impl Notifications
+{
+    pub fn push(&mut self, notification: Notification)
+    {
+        self.entries.push(notification);
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.entries.is_empty()
+    }
+
+    /// Draw the notifications in the bottom right corner, newest at the bottom. Returns the
+    /// operation to recover, if the user chose one.
+    pub fn show(&mut self, ctx: &Context) -> Option<(FileOperation, Recovery)>
+    {
+        let now = Instant::now();
+        self.entries.retain(|entry| entry.expires.is_none_or(|expires| expires > now));
+        if let Some(next_expiry) = self.entries.iter().filter_map(|entry| entry.expires).min() {
+            ctx.request_repaint_after(next_expiry - now);
+        }
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let mut dismissed = None;
+        let mut chosen = None;
+        egui::Area::new(Id::new("notifications"))
+            .anchor(Align2::RIGHT_BOTTOM, [-12., -12.])
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.set_max_width(MAX_WIDTH);
+                for (i, entry) in self.entries.iter().enumerate() {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        let Some(detail) = &entry.detail else {
+                            ui.label(&entry.title);
+                            return;
+                        };
+                        ui.label(RichText::new(&entry.title).strong().color(Color32::LIGHT_RED));
+                        ui.label(detail);
+                        ui.horizontal(|ui| {
+                            if entry.operation.is_some() {
+                                if entry.can_retry && ui.button("Try again").clicked() {
+                                    chosen = Some((i, Recovery::Retry));
+                                }
+                                if ui.button("Choose another file").clicked() {
+                                    chosen = Some((i, Recovery::ChooseAnotherFile));
+                                }
+                            }
+                            if ui.button("Dismiss").clicked() {
+                                dismissed = Some(i);
+                            }
+                        });
+                    });
+                }
+            });
+
+        if let Some(i) = dismissed {
+            self.entries.remove(i);
+        }
+        let (i, recovery) = chosen?;
+        let operation = self.entries.remove(i).operation?;
+        Some((operation, recovery))
+    }
+}
@@ -0,0 +1,78 @@
+//! Writing orbits to files for analysis in other programs.
+//!
+//! The format is chosen by the file extension. JSON files hold an object with the
+//! classification of the orbit and the list of its steps; otherwise the orbit is written as CSV,
+//! with the classification in a comment on the first line.
+use std::io::Write;
+use std::path::Path;
+
+use dynamo_core::orbit::OrbitStep;
+use serde_json::json;
+
+pub fn save(
+    path: &Path,
+    steps: &[OrbitStep],
+    classification: &str,
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    if is_json {
+        serde_json::to_writer_pretty(&mut file, &to_json(steps, classification))?;
+    } else {
+        write_csv(&mut file, steps, classification)?;
+    }
+    file.flush()?;
+    Ok(())
+}
+
+fn to_json(steps: &[OrbitStep], classification: &str) -> serde_json::Value
+{
+    let steps: Vec<_> = steps
+        .iter()
+        .map(|step| {
+            json!({
+                "iter": step.iter,
+                "re": step.z.re,
+                "im": step.z.im,
+                "multiplier": [step.multiplier.re, step.multiplier.im],
+                "derivative": [step.derivative.re, step.derivative.im],
+            })
+        })
+        .collect();
+    json!({
+        "classification": classification,
+        "orbit": steps,
+    })
+}
+
+fn write_csv(
+    writer: &mut impl Write,
+    steps: &[OrbitStep],
+    classification: &str,
+) -> std::io::Result<()>
+{
+    for line in classification.lines() {
+        writeln!(writer, "# {line}")?;
+    }
+    writeln!(
+        writer,
+        "iter,re,im,multiplier_re,multiplier_im,derivative_re,derivative_im"
+    )?;
+    for step in steps {
+        writeln!(
+            writer,
+            "{},{:e},{:e},{:e},{:e},{:e},{:e}",
+            step.iter,
+            step.z.re,
+            step.z.im,
+            step.multiplier.re,
+            step.multiplier.im,
+            step.derivative.re,
+            step.derivative.im,
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use dynamo_common::prelude::*;
+use egui::{Color32, Context, RichText};
+use std::fmt::Display;
+
+/// Height of the list of attractors, beyond which it scrolls.
+const MAX_HEIGHT: f32 = 320.;
+
+/// Window listing the attracting cycles whose basins are colored in a pane, with a button to
+/// mark each cycle on it. Returns false once the window has been closed.
+pub(super) fn show<D: Display>(
+    ctx: &Context,
+    title: String,
+    attractors: &[Attractor<D>],
+    can_mark: bool,
+    color_of: impl Fn(&Attractor<D>) -> Color32,
+    is_marked: impl Fn(&Attractor<D>) -> bool,
+    mut toggle_mark: impl FnMut(&Attractor<D>),
+) -> bool
+{
+    let mut open = true;
+    egui::Window::new(title)
+        .open(&mut open)
+        .resizable(false)
+        .show(ctx, |ui| {
+            if attractors.is_empty() {
+                ui.label("No basins found. Color the basins of attractors to list them here.");
+                return;
+            }
+            egui::ScrollArea::vertical()
+                .max_height(MAX_HEIGHT)
+                .show(ui, |ui| {
+                    egui::Grid::new("attractors")
+                        .num_columns(6)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for heading in ["", "Period", "Point", "Multiplier", "Basin", ""] {
+                                ui.strong(heading);
+                            }
+                            ui.end_row();
+
+                            for attractor in attractors {
+                                ui.label(RichText::new("■").color(color_of(attractor)));
+                                ui.label(attractor.period.to_string());
+                                ui.label(format!("{:.6}", attractor.cycle_point));
+                                ui.label(format!("{:.4}", attractor.multiplier));
+                                ui.label(format!("{:.2}%", 100. * attractor.basin_fraction));
+                                let text = if is_marked(attractor) { "Unmark" } else { "Mark" };
+                                let button = ui
+                                    .add_enabled(can_mark, egui::Button::new(text))
+                                    .on_disabled_hover_text(
+                                        "Cycles can only be marked on dynamical planes.",
+                                    );
+                                if button.clicked() {
+                                    toggle_mark(attractor);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+    open
+}
@@ -0,0 +1,138 @@
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, OnceLock};
+use web_time::{Duration, Instant};
+
+/// Approximate number of pixels in each tile.
+const TILE_PIXELS: usize = 1 << 14;
+
+/// Time spent computing tiles on the UI thread in each frame, if there are no worker threads.
+const FRAME_BUDGET: Duration = Duration::from_millis(12);
+
+/// Pool for background work, used instead of the global rayon pool if set. On the web, the global
+/// pool has only the UI thread, and workers must be started from JavaScript.
+static WORKER_POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+
+/// Computation of a plane split into tiles, which arrive over several frames so that the UI stays
+/// responsive while they are computed.
+pub(super) struct BackgroundCompute<P>
+where
+    P: Displayable,
+{
+    plane: Arc<P>,
+    /// Tiles not yet handed out, if they are computed on the UI thread.
+    queued: VecDeque<TileJob>,
+    finished: Receiver<ComputedTile<P::Deriv>>,
+    remaining: usize,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl<P> BackgroundCompute<P>
+where
+    P: Displayable + Clone + 'static,
+{
+    pub fn start(plane: &P) -> Self
+    {
+        let plane = Arc::new(plane.clone());
+        let rows_per_tile = TILE_PIXELS / plane.point_grid().res_x.max(1);
+        let tiles = TileJob::split(plane.point_grid(), rows_per_tile);
+        let remaining = tiles.len();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let (sender, finished) = channel();
+
+        let queued = if has_worker_threads() {
+            for tile in tiles {
+                let plane = Arc::clone(&plane);
+                let sender = sender.clone();
+                let cancelled = Arc::clone(&cancelled);
+                spawn_background(move || {
+                    if !cancelled.load(Ordering::Relaxed) {
+                        // The receiver is gone if the computation was superseded
+                        let _ = sender.send(plane.compute_tile(tile));
+                    }
+                });
+            }
+            VecDeque::new()
+        } else {
+            tiles.into()
+        };
+
+        Self {
+            plane,
+            queued,
+            finished,
+            remaining,
+            cancelled,
+        }
+    }
+
+    /// Copy the tiles finished so far into `iter_plane`, computing some first if there are no
+    /// worker threads. Returns the jobs of the tiles copied.
+    pub fn poll(&mut self, iter_plane: &mut IterPlane<P::Deriv>) -> Vec<TileJob>
+    {
+        let mut jobs = Vec::new();
+
+        let start = Instant::now();
+        while start.elapsed() < FRAME_BUDGET {
+            let Some(tile) = self.queued.pop_front() else {
+                break;
+            };
+            self.plane.compute_tile(tile).paste_into(iter_plane);
+            jobs.push(tile);
+        }
+
+        for tile in self.finished.try_iter() {
+            tile.paste_into(iter_plane);
+            jobs.push(tile.job);
+        }
+
+        self.remaining -= jobs.len();
+        jobs
+    }
+
+    pub const fn is_done(&self) -> bool
+    {
+        self.remaining == 0
+    }
+}
+
+/// Run background work such as tiles on `pool` rather than the global rayon pool. Returns the pool
+/// if one was already set.
+pub fn set_worker_pool(pool: rayon::ThreadPool) -> Result<(), rayon::ThreadPool>
+{
+    WORKER_POOL.set(pool)
+}
+
+/// Whether tiles can be computed on other threads. Without a worker pool, the web build runs
+/// everything on the UI thread, and the tiles are computed a few per frame instead.
+pub(super) fn has_worker_threads() -> bool
+{
+    WORKER_POOL
+        .get()
+        .map_or_else(rayon::current_num_threads, rayon::ThreadPool::current_num_threads)
+        > 1
+}
+
+/// Run `job` on the worker pool, or on the global rayon pool if none was set.
+pub(super) fn spawn_background<F>(job: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    match WORKER_POOL.get() {
+        Some(pool) => pool.spawn(job),
+        None => rayon::spawn(job),
+    }
+}
+
+impl<P> Drop for BackgroundCompute<P>
+where
+    P: Displayable,
+{
+    fn drop(&mut self)
+    {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
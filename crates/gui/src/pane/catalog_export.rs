@@ -0,0 +1,143 @@
+use dynamo_common::prelude::*;
+use dynamo_core::dynamics::pcf::{catalog_angles, collect_catalog};
+use dynamo_core::error::DynamoResult;
+use dynamo_core::prelude::*;
+use rayon::prelude::*;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Arc;
+use web_time::{Duration, Instant};
+
+use crate::catalog_file;
+
+/// Time spent landing rays on the UI thread in each frame, if there are no worker threads.
+const FRAME_BUDGET: Duration = Duration::from_millis(12);
+
+type LandedRay = (RationalAngle, Option<PcfParameter>);
+
+/// Parameter rays landed concurrently over several frames, whose landing points are written
+/// to a file once all have arrived.
+pub(super) struct CatalogExport<P>
+where
+    P: Displayable,
+{
+    plane: Arc<P>,
+    path: PathBuf,
+    max_len: Period,
+    /// Angles not yet landed, if they are landed on the UI thread.
+    queued: VecDeque<RationalAngle>,
+    finished: Receiver<LandedRay>,
+    landed: Vec<(RationalAngle, PcfParameter)>,
+    total: usize,
+    done: usize,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl<P> CatalogExport<P>
+where
+    P: Displayable + Clone + 'static,
+{
+    pub fn start(plane: &P, max_len: Period, path: &Path) -> Self
+    {
+        let plane = Arc::new(plane.clone());
+        let angles = catalog_angles(plane.degree(), max_len);
+        let total = angles.len();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let (sender, finished) = channel();
+
+        let queued = if super::background::has_worker_threads() {
+            let plane = Arc::clone(&plane);
+            let cancelled = Arc::clone(&cancelled);
+            super::background::spawn_background(move || {
+                angles.into_par_iter().for_each_with(sender, |sender, angle| {
+                    if !cancelled.load(Ordering::Relaxed) {
+                        // The receiver is gone if the export was cancelled
+                        let _ = sender.send((angle, plane.land_parameter_ray(angle)));
+                    }
+                });
+            });
+            VecDeque::new()
+        } else {
+            angles.into()
+        };
+
+        Self {
+            plane,
+            path: path.to_path_buf(),
+            max_len,
+            queued,
+            finished,
+            landed: Vec::new(),
+            total,
+            done: 0,
+            cancelled,
+        }
+    }
+
+    /// Gather the rays landed since the last poll, landing some first if there are no worker
+    /// threads. Once all have arrived, the catalog is written to the file, and the number of
+    /// parameters written is returned.
+    pub fn poll(&mut self) -> Option<DynamoResult<usize>>
+    {
+        let mut arrived = Vec::new();
+
+        let start = Instant::now();
+        while start.elapsed() < FRAME_BUDGET {
+            let Some(angle) = self.queued.pop_front() else {
+                break;
+            };
+            arrived.push((angle, self.plane.land_parameter_ray(angle)));
+        }
+        arrived.extend(self.finished.try_iter());
+
+        self.done += arrived.len();
+        self.landed.extend(
+            arrived
+                .into_iter()
+                .filter_map(|(angle, parameter)| Some((angle, parameter?))),
+        );
+        if !self.is_done() {
+            return None;
+        }
+
+        let catalog = collect_catalog(std::mem::take(&mut self.landed), self.plane.degree());
+        Some(
+            catalog_file::save(&self.path, &catalog)
+                .map(|()| catalog.len())
+                .map_err(Into::into),
+        )
+    }
+
+    /// Number of rays landed so far, and in total.
+    pub const fn progress(&self) -> (usize, usize)
+    {
+        (self.done, self.total)
+    }
+
+    pub const fn is_done(&self) -> bool
+    {
+        self.done >= self.total
+    }
+
+    pub fn path(&self) -> &Path
+    {
+        &self.path
+    }
+
+    pub const fn max_len(&self) -> Period
+    {
+        self.max_len
+    }
+}
+
+impl<P> Drop for CatalogExport<P>
+where
+    P: Displayable,
+{
+    fn drop(&mut self)
+    {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
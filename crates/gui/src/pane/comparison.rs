@@ -0,0 +1,80 @@
+use super::{Pane, WindowPane};
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+
+/// A second child pane, showing the dynamics for another parameter of the same family side by
+/// side with the child. Zooming or panning either pane moves the other in the same way.
+pub(crate) struct ComparisonPane<J>
+where
+    J: Displayable,
+{
+    pub pane: WindowPane<J>,
+    /// Point in the parent plane whose dynamics are shown.
+    pub parent_point: Cplx,
+    /// Bounds of both panes when they were last synchronized.
+    synced_bounds: Bounds,
+}
+
+impl<J> ComparisonPane<J>
+where
+    J: Displayable + Clone + 'static,
+{
+    /// Start comparing against a copy of the child pane, for the parameter at `parent_point`.
+    pub fn new(child: &WindowPane<J>, parent_point: Cplx) -> Self
+    {
+        let mut pane = WindowPane::new(child.plane.clone(), child.coloring.clone());
+        pane.zoom_factor = child.zoom_factor;
+        if child.minimap.is_some() {
+            pane.toggle_minimap();
+        }
+        Self {
+            pane,
+            parent_point,
+            synced_bounds: child.grid().bounds.clone(),
+        }
+    }
+
+    /// Show the dynamics for the parameter at another point of the parent plane.
+    pub fn set_param(&mut self, parent_point: Cplx, param: <J::MetaParam as ParamList>::Param)
+    {
+        self.parent_point = parent_point;
+        self.pane.set_param(param);
+    }
+
+    /// Keep the comparison pane in step with the child: both panes show the same region, with the
+    /// same coloring and iteration limit.
+    pub fn sync_with(&mut self, child: &mut WindowPane<J>)
+    {
+        self.sync_bounds(child);
+        if self.pane.coloring != child.coloring {
+            self.pane.coloring = child.coloring.clone();
+            self.pane.marking_mut().sched_recolor_all();
+            self.pane.schedule_redraw();
+        }
+        self.pane.set_max_iter(child.plane.max_iter());
+    }
+
+    /// Give both panes the same bounds, following whichever of them has moved since they were
+    /// last synchronized. The child takes precedence if both have moved.
+    fn sync_bounds(&mut self, child: &mut WindowPane<J>)
+    {
+        if child.grid().bounds != self.synced_bounds {
+            follow(&mut self.pane, child);
+        } else if self.pane.grid().bounds != self.synced_bounds {
+            follow(child, &self.pane);
+        } else {
+            return;
+        }
+        self.synced_bounds = child.grid().bounds.clone();
+    }
+}
+
+/// Move `pane` to show the same region as `leader`.
+fn follow<J>(pane: &mut WindowPane<J>, leader: &WindowPane<J>)
+where
+    J: Displayable + Clone + 'static,
+{
+    pane.grid_mut().change_bounds(leader.grid().bounds.clone());
+    pane.zoom_factor = leader.zoom_factor;
+    pane.schedule_recompute();
+}
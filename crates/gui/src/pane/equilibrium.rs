@@ -0,0 +1,81 @@
+use egui::{Color32, ColorImage};
+use ndarray::Array2;
+
+/// Number of points of the backward orbit per pixel of the image.
+const POINTS_PER_PIXEL: usize = 8;
+/// Fraction of the brightness of the image kept underneath the measure.
+const BACKGROUND_BRIGHTNESS: f32 = 0.3;
+const MEASURE_COLOR: [f32; 3] = [255., 220., 120.];
+
+/// Density of the equilibrium measure on the Julia set in view, or of the natural measure on the
+/// attractor in view, drawn over the image. The density is recomputed lazily, since the image is
+/// drawn several times per computation when computed in the background.
+#[derive(Clone, Debug, Default)]
+pub(super) struct EquilibriumOverlay
+{
+    density: Option<Array2<u32>>,
+    /// Whether the density is sampled along a forward orbit on an attractor, rather than along
+    /// backward orbits.
+    attractor: bool,
+}
+
+impl EquilibriumOverlay
+{
+    pub(super) const fn num_points(width: usize, height: usize) -> usize
+    {
+        POINTS_PER_PIXEL * width * height
+    }
+
+    pub(super) fn new(density: Array2<u32>, attractor: bool) -> Self
+    {
+        Self {
+            density: Some(density),
+            attractor,
+        }
+    }
+
+    pub(super) const fn is_attractor(&self) -> bool
+    {
+        self.attractor
+    }
+
+    pub(super) fn invalidate(&mut self)
+    {
+        self.density = None;
+    }
+
+    pub(super) const fn is_stale(&self) -> bool
+    {
+        self.density.is_none()
+    }
+
+    /// Darken the image and brighten each pixel on a log scale of its share of the measure.
+    pub(super) fn composite(&self, image: &mut ColorImage)
+    {
+        let Some(density) = self.density.as_ref() else {
+            return;
+        };
+        let width = image.width();
+        let max_count = density.iter().copied().max().unwrap_or_default();
+        if max_count == 0 {
+            return;
+        }
+        let log_max = (f64::from(max_count)).ln_1p() as f32;
+
+        density.indexed_iter().for_each(|((x, y), &count)| {
+            let Some(pixel) = image.pixels.get_mut(x + y * width) else {
+                return;
+            };
+            let weight = (f64::from(count)).ln_1p() as f32 / log_max;
+            let blend = |channel: u8, target: f32| -> u8 {
+                let base = f32::from(channel) * BACKGROUND_BRIGHTNESS;
+                weight.mul_add(target - base, base) as u8
+            };
+            *pixel = Color32::from_rgb(
+                blend(pixel.r(), MEASURE_COLOR[0]),
+                blend(pixel.g(), MEASURE_COLOR[1]),
+                blend(pixel.b(), MEASURE_COLOR[2]),
+            );
+        });
+    }
+}
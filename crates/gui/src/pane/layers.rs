@@ -0,0 +1,137 @@
+use dynamo_color::prelude::*;
+use egui::{ComboBox, Context};
+
+/// Period of the sinusoids in the black and white palettes offered for layers.
+const MONOCHROME_PERIOD: f64 = 16.;
+
+/// Window listing the layers composited over the base coloring of a pane, in which they can be
+/// added, edited, reordered and removed, and the light shading them adjusted. Returns whether
+/// the window is still open and whether any layer or the light was changed.
+pub(super) fn show(
+    ctx: &Context,
+    title: String,
+    layers: &mut LayerStack,
+    base: &Coloring,
+) -> (bool, bool)
+{
+    let mut open = true;
+    let mut changed = false;
+    egui::Window::new(title)
+        .open(&mut open)
+        .resizable(false)
+        .show(ctx, |ui| {
+            if layers.is_empty() {
+                ui.label("Add a layer to draw another coloring of the image over it.");
+            }
+            let mut removed = None;
+            let mut raised = None;
+            let num_layers = layers.len();
+            egui::Grid::new("layers")
+                .num_columns(5)
+                .striped(true)
+                .show(ui, |ui| {
+                    // Listed from the top down, as the layers are seen
+                    let mut layer_list: Vec<_> = layers.iter_mut().enumerate().collect();
+                    layer_list.reverse();
+                    for (index, layer) in layer_list {
+                        changed |= ui.checkbox(&mut layer.visible, "").changed();
+                        ComboBox::from_id_salt(("blend_mode", index))
+                            .selected_text(layer.blend_mode.name())
+                            .show_ui(ui, |ui| {
+                                for mode in BlendMode::ALL {
+                                    changed |= ui
+                                        .selectable_value(&mut layer.blend_mode, mode, mode.name())
+                                        .changed();
+                                }
+                            });
+                        changed |= ui
+                            .add(egui::Slider::new(&mut layer.opacity, 0.0..=1.0).text("Opacity"))
+                            .changed();
+                        ui.menu_button("Coloring", |ui| {
+                            let mut palette = None;
+                            if ui.button("Same as base").clicked() {
+                                palette = Some(*base.get_palette());
+                            }
+                            if ui.button("Black on white").clicked() {
+                                palette = Some(Palette::white(MONOCHROME_PERIOD));
+                            }
+                            if ui.button("White on black").clicked() {
+                                palette = Some(Palette::black(MONOCHROME_PERIOD));
+                            }
+                            if ui.button("Random").clicked() {
+                                palette = Some(Palette::new_random(0.45, 0.38));
+                            }
+                            if let Some(palette) = palette {
+                                layer.coloring.set_palette(palette);
+                                changed = true;
+                                ui.close_menu();
+                            }
+                            if ui.button("Use the algorithm of the base").clicked() {
+                                layer
+                                    .coloring
+                                    .set_interior_algorithm(base.get_algorithm().clone());
+                                changed = true;
+                                ui.close_menu();
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            if ui
+                                .add_enabled(index + 1 < num_layers, egui::Button::new("⏶"))
+                                .clicked()
+                            {
+                                raised = Some(index);
+                            }
+                            if ui.add_enabled(index > 0, egui::Button::new("⏷")).clicked() {
+                                raised = index.checked_sub(1);
+                            }
+                            if ui.button("✖").clicked() {
+                                removed = Some(index);
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+            if let Some(index) = raised {
+                layers.raise(index);
+                changed = true;
+            }
+            if let Some(index) = removed {
+                layers.remove(index);
+                changed = true;
+            }
+            if ui
+                .button("Add layer")
+                .on_hover_text("Multiply a black on white copy of the base coloring over the image")
+                .clicked()
+            {
+                let mut coloring = base.clone();
+                coloring.set_palette(Palette::white(MONOCHROME_PERIOD));
+                layers.push(Layer::new(coloring, BlendMode::Multiply, 1.));
+                changed = true;
+            }
+            ui.separator();
+            let lighting = &mut layers.lighting;
+            changed |= ui
+                .checkbox(&mut lighting.enabled, "Lighting")
+                .on_hover_text("Shade the image as a relief lit from one side")
+                .changed();
+            ui.add_enabled_ui(lighting.enabled, |ui| {
+                let sliders = [
+                    (&mut lighting.azimuth, 0.0..=360.0, "Azimuth (°)"),
+                    (&mut lighting.elevation, 5.0..=90.0, "Elevation (°)"),
+                    (&mut lighting.intensity, 0.0..=1.0, "Intensity"),
+                ];
+                for (value, range, text) in sliders {
+                    changed |= ui.add(egui::Slider::new(value, range).text(text)).changed();
+                }
+                changed |= ui
+                    .add(
+                        egui::Slider::new(&mut lighting.relief, 1.0..=1000.0)
+                            .logarithmic(true)
+                            .text("Relief"),
+                    )
+                    .changed();
+            });
+        });
+    (open, changed)
+}
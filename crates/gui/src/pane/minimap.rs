@@ -0,0 +1,128 @@
+use dynamo_color::prelude::*;
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use egui::{Color32, Pos2, Rect, Stroke, TextureOptions, Ui, Vec2};
+use epaint::{ColorImage, TextureHandle};
+
+/// Height of the minimap in pixels.
+const MINIMAP_HEIGHT: usize = 128;
+
+/// Space in points between the minimap and the corner of its pane.
+const MARGIN: f32 = 6.;
+
+/// Low-resolution overview of the default view of a plane, shown in the bottom right corner of
+/// its pane with a rectangle around the region currently in view.
+pub(super) struct Minimap<P>
+where
+    P: Displayable,
+{
+    iter_plane: IterPlane<P::Deriv>,
+    image: ColorImage,
+    texture: Option<TextureHandle>,
+    /// Where the minimap was last shown, in screen coordinates.
+    region: Rect,
+    /// Whether the plane has changed since the overview was computed, other than by zooming or
+    /// panning.
+    stale: bool,
+}
+
+impl<P> Minimap<P>
+where
+    P: Displayable + Clone,
+{
+    pub fn new(plane: &P) -> Self
+    {
+        Self {
+            iter_plane: IterPlane::create(overview_grid(plane)),
+            image: ColorImage::default(),
+            texture: None,
+            region: Rect::NOTHING,
+            stale: true,
+        }
+    }
+
+    pub fn invalidate(&mut self)
+    {
+        self.stale = true;
+    }
+
+    /// Recompute the overview if the plane has changed.
+    pub fn update(&mut self, plane: &P, coloring: &Coloring)
+    {
+        if !self.stale {
+            return;
+        }
+        let mut plane = plane.clone();
+        *plane.point_grid_mut() = overview_grid(&plane);
+        self.iter_plane = plane.compute();
+        self.stale = false;
+        self.render(coloring);
+    }
+
+    pub fn render(&mut self, coloring: &Coloring)
+    {
+        self.image = self.iter_plane.render(coloring);
+        if let Some(texture) = self.texture.as_mut() {
+            texture.set(self.image.clone(), TextureOptions::default());
+        }
+    }
+
+    /// Show the minimap in the corner of `frame_region`, marking the region within `view_bounds`.
+    pub fn put(&mut self, ui: &Ui, frame_region: Rect, view_bounds: &Bounds)
+    {
+        let texture = self.texture.get_or_insert_with(|| {
+            ui.ctx()
+                .load_texture("minimap", self.image.clone(), TextureOptions::default())
+        });
+        let [width, height] = self.image.size;
+        let size = Vec2::new(width as f32, height as f32);
+        self.region = Rect::from_min_size(frame_region.max - size - Vec2::splat(MARGIN), size);
+
+        let painter = ui.painter().with_clip_rect(self.region);
+        let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1., 1.));
+        painter.image(texture.id(), self.region, uv, Color32::WHITE);
+        painter.rect_stroke(
+            self.locate_bounds(view_bounds),
+            0.,
+            Stroke::new(1.5, Color32::WHITE),
+        );
+        ui.painter()
+            .rect_stroke(self.region, 0., Stroke::new(1., Color32::GRAY));
+    }
+
+    /// The point of the plane under `pointer_pos`, if it lies on the minimap.
+    pub fn point_at(&self, pointer_pos: Pos2) -> Option<Cplx>
+    {
+        if !self.region.contains(pointer_pos) {
+            return None;
+        }
+        let local_pos = pointer_pos - self.region.min;
+        Some(self.iter_plane.point_grid.map_pos(local_pos.into()))
+    }
+
+    /// The rectangle on the screen covering `bounds`.
+    fn locate_bounds(&self, bounds: &Bounds) -> Rect
+    {
+        let grid = &self.iter_plane.point_grid;
+        let to_screen = |re: Real, im: Real| {
+            let x = (re - grid.bounds.min_x) / grid.pixel_width();
+            let y = (grid.bounds.max_y - im) / grid.pixel_height();
+            self.region.min + Vec2::new(x as f32, y as f32)
+        };
+        Rect::from_two_pos(
+            to_screen(bounds.min_x, bounds.max_y),
+            to_screen(bounds.max_x, bounds.min_y),
+        )
+    }
+}
+
+/// Low-resolution grid covering the default bounds of the plane.
+fn overview_grid<P>(plane: &P) -> PointGrid
+where
+    P: Displayable,
+{
+    let mut grid = plane.point_grid().clone();
+    grid.change_bounds(plane.default_bounds());
+    grid.resize_y(MINIMAP_HEIGHT);
+    grid
+}
@@ -1,22 +1,79 @@
-use egui::{Color32, Pos2, Ui};
+use egui::{Align2, Color32, ColorImage, Context, FontId, Id, Pos2, Stroke, Ui};
+use epaint::PathShape;
+use ndarray::Array2;
 use std::path::Path;
 
 use crate::actions::ChangeBoolean;
-use crate::marked_points::ContourType;
+use crate::contour_file;
+use crate::dialog::SaveFileType;
+use crate::marked_points::{ColoredPoint, ContourType};
+use crate::notifications::FinishedSave;
+use crate::orbit_file;
+use crate::ray_file;
+use crate::render_job::{export_image, RenderJob};
+use crate::tiled_export;
+use crate::view_state::PaneView;
 
 use super::image_frame::ImageFrame;
 use super::marked_points::Marking;
 use dynamo_color::prelude::*;
+use dynamo_common::analysis::{self, Estimate};
 use dynamo_common::prelude::*;
-use dynamo_core::error::FindPointResult;
+use dynamo_core::error::{DynamoError, DynamoResult, FindPointError, FindPointResult};
 use dynamo_core::prelude::*;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+mod attractors;
+mod background;
+mod catalog_export;
+mod equilibrium;
+pub(crate) mod comparison;
 pub mod id;
+mod layers;
+mod minimap;
+mod pcf;
+pub mod preimages;
+pub(crate) mod preview;
+mod ray_batch;
+mod statistics;
 pub mod tasks;
-use tasks::{ChildTask, FollowState, PaneTasks, RepeatableTask};
+mod tooltip;
+mod trace;
+
+pub use background::set_worker_pool;
+use background::BackgroundCompute;
+use catalog_export::CatalogExport;
+use equilibrium::EquilibriumOverlay;
+use minimap::Minimap;
+use pcf::PcfDatabase;
+use preimages::PreimageExplorer;
+use ray_batch::RayBatch;
+use tasks::{ChildTask, FollowState, PaneTasks, RepeatableTask, Ruler};
+use tooltip::OrbitTooltip;
+
+/// Components with fewer pixels than this are too small to label legibly.
+const MIN_LABELED_PIXELS: usize = 100;
+const PERIOD_LABEL_SIZE: f32 = 14.;
+/// Spacing of the levels of the potential along which band contours are traced, so that they
+/// bound the bands of points escaping after equally many iterations.
+const BAND_CONTOUR_SPACING: IterCountSmooth = 1.;
+const BAND_CONTOUR_COLOR: Color32 = Color32::from_black_alpha(160);
+/// Width of the coarsest image used to estimate areas, and number of times its resolution is
+/// doubled.
+const AREA_BASE_WIDTH: usize = 256;
+const AREA_LEVELS: u32 = 3;
+/// A label within this many pixels of the selection is the one edited.
+const LABEL_PICK_PIXELS: Real = 8.;
+/// The pointer shows the multiplier of a marked periodic point within this many pixels of it.
+const PERIODIC_POINT_PICK_PIXELS: Real = 5.;
+/// Iterates of the critical point whose curves are drawn together.
+const CRITICAL_CURVE_STEPS: Period = 4;
+/// Seeds per axis of the grid from which postcritically finite parameters are sought.
+const PCF_SEEDS_PER_AXIS: usize = 32;
+const RULER_COLOR: Color32 = Color32::YELLOW;
+const RULER_THICKNESS: f32 = 1.5;
 
 pub trait Pane
 {
@@ -24,6 +81,8 @@ pub trait Pane
     fn tasks_mut(&mut self) -> &mut PaneTasks;
     fn frame(&self) -> &ImageFrame;
     fn frame_mut(&mut self) -> &mut ImageFrame;
+    fn ruler(&self) -> &Ruler;
+    fn ruler_mut(&mut self) -> &mut Ruler;
 
     fn get_coloring(&self) -> &Coloring;
     fn get_coloring_mut(&mut self) -> &mut Coloring;
@@ -33,6 +92,27 @@ pub trait Pane
     fn reset_selection(&mut self);
     fn reset(&mut self);
     fn select_nearby_point(&mut self, orbit_schema: OrbitSchema) -> FindPointResult<Cplx>;
+    /// Select a nearby parameter at which a cycle of the given period has the given multiplier.
+    fn select_nearby_multiplier(
+        &mut self,
+        period: Period,
+        multiplier: Cplx,
+    ) -> FindPointResult<Cplx>;
+    /// Landing point of the external ray at `angle`, as far as it can be traced.
+    fn ray_landing_point(&self, angle: RationalAngle) -> Option<Cplx>;
+    /// Select the nearby Misiurewicz point with the given orbit schema, and draw the spiral of
+    /// its self-similarity through the current selection.
+    fn analyze_misiurewicz(
+        &mut self,
+        orbit_schema: OrbitSchema,
+    ) -> Result<MisiurewiczSpiral, FindPointError<Cplx>>;
+    /// Draw the boundary of the hyperbolic component of the given period containing the
+    /// selection, traced from the given bifurcation.
+    fn trace_multiplier_locus(
+        &mut self,
+        period: Period,
+        bifurcation: Bifurcation,
+    ) -> Result<(), FindPointError<Cplx>>;
     fn map_selection(&mut self);
     fn stop_following(&mut self);
     fn set_follow_state(&mut self, follow_state: FollowState);
@@ -40,6 +120,12 @@ pub trait Pane
 
     fn draw_contour(&mut self, contour_type: ContourType);
     fn draw_aux_contours(&mut self);
+    /// Draw the curves through the selection, in a parameter plane, on which the first few
+    /// iterates of the critical point keep their distances from critical and co-critical points.
+    fn draw_critical_curves(&mut self);
+    /// Outline the region of the computed image containing the selection that is attracted to
+    /// cycles of the same period.
+    fn mark_component_boundary(&mut self);
 
     fn get_image_frame(&self) -> &ImageFrame;
     fn get_image_frame_mut(&mut self) -> &mut ImageFrame;
@@ -52,6 +138,65 @@ pub trait Pane
     fn put_marked_points(&self, ui: &mut Ui);
     fn put_marked_curves(&self, ui: &mut Ui);
 
+    /// Show or hide the overview of the default view in the corner of the pane.
+    fn toggle_minimap(&mut self);
+    /// Preview the image as seen with the next form of color blindness in turn. Saved images
+    /// are not affected.
+    fn cycle_color_vision_filter(&mut self);
+    fn get_color_vision_filter(&self) -> ColorVisionFilter;
+    /// Show or hide the period of each sizeable hyperbolic component, written at its center.
+    fn toggle_period_labels(&mut self);
+    /// Show or hide curves tracing the boundaries between the bands of escaping points.
+    fn toggle_band_contours(&mut self);
+    /// Write the boundaries between the bands of escaping points to an SVG file. Returns the
+    /// number of contours written.
+    fn save_band_contours(&self, filename: &Path) -> DynamoResult<usize>;
+    /// Show or hide the tree of preimages of the selection. Returns false if the plane is not a
+    /// dynamical plane of a map with known inverse branches.
+    fn toggle_preimage_tree(&mut self) -> bool;
+    /// Depth and pruning of the tree of preimages, while it is shown.
+    fn preimage_explorer_mut(&mut self) -> Option<&mut PreimageExplorer>;
+    /// Show or hide a window summarizing the periods, escape times and area fractions found in
+    /// the image.
+    fn toggle_statistics(&mut self);
+    /// Show or hide the density of the equilibrium measure over the image. Returns false if it
+    /// cannot be shown, as the plane is not dynamical or its map cannot be inverted.
+    fn toggle_equilibrium_measure(&mut self) -> bool;
+    /// Show or hide the density of a long forward orbit over the image, approximating the
+    /// natural measure on the attractor in view. Returns false if the family has no attractor to
+    /// sample, or the plane is not dynamical.
+    fn toggle_attractor_density(&mut self) -> bool;
+    fn show_statistics(&mut self, ctx: &Context);
+    /// Show or hide a window listing the attracting cycles whose basins are colored in the image.
+    fn toggle_attractor_inventory(&mut self);
+    fn show_attractor_inventory(&mut self, ctx: &Context);
+    /// Show or hide a window of the colorings layered over the image, such as distance
+    /// estimates multiplied over the periods of attractors.
+    fn toggle_layers(&mut self);
+    fn show_layers(&mut self, ctx: &Context);
+    /// Turn on or off the light shading the image as an embossed relief.
+    fn toggle_lighting(&mut self);
+    /// Show or hide a window listing the postcritically finite parameters found in view, which
+    /// can be filtered, overlaid on the image and selected.
+    fn toggle_pcf_database(&mut self);
+    /// Show the window of postcritically finite parameters, if open. Returns true if a parameter
+    /// was selected from it.
+    fn show_pcf_database(&mut self, ctx: &Context) -> bool;
+    /// Enable or disable a tooltip summarizing the orbit of the point under the pointer.
+    fn toggle_orbit_tooltip(&mut self);
+    /// Show the orbit tooltip for the point under the pointer, if enabled.
+    fn show_orbit_tooltip(&mut self, ctx: &Context, pointer_value: Cplx, pointer_pos: Pos2);
+    /// Show or hide a window plotting the coordinates of the selected orbit against time.
+    fn toggle_orbit_trace(&mut self);
+    fn show_orbit_trace(&mut self, ctx: &Context);
+    /// Box-counting dimension of the boundary in the computed image.
+    fn box_counting_dimension(&self) -> Option<Estimate>;
+    /// Area of the points in view that do not escape, extrapolated from several resolutions.
+    fn estimate_area(&self) -> Option<Estimate>;
+    fn put_minimap(&mut self, ui: &mut Ui);
+    /// The point under the pointer, if it lies on the minimap.
+    fn minimap_point(&self, pointer_pos: Pos2) -> Option<Cplx>;
+
     fn plane_type(&self) -> PlaneType;
     fn name(&self) -> String;
     fn long_name(&self) -> String;
@@ -116,12 +261,46 @@ pub trait Pane
         self.schedule_redraw();
     }
 
+    /// Color the image by the next user-defined outcoloring registered, or by the built-in
+    /// coloring after the last one.
+    fn cycle_outcoloring(&mut self)
+    {
+        let registered = dynamo_color::outcoloring::registered();
+        let next = match self.get_coloring().get_outcoloring() {
+            None => registered.first().cloned(),
+            Some(current) => registered
+                .iter()
+                .skip_while(|algorithm| *algorithm != current)
+                .nth(1)
+                .cloned(),
+        };
+        self.get_coloring_mut().set_outcoloring(next);
+        self.schedule_redraw();
+    }
+
     fn shift_palette(&mut self, shift: f64)
     {
         self.get_coloring_mut().adjust_phase(shift);
         self.schedule_redraw();
     }
 
+    fn is_color_cycling(&self) -> bool
+    {
+        self.tasks().color_cycle.is_running()
+    }
+
+    /// Whether the pane changes from frame to frame without input.
+    fn is_animating(&self) -> bool
+    {
+        self.is_color_cycling() || self.marking().orbit_playback().playing
+    }
+
+    /// Start or stop advancing the palette phase every frame.
+    fn set_color_cycling(&mut self, running: bool)
+    {
+        self.tasks_mut().color_cycle.set_running(running);
+    }
+
     fn zoom(&mut self, scale: Real, base_point: Cplx);
 
     #[inline]
@@ -158,6 +337,24 @@ pub trait Pane
     }
 
     fn process_tasks(&mut self);
+    /// Whether points or rays are still being computed in the background.
+    fn is_computing(&self) -> bool;
+    /// Compute points in tiles spread over several frames rather than all at once. This is the
+    /// default on the web, where blocking the UI thread freezes the page.
+    fn set_background_compute(&mut self, enabled: bool);
+    /// Trace the external rays at many angles concurrently, showing each as it finishes. Replaces
+    /// any batch still being traced.
+    fn trace_rays(&mut self, angles: Vec<RationalAngle>);
+    /// Number of rays traced so far and in total, while a batch of rays is being traced.
+    fn ray_progress(&self) -> Option<(usize, usize)>;
+    /// Land the parameter rays whose angles have preperiod and period adding up to at most
+    /// `max_len` in the background, and write the centers and Misiurewicz parameters reached to
+    /// a CSV file with their angles. Replaces any export still running.
+    fn export_pcf_catalog(&mut self, max_len: Period, filename: &Path);
+    /// Number of rays landed so far and in total, while a catalog is being exported.
+    fn catalog_progress(&self) -> Option<(usize, usize)>;
+    /// Take the outcome of a catalog export that has finished since the last call.
+    fn take_finished_save(&mut self) -> Option<FinishedSave>;
 
     fn frame_contains_pixel(&self, pointer_pos: Pos2) -> bool
     {
@@ -170,17 +367,40 @@ pub trait Pane
         self.grid().map_pos(relative_pos.into())
     }
 
-    fn process_mouse_input(&mut self, pointer_value: Cplx, zoom_factor: f32, reselect_point: bool)
+    /// Zoom about the pointer, and either select the point under it or, while the ruler is
+    /// active, move the end of the ruler there.
+    fn process_mouse_input(
+        &mut self,
+        pointer_value: Cplx,
+        zoom_factor: f32,
+        reselect_point: bool,
+        clicked: bool,
+    )
     {
         if (zoom_factor - 1.0).abs() > f32::EPSILON {
             self.zoom((1. / zoom_factor).into(), pointer_value);
         }
 
-        if reselect_point {
-            self.select_point(pointer_value);
+        let point = self.grid().transform.apply(pointer_value);
+        if self.ruler().is_active() {
+            self.ruler_mut().process_pointer(point, clicked);
+        } else if reselect_point {
+            self.select_point(point);
         }
     }
 
+    /// Start or stop measuring segments by clicking their ends.
+    fn toggle_ruler(&mut self)
+    {
+        self.ruler_mut().toggle();
+    }
+
+    fn set_coordinate_change(&mut self, transform: ViewTransform)
+    {
+        self.grid_mut().transform = transform;
+        self.schedule_recompute();
+    }
+
     fn select_preperiod_smooth_coloring(&mut self);
     fn select_preperiod_period_smooth_coloring(&mut self);
     fn select_preperiod_coloring(&mut self);
@@ -188,14 +408,147 @@ pub trait Pane
     fn marking(&self) -> &Marking;
     fn marking_mut(&mut self) -> &mut Marking;
 
+    fn view(&self) -> PaneView
+    {
+        PaneView {
+            bounds: self.grid().bounds.clone(),
+            selection: self.get_selection(),
+            palette: *self.get_coloring().get_palette(),
+            rays: self.marking().visible_rays(),
+        }
+    }
+
+    fn restore_view(&mut self, view: &PaneView)
+    {
+        self.grid_mut().change_bounds(view.bounds.clone());
+        self.select_point(view.selection);
+        self.change_palette(view.palette);
+        self.marking_mut().disable_all_rays();
+        for &angle in &view.rays {
+            self.marking_mut().enable_ray(angle);
+        }
+        self.schedule_recompute();
+    }
+
     fn cycle_active_plane(&mut self);
     fn change_compute_mode(&mut self, change: ChangeBoolean);
+    /// Switch between escape-time rendering and a quick outline of the Julia set by inverse
+    /// iteration. Returns false if the map of the plane cannot be inverted.
+    fn toggle_inverse_iteration(&mut self) -> bool;
+    /// Switch between escape-time rendering and coloring by Lyapunov exponents. Returns false if
+    /// the family does not compute Lyapunov exponents.
+    fn toggle_lyapunov(&mut self) -> bool;
+    /// Switch between coloring bounded pixels by period and by the basin of their cycle.
+    fn toggle_basins(&mut self);
+    /// Switch between escape-time rendering and coloring by the probability that orbits escape
+    /// when random noise is added at every iteration.
+    fn toggle_stochastic(&mut self);
+    /// Toggle coloring escaping points by their phase, taking the escape period and phase from
+    /// the plane whenever it is switched on.
+    fn toggle_escape_phase_coloring(&mut self);
+    /// Scale the amplitude of the noise added to orbits, if stochastic iteration is in use.
+    fn scale_noise(&mut self, factor: f64);
+    /// Switch between escape-time rendering and iterating the image by the flow of the map.
+    /// Returns false if the plane is not a dynamical plane.
+    fn toggle_flow(&mut self) -> bool;
+    /// Time by which the image is iterated, if it is iterated continuously.
+    fn flow_time(&self) -> Option<Real>;
+    fn set_flow_time(&mut self, time: Real);
+    /// Switch between following orbits in the usual coordinates and in logarithmic coordinates.
+    /// Returns false if the family does not lift its map to logarithmic coordinates.
+    fn toggle_log_coordinates(&mut self) -> bool;
+    /// Switch between escape-time rendering and estimating distances with the iteration limit
+    /// raised for pixels near the boundary.
+    fn toggle_adaptive_iterations(&mut self);
 
     fn scale_max_iter(&mut self, factor: f64);
+    fn set_max_iter(&mut self, max_iter: IterCount);
+
+    /// Compute and save the image at the given width, leaving the points selected by
+    /// `transparency` transparent.
+    fn save_image(
+        &mut self,
+        img_width: usize,
+        filename: &Path,
+        transparency: Transparency,
+    ) -> DynamoResult<()>;
+    fn render_job(&self, img_width: usize, filename: &Path, transparency: Transparency)
+        -> RenderJob;
+    fn save_palette(&mut self, filename: &Path) -> DynamoResult<()>;
+    fn load_palette(&mut self, filename: &Path) -> DynamoResult<()>;
+    fn save_data(&mut self, filename: &Path) -> DynamoResult<()>;
+    fn load_data(&mut self, filename: &Path) -> DynamoResult<()>;
+    /// Write the orbit of the selection, with the derivatives along it and its classification.
+    fn save_orbit(&mut self, filename: &Path) -> DynamoResult<()>;
+
+    /// Mark the points listed in a CSV or JSON file, in addition to any loaded before. Returns
+    /// the number of points loaded.
+    fn load_points(&mut self, filename: &Path) -> DynamoResult<usize>
+    {
+        Ok(self.marking_mut().load_points(filename)?)
+    }
+
+    /// Select the landing point of the ray at `angle` and draw the ray. If the angle is periodic,
+    /// the selection moves on to the center of the hyperbolic component at whose root the ray
+    /// lands. Returns the point selected.
+    fn select_ray_landing_point(&mut self, angle: RationalAngle) -> Option<Cplx>
+    {
+        let landing_point = self.ray_landing_point(angle)?;
+        self.select_point(landing_point);
+        let orbit_schema = angle.with_degree(self.degree()).orbit_schema();
+        if orbit_schema.preperiod == 0 {
+            // Newton's method started at the root usually converges to the center
+            let _ = self.select_nearby_point(orbit_schema);
+        }
+        self.marking_mut().enable_ray(angle);
+        self.schedule_redraw();
+        Some(self.get_selection())
+    }
+
+    /// Write the rays currently shown to a file. Returns the number of rays written.
+    fn save_rays(&mut self, filename: &Path) -> DynamoResult<usize>
+    {
+        let rays = self.marking().traced_rays();
+        ray_file::save(filename, &rays)?;
+        Ok(rays.len())
+    }
+
+    /// Show the rays saved in a file, alongside those already shown. Returns the number of rays
+    /// loaded.
+    fn load_rays(&mut self, filename: &Path) -> DynamoResult<usize>
+    {
+        let rays = ray_file::load(filename)?;
+        let count = rays.len();
+        self.marking_mut().import_rays(rays);
+        Ok(count)
+    }
+
+    fn clear_loaded_points(&mut self)
+    {
+        self.marking_mut().clear_loaded_points();
+    }
 
-    fn save_image(&mut self, img_width: usize, filename: &Path);
-    fn save_palette(&mut self, filename: &Path);
-    fn load_palette(&mut self, filename: &Path);
+    /// Text of the label at the selection, if there is one.
+    fn label_at_selection(&self) -> Option<String>
+    {
+        let radius = LABEL_PICK_PIXELS * self.grid().pixel_width();
+        self.marking()
+            .label_near(self.get_selection(), radius)
+            .map(|label| label.text.clone())
+    }
+
+    /// Write `text` at the selection, replacing the label already there. Empty text removes it.
+    fn edit_label_at_selection(&mut self, text: &str)
+    {
+        let radius = LABEL_PICK_PIXELS * self.grid().pixel_width();
+        let selection = self.get_selection();
+        self.marking_mut().edit_label(selection, radius, text);
+    }
+
+    fn clear_labels(&mut self)
+    {
+        self.marking_mut().clear_labels();
+    }
 
     fn change_height(&mut self, new_height: usize);
 
@@ -227,10 +580,58 @@ where
     pub marking: Marking,
     pub zoom_factor: Real,
     pub child_task: ChildTask,
+    background_compute: bool,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    background: Option<BackgroundCompute<P>>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    ray_batch: Option<RayBatch<P>>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    catalog_export: Option<CatalogExport<P>>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    finished_save: Option<FinishedSave>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    minimap: Option<Minimap<P>>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    period_labels: Option<Vec<(Cplx, Period)>>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    band_contours: Option<Vec<Contour>>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    preimage_explorer: Option<PreimageExplorer>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    statistics: Option<PlaneStatistics>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    attractor_inventory: Option<Vec<Attractor<P::Deriv>>>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    pcf_database: Option<PcfDatabase>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    equilibrium: Option<EquilibriumOverlay>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    orbit_tooltip: Option<OrbitTooltip>,
+    /// Orbit of the selection plotted against time, if the trace window is open.
+    #[cfg_attr(feature = "serde", serde(skip, default = "Option::default"))]
+    orbit_trace: Option<ComplexVec>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ruler: Ruler,
+    /// Simulated form of color blindness with which the image is previewed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    color_vision: ColorVisionFilter,
+    /// Colorings composited over `coloring`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    layers: LayerStack,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    show_layers: bool,
+    /// Part of the panning not yet applied to the grid, which only moves by whole pixels while
+    /// computed points are kept.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pan_residual: Cplx,
+    /// Whether the grid was just zoomed in from computed points, which can be upsampled while
+    /// the new ones are computed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    zoom_preview: bool,
 }
 impl<P> WindowPane<P>
 where
-    P: Displayable + 'static,
+    P: Displayable + Clone + 'static,
 {
     /// Change the meta-parameter for the plane. Returns true if the new value is distinct from the
     /// old one.
@@ -253,6 +654,10 @@ where
             false
         } else {
             self.plane.set_param(new_param);
+            self.zoom_preview = false;
+            self.invalidate_minimap();
+            // Rays still being traced belong to the old parameter
+            self.ray_batch = None;
             if matches!(self.tasks().follow, FollowState::Idle) {
                 self.select_point(self.plane.default_selection());
             }
@@ -301,6 +706,201 @@ where
             marking,
             zoom_factor: 1.,
             child_task: ChildTask::Idle,
+            background_compute: cfg!(target_arch = "wasm32"),
+            background: None,
+            ray_batch: None,
+            catalog_export: None,
+            finished_save: None,
+            minimap: None,
+            period_labels: None,
+            band_contours: None,
+            preimage_explorer: None,
+            statistics: None,
+            attractor_inventory: None,
+            pcf_database: None,
+            equilibrium: None,
+            orbit_tooltip: None,
+            orbit_trace: None,
+            ruler: Ruler::default(),
+            color_vision: ColorVisionFilter::default(),
+            layers: LayerStack::default(),
+            show_layers: false,
+            pan_residual: ZERO,
+            zoom_preview: false,
+        }
+    }
+
+    fn update_statistics(&mut self)
+    {
+        if let Some(statistics) = self.statistics.as_mut() {
+            *statistics = self.iter_plane.statistics();
+        }
+        if let Some(inventory) = self.attractor_inventory.as_mut() {
+            *inventory = self.iter_plane.attractors();
+        }
+    }
+
+    /// Search the view for the postcritically finite parameters within the bounds set in the
+    /// database window, and refresh the overlay.
+    fn search_pcf_parameters(&mut self)
+    {
+        let Some(database) = self.pcf_database.as_mut() else {
+            return;
+        };
+        database.entries = self.plane.pcf_parameters(
+            database.max_preperiod,
+            database.max_period,
+            PCF_SEEDS_PER_AXIS,
+        );
+        self.overlay_pcf_parameters();
+    }
+
+    fn overlay_pcf_parameters(&mut self)
+    {
+        let points = match self.pcf_database.as_ref() {
+            Some(database) if database.overlay => {
+                let palette = self.coloring.get_period_coloring();
+                database
+                    .entries
+                    .iter()
+                    .map(|entry| ColoredPoint {
+                        point: entry.point,
+                        color: palette.map_preperiodic(entry.orbit_schema),
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        self.marking.set_pcf_parameters(points);
+        self.schedule_redraw();
+    }
+
+    /// The points of the attracting cycle through the representative point of `attractor`.
+    fn attractor_cycle(&self, attractor: &Attractor<P::Deriv>) -> ComplexVec
+    {
+        let param = self.plane.param_map(self.plane.point_grid().center());
+        let mut z = P::Var::from(attractor.cycle_point);
+        (0..attractor.period)
+            .map(|_| {
+                let point = z.into();
+                z = self.plane.map(z, &param);
+                point
+            })
+            .collect()
+    }
+
+    /// Inverse iteration and Lyapunov exponents cover the whole plane at once, without tiles, and
+    /// basins can only be told apart once the whole plane is known.
+    fn computes_in_background(&self) -> bool
+    {
+        self.background_compute && self.computes_pointwise()
+    }
+
+    /// Whether each point is computed on its own, so that parts of the plane can be computed
+    /// separately.
+    fn computes_pointwise(&self) -> bool
+    {
+        !matches!(
+            self.plane.compute_mode(),
+            ComputeMode::InverseIteration
+                | ComputeMode::Lyapunov
+                | ComputeMode::Basins
+                | ComputeMode::AdaptiveIterations
+        )
+    }
+
+    /// Compute the plane on the current thread.
+    fn compute_now(&mut self)
+    {
+        self.plane.compute_into(&mut self.iter_plane);
+        if self.plane.compute_mode() == ComputeMode::AdaptiveIterations {
+            self.plane.raise_iterations_near_boundary(&mut self.iter_plane);
+        }
+    }
+
+    /// Sample the density shown by the overlay, either of the equilibrium measure or along a
+    /// forward orbit on the attractor.
+    fn sample_density(&self, attractor: bool) -> Option<Array2<u32>>
+    {
+        let (width, height) = self.plane.point_grid().shape();
+        let num_points = EquilibriumOverlay::num_points(width, height);
+        if attractor {
+            self.plane.attractor_density(num_points)
+        } else {
+            self.plane.equilibrium_density(num_points)
+        }
+    }
+
+    /// Draw the equilibrium measure over the image, if shown, sampling it again after the plane
+    /// has been recomputed.
+    fn draw_equilibrium_measure(&mut self)
+    {
+        let Some(overlay) = self.equilibrium.as_ref() else {
+            return;
+        };
+        if overlay.is_stale() {
+            let attractor = overlay.is_attractor();
+            self.equilibrium = self
+                .sample_density(attractor)
+                .map(|density| EquilibriumOverlay::new(density, attractor));
+        }
+        if let Some(overlay) = self.equilibrium.as_ref() {
+            overlay.composite(&mut self.image_frame.image);
+        }
+    }
+
+    /// Locate the components of equal period in the computed image, if their labels are shown.
+    fn update_period_labels(&mut self)
+    {
+        let Some(labels) = self.period_labels.as_mut() else {
+            return;
+        };
+        let grid = &self.iter_plane.point_grid;
+        *labels = self
+            .iter_plane
+            .period_components()
+            .into_iter()
+            .filter(|component| component.size >= MIN_LABELED_PIXELS)
+            .map(|component| {
+                let (x, y) = component.center;
+                (grid.map_pixel(x, y), component.key)
+            })
+            .collect();
+    }
+
+    /// Trace the boundaries between the bands of escaping points in the computed image, if they
+    /// are shown.
+    fn update_band_contours(&mut self)
+    {
+        if let Some(contours) = self.band_contours.as_mut() {
+            *contours = self.iter_plane.potential_contours(BAND_CONTOUR_SPACING);
+        }
+    }
+
+    /// Trace the tree of preimages of the selection again, if it is shown and the selection, the
+    /// view or its settings have changed.
+    fn update_preimage_tree(&mut self)
+    {
+        let Some(explorer) = self.preimage_explorer.as_mut() else {
+            return;
+        };
+        let min_separation = explorer.prune_pixels * self.plane.point_grid().pixel_width();
+        if !explorer.needs_tracing(self.selection, min_separation) {
+            return;
+        }
+        let levels = self
+            .plane
+            .preimage_tree(self.selection, explorer.depth, min_separation)
+            .unwrap_or_default();
+        self.marking.set_preimage_tree(levels);
+    }
+
+    /// Recompute the minimap, if shown, after a change to the plane other than zooming or
+    /// panning.
+    pub fn invalidate_minimap(&mut self)
+    {
+        if let Some(minimap) = self.minimap.as_mut() {
+            minimap.invalidate();
         }
     }
 
@@ -312,6 +912,7 @@ where
     #[inline]
     fn plane_mut(&mut self) -> &mut P
     {
+        self.pan_residual = ZERO;
         &mut self.plane
     }
 
@@ -377,36 +978,186 @@ where
 
     fn draw(&mut self)
     {
-        let image = self.iter_plane.render(self.get_coloring());
-        let image_frame = self.frame_mut();
-        image_frame.image = image;
-        image_frame.update_texture();
+        let grid = &self.iter_plane.point_grid;
+        let mut image = ColorImage::new([grid.res_x, grid.res_y], Color32::default());
+        self.iter_plane
+            .render_into(&mut image, &self.coloring, &self.layers);
+        let previous = std::mem::replace(&mut self.frame_mut().image, image);
+        self.draw_equilibrium_measure();
+        self.color_vision.apply(&mut self.image_frame.image);
+        self.image_frame.update_texture_since(&previous);
+        if let Some(minimap) = self.minimap.as_mut() {
+            minimap.render(&self.coloring);
+        }
+        self.update_period_labels();
+        self.update_band_contours();
+        self.update_statistics();
     }
 
     fn redraw(&mut self)
     {
         let coloring = self.coloring.clone();
+        let previous = self.image_frame.image.clone();
         self.iter_plane
-            .render_into(&mut self.image_frame.image, &coloring);
-        self.image_frame.update_texture();
+            .render_into(&mut self.image_frame.image, &coloring, &self.layers);
+        self.draw_equilibrium_measure();
+        self.color_vision.apply(&mut self.image_frame.image);
+        self.image_frame.update_texture_since(&previous);
+        if let Some(minimap) = self.minimap.as_mut() {
+            minimap.render(&coloring);
+        }
+        self.update_period_labels();
+        self.update_statistics();
     }
 
-    #[inline]
     fn compute(&mut self)
     {
-        self.iter_plane = self.plane.compute();
+        self.zoom_preview = false;
+        self.invalidate_minimap();
+        if let Some(overlay) = self.equilibrium.as_mut() {
+            overlay.invalidate();
+        }
+        if let Some(tooltip) = self.orbit_tooltip.as_mut() {
+            tooltip.invalidate();
+        }
+        if self.computes_in_background() {
+            self.iter_plane = IterPlane::create(self.plane.point_grid().clone());
+            self.background = Some(BackgroundCompute::start(&self.plane));
+        } else {
+            self.iter_plane = IterPlane::create(self.plane.point_grid().clone());
+            self.compute_now();
+        }
     }
 
-    #[inline]
     fn recompute(&mut self)
     {
-        self.plane.compute_into(&mut self.iter_plane);
+        if let Some(overlay) = self.equilibrium.as_mut() {
+            overlay.invalidate();
+        }
+        if let Some(tooltip) = self.orbit_tooltip.as_mut() {
+            tooltip.invalidate();
+        }
+        let zoom_preview = std::mem::take(&mut self.zoom_preview)
+            && self.iter_plane.point_grid.shape() == self.plane.point_grid().shape()
+            && self.computes_pointwise();
+        if zoom_preview {
+            // Show the old points upsampled at once, and compute only those not already known
+            let (preview, stale) = self
+                .iter_plane
+                .zoom_preview(self.plane.point_grid().clone());
+            self.iter_plane = preview;
+            if self.computes_in_background() {
+                self.background = Some(BackgroundCompute::start(&self.plane));
+            } else {
+                self.plane.compute_stale_into(&mut self.iter_plane, &stale);
+            }
+            return;
+        }
+        if self.computes_in_background() {
+            // Keep showing the old points until the new tiles replace them
+            if self.iter_plane.point_grid.shape() == self.plane.point_grid().shape() {
+                self.iter_plane
+                    .point_grid
+                    .clone_from(self.plane.point_grid());
+                self.iter_plane.checkpoints.clear();
+            } else {
+                self.iter_plane = IterPlane::create(self.plane.point_grid().clone());
+            }
+            self.background = Some(BackgroundCompute::start(&self.plane));
+        } else {
+            self.compute_now();
+        }
+    }
+
+    fn poll_background_compute(&mut self)
+    {
+        let Some(background) = self.background.as_mut() else {
+            return;
+        };
+        let tiles = background.poll(&mut self.iter_plane);
+        if background.is_done() {
+            self.background = None;
+        }
+        if !tiles.is_empty() {
+            self.draw_tiles(&tiles);
+        }
+    }
+
+    /// Color the rows of newly computed tiles and stream just those to the texture. Lighting and
+    /// the equilibrium overlay depend on the whole image, so the image is redrawn instead if
+    /// either is shown, and the annotations found from the whole plane are updated once the last
+    /// tile is in.
+    fn draw_tiles(&mut self, tiles: &[TileJob])
+    {
+        if self.tasks.draw != RepeatableTask::DoNothing {
+            return;
+        }
+        let [width, height] = self.image_frame.image.size;
+        if self.layers.lighting.enabled
+            || self.equilibrium.is_some()
+            || self.iter_plane.point_grid.shape() != (width, height)
+        {
+            self.tasks.draw.schedule_rerun();
+            return;
+        }
+        for tile in tiles {
+            let rows = tile.first_row..tile.first_row + tile.num_rows;
+            let image_rows = height - rows.end..height - rows.start;
+            self.iter_plane
+                .render_rows_into(&mut self.image_frame.image, &self.coloring, &self.layers, rows);
+            self.color_vision
+                .apply_to_rows(&mut self.image_frame.image, image_rows.clone());
+            self.image_frame.update_texture_rows(image_rows);
+        }
+        if self.background.is_none() {
+            if let Some(minimap) = self.minimap.as_mut() {
+                minimap.render(&self.coloring);
+            }
+            self.update_period_labels();
+            self.update_band_contours();
+            self.update_statistics();
+        }
+    }
+
+    fn poll_ray_batch(&mut self)
+    {
+        let Some(ray_batch) = self.ray_batch.as_mut() else {
+            return;
+        };
+        let rays = ray_batch.poll();
+        if ray_batch.is_done() {
+            self.ray_batch = None;
+        }
+        if !rays.is_empty() {
+            self.marking.import_rays(rays);
+        }
+    }
+
+    fn poll_catalog_export(&mut self)
+    {
+        let Some(export) = self.catalog_export.as_mut() else {
+            return;
+        };
+        let Some(result) = export.poll() else {
+            return;
+        };
+        let path = export.path().to_owned();
+        let name = path.to_string_lossy().into_owned();
+        self.finished_save = Some(FinishedSave {
+            file_type: SaveFileType::PcfCatalog(export.max_len()),
+            result: result.map(|count| format!("{count} parameters saved to {name}")),
+            path,
+        });
+        self.catalog_export = None;
     }
 
     fn mark_orbit_and_info(&mut self, pointer_value: Cplx)
     {
         let orbit::OrbitAndInfo { orbit, info } = self.plane.get_orbit_and_info(pointer_value);
-        let orbit_pts = orbit.iter().map(|x| (*x).into()).collect();
+        let orbit_pts: ComplexVec = orbit.iter().map(|x| (*x).into()).collect();
+        if let Some(trace) = self.orbit_trace.as_mut() {
+            trace.clone_from(&orbit_pts);
+        }
         self.mark_orbit(orbit_pts, Color32::GREEN);
         self.set_orbit_info(info);
     }
@@ -426,7 +1177,7 @@ where
 
 impl<P> From<P> for WindowPane<P>
 where
-    P: Displayable + 'static,
+    P: Displayable + Clone + 'static,
 {
     fn from(plane: P) -> Self
     {
@@ -440,7 +1191,7 @@ where
 /// handling tasks, zooming, panning, and managing selections and markings.
 impl<P> Pane for WindowPane<P>
 where
-    P: Displayable + 'static,
+    P: Displayable + Clone + 'static,
 {
     #[inline]
     fn tasks(&self) -> &PaneTasks
@@ -457,9 +1208,12 @@ where
     {
         self.plane.point_grid()
     }
+    /// Also forgets the part of a pan too small to move by a pixel, since it was measured on the
+    /// grid as it was before the change.
     #[inline]
     fn grid_mut(&mut self) -> &mut PointGrid
     {
+        self.pan_residual = ZERO;
         self.plane.point_grid_mut()
     }
     #[inline]
@@ -473,6 +1227,16 @@ where
         &mut self.image_frame
     }
     #[inline]
+    fn ruler(&self) -> &Ruler
+    {
+        &self.ruler
+    }
+    #[inline]
+    fn ruler_mut(&mut self) -> &mut Ruler
+    {
+        &mut self.ruler
+    }
+    #[inline]
     fn get_coloring(&self) -> &Coloring
     {
         &self.coloring
@@ -548,6 +1312,12 @@ where
 
         self.marking_mut().toggle_contour(contour_type, selection);
     }
+    fn mark_component_boundary(&mut self)
+    {
+        let polygons = self.iter_plane.period_component_boundary(self.selection);
+        self.marking.mark_boundary(polygons);
+    }
+
     fn draw_aux_contours(&mut self)
     {
         let selection = self.get_selection();
@@ -567,6 +1337,15 @@ where
         }
     }
 
+    fn draw_critical_curves(&mut self)
+    {
+        let selection = self.get_selection();
+        for steps in 1..=CRITICAL_CURVE_STEPS {
+            self.marking_mut()
+                .toggle_contour(ContourType::CriticalRelation(steps), selection);
+        }
+    }
+
     #[inline]
     fn marking(&self) -> &Marking
     {
@@ -587,6 +1366,50 @@ where
             })
     }
 
+    fn select_nearby_multiplier(
+        &mut self,
+        period: Period,
+        multiplier: Cplx,
+    ) -> FindPointResult<Cplx>
+    {
+        self.plane
+            .find_nearby_multiplier(self.selection, period, multiplier)
+            .inspect(|&pt| {
+                self.select_point(pt);
+            })
+    }
+
+    fn ray_landing_point(&self, angle: RationalAngle) -> Option<Cplx>
+    {
+        self.plane.external_ray(angle)?.last().copied()
+    }
+
+    fn analyze_misiurewicz(
+        &mut self,
+        o: OrbitSchema,
+    ) -> Result<MisiurewiczSpiral, FindPointError<Cplx>>
+    {
+        let base_point = self.selection;
+        let spiral = self.plane.misiurewicz_spiral(base_point, o)?;
+        self.marking.toggle_spiral(o, base_point);
+        self.select_point(spiral.center);
+        Ok(spiral)
+    }
+
+    fn trace_multiplier_locus(
+        &mut self,
+        period: Period,
+        bifurcation: Bifurcation,
+    ) -> Result<(), FindPointError<Cplx>>
+    {
+        let base_point = self.selection;
+        self.plane
+            .multiplier_locus(base_point, period, bifurcation)?;
+        self.marking
+            .toggle_multiplier_locus(period, bifurcation, base_point);
+        Ok(())
+    }
+
     fn map_selection(&mut self)
     {
         if self.plane_type().is_dynamical() {
@@ -601,6 +1424,7 @@ where
     fn cycle_active_plane(&mut self)
     {
         self.plane.cycle_active_plane();
+        self.invalidate_minimap();
         self.schedule_recompute();
         self.schedule_redraw();
     }
@@ -618,7 +1442,149 @@ where
                 self.plane.compute_mode_mut().cycle();
             }
         }
+        self.invalidate_minimap();
+        self.schedule_recompute();
+    }
+
+    fn toggle_inverse_iteration(&mut self) -> bool
+    {
+        if self.plane.compute_mode() == ComputeMode::InverseIteration {
+            self.plane.set_compute_mode(ComputeMode::SmoothPotential);
+        } else {
+            let param = self.plane.param_map(self.plane.point_grid().center());
+            if !self.plane.plane_type().is_dynamical()
+                || self.plane.preimages(ONE.into(), &param).is_empty()
+            {
+                return false;
+            }
+            self.plane.set_compute_mode(ComputeMode::InverseIteration);
+        }
+        self.invalidate_minimap();
         self.schedule_recompute();
+        true
+    }
+
+    fn toggle_lyapunov(&mut self) -> bool
+    {
+        if self.plane.compute_mode() == ComputeMode::Lyapunov {
+            self.plane.set_compute_mode(ComputeMode::SmoothPotential);
+        } else {
+            let center = self.plane.point_grid().center();
+            let param = self.plane.param_map(center);
+            let start = self.plane.start_point(center, &param);
+            if self.plane.lyapunov_info(start, &param).is_none() {
+                return false;
+            }
+            self.plane.set_compute_mode(ComputeMode::Lyapunov);
+        }
+        self.invalidate_minimap();
+        self.schedule_recompute();
+        true
+    }
+
+    fn toggle_adaptive_iterations(&mut self)
+    {
+        if self.plane.compute_mode() == ComputeMode::AdaptiveIterations {
+            self.plane.set_compute_mode(ComputeMode::SmoothPotential);
+        } else {
+            self.plane.set_compute_mode(ComputeMode::AdaptiveIterations);
+        }
+        self.invalidate_minimap();
+        self.schedule_recompute();
+    }
+
+    fn toggle_basins(&mut self)
+    {
+        if self.plane.compute_mode() == ComputeMode::Basins {
+            self.plane.set_compute_mode(ComputeMode::SmoothPotential);
+        } else {
+            self.plane.set_compute_mode(ComputeMode::Basins);
+        }
+        self.invalidate_minimap();
+        self.schedule_recompute();
+    }
+
+    fn toggle_escape_phase_coloring(&mut self)
+    {
+        let esc_period = self.plane.escaping_period();
+        let esc_phase = self.plane.escaping_phase();
+        let coloring = self.get_coloring_mut();
+        coloring.toggle_escape_phase_coloring();
+        if coloring.is_escape_phase_coloring() {
+            coloring.suggest_escape_phase(esc_period, esc_phase);
+        }
+        self.schedule_redraw();
+    }
+
+    fn toggle_stochastic(&mut self)
+    {
+        if matches!(self.plane.compute_mode(), ComputeMode::Stochastic { .. }) {
+            self.plane.set_compute_mode(ComputeMode::SmoothPotential);
+        } else {
+            self.plane.set_compute_mode(ComputeMode::Stochastic {
+                sigma: orbit::stochastic::DEFAULT_NOISE,
+                trials: orbit::stochastic::DEFAULT_TRIALS,
+            });
+        }
+        self.invalidate_minimap();
+        self.schedule_recompute();
+    }
+
+    fn scale_noise(&mut self, factor: f64)
+    {
+        if let ComputeMode::Stochastic { sigma, .. } = self.plane.compute_mode_mut() {
+            *sigma *= factor;
+            self.invalidate_minimap();
+            self.schedule_recompute();
+        }
+    }
+
+    fn toggle_flow(&mut self) -> bool
+    {
+        if self.flow_time().is_some() {
+            self.plane.set_compute_mode(ComputeMode::SmoothPotential);
+        } else {
+            if !self.plane.plane_type().is_dynamical() {
+                return false;
+            }
+            self.plane.set_compute_mode(ComputeMode::Flow { time: 0. });
+        }
+        self.invalidate_minimap();
+        self.schedule_recompute();
+        true
+    }
+
+    fn flow_time(&self) -> Option<Real>
+    {
+        match self.plane.compute_mode() {
+            ComputeMode::Flow { time } => Some(time),
+            _ => None,
+        }
+    }
+
+    fn set_flow_time(&mut self, time: Real)
+    {
+        if let ComputeMode::Flow { time: t } = self.plane.compute_mode_mut() {
+            *t = time;
+            self.invalidate_minimap();
+            self.schedule_recompute();
+        }
+    }
+
+    fn toggle_log_coordinates(&mut self) -> bool
+    {
+        if self.plane.compute_mode() == ComputeMode::Logarithmic {
+            self.plane.set_compute_mode(ComputeMode::SmoothPotential);
+        } else {
+            let param = self.plane.param_map(self.plane.point_grid().center());
+            if self.plane.log_lift(ZERO, &param).is_none() {
+                return false;
+            }
+            self.plane.set_compute_mode(ComputeMode::Logarithmic);
+        }
+        self.invalidate_minimap();
+        self.schedule_recompute();
+        true
     }
 
     #[allow(clippy::cast_sign_loss)]
@@ -630,24 +1596,94 @@ where
         );
         let iters = self.plane.max_iter_mut();
         *iters = ((*iters as f64) * factor) as IterCount;
-        self.schedule_recompute();
+        self.invalidate_minimap();
+
+        // Only the orbits that ran out of iterations change when more are allowed
+        let can_resume = factor > 1.
+            && !self.computes_in_background()
+            && self.tasks.compute == RepeatableTask::DoNothing
+            && self.iter_plane.point_grid == *self.plane.point_grid()
+            && self.computes_pointwise();
+        if can_resume {
+            self.plane.resume_into(&mut self.iter_plane);
+            if let Some(overlay) = self.equilibrium.as_mut() {
+                overlay.invalidate();
+            }
+            if let Some(tooltip) = self.orbit_tooltip.as_mut() {
+                tooltip.invalidate();
+            }
+            self.marking.sched_recompute_all();
+        } else {
+            self.schedule_recompute();
+        }
         self.schedule_redraw();
     }
 
+    fn set_max_iter(&mut self, max_iter: IterCount)
+    {
+        if self.plane.max_iter() != max_iter {
+            *self.plane.max_iter_mut() = max_iter;
+            self.invalidate_minimap();
+            self.schedule_recompute();
+            self.schedule_redraw();
+        }
+    }
+
     fn change_height(&mut self, new_height: usize)
     {
-        self.plane.point_grid_mut().resize_y(new_height);
+        self.grid_mut().resize_y(new_height);
         self.schedule_compute();
     }
 
     #[inline]
     fn zoom(&mut self, scale: Real, base_point: Cplx)
     {
+        self.zoom_preview = scale < 1.
+            && self.background.is_none()
+            && self.tasks.compute == RepeatableTask::DoNothing
+            && self.iter_plane.point_grid == *self.plane.point_grid();
         self.zoom_factor *= scale;
         self.grid_mut().zoom(scale, base_point);
         self.schedule_recompute_keep_old_annotations();
     }
 
+    /// Pan by whole pixels, keeping the points still in view and computing only the strips
+    /// exposed, unless the pan is a screenful or more or the points depend on the whole grid.
+    fn pan(&mut self, offset_vector: Cplx)
+    {
+        let offset_vector = offset_vector + self.pan_residual;
+        let can_shift = self.background.is_none()
+            && self.tasks.compute == RepeatableTask::DoNothing
+            && self.iter_plane.point_grid == *self.plane.point_grid()
+            && self.computes_pointwise();
+        let (dx, dy) = self.iter_plane.point_grid.pixel_offset(offset_vector);
+        let exposed = can_shift
+            .then(|| self.iter_plane.translate_and_invalidate(dx, dy))
+            .flatten();
+
+        if let Some(exposed) = exposed {
+            let translation = self.plane.point_grid_mut().translate_pixels(dx, dy);
+            self.pan_residual = offset_vector - translation;
+            if exposed.is_empty() {
+                return;
+            }
+            for block in exposed {
+                self.plane.compute_block_into(&mut self.iter_plane, block);
+            }
+            if let Some(overlay) = self.equilibrium.as_mut() {
+                overlay.invalidate();
+            }
+            if let Some(tooltip) = self.orbit_tooltip.as_mut() {
+                tooltip.invalidate();
+            }
+            self.marking.sched_recompute_all();
+        } else {
+            self.grid_mut().translate(offset_vector);
+            self.schedule_recompute();
+        }
+        self.schedule_redraw();
+    }
+
     fn process_tasks(&mut self)
     {
         self.process_marking_tasks();
@@ -689,6 +1725,17 @@ where
                 self.compute();
             }
         }
+        self.poll_background_compute();
+        self.poll_ray_batch();
+        self.poll_catalog_export();
+        if let Some(minimap) = self.minimap.as_mut() {
+            minimap.update(&self.plane, &self.coloring);
+        }
+        if let Some(shift) = self.tasks_mut().color_cycle.pop() {
+            self.shift_palette(shift);
+        }
+        self.marking.advance_orbit_playback();
+        self.update_preimage_tree();
         match self.tasks_mut().draw.pop() {
             RepeatableTask::Rerun => {
                 self.redraw();
@@ -700,6 +1747,41 @@ where
         }
     }
 
+    fn is_computing(&self) -> bool
+    {
+        self.background.is_some() || self.ray_batch.is_some() || self.catalog_export.is_some()
+    }
+
+    fn set_background_compute(&mut self, enabled: bool)
+    {
+        self.background_compute = enabled;
+    }
+
+    fn trace_rays(&mut self, angles: Vec<RationalAngle>)
+    {
+        self.ray_batch = Some(RayBatch::start(&self.plane, angles));
+    }
+
+    fn ray_progress(&self) -> Option<(usize, usize)>
+    {
+        self.ray_batch.as_ref().map(RayBatch::progress)
+    }
+
+    fn take_finished_save(&mut self) -> Option<FinishedSave>
+    {
+        self.finished_save.take()
+    }
+
+    fn export_pcf_catalog(&mut self, max_len: Period, filename: &Path)
+    {
+        self.catalog_export = Some(CatalogExport::start(&self.plane, max_len, filename));
+    }
+
+    fn catalog_progress(&self) -> Option<(usize, usize)>
+    {
+        self.catalog_export.as_ref().map(CatalogExport::progress)
+    }
+
     fn select_preperiod_smooth_coloring(&mut self)
     {
         let coloring_algorithm = self.plane.internal_potential_coloring();
@@ -718,40 +1800,107 @@ where
         self.set_coloring_algorithm(coloring_algorithm);
     }
 
-    fn save_image(&mut self, img_width: usize, filename: &Path)
-    {
-        let old_res_x = self.plane.point_grid().res_x;
-        self.plane.point_grid_mut().resize_x(img_width);
-        let iter_plane = self.plane.compute();
-        // iter_plane.save(self.get_coloring(), filename.to_owned());
+    fn save_image(
+        &mut self,
+        img_width: usize,
+        filename: &Path,
+        transparency: Transparency,
+    ) -> DynamoResult<()>
+    {
+        let old_grid = self.plane.point_grid().clone();
+        let grid = old_grid.clone().with_width(img_width);
+
+        // PNGs are computed and written in strips, so very large exports never hold the
+        // whole image in memory.
+        let result = if tiled_export::is_png(filename) {
+            tiled_export::save_png_in_strips(
+                filename,
+                grid.res_x,
+                grid.res_y,
+                transparency != Transparency::Opaque,
+                |first_row, num_rows| {
+                    let strip_grid = grid.row_strip(first_row, num_rows);
+                    self.plane.point_grid_mut().clone_from(&strip_grid);
+                    let iter_plane = self.plane.compute();
+                    export_image(
+                        &iter_plane,
+                        &self.coloring,
+                        &self.layers,
+                        &self.marking,
+                        transparency,
+                    )
+                },
+            )
+        } else {
+            self.plane.point_grid_mut().clone_from(&grid);
+            let iter_plane = self.plane.compute();
+            export_image(&iter_plane, &self.coloring, &self.layers, &self.marking, transparency)
+                .save(filename)
+                .map_err(Into::into)
+        };
 
-        let mut image = iter_plane.write_image(self.get_coloring());
-        self.marking.mark_image(self.grid(), &mut image);
+        self.plane.point_grid_mut().clone_from(&old_grid);
 
-        if let Err(e) = image.save(filename) {
-            println!("Error saving file: {e:?}");
-        } else {
-            println!("Image saved to {}", filename.to_string_lossy());
-        }
+        // Failures to open the file surface as I/O errors; anything else went wrong encoding it.
+        result.map_err(|e| match DynamoError::from(e) {
+            DynamoError::Format(msg) => DynamoError::Image(msg),
+            err => err,
+        })
+    }
+
+    fn render_job(&self, img_width: usize, filename: &Path, transparency: Transparency)
+        -> RenderJob
+    {
+        let mut plane = self.plane.clone();
+        plane.point_grid_mut().resize_x(img_width);
+        let grid = plane.point_grid().clone();
+        let coloring = self.coloring.clone();
+        let layers = self.layers.clone();
+        let marking = self.marking.clone();
+
+        let description = format!("{} ({}x{})", self.long_name(), grid.res_x, grid.res_y);
 
-        self.plane.point_grid_mut().resize_x(old_res_x);
+        RenderJob::new(description, filename, move || {
+            export_image(&plane.compute(), &coloring, &layers, &marking, transparency)
+        })
     }
 
-    fn save_palette(&mut self, filename: &Path)
+    fn save_palette(&mut self, filename: &Path) -> DynamoResult<()>
     {
-        if let Err(e) = self.coloring.save_to_file(filename) {
-            println!("Error saving palette: {e:?}");
-        } else {
-            println!("Palette saved to {}", filename.to_string_lossy());
-        }
+        Ok(self.coloring.save_to_file(filename)?)
     }
 
-    fn load_palette(&mut self, filename: &Path)
+    fn load_palette(&mut self, filename: &Path) -> DynamoResult<()>
     {
-        if let Err(e) = self.coloring.load_palette(filename) {
-            println!("Error loading palette: {e:?}");
-        }
+        self.coloring.load_palette(filename)?;
         self.schedule_redraw();
+        Ok(())
+    }
+
+    fn save_data(&mut self, filename: &Path) -> DynamoResult<()>
+    {
+        Ok(self.iter_plane.save_data(filename)?)
+    }
+
+    fn save_orbit(&mut self, filename: &Path) -> DynamoResult<()>
+    {
+        let orbit::OrbitAndInfo { orbit, info } = self.plane.get_orbit_and_info(self.selection);
+        let steps = self.plane.orbit_steps(&orbit, &info.param);
+        Ok(orbit_file::save(filename, &steps, &info.classification())?)
+    }
+
+    /// Replace the current image with previously saved iteration data. The plane's grid is moved
+    /// to match the data, and the pending computation is dropped so that only recoloring happens.
+    fn load_data(&mut self, filename: &Path) -> DynamoResult<()>
+    {
+        let iter_plane = IterPlane::load_data(filename)?;
+        self.grid_mut().clone_from(&iter_plane.point_grid);
+        self.iter_plane = iter_plane;
+        self.tasks_mut().compute.clear();
+        self.background = None;
+        self.schedule_draw();
+        self.marking_mut().flush_path_cache();
+        Ok(())
     }
 
     #[inline]
@@ -764,6 +1913,7 @@ where
     #[inline]
     fn clear_marked_rays(&mut self)
     {
+        self.ray_batch = None;
         self.marking.disable_all_rays();
     }
 
@@ -774,6 +1924,7 @@ where
 
     fn clear_curves(&mut self)
     {
+        self.ray_batch = None;
         self.marking.disable_all_curves();
         self.tasks_mut().orbit.disable();
     }
@@ -786,6 +1937,16 @@ where
 
         self.marking()
             .draw_curves(&painter, self.grid(), self.frame());
+        for contour in self.band_contours.iter().flatten() {
+            for run in self.grid().locate_curve(&contour.points) {
+                let points = run
+                    .into_iter()
+                    .map(|pt| frame.to_global_coords(pt.into()))
+                    .collect();
+                let stroke = Stroke::new(1.0, BAND_CONTOUR_COLOR);
+                painter.add(PathShape::line(points, stroke));
+            }
+        }
     }
 
     fn clear_marked_points(&mut self)
@@ -799,16 +1960,325 @@ where
         let grid = self.grid();
         let painter = ui.painter().with_clip_rect(frame.region);
         self.marking.draw_points(&painter, grid, frame);
+        if let Some(pointer_pos) = ui
+            .ctx()
+            .pointer_hover_pos()
+            .filter(|pos| self.frame_contains_pixel(*pos))
+        {
+            let radius = PERIODIC_POINT_PICK_PIXELS * grid.pixel_width();
+            let pointer_value = self.map_pixel(pointer_pos);
+            if let Some(periodic) = self.marking.periodic_point_near(pointer_value, radius) {
+                let id = Id::new("periodic_point_tooltip");
+                egui::show_tooltip_at_pointer(ui.ctx(), ui.layer_id(), id, |ui| {
+                    ui.label(periodic.to_string());
+                });
+            }
+        }
+        let locate = |z| grid.locate_point(z).map(|pt| frame.to_global_coords(pt.into()));
+        for (z, period) in self.period_labels.iter().flatten() {
+            let Some(pos) = locate(*z) else {
+                continue;
+            };
+            let font = FontId::proportional(PERIOD_LABEL_SIZE);
+            painter.text(pos, Align2::CENTER_CENTER, period, font, Color32::WHITE);
+        }
+        let ruler = self
+            .ruler
+            .segment()
+            .and_then(|(start, end)| Some([locate(start)?, locate(end)?]));
+        if let Some([start, end]) = ruler {
+            painter.line_segment([start, end], Stroke::new(RULER_THICKNESS, RULER_COLOR));
+            for pos in [start, end] {
+                painter.circle_filled(pos, RULER_THICKNESS + 1., RULER_COLOR);
+            }
+        }
+    }
+
+    fn toggle_minimap(&mut self)
+    {
+        self.minimap = match self.minimap {
+            Some(_) => None,
+            None => Some(Minimap::new(&self.plane)),
+        };
+    }
+
+    fn cycle_color_vision_filter(&mut self)
+    {
+        self.color_vision = self.color_vision.next();
+        self.schedule_redraw();
+    }
+
+    fn get_color_vision_filter(&self) -> ColorVisionFilter
+    {
+        self.color_vision
+    }
+
+    fn toggle_period_labels(&mut self)
+    {
+        self.period_labels = match self.period_labels {
+            Some(_) => None,
+            None => Some(Vec::new()),
+        };
+        self.update_period_labels();
+    }
+
+    fn toggle_band_contours(&mut self)
+    {
+        self.band_contours = match self.band_contours {
+            Some(_) => None,
+            None => Some(Vec::new()),
+        };
+        self.update_band_contours();
+    }
+
+    fn toggle_preimage_tree(&mut self) -> bool
+    {
+        if self.preimage_explorer.take().is_some() {
+            self.marking.set_preimage_tree(Vec::new());
+            return true;
+        }
+        let param = self.plane.param_map(self.plane.point_grid().center());
+        if !self.plane.plane_type().is_dynamical()
+            || self.plane.preimages(ONE.into(), &param).is_empty()
+        {
+            return false;
+        }
+        self.preimage_explorer = Some(PreimageExplorer::default());
+        self.update_preimage_tree();
+        true
+    }
+
+    fn preimage_explorer_mut(&mut self) -> Option<&mut PreimageExplorer>
+    {
+        self.preimage_explorer.as_mut()
+    }
+
+    fn save_band_contours(&self, filename: &Path) -> DynamoResult<usize>
+    {
+        let contours = self.iter_plane.potential_contours(BAND_CONTOUR_SPACING);
+        contour_file::save(filename, &contours, &self.iter_plane.point_grid)?;
+        Ok(contours.len())
+    }
+
+    fn toggle_statistics(&mut self)
+    {
+        self.statistics = match self.statistics {
+            Some(_) => None,
+            None => Some(self.iter_plane.statistics()),
+        };
+    }
+
+    fn toggle_equilibrium_measure(&mut self) -> bool
+    {
+        if self.equilibrium.take().is_none_or(|overlay| overlay.is_attractor()) {
+            let Some(density) = self.sample_density(false) else {
+                return false;
+            };
+            self.equilibrium = Some(EquilibriumOverlay::new(density, false));
+        }
+        self.schedule_redraw();
+        true
+    }
+
+    fn toggle_attractor_density(&mut self) -> bool
+    {
+        if self.equilibrium.take().is_none_or(|overlay| !overlay.is_attractor()) {
+            let Some(density) = self.sample_density(true) else {
+                return false;
+            };
+            self.equilibrium = Some(EquilibriumOverlay::new(density, true));
+        }
+        self.schedule_redraw();
+        true
+    }
+
+    fn show_statistics(&mut self, ctx: &Context)
+    {
+        let Some(stats) = self.statistics.as_ref() else {
+            return;
+        };
+        let title = format!("{} Statistics", self.long_name());
+        let palette = self.coloring.get_period_coloring();
+        if !statistics::show(ctx, title, stats, palette) {
+            self.statistics = None;
+        }
+    }
+
+    fn toggle_attractor_inventory(&mut self)
+    {
+        self.attractor_inventory = match self.attractor_inventory {
+            Some(_) => None,
+            None => Some(self.iter_plane.attractors()),
+        };
+    }
+
+    fn show_attractor_inventory(&mut self, ctx: &Context)
+    {
+        let Some(inventory) = self.attractor_inventory.as_ref() else {
+            return;
+        };
+        let title = format!("{} Attractors", self.long_name());
+        let num_classes = inventory.len();
+        let mut toggled = None;
+        let open = attractors::show(
+            ctx,
+            title,
+            inventory,
+            self.plane.plane_type().is_dynamical(),
+            |attractor| Coloring::class_color(attractor.class_id, num_classes),
+            |attractor| self.marking.cycle_marked(attractor.cycle_point),
+            |attractor| toggled = Some(attractor.clone()),
+        );
+        if let Some(attractor) = toggled {
+            let color = Coloring::class_color(attractor.class_id, num_classes);
+            let cycle = self.attractor_cycle(&attractor);
+            self.marking.toggle_cycle(cycle, color);
+            self.schedule_redraw();
+        }
+        if !open {
+            self.attractor_inventory = None;
+        }
+    }
+
+    fn toggle_layers(&mut self)
+    {
+        self.show_layers ^= true;
+    }
+
+    fn show_layers(&mut self, ctx: &Context)
+    {
+        if !self.show_layers {
+            return;
+        }
+        let title = format!("{} Layers", self.long_name());
+        let (open, changed) = layers::show(ctx, title, &mut self.layers, &self.coloring);
+        if changed {
+            self.schedule_redraw();
+        }
+        self.show_layers = open;
+    }
+
+    fn toggle_lighting(&mut self)
+    {
+        self.layers.lighting.enabled ^= true;
+        self.schedule_redraw();
+    }
+
+    fn toggle_pcf_database(&mut self)
+    {
+        if self.pcf_database.take().is_some() {
+            self.overlay_pcf_parameters();
+        } else {
+            self.pcf_database = Some(PcfDatabase::default());
+            self.search_pcf_parameters();
+        }
+    }
+
+    fn show_pcf_database(&mut self, ctx: &Context) -> bool
+    {
+        if self.pcf_database.is_none() {
+            return false;
+        }
+        let title = format!("{} PCF Parameters", self.long_name());
+        let Some(database) = self.pcf_database.as_mut() else {
+            return false;
+        };
+        let palette = self.coloring.get_period_coloring();
+        let response = database.show(ctx, title, |entry| {
+            palette.map_preperiodic(entry.orbit_schema)
+        });
+        if !response.open {
+            self.pcf_database = None;
+            self.overlay_pcf_parameters();
+            return false;
+        }
+        if response.search {
+            self.search_pcf_parameters();
+        } else if response.overlay_changed {
+            self.overlay_pcf_parameters();
+        }
+        if let Some(point) = response.selected {
+            self.select_point(point);
+        }
+        response.selected.is_some()
+    }
+
+    fn toggle_orbit_tooltip(&mut self)
+    {
+        self.orbit_tooltip = match self.orbit_tooltip {
+            Some(_) => None,
+            None => Some(OrbitTooltip::default()),
+        };
+    }
+
+    fn show_orbit_tooltip(&mut self, ctx: &Context, pointer_value: Cplx, pointer_pos: Pos2)
+    {
+        let Some(tooltip) = self.orbit_tooltip.as_mut() else {
+            return;
+        };
+        let grid = self.plane.point_grid();
+        let tolerance = 0.5 * grid.pixel_width();
+        let start = grid.transform.apply(pointer_value);
+        tooltip.show(ctx, &self.plane, pointer_value, start, pointer_pos, tolerance);
+    }
+
+    fn toggle_orbit_trace(&mut self)
+    {
+        self.orbit_trace = match self.orbit_trace {
+            Some(_) => None,
+            None => {
+                let orbit = self.plane.get_orbit_and_info(self.selection).orbit;
+                Some(orbit.into_iter().map(Into::into).collect())
+            }
+        };
+    }
+
+    fn show_orbit_trace(&mut self, ctx: &Context)
+    {
+        let Some(orbit) = self.orbit_trace.as_ref() else {
+            return;
+        };
+        let title = format!("{} Orbit Trace", self.long_name());
+        if !trace::show(ctx, title, orbit, self.plane.trace_labels()) {
+            self.orbit_trace = None;
+        }
+    }
+
+    fn box_counting_dimension(&self) -> Option<Estimate>
+    {
+        analysis::box_counting_dimension(&self.iter_plane)
+    }
+
+    fn estimate_area(&self) -> Option<Estimate>
+    {
+        self.plane
+            .estimate_bounded_area(AREA_BASE_WIDTH, AREA_LEVELS)
+    }
+
+    fn put_minimap(&mut self, ui: &mut Ui)
+    {
+        if let Some(minimap) = self.minimap.as_mut() {
+            let bounds = &self.plane.point_grid().bounds;
+            minimap.put(ui, self.image_frame.region, bounds);
+        }
+    }
+
+    fn minimap_point(&self, pointer_pos: Pos2) -> Option<Cplx>
+    {
+        self.minimap.as_ref()?.point_at(pointer_pos)
     }
 
     fn state_info(&self) -> String
     {
         format!(
-            "{iters_info}\n{selection_info}\n{orbit_info}\n\n{follow_state}",
+            "{iters_info}\n{selection_info}\n{orbit_info}\n\n{follow_state}\n{ruler}",
             iters_info = self.describe_max_iter(),
             selection_info = self.describe_selection(),
             orbit_info = self.describe_orbit_info(),
             follow_state = self.tasks().follow,
+            ruler = self
+                .ruler
+                .describe(matches!(self.plane_type(), PlaneType::Dynamical)),
         )
     }
 
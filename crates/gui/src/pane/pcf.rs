@@ -0,0 +1,127 @@
+use dynamo_common::prelude::*;
+use dynamo_common::symbolic_dynamics::OrbitSchema;
+use dynamo_core::prelude::PcfParameter;
+use egui::{Color32, Context, RichText};
+
+/// Height of the list of parameters, beyond which it scrolls.
+const MAX_HEIGHT: f32 = 320.;
+const MAX_PREPERIOD: Period = 8;
+const MAX_PERIOD: Period = 12;
+
+/// Postcritically finite parameters found in a pane, with the bounds of the search and the
+/// query filtering the list.
+pub(super) struct PcfDatabase
+{
+    pub max_preperiod: Period,
+    pub max_period: Period,
+    pub entries: Vec<PcfParameter>,
+    pub query: String,
+    pub overlay: bool,
+}
+
+impl Default for PcfDatabase
+{
+    fn default() -> Self
+    {
+        Self {
+            max_preperiod: 2,
+            max_period: 4,
+            entries: Vec::new(),
+            query: String::new(),
+            overlay: true,
+        }
+    }
+}
+
+/// What was done in the window during a frame.
+#[derive(Default)]
+pub(super) struct PcfResponse
+{
+    pub open: bool,
+    pub search: bool,
+    pub overlay_changed: bool,
+    pub selected: Option<Cplx>,
+}
+
+impl PcfDatabase
+{
+    /// Whether `entry` matches the query, either as an orbit schema such as "2, 1" or by the
+    /// text of the entry.
+    fn matches(&self, entry: &PcfParameter) -> bool
+    {
+        let query = self.query.trim();
+        if query.is_empty() {
+            return true;
+        }
+        query.parse::<OrbitSchema>().map_or_else(
+            |_| entry.to_string().contains(query),
+            |schema| entry.orbit_schema == schema,
+        )
+    }
+
+    /// Window listing the parameters found, with controls to search the view again, filter the
+    /// list and overlay the parameters on the image.
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        title: String,
+        color_of: impl Fn(&PcfParameter) -> Color32,
+    ) -> PcfResponse
+    {
+        let mut response = PcfResponse::default();
+        let mut open = true;
+        egui::Window::new(title)
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Max preperiod");
+                    let preperiod = egui::DragValue::new(&mut self.max_preperiod);
+                    ui.add(preperiod.range(0..=MAX_PREPERIOD));
+                    ui.label("Max period");
+                    ui.add(egui::DragValue::new(&mut self.max_period).range(1..=MAX_PERIOD));
+                    if ui.button("Search view").clicked() {
+                        response.search = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Filter");
+                    ui.text_edit_singleline(&mut self.query)
+                        .on_hover_text("An orbit schema such as \"2, 1\", or any text to match.");
+                    response.overlay_changed =
+                        ui.checkbox(&mut self.overlay, "Overlay").changed();
+                });
+                ui.separator();
+
+                if self.entries.is_empty() {
+                    ui.label("No parameters found. Search the view to list them here.");
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .max_height(MAX_HEIGHT)
+                    .show(ui, |ui| {
+                        egui::Grid::new("pcf_parameters")
+                            .num_columns(4)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for heading in ["", "Orbit", "Parameter", ""] {
+                                    ui.strong(heading);
+                                }
+                                ui.end_row();
+
+                                for entry in self.entries.iter().filter(|e| self.matches(e)) {
+                                    ui.label(RichText::new("■").color(color_of(entry)));
+                                    ui.label(entry.orbit_schema.to_string());
+                                    ui.label(format!("{:.DISPLAY_PREC$}", entry.point));
+                                    if ui.button("Select").clicked() {
+                                        response.selected = Some(entry.point);
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            });
+        response.open = open;
+        response
+    }
+}
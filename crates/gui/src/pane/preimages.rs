@@ -0,0 +1,49 @@
+//! Interactive exploration of the tree of preimages of the selected point. Since the Julia set
+//! is the closure of the backward orbit of almost any point, the tree outlines it more and more
+//! finely as it grows deeper.
+use dynamo_common::prelude::*;
+
+/// Depth and pruning of the tree of preimages of the selection, which is traced again whenever
+/// either of them or the selection changes.
+#[derive(Clone, Debug)]
+pub struct PreimageExplorer
+{
+    /// Highest order of the preimages traced.
+    pub depth: usize,
+    /// Preimages closer than this many pixels to another of the same order are pruned, together
+    /// with their own preimages.
+    pub prune_pixels: Real,
+    traced_from: Option<(Cplx, usize, Real)>,
+}
+
+impl Default for PreimageExplorer
+{
+    fn default() -> Self
+    {
+        Self {
+            depth: Self::DEFAULT_DEPTH,
+            prune_pixels: Self::DEFAULT_PRUNE_PIXELS,
+            traced_from: None,
+        }
+    }
+}
+
+impl PreimageExplorer
+{
+    pub const DEFAULT_DEPTH: usize = 8;
+    pub const MAX_DEPTH: usize = 20;
+    pub const DEFAULT_PRUNE_PIXELS: Real = 2.;
+    pub const MAX_PRUNE_PIXELS: Real = 32.;
+
+    /// Whether the tree from `root`, pruned at `min_separation`, differs from the one traced
+    /// last. If so, it is recorded as traced.
+    pub(super) fn needs_tracing(&mut self, root: Cplx, min_separation: Real) -> bool
+    {
+        let key = Some((root, self.depth, min_separation));
+        if self.traced_from == key {
+            return false;
+        }
+        self.traced_from = key;
+        true
+    }
+}
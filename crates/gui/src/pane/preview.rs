@@ -0,0 +1,85 @@
+use super::WindowPane;
+use dynamo_color::prelude::*;
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use egui::{Color32, Context, Id, Order, Pos2, Stroke, TextureOptions, Vec2};
+use epaint::{ColorImage, TextureHandle};
+
+/// Height of the preview in pixels.
+const PREVIEW_HEIGHT: usize = 128;
+/// Iteration limit of the preview, for planes that cannot be drawn by inverse iteration.
+const PREVIEW_MAX_ITER: IterCount = 256;
+/// Offset of the preview from the pointer, so that it does not hide the point being chosen.
+const POINTER_OFFSET: Vec2 = Vec2::splat(18.);
+
+/// Low-resolution Julia set for the parameter under the pointer, shown next to it while moving
+/// around the parent pane in live mode. It is computed separately from the child pane, by inverse
+/// iteration where possible, so that it keeps up with the pointer.
+#[derive(Default)]
+pub(crate) struct JuliaPreview
+{
+    /// Point of the parent plane whose Julia set is shown.
+    parent_point: Option<Cplx>,
+    image: ColorImage,
+    texture: Option<TextureHandle>,
+}
+
+impl JuliaPreview
+{
+    /// Recompute the preview for the parameter at `parent_point`, unless it is already shown.
+    pub fn update<J>(
+        &mut self,
+        child: &WindowPane<J>,
+        parent_point: Cplx,
+        param: <J::MetaParam as ParamList>::Param,
+    ) where
+        J: Displayable + Clone + 'static,
+    {
+        if self.parent_point == Some(parent_point) {
+            return;
+        }
+        self.parent_point = Some(parent_point);
+
+        let mut plane = child.plane.clone();
+        plane.set_param(param);
+        let bounds = plane.default_bounds();
+        let grid = plane.point_grid_mut();
+        grid.change_bounds(bounds);
+        grid.resize_y(PREVIEW_HEIGHT);
+
+        let center = plane.point_grid().center();
+        let invertible = plane.plane_type().is_dynamical()
+            && !plane.preimages(ONE.into(), &plane.param_map(center)).is_empty();
+        if invertible {
+            plane.set_compute_mode(ComputeMode::InverseIteration);
+        } else {
+            plane.set_max_iter(plane.max_iter().min(PREVIEW_MAX_ITER));
+        }
+
+        self.image = plane.compute().render(&child.coloring);
+        if let Some(texture) = self.texture.as_mut() {
+            texture.set(self.image.clone(), TextureOptions::default());
+        }
+    }
+
+    /// Show the preview next to the pointer, on top of the panes.
+    pub fn put(&mut self, ctx: &Context, pointer_pos: Pos2)
+    {
+        let texture = self.texture.get_or_insert_with(|| {
+            ctx.load_texture("julia_preview", self.image.clone(), TextureOptions::default())
+        });
+        let [width, height] = self.image.size;
+        let size = Vec2::new(width as f32, height as f32);
+        egui::Area::new(Id::new("julia_preview"))
+            .order(Order::Tooltip)
+            .fixed_pos(pointer_pos + POINTER_OFFSET)
+            .interactable(false)
+            .show(ctx, |ui| {
+                let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+                let uv = egui::Rect::from_min_max(Pos2::ZERO, Pos2::new(1., 1.));
+                ui.painter().image(texture.id(), rect, uv, Color32::WHITE);
+                ui.painter()
+                    .rect_stroke(rect, 0., Stroke::new(1., Color32::GRAY));
+            });
+    }
+}
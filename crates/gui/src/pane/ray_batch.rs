@@ -0,0 +1,108 @@
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use rayon::prelude::*;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Arc;
+use web_time::{Duration, Instant};
+
+/// Time spent tracing rays on the UI thread in each frame, if there are no worker threads.
+const FRAME_BUDGET: Duration = Duration::from_millis(12);
+
+type TracedRay = (RationalAngle, Option<Vec<Cplx>>);
+
+/// Many external rays traced concurrently, arriving over several frames.
+pub(super) struct RayBatch<P>
+where
+    P: Displayable,
+{
+    plane: Arc<P>,
+    /// Angles not yet traced, if they are traced on the UI thread.
+    queued: VecDeque<RationalAngle>,
+    finished: Receiver<TracedRay>,
+    total: usize,
+    done: usize,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl<P> RayBatch<P>
+where
+    P: Displayable + Clone + 'static,
+{
+    pub fn start(plane: &P, angles: Vec<RationalAngle>) -> Self
+    {
+        let plane = Arc::new(plane.clone());
+        let total = angles.len();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let (sender, finished) = channel();
+
+        let queued = if super::background::has_worker_threads() {
+            let plane = Arc::clone(&plane);
+            let cancelled = Arc::clone(&cancelled);
+            super::background::spawn_background(move || {
+                angles.into_par_iter().for_each_with(sender, |sender, angle| {
+                    if !cancelled.load(Ordering::Relaxed) {
+                        // The receiver is gone if the batch was cancelled
+                        let _ = sender.send((angle, plane.external_ray(angle)));
+                    }
+                });
+            });
+            VecDeque::new()
+        } else {
+            angles.into()
+        };
+
+        Self {
+            plane,
+            queued,
+            finished,
+            total,
+            done: 0,
+            cancelled,
+        }
+    }
+
+    /// The rays traced since the last poll, tracing some first if there are no worker threads.
+    /// Rays that could not be traced are counted as done but left out.
+    pub fn poll(&mut self) -> Vec<(RationalAngle, Vec<Cplx>)>
+    {
+        let mut traced = Vec::new();
+
+        let start = Instant::now();
+        while start.elapsed() < FRAME_BUDGET {
+            let Some(angle) = self.queued.pop_front() else {
+                break;
+            };
+            traced.push((angle, self.plane.external_ray(angle)));
+        }
+        traced.extend(self.finished.try_iter());
+
+        self.done += traced.len();
+        traced
+            .into_iter()
+            .filter_map(|(angle, ray)| Some((angle, ray?)))
+            .collect()
+    }
+
+    /// Number of rays traced so far, and in total.
+    pub const fn progress(&self) -> (usize, usize)
+    {
+        (self.done, self.total)
+    }
+
+    pub const fn is_done(&self) -> bool
+    {
+        self.done >= self.total
+    }
+}
+
+impl<P> Drop for RayBatch<P>
+where
+    P: Displayable,
+{
+    fn drop(&mut self)
+    {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,136 @@
+use dynamo_color::palette::DiscretePalette;
+use dynamo_common::prelude::*;
+use egui::{Color32, Context, Pos2, Rect, Sense, Ui, Vec2};
+
+/// Largest number of bars in the histogram of escape iterations. Longer ranges of iterations
+/// are grouped into bins.
+const MAX_BARS: usize = 48;
+const BAR_WIDTH: f32 = 6.;
+const HISTOGRAM_HEIGHT: f32 = 80.;
+const ESCAPE_COLOR: Color32 = Color32::GRAY;
+const LIMIT_COLOR: Color32 = Color32::LIGHT_BLUE;
+
+/// Window summarizing the image in a pane. Returns false once the window has been closed.
+pub(super) fn show(
+    ctx: &Context,
+    title: String,
+    stats: &PlaneStatistics,
+    palette: &DiscretePalette,
+) -> bool
+{
+    let mut open = true;
+    egui::Window::new(title)
+        .open(&mut open)
+        .resizable(false)
+        .show(ctx, |ui| {
+            egui::Grid::new("area_fractions")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    for (name, fraction) in [
+                        ("Interior", stats.interior_fraction()),
+                        ("Exterior", stats.exterior_fraction()),
+                        ("Undetermined", stats.undetermined_fraction()),
+                    ] {
+                        ui.label(name);
+                        ui.label(format!("{:.2}%", 100. * fraction));
+                        ui.end_row();
+                    }
+                });
+
+            ui.separator();
+            ui.label("Periods");
+            let period_bars: Vec<_> = stats
+                .periods
+                .iter()
+                .map(|(&period, &count)| {
+                    let color = palette.map(period as f32, 1.);
+                    (format!("Period {period}"), count, color)
+                })
+                .collect();
+            histogram(ui, &period_bars);
+
+            ui.separator();
+            ui.label("Escape iterations");
+            histogram(ui, &escape_bars(stats));
+
+            if let (Some(mean), Some((&peak, _))) = (
+                stats.mean_iteration_limit(),
+                stats.iteration_limits.last_key_value(),
+            ) {
+                ui.separator();
+                ui.label("Iteration limits");
+                let limit_bars: Vec<_> = stats
+                    .iteration_limits
+                    .iter()
+                    .map(|(&limit, &count)| (format!("Limit {limit}"), count, LIMIT_COLOR))
+                    .collect();
+                histogram(ui, &limit_bars);
+                ui.label(format!("Effective limit: {mean:.0} of {peak} iterations"));
+            }
+        });
+    open
+}
+
+/// Group the escape iterations into at most [`MAX_BARS`] bins of equal width.
+fn escape_bars(stats: &PlaneStatistics) -> Vec<(String, usize, Color32)>
+{
+    let (Some((&first, _)), Some((&last, _))) = (
+        stats.escape_iters.first_key_value(),
+        stats.escape_iters.last_key_value(),
+    ) else {
+        return Vec::new();
+    };
+    let width = (last - first) / MAX_BARS as IterCount + 1;
+    let num_bars = ((last - first) / width + 1) as usize;
+    let mut counts = vec![0; num_bars];
+    for (&iters, &count) in &stats.escape_iters {
+        counts[((iters - first) / width) as usize] += count;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let start = first + i as IterCount * width;
+            let label = if width == 1 {
+                format!("{start} iterations")
+            } else {
+                format!("{start}-{end} iterations", end = start + width - 1)
+            };
+            (label, count, ESCAPE_COLOR)
+        })
+        .collect()
+}
+
+/// Draw labeled bars, with heights proportional to their counts. The label and count of a bar
+/// are shown on hover.
+fn histogram(ui: &mut Ui, bars: &[(String, usize, Color32)])
+{
+    if bars.is_empty() {
+        ui.label("None");
+        return;
+    }
+    let max_count = bars.iter().map(|(_, count, _)| *count).max().unwrap_or(1).max(1);
+    let size = Vec2::new(BAR_WIDTH * bars.len() as f32, HISTOGRAM_HEIGHT);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    let mut hovered = None;
+    for (i, (_, count, color)) in bars.iter().enumerate() {
+        let left = (i as f32).mul_add(BAR_WIDTH, rect.left());
+        let height = HISTOGRAM_HEIGHT * *count as f32 / max_count as f32;
+        let bar = Rect::from_min_max(
+            Pos2::new(left, rect.bottom() - height),
+            Pos2::new(left + BAR_WIDTH - 1., rect.bottom()),
+        );
+        painter.rect_filled(bar, 0., *color);
+        if response
+            .hover_pos()
+            .is_some_and(|pos| (left..left + BAR_WIDTH).contains(&pos.x))
+        {
+            hovered = Some(i);
+        }
+    }
+    if let Some((label, count, _)) = hovered.map(|i| &bars[i]) {
+        response.on_hover_text(format!("{label}: {count} pixels"));
+    }
+}
@@ -1,6 +1,9 @@
+use dynamo_common::math_utils::hyperbolic_distance;
 use dynamo_common::prelude::*;
+use std::fmt::Write;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use web_time::Instant;
 
 use crate::dialog::RayParams;
 
@@ -38,7 +41,7 @@ impl RepeatableTask
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PaneTasks
 {
@@ -46,6 +49,8 @@ pub struct PaneTasks
     pub draw: RepeatableTask,
     pub orbit: OrbitTask,
     pub follow: FollowState,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub color_cycle: ColorCycle,
 }
 
 impl PaneTasks
@@ -59,7 +64,73 @@ impl PaneTasks
             draw: task,
             orbit: OrbitTask::Disabled,
             follow: FollowState::Idle,
+            color_cycle: ColorCycle::new(),
+        }
+    }
+}
+
+/// Color cycling, which advances the phase of the palette steadily over time. Only the image is
+/// redrawn as the phase changes, so the points are not recomputed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColorCycle
+{
+    /// Palette periods advanced per second. Negative rates cycle backwards.
+    pub rate: f64,
+    running: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_step: Option<Instant>,
+}
+
+impl Default for ColorCycle
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl ColorCycle
+{
+    pub const DEFAULT_RATE: f64 = 0.25;
+    pub const MAX_RATE: f64 = 4.;
+
+    #[must_use]
+    pub const fn new() -> Self
+    {
+        Self {
+            rate: Self::DEFAULT_RATE,
+            running: false,
+            last_step: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_running(&self) -> bool
+    {
+        self.running
+    }
+
+    pub fn set_running(&mut self, running: bool)
+    {
+        self.running = running;
+        self.last_step = None;
+    }
+
+    /// Phase shift since the previous step, if cycling. The first step after starting only
+    /// records the time.
+    #[must_use]
+    pub fn pop(&mut self) -> Option<f64>
+    {
+        if !self.running {
+            return None;
         }
+        let now = Instant::now();
+        let elapsed = self
+            .last_step
+            .replace(now)
+            .map(|last| now.duration_since(last).as_secs_f64())?;
+        Some(self.rate * elapsed)
     }
 }
 
@@ -157,6 +228,99 @@ impl std::fmt::Display for FollowState
     }
 }
 
+/// Interaction mode in which clicks pick the ends of a segment to measure, instead of selecting
+/// points. While the second end is being picked, it follows the pointer.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Ruler
+{
+    #[default]
+    Inactive,
+    PickStart,
+    PickEnd
+    {
+        start: Cplx, end: Cplx
+    },
+    Measured
+    {
+        start: Cplx, end: Cplx
+    },
+}
+
+impl Ruler
+{
+    pub fn toggle(&mut self)
+    {
+        *self = if self.is_active() {
+            Self::Inactive
+        } else {
+            Self::PickStart
+        };
+    }
+
+    #[must_use]
+    pub const fn is_active(&self) -> bool
+    {
+        !matches!(self, Self::Inactive)
+    }
+
+    /// Move the pointer to `point`, clicking if `clicked`. Clicking after a measurement starts a
+    /// new one.
+    pub fn process_pointer(&mut self, point: Cplx, clicked: bool)
+    {
+        *self = match *self {
+            Self::Inactive => Self::Inactive,
+            Self::PickStart | Self::Measured { .. } if clicked => Self::PickEnd {
+                start: point,
+                end: point,
+            },
+            Self::PickEnd { start, .. } if clicked => Self::Measured { start, end: point },
+            Self::PickEnd { start, .. } => Self::PickEnd { start, end: point },
+            other => other,
+        };
+    }
+
+    /// The segment being measured, from its first end to its second.
+    #[must_use]
+    pub const fn segment(&self) -> Option<(Cplx, Cplx)>
+    {
+        match *self {
+            Self::PickEnd { start, end } | Self::Measured { start, end } => Some((start, end)),
+            _ => None,
+        }
+    }
+
+    /// Difference, modulus and argument of the segment, and, if `hyperbolic`, the distance
+    /// between its ends in the Poincaré metric on the unit disk.
+    #[must_use]
+    pub fn describe(&self, hyperbolic: bool) -> String
+    {
+        let Some((start, end)) = self.segment() else {
+            return match self {
+                Self::PickStart => "Ruler: click to pick the first point.".to_owned(),
+                _ => String::new(),
+            };
+        };
+        let diff = end - start;
+        let mut description = format!(
+            "Ruler from {start:.DISPLAY_PREC$} to {end:.DISPLAY_PREC$}\n\
+            Difference: {diff:.DISPLAY_PREC$}\n\
+            Modulus: {modulus:.DISPLAY_PREC$}\n\
+            Argument: {arg:.6} rad ({degrees:.4}°)",
+            modulus = diff.norm(),
+            arg = diff.arg(),
+            degrees = diff.arg().to_degrees(),
+        );
+        if hyperbolic {
+            if let Some(dist) = hyperbolic_distance(start, end) {
+                write!(description, "\nHyperbolic distance in disk: {dist:.DISPLAY_PREC$}")
+                    .unwrap_or_default();
+            }
+        }
+        description
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OrbitTask
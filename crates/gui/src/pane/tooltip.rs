@@ -0,0 +1,119 @@
+use super::background::{has_worker_threads, spawn_background};
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use egui::{Align2, Context, Id, Order, Pos2, Vec2};
+use std::sync::mpsc::{channel, Receiver};
+use web_time::{Duration, Instant};
+
+/// Time the pointer must rest on a point before its orbit is summarized.
+const DWELL: Duration = Duration::from_millis(400);
+/// Offset of the tooltip from the pointer, above and to the right of it.
+const POINTER_OFFSET: Vec2 = Vec2::new(18., -18.);
+
+/// Summary of the orbit of the point under the pointer, shown in a tooltip once the pointer has
+/// rested there for a moment. Orbits are computed on a worker thread where possible, and the last
+/// summary is kept until the plane changes.
+#[derive(Default)]
+pub(super) struct OrbitTooltip
+{
+    /// Point under the pointer, and when the pointer arrived at it.
+    hovered: Option<(Cplx, Instant)>,
+    /// Point whose orbit is being computed, and where its summary will arrive.
+    pending: Option<(Cplx, Receiver<String>)>,
+    summary: Option<(Cplx, String)>,
+}
+
+impl OrbitTooltip
+{
+    /// Forget the last summary, e.g. after the plane has changed.
+    pub fn invalidate(&mut self)
+    {
+        self.pending = None;
+        self.summary = None;
+    }
+
+    /// Show the summary of the orbit of `start` next to the pointer, once it is known. `point` is
+    /// the point of the view under the pointer, which `start` is the image of under the view
+    /// transform. Points closer than `tolerance` are considered the same, so that the pointer may
+    /// wobble within a pixel.
+    pub fn show<P>(
+        &mut self,
+        ctx: &Context,
+        plane: &P,
+        point: Cplx,
+        start: Cplx,
+        pointer_pos: Pos2,
+        tolerance: Real,
+    ) where
+        P: Displayable + Clone + 'static,
+    {
+        let is_hovered = |p: &Cplx| (p - point).norm() < tolerance;
+        let arrival = match self.hovered {
+            Some((p, arrival)) if is_hovered(&p) => arrival,
+            _ => {
+                let now = Instant::now();
+                self.hovered = Some((point, now));
+                now
+            }
+        };
+
+        if let Some((p, summary)) = self
+            .pending
+            .as_ref()
+            .and_then(|(p, receiver)| Some((*p, receiver.try_recv().ok()?)))
+        {
+            self.summary = Some((p, summary));
+            self.pending = None;
+        }
+
+        if let Some((_, summary)) = self.summary.as_ref().filter(|(p, _)| is_hovered(p)) {
+            show_tooltip(ctx, pointer_pos, summary);
+            return;
+        }
+
+        let elapsed = arrival.elapsed();
+        if elapsed < DWELL {
+            ctx.request_repaint_after(DWELL - elapsed);
+            return;
+        }
+        if !self.pending.as_ref().is_some_and(|(p, _)| is_hovered(p)) {
+            self.pending = Some((point, spawn_summary(plane, start)));
+        }
+        ctx.request_repaint();
+    }
+}
+
+/// Summarize the orbit of `point`, on a worker thread if there is one.
+fn spawn_summary<P>(plane: &P, point: Cplx) -> Receiver<String>
+where
+    P: Displayable + Clone + 'static,
+{
+    let plane = plane.clone();
+    let (sender, receiver) = channel();
+    let job = move || {
+        let conf = plane.orbit_summary_conf();
+        let info = plane.get_orbit_and_info(point).info;
+        // The receiver is gone if the pointer has moved on
+        let _ = sender.send(info.summary(&conf));
+    };
+    if has_worker_threads() {
+        spawn_background(job);
+    } else {
+        job();
+    }
+    receiver
+}
+
+fn show_tooltip(ctx: &Context, pointer_pos: Pos2, summary: &str)
+{
+    egui::Area::new(Id::new("orbit_tooltip"))
+        .order(Order::Tooltip)
+        .pivot(Align2::LEFT_BOTTOM)
+        .fixed_pos(pointer_pos + POINTER_OFFSET)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.label(summary);
+            });
+        });
+}
@@ -0,0 +1,90 @@
+use dynamo_common::prelude::*;
+use egui::{Align2, Color32, Context, FontId, Pos2, Rect, Sense, Shape, Stroke, Ui, Vec2};
+
+const TRACE_WIDTH: f32 = 480.;
+const TRACE_HEIGHT: f32 = 90.;
+const TRACE_COLORS: [Color32; 2] = [Color32::LIGHT_GREEN, Color32::LIGHT_BLUE];
+const LABEL_FONT: FontId = FontId::proportional(11.);
+
+/// Window plotting the real and imaginary parts of the selected orbit against time, one above
+/// the other. Returns false once the window has been closed.
+pub(super) fn show(ctx: &Context, title: String, orbit: &[Cplx], labels: [&str; 2]) -> bool
+{
+    let mut open = true;
+    egui::Window::new(title)
+        .open(&mut open)
+        .resizable(false)
+        .show(ctx, |ui| {
+            if orbit.len() < 2 {
+                ui.label("Select a point to trace its orbit.");
+                return;
+            }
+            let parts: [fn(&Cplx) -> Real; 2] = [|z| z.re, |z| z.im];
+            for ((part, label), color) in parts.into_iter().zip(labels).zip(TRACE_COLORS) {
+                let values: Vec<Real> = orbit.iter().map(part).collect();
+                ui.label(label);
+                trace(ui, &values, color);
+            }
+            ui.label(format!("{} iterates", orbit.len()));
+        });
+    open
+}
+
+/// Plot the values as a polyline, scaled to fill the plot vertically. The range of values is
+/// printed in the corners, and the value under the pointer is shown on hover.
+fn trace(ui: &mut Ui, values: &[Real], color: Color32)
+{
+    let (rect, response) =
+        ui.allocate_exact_size(Vec2::new(TRACE_WIDTH, TRACE_HEIGHT), Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0., Color32::from_gray(20));
+
+    let finite = values.iter().copied().filter(|v| v.is_finite());
+    let lo = finite.clone().fold(Real::INFINITY, Real::min);
+    let hi = finite.fold(Real::NEG_INFINITY, Real::max);
+    if lo > hi {
+        return;
+    }
+    let span = (hi - lo).max(Real::EPSILON);
+
+    let dx = rect.width() / (values.len() - 1) as f32;
+    let to_screen = |n: usize, v: Real| {
+        let t = ((v - lo) / span) as f32;
+        Pos2::new((n as f32).mul_add(dx, rect.left()), rect.bottom() - t * rect.height())
+    };
+    let points: Vec<Pos2> = values
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.is_finite())
+        .map(|(n, &v)| to_screen(n, v))
+        .collect();
+    painter.add(Shape::line(points, Stroke::new(1., color)));
+
+    let text_color = Color32::GRAY;
+    painter.text(
+        rect.left_top(),
+        Align2::LEFT_TOP,
+        format!("{hi:.4}"),
+        LABEL_FONT,
+        text_color,
+    );
+    painter.text(
+        rect.left_bottom(),
+        Align2::LEFT_BOTTOM,
+        format!("{lo:.4}"),
+        LABEL_FONT,
+        text_color,
+    );
+
+    if let Some(pos) = response.hover_pos() {
+        let n = (((pos.x - rect.left()) / dx).round() as usize).min(values.len() - 1);
+        if !values[n].is_finite() {
+            return;
+        }
+        let x = to_screen(n, lo).x;
+        painter.vline(x, rect.y_range(), Stroke::new(1., Color32::DARK_GRAY));
+        let marker = Rect::from_center_size(to_screen(n, values[n]), Vec2::splat(4.));
+        painter.rect_filled(marker, 0., color);
+        response.on_hover_text(format!("n = {n}: {value:.6}", value = values[n]));
+    }
+}
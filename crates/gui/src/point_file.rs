@@ -0,0 +1,201 @@
+//! Reading lists of points to mark from files.
+//!
+//! Two formats are understood, chosen by the file extension:
+//!
+//! * CSV (the default): one point per line, as `re, im[, label[, color]]`. Blank lines and lines
+//!   starting with `#` are skipped, as is a header line.
+//! * JSON: an array whose entries are either `[re, im]` or objects with the fields `re` and `im`
+//!   and optionally `label` and `color`.
+//!
+//! Colors are given in hex, as `#rrggbb` or `#rrggbbaa`.
+use std::path::Path;
+
+use dynamo_common::types::{Cplx, Real};
+use dynamo_core::error::DynamoError;
+use egui::Color32;
+use serde_json::Value;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Color of loaded points that do not specify their own.
+pub const DEFAULT_COLOR: Color32 = Color32::from_rgb(0, 200, 255);
+
+/// A point read from a file, with an optional label.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LabeledPoint
+{
+    pub point: Cplx,
+    pub label: Option<String>,
+    pub color: Color32,
+}
+
+#[derive(Debug)]
+pub enum PointFileError
+{
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// A line or entry that could not be understood, numbered from 1.
+    Invalid
+    {
+        entry: usize,
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for PointFileError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::Json(e) => write!(f, "invalid JSON: {e}"),
+            Self::Invalid { entry, reason } => write!(f, "entry {entry}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for PointFileError {}
+
+impl From<PointFileError> for DynamoError
+{
+    fn from(e: PointFileError) -> Self
+    {
+        match e {
+            PointFileError::Io(e) => Self::Io(e),
+            e => Self::Format(e.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for PointFileError
+{
+    fn from(e: std::io::Error) -> Self
+    {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for PointFileError
+{
+    fn from(e: serde_json::Error) -> Self
+    {
+        Self::Json(e)
+    }
+}
+
+/// Read the points listed in a file, in the format given by its extension.
+pub fn load(path: &Path) -> Result<Vec<LabeledPoint>, PointFileError>
+{
+    let content = std::fs::read_to_string(path)?;
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    if is_json {
+        parse_json(&content)
+    } else {
+        parse_csv(&content)
+    }
+}
+
+pub fn parse_csv(content: &str) -> Result<Vec<LabeledPoint>, PointFileError>
+{
+    let mut points = Vec::new();
+    let mut first_line = true;
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let invalid = |reason: String| PointFileError::Invalid { entry: i + 1, reason };
+
+        // Only the first line may be a header, which names the columns instead of giving numbers
+        let is_header = fields.iter().all(|field| field.parse::<Real>().is_err());
+        if std::mem::take(&mut first_line) && is_header {
+            continue;
+        }
+
+        let coords = match fields.as_slice() {
+            [re, im, ..] => re.parse::<Real>().and_then(|re| Ok((re, im.parse::<Real>()?))),
+            _ => return Err(invalid("expected at least two fields".to_owned())),
+        };
+        let Ok((re, im)) = coords else {
+            return Err(invalid(format!("could not read coordinates from `{line}`")));
+        };
+
+        let label = fields
+            .get(2)
+            .filter(|label| !label.is_empty())
+            .map(|&label| label.to_owned());
+        let color = match fields.get(3).filter(|color| !color.is_empty()) {
+            Some(hex) => parse_color(hex).map_err(invalid)?,
+            None => DEFAULT_COLOR,
+        };
+        points.push(LabeledPoint {
+            point: Cplx::new(re, im),
+            label,
+            color,
+        });
+    }
+    Ok(points)
+}
+
+pub fn parse_json(content: &str) -> Result<Vec<LabeledPoint>, PointFileError>
+{
+    let Value::Array(entries) = serde_json::from_str(content)? else {
+        return Err(PointFileError::Invalid {
+            entry: 0,
+            reason: "expected an array of points".to_owned(),
+        });
+    };
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            parse_json_entry(entry).map_err(|reason| PointFileError::Invalid {
+                entry: i + 1,
+                reason,
+            })
+        })
+        .collect()
+}
+
+fn parse_json_entry(entry: &Value) -> Result<LabeledPoint, String>
+{
+    let number = |value: Option<&Value>, name: &str| {
+        value
+            .and_then(Value::as_f64)
+            .ok_or_else(|| format!("missing or invalid `{name}`"))
+    };
+    match entry {
+        Value::Array(coords) => Ok(LabeledPoint {
+            point: Cplx::new(number(coords.first(), "re")?, number(coords.get(1), "im")?),
+            label: None,
+            color: DEFAULT_COLOR,
+        }),
+        Value::Object(fields) => {
+            let label = match fields.get("label") {
+                None | Some(Value::Null) => None,
+                Some(Value::String(label)) => Some(label.clone()),
+                Some(other) => Some(other.to_string()),
+            };
+            let color = match fields.get("color").and_then(Value::as_str) {
+                Some(hex) => parse_color(hex)?,
+                None => DEFAULT_COLOR,
+            };
+            Ok(LabeledPoint {
+                point: Cplx::new(number(fields.get("re"), "re")?, number(fields.get("im"), "im")?),
+                label,
+                color,
+            })
+        }
+        _ => Err("expected `[re, im]` or an object with fields `re` and `im`".to_owned()),
+    }
+}
+
+fn parse_color(hex: &str) -> Result<Color32, String>
+{
+    Color32::from_hex(hex).map_err(|_| format!("invalid color `{hex}`"))
+}
@@ -0,0 +1,74 @@
+//! Saving traced external rays to JSON files and reading them back.
+//!
+//! A file holds an object with a list of rays, each with its angle written as a fraction, the
+//! points traced along it from the outside in, and its landing point:
+//!
+//! ```json
+//! { "rays": [ { "angle": "1/7", "landing_point": [-0.12, 0.64], "points": [[...], ...] } ] }
+//! ```
+//!
+//! The landing point is only informative; it is the last of the points.
+use std::path::Path;
+
+use dynamo_common::rational_angle::RationalAngle;
+use dynamo_common::types::Cplx;
+use serde_json::{json, Value};
+
+type Error = Box<dyn std::error::Error>;
+
+pub fn save(path: &Path, rays: &[(RationalAngle, Vec<Cplx>)]) -> Result<(), Error>
+{
+    let rays: Vec<_> = rays
+        .iter()
+        .map(|(angle, points)| {
+            json!({
+                "angle": angle.to_string(),
+                "landing_point": points.last().map(|z| [z.re, z.im]),
+                "points": points.iter().map(|z| [z.re, z.im]).collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+    let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    serde_json::to_writer(file, &json!({ "rays": rays }))?;
+    Ok(())
+}
+
+pub fn load(path: &Path) -> Result<Vec<(RationalAngle, Vec<Cplx>)>, Error>
+{
+    let content = std::fs::read_to_string(path)?;
+    let value: Value = serde_json::from_str(&content)?;
+    let rays = value
+        .get("rays")
+        .and_then(Value::as_array)
+        .ok_or("expected an object with a list of rays")?;
+    rays.iter()
+        .enumerate()
+        .map(|(i, ray)| parse_ray(ray).map_err(|e| format!("ray {}: {e}", i + 1).into()))
+        .collect()
+}
+
+fn parse_ray(ray: &Value) -> Result<(RationalAngle, Vec<Cplx>), String>
+{
+    let angle = ray
+        .get("angle")
+        .and_then(Value::as_str)
+        .ok_or("missing angle")?;
+    let angle = angle
+        .parse::<RationalAngle>()
+        .map_err(|_| format!("invalid angle `{angle}`"))?;
+    let points = ray
+        .get("points")
+        .and_then(Value::as_array)
+        .ok_or("missing points")?
+        .iter()
+        .map(|point| match point.as_array().map(Vec::as_slice) {
+            Some([re, im]) => re
+                .as_f64()
+                .zip(im.as_f64())
+                .map(|(re, im)| Cplx::new(re, im))
+                .ok_or_else(|| format!("invalid point {point}")),
+            _ => Err(format!("invalid point {point}")),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((angle, points))
+}
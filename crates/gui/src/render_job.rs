@@ -0,0 +1,89 @@
+use crate::marked_points::Marking;
+use dynamo_color::prelude::*;
+use dynamo_common::prelude::*;
+use dynamo_render::color_layered;
+use image::{DynamicImage, ImageResult, Pixel};
+use std::path::{Path, PathBuf};
+
+/// Exported image, in RGB, or in RGBA if some points are left transparent.
+pub type RenderedImage = DynamicImage;
+
+/// Color the computed image, with the layers composited over the base coloring, and draw the
+/// marked curves, points and labels on it. Markings stay opaque where they cross transparent
+/// points.
+pub fn export_image<D>(
+    iter_plane: &IterPlane<D>,
+    coloring: &Coloring,
+    layers: &LayerStack,
+    marking: &Marking,
+    transparency: Transparency,
+) -> RenderedImage
+where
+    D: Polar<Real>,
+{
+    let grid = &iter_plane.point_grid;
+    let image = color_layered(iter_plane, coloring, layers, transparency);
+    if transparency == Transparency::Opaque {
+        let mut image = image.into_rgb8();
+        marking.mark_image(grid, &mut image);
+        return image.into();
+    }
+
+    let mut image = image.into_rgba8();
+    let unmarked = DynamicImage::from(image.clone()).into_rgb8();
+    let mut marked = unmarked.clone();
+    marking.mark_image(grid, &mut marked);
+    for ((pixel, before), after) in image
+        .pixels_mut()
+        .zip(unmarked.pixels())
+        .zip(marked.pixels())
+    {
+        if before != after {
+            *pixel = after.to_rgba();
+        }
+    }
+    image.into()
+}
+
+/// A self-contained image export. Everything needed to compute and color the image is captured
+/// when the job is created, so it can be run on a worker thread while the panes keep changing.
+pub struct RenderJob
+{
+    pub description: String,
+    pub path: PathBuf,
+    render: Box<dyn FnOnce() -> RenderedImage + Send>,
+}
+
+impl RenderJob
+{
+    #[must_use]
+    pub fn new<F>(description: String, path: &Path, render: F) -> Self
+    where
+        F: FnOnce() -> RenderedImage + Send + 'static,
+    {
+        Self {
+            description,
+            path: path.to_owned(),
+            render: Box::new(render),
+        }
+    }
+
+    /// Compute the image and write it to `self.path`. This blocks until the file is saved.
+    pub fn run(self) -> ImageResult<PathBuf>
+    {
+        let image = (self.render)();
+        image.save(&self.path)?;
+        Ok(self.path)
+    }
+}
+
+impl std::fmt::Debug for RenderJob
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.debug_struct("RenderJob")
+            .field("description", &self.description)
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
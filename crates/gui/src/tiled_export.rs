@@ -0,0 +1,73 @@
+use crate::render_job::RenderedImage;
+use image::{ColorType, GenericImageView};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Approximate number of pixels computed at once during a tiled export.
+const STRIP_PIXELS: usize = 1 << 22;
+
+#[must_use]
+pub fn rows_per_strip(width: usize) -> usize
+{
+    (STRIP_PIXELS / width.max(1)).max(1)
+}
+
+#[must_use]
+pub fn is_png(path: &Path) -> bool
+{
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("png"))
+}
+
+/// Write a `width` by `height` PNG one horizontal strip at a time, so that only a single
+/// strip is ever held in memory.
+///
+/// `render_strip(first_row, num_rows)` is called for consecutive strips from top to bottom,
+/// and must return an image `width` pixels wide and `num_rows` pixels tall, in RGBA if `alpha`
+/// is set and in RGB otherwise.
+pub fn save_png_in_strips<F>(
+    path: &Path,
+    width: usize,
+    height: usize,
+    alpha: bool,
+    mut render_strip: F,
+) -> Result<(), Box<dyn Error>>
+where
+    F: FnMut(usize, usize) -> RenderedImage,
+{
+    let (color_type, expected_color) = if alpha {
+        (png::ColorType::Rgba, ColorType::Rgba8)
+    } else {
+        (png::ColorType::Rgb, ColorType::Rgb8)
+    };
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(file, u32::try_from(width)?, u32::try_from(height)?);
+    encoder.set_color(color_type);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut stream = encoder.write_header()?.into_stream_writer()?;
+
+    let strip_height = rows_per_strip(width);
+    for first_row in (0..height).step_by(strip_height) {
+        let num_rows = strip_height.min(height - first_row);
+        let strip = render_strip(first_row, num_rows);
+        if strip.dimensions() != (u32::try_from(width)?, u32::try_from(num_rows)?) {
+            return Err(format!(
+                "Strip at row {first_row} has size {:?}, expected ({width}, {num_rows})",
+                strip.dimensions()
+            )
+            .into());
+        }
+        if strip.color() != expected_color {
+            return Err(format!(
+                "Strip at row {first_row} has color type {:?}, expected {expected_color:?}",
+                strip.color()
+            )
+            .into());
+        }
+        stream.write_all(strip.as_bytes())?;
+    }
+    stream.finish()?;
+    Ok(())
+}
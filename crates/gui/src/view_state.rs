@@ -0,0 +1,20 @@
+use dynamo_color::Palette;
+use dynamo_common::prelude::*;
+
+/// Enough of the state of a pane to reproduce what it shows.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaneView
+{
+    pub bounds: Bounds,
+    pub selection: Cplx,
+    pub palette: Palette,
+    pub rays: Vec<RationalAngle>,
+}
+
+/// Enough of the state of an interface to reproduce what it shows, e.g. from a shared link.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewState
+{
+    pub parent: PaneView,
+    pub child: PaneView,
+}
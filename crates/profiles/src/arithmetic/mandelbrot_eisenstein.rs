@@ -1,3 +1,4 @@
+use super::{power, Modulus};
 use crate::macros::{basic_plane_impl, degree_impl, profile_imports};
 use dynamo_color::prelude::*;
 use dynamo_common::cache::Cache;
@@ -5,42 +6,58 @@ profile_imports!();
 
 type EInt = EisensteinInteger;
 
+/// Polynomials `z^D + c` acting on the residues of the Eisenstein integers modulo a prime, i.e. on
+/// a finite field.
 #[derive(Clone, Debug)]
-pub struct EisensteinMandel<const A: i64, const B: i64>
+pub struct EisensteinMandel<const D: i64>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
     max_iter: IterCount,
+    modulus: Modulus<EInt>,
     cache: Cache<(EInt, EInt), PointInfo<EInt>>,
 }
 
-impl<const A: i64, const B: i64> EisensteinMandel<A, B>
+impl<const D: i64> EisensteinMandel<D>
 {
-    const MOD: EInt = EInt::new(A, B);
+    const FAMILY: &'static str = match D {
+        2 => "Mandelbrot",
+        3 => "Cubic",
+        _ => "Unicritical",
+    };
 }
 
-impl<const A: i64, const B: i64> Default for EisensteinMandel<A, B>
+impl<const D: i64> Default for EisensteinMandel<D>
 {
     fn default() -> Self
     {
-        let bounds = Bounds::centered_square(Self::MOD.norm());
+        let modulus = Modulus::default();
+        let bounds = Bounds::centered_square(modulus.norm());
         let point_grid = PointGrid::new_by_res_y(1024, bounds);
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
             max_iter: 1024,
+            modulus,
             cache: Cache::new(),
         }
     }
 }
 
-impl<const A: i64, const B: i64> DynamicalFamily for EisensteinMandel<A, B>
+impl<const D: i64> DynamicalFamily for EisensteinMandel<D>
 {
     basic_plane_impl!();
     type Var = EInt;
     type Param = EInt;
     type Deriv = EInt;
-    type MetaParam = NoParam;
+    type MetaParam = Modulus<EInt>;
+
+    /// Orbits stay among the finitely many residues, so they never escape.
+    #[inline]
+    fn escape_test(&self) -> EscapeTest
+    {
+        EscapeTest::Never
+    }
 
     #[inline]
     fn early_bailout(&self, start: Self::Var, c: &Self::Param) -> Option<PointInfo<Self::Deriv>>
@@ -50,12 +67,36 @@ impl<const A: i64, const B: i64> DynamicalFamily for EisensteinMandel<A, B>
 
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
-        (z * z + *c) % Self::MOD
+        (power(z, D) + *c) % self.modulus.value()
     }
 
     fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
     {
-        ((z * z + *c) % Self::MOD, (2 * z) % Self::MOD)
+        let modulus = self.modulus.value();
+        let df = D * power(z, D - 1);
+        ((power(z, D) + *c) % modulus, df % modulus)
+    }
+
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        self.modulus
+    }
+
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.modulus
+    }
+
+    fn set_meta_param(&mut self, modulus: Self::MetaParam)
+    {
+        self.set_param(modulus);
+    }
+
+    /// The cached results are only valid for the previous modulus, so they are discarded.
+    fn set_param(&mut self, modulus: <Self::MetaParam as ParamList>::Param)
+    {
+        self.modulus = modulus;
+        self.cache = Cache::new();
     }
 
     fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
@@ -63,15 +104,24 @@ impl<const A: i64, const B: i64> DynamicalFamily for EisensteinMandel<A, B>
         Self::Var::default()
     }
 
+    #[inline]
+    fn param_map(&self, point: Cplx) -> Self::Param
+    {
+        point.into()
+    }
+}
+
+impl<const D: i64> DisplayDefaults for EisensteinMandel<D>
+{
     fn name(&self) -> String
     {
-        format!("Eisenstein Integer Mandelbrot mod {}", Self::MOD)
+        format!("Eisenstein Integer {} mod {}", Self::FAMILY, self.modulus)
     }
 
     fn preperiod_coloring(&self) -> IncoloringAlgorithm
     {
         IncoloringAlgorithm::PreperiodPeriod {
-            fill_rate: 8.0 / Self::MOD.norm(),
+            fill_rate: 8.0 / self.modulus.norm(),
         }
     }
 
@@ -79,55 +129,51 @@ impl<const A: i64, const B: i64> DynamicalFamily for EisensteinMandel<A, B>
     {
         IncoloringAlgorithm::PotentialAndPeriod {
             periodicity_tolerance: self.periodicity_tolerance(),
-            crit_degree: 2.0,
-            fill_rate: 8.0 / Self::MOD.norm(),
+            crit_degree: D as f64,
+            fill_rate: 8.0 / self.modulus.norm(),
         }
     }
 
-    #[inline]
-    fn param_map(&self, point: Cplx) -> Self::Param
-    {
-        point.into()
-    }
-}
-
-impl<const A: i64, const B: i64> FamilyDefaults for EisensteinMandel<A, B>
-{
     fn default_bounds(&self) -> Bounds
     {
-        Bounds::centered_square(Self::MOD.norm())
+        Bounds::centered_square(self.modulus.norm())
     }
 
     fn default_coloring(&self) -> Coloring
     {
+        if D != 2 {
+            return Coloring::default().with_interior_algorithm(IncoloringAlgorithm::CycleLength {
+                field_size: self.modulus.norm(),
+            });
+        }
         let mut coloring = Coloring::default();
-        // coloring.get_period_coloring_mut().num_colors = Self::MOD.norm() as f32;
+        // coloring.get_period_coloring_mut().num_colors = self.modulus.norm() as f32;
         coloring.get_period_coloring_mut().num_colors = 19.;
         coloring.with_interior_algorithm(IncoloringAlgorithm::Period)
     }
+
+    fn default_coloring_child(&self) -> Coloring
+    {
+        self.default_coloring()
+    }
 }
 
-impl<const A: i64, const B: i64> HasJulia for EisensteinMandel<A, B>
+impl<const D: i64> HasJulia for EisensteinMandel<D>
 {
     fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
     {
         self.default_bounds()
     }
-
-    fn default_coloring_child(&self) -> Coloring
-    {
-        self.default_coloring()
-    }
 }
 
-impl<const A: i64, const B: i64> InfinityFirstReturnMap for EisensteinMandel<A, B>
+impl<const D: i64> InfinityFirstReturnMap for EisensteinMandel<D>
 {
-    degree_impl!(2);
+    degree_impl!(D);
 }
 
-impl<const A: i64, const B: i64> MarkedPoints for EisensteinMandel<A, B> {}
+impl<const D: i64> MarkedPoints for EisensteinMandel<D> {}
 
-impl<const A: i64, const B: i64> EscapeEncoding for EisensteinMandel<A, B>
+impl<const D: i64> EscapeEncoding for EisensteinMandel<D>
 {
     fn encode_escape_result(
         &self,
@@ -141,7 +187,7 @@ impl<const A: i64, const B: i64> EscapeEncoding for EisensteinMandel<A, B>
                 mut info,
                 final_value,
             } => {
-                info.multiplier = info.multiplier % Self::MOD;
+                info.multiplier = info.multiplier % self.modulus.value();
                 self.identify_marked_points(final_value, c, info)
             }
             EscapeResult::Bounded(_) => PointInfo::Bounded,
@@ -160,6 +206,7 @@ impl<const A: i64, const B: i64> EscapeEncoding for EisensteinMandel<A, B>
             return PointInfo::Escaping {
                 potential: (iters - 1) as IterCountSmooth,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -174,8 +221,9 @@ impl<const A: i64, const B: i64> EscapeEncoding for EisensteinMandel<A, B>
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
 
-impl<const A: i64, const B: i64> ExternalRays for EisensteinMandel<A, B> {}
+impl<const D: i64> ExternalRays for EisensteinMandel<D> {}
@@ -1,3 +1,4 @@
+use super::{power, Modulus};
 use crate::macros::{basic_plane_impl, degree_impl, profile_imports};
 use dynamo_color::prelude::*;
 use dynamo_common::cache::Cache;
@@ -5,42 +6,58 @@ profile_imports!();
 
 type GInt = GaussianInteger;
 
+/// Polynomials `z^D + c` acting on the residues of the Gaussian integers modulo a prime, i.e. on
+/// a finite field.
 #[derive(Clone, Debug)]
-pub struct GaussianMandel<const A: i64, const B: i64>
+pub struct GaussianMandel<const D: i64>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
     max_iter: IterCount,
+    modulus: Modulus<GInt>,
     cache: Cache<(GInt, GInt), PointInfo<GInt>>,
 }
 
-impl<const A: i64, const B: i64> GaussianMandel<A, B>
+impl<const D: i64> GaussianMandel<D>
 {
-    const MOD: GInt = GInt::new(A, B);
+    const FAMILY: &'static str = match D {
+        2 => "Mandelbrot",
+        3 => "Cubic",
+        _ => "Unicritical",
+    };
 }
 
-impl<const A: i64, const B: i64> Default for GaussianMandel<A, B>
+impl<const D: i64> Default for GaussianMandel<D>
 {
     fn default() -> Self
     {
-        let bounds = Bounds::square(Self::MOD.norm() / 2.0, Cplx::from(Self::MOD) / 2.0);
+        let modulus = Modulus::default();
+        let bounds = Bounds::square(modulus.norm() / 2.0, Cplx::from(modulus.value()) / 2.0);
         let point_grid = PointGrid::new_by_res_y(1024, bounds);
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
             max_iter: 1024,
+            modulus,
             cache: Cache::new(),
         }
     }
 }
 
-impl<const A: i64, const B: i64> DynamicalFamily for GaussianMandel<A, B>
+impl<const D: i64> DynamicalFamily for GaussianMandel<D>
 {
     basic_plane_impl!();
     type Var = GInt;
     type Param = GInt;
     type Deriv = GInt;
-    type MetaParam = NoParam;
+    type MetaParam = Modulus<GInt>;
+
+    /// Orbits stay among the finitely many residues, so they never escape.
+    #[inline]
+    fn escape_test(&self) -> EscapeTest
+    {
+        EscapeTest::Never
+    }
 
     #[inline]
     fn early_bailout(&self, start: Self::Var, c: &Self::Param) -> Option<PointInfo<GInt>>
@@ -50,12 +67,36 @@ impl<const A: i64, const B: i64> DynamicalFamily for GaussianMandel<A, B>
 
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
-        (z * z + *c) % Self::MOD
+        (power(z, D) + *c) % self.modulus.value()
     }
 
     fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
     {
-        ((z * z + *c) % Self::MOD, (2 * z) % Self::MOD)
+        let modulus = self.modulus.value();
+        let df = D * power(z, D - 1);
+        ((power(z, D) + *c) % modulus, df % modulus)
+    }
+
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        self.modulus
+    }
+
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.modulus
+    }
+
+    fn set_meta_param(&mut self, modulus: Self::MetaParam)
+    {
+        self.set_param(modulus);
+    }
+
+    /// The cached results are only valid for the previous modulus, so they are discarded.
+    fn set_param(&mut self, modulus: <Self::MetaParam as ParamList>::Param)
+    {
+        self.modulus = modulus;
+        self.cache = Cache::new();
     }
 
     fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
@@ -63,15 +104,24 @@ impl<const A: i64, const B: i64> DynamicalFamily for GaussianMandel<A, B>
         Self::Var::default()
     }
 
+    #[inline]
+    fn param_map(&self, point: Cplx) -> Self::Param
+    {
+        point.into()
+    }
+}
+
+impl<const D: i64> DisplayDefaults for GaussianMandel<D>
+{
     fn name(&self) -> String
     {
-        format!("Gaussian Integer Mandelbrot mod {}", Self::MOD)
+        format!("Gaussian Integer {} mod {}", Self::FAMILY, self.modulus)
     }
 
     fn preperiod_coloring(&self) -> IncoloringAlgorithm
     {
         IncoloringAlgorithm::PreperiodPeriod {
-            fill_rate: 8.0 / Self::MOD.norm(),
+            fill_rate: 8.0 / self.modulus.norm(),
         }
     }
 
@@ -79,54 +129,50 @@ impl<const A: i64, const B: i64> DynamicalFamily for GaussianMandel<A, B>
     {
         IncoloringAlgorithm::PotentialAndPeriod {
             periodicity_tolerance: self.periodicity_tolerance(),
-            crit_degree: 2.0,
-            fill_rate: 8.0 / Self::MOD.norm(),
+            crit_degree: D as f64,
+            fill_rate: 8.0 / self.modulus.norm(),
         }
     }
 
-    #[inline]
-    fn param_map(&self, point: Cplx) -> Self::Param
-    {
-        point.into()
-    }
-}
-
-impl<const A: i64, const B: i64> FamilyDefaults for GaussianMandel<A, B>
-{
     fn default_bounds(&self) -> Bounds
     {
-        Bounds::square(Self::MOD.norm() / 2.0, Cplx::from(Self::MOD) / 2.0)
+        Bounds::square(self.modulus.norm() / 2.0, Cplx::from(self.modulus.value()) / 2.0)
     }
 
     fn default_coloring(&self) -> Coloring
     {
+        if D != 2 {
+            return Coloring::default().with_interior_algorithm(IncoloringAlgorithm::CycleLength {
+                field_size: self.modulus.norm(),
+            });
+        }
         let mut coloring = Coloring::default();
-        coloring.get_period_coloring_mut().num_colors = Self::MOD.norm() as f32;
+        coloring.get_period_coloring_mut().num_colors = self.modulus.norm() as f32;
         coloring.with_interior_algorithm(IncoloringAlgorithm::Period)
     }
+
+    fn default_coloring_child(&self) -> Coloring
+    {
+        self.default_coloring()
+    }
 }
 
-impl<const A: i64, const B: i64> HasJulia for GaussianMandel<A, B>
+impl<const D: i64> HasJulia for GaussianMandel<D>
 {
     fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
     {
         self.default_bounds()
     }
-
-    fn default_coloring_child(&self) -> Coloring
-    {
-        self.default_coloring()
-    }
 }
 
-impl<const A: i64, const B: i64> InfinityFirstReturnMap for GaussianMandel<A, B>
+impl<const D: i64> InfinityFirstReturnMap for GaussianMandel<D>
 {
-    degree_impl!(2);
+    degree_impl!(D);
 }
 
-impl<const A: i64, const B: i64> MarkedPoints for GaussianMandel<A, B> {}
+impl<const D: i64> MarkedPoints for GaussianMandel<D> {}
 
-impl<const A: i64, const B: i64> EscapeEncoding for GaussianMandel<A, B>
+impl<const D: i64> EscapeEncoding for GaussianMandel<D>
 {
     fn encode_escape_result(
         &self,
@@ -140,7 +186,7 @@ impl<const A: i64, const B: i64> EscapeEncoding for GaussianMandel<A, B>
                 mut info,
                 final_value,
             } => {
-                info.multiplier = info.multiplier % Self::MOD;
+                info.multiplier = info.multiplier % self.modulus.value();
                 self.identify_marked_points(final_value, c, info)
             }
             EscapeResult::Bounded(_) => PointInfo::Bounded,
@@ -159,6 +205,7 @@ impl<const A: i64, const B: i64> EscapeEncoding for GaussianMandel<A, B>
             return PointInfo::Escaping {
                 potential: (iters - 1) as IterCountSmooth,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -173,8 +220,9 @@ impl<const A: i64, const B: i64> EscapeEncoding for GaussianMandel<A, B>
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
 
-impl<const A: i64, const B: i64> ExternalRays for GaussianMandel<A, B> {}
+impl<const D: i64> ExternalRays for GaussianMandel<D> {}
@@ -1,5 +1,16 @@
+pub mod modulus;
+pub use modulus::{Modulus, QuadraticRing};
+
 pub mod mandelbrot_eisenstein;
 pub use mandelbrot_eisenstein::EisensteinMandel;
 
 pub mod mandelbrot_gaussian;
 pub use mandelbrot_gaussian::GaussianMandel;
+
+/// `z^n` for `n >= 1`, by repeated multiplication.
+fn power<R>(z: R, n: i64) -> R
+where
+    R: Copy + std::ops::Mul<Output = R>,
+{
+    (1..n).fold(z, |w, _| w * z)
+}
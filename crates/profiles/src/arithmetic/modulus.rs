@@ -0,0 +1,288 @@
+use crate::macros::profile_imports;
+use dynamo_common::math_utils::arithmetic::is_prime;
+profile_imports!();
+
+/// Rings of quadratic integers whose residues can be iterated in an arithmetic plane.
+pub trait QuadraticRing: Copy + Eq + std::fmt::Display
+{
+    /// Name of the ring, e.g. `Z[i]`.
+    const RING: &'static str;
+    /// Symbols accepted for the generator of the ring when parsing a modulus.
+    const UNITS: &'static [char];
+    /// The only rational prime that ramifies in the ring.
+    const RAMIFIED: u64;
+
+    fn from_parts(a: i64, b: i64) -> Self;
+
+    fn norm_int(self) -> i64;
+
+    /// Whether the odd rational prime `p` stays prime in the ring.
+    fn is_inert(p: u64) -> bool;
+}
+
+impl QuadraticRing for GaussianInteger
+{
+    const RING: &'static str = "Z[i]";
+    const UNITS: &'static [char] = &['i', 'I'];
+    const RAMIFIED: u64 = 2;
+
+    fn from_parts(a: i64, b: i64) -> Self
+    {
+        Self::new(a, b)
+    }
+
+    fn norm_int(self) -> i64
+    {
+        self.a.pow(2) + self.b.pow(2)
+    }
+
+    fn is_inert(p: u64) -> bool
+    {
+        p % 4 == 3
+    }
+}
+
+impl QuadraticRing for EisensteinInteger
+{
+    const RING: &'static str = "Z[ω]";
+    const UNITS: &'static [char] = &['ω', 'w', 'W'];
+    const RAMIFIED: u64 = 3;
+
+    fn from_parts(a: i64, b: i64) -> Self
+    {
+        Self::new(a, b)
+    }
+
+    fn norm_int(self) -> i64
+    {
+        self.a.pow(2) + self.b.pow(2) - self.a * self.b
+    }
+
+    fn is_inert(p: u64) -> bool
+    {
+        p % 3 == 2
+    }
+}
+
+/// A prime of a ring of quadratic integers, modulo which an arithmetic plane is iterated.
+///
+/// Only primes are accepted, so that the residues form a finite field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modulus<R>
+{
+    value: R,
+}
+
+impl<R: QuadraticRing> Modulus<R>
+{
+    /// Largest norm accepted, which bounds both the size of the plane and of its cache.
+    const MAX_NORM: i64 = 1 << 20;
+
+    /// Validate `value` as a prime of the ring.
+    ///
+    /// # Errors
+    ///
+    /// Returns an explanation if `value` is zero, a unit, composite, or too large.
+    pub fn new(value: R) -> Result<Self, String>
+    {
+        let norm = value.norm_int();
+        if norm == 0 {
+            return Err("The modulus must be nonzero.".to_owned());
+        }
+        if norm == 1 {
+            return Err(format!("{value} is a unit of {}.", R::RING));
+        }
+        if norm > Self::MAX_NORM {
+            return Err(format!(
+                "{value} has norm {norm}, but at most {} is supported.",
+                Self::MAX_NORM
+            ));
+        }
+
+        // A prime of the ring has either prime norm, or norm p^2 for an inert rational prime p.
+        let norm = norm as u64;
+        if is_prime(norm) {
+            return Ok(Self { value });
+        }
+        let p = (norm as f64).sqrt().round() as u64;
+        if p * p == norm && is_prime(p) {
+            if p == R::RAMIFIED {
+                return Err(format!(
+                    "{value} is not prime: {p} ramifies in {}, so {value} is the square of a \
+                        prime of norm {p}.",
+                    R::RING
+                ));
+            }
+            if R::is_inert(p) {
+                return Ok(Self { value });
+            }
+            return Err(format!(
+                "{value} is not prime: {p} splits in {} as a product of two primes of norm {p}.",
+                R::RING
+            ));
+        }
+        Err(format!(
+            "{value} is not prime, since its norm {norm} is neither a prime nor the square of \
+                a prime."
+        ))
+    }
+
+    #[must_use]
+    pub const fn value(&self) -> R
+    {
+        self.value
+    }
+
+    #[must_use]
+    pub fn norm(&self) -> Real
+    {
+        self.value.norm_int() as Real
+    }
+
+    /// Parse a modulus written as `a`, `a, b`, or `a + bi` (with `ω` or `w` for Eisenstein
+    /// integers).
+    fn parse_parts(text: &str) -> Option<(i64, i64)>
+    {
+        let text: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+        if let Some((a, b)) = text.split_once(',') {
+            return Some((a.parse().ok()?, b.parse().ok()?));
+        }
+        if text.is_empty() {
+            return None;
+        }
+
+        // Split into signed terms, keeping repeated signs such as "+-" together
+        let mut terms = vec![String::new()];
+        for c in text.chars() {
+            let last = terms.last_mut()?;
+            if (c == '+' || c == '-') && last.chars().any(|d| d != '+' && d != '-') {
+                terms.push(c.to_string());
+            } else {
+                last.push(c);
+            }
+        }
+
+        let (mut a, mut b) = (0_i64, 0_i64);
+        for term in terms {
+            let (coeff, is_unit) = match term.strip_suffix(R::UNITS) {
+                Some(coeff) => (coeff.strip_suffix('*').unwrap_or(coeff), true),
+                None => (term.as_str(), false),
+            };
+            let coeff = coeff.strip_prefix('+').unwrap_or(coeff);
+            let coeff = match coeff {
+                "" if is_unit => 1,
+                "-" if is_unit => -1,
+                _ => coeff.parse().ok()?,
+            };
+            if is_unit {
+                b = b.checked_add(coeff)?;
+            } else {
+                a = a.checked_add(coeff)?;
+            }
+        }
+        Some((a, b))
+    }
+}
+
+impl<R: QuadraticRing> std::str::FromStr for Modulus<R>
+{
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err>
+    {
+        let (a, b) = Self::parse_parts(text).ok_or_else(|| {
+            format!(
+                "Could not read \"{}\" as an element of {}.",
+                text.trim(),
+                R::RING
+            )
+        })?;
+        if a.unsigned_abs().max(b.unsigned_abs()) > Self::MAX_NORM as u64 {
+            return Err(format!("{} is too large.", text.trim()));
+        }
+        Self::new(R::from_parts(a, b))
+    }
+}
+
+impl Default for Modulus<GaussianInteger>
+{
+    fn default() -> Self
+    {
+        Self {
+            value: GaussianInteger::new(7, 0),
+        }
+    }
+}
+
+impl Default for Modulus<EisensteinInteger>
+{
+    fn default() -> Self
+    {
+        Self {
+            value: EisensteinInteger::new(5, 0),
+        }
+    }
+}
+
+impl<R: QuadraticRing> std::fmt::Display for Modulus<R>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.value.fmt(f)
+    }
+}
+
+impl<R> Named for Modulus<R>
+{
+    fn name(&self) -> &'static str
+    {
+        "Modulus"
+    }
+}
+
+impl<R: QuadraticRing> Describe for Modulus<R>
+{
+    fn describe(&self, _desc_conf: &DescriptionConf) -> Option<String>
+    {
+        Some(self.to_string())
+    }
+}
+
+impl<R> ParamList for Modulus<R>
+where
+    R: QuadraticRing,
+    Self: Default,
+{
+    type Param = Self;
+
+    fn local_param(&self) -> &Self::Param
+    {
+        self
+    }
+    fn into_local_param(self) -> Self::Param
+    {
+        self
+    }
+}
+
+impl<R: QuadraticRing> HasSliders for Modulus<R>
+{
+    fn text_entry(&self) -> Option<MetaParamText>
+    {
+        Some(MetaParamText {
+            name: "Modulus".to_owned(),
+            value: self.to_string(),
+            prompt: format!(
+                "Enter a prime of {} as a + b{}, or as a, b.",
+                R::RING,
+                R::UNITS[0]
+            ),
+        })
+    }
+
+    fn set_text_entry(&mut self, text: &str) -> Result<(), String>
+    {
+        *self = text.parse()?;
+        Ok(())
+    }
+}
@@ -204,4 +204,1017 @@ mod tests
         let q = plane.escape_coeff(&c);
         assert!((q - 0.119_960_462_401_084).norm_sqr() < 1e-12);
     }
+
+    #[test]
+    fn simd_matches_scalar()
+    {
+        fn check<P: EscapeEncoding<Deriv = Cplx>>(plane: &P)
+        {
+            let iter_plane = plane.compute();
+            let mut orbit = orbit::CycleDetected::new(plane);
+            for ((x, y), info) in iter_plane.iter_counts.indexed_iter() {
+                orbit.reset(plane.point_grid().map_pixel(x, y));
+                assert_eq!(*info, orbit.run_until_complete());
+            }
+        }
+        let plane = Mandelbrot::default().with_res_y(61);
+        check(&plane);
+        check(&JuliaSet::from(plane));
+        check(&CubicPer1_0::default().with_res_y(61));
+    }
+
+    #[test]
+    fn potential_normalization()
+    {
+        // Smooth iteration counts should not depend on how far the orbit is followed, even when
+        // the map at infinity is not monic
+        let plane = QuadRatPer2::default();
+        let c = plane.param_map(Cplx::new(0.3, 0.2));
+        let mut z = Cplx::new(3., 1.);
+        let mut counts = vec![];
+        for iters in (0..20).step_by(2) {
+            if (1e6..1e16).contains(&z.norm_sqr()) {
+                counts.push(plane.smooth_iter_count(iters, z, &c));
+            }
+            z = plane.map(plane.map(z, &c), &c);
+        }
+        dbg!(&counts);
+        assert!(counts.len() > 1);
+        assert!(counts.iter().all(|count| (count - counts[0]).abs() < 1e-6));
+    }
+
+    #[test]
+    fn smooth_preperiod()
+    {
+        // The smooth preperiod should vary continuously where the detected preperiod jumps
+        let plane = Mandelbrot::default();
+        let mut results = vec![];
+        for i in 0..200 {
+            let selection = Cplx::new(-0.1 + 0.001 * f64::from(i), 0.3);
+            let orbit = orbit::CycleDetected::new(&plane).init(selection);
+            if let Some((_, Some(EscapeResult::Periodic { info, .. }))) = orbit.last() {
+                let (pre, per) = (info.preperiod as f64, f64::from(info.period));
+                assert!((pre - 2. * per..=pre).contains(&info.smooth_preperiod));
+                results.push((pre, info.smooth_preperiod));
+            }
+        }
+        let max_jump = |f: fn(&(f64, f64)) -> f64| {
+            results
+                .windows(2)
+                .map(|w| (f(&w[1]) - f(&w[0])).abs())
+                .fold(0., f64::max)
+        };
+        let (jump, smooth_jump) = (max_jump(|r| r.0), max_jump(|r| r.1));
+        dbg!(jump, smooth_jump);
+        assert_eq!(results.len(), 200);
+        assert!(jump >= 2.);
+        assert!(smooth_jump < 0.5);
+    }
+
+    #[test]
+    fn interior_distance_estimate()
+    {
+        // The real period 3 component of the Mandelbrot set has its center at about -1.7549, and
+        // meets the real axis in (-1.7685, -1.75)
+        use orbit::Orbit;
+        fn estimate<P: EscapeEncoding<Deriv = Cplx>>(plane: &P, t: Cplx) -> (Real, Period)
+        {
+            let mut orbit = orbit::DistanceEstimation::new(plane);
+            orbit.reset(t);
+            let result = orbit.run_until_complete();
+            dbg!(&result);
+            let PointInfo::InteriorDistanceEstimate { distance, period } = result else {
+                panic!("Expected an interior distance estimate");
+            };
+            (distance, period)
+        }
+        let plane = Mandelbrot::default();
+        let cover = CoveringMap::new(Mandelbrot::default(), |t| (2. * t, Cplx::new(2., 0.)));
+        for (c, exact) in [(-1.7549, 0.0049), (-1.76, 0.0085)] {
+            let (distance, period) = estimate(&plane, Cplx::new(c, 0.));
+            assert_eq!(period, 3);
+            assert!((exact / 4.0..=exact * 4.0).contains(&distance));
+
+            // Distances on the cover are measured in its own coordinate
+            let (cover_distance, _) = estimate(&cover, Cplx::new(c / 2., 0.));
+            assert!((cover_distance - distance / 2.).abs() < 1e-6 * distance);
+        }
+    }
+
+    #[test]
+    fn misiurewicz_spiral()
+    {
+        // The critical orbit of z^2 + i lands on the 2-cycle {-1 + i, -i}, with multiplier 4 + 4i
+        let plane = Mandelbrot::default();
+        let o = OrbitSchema {
+            period: 2,
+            preperiod: 2,
+        };
+        let start = Cplx::new(0.05, 1.05);
+        let spiral = plane
+            .misiurewicz_spiral(start, o)
+            .expect("Failed to converge");
+        dbg!(&spiral);
+        assert!(spiral.center.dist_sqr(Cplx::new(0., 1.)) < 1e-10);
+        assert!(spiral.multiplier.dist_sqr(Cplx::new(4., 4.)) < 1e-8);
+        assert!((spiral.rotation() - 0.125).abs() < 1e-8);
+
+        let curve = spiral.spiral(start, 2, 16);
+        assert_eq!(curve.len(), 33);
+        assert!(curve[16].dist_sqr(spiral.image(start, 1)) < 1e-20);
+        assert!(curve[32].dist_sqr(spiral.image(start, 2)) < 1e-20);
+    }
+
+    #[test]
+    fn multiplier_locus()
+    {
+        // The period 2 component of the Mandelbrot set is the disk |c + 1| < 1/4, and the cycle
+        // doubles its period at c = -5/4
+        let plane = Mandelbrot::default();
+        let locus = plane
+            .multiplier_locus(Cplx::new(-1.1, 0.05), 2, Bifurcation::PeriodDoubling)
+            .expect("Failed to converge");
+        dbg!(locus.len(), locus[0]);
+        assert!(locus.len() > 1000);
+        assert!(locus[0].dist_sqr(Cplx::new(-1.25, 0.)) < 1e-12);
+        assert!(locus
+            .iter()
+            .all(|c| ((c + 1.).norm() - 0.25).abs() < 1e-8));
+
+        // The main cardioid has its root at c = 1/4
+        let locus = plane
+            .multiplier_locus(Cplx::new(0.1, 0.2), 1, Bifurcation::Root)
+            .expect("Failed to converge");
+        assert!(locus[0].dist_sqr(Cplx::new(0.25, 0.)) < 1e-12);
+        assert!(locus.last().unwrap().dist_sqr(locus[0]) < 1e-12);
+    }
+
+    #[test]
+    fn find_nearby_multiplier()
+    {
+        // On the main cardioid, the fixed point has multiplier λ at c = λ/2 - λ²/4
+        let plane = Mandelbrot::default();
+        let multiplier = Cplx::from_polar(1., TAU / 3.);
+        let c = plane
+            .find_nearby_multiplier(Cplx::new(-0.1, 0.5), 1, multiplier)
+            .expect("Failed to converge");
+        dbg!(c);
+        assert!(c.dist_sqr(0.5 * multiplier - 0.25 * multiplier * multiplier) < 1e-20);
+
+        // The center of the period 2 component
+        let c = plane
+            .find_nearby_multiplier(Cplx::new(-1.1, 0.05), 2, Cplx::new(0., 0.))
+            .expect("Failed to converge");
+        assert!(c.dist_sqr(Cplx::new(-1., 0.)) < 1e-20);
+    }
+
+    #[test]
+    fn pcf_parameters()
+    {
+        let plane = Mandelbrot::default().with_res_y(128);
+        let params = plane.pcf_parameters(2, 3, 24);
+        dbg!(params.len());
+        let count = |o: OrbitSchema| params.iter().filter(|p| p.orbit_schema == o).count();
+
+        // The centers of the hyperbolic components of period up to 3
+        for (period, centers) in [(1, 1), (2, 1), (3, 3)] {
+            assert_eq!(count(OrbitSchema { period, preperiod: 0 }), centers);
+        }
+
+        // The critical orbit of z^2 + i lands on the 2-cycle {-1 + i, -i} after two iterations
+        let o = OrbitSchema {
+            period: 2,
+            preperiod: 2,
+        };
+        for c in [Cplx::new(0., 1.), Cplx::new(0., -1.)] {
+            assert!(params
+                .iter()
+                .any(|p| p.orbit_schema == o && p.point.dist_sqr(c) < 1e-16));
+        }
+    }
+
+    #[test]
+    fn pcf_catalog()
+    {
+        let plane = Mandelbrot::default();
+        let catalog = plane.pcf_catalog(3);
+        dbg!(catalog.len());
+        let angles_at = |c: Cplx| {
+            catalog
+                .iter()
+                .find(|entry| entry.parameter.point.dist_sqr(c) < 1e-16)
+                .map(|entry| entry.angles.clone())
+        };
+
+        // The two rays of period 2 land at the root of the component centered at -1
+        let third = |k| RationalAngle::new(k, 3);
+        assert_eq!(angles_at(Cplx::new(-1., 0.)), Some(vec![third(1), third(2)]));
+        assert_eq!(
+            angles_at(Cplx::new(-2., 0.)),
+            Some(vec![RationalAngle::new(1, 2)])
+        );
+        assert_eq!(
+            angles_at(Cplx::new(0., 1.)),
+            Some(vec![RationalAngle::new(1, 6)])
+        );
+
+        // Every center of period 3 is reached by a pair of rays
+        let o = OrbitSchema {
+            period: 3,
+            preperiod: 0,
+        };
+        let centers: Vec<_> = catalog
+            .iter()
+            .filter(|entry| entry.parameter.orbit_schema == o)
+            .collect();
+        assert_eq!(centers.len(), 3);
+        assert!(centers.iter().all(|entry| entry.angles.len() == 2));
+    }
+
+    #[test]
+    fn periodic_ray_landings()
+    {
+        use dynamo_core::dynamics::ray_landing::landing_classes;
+
+        // The rays of period 4 lead in pairs to the centers of the six components of period 4,
+        // each paired with its companion under Lavaurs' algorithm
+        let param_plane = Mandelbrot::default();
+        let tolerance = param_plane.point_grid().pixel_width();
+        let landings = param_plane.periodic_ray_landings(4);
+        assert_eq!(landings.len(), 12);
+        let classes = landing_classes(&landings, tolerance);
+        dbg!(&classes);
+        assert_eq!(classes.len(), 6);
+        for class in &classes {
+            let companion = class[0].with_degree(2).companion_angle();
+            assert_eq!(Some(class[1]), companion);
+        }
+
+        // In the Julia set of the rabbit, the rays at 1/7, 2/7 and 4/7 land together at the
+        // alpha fixed point, while 3/7, 6/7 and 5/7 land at a cycle of period 3.
+        let c = Cplx::new(-0.122_561_166_876_654, 0.744_861_766_619_744);
+        let dynam_plane = JuliaSet::from(param_plane).with_param(c);
+        let tolerance = dynam_plane.point_grid().pixel_width();
+        let landings = dynam_plane.periodic_ray_landings(3);
+        let classes = landing_classes(&landings, tolerance);
+        dbg!(&classes);
+        let seventh = |k| RationalAngle::new(k, 7);
+        assert_eq!(
+            classes,
+            vec![
+                vec![seventh(1), seventh(2), seventh(4)],
+                vec![seventh(3)],
+                vec![seventh(5)],
+                vec![seventh(6)],
+            ]
+        );
+    }
+
+    #[test]
+    fn mandelbrot_area()
+    {
+        // The area of the Mandelbrot set is about 1.5066
+        let plane = Mandelbrot::default();
+        let area = plane
+            .estimate_bounded_area(256, 3)
+            .expect("Not enough resolutions");
+        dbg!(area);
+        assert!((area.value - 1.5066).abs() < 0.01);
+        assert!(area.error < 0.05);
+    }
+
+    #[test]
+    fn orbit_steps()
+    {
+        let plane = Mandelbrot::default();
+        let c = Cplx::new(-0.1, 0.6);
+        let orbit::OrbitAndInfo { orbit, info } = plane.get_orbit_and_info(c);
+        let steps = plane.orbit_steps(&orbit, &info.param);
+        dbg!(&steps[..4]);
+
+        assert_eq!(steps.len(), orbit.len());
+        for (step, next) in steps.iter().zip(&steps[1..]) {
+            assert!((step.multiplier - 2. * step.z).norm() < 1e-12);
+            assert!((next.derivative - step.derivative * step.multiplier).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn renormalization_periods()
+    {
+        let plane = Mandelbrot::default();
+        let levels = |c: Cplx| {
+            let info = plane.get_orbit_and_info(c).info;
+            dbg!(c, &info.renormalization);
+            info.renormalization
+        };
+
+        // Period 4 in the period-2 copy along the real axis
+        assert_eq!(levels(Cplx::new(-1.3107, 0.)), vec![2]);
+        // Period 6 in the period-2 copy
+        assert_eq!(levels(Cplx::new(-1.138, 0.240)), vec![2]);
+        // Period 6 in the airplane copy
+        assert_eq!(levels(Cplx::new(-1.7729, 0.)), vec![3]);
+        // Period 3 satellite of the main cardioid, not renormalizable
+        assert_eq!(levels(Cplx::new(-0.122, 0.745)), vec![]);
+        assert_eq!(levels(Cplx::new(0.282, 0.530)), vec![]);
+    }
+
+    #[test]
+    fn quad_rat_per_n_continuation()
+    {
+        fn check<P>(plane: &P, period: Period)
+        where
+            P: DynamicalFamily<Var = Cplx, Param = CplxPair>,
+        {
+            for t in [Cplx::new(-3., 1.), Cplx::new(0.5, -2.), Cplx::new(2., 0.5)] {
+                let c = plane.param_map(t);
+                dbg!(t, c);
+                assert_eq!(c.a, t);
+                // The critical orbit 0 -> ∞ -> 1 -> ... returns to 0, and not before
+                let mut z = ONE;
+                for k in 2..period {
+                    assert!(z.norm() > 1e-3, "period {k} instead of {period}");
+                    z = plane.map(z, &c);
+                }
+                assert!(z.norm() < 1e-8);
+            }
+        }
+        check(&QuadRatPer6::default(), 6);
+        check(&QuadRatPer7::default(), 7);
+    }
+
+    #[test]
+    fn per_n_lambda_slider()
+    {
+        let lambda = Cplx::new(0.3, -0.8);
+        let t = Cplx::new(0.7, 0.2);
+
+        let mut plane = QuadRatPer1Lambda::default();
+        let mut meta_params = plane.get_meta_params();
+        meta_params.set_slider(0, lambda);
+        plane.set_meta_param(meta_params);
+        assert_eq!(plane.get_param(), lambda);
+
+        // The marked fixed point is -t/2
+        let c = plane.param_map(t);
+        let (z, mult) = plane.map_and_multiplier(-0.5 * t, &c);
+        dbg!(z, mult);
+        assert!((z + 0.5 * t).norm() < 1e-12);
+        assert!((mult - lambda).norm() < 1e-12);
+
+        let plane = QuadRatPer2Lambda::default().with_param(lambda);
+        let c = plane.param_map(t);
+        let mults: Vec<Cplx> = plane
+            .cycles_child(&c, 2)
+            .into_iter()
+            .map(|z| {
+                let (w, m0) = plane.map_and_multiplier(z, &c);
+                m0 * plane.map_and_multiplier(w, &c).1
+            })
+            .collect();
+        dbg!(&mults);
+        assert!(mults.iter().any(|m| (m - lambda).norm() < 1e-8));
+    }
+
+    #[test]
+    fn herman_ring_rotation_number()
+    {
+        let mut plane = HermanRing::default();
+        let mut meta_params = plane.get_meta_params();
+        meta_params.set_slider(1, Cplx::from(0.25));
+        plane.set_meta_param(meta_params);
+
+        // The map at the origin of the plane is a circle map with the chosen rotation number
+        let u = plane.param_map(ZERO);
+        let angle = u.arg() / TAU;
+        let rho = meta_params.rotation_number_at(angle);
+        dbg!(u, rho);
+        assert!((u.norm() - 1.).abs() < 1e-12);
+        assert!((rho - 0.25).abs() < 1e-3);
+
+        // The free critical points are exchanged by reflection in the unit circle
+        let crit = plane.critical_points_child(&u);
+        dbg!(&crit);
+        assert!((crit[0] * crit[1].conj() - 1.).norm() < 1e-12);
+        for z in &crit[..2] {
+            assert!(plane.map_and_multiplier(*z, &u).1.norm() < 1e-12);
+        }
+        let fixed = plane.cycles_child(&u, 1);
+        for z in fixed {
+            assert!((plane.map(z, &u) - z).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn equilibrium_measure()
+    {
+        let c = Cplx::new(-1., 0.);
+        let z = Cplx::new(0.3, -0.7);
+        let preimages = Mandelbrot::default().preimages(z, &c);
+        dbg!(&preimages);
+        assert_eq!(preimages.len(), 2);
+        for w in preimages {
+            assert!((Mandelbrot::default().map(w, &c) - z).norm() < 1e-12);
+        }
+
+        let param_plane = Mandelbrot::default().with_res_y(64);
+        assert!(param_plane.equilibrium_density(1000).is_none());
+
+        // The measure is symmetric under z -> -z and carried by the Julia set of the basilica
+        let julia = JuliaSet::from(param_plane).with_param(c);
+        let density = julia.equilibrium_density(100_000).unwrap();
+        let total: u32 = density.iter().sum();
+        dbg!(total);
+        assert!(total > 90_000);
+        let res_x = density.dim().0;
+        let left: u32 = density
+            .indexed_iter()
+            .filter(|((x, _), _)| 2 * x < res_x)
+            .map(|(_, count)| count)
+            .sum();
+        dbg!(left);
+        assert!((Real::from(left) / Real::from(total) - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn inverse_iteration()
+    {
+        let c = Cplx::new(-1., 0.);
+        let mut param_plane = Mandelbrot::default().with_res_y(128);
+        param_plane.set_compute_mode(ComputeMode::InverseIteration);
+
+        // Parameter planes are computed as usual
+        let iter_plane = param_plane.compute();
+        assert!(!iter_plane.iter_counts.iter().any(|p| matches!(p, PointInfo::Unknown)));
+
+        let mut julia = JuliaSet::from(param_plane).with_param(c);
+        julia.set_compute_mode(ComputeMode::InverseIteration);
+        let iter_plane = julia.compute();
+        let is_hit =
+            |x: usize, y: usize| matches!(iter_plane.iter_counts[(x, y)], PointInfo::Bounded);
+        let (res_x, res_y) = iter_plane.iter_counts.dim();
+        let hits: Vec<(usize, usize)> = (0..res_x)
+            .flat_map(|x| (0..res_y).map(move |y| (x, y)))
+            .filter(|&(x, y)| is_hit(x, y))
+            .collect();
+        dbg!(hits.len(), res_x * res_y);
+        assert!(hits.len() > 100);
+        assert!(hits.len() < res_x * res_y / 4);
+
+        // The outline is symmetric under z -> -z, up to a pixel
+        let mirrored = hits
+            .iter()
+            .filter(|&&(x, y)| {
+                let (x, y) = (res_x - 1 - x, res_y - 1 - y);
+                (x.saturating_sub(1)..=(x + 1).min(res_x - 1)).any(|x| {
+                    (y.saturating_sub(1)..=(y + 1).min(res_y - 1)).any(|y| is_hit(x, y))
+                })
+            })
+            .count();
+        dbg!(mirrored);
+        assert!(mirrored > hits.len() * 9 / 10);
+    }
+
+    #[test]
+    fn critical_relation_curve()
+    {
+        let plane = Mandelbrot::default().with_res_y(256);
+        let modulus = |t: Cplx| t.norm();
+
+        // The critical value c keeps its distance from the critical point 0 on circles
+        let t0 = Cplx::new(0.5, 0.);
+        let curve = plane.critical_relation_curve(t0, 1);
+        dbg!(curve.len());
+        assert!(curve.len() > 100);
+        assert!(curve.iter().all(|&t| (modulus(t) - 0.5).abs() < 1e-3));
+        let last = curve.last().unwrap();
+        assert!((last - t0).norm() < 0.05);
+
+        // The second iterate c^2 + c vanishes at the center of period 1 and 2
+        let t0 = Cplx::new(-0.3, 0.4);
+        let level = modulus(t0 * (t0 + 1.));
+        let curve = plane.critical_relation_curve(t0, 2);
+        assert!(curve.len() > 100);
+        assert!(curve
+            .iter()
+            .all(|&t| (modulus(t * (t + 1.)) - level).abs() < 1e-3));
+
+        let julia = JuliaSet::from(plane).with_param(t0);
+        assert!(julia.critical_relation_curve(ZERO, 1).is_empty());
+    }
+
+    #[test]
+    fn preimage_tree()
+    {
+        let param_plane = Mandelbrot::default().with_res_y(64);
+        assert!(param_plane.preimage_tree(ONE, 3, 0.).is_none());
+
+        // The preimages of 1 under z^2 are the roots of unity of order a power of 2
+        let julia = JuliaSet::from(param_plane).with_param(ZERO);
+        let levels = julia.preimage_tree(ONE, 4, 1e-9).unwrap();
+        let widths: Vec<usize> = levels.iter().map(Vec::len).collect();
+        assert_eq!(widths, [1, 2, 4, 8, 16]);
+        for (k, level) in levels.iter().enumerate().skip(1) {
+            for node in level {
+                let image = levels[k - 1][node.image].point;
+                assert!((node.point * node.point - image).norm() < 1e-12);
+                assert!((node.point.powi(1 << k) - ONE).norm() < 1e-9);
+            }
+        }
+
+        // Coarse pruning drops the preimages that come close to those found before
+        let levels = julia.preimage_tree(ONE, 4, 0.5).unwrap();
+        let widths: Vec<usize> = levels.iter().map(Vec::len).collect();
+        dbg!(&widths);
+        assert_eq!(widths[..3], [1, 2, 4]);
+        assert!(widths[4] < 16);
+    }
+
+    #[test]
+    fn arithmetic_modulus()
+    {
+        let gaussian = |text: &str| text.parse::<Modulus<GaussianInteger>>();
+        let eisenstein = |text: &str| text.parse::<Modulus<EisensteinInteger>>();
+
+        // Primes of prime norm, and rational primes that stay prime
+        assert_eq!(gaussian("2 + i").unwrap().value(), GaussianInteger::new(2, 1));
+        assert_eq!(gaussian("5, 2").unwrap().value(), GaussianInteger::new(5, 2));
+        assert_eq!(gaussian("-i + 1").unwrap().value(), GaussianInteger::new(1, -1));
+        assert!(gaussian("311").is_ok());
+        assert!(eisenstein("5+2w").is_ok());
+        assert!(eisenstein("2 + ω").is_ok());
+        assert!(eisenstein("17").is_ok());
+
+        // Rational primes that split or ramify, composites, units, and malformed input
+        dbg!(gaussian("5").unwrap_err());
+        assert!(gaussian("2").is_err());
+        assert!(eisenstein("3").is_err());
+        assert!(eisenstein("7").is_err());
+        assert!(gaussian("3 + 3i").is_err());
+        assert!(gaussian("-i").is_err());
+        assert!(gaussian("0").is_err());
+        assert!(gaussian("2 + j").is_err());
+        assert!(gaussian("-9223372036854775808").is_err());
+        assert!(eisenstein("1, -9223372036854775808").is_err());
+
+        // The displayed value can be entered again
+        let modulus = gaussian("7 - 2i").unwrap();
+        assert_eq!(gaussian(&modulus.to_string()), Ok(modulus));
+
+        let mut plane = GaussianMandel::<2>::default();
+        let bounds = plane.default_bounds();
+        let mut modulus = plane.get_meta_params();
+        assert!(modulus.set_text_entry("4").is_err());
+        modulus.set_text_entry("3").unwrap();
+        plane.set_meta_param(modulus);
+        dbg!(plane.name());
+        assert!(plane.name().ends_with(&modulus.to_string()));
+        assert!(plane.default_bounds().range_x() < bounds.range_x());
+    }
+
+    #[test]
+    fn cubic_residues()
+    {
+        use dynamo_color::prelude::IncoloringAlgorithm;
+
+        // The residues of the Gaussian integers modulo 7 form a field with 49 elements
+        let plane = GaussianMandel::<3>::default();
+        assert!(matches!(
+            plane.default_coloring().get_algorithm(),
+            IncoloringAlgorithm::CycleLength { field_size } if *field_size == 49.
+        ));
+
+        let c = GaussianInteger::new(2, 3);
+        let z = GaussianInteger::new(1, 5);
+        let (w, dw) = plane.map_and_multiplier(z, &c);
+        assert_eq!(w, plane.map(z, &c));
+        assert_eq!(w, (z * z * z + c) % GaussianInteger::new(7, 0));
+        assert_eq!(dw, (3 * z * z) % GaussianInteger::new(7, 0));
+
+        // Every orbit is eventually periodic, with a cycle no longer than the field
+        let iter_plane = plane.with_res_y(32).compute();
+        let periods: Vec<Period> =
+            iter_plane.iter_counts.iter().filter_map(PointInfo::period).collect();
+        dbg!(periods.iter().max());
+        assert_eq!(periods.len(), iter_plane.iter_counts.len());
+        assert!(periods.iter().all(|&period| (1..=49).contains(&period)));
+
+        let plane = EisensteinMandel::<3>::default();
+        dbg!(plane.name());
+        assert!(plane.name().contains("Cubic"));
+    }
+
+    #[test]
+    fn real_plane_2d()
+    {
+        use dynamo_common::types::variables::{Matrix2x2, Point};
+
+        // The classical Hénon attractor is chaotic, with Lyapunov exponent about 0.42
+        let henon = Henon::default();
+        let c = Point { x: 1.4, y: 0.3 };
+        let info = henon.lyapunov_info(henon.seed(&c), &c);
+        dbg!(&info);
+        let Some(PointInfo::Lyapunov { exponent }) = info else {
+            panic!("expected a Lyapunov exponent, got {info:?}");
+        };
+        assert!((exponent - 0.42).abs() < 0.03);
+
+        // ...while for small a, orbits are attracted to a fixed point
+        let c = Point { x: 0.2, y: 0.3 };
+        let info = henon.lyapunov_info(henon.seed(&c), &c);
+        assert!(matches!(info, Some(PointInfo::Lyapunov { exponent }) if exponent < 0.));
+
+        // Jacobians agree with finite differences
+        let z = Point { x: 0.3, y: -0.4 };
+        let c = Point { x: 0.9, y: 6. };
+        let ikeda = Ikeda::default();
+        let h = 1e-6;
+        let diff = |dz: Point| {
+            Cplx::from(ikeda.map_2d(z + dz, &c) - ikeda.map_2d(z - dz, &c)) / (2. * h)
+        };
+        let Matrix2x2 { v0, v1 } = ikeda.jacobian(z, &c);
+        assert!((diff(Point { x: h, y: 0. }) - Cplx::from(v0)).norm() < 1e-6);
+        assert!((diff(Point { x: 0., y: h }) - Cplx::from(v1)).norm() < 1e-6);
+
+        // Parameter planes have no attractor of their own
+        assert!(henon.attractor_density(1000).is_none());
+        let julia = JuliaSet::from(henon.with_res_y(64)).with_param(Point { x: 1.4, y: 0.3 });
+        let density = julia.attractor_density(10_000).unwrap();
+        let total: u32 = density.iter().sum();
+        dbg!(total);
+        assert!(total > 9_000);
+
+        let mut standard = JuliaSet::from(StandardMap::default().with_res_y(16));
+        standard.set_compute_mode(ComputeMode::Lyapunov);
+        let iter_plane = standard.compute();
+        assert!(iter_plane
+            .iter_counts
+            .iter()
+            .all(|info| matches!(info, PointInfo::Lyapunov { .. })));
+    }
+
+    #[test]
+    fn basins()
+    {
+        use std::collections::BTreeMap;
+
+        // At a = 3, the cube roots of unity are superattracting fixed points of the map, which
+        // share their period and so cannot be told apart by it
+        let c = Cplx::new(3., 0.);
+        let mut julia = JuliaSet::from(MinsikHanPhi::<3>::default().with_res_y(64)).with_param(c);
+        let iter_plane = julia.compute();
+        assert!(!iter_plane
+            .iter_counts
+            .iter()
+            .any(|info| matches!(info, PointInfo::MarkedPoint { .. })));
+
+        julia.set_compute_mode(ComputeMode::Basins);
+        let iter_plane = julia.compute();
+
+        let mut sizes = BTreeMap::new();
+        for info in &iter_plane.iter_counts {
+            if let PointInfo::MarkedPoint { data, class_id, .. } = info {
+                assert_eq!(data.period, 1);
+                *sizes.entry(class_id.0).or_insert(0) += 1;
+            }
+        }
+        dbg!(&sizes);
+        assert_eq!(sizes.len(), 3);
+        let total: usize = sizes.values().sum();
+        assert!(sizes.values().all(|&size| 4 * size > total));
+    }
+
+    #[test]
+    fn runtime_degrees()
+    {
+        let z = Cplx::new(0.3, -0.7);
+        let c = Cplx::new(-1.2, 0.4);
+
+        let unicritical = UnicriticalDyn::new(4);
+        let gradient = unicritical.gradient(z, &c);
+        dbg!(gradient);
+        assert_eq!(gradient, Unicritical::<4>::default().gradient(z, &c));
+
+        let mut chebyshev = ChebyshevDyn::default();
+        chebyshev.set_meta_param(ChebyshevHalfDegree(3));
+        assert_eq!(chebyshev.degree(), 6);
+        assert_eq!(chebyshev.gradient(z, &c), Chebyshev::<3>::default().gradient(z, &c));
+        assert_eq!(
+            chebyshev.critical_points_child(&c).len(),
+            Chebyshev::<3>::default().critical_points_child(&c).len()
+        );
+
+        let mcmullen = McMullenFamilyDyn::new(2, 5);
+        assert_eq!(mcmullen.name(), McMullenFamily::<2, 5>::default().name());
+        assert_eq!(
+            mcmullen.map_and_multiplier(z, &c),
+            McMullenFamily::<2, 5>::default().map_and_multiplier(z, &c)
+        );
+    }
+
+    #[test]
+    fn composed_family()
+    {
+        let z = Cplx::new(0.3, -0.7);
+        let c = Cplx::new(-1.2, 0.4);
+        let step = 1e-6;
+        let escapes = |result| matches!(result, EscapeResult::Escaped { .. });
+        let quadratic = Mandelbrot::default();
+
+        let twice = ComposedFamily::new(Mandelbrot::default(), Mandelbrot::default());
+        assert_eq!(twice.degree(), 4);
+        let (w, dw_dz, dw_dc) = twice.gradient(z, &c);
+        assert!((w - quadratic.map(quadratic.map(z, &c), &c)).norm() < 1e-12);
+        assert!(((twice.map(z + step, &c) - w) / step - dw_dz).norm() < 1e-4);
+        assert!(((twice.map(z, &(c + step)) - w) / step - dw_dc).norm() < 1e-4);
+
+        // Besides the critical point of the inner map, its preimages of the outer one
+        let critical_points = twice.critical_points_child(&c);
+        dbg!(&critical_points);
+        assert_eq!(critical_points.len(), 3);
+        assert!(critical_points
+            .iter()
+            .all(|&p| twice.map_and_multiplier(p, &c).1.norm() < 1e-12));
+
+        let h = Mobius::affine(Cplx::new(2., 1.), Cplx::new(0.5, -0.5));
+        let conjugate = ComposedFamily::conjugate(Mandelbrot::default(), h);
+        let w = conjugate.map(h.apply(z), &c);
+        assert!((w - h.apply(quadratic.map(z, &c))).norm() < 1e-12);
+        assert!((conjugate.escape_coeff(&c) - 1. / h.a).norm() < 1e-12);
+        assert_eq!(
+            escapes(conjugate.run_point(c)),
+            escapes(quadratic.run_point(c))
+        );
+
+        // Conjugating by an inversion moves the escaping region to a basin of a finite point
+        let inversion = Mobius {
+            a: ZERO,
+            b: ONE,
+            c: ONE,
+            d: ZERO,
+        };
+        let conjugate = ComposedFamily::conjugate(Mandelbrot::default(), inversion);
+        assert!(conjugate.degree_real().is_nan());
+        let (w, dw_dz, dw_dc) = conjugate.gradient(z, &c);
+        assert!(((conjugate.map(z + step, &c) - w) / step - dw_dz).norm() < 1e-4);
+        assert!(((conjugate.map(z, &(c + step)) - w) / step - dw_dc).norm() < 1e-4);
+
+        let perturbed = ComposedFamily::new(Mandelbrot::default(), Chebyshev::<1>::default());
+        assert!(!escapes(perturbed.run_point(ZERO)));
+        assert!(escapes(perturbed.run_point(Cplx::new(3., 0.))));
+    }
+
+    #[test]
+    fn skew_product()
+    {
+        let base = SkewBase::<QuadraticSkewProduct>::default();
+        assert_eq!(base.pane_link(), PaneLink::Fiber);
+        let result = base.run_point(ZERO);
+        dbg!(&result);
+        assert!(matches!(result, EscapeResult::Periodic { info, .. } if info.period == 2));
+
+        // Over the critical point of the Basilica, two steps along the fiber give w -> w^4 - 1
+        let fiber = SkewFiber::from(base);
+        let w = Cplx::new(0.3, -0.7);
+        let image = fiber.map(fiber.map(fiber.start_point(w, &NoParam), &NoParam), &NoParam);
+        assert_eq!(image.z, ZERO);
+        assert!((image.w - (w.powi(4) - 1.)).norm() < 1e-12);
+
+        // The orbit of (0, 0) is a superattracting cycle of the skew product of period 4, twice
+        // that of the base point
+        let result = fiber.run_point(ZERO);
+        dbg!(&result);
+        assert!(matches!(result, EscapeResult::Periodic { info, .. } if info.period == 4));
+        assert!(matches!(
+            fiber.run_point(Cplx::new(2., 0.)),
+            EscapeResult::Escaped { .. }
+        ));
+    }
+
+    #[test]
+    fn perturbed_family()
+    {
+        let z = Cplx::new(0.3, -0.7);
+        let c = Cplx::new(-0.1, 0.65);
+        let epsilon = Cplx::new(0.05, 0.02);
+
+        let unperturbed = Perturbed::<Mandelbrot>::default();
+        assert_eq!(unperturbed.map(z, &c), Mandelbrot::default().map(z, &c));
+
+        let perturbation = Perturbation::antiholomorphic(epsilon);
+        let perturbed = Perturbed::new(Mandelbrot::default(), perturbation);
+        assert!((perturbed.map(z, &c) - (z * z + c + epsilon * z.conj())).norm() < 1e-12);
+
+        // The noise is bounded by ε, and the same each time a point is visited
+        let noisy = Perturbed::new(Mandelbrot::default(), Perturbation::noise(epsilon));
+        let offset = noisy.map(z, &c) - (z * z + c);
+        assert!(offset.norm() <= epsilon.norm());
+        assert_eq!(noisy.map(z, &c), noisy.map(z, &c));
+
+        // The sliders of ε and of the noise toggle come before those of the wrapped family
+        let mut family = Perturbed::new(UnicriticalDyn::new(3), Perturbation::default());
+        let mut meta_params = family.get_meta_params();
+        let sliders = meta_params.sliders();
+        dbg!(&sliders);
+        assert_eq!(sliders.len(), 3);
+        meta_params.set_slider(0, epsilon);
+        meta_params.set_slider(1, ONE);
+        meta_params.set_slider(2, Cplx::from(5.));
+        family.set_meta_param(meta_params);
+        assert_eq!(family.perturbation(), Perturbation::noise(epsilon));
+        assert_eq!(family.degree(), 5);
+    }
+
+    #[test]
+    fn stochastic_iteration()
+    {
+        let escape_probability = |plane: &Mandelbrot, c: Cplx| {
+            let orbit = plane.compute_mode().create_orbit(plane);
+            let mut orbit = orbit.borrow_mut();
+            orbit.reset(c);
+            match orbit.run_until_complete() {
+                PointInfo::EscapeProbability { probability } => probability,
+                info => panic!("expected an escape probability, got {info:?}"),
+            }
+        };
+
+        let mut plane = Mandelbrot::default().with_res_y(32);
+        plane.set_compute_mode(ComputeMode::Stochastic {
+            sigma: 0.,
+            trials: 8,
+        });
+        assert_eq!(escape_probability(&plane, ZERO), 0.);
+        assert_eq!(escape_probability(&plane, ONE), 1.);
+
+        // Near the cusp, the fixed point is only weakly attracting, and noise lets orbits slip
+        // through the gate between it and its repelling partner
+        let c = Cplx::new(0.24, 0.);
+        assert_eq!(escape_probability(&plane, c), 0.);
+        plane.set_compute_mode(ComputeMode::Stochastic {
+            sigma: 0.05,
+            trials: 64,
+        });
+        let probability = escape_probability(&plane, c);
+        dbg!(probability);
+        assert!(probability > 0.);
+        assert_eq!(escape_probability(&plane, c), probability);
+
+        // The superattracting fixed point at c = 0 holds orbits against small noise
+        assert_eq!(escape_probability(&plane, ZERO), 0.);
+
+        let iter_plane = plane.compute();
+        assert!(iter_plane
+            .iter_counts
+            .iter()
+            .all(|info| matches!(info, PointInfo::EscapeProbability { .. })));
+    }
+
+    #[test]
+    fn continuous_iteration()
+    {
+        // For z -> z^2, the flow is z -> z^(2^t) on both basins
+        let julia = JuliaSet::from(Mandelbrot::default()).with_param(ZERO);
+        let t = 0.5;
+        for z in [Cplx::new(2., 0.5), Cplx::new(0.5, -0.25)] {
+            let w = julia.fractional_iterate(z, &NoParam, t).unwrap();
+            dbg!(z, w);
+            assert!((w - z.powf(2_f64.powf(t))).norm() < 1e-9);
+        }
+
+        // On the basin of an attracting fixed point, half-iterates compose to the map
+        let c = Cplx::new(-0.5, 0.1);
+        let julia = JuliaSet::from(Mandelbrot::default()).with_param(c);
+        let z = Cplx::new(0.1, 0.2);
+        let half = julia.fractional_iterate(z, &NoParam, 0.5).unwrap();
+        let w = julia.fractional_iterate(half, &NoParam, 0.5).unwrap();
+        dbg!(half, w);
+        assert!((w - julia.map(z, &NoParam)).norm() < 1e-6);
+        let almost = julia.fractional_iterate(z, &NoParam, 0.999).unwrap();
+        assert!((almost - julia.map(z, &NoParam)).norm() < 1e-2);
+        assert_eq!(
+            julia.fractional_iterate(z, &NoParam, 1.),
+            Some(julia.map(z, &NoParam))
+        );
+
+        // The basilica has no flow on the basin of its cycle of period 2
+        let basilica = JuliaSet::from(Mandelbrot::default()).with_param(-ONE);
+        assert!(basilica.flow_chart(Cplx::new(0.1, 0.), &NoParam).is_none());
+
+        let mut julia = julia.with_res_y(32);
+        julia.set_compute_mode(ComputeMode::Flow { time: 0.5 });
+        let iter_plane = julia.compute();
+        assert!(!iter_plane
+            .iter_counts
+            .iter()
+            .any(|info| matches!(info, PointInfo::Unknown)));
+    }
+
+    #[test]
+    fn logarithmic_coordinates()
+    {
+        let outcome = |plane: &Exponential, lambda: Cplx| {
+            let orbit = plane.compute_mode().create_orbit(plane);
+            let mut orbit = orbit.borrow_mut();
+            orbit.reset(lambda);
+            orbit.run_until_complete()
+        };
+
+        let num_unknown = |plane: &Exponential| {
+            plane
+                .compute()
+                .iter_counts
+                .iter()
+                .filter(|info| matches!(info, PointInfo::Unknown))
+                .count()
+        };
+
+        let mut plane = Exponential::default().with_res_y(32);
+        let usual_unknown = num_unknown(&plane);
+        let attracting = Cplx::new(0.3, 0.);
+        let escaping = Cplx::new(2., 0.);
+        let usual = [outcome(&plane, attracting), outcome(&plane, escaping)];
+        plane.set_compute_mode(ComputeMode::Logarithmic);
+        let lifted = [outcome(&plane, attracting), outcome(&plane, escaping)];
+        dbg!(&usual, &lifted);
+
+        match (&usual[0], &lifted[0]) {
+            (PointInfo::Periodic(data), PointInfo::Periodic(lifted_data)) => {
+                assert_eq!(data.period, 1);
+                assert_eq!(lifted_data.period, 1);
+                assert!((data.multiplier - lifted_data.multiplier).norm() < 1e-6);
+            }
+            infos => panic!("expected attracting fixed points, got {infos:?}"),
+        }
+        match (&usual[1], &lifted[1]) {
+            (
+                PointInfo::Escaping { potential, .. },
+                PointInfo::Escaping {
+                    potential: lifted_potential,
+                    ..
+                },
+            ) => assert!((potential - lifted_potential).abs() < 1.),
+            infos => panic!("expected escaping points, got {infos:?}"),
+        }
+
+        let lifted_unknown = num_unknown(&plane);
+        dbg!(usual_unknown, lifted_unknown);
+        assert!(lifted_unknown <= usual_unknown);
+    }
+
+    #[test]
+    fn resume_iterations()
+    {
+        for compute_mode in [ComputeMode::SmoothPotential, ComputeMode::DistanceEstimation] {
+            let mut plane = Mandelbrot::default().with_res_y(32);
+            plane.set_compute_mode(compute_mode);
+            *plane.max_iter_mut() = 16;
+            let mut iter_plane = plane.compute();
+            let resumed: Vec<(usize, usize)> = iter_plane.checkpoints.keys().copied().collect();
+            assert!(!resumed.is_empty());
+
+            // Resuming the orbits that ran out of iterations gives the same outcomes as starting
+            // over
+            *plane.max_iter_mut() = 1024;
+            plane.resume_into(&mut iter_plane);
+            let orbit = plane.compute_mode().create_orbit(&plane);
+            let mut orbit = orbit.borrow_mut();
+            for (x, y) in resumed {
+                orbit.reset(plane.point_grid().map_pixel(x, y));
+                assert_eq!(iter_plane.iter_counts[(x, y)], orbit.run_until_complete());
+                assert_eq!(iter_plane.checkpoints.get(&(x, y)), orbit.checkpoint().as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn adaptive_iterations()
+    {
+        let num_bounded = |iter_plane: &IterPlane<Cplx>| {
+            iter_plane
+                .iter_counts
+                .iter()
+                .filter(|point_info| matches!(point_info, PointInfo::Bounded))
+                .count()
+        };
+
+        let mut plane = Mandelbrot::default().with_res_y(64);
+        plane.set_compute_mode(ComputeMode::AdaptiveIterations);
+        *plane.max_iter_mut() = 32;
+        let mut iter_plane = plane.compute();
+        let bounded_before = num_bounded(&iter_plane);
+        plane.raise_iterations_near_boundary(&mut iter_plane);
+        let bounded_after = num_bounded(&iter_plane);
+        dbg!(bounded_before, bounded_after);
+        assert!(bounded_after < bounded_before);
+
+        // The limit is raised for some pixels, but left as it was for most of them
+        let stats = iter_plane.statistics();
+        dbg!(&stats.iteration_limits);
+        assert_eq!(stats.iteration_limits.values().sum::<usize>(), stats.total);
+        assert!(stats.iteration_limits.len() > 1);
+        assert!(stats.iteration_limits.keys().all(|&limit| limit <= 32 * ADAPTIVE_MAX_FACTOR));
+        assert!(stats.iteration_limits[&32] > stats.total / 2);
+        assert!(stats.mean_iteration_limit().unwrap() < 64.);
+    }
 }
@@ -7,7 +7,6 @@ macro_rules! profile_imports {
         use dynamo_common::prelude::*;
         use dynamo_core::prelude::*;
         use num_traits::ops::mul_add::MulAdd;
-        use std::any::type_name;
         use $crate::macros::parameter_plane_impl;
 
         #[cfg(feature = "serde")]
@@ -116,6 +115,9 @@ macro_rules! degree_impl {
     };
 }
 
+/// Squared modulus past which bounded orbits of transcendental maps are taken to be wandering.
+pub(crate) const WANDERING_NORM_SQR: f64 = 1e5;
+
 macro_rules! degree_impl_transcendental {
     ($plane: ty) => {
         impl InfinityFirstReturnMap for $plane
@@ -128,23 +130,36 @@ macro_rules! degree_impl_transcendental {
             fn encode_escape_result(
                 &self,
                 state: EscapeResult<Self::Var, Self::Deriv>,
-                _start: Self::Var,
+                start: Self::Var,
                 base_param: &Self::Param,
             ) -> PointInfo<Self::Deriv>
             {
+                use dynamo_common::math_utils::fast_escape_level;
+                // Orbits that end far out, or are lost to precision, are followed again to see
+                // whether they enter the fast escaping set.
+                let fast_escaping = || {
+                    let orbit = std::iter::successors(Some(start), |&z| {
+                        z.is_finite().then(|| self.map(z, base_param))
+                    })
+                    .take(self.max_iter() as usize);
+                    fast_escape_level(orbit, $crate::macros::WANDERING_NORM_SQR.sqrt())
+                        .map(|level| PointInfo::FastEscaping { level })
+                };
                 match state {
-                    EscapeResult::Periodic { info, .. } => PointInfo::Periodic(info),
+                    EscapeResult::Periodic { info, final_value } => {
+                        self.identify_marked_points(final_value, base_param, info)
+                    }
                     EscapeResult::Escaped { iters, final_value } => {
                         self.encode_escaping_point(iters, final_value, base_param)
                     }
                     EscapeResult::Bounded(final_value) => {
-                        if final_value.norm_sqr() > 1e5 {
-                            PointInfo::Wandering
+                        if final_value.norm_sqr() > $crate::macros::WANDERING_NORM_SQR {
+                            fast_escaping().unwrap_or(PointInfo::Wandering)
                         } else {
                             PointInfo::Bounded
                         }
                     }
-                    EscapeResult::Unknown => PointInfo::Unknown,
+                    EscapeResult::Unknown => fast_escaping().unwrap_or(PointInfo::Unknown),
                 }
             }
 
@@ -160,12 +175,14 @@ macro_rules! degree_impl_transcendental {
                     return PointInfo::Escaping {
                         potential: (iters as f64) - 1.,
                         phase: None,
+                        angle: None,
                     };
                 }
                 if z.is_infinite() {
                     return PointInfo::Escaping {
                         potential: (iters as f64) + 1.,
                         phase: None,
+                        angle: None,
                     };
                 }
                 let u = slog(self.escape_radius());
@@ -175,6 +192,7 @@ macro_rules! degree_impl_transcendental {
                 PointInfo::Escaping {
                     potential,
                     phase: None,
+                    angle: None,
                 }
             }
         }
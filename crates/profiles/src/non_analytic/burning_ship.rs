@@ -38,7 +38,12 @@ impl<const N: Period> DynamicalFamily for BurningShip<N>
     // type Deriv = Matrix2x2;
     type Deriv = Cplx;
     basic_plane_impl!();
-    default_name!();
+
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        self.calibrated_escape_radius()
+    }
 
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
@@ -75,7 +80,7 @@ impl<const N: Period> DynamicalFamily for BurningShip<N>
     }
 }
 
-impl<const D: Period> FamilyDefaults for BurningShip<D>
+impl<const D: Period> DisplayDefaults for BurningShip<D>
 {
     default_bounds!();
 }
@@ -195,17 +200,17 @@ impl<const N: Period> DynamicalFamily for Sailboat<N>
     {
         self.shift
     }
+}
 
+impl<const N: Period> DisplayDefaults for Sailboat<N>
+{
     #[inline]
     fn name(&self) -> String
     {
         let shift = self.shift;
         format!("Sailboat({shift})")
     }
-}
 
-impl<const N: Period> FamilyDefaults for Sailboat<N>
-{
     fn default_bounds(&self) -> Bounds
     {
         let center = Self::DEFAULT_BOUNDS.center();
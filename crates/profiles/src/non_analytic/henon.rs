@@ -0,0 +1,96 @@
+use crate::macros::{degree_impl, profile_imports};
+use dynamo_common::types::{
+    variables::{Matrix2x2, Point},
+    Cplx,
+};
+
+profile_imports!();
+
+/// The Hénon map `(x, y) ↦ (1 - ax² + y, bx)`, with parameter `(a, b)`.
+///
+/// For `b ≠ 0` it is a diffeomorphism of the plane contracting area by `|b|`; at the classical
+/// parameters `a = 1.4`, `b = 0.3` it has a chaotic strange attractor.
+#[derive(Clone, Debug)]
+pub struct Henon
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+}
+impl Henon
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds {
+        min_x: -0.2,
+        max_x: 1.6,
+        min_y: -0.6,
+        max_y: 0.6,
+    };
+}
+impl Default for Henon
+{
+    fractal_impl!();
+}
+
+impl DynamicalFamily for Henon
+{
+    type MetaParam = NoParam;
+
+    basic_plane_impl!();
+    real_plane_2d_impl!();
+}
+
+impl RealPlane2D for Henon
+{
+    #[inline]
+    fn map_2d(&self, z: Point, c: &Point) -> Point
+    {
+        Point {
+            x: (-c.x * z.x).mul_add(z.x, 1. + z.y),
+            y: c.y * z.x,
+        }
+    }
+
+    #[inline]
+    fn jacobian(&self, z: Point, c: &Point) -> Matrix2x2
+    {
+        Matrix2x2 {
+            v0: Point {
+                x: -2. * c.x * z.x,
+                y: c.y,
+            },
+            v1: Point { x: 1., y: 0. },
+        }
+    }
+
+    #[inline]
+    fn param_jacobian(&self, z: Point, _c: &Point) -> Matrix2x2
+    {
+        Matrix2x2::diag(-z.x * z.x, z.x)
+    }
+}
+
+impl DisplayDefaults for Henon
+{
+    default_bounds!();
+
+    fn default_selection(&self) -> Cplx
+    {
+        Cplx::new(1.4, 0.3)
+    }
+}
+
+impl HasJulia for Henon
+{
+    fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
+    {
+        Bounds {
+            min_x: -1.5,
+            max_x: 1.5,
+            min_y: -0.5,
+            max_y: 0.5,
+        }
+    }
+}
+
+impl MarkedPoints for Henon {}
+degree_impl!(Henon, 2);
@@ -0,0 +1,134 @@
+use crate::macros::{degree_impl, profile_imports};
+use dynamo_common::types::{
+    variables::{Matrix2x2, Point},
+    Cplx,
+};
+
+profile_imports!();
+
+/// Phase shift of the Ikeda map at `z`, with its partial derivatives in `x` and `y`.
+fn phase(z: Point, kappa: Real) -> (Real, Real, Real)
+{
+    let r = z.norm_sqr() + 1.;
+    let dt = 2. * kappa / (r * r);
+    (0.4 - kappa / r, dt * z.x, dt * z.y)
+}
+
+/// The Ikeda map `z ↦ 1 + uz exp(i(0.4 - κ/(1 + |z|²)))`, modelling light circulating in a
+/// nonlinear optical resonator, with parameter `(u, κ)`.
+///
+/// Written in the coordinates `z = x + iy`, it is not holomorphic. The attractor usually studied
+/// appears at `u = 0.9`, `κ = 6`.
+#[derive(Clone, Debug)]
+pub struct Ikeda
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+}
+impl Ikeda
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds {
+        min_x: 0.,
+        max_x: 1.,
+        min_y: 0.,
+        max_y: 10.,
+    };
+}
+impl Default for Ikeda
+{
+    fractal_impl!();
+}
+
+impl DynamicalFamily for Ikeda
+{
+    type MetaParam = NoParam;
+
+    basic_plane_impl!();
+    real_plane_2d_impl!();
+}
+
+impl RealPlane2D for Ikeda
+{
+    #[inline]
+    fn map_2d(&self, z: Point, c: &Point) -> Point
+    {
+        let (t, _, _) = phase(z, c.y);
+        let (sin, cos) = t.sin_cos();
+        Point {
+            x: c.x.mul_add(z.x.mul_add(cos, -z.y * sin), 1.),
+            y: c.x * z.x.mul_add(sin, z.y * cos),
+        }
+    }
+
+    fn jacobian(&self, z: Point, c: &Point) -> Matrix2x2
+    {
+        let (t, dt_dx, dt_dy) = phase(z, c.y);
+        let (sin, cos) = t.sin_cos();
+        // z rotated by the phase
+        let w = Point {
+            x: z.x.mul_add(cos, -z.y * sin),
+            y: z.x.mul_add(sin, z.y * cos),
+        };
+        let u = c.x;
+        Matrix2x2 {
+            v0: Point {
+                x: u * (-w.y).mul_add(dt_dx, cos),
+                y: u * w.x.mul_add(dt_dx, sin),
+            },
+            v1: Point {
+                x: u * (-w.y).mul_add(dt_dy, -sin),
+                y: u * w.x.mul_add(dt_dy, cos),
+            },
+        }
+    }
+
+    fn param_jacobian(&self, z: Point, c: &Point) -> Matrix2x2
+    {
+        let (t, _, _) = phase(z, c.y);
+        let (sin, cos) = t.sin_cos();
+        let w = Point {
+            x: z.x.mul_add(cos, -z.y * sin),
+            y: z.x.mul_add(sin, z.y * cos),
+        };
+        let s = c.x / (z.norm_sqr() + 1.);
+        Matrix2x2 {
+            v0: w,
+            v1: Point {
+                x: s * w.y,
+                y: -s * w.x,
+            },
+        }
+    }
+
+    fn seed(&self, _c: &Point) -> Point
+    {
+        Point { x: 0., y: 0. }
+    }
+}
+
+impl DisplayDefaults for Ikeda
+{
+    default_bounds!();
+
+    fn default_selection(&self) -> Cplx
+    {
+        Cplx::new(0.9, 6.)
+    }
+}
+
+impl HasJulia for Ikeda
+{
+    fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
+    {
+        Bounds {
+            min_x: -0.5,
+            max_x: 2.,
+            min_y: -2.5,
+            max_y: 1.,
+        }
+    }
+}
+
+impl MarkedPoints for Ikeda {}
+degree_impl!(Ikeda, 1);
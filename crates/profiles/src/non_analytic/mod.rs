@@ -9,3 +9,12 @@ pub use unicorn::Unicorn;
 
 pub mod rulkov;
 pub use rulkov::Rulkov;
+
+pub mod henon;
+pub use henon::Henon;
+
+pub mod ikeda;
+pub use ikeda::Ikeda;
+
+pub mod standard_map;
+pub use standard_map::StandardMap;
@@ -62,53 +62,51 @@ impl Default for Rulkov
 
 impl DynamicalFamily for Rulkov
 {
-    type Var = Point;
-    type Param = Point;
-    type Deriv = Matrix2x2;
     type MetaParam = NoParam;
 
     basic_plane_impl!();
-    default_name!();
-
-    fn param_map(&self, point: Cplx) -> Self::Param
-    {
-        Point {
-            x: point.re,
-            y: point.im,
-        }
-    }
+    real_plane_2d_impl!();
+}
 
+impl RealPlane2D for Rulkov
+{
     #[inline]
-    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    fn map_2d(&self, z: Point, c: &Point) -> Point
     {
         f(z, c)
     }
 
     #[inline]
-    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    fn jacobian(&self, z: Point, c: &Point) -> Matrix2x2
     {
-        (f(z, c), df_dz(z, c))
+        df_dz(z, c)
     }
 
     #[inline]
-    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    fn param_jacobian(&self, z: Point, c: &Point) -> Matrix2x2
     {
-        (f(z, c), df_dz(z, c), df_dc(z, c))
+        df_dc(z, c)
     }
 
-    #[inline]
-    fn start_point(&self, _point: Cplx, param: &Self::Param) -> Self::Var
+    fn seed(&self, c: &Point) -> Point
     {
         let mut z = Point { x: 0.5, y: 1.5 };
         for _ in 0..10000 {
-            z = f(z, param);
+            z = f(z, c);
         }
         z
     }
 }
 
-impl FamilyDefaults for Rulkov
+impl DisplayDefaults for Rulkov
 {
+    /// The fast variable `x` models the membrane potential of a neuron, and the slow variable
+    /// `y` modulates its bursts of spikes.
+    fn trace_labels(&self) -> [&'static str; 2]
+    {
+        ["x (fast)", "y (slow)"]
+    }
+
     default_bounds!();
 }
 
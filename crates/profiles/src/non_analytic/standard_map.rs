@@ -0,0 +1,124 @@
+use crate::macros::{degree_impl, profile_imports};
+use dynamo_common::types::{
+    variables::{Matrix2x2, Point},
+    Cplx,
+};
+
+profile_imports!();
+
+/// Reduce an angle to the interval `[-π, π)`.
+fn wrap(t: Real) -> Real
+{
+    (t + PI).rem_euclid(TAU) - PI
+}
+
+/// The dissipative standard map `(θ, p) ↦ (θ + p', p')`, where `p' = bp + K sin θ`, on the
+/// torus, with parameter `(K, b)`.
+///
+/// At `b = 1` this is the area-preserving Chirikov standard map, a kicked rotor; for `|b| < 1`
+/// the momentum is damped and orbits settle on attractors. Orbits never escape.
+#[derive(Clone, Debug)]
+pub struct StandardMap
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+}
+impl StandardMap
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds {
+        min_x: 0.,
+        max_x: 8.,
+        min_y: 0.,
+        max_y: 1.,
+    };
+}
+impl Default for StandardMap
+{
+    fractal_impl!();
+}
+
+impl DynamicalFamily for StandardMap
+{
+    type MetaParam = NoParam;
+
+    basic_plane_impl!();
+    real_plane_2d_impl!();
+
+    fn escape_test(&self) -> EscapeTest
+    {
+        EscapeTest::Never
+    }
+}
+
+impl RealPlane2D for StandardMap
+{
+    #[inline]
+    fn map_2d(&self, z: Point, c: &Point) -> Point
+    {
+        let p = c.y.mul_add(z.y, c.x * z.x.sin());
+        Point {
+            x: wrap(z.x + p),
+            y: wrap(p),
+        }
+    }
+
+    #[inline]
+    fn jacobian(&self, z: Point, c: &Point) -> Matrix2x2
+    {
+        let dp_dt = c.x * z.x.cos();
+        Matrix2x2 {
+            v0: Point {
+                x: 1. + dp_dt,
+                y: dp_dt,
+            },
+            v1: Point { x: c.y, y: c.y },
+        }
+    }
+
+    #[inline]
+    fn param_jacobian(&self, z: Point, _c: &Point) -> Matrix2x2
+    {
+        let sin = z.x.sin();
+        Matrix2x2 {
+            v0: Point { x: sin, y: sin },
+            v1: Point { x: z.y, y: z.y },
+        }
+    }
+
+    fn seed(&self, _c: &Point) -> Point
+    {
+        Point { x: 1., y: 0.5 }
+    }
+}
+
+impl DisplayDefaults for StandardMap
+{
+    fn trace_labels(&self) -> [&'static str; 2]
+    {
+        ["θ (angle)", "p (momentum)"]
+    }
+
+    default_bounds!();
+
+    fn default_selection(&self) -> Cplx
+    {
+        Cplx::new(0.97, 1.)
+    }
+}
+
+impl HasJulia for StandardMap
+{
+    fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
+    {
+        Bounds {
+            min_x: -PI,
+            max_x: PI,
+            min_y: -PI,
+            max_y: PI,
+        }
+    }
+}
+
+impl MarkedPoints for StandardMap {}
+degree_impl!(StandardMap, 1);
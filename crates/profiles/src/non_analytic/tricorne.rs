@@ -33,7 +33,12 @@ impl<const N: Period> Default for Tricorne<N>
 impl<const N: Period> DynamicalFamily for Tricorne<N>
 {
     parameter_plane_impl!();
-    default_name!();
+
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        self.calibrated_escape_radius()
+    }
 
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
@@ -58,7 +63,7 @@ impl<const N: Period> DynamicalFamily for Tricorne<N>
     }
 }
 
-impl<const N: Period> FamilyDefaults for Tricorne<N>
+impl<const N: Period> DisplayDefaults for Tricorne<N>
 {
     default_bounds!();
 }
@@ -60,15 +60,15 @@ impl<const D: i32> DynamicalFamily for Unicorn<D>
     {
         1e-18
     }
+}
 
+impl<const D: i32> DisplayDefaults for Unicorn<D>
+{
     fn name(&self) -> String
     {
         format!("Unicorn({D})")
     }
-}
 
-impl<const D: i32> FamilyDefaults for Unicorn<D>
-{
     default_bounds!();
 
     fn default_selection(&self) -> Cplx
@@ -35,13 +35,6 @@ impl DynamicalFamily for Biquadratic
     type MetaParam = Cplx;
     basic_plane_impl!();
 
-    #[inline]
-    fn name(&self) -> String
-    {
-        let param = self.multiplier;
-        format!("Biquadratic({param})")
-    }
-
     #[inline]
     fn param_map(&self, t: Cplx) -> Cplx
     {
@@ -80,7 +73,17 @@ impl DynamicalFamily for Biquadratic
         }
     }
 }
-default_bounds_impl!(Biquadratic);
+impl DisplayDefaults for Biquadratic
+{
+    default_bounds!();
+
+    #[inline]
+    fn name(&self) -> String
+    {
+        let param = self.multiplier;
+        format!("Biquadratic({param})")
+    }
+}
 
 impl EscapeEncoding for Biquadratic
 {
@@ -95,6 +98,7 @@ impl EscapeEncoding for Biquadratic
             return PointInfo::Escaping {
                 potential: (iters as f64) - 1.,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -105,6 +109,7 @@ impl EscapeEncoding for Biquadratic
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
@@ -156,13 +161,6 @@ impl DynamicalFamily for BiquadraticMult
     type MetaParam = Cplx;
     basic_plane_impl!();
 
-    #[inline]
-    fn name(&self) -> String
-    {
-        let param = self.multiplier;
-        format!("Biquadratic({param})")
-    }
-
     #[inline]
     fn param_map(&self, point: Cplx) -> Self::Param
     {
@@ -243,8 +241,15 @@ impl DynamicalFamily for BiquadraticMult
     }
 }
 
-impl FamilyDefaults for BiquadraticMult
+impl DisplayDefaults for BiquadraticMult
 {
+    #[inline]
+    fn name(&self) -> String
+    {
+        let param = self.multiplier;
+        format!("Biquadratic({param})")
+    }
+
     fn default_bounds(&self) -> Bounds
     {
         Bounds::square(14., self.default_selection())
@@ -387,6 +392,7 @@ impl EscapeEncoding for BiquadraticMult
             return PointInfo::Escaping {
                 potential: (iters as f64) - 1.,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -397,6 +403,7 @@ impl EscapeEncoding for BiquadraticMult
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
@@ -480,15 +487,15 @@ impl DynamicalFamily for BiquadraticMultParam
     {
         self.starting_plane = self.starting_plane.swap();
     }
+}
 
+impl DisplayDefaults for BiquadraticMultParam
+{
     fn name(&self) -> String
     {
         "Biquadratic Param".to_owned()
     }
-}
 
-impl FamilyDefaults for BiquadraticMultParam
-{
     default_bounds!();
 
     fn default_selection(&self) -> Cplx
@@ -534,6 +541,7 @@ impl EscapeEncoding for BiquadraticMultParam
             return PointInfo::Escaping {
                 potential: (iters as f64) - 1.,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -544,6 +552,7 @@ impl EscapeEncoding for BiquadraticMultParam
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
@@ -598,13 +607,6 @@ impl DynamicalFamily for BiquadraticMultSecondIterate
     type MetaParam = Cplx;
     basic_plane_impl!();
 
-    #[inline]
-    fn name(&self) -> String
-    {
-        let param = self.multiplier;
-        format!("BiquadraticMult({param})")
-    }
-
     #[inline]
     fn param_map(&self, t: Cplx) -> Cplx
     {
@@ -658,8 +660,15 @@ impl DynamicalFamily for BiquadraticMultSecondIterate
     }
 }
 
-impl FamilyDefaults for BiquadraticMultSecondIterate
+impl DisplayDefaults for BiquadraticMultSecondIterate
 {
+    #[inline]
+    fn name(&self) -> String
+    {
+        let param = self.multiplier;
+        format!("BiquadraticMult({param})")
+    }
+
     default_bounds!();
 }
 
@@ -684,6 +693,7 @@ impl EscapeEncoding for BiquadraticMultSecondIterate
             return PointInfo::Escaping {
                 potential: (iters as f64) - 1.,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -694,6 +704,7 @@ impl EscapeEncoding for BiquadraticMultSecondIterate
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
@@ -741,12 +752,6 @@ impl DynamicalFamily for BiquadraticMultSection
     type MetaParam = NoParam;
     basic_plane_impl!();
 
-    #[inline]
-    fn name(&self) -> String
-    {
-        "Biquadratic Section".to_string()
-    }
-
     #[inline]
     fn param_map(&self, multiplier: Cplx) -> Self::Param
     {
@@ -800,8 +805,14 @@ impl DynamicalFamily for BiquadraticMultSection
     }
 }
 
-impl FamilyDefaults for BiquadraticMultSection
+impl DisplayDefaults for BiquadraticMultSection
 {
+    #[inline]
+    fn name(&self) -> String
+    {
+        "Biquadratic Section".to_string()
+    }
+
     default_bounds!();
 
     fn default_selection(&self) -> Cplx
@@ -897,6 +908,7 @@ impl EscapeEncoding for BiquadraticMultSection
             return PointInfo::Escaping {
                 potential: (iters as f64) - 1.,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -907,6 +919,7 @@ impl EscapeEncoding for BiquadraticMultSection
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
@@ -30,7 +30,7 @@ impl ChebyshevCoeffTable
         let n = self.coeffs.len();
         let coeff0 = self.coeffs[n - 2].iter().chain(once(&0));
 
-        let new_coeff: Vec<i32> = if n % 2 == 0 {
+        let new_coeff: Vec<i32> = if n.is_multiple_of(2) {
             let coeff1 = once(&0).chain(self.coeffs[n - 1].iter());
             coeff0.zip(coeff1).map(|(&a, &b)| 2 * b - a).collect()
         } else {
@@ -74,26 +74,76 @@ pub struct Chebyshev<const D: Period>
     coeffs_d: Vec<Real>,
 }
 
+/// Coefficients of (-1)^k * T_2k(z/2) in powers of z^2/4, together with those of its derivative.
+fn chebyshev_coeffs(k: Period) -> (Vec<Real>, Vec<Real>)
+{
+    let sign = 1 - 2 * ((k % 2) as i32);
+
+    let coeffs: Vec<Real> = ChebyshevCoeffTable::new(2 * k as usize)
+        .coefficients(2 * k as usize)
+        .iter()
+        .map(|&x| f64::from(sign * x))
+        .collect();
+    let coeffs_d: Vec<Real> = coeffs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, a)| (k as Real) * a)
+        .collect();
+    (coeffs, coeffs_d)
+}
+
+#[inline]
+fn chebyshev_map(coeffs: &[Real], z: Cplx, c: Cplx) -> Cplx
+{
+    let w = z * z * 0.25;
+
+    let mut z_iter = coeffs.iter().rev();
+
+    let an = *z_iter.next().unwrap_or(&0.0);
+
+    let mut zval = Cplx::from(an);
+
+    for &a in z_iter {
+        zval = zval * w + a;
+    }
+
+    c * zval
+}
+
+/// The map, its derivative in `z`, and its derivative in `c`.
+#[inline]
+fn chebyshev_gradient(coeffs: &[Real], coeffs_d: &[Real], z: Cplx, c: Cplx) -> (Cplx, Cplx, Cplx)
+{
+    let w = z * 0.5;
+    let w2 = w * w;
+
+    let mut z_iter = coeffs.iter().rev();
+    let mut d_iter = coeffs_d.iter().rev();
+
+    let an = *z_iter.next().unwrap_or(&0.0);
+    let bn = *d_iter.next().unwrap_or(&0.0);
+
+    let mut zval = Cplx::from(an);
+    let mut dval = Cplx::from(bn);
+
+    for &a in z_iter {
+        zval = zval * w2 + a;
+    }
+    for &b in d_iter {
+        dval = dval * w2 + b;
+    }
+
+    (c * zval, c * dval * w, zval)
+}
+
 impl<const D: Period> Default for Chebyshev<D>
 {
     fn default() -> Self
     {
         let bounds = Bounds::centered_square(3.0 / f64::from(D));
         let point_grid = PointGrid::new_by_res_y(1024, bounds);
-
-        let sign = 1 - 2 * ((D % 2) as i32);
-
-        let coeffs: Vec<Real> = ChebyshevCoeffTable::new(2 * D as usize)
-            .coefficients(2 * D as usize)
-            .iter()
-            .map(|&x| f64::from(sign * x))
-            .collect();
-        let coeffs_d: Vec<Real> = coeffs
-            .iter()
-            .enumerate()
-            .skip(1)
-            .map(|(k, a)| (k as Real) * a)
-            .collect();
+        let (coeffs, coeffs_d) = chebyshev_coeffs(D);
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
@@ -130,84 +180,41 @@ impl<const D: Period> DynamicalFamily for Chebyshev<D>
 {
     parameter_plane_impl!();
 
-    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    #[inline]
+    fn escape_radius(&self) -> Real
     {
-        let w = z * z * 0.25;
-
-        let mut z_iter = self.coeffs.iter().rev();
-
-        let an = *z_iter.next().unwrap_or(&0.0);
-
-        let mut zval = Cplx::from(an);
-
-        for &a in z_iter {
-            zval = zval * w + a;
-        }
+        self.calibrated_escape_radius()
+    }
 
-        c * zval
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        chebyshev_map(&self.coeffs, z, *c)
     }
 
     fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
     {
-        let w = z * 0.5;
-        let w2 = w * w;
-
-        let mut z_iter = self.coeffs.iter().rev();
-        let mut d_iter = self.coeffs_d.iter().rev();
-
-        let an = *z_iter.next().unwrap_or(&0.0);
-        let bn = *d_iter.next().unwrap_or(&0.0);
-
-        let mut zval = Cplx::from(an);
-        let mut dval = Cplx::from(bn);
-
-        for &a in z_iter {
-            zval = zval * w2 + a;
-        }
-        for &b in d_iter {
-            dval = dval * w2 + b;
-        }
-
-        (c * zval, c * dval * w)
+        let (f, df_dz, _) = chebyshev_gradient(&self.coeffs, &self.coeffs_d, z, *c);
+        (f, df_dz)
     }
 
     fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
     {
-        let w = z * 0.5;
-        let w2 = w * w;
-
-        let mut z_iter = self.coeffs.iter().rev();
-        let mut d_iter = self.coeffs_d.iter().rev();
-
-        let an = *z_iter.next().unwrap_or(&0.0);
-        let bn = *d_iter.next().unwrap_or(&0.0);
-
-        let mut zval = Cplx::from(an);
-        let mut dval = Cplx::from(bn);
-
-        for &a in z_iter {
-            zval = zval * w2 + a;
-        }
-        for &b in d_iter {
-            dval = dval * w2 + b;
-        }
-
-        (c * zval, c * dval * w, zval)
+        chebyshev_gradient(&self.coeffs, &self.coeffs_d, z, *c)
     }
 
     fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
     {
         ZERO
     }
+}
 
+impl<const D: Period> DisplayDefaults for Chebyshev<D>
+{
     fn name(&self) -> String
     {
         format!("Chebyshev degree {}", 2 * D)
     }
-}
 
-impl<const D: Period> FamilyDefaults for Chebyshev<D>
-{
     default_bounds!(Bounds::centered_square(3.0 / f64::from(D)));
 }
 
@@ -249,7 +256,7 @@ impl<const D: Period> InfinityFirstReturnMap for Chebyshev<D>
 
     fn escape_coeff_d(&self, param: &Self::Param) -> (Cplx, Cplx)
     {
-        if D % 2 == 0 {
+        if D.is_multiple_of(2) {
             (0.5 * param, Cplx::new(0.5, 0.))
         } else {
             (-0.5 * param, Cplx::new(-0.5, 0.))
@@ -262,3 +269,251 @@ impl<const D: Period> ExternalRays for Chebyshev<D>
 {
     ext_ray_impl_rk!();
 }
+
+/// Half the degree of a [`ChebyshevDyn`] plane, adjustable from a spin box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChebyshevHalfDegree(pub Period);
+
+impl ChebyshevHalfDegree
+{
+    const MIN: Period = 1;
+    const MAX: Period = 10;
+}
+
+impl Default for ChebyshevHalfDegree
+{
+    fn default() -> Self
+    {
+        Self(2)
+    }
+}
+
+impl std::fmt::Display for ChebyshevHalfDegree
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.0.fmt(f)
+    }
+}
+
+impl Named for ChebyshevHalfDegree
+{
+    fn name(&self) -> &'static str
+    {
+        "k"
+    }
+}
+
+impl Describe for ChebyshevHalfDegree
+{
+    fn describe(&self, desc_conf: &DescriptionConf) -> Option<String>
+    {
+        desc_conf.is_enabled.then(|| self.to_string())
+    }
+}
+
+impl ParamList for ChebyshevHalfDegree
+{
+    type Param = Self;
+
+    fn local_param(&self) -> &Self::Param
+    {
+        self
+    }
+    fn into_local_param(self) -> Self::Param
+    {
+        self
+    }
+}
+
+impl HasSliders for ChebyshevHalfDegree
+{
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        vec![MetaParamSlider {
+            name: "k".to_owned(),
+            value: Real::from(self.0).into(),
+            min: Self::MIN.into(),
+            max: Self::MAX.into(),
+            complex: false,
+            integer: true,
+        }]
+    }
+
+    fn set_slider(&mut self, idx: usize, value: Cplx)
+    {
+        if idx == 0 {
+            let k = value.re.round().clamp(Self::MIN.into(), Self::MAX.into());
+            self.0 = k as Period;
+        }
+    }
+}
+
+/// The same family as [`Chebyshev`], with the degree 2k chosen at runtime rather than at
+/// compile time, so that it can be changed from the interface.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChebyshevDyn
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+    half_degree: Period,
+    coeffs: Vec<Real>,
+    coeffs_d: Vec<Real>,
+}
+
+impl ChebyshevDyn
+{
+    #[must_use]
+    pub fn new(half_degree: Period) -> Self
+    {
+        let half_degree = half_degree.clamp(ChebyshevHalfDegree::MIN, ChebyshevHalfDegree::MAX);
+        let bounds = Self::bounds_for(half_degree);
+        let (coeffs, coeffs_d) = chebyshev_coeffs(half_degree);
+        Self {
+            point_grid: PointGrid::new_by_res_y(1024, bounds),
+            compute_mode: ComputeMode::default(),
+            max_iter: 1024,
+            half_degree,
+            coeffs,
+            coeffs_d,
+        }
+    }
+
+    fn bounds_for(half_degree: Period) -> Bounds
+    {
+        Bounds::centered_square(3.0 / f64::from(half_degree))
+    }
+}
+
+impl Default for ChebyshevDyn
+{
+    fn default() -> Self
+    {
+        Self::new(ChebyshevHalfDegree::default().0)
+    }
+}
+
+impl DynamicalFamily for ChebyshevDyn
+{
+    parameter_plane_impl!(Cplx, Cplx, Cplx, ChebyshevHalfDegree);
+    param_map!();
+
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        self.calibrated_escape_radius()
+    }
+
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        chebyshev_map(&self.coeffs, z, *c)
+    }
+
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let (f, df_dz, _) = chebyshev_gradient(&self.coeffs, &self.coeffs_d, z, *c);
+        (f, df_dz)
+    }
+
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        chebyshev_gradient(&self.coeffs, &self.coeffs_d, z, *c)
+    }
+
+    fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
+    {
+        ZERO
+    }
+
+    #[inline]
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        ChebyshevHalfDegree(self.half_degree)
+    }
+
+    #[inline]
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        ChebyshevHalfDegree(self.half_degree)
+    }
+
+    #[inline]
+    fn set_meta_param(&mut self, value: Self::MetaParam)
+    {
+        self.set_param(value);
+    }
+
+    fn set_param(&mut self, ChebyshevHalfDegree(k): <Self::MetaParam as ParamList>::Param)
+    {
+        let k = k.clamp(ChebyshevHalfDegree::MIN, ChebyshevHalfDegree::MAX);
+        if k != self.half_degree {
+            self.half_degree = k;
+            (self.coeffs, self.coeffs_d) = chebyshev_coeffs(k);
+        }
+    }
+}
+
+impl DisplayDefaults for ChebyshevDyn
+{
+    fn name(&self) -> String
+    {
+        format!("Chebyshev degree {}", 2 * self.half_degree)
+    }
+
+    fn long_name(&self) -> String
+    {
+        self.name()
+    }
+
+    fn default_bounds(&self) -> Bounds
+    {
+        Self::bounds_for(self.half_degree)
+    }
+}
+
+impl HasJulia for ChebyshevDyn {}
+
+impl MarkedPoints for ChebyshevDyn
+{
+    /// The critical points of T_2k(z/2) are 2cos(jπ/2k) for 0 < j < 2k.
+    fn critical_points_child(&self, _c: &Self::Param) -> Vec<Self::Var>
+    {
+        let n = 2 * self.half_degree;
+        (1..n)
+            .map(|j| Cplx::from(2. * (PI * Real::from(j) / Real::from(n)).cos()))
+            .collect()
+    }
+}
+
+impl InfinityFirstReturnMap for ChebyshevDyn
+{
+    #[inline]
+    fn degree(&self) -> AngleNum
+    {
+        (2 * self.half_degree).into()
+    }
+
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        Real::from(2 * self.half_degree)
+    }
+
+    fn escape_coeff_d(&self, param: &Self::Param) -> (Cplx, Cplx)
+    {
+        if self.half_degree.is_multiple_of(2) {
+            (0.5 * param, Cplx::new(0.5, 0.))
+        } else {
+            (-0.5 * param, Cplx::new(-0.5, 0.))
+        }
+    }
+}
+
+impl EscapeEncoding for ChebyshevDyn {}
+impl ExternalRays for ChebyshevDyn
+{
+    ext_ray_impl_rk!();
+}
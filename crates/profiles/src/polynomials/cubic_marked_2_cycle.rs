@@ -29,7 +29,6 @@ impl Default for CubicMarked2Cycle
 impl DynamicalFamily for CubicMarked2Cycle
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
@@ -77,7 +76,7 @@ impl DynamicalFamily for CubicMarked2Cycle
     }
 }
 
-impl FamilyDefaults for CubicMarked2Cycle
+impl DisplayDefaults for CubicMarked2Cycle
 {
     default_bounds!();
 }
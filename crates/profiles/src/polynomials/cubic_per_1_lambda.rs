@@ -70,6 +70,8 @@ impl DynamicalFamily for CubicPer1Lambda
         (z * u, u + z * (c + 2. * z), z2)
     }
 
+    inverse_map_impl!();
+
     #[inline]
     fn start_point(&self, t: Cplx, _c: &Self::Param) -> Self::Var
     {
@@ -113,11 +115,6 @@ impl DynamicalFamily for CubicPer1Lambda
         -t.inv() - 0.75 * self.multiplier * t
     }
 
-    fn name(&self) -> String
-    {
-        format!("Cubic Per(1, {}) {}", self.multiplier, self.starting_crit)
-    }
-
     fn cycle_active_plane(&mut self)
     {
         self.starting_crit = self.starting_crit.swap();
@@ -126,8 +123,21 @@ impl DynamicalFamily for CubicPer1Lambda
 
 has_child_impl!(CubicPer1Lambda);
 
-impl FamilyDefaults for CubicPer1Lambda
+impl InverseMap for CubicPer1Lambda
 {
+    fn inverse_branches(&self, z: Self::Var, c: &Self::Param) -> Vec<Self::Var>
+    {
+        solve_cubic(-z, self.multiplier, *c).to_vec()
+    }
+}
+
+impl DisplayDefaults for CubicPer1Lambda
+{
+    fn name(&self) -> String
+    {
+        format!("Cubic Per(1, {}) {}", self.multiplier, self.starting_crit)
+    }
+
     fn default_bounds(&self) -> Bounds
     {
         let r = 4. / (self.multiplier.norm() + 0.01);
@@ -407,19 +417,19 @@ impl DynamicalFamily for CubicPer1LambdaParam
         }
     }
 
-    fn name(&self) -> String
-    {
-        "Cubic Per(1, lambda) lambda-plane".to_owned()
-    }
-
     fn cycle_active_plane(&mut self)
     {
         self.starting_crit = self.starting_crit.swap();
     }
 }
 
-impl FamilyDefaults for CubicPer1LambdaParam
+impl DisplayDefaults for CubicPer1LambdaParam
 {
+    fn name(&self) -> String
+    {
+        "Cubic Per(1, lambda) lambda-plane".to_owned()
+    }
+
     default_bounds!();
 
     fn default_selection(&self) -> Cplx
@@ -515,7 +525,6 @@ impl Default for CubicPer1_1
 impl DynamicalFamily for CubicPer1_1
 {
     parameter_plane_impl!();
-    default_name!();
 
     fn periodicity_tolerance(&self) -> Real
     {
@@ -565,7 +574,7 @@ impl DynamicalFamily for CubicPer1_1
     }
 }
 
-impl FamilyDefaults for CubicPer1_1
+impl DisplayDefaults for CubicPer1_1
 {
     default_bounds!();
 }
@@ -657,6 +666,7 @@ impl EscapeEncoding for CubicPer1_1
             return PointInfo::Escaping {
                 potential: (iters as f64) - 1.,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -667,6 +677,7 @@ impl EscapeEncoding for CubicPer1_1
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
@@ -702,7 +713,6 @@ impl Default for CubicPer1_0
 impl DynamicalFamily for CubicPer1_0
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[allow(clippy::suspicious_operation_groupings)]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
@@ -733,9 +743,20 @@ impl DynamicalFamily for CubicPer1_0
         let z2 = z.powi(2);
         (z2 * (z + c), z * (2. * c + 3. * z), z2)
     }
+
+    simd_impl!();
+}
+
+impl SimdMap for CubicPer1_0
+{
+    #[inline]
+    fn map_simd(&self, z: CplxLanes, c: CplxLanes) -> CplxLanes
+    {
+        z.square() * (z + c)
+    }
 }
 
-impl FamilyDefaults for CubicPer1_0
+impl DisplayDefaults for CubicPer1_0
 {
     default_bounds!();
 }
@@ -1331,7 +1352,6 @@ impl DynamicalFamily for CubicPer1LambdaModuli
     type Deriv = Cplx;
 
     basic_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Self::Var, CplxPair { a, b }: &Self::Param) -> Self::Var
@@ -1411,7 +1431,7 @@ impl DynamicalFamily for CubicPer1LambdaModuli
     }
 }
 
-impl FamilyDefaults for CubicPer1LambdaModuli
+impl DisplayDefaults for CubicPer1LambdaModuli
 {
     default_bounds!();
 }
@@ -1477,6 +1497,7 @@ impl EscapeEncoding for CubicPer1LambdaModuli
             return PointInfo::Escaping {
                 potential: (iters as IterCountSmooth) - 1.,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -1488,6 +1509,7 @@ impl EscapeEncoding for CubicPer1LambdaModuli
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
@@ -95,15 +95,15 @@ impl DynamicalFamily for CubicPer2Lambda
     {
         self.starting_crit = self.starting_crit.swap();
     }
+}
 
+impl DisplayDefaults for CubicPer2Lambda
+{
     fn name(&self) -> String
     {
         format!("Cubic Per(2, {})", self.multiplier)
     }
-}
 
-impl FamilyDefaults for CubicPer2Lambda
-{
     default_bounds!();
 }
 
@@ -272,15 +272,15 @@ impl DynamicalFamily for CubicPer2LambdaParam
     {
         self.starting_crit = self.starting_crit.swap();
     }
+}
 
+impl DisplayDefaults for CubicPer2LambdaParam
+{
     fn name(&self) -> String
     {
         "Cubic Per(2, lambda) lambda-plane".to_owned()
     }
-}
 
-impl FamilyDefaults for CubicPer2LambdaParam
-{
     default_bounds!(Bounds::centered_square(2.5));
 
     fn default_selection(&self) -> Cplx
@@ -361,7 +361,6 @@ impl Default for CubicPer2CritMarked
 impl DynamicalFamily for CubicPer2CritMarked
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
@@ -392,7 +391,7 @@ impl DynamicalFamily for CubicPer2CritMarked
     }
 }
 
-impl FamilyDefaults for CubicPer2CritMarked
+impl DisplayDefaults for CubicPer2CritMarked
 {
     default_bounds!();
 }
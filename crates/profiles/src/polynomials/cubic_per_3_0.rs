@@ -33,7 +33,6 @@ impl DynamicalFamily for CubicPer3_0
     type MetaParam = NoParam;
     type Deriv = Cplx;
     basic_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map_and_multiplier(
@@ -82,7 +81,7 @@ impl DynamicalFamily for CubicPer3_0
     }
 }
 
-impl FamilyDefaults for CubicPer3_0
+impl DisplayDefaults for CubicPer3_0
 {
     default_bounds!();
 
@@ -29,11 +29,11 @@ impl Default for Mandelbrot
 impl DynamicalFamily for Mandelbrot
 {
     parameter_plane_impl!();
-    default_name!();
 
+    #[inline]
     fn escape_radius(&self) -> Real
     {
-        1e26
+        self.calibrated_escape_radius()
     }
 
     fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
@@ -53,6 +53,10 @@ impl DynamicalFamily for Mandelbrot
         (z.powi(2) + c, 2. * z)
     }
 
+    inverse_map_impl!();
+
+    simd_impl!();
+
     fn early_bailout(&self, _start: Cplx, c: &Self::Param) -> Option<PointInfo<Cplx>>
     {
         // Main cardioid
@@ -91,7 +95,29 @@ impl DynamicalFamily for Mandelbrot
 
         None
     }
+}
+
+impl InverseMap for Mandelbrot
+{
+    #[inline]
+    fn inverse_branches(&self, z: Self::Var, c: &Self::Param) -> Vec<Self::Var>
+    {
+        let w = (z - c).sqrt();
+        vec![w, -w]
+    }
+}
 
+impl SimdMap for Mandelbrot
+{
+    #[inline]
+    fn map_simd(&self, z: CplxLanes, c: CplxLanes) -> CplxLanes
+    {
+        z.square() + c
+    }
+}
+
+impl DisplayDefaults for Mandelbrot
+{
     fn description(&self) -> String
     {
         "The moduli space of quadratic polynomials, \
@@ -102,10 +128,7 @@ impl DynamicalFamily for Mandelbrot
             under forward iteration of $f_c$."
             .to_owned()
     }
-}
 
-impl FamilyDefaults for Mandelbrot
-{
     default_bounds!();
 }
 
@@ -23,10 +23,13 @@ pub mod cubic_marked_2_cycle;
 pub use cubic_marked_2_cycle::CubicMarked2Cycle;
 
 pub mod unicritical;
-pub use unicritical::Unicritical;
+pub use unicritical::{Unicritical, UnicriticalDegree, UnicriticalDyn};
 
 pub mod chebyshev;
-pub use chebyshev::Chebyshev;
+pub use chebyshev::{Chebyshev, ChebyshevDyn, ChebyshevHalfDegree};
+
+pub mod quadratic_skew_product;
+pub use quadratic_skew_product::QuadraticSkewProduct;
 
 pub mod biquadratic;
 pub use biquadratic::{Biquadratic, BiquadraticMult, BiquadraticMultParam, BiquadraticMultSection};
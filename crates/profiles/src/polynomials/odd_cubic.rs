@@ -28,7 +28,6 @@ impl Default for OddCubic
 impl DynamicalFamily for OddCubic
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
@@ -57,6 +56,8 @@ impl DynamicalFamily for OddCubic
         (2. * z * (z2 / 3. - c), 2. * (z2 - c))
     }
 
+    inverse_map_impl!();
+
     #[inline]
     fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
     {
@@ -66,7 +67,15 @@ impl DynamicalFamily for OddCubic
     }
 }
 
-impl FamilyDefaults for OddCubic
+impl InverseMap for OddCubic
+{
+    fn inverse_branches(&self, z: Self::Var, c: &Self::Param) -> Vec<Self::Var>
+    {
+        solve_cubic(-1.5 * z, -3. * c, ZERO).to_vec()
+    }
+}
+
+impl DisplayDefaults for OddCubic
 {
     default_bounds!();
 }
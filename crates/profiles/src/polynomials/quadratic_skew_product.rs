@@ -0,0 +1,80 @@
+use crate::macros::profile_imports;
+profile_imports!();
+
+/// Skew products `(z, w) -> (z^2 + c, w^2 + az)`, whose fibers are carried from one to the next
+/// by quadratic maps with varying constant terms.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuadraticSkewProduct
+{
+    /// Constant term of the base map.
+    pub c: Cplx,
+    /// Coupling of the fiber maps to the base point.
+    pub a: Cplx,
+}
+
+impl QuadraticSkewProduct
+{
+    const BASE_BOUNDS: Bounds = Bounds {
+        min_x: -1.75,
+        max_x: 1.75,
+        min_y: -1.25,
+        max_y: 1.25,
+    };
+}
+
+impl Default for QuadraticSkewProduct
+{
+    fn default() -> Self
+    {
+        Self { c: -ONE, a: ONE }
+    }
+}
+
+impl SkewProduct for QuadraticSkewProduct
+{
+    #[inline]
+    fn base_map(&self, z: Cplx) -> (Cplx, Cplx)
+    {
+        (z * z + self.c, 2. * z)
+    }
+
+    #[inline]
+    fn fiber_map(&self, z: Cplx, w: Cplx) -> (Cplx, Cplx)
+    {
+        (w * w + self.a * z, 2. * w)
+    }
+
+    fn base_critical_points(&self) -> Vec<Cplx>
+    {
+        vec![ZERO]
+    }
+
+    fn fiber_critical_points(&self, _z: Cplx) -> Vec<Cplx>
+    {
+        vec![ZERO]
+    }
+
+    fn name(&self) -> String
+    {
+        "Quadratic Skew Product".to_owned()
+    }
+
+    fn description(&self) -> String
+    {
+        format!(
+            "Skew products (z, w) -> (z^2 + c, w^2 + az) with c = {} and a = {}. The base plane \
+                shows the Julia set of z^2 + c, and the fiber plane the points w whose orbits \
+                stay bounded as the base point z runs along its orbit. Over a periodic base \
+                point, the fiber is the Julia set of the composition of the quadratic maps \
+                along the cycle.",
+            self.c, self.a
+        )
+    }
+
+    #[inline]
+    fn base_bounds(&self) -> Bounds
+    {
+        Self::BASE_BOUNDS
+    }
+}
@@ -1,6 +1,4 @@
-use crate::macros::{
-    basic_plane_impl, default_bounds, default_name, degree_impl, fractal_impl, profile_imports,
-};
+use crate::macros::{basic_plane_impl, default_bounds, degree_impl, fractal_impl, profile_imports};
 profile_imports!();
 
 #[derive(Clone, Debug)]
@@ -34,7 +32,6 @@ impl DynamicalFamily for RealCubicRealCrit
     type Deriv = Cplx;
     type MetaParam = NoParam;
     basic_plane_impl!();
-    default_name!();
 
     // Critical point = a
     fn map(&self, z: Self::Var, RealPair { a, b }: &Self::Param) -> Self::Var
@@ -68,7 +65,7 @@ impl DynamicalFamily for RealCubicRealCrit
     }
 }
 
-impl FamilyDefaults for RealCubicRealCrit
+impl DisplayDefaults for RealCubicRealCrit
 {
     default_bounds!();
 }
@@ -117,7 +114,6 @@ impl DynamicalFamily for RealCubicImagCrit
     type Deriv = Cplx;
     type MetaParam = NoParam;
     basic_plane_impl!();
-    default_name!();
 
     // Critical point = ai
     fn map(&self, z: Self::Var, RealPair { a, b }: &Self::Param) -> Self::Var
@@ -151,7 +147,7 @@ impl DynamicalFamily for RealCubicImagCrit
     }
 }
 
-impl FamilyDefaults for RealCubicImagCrit
+impl DisplayDefaults for RealCubicImagCrit
 {
     default_bounds!();
 }
@@ -1,5 +1,8 @@
 use crate::macros::{degree_impl, ext_ray_impl_nonmonic, horner_monic, profile_imports};
-use dynamo_common::{horner, math_utils::roots_of_unity};
+use dynamo_common::{
+    horner,
+    math_utils::{nth_roots, roots_of_unity},
+};
 profile_imports!();
 
 #[derive(Clone, Debug)]
@@ -29,6 +32,12 @@ impl<const D: i32> DynamicalFamily for Unicritical<D>
 {
     parameter_plane_impl!();
 
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        self.calibrated_escape_radius()
+    }
+
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
@@ -51,6 +60,8 @@ impl<const D: i32> DynamicalFamily for Unicritical<D>
         (u * df, df, u * v)
     }
 
+    inverse_map_impl!();
+
     fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
     {
         ZERO
@@ -60,15 +71,25 @@ impl<const D: i32> DynamicalFamily for Unicritical<D>
     {
         1e-18
     }
+}
 
-    fn name(&self) -> String
+impl<const D: i32> InverseMap for Unicritical<D>
+{
+    fn inverse_branches(&self, z: Self::Var, c: &Self::Param) -> Vec<Self::Var>
     {
-        format!("Unicritical({D})")
+        nth_roots(z / c, D)
+            .map(|u| Self::D_FLOAT * (u - 1.))
+            .collect()
     }
 }
 
-impl<const D: i32> FamilyDefaults for Unicritical<D>
+impl<const D: i32> DisplayDefaults for Unicritical<D>
 {
+    fn name(&self) -> String
+    {
+        format!("Unicritical({D})")
+    }
+
     default_bounds!();
 
     fn default_selection(&self) -> Cplx
@@ -292,3 +313,311 @@ impl HasDynamicalCovers for Unicritical<3>
         CoveringMap::new(self, param_map).with_orig_bounds(bounds)
     }
 }
+
+/// The degree of a [`UnicriticalDyn`] plane, adjustable from a spin box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnicriticalDegree(pub i32);
+
+impl UnicriticalDegree
+{
+    const MIN: i32 = 2;
+    const MAX: i32 = 16;
+}
+
+impl Default for UnicriticalDegree
+{
+    fn default() -> Self
+    {
+        Self(3)
+    }
+}
+
+impl std::fmt::Display for UnicriticalDegree
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.0.fmt(f)
+    }
+}
+
+impl Named for UnicriticalDegree
+{
+    fn name(&self) -> &'static str
+    {
+        "d"
+    }
+}
+
+impl Describe for UnicriticalDegree
+{
+    fn describe(&self, desc_conf: &DescriptionConf) -> Option<String>
+    {
+        desc_conf.is_enabled.then(|| self.to_string())
+    }
+}
+
+impl ParamList for UnicriticalDegree
+{
+    type Param = Self;
+
+    fn local_param(&self) -> &Self::Param
+    {
+        self
+    }
+    fn into_local_param(self) -> Self::Param
+    {
+        self
+    }
+}
+
+impl HasSliders for UnicriticalDegree
+{
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        vec![MetaParamSlider {
+            name: "d".to_owned(),
+            value: Real::from(self.0).into(),
+            min: Self::MIN.into(),
+            max: Self::MAX.into(),
+            complex: false,
+            integer: true,
+        }]
+    }
+
+    fn set_slider(&mut self, idx: usize, value: Cplx)
+    {
+        if idx == 0 {
+            self.0 = (value.re.round() as i32).clamp(Self::MIN, Self::MAX);
+        }
+    }
+}
+
+/// The same family as [`Unicritical`], with the degree chosen at runtime rather than at compile
+/// time, so that it can be changed from the interface.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnicriticalDyn
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+    degree: i32,
+}
+
+impl UnicriticalDyn
+{
+    #[must_use]
+    pub fn new(degree: i32) -> Self
+    {
+        let degree = degree.clamp(UnicriticalDegree::MIN, UnicriticalDegree::MAX);
+        let bounds = Self::bounds_for(degree);
+        Self {
+            point_grid: PointGrid::new_by_res_y(1024, bounds),
+            compute_mode: ComputeMode::default(),
+            max_iter: 1024,
+            degree,
+        }
+    }
+
+    fn bounds_for(degree: i32) -> Bounds
+    {
+        let d = Real::from(degree);
+        Bounds::square(d * 1.2, Cplx::new(-d + 1.0, 0.0))
+    }
+
+    #[inline]
+    fn d_float(&self) -> Real
+    {
+        Real::from(self.degree)
+    }
+
+    #[inline]
+    fn crit(&self) -> Cplx
+    {
+        Cplx::new(-self.d_float(), 0.0)
+    }
+}
+
+impl Default for UnicriticalDyn
+{
+    fn default() -> Self
+    {
+        Self::new(UnicriticalDegree::default().0)
+    }
+}
+
+#[allow(clippy::suspicious_operation_groupings)]
+impl DynamicalFamily for UnicriticalDyn
+{
+    parameter_plane_impl!(Cplx, Cplx, Cplx, UnicriticalDegree);
+    param_map!();
+
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        self.calibrated_escape_radius()
+    }
+
+    #[inline]
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        c * (1. + z / self.d_float()).powi(self.degree)
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let u = 1. + z / self.d_float();
+        let df = c * u.powi(self.degree - 1);
+        (u * df, df)
+    }
+
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let u = 1. + z / self.d_float();
+        let v = u.powi(self.degree - 1);
+        let df = c * v;
+        (u * df, df, u * v)
+    }
+
+    inverse_map_impl!();
+
+    fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
+    {
+        ZERO
+    }
+
+    fn periodicity_tolerance(&self) -> Real
+    {
+        1e-18
+    }
+
+    #[inline]
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        UnicriticalDegree(self.degree)
+    }
+
+    #[inline]
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        UnicriticalDegree(self.degree)
+    }
+
+    #[inline]
+    fn set_meta_param(&mut self, value: Self::MetaParam)
+    {
+        self.set_param(value);
+    }
+
+    #[inline]
+    fn set_param(&mut self, UnicriticalDegree(degree): <Self::MetaParam as ParamList>::Param)
+    {
+        self.degree = degree.clamp(UnicriticalDegree::MIN, UnicriticalDegree::MAX);
+    }
+}
+
+impl InverseMap for UnicriticalDyn
+{
+    fn inverse_branches(&self, z: Self::Var, c: &Self::Param) -> Vec<Self::Var>
+    {
+        nth_roots(z / c, self.degree)
+            .map(|u| self.d_float() * (u - 1.))
+            .collect()
+    }
+}
+
+impl DisplayDefaults for UnicriticalDyn
+{
+    fn name(&self) -> String
+    {
+        format!("Unicritical({})", self.degree)
+    }
+
+    fn long_name(&self) -> String
+    {
+        self.name()
+    }
+
+    fn default_bounds(&self) -> Bounds
+    {
+        Self::bounds_for(self.degree)
+    }
+
+    fn default_selection(&self) -> Cplx
+    {
+        let zeta = (TAUI / self.d_float()).exp();
+        (zeta - 1.) * self.d_float()
+    }
+}
+
+impl HasJulia for UnicriticalDyn
+{
+    fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
+    {
+        Bounds::square(self.d_float() * 1.618, self.crit())
+    }
+}
+
+impl MarkedPoints for UnicriticalDyn
+{
+    #[inline]
+    fn critical_points_child(&self, _c: &Self::Param) -> Vec<Self::Var>
+    {
+        vec![self.crit()]
+    }
+
+    fn cycles_child(&self, c: &Self::Param, period: Period) -> Vec<Self::Var>
+    {
+        use dynamo_common::math_utils::binomial;
+        match period {
+            1 => {
+                let d = self.degree;
+                let mut coeffs: Vec<Cplx> =
+                    (0..=d).map(|x| c * Real::from(binomial(d, x))).collect();
+                coeffs[1] -= self.d_float();
+                solve_polynomial(coeffs)
+                    .iter()
+                    .map(|z| z * self.d_float())
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl InfinityFirstReturnMap for UnicriticalDyn
+{
+    #[inline]
+    fn degree(&self) -> AngleNum
+    {
+        self.degree.into()
+    }
+
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        self.d_float()
+    }
+
+    #[inline]
+    fn escape_coeff(&self, c: &Self::Param) -> Cplx
+    {
+        c * self.d_float().powi(-self.degree)
+    }
+
+    #[inline]
+    fn escape_coeff_d(&self, c: &Self::Param) -> (Cplx, Cplx)
+    {
+        let a = self.d_float().powi(-self.degree);
+        (c * a, a.into())
+    }
+}
+
+impl EscapeEncoding for UnicriticalDyn {}
+
+impl ExternalRays for UnicriticalDyn
+{
+    ext_ray_impl_nonmonic!();
+}
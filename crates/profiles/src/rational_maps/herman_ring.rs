@@ -0,0 +1,323 @@
+use crate::macros::{degree_impl, horner, profile_imports};
+profile_imports!();
+
+/// Meta-parameters of a [`HermanRing`] plane: the zero `a` of its Blaschke factor, and the
+/// rotation number of the map at the origin of the plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RingParams
+{
+    pub zero: Real,
+    pub rotation_number: Real,
+}
+
+impl RingParams
+{
+    const MIN_ZERO: Real = 3.;
+    const MAX_ZERO: Real = 12.;
+    /// Number of iterates of the circle map used to estimate its rotation number, which is then
+    /// accurate to within their reciprocal.
+    const ROTATION_ITERS: u32 = 10_000;
+    const BISECTION_STEPS: u32 = 40;
+
+    /// Lift to the real line of the restriction of the map with rotation factor `e^(2πi angle)`
+    /// to the unit circle, with angles measured in turns.
+    fn circle_lift(&self, x: Real, angle: Real) -> Real
+    {
+        // For a > 1, both arguments stay within (-π/2, π/2), so the lift is continuous
+        let z = Cplx::from_polar(1., TAU * x);
+        let numer = (self.zero - z).arg();
+        let denom = (1. - z.conj() / self.zero).arg();
+        x + angle + (numer - denom) / TAU
+    }
+
+    /// Rotation number of the circle map with rotation factor `e^(2πi angle)`.
+    #[must_use]
+    pub fn rotation_number_at(&self, angle: Real) -> Real
+    {
+        let mut x = 0.;
+        for _ in 0..Self::ROTATION_ITERS {
+            x = self.circle_lift(x, angle);
+        }
+        x / Real::from(Self::ROTATION_ITERS)
+    }
+
+    /// Angle, in turns, of the rotation factor at which the circle map has the given rotation
+    /// number. The rotation number is nondecreasing in the angle and differs from it by less than
+    /// 1/2, so the angle is found by bisection.
+    #[must_use]
+    pub fn angle_for_rotation_number(&self) -> Real
+    {
+        let target = self.rotation_number;
+        let (mut lo, mut hi) = (target - 0.5, target + 0.5);
+        for _ in 0..Self::BISECTION_STEPS {
+            let mid = 0.5 * (lo + hi);
+            if self.rotation_number_at(mid) < target {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        0.5 * (lo + hi)
+    }
+}
+
+impl Default for RingParams
+{
+    fn default() -> Self
+    {
+        Self {
+            zero: 4.,
+            rotation_number: (5_f64.sqrt() - 1.) / 2.,
+        }
+    }
+}
+
+impl std::fmt::Display for RingParams
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "a = {}, ρ = {:.6}", self.zero, self.rotation_number)
+    }
+}
+
+impl Named for RingParams
+{
+    fn name(&self) -> &'static str
+    {
+        "Ring parameters"
+    }
+}
+
+impl Describe for RingParams
+{
+    fn describe(&self, _desc_conf: &DescriptionConf) -> Option<String>
+    {
+        Some(self.to_string())
+    }
+}
+
+impl ParamList for RingParams
+{
+    type Param = Self;
+
+    fn local_param(&self) -> &Self::Param
+    {
+        self
+    }
+    fn into_local_param(self) -> Self::Param
+    {
+        self
+    }
+}
+
+impl HasSliders for RingParams
+{
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        vec![
+            MetaParamSlider {
+                name: "a".to_owned(),
+                value: self.zero.into(),
+                min: Self::MIN_ZERO,
+                max: Self::MAX_ZERO,
+                complex: false,
+                integer: false,
+            },
+            MetaParamSlider {
+                name: "ρ".to_owned(),
+                value: self.rotation_number.into(),
+                min: 0.,
+                max: 1.,
+                complex: false,
+                integer: false,
+            },
+        ]
+    }
+
+    fn set_slider(&mut self, idx: usize, value: Cplx)
+    {
+        match idx {
+            0 => self.zero = value.re.max(Self::MIN_ZERO),
+            1 => self.rotation_number = value.re,
+            _ => {}
+        }
+    }
+}
+
+/// Degree-3 Blaschke products `f_u(z) = u z^2 (z-a)/(1-az)` with `a > 3` real, whose restrictions
+/// to the unit circle are analytic diffeomorphisms.
+///
+/// The plane is parameterized by `u = e^(2πi(θ + t))`, where `θ` is chosen so that the map at
+/// `t = 0` has the rotation number given by the meta-parameters.
+#[derive(Clone, Debug)]
+pub struct HermanRing
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+    params: RingParams,
+    /// Angle of the rotation factor at the origin of the plane, in turns.
+    base_angle: Real,
+}
+
+impl HermanRing
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds::centered_square(0.75);
+}
+
+impl Default for HermanRing
+{
+    fn default() -> Self
+    {
+        let bounds = Self::DEFAULT_BOUNDS;
+        let point_grid = PointGrid::new_by_res_y(1024, bounds);
+        let params = RingParams::default();
+        Self {
+            point_grid,
+            compute_mode: ComputeMode::default(),
+            max_iter: 1024,
+            params,
+            base_angle: params.angle_for_rotation_number(),
+        }
+    }
+}
+
+impl DynamicalFamily for HermanRing
+{
+    parameter_plane_impl!(Cplx, Cplx, Cplx, RingParams);
+
+    #[inline]
+    fn map(&self, z: Self::Var, u: &Self::Param) -> Self::Var
+    {
+        let a = self.params.zero;
+        u * z.powi(2) * (z - a) / (1. - a * z)
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, u: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let a = self.params.zero;
+        let z2 = z.powi(2);
+        let denom = 1. - a * z;
+        let w = u * z2 * (z - a) / denom;
+        // Logarithmic derivative 2/z + 1/(z-a) + a/(1-az), over a common denominator
+        let dw = u * z * horner!(z, -2. * a, 3. + a * a, -2. * a) / denom.powi(2);
+        (w, dw)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Self::Var, u: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let (w, dw) = self.map_and_multiplier(z, u);
+        (w, dw, w / u)
+    }
+
+    #[inline]
+    fn start_point(&self, _point: Cplx, _u: &Self::Param) -> Self::Var
+    {
+        self.critical_points()[0]
+    }
+
+    #[inline]
+    fn param_map(&self, t: Cplx) -> Self::Param
+    {
+        (TAUI * (t + self.base_angle)).exp()
+    }
+
+    #[inline]
+    fn param_map_d(&self, t: Cplx) -> (Self::Param, Self::Deriv)
+    {
+        let u = self.param_map(t);
+        (u, TAUI * u)
+    }
+
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        self.params
+    }
+
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.params
+    }
+
+    fn set_meta_param(&mut self, params: Self::MetaParam)
+    {
+        self.set_param(params);
+    }
+
+    fn set_param(&mut self, params: <Self::MetaParam as ParamList>::Param)
+    {
+        self.params = params;
+        self.base_angle = params.angle_for_rotation_number();
+    }
+}
+
+impl HermanRing
+{
+    /// The free critical points, inside and outside the unit circle; they are exchanged by the
+    /// reflection `z -> 1/z̄`, which commutes with the map when `|u| = 1`.
+    fn critical_points(&self) -> [Cplx; 2]
+    {
+        let a = self.params.zero;
+        let s = 0.5 * a.mul_add(a, 3.);
+        let disc = s.mul_add(s, -4. * a * a).sqrt();
+        [((s - disc) / (2. * a)).into(), ((s + disc) / (2. * a)).into()]
+    }
+}
+
+impl DisplayDefaults for HermanRing
+{
+    fn description(&self) -> String
+    {
+        "Blaschke products f(z) = u z^2 (z-a)/(1-az) with a > 3 real. \
+            Both 0 and ∞ are superattracting, and for |u| = 1 the map restricts to a \
+            diffeomorphism of the unit circle. When its rotation number is irrational and \
+            Diophantine, e.g. the golden mean, the circle is contained in a Herman ring. \
+            These maps lie on the real axis of the plane, between the Arnold tongues of the \
+            rational rotation numbers. The plane is centered at the map with the rotation \
+            number set by the slider ρ, and colored according to the free critical point \
+            inside the unit disk."
+            .to_owned()
+    }
+
+    fn name(&self) -> String
+    {
+        "Herman Ring".to_owned()
+    }
+
+    default_bounds!();
+}
+
+impl HasJulia for HermanRing
+{
+    fn default_bounds_child(&self, _point: Cplx, _u: &Self::Param) -> Bounds
+    {
+        Bounds::centered_square(2.5)
+    }
+}
+
+impl MarkedPoints for HermanRing
+{
+    fn critical_points_child(&self, _u: &Self::Param) -> Vec<Self::Var>
+    {
+        let mut points = self.critical_points().to_vec();
+        points.push(ZERO);
+        points
+    }
+
+    fn cycles_child(&self, u: &Self::Param, period: Period) -> Vec<Self::Var>
+    {
+        match period {
+            1 => {
+                // Besides 0 and ∞, the fixed points solve u z^2 + a(1-u) z - 1 = 0
+                let a = self.params.zero;
+                let [z0, z1] = solve_quadratic(-1. / u, a * (1. - u) / u);
+                vec![ZERO, z0, z1]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+degree_impl!(HermanRing, 2);
@@ -28,6 +28,12 @@ impl<const M: i32, const N: i32> DynamicalFamily for McMullenFamily<M, N>
 {
     parameter_plane_impl!();
 
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        self.calibrated_escape_radius()
+    }
+
     fn start_point(&self, _point: Cplx, c: &Self::Param) -> Self::Var
     {
         let z0 = Self::N_FLOAT / (c * Self::M_FLOAT);
@@ -59,15 +65,15 @@ impl<const M: i32, const N: i32> DynamicalFamily for McMullenFamily<M, N>
             -czn_inv / c,
         )
     }
+}
 
+impl<const M: i32, const N: i32> DisplayDefaults for McMullenFamily<M, N>
+{
     fn name(&self) -> String
     {
         format!("McMullen Family ({M}, {N})")
     }
-}
 
-impl<const M: i32, const N: i32> FamilyDefaults for McMullenFamily<M, N>
-{
     default_bounds!();
 
     fn default_selection(&self) -> Cplx
@@ -122,3 +128,315 @@ impl<const M: i32, const N: i32> InfinityFirstReturnMap for McMullenFamily<M, N>
 
 impl<const M: i32, const N: i32> EscapeEncoding for McMullenFamily<M, N> {}
 impl<const M: i32, const N: i32> ExternalRays for McMullenFamily<M, N> {}
+
+/// The degrees `m` and `n` of a [`McMullenFamilyDyn`] plane, adjustable from spin boxes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct McMullenDegrees
+{
+    pub m: i32,
+    pub n: i32,
+}
+
+impl McMullenDegrees
+{
+    const MIN_M: i32 = 2;
+    const MIN_N: i32 = 1;
+    const MAX: i32 = 12;
+
+    #[must_use]
+    fn clamped(self) -> Self
+    {
+        Self {
+            m: self.m.clamp(Self::MIN_M, Self::MAX),
+            n: self.n.clamp(Self::MIN_N, Self::MAX),
+        }
+    }
+}
+
+impl Default for McMullenDegrees
+{
+    fn default() -> Self
+    {
+        Self { m: 3, n: 3 }
+    }
+}
+
+impl std::fmt::Display for McMullenDegrees
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "m = {}, n = {}", self.m, self.n)
+    }
+}
+
+impl Named for McMullenDegrees
+{
+    fn name(&self) -> &'static str
+    {
+        "Degrees"
+    }
+}
+
+impl Describe for McMullenDegrees
+{
+    fn describe(&self, desc_conf: &DescriptionConf) -> Option<String>
+    {
+        desc_conf.is_enabled.then(|| self.to_string())
+    }
+}
+
+impl ParamList for McMullenDegrees
+{
+    type Param = Self;
+
+    fn local_param(&self) -> &Self::Param
+    {
+        self
+    }
+    fn into_local_param(self) -> Self::Param
+    {
+        self
+    }
+}
+
+impl HasSliders for McMullenDegrees
+{
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        vec![
+            MetaParamSlider {
+                name: "m".to_owned(),
+                value: Real::from(self.m).into(),
+                min: Self::MIN_M.into(),
+                max: Self::MAX.into(),
+                complex: false,
+                integer: true,
+            },
+            MetaParamSlider {
+                name: "n".to_owned(),
+                value: Real::from(self.n).into(),
+                min: Self::MIN_N.into(),
+                max: Self::MAX.into(),
+                complex: false,
+                integer: true,
+            },
+        ]
+    }
+
+    fn set_slider(&mut self, idx: usize, value: Cplx)
+    {
+        let value = value.re.round() as i32;
+        match idx {
+            0 => self.m = value,
+            1 => self.n = value,
+            _ => {}
+        }
+        *self = self.clamped();
+    }
+}
+
+/// The same family as [`McMullenFamily`], with the degrees chosen at runtime rather than at
+/// compile time, so that they can be changed from the interface.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct McMullenFamilyDyn
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+    degrees: McMullenDegrees,
+}
+
+impl McMullenFamilyDyn
+{
+    #[must_use]
+    pub fn new(m: i32, n: i32) -> Self
+    {
+        let degrees = McMullenDegrees { m, n }.clamped();
+        let bounds = Self::bounds_for(degrees);
+        Self {
+            point_grid: PointGrid::new_by_res_y(1024, bounds),
+            compute_mode: ComputeMode::default(),
+            max_iter: 1024,
+            degrees,
+        }
+    }
+
+    fn bounds_for(McMullenDegrees { m, .. }: McMullenDegrees) -> Bounds
+    {
+        Bounds::centered_square(80. / (Real::from(m) - 1.8))
+    }
+
+    #[inline]
+    fn m_float(&self) -> Real
+    {
+        Real::from(self.degrees.m)
+    }
+
+    #[inline]
+    fn n_float(&self) -> Real
+    {
+        Real::from(self.degrees.n)
+    }
+
+    #[inline]
+    fn m_plus_n_inv(&self) -> Real
+    {
+        1. / Real::from(self.degrees.m + self.degrees.n)
+    }
+}
+
+impl Default for McMullenFamilyDyn
+{
+    fn default() -> Self
+    {
+        let McMullenDegrees { m, n } = McMullenDegrees::default();
+        Self::new(m, n)
+    }
+}
+
+impl DynamicalFamily for McMullenFamilyDyn
+{
+    parameter_plane_impl!(Cplx, Cplx, Cplx, McMullenDegrees);
+    param_map!();
+
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        self.calibrated_escape_radius()
+    }
+
+    fn start_point(&self, _point: Cplx, c: &Self::Param) -> Self::Var
+    {
+        let z0 = self.n_float() / (c * self.m_float());
+        z0.powf(self.m_plus_n_inv())
+    }
+
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        z.powi(self.degrees.m) + (c * z.powi(self.degrees.n)).inv()
+    }
+
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let zm1 = z.powi(self.degrees.m - 1);
+        let czn_inv = (c * z.powi(self.degrees.n)).inv();
+        (
+            zm1 * z + czn_inv,
+            self.m_float() * zm1 - self.n_float() * czn_inv / z,
+        )
+    }
+
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let zm1 = z.powi(self.degrees.m - 1);
+        let czn_inv = (c * z.powi(self.degrees.n)).inv();
+        (
+            zm1 * z + czn_inv,
+            self.m_float() * zm1 - self.n_float() * czn_inv / z,
+            -czn_inv / c,
+        )
+    }
+
+    #[inline]
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        self.degrees
+    }
+
+    #[inline]
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.degrees
+    }
+
+    #[inline]
+    fn set_meta_param(&mut self, value: Self::MetaParam)
+    {
+        self.set_param(value);
+    }
+
+    #[inline]
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {
+        self.degrees = value.clamped();
+    }
+}
+
+impl DisplayDefaults for McMullenFamilyDyn
+{
+    fn name(&self) -> String
+    {
+        let McMullenDegrees { m, n } = self.degrees;
+        format!("McMullen Family ({m}, {n})")
+    }
+
+    fn long_name(&self) -> String
+    {
+        self.name()
+    }
+
+    fn default_bounds(&self) -> Bounds
+    {
+        Self::bounds_for(self.degrees)
+    }
+
+    fn default_selection(&self) -> Cplx
+    {
+        ONE
+    }
+}
+
+impl HasJulia for McMullenFamilyDyn
+{
+    fn default_bounds_child(&self, _point: Cplx, _param: &Self::Param) -> Bounds
+    {
+        Bounds::centered_square(1.15)
+    }
+}
+
+impl MarkedPoints for McMullenFamilyDyn
+{
+    fn cycles_child(&self, c: &Self::Param, period: Period) -> Vec<Self::Var>
+    {
+        let McMullenDegrees { m, n } = self.degrees;
+        match period {
+            1 => {
+                let mut coeffs = vec![ZERO; (m + n + 1).try_into().unwrap_or(3)];
+                coeffs[usize::try_from(m + n).unwrap_or(2)] = *c;
+                coeffs[usize::try_from(n + 1).unwrap_or(1)] = -c;
+                coeffs[0] = ONE;
+                solve_polynomial(coeffs)
+            }
+            _ => vec![],
+        }
+    }
+
+    fn critical_points_child(&self, c: &Self::Param) -> Vec<Self::Var>
+    {
+        let w0 = self.n_float() / (c * self.m_float());
+        let z0 = w0.powf(self.m_plus_n_inv());
+        (0..(self.degrees.m + self.degrees.n))
+            .map(|k| (TAUI * f64::from(k) * self.m_plus_n_inv()).exp() * z0)
+            .collect()
+    }
+}
+
+impl InfinityFirstReturnMap for McMullenFamilyDyn
+{
+    #[inline]
+    fn degree(&self) -> AngleNum
+    {
+        self.degrees.m.into()
+    }
+
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        self.m_float()
+    }
+}
+
+impl EscapeEncoding for McMullenFamilyDyn {}
+impl ExternalRays for McMullenFamilyDyn {}
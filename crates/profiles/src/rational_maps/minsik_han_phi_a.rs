@@ -70,15 +70,15 @@ impl<const D: i32> DynamicalFamily for MinsikHanPhi<D>
     {
         ONE
     }
+}
 
+impl<const D: i32> DisplayDefaults for MinsikHanPhi<D>
+{
     fn name(&self) -> String
     {
         format!("Minsik Han Family, degree {D}")
     }
-}
 
-impl<const D: i32> FamilyDefaults for MinsikHanPhi<D>
-{
     default_bounds!();
 
     fn default_selection(&self) -> Cplx
@@ -129,7 +129,7 @@ impl<const D: i32> MarkedPoints for MinsikHanPhi<D>
 
                 solve_polynomial(coeffs)
                     .iter()
-                    .map(|z| (z * Self::D_MINUS_1))
+                    .map(|z| z * Self::D_MINUS_1)
                     .flat_map(|z| nth_roots(z, D))
                     .collect()
             }
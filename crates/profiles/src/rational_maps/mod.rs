@@ -1,7 +1,11 @@
+pub mod quad_rat_per_n_lambda;
+pub use quad_rat_per_n_lambda::{
+    Multiplier, QuadRatPer1Lambda, QuadRatPer2Lambda, QuadRatPerNLambda,
+};
 pub mod quad_rat_per_1_lambda;
-pub use quad_rat_per_1_lambda::{QuadRatPer1Lambda, QuadRatPer1LambdaParam, QuadRatPer1_1};
+pub use quad_rat_per_1_lambda::{QuadRatPer1LambdaParam, QuadRatPer1_1};
 pub mod quad_rat_per_2_lambda;
-pub use quad_rat_per_2_lambda::{QuadRatPer2Lambda, QuadRatPer2LambdaParam};
+pub use quad_rat_per_2_lambda::QuadRatPer2LambdaParam;
 pub mod quad_rat_per_2;
 pub use quad_rat_per_2::{QuadRatPer2, QuadRatPer2Cover, QuadRatPer2InfPuncture};
 pub mod quad_rat_per_3;
@@ -10,6 +14,11 @@ pub mod quad_rat_per_4;
 pub use quad_rat_per_4::QuadRatPer4;
 pub mod quad_rat_per_5;
 pub use quad_rat_per_5::QuadRatPer5;
+pub mod quad_rat_per_6;
+pub use quad_rat_per_6::QuadRatPer6;
+pub mod quad_rat_per_7;
+pub use quad_rat_per_7::QuadRatPer7;
+mod quad_rat_per_n;
 pub mod quad_rat_preper_2_1;
 pub use quad_rat_preper_2_1::QuadRatPreper21;
 pub mod quad_rat_preper_2_2;
@@ -19,8 +28,11 @@ pub use quad_rat_symmetry_locus::QuadRatSymmetryLocus;
 
 pub mod quad_rat_general;
 
+pub mod herman_ring;
+pub use herman_ring::{HermanRing, RingParams};
+
 pub mod mcmullen_family;
-pub use mcmullen_family::McMullenFamily;
+pub use mcmullen_family::{McMullenDegrees, McMullenFamily, McMullenFamilyDyn};
 
 pub mod minsik_han_phi_a;
 pub use minsik_han_phi_a::MinsikHanPhi;
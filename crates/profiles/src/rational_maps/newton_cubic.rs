@@ -1,6 +1,5 @@
 use crate::macros::{
-    default_bounds, default_bounds_impl, default_name, degree_impl, fractal_impl, has_child_impl,
-    profile_imports,
+    default_bounds, default_bounds_impl, degree_impl, fractal_impl, has_child_impl, profile_imports,
 };
 profile_imports!();
 
@@ -30,7 +29,6 @@ impl Default for NewtonCubic
 impl DynamicalFamily for NewtonCubic
 {
     parameter_plane_impl!();
-    default_name!();
 
     // f(z) = z^3 + cz - 1
     // f'(z) = 3z^2 + c
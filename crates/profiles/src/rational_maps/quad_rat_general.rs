@@ -33,7 +33,6 @@ impl DynamicalFamily for QuadRatGeneral
     type Deriv = Cplx;
     type MetaParam = NoParam;
     basic_plane_impl!();
-    default_name!();
 
     fn map(&self, z: Self::Var, CplxPair { a, b }: &Self::Param) -> Self::Var
     {
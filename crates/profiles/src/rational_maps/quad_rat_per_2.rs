@@ -34,16 +34,6 @@ impl DynamicalFamily for QuadRatPer2
     type Deriv = Cplx;
     type MetaParam = NoParam;
     basic_plane_impl!();
-    default_name!();
-
-    fn description(&self) -> String
-    {
-        "The moduli space of quadratic rational maps with a critical 2-cycle, \
-            parameterized as $f_c(z) = (z^2 + c)/(z^2 - 1)$. In these coordinates, \
-            ∞ <-> 1 is the critical 2-cycle. The plane is colored according to the \
-            activity of the free critical point 0."
-            .to_owned()
-    }
 
     #[inline]
     fn map(&self, z: Self::Var, Prm { a: _, c }: &Self::Param) -> Self::Var
@@ -102,7 +92,20 @@ impl DynamicalFamily for QuadRatPer2
     }
 }
 
-default_bounds_impl!(QuadRatPer2);
+impl DisplayDefaults for QuadRatPer2
+{
+    default_bounds!();
+
+    fn description(&self) -> String
+    {
+        "The moduli space of quadratic rational maps with a critical 2-cycle, \
+            parameterized as $f_c(z) = (z^2 + c)/(z^2 - 1)$. In these coordinates, \
+            ∞ <-> 1 is the critical 2-cycle. The plane is colored according to the \
+            activity of the free critical point 0."
+            .to_owned()
+    }
+}
+
 has_child_impl!(QuadRatPer2, 4.0);
 
 #[allow(clippy::too_many_lines)]
@@ -1021,16 +1024,6 @@ impl Default for QuadRatPer2Cover
 impl DynamicalFamily for QuadRatPer2Cover
 {
     parameter_plane_impl!();
-    default_name!();
-
-    fn description(&self) -> String
-    {
-        "The moduli space of quadratic rational maps with a critical 2-cycle, \
-            parameterized as $f_c(z) = (z^2 + c)/(z^2 - 1)$. In these coordinates, \
-            ∞ <-> 1 is the critical 2-cycle. The plane is colored according to the \
-            activity of the free critical point 0."
-            .to_owned()
-    }
 
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
@@ -1074,8 +1067,17 @@ impl DynamicalFamily for QuadRatPer2Cover
     }
 }
 
-impl FamilyDefaults for QuadRatPer2Cover
+impl DisplayDefaults for QuadRatPer2Cover
 {
+    fn description(&self) -> String
+    {
+        "The moduli space of quadratic rational maps with a critical 2-cycle, \
+            parameterized as $f_c(z) = (z^2 + c)/(z^2 - 1)$. In these coordinates, \
+            ∞ <-> 1 is the critical 2-cycle. The plane is colored according to the \
+            activity of the free critical point 0."
+            .to_owned()
+    }
+
     default_bounds!();
 }
 
@@ -1205,16 +1207,6 @@ impl Default for QuadRatPer2InfPuncture
 impl DynamicalFamily for QuadRatPer2InfPuncture
 {
     parameter_plane_impl!();
-    default_name!();
-
-    fn description(&self) -> String
-    {
-        "The moduli space of quadratic rational maps with a critical 2-cycle, \
-            parameterized as $f_c(z) = (z^2 + c)/(z^2 - 1)$. In these coordinates, \
-            ∞ <-> 1 is the critical 2-cycle. The plane is colored according to the \
-            activity of the free critical point 0."
-            .to_owned()
-    }
 
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
@@ -1266,8 +1258,17 @@ impl DynamicalFamily for QuadRatPer2InfPuncture
     }
 }
 
-impl FamilyDefaults for QuadRatPer2InfPuncture
+impl DisplayDefaults for QuadRatPer2InfPuncture
 {
+    fn description(&self) -> String
+    {
+        "The moduli space of quadratic rational maps with a critical 2-cycle, \
+            parameterized as $f_c(z) = (z^2 + c)/(z^2 - 1)$. In these coordinates, \
+            ∞ <-> 1 is the critical 2-cycle. The plane is colored according to the \
+            activity of the free critical point 0."
+            .to_owned()
+    }
+
     default_bounds!();
 }
 
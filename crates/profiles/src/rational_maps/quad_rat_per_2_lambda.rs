@@ -1,160 +1,8 @@
 use super::quad_rat_general::QuadRatGeneral;
-use crate::macros::{degree_impl, has_child_impl, horner_monic, profile_imports};
+use super::quad_rat_per_n_lambda::QuadRatPer2Lambda;
+use crate::macros::{degree_impl, horner_monic, profile_imports};
 profile_imports!();
 
-#[derive(Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct QuadRatPer2Lambda
-{
-    general_plane: QuadRatGeneral,
-    multiplier: Cplx,
-}
-
-impl Default for QuadRatPer2Lambda
-{
-    fn default() -> Self
-    {
-        let general_plane = QuadRatGeneral::default();
-        Self {
-            general_plane,
-            multiplier: ZERO,
-        }
-    }
-}
-
-impl DynamicalFamily for QuadRatPer2Lambda
-{
-    type Var = Cplx;
-    type Param = CplxPair;
-    type Deriv = Cplx;
-    type MetaParam = Cplx;
-
-    fn max_iter(&self) -> IterCount
-    {
-        self.general_plane.max_iter
-    }
-
-    fn max_iter_mut(&mut self) -> &mut IterCount
-    {
-        &mut self.general_plane.max_iter
-    }
-
-    fn set_max_iter(&mut self, new_max_iter: IterCount)
-    {
-        self.general_plane.max_iter = new_max_iter;
-    }
-
-    #[must_use]
-    fn with_max_iter(mut self, max_iter: IterCount) -> Self
-    {
-        self.general_plane.max_iter = max_iter;
-        self
-    }
-
-    #[inline]
-    fn point_grid(&self) -> &PointGrid
-    {
-        &self.general_plane.point_grid
-    }
-
-    #[inline]
-    fn point_grid_mut(&mut self) -> &mut PointGrid
-    {
-        &mut self.general_plane.point_grid
-    }
-
-    #[inline]
-    fn with_point_grid(mut self, point_grid: PointGrid) -> Self
-    {
-        self.general_plane.point_grid = point_grid;
-        self
-    }
-
-    #[inline]
-    fn compute_mode(&self) -> ComputeMode
-    {
-        self.general_plane.compute_mode()
-    }
-
-    #[inline]
-    fn compute_mode_mut(&mut self) -> &mut ComputeMode
-    {
-        self.general_plane.compute_mode_mut()
-    }
-
-    #[inline]
-    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
-    {
-        self.general_plane.map(z, c)
-    }
-
-    fn param_map(&self, t: Cplx) -> Self::Param
-    {
-        let alpha = 0.25 * t;
-        let u = self.multiplier * t - 4.;
-        CplxPair {
-            a: alpha * t * (self.multiplier + u),
-            b: alpha * u,
-        }
-    }
-
-    #[inline]
-    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
-    {
-        self.general_plane.map_and_multiplier(z, c)
-    }
-
-    #[inline]
-    fn start_point(&self, t: Cplx, c: &Self::Param) -> Self::Var
-    {
-        self.general_plane.start_point(t, c)
-    }
-
-    #[inline]
-    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
-    {
-        self.multiplier
-    }
-    #[inline]
-    fn set_param(&mut self, lambda: <Self::MetaParam as ParamList>::Param)
-    {
-        self.multiplier = lambda;
-    }
-
-    #[inline]
-    fn name(&self) -> String
-    {
-        "QuadRat Per(2, λ)".to_owned()
-        // format!("QuadRat Per(2, λ); λ={:.4}", self.multiplier)
-    }
-}
-
-impl FamilyDefaults for QuadRatPer2Lambda
-{
-    fn default_bounds(&self) -> Bounds
-    {
-        let r = 4. / (self.multiplier.norm() + 0.01);
-        Bounds::centered_square(r)
-    }
-}
-
-has_child_impl!(QuadRatPer2Lambda);
-
-impl MarkedPoints for QuadRatPer2Lambda
-{
-    #[inline]
-    fn critical_points_child(&self, c: &Self::Param) -> Vec<Self::Var>
-    {
-        self.general_plane.critical_points_child(c)
-    }
-
-    #[inline]
-    fn cycles_child(&self, c: &Self::Param, period: Period) -> Vec<Self::Var>
-    {
-        self.general_plane.cycles_child(c, period)
-    }
-}
-
 #[derive(Clone, Debug)]
 pub struct QuadRatPer2LambdaParam
 {
@@ -234,16 +82,16 @@ impl DynamicalFamily for QuadRatPer2LambdaParam
     {
         (point, ONE)
     }
+}
 
+impl DisplayDefaults for QuadRatPer2LambdaParam
+{
     #[inline]
     fn name(&self) -> String
     {
         "QuadRat Per(2, λ) λ-plane".to_owned()
     }
-}
 
-impl FamilyDefaults for QuadRatPer2LambdaParam
-{
     default_bounds!();
 
     fn default_selection(&self) -> Cplx
@@ -281,13 +129,8 @@ impl From<QuadRatPer2LambdaParam> for QuadRatPer2Lambda
             compute_mode: ComputeMode::default(),
             max_iter: parent.max_iter(),
         };
-        Self {
-            general_plane,
-            multiplier: point,
-        }
-        .with_default_bounds()
+        Self::new(general_plane, point).with_default_bounds()
     }
 }
 
-degree_impl!(QuadRatPer2Lambda, 1, 1);
 degree_impl!(QuadRatPer2LambdaParam, 1, 1);
@@ -37,17 +37,6 @@ impl DynamicalFamily for QuadRatPer3
     type Deriv = Cplx;
     type MetaParam = NoParam;
     basic_plane_impl!();
-    default_name!();
-
-    fn description(&self) -> String
-    {
-        "The moduli space of quadratic rational maps with a critical 3-cycle, \
-            parameterized as $f_c(z) = (z^2 + c^3 - c - 1)/(z^2 - c^2)$. \
-            In these coordinates, ∞ -> 1 -> -c is the critical 3-cycle. \
-            The plane is colored according to the \
-            activity of the free critical point 0."
-            .to_owned()
-    }
 
     fn start_point(&self, _point: Cplx, _c: &Prm) -> Cplx
     {
@@ -103,7 +92,20 @@ impl DynamicalFamily for QuadRatPer3
 }
 
 has_child_impl!(QuadRatPer3, 4.0);
-default_bounds_impl!(QuadRatPer3);
+impl DisplayDefaults for QuadRatPer3
+{
+    default_bounds!();
+
+    fn description(&self) -> String
+    {
+        "The moduli space of quadratic rational maps with a critical 3-cycle, \
+            parameterized as $f_c(z) = (z^2 + c^3 - c - 1)/(z^2 - c^2)$. \
+            In these coordinates, ∞ -> 1 -> -c is the critical 3-cycle. \
+            The plane is colored according to the \
+            activity of the free critical point 0."
+            .to_owned()
+    }
+}
 
 impl MarkedPoints for QuadRatPer3
 {
@@ -32,17 +32,6 @@ impl DynamicalFamily for QuadRatPer4
     type Deriv = Cplx;
     type MetaParam = NoParam;
     basic_plane_impl!();
-    default_name!();
-
-    fn description(&self) -> String
-    {
-        "The moduli space of quadratic rational maps with a critical 4-cycle, \
-            parameterized as $f_c(z) = (z-c)(z(c-1)-2c+1)/(z^2(c-1))$. \
-            In these coordinates, 0 -> ∞ -> 1 -> c is the critical 4-cycle. \
-            The plane is colored according to the \
-            activity of the free critical point 0."
-            .to_owned()
-    }
 
     #[inline]
     fn param_map(&self, t: Cplx) -> Cplx
@@ -114,8 +103,18 @@ impl DynamicalFamily for QuadRatPer4
     }
 }
 
-impl FamilyDefaults for QuadRatPer4
+impl DisplayDefaults for QuadRatPer4
 {
+    fn description(&self) -> String
+    {
+        "The moduli space of quadratic rational maps with a critical 4-cycle, \
+            parameterized as $f_c(z) = (z-c)(z(c-1)-2c+1)/(z^2(c-1))$. \
+            In these coordinates, 0 -> ∞ -> 1 -> c is the critical 4-cycle. \
+            The plane is colored according to the \
+            activity of the free critical point 0."
+            .to_owned()
+    }
+
     default_bounds!();
 }
 
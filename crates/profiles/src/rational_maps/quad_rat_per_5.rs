@@ -73,7 +73,6 @@ impl DynamicalFamily for QuadRatPer5
     type MetaParam = NoParam;
 
     basic_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
@@ -128,7 +127,7 @@ impl DynamicalFamily for QuadRatPer5
     }
 }
 
-impl FamilyDefaults for QuadRatPer5
+impl DisplayDefaults for QuadRatPer5
 {
     default_bounds!();
 
@@ -215,6 +214,7 @@ impl EscapeEncoding for QuadRatPer5
             return PointInfo::Escaping {
                 potential: (iters as f64) - 5.,
                 phase,
+                angle: None,
             };
         }
 
@@ -223,7 +223,11 @@ impl EscapeEncoding for QuadRatPer5
         let delta = top_coeff(*a, *b).norm_sqr().log2();
         let residual = ((u + delta) / (v + delta)).log2();
         let potential = (residual as IterCountSmooth).mul_add(5., iters as f64);
-        PointInfo::Escaping { potential, phase }
+        PointInfo::Escaping {
+            potential,
+            phase,
+            angle: None,
+        }
     }
 }
 impl ExternalRays for QuadRatPer5 {}
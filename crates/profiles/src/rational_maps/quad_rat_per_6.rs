@@ -0,0 +1,178 @@
+use super::quad_rat_per_n::{continue_param, top_coeff};
+use crate::macros::{horner, horner_monic, profile_imports};
+profile_imports!();
+
+// Quadratic rational maps with a critical 6-cycle: 0 => ∞ -> 1 -> 1 + a + b -> ... -> 0.
+// The plane is the coordinate a; see `quad_rat_per_n` for how b is chosen.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuadRatPer6
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    max_iter: IterCount,
+}
+impl QuadRatPer6
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds {
+        min_x: -4.,
+        max_x: 3.,
+        min_y: -3.5,
+        max_y: 3.5,
+    };
+    /// A point of the curve on the real line, from which the others are continued.
+    const BASE_PARAM: CplxPair = CplxPair {
+        a: Cplx::new(-2., 0.),
+        b: Cplx::new(-0.884_146_802_924_771, 0.),
+    };
+}
+impl Default for QuadRatPer6
+{
+    fractal_impl!();
+}
+
+impl DynamicalFamily for QuadRatPer6
+{
+    type Var = Cplx;
+    type Param = CplxPair;
+    type Deriv = Cplx;
+    type MetaParam = NoParam;
+
+    basic_plane_impl!();
+
+    #[inline]
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        let z2 = z.powi(2);
+        1. + (c.a * z + c.b) / z2
+    }
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let z2 = z.powi(2);
+        let az = c.a * z;
+        (1. + (az + c.b) / z2, -(az + 2. * c.b) / (z2 * z))
+    }
+
+    #[inline]
+    fn start_point(&self, _point: Cplx, CplxPair { a, b }: &Self::Param) -> Self::Var
+    {
+        -2. * b / a
+    }
+
+    #[inline]
+    fn param_map(&self, t: Cplx) -> Self::Param
+    {
+        continue_param(6, Self::BASE_PARAM, t)
+    }
+
+    fn escape_radius(&self) -> Real
+    {
+        1e24
+    }
+}
+
+impl DisplayDefaults for QuadRatPer6
+{
+    default_bounds!();
+
+    fn default_selection(&self) -> Cplx
+    {
+        Self::BASE_PARAM.a
+    }
+}
+
+impl HasJulia for QuadRatPer6
+{
+    fn default_bounds_child(&self, _point: Cplx, param: &Self::Param) -> Bounds
+    {
+        Bounds::square(20., self.start_point(ONE, param))
+    }
+}
+
+impl MarkedPoints for QuadRatPer6
+{
+    fn cycles_child(&self, CplxPair { a, b }: &Self::Param, period: Period) -> Vec<Self::Var>
+    {
+        match period {
+            1 => solve_cubic(-b, -a, -ONE).to_vec(),
+            2 => solve_quadratic(*b, a - b).to_vec(),
+            3 => {
+                let b2 = b.powi(2);
+                let b3 = b * b2;
+                let u = 3. * (b + 1.);
+                let ub = u * b;
+                let coeffs = [
+                    b3 * (1. + a + b),
+                    b2 * horner!(a, -b, u, 3.),
+                    b * horner!(a, ub, 2. * b, u + b, 3.),
+                    horner_monic!(a, b2 * (b - 2.), b * (5. * b + 6.), 7. * b, u - 2.),
+                    horner_monic!(a, ub + b2, ub, u + b, 4.),
+                    horner!(a, -b, u, 2. * b + 5., 2.),
+                    horner_monic!(a, b2 + 2. * b + 1., 2. * b + 2.),
+                ];
+                solve_polynomial(coeffs)
+            }
+            _ => vec![],
+        }
+    }
+
+    #[inline]
+    fn critical_points_child(&self, param: &Self::Param) -> Vec<Self::Var>
+    {
+        vec![self.start_point(ONE, param)]
+    }
+}
+
+impl InfinityFirstReturnMap for QuadRatPer6
+{
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        2.0
+    }
+
+    #[inline]
+    fn degree(&self) -> AngleNum
+    {
+        2
+    }
+
+    #[inline]
+    fn escaping_period(&self) -> Period
+    {
+        6
+    }
+}
+
+impl EscapeEncoding for QuadRatPer6
+{
+    fn encode_escaping_point(
+        &self,
+        iters: IterCount,
+        z: Cplx,
+        param: &Self::Param,
+    ) -> PointInfo<Self::Deriv>
+    {
+        let phase = Some((iters % 6) as Period);
+        if z.is_nan() {
+            return PointInfo::Escaping {
+                potential: (iters as f64) - 6.,
+                phase,
+                angle: None,
+            };
+        }
+
+        let u = self.escape_radius().log2();
+        let v = z.norm_sqr().log2();
+        let delta = top_coeff(6, *param).norm_sqr().log2();
+        let residual = ((u + delta) / (v + delta)).log2();
+        let potential = (residual as IterCountSmooth).mul_add(6., iters as f64);
+        PointInfo::Escaping {
+            potential,
+            phase,
+            angle: None,
+        }
+    }
+}
+impl ExternalRays for QuadRatPer6 {}
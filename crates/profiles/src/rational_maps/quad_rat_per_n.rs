@@ -0,0 +1,102 @@
+//! Shared computations for the curves Per(n) of quadratic rational maps
+//! `f(z) = 1 + (az + b)/z^2` with a critical `n`-cycle `0 -> ∞ -> 1 -> ... -> 0`.
+//!
+//! From period 6 on these curves have genus greater than one, so they cannot be uniformized
+//! the way Per(5) is. Instead, the parameter `a` is used as a coordinate, and `b` is found by
+//! continuing a known point of the curve along the segment from its value of `a`.
+use dynamo_common::prelude::*;
+
+/// Maximum number of steps, successful or not, taken when continuing a point of the curve.
+const MAX_CONTINUATION_STEPS: usize = 256;
+/// Newton iterations allowed for correcting each step of the continuation.
+const NEWTON_STEPS: usize = 8;
+/// Squared relative size of a Newton correction at which it is taken to have converged.
+const NEWTON_TOLERANCE: Real = 1e-14;
+/// Squared relative change in `b` allowed in one step, to keep to the sheet being continued.
+const MAX_JUMP: Real = 1e-2;
+
+/// Newton step in `b` towards a zero of `f^(n-2)(1)`, with the factors `f^(m-2)(1)` vanishing on
+/// the curves Per(m) for proper divisors `m` of `n` divided out.
+///
+/// The orbit of 1 is computed in homogeneous coordinates, so that the residual is a polynomial in
+/// `b` and has no poles near the curves Per(m) for other `m` to throw off Newton's method.
+fn newton_step(period: Period, a: Cplx, b: Cplx) -> Cplx
+{
+    let (mut p, mut q) = (ONE, ONE);
+    let (mut dp, mut dq) = (ZERO, ZERO);
+    // Logarithmic derivative of the product of the divided factors
+    let mut dlog_factors = ZERO;
+    for k in 2..period {
+        if k > 2 && period.is_multiple_of(k) {
+            dlog_factors += dp / p;
+        }
+        let p2 = p * p;
+        let pq = p * q;
+        let q2 = q * q;
+        (dp, dq) = (
+            2. * p * dp + a * (dp * q + p * dq) + 2. * b * q * dq + q2,
+            2. * p * dp,
+        );
+        (p, q) = (p2 + a * pq + b * q2, p2);
+    }
+    p / (dp - p * dlog_factors)
+}
+
+/// Newton's method in `b` started from `b`, if it converges quickly to a nearby point of the curve.
+fn correct(period: Period, a: Cplx, b: Cplx) -> Option<Cplx>
+{
+    let mut b_next = b;
+    for _ in 0..NEWTON_STEPS {
+        let delta = newton_step(period, a, b_next);
+        b_next -= delta;
+        if delta.norm_sqr() <= NEWTON_TOLERANCE * (1. + b_next.norm_sqr()) {
+            return ((b_next - b).norm_sqr() <= MAX_JUMP * (1. + b.norm_sqr())).then_some(b_next);
+        }
+    }
+    None
+}
+
+/// The point of Per(n) over `a`, found by continuing the point `base` along the segment from
+/// `base.a`, with steps shortened wherever the curve turns quickly. The result depends on the
+/// sheet of the base point and jumps across branch cuts. It is NaN if the continuation gets stuck,
+/// as it does on the ray beyond the degenerate map `1 - 1/z` over `a = -1`, where all the sheets
+/// meet.
+pub(super) fn continue_param(period: Period, base: CplxPair, a: Cplx) -> CplxPair
+{
+    let a_at = |s: Real| base.a + (a - base.a) * s;
+    let mut s = 0.;
+    let mut b = base.b;
+    let mut step: Real = 0.125;
+    for _ in 0..MAX_CONTINUATION_STEPS {
+        if s >= 1. {
+            break;
+        }
+        let s_next = (s + step).min(1.);
+        if let Some(b_next) = correct(period, a_at(s_next), b) {
+            s = s_next;
+            b = b_next;
+            step *= 2.;
+        } else {
+            step *= 0.5;
+        }
+    }
+    if s < 1. {
+        b = Cplx::new(Real::NAN, Real::NAN);
+    }
+    CplxPair::from((a, b))
+}
+
+/// Leading coefficient `T` of the first return map `f^n(z) ≈ T z^2` near infinity, used for smooth
+/// coloring of the points attracted to the critical cycle.
+pub(super) fn top_coeff(period: Period, CplxPair { a, b }: CplxPair) -> Cplx
+{
+    // f maps z near infinity to 1 + a/z, and f^(n-2) then maps 1 + ε to about Dε near 0
+    let mut z = ONE;
+    let mut d = ONE;
+    for _ in 2..period {
+        let z2 = z * z;
+        d *= -(a * z + 2. * b) / (z2 * z);
+        z = 1. + (a * z + b) / z2;
+    }
+    b / (d * a).powi(2)
+}
@@ -0,0 +1,312 @@
+use super::quad_rat_general::QuadRatGeneral;
+use crate::macros::{degree_impl, profile_imports};
+profile_imports!();
+
+/// The multiplier of the marked cycle of a [`QuadRatPerNLambda`] plane, adjustable from a slider.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Multiplier(pub Cplx);
+
+impl Multiplier
+{
+    /// Range of the real and imaginary parts of the multiplier offered by the slider.
+    const SLIDER_RANGE: Real = 4.;
+}
+
+impl std::fmt::Display for Multiplier
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.0.fmt(f)
+    }
+}
+
+impl Named for Multiplier
+{
+    fn name(&self) -> &'static str
+    {
+        "λ"
+    }
+}
+
+impl Describe for Multiplier
+{
+    fn describe(&self, desc_conf: &DescriptionConf) -> Option<String>
+    {
+        self.0.describe(desc_conf)
+    }
+}
+
+impl ParamList for Multiplier
+{
+    type Param = Cplx;
+
+    fn local_param(&self) -> &Self::Param
+    {
+        &self.0
+    }
+    fn into_local_param(self) -> Self::Param
+    {
+        self.0
+    }
+}
+
+impl HasSliders for Multiplier
+{
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        vec![MetaParamSlider {
+            name: "λ".to_owned(),
+            value: self.0,
+            min: -Self::SLIDER_RANGE,
+            max: Self::SLIDER_RANGE,
+            complex: true,
+            integer: false,
+        }]
+    }
+
+    fn set_slider(&mut self, idx: usize, value: Cplx)
+    {
+        if idx == 0 {
+            self.0 = value;
+        }
+    }
+}
+
+// Quadratic rational maps of the form f_t(z) = (z^2+a_t)/(z^2+b_t), with critical points 0 and
+// infinity, and a marked cycle of period N and multiplier λ.
+// For N = 1, the fixed point is z0 = -t/2.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuadRatPerNLambda<const N: Period>
+{
+    general_plane: QuadRatGeneral,
+    multiplier: Cplx,
+    tolerance: Real,
+}
+
+pub type QuadRatPer1Lambda = QuadRatPerNLambda<1>;
+pub type QuadRatPer2Lambda = QuadRatPerNLambda<2>;
+
+impl<const N: Period> QuadRatPerNLambda<N>
+{
+    /// Fails to compile for periods whose curves Per(N, λ) have no parameterization here.
+    const SUPPORTED_PERIOD: () = assert!(
+        N == 1 || N == 2,
+        "Per(N, λ) is only implemented for N = 1, 2"
+    );
+
+    #[must_use]
+    pub fn new(general_plane: QuadRatGeneral, multiplier: Cplx) -> Self
+    {
+        let () = Self::SUPPORTED_PERIOD;
+        Self {
+            general_plane,
+            multiplier,
+            tolerance: Self::compute_tolerance(multiplier),
+        }
+    }
+
+    /// Tolerance for detecting convergence to the marked fixed point, which is slow when its
+    /// multiplier is near the unit circle.
+    fn compute_tolerance(multiplier: Cplx) -> Real
+    {
+        let err = multiplier.norm() - 1.;
+        if err > 1e-3 {
+            return 1e-12;
+        }
+        let err2 = -1e8 * err * err;
+        err2.exp2().mul_add(1e-8, 1e-12)
+    }
+}
+
+impl<const N: Period> Default for QuadRatPerNLambda<N>
+{
+    fn default() -> Self
+    {
+        Self::new(QuadRatGeneral::default(), ZERO)
+    }
+}
+
+impl<const N: Period> DynamicalFamily for QuadRatPerNLambda<N>
+{
+    type Var = Cplx;
+    type Param = CplxPair;
+    type Deriv = Cplx;
+    type MetaParam = Multiplier;
+
+    #[inline]
+    fn max_iter(&self) -> IterCount
+    {
+        self.general_plane.max_iter
+    }
+
+    #[inline]
+    fn max_iter_mut(&mut self) -> &mut IterCount
+    {
+        &mut self.general_plane.max_iter
+    }
+
+    #[inline]
+    fn set_max_iter(&mut self, new_max_iter: IterCount)
+    {
+        self.general_plane.max_iter = new_max_iter;
+    }
+
+    fn with_max_iter(mut self, max_iter: IterCount) -> Self
+    {
+        self.general_plane.max_iter = max_iter;
+        self
+    }
+
+    #[inline]
+    fn point_grid(&self) -> &PointGrid
+    {
+        &self.general_plane.point_grid
+    }
+
+    #[inline]
+    fn point_grid_mut(&mut self) -> &mut PointGrid
+    {
+        &mut self.general_plane.point_grid
+    }
+
+    #[inline]
+    fn with_point_grid(mut self, point_grid: PointGrid) -> Self
+    {
+        self.general_plane.point_grid = point_grid;
+        self
+    }
+
+    #[inline]
+    fn compute_mode(&self) -> ComputeMode
+    {
+        self.general_plane.compute_mode()
+    }
+
+    #[inline]
+    fn compute_mode_mut(&mut self) -> &mut ComputeMode
+    {
+        self.general_plane.compute_mode_mut()
+    }
+
+    fn param_map(&self, t: Cplx) -> Self::Param
+    {
+        let lambda = self.multiplier;
+        match N {
+            1 => {
+                let alpha = 0.25 * t / lambda;
+                CplxPair {
+                    a: alpha * t * (t - lambda + 2.),
+                    b: -alpha * (4. + (lambda + 2.) * t),
+                }
+            }
+            _ => {
+                let alpha = 0.25 * t;
+                let u = lambda * t - 4.;
+                CplxPair {
+                    a: alpha * t * (lambda + u),
+                    b: alpha * u,
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        self.general_plane.map(z, c)
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        self.general_plane.map_and_multiplier(z, c)
+    }
+
+    #[inline]
+    fn start_point(&self, t: Cplx, c: &Self::Param) -> Self::Var
+    {
+        self.general_plane.start_point(t, c)
+    }
+
+    #[inline]
+    fn start_point_d(&self, point: Cplx, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        self.general_plane.start_point_d(point, c)
+    }
+
+    #[inline]
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        Multiplier(self.multiplier)
+    }
+
+    #[inline]
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.multiplier
+    }
+
+    #[inline]
+    fn set_meta_param(&mut self, Multiplier(lambda): Self::MetaParam)
+    {
+        self.set_param(lambda);
+    }
+
+    #[inline]
+    fn set_param(&mut self, lambda: <Self::MetaParam as ParamList>::Param)
+    {
+        self.multiplier = lambda;
+        self.tolerance = Self::compute_tolerance(lambda);
+    }
+
+    #[inline]
+    fn periodicity_tolerance(&self) -> Real
+    {
+        match N {
+            1 => self.tolerance,
+            _ => self.point_grid().bounds.area() * 1e-14,
+        }
+    }
+}
+
+impl<const N: Period> DisplayDefaults for QuadRatPerNLambda<N>
+{
+    #[inline]
+    fn name(&self) -> String
+    {
+        format!("QuadRat Per({N}, λ)")
+    }
+
+    fn default_bounds(&self) -> Bounds
+    {
+        match N {
+            1 => Bounds::centered_square(4.0),
+            _ => Bounds::centered_square(4. / (self.multiplier.norm() + 0.01)),
+        }
+    }
+}
+
+impl<const N: Period> HasJulia for QuadRatPerNLambda<N> {}
+
+impl<const N: Period> MarkedPoints for QuadRatPerNLambda<N>
+{
+    #[inline]
+    fn critical_points_child(&self, c: &Self::Param) -> Vec<Self::Var>
+    {
+        self.general_plane.critical_points_child(c)
+    }
+
+    #[inline]
+    fn cycles_child(&self, c: &Self::Param, period: Period) -> Vec<Self::Var>
+    {
+        self.general_plane.cycles_child(c, period)
+    }
+}
+
+impl<const N: Period> InfinityFirstReturnMap for QuadRatPerNLambda<N>
+{
+    degree_impl!(1, 1);
+}
+impl<const N: Period> EscapeEncoding for QuadRatPerNLambda<N> {}
+impl<const N: Period> ExternalRays for QuadRatPerNLambda<N> {}
@@ -28,7 +28,6 @@ impl Default for QuadRatPreper21
 impl DynamicalFamily for QuadRatPreper21
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
@@ -57,7 +56,7 @@ impl DynamicalFamily for QuadRatPreper21
     }
 }
 
-impl FamilyDefaults for QuadRatPreper21
+impl DisplayDefaults for QuadRatPreper21
 {
     default_bounds!();
 }
@@ -159,6 +158,7 @@ impl EscapeEncoding for QuadRatPreper21
             return PointInfo::Escaping {
                 potential: (iters - 1) as IterCountSmooth,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -170,6 +170,7 @@ impl EscapeEncoding for QuadRatPreper21
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
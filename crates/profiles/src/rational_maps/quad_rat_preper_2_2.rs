@@ -32,7 +32,6 @@ impl DynamicalFamily for QuadRatPreper22
     type Deriv = Cplx;
     type MetaParam = NoParam;
     basic_plane_impl!();
-    default_name!();
 
     fn map(&self, z: Self::Var, CplxPair { a, b }: &Self::Param) -> Self::Var
     {
@@ -123,6 +122,7 @@ impl EscapeEncoding for QuadRatPreper22
             return PointInfo::Escaping {
                 potential: (iters as f64) - 2.,
                 phase,
+                angle: None,
             };
         }
 
@@ -131,7 +131,11 @@ impl EscapeEncoding for QuadRatPreper22
         let v = z.norm_sqr().log(expansion_rate);
         let residual = u - v;
         let potential = 2.0f64.mul_add(residual as IterCountSmooth, iters as IterCountSmooth);
-        PointInfo::Escaping { potential, phase }
+        PointInfo::Escaping {
+            potential,
+            phase,
+            angle: None,
+        }
     }
 }
 
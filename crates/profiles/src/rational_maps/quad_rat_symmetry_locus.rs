@@ -27,7 +27,6 @@ impl Default for QuadRatSymmetryLocus
 impl DynamicalFamily for QuadRatSymmetryLocus
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
@@ -140,6 +139,7 @@ impl EscapeEncoding for QuadRatSymmetryLocus
             return PointInfo::Escaping {
                 potential: (iters as f64) - 2.,
                 phase: None,
+                angle: None,
             };
         }
 
@@ -151,6 +151,7 @@ impl EscapeEncoding for QuadRatSymmetryLocus
         PointInfo::Escaping {
             potential,
             phase: None,
+            angle: None,
         }
     }
 }
@@ -1,8 +1,8 @@
 use dynamo_color::{Coloring, IncoloringAlgorithm};
 
 use crate::macros::{
-    basic_plane_impl, default_bounds, default_bounds_impl, default_name,
-    degree_impl_transcendental, fractal_impl, has_child_impl, profile_imports,
+    basic_plane_impl, default_bounds, default_bounds_impl, degree_impl_transcendental, fractal_impl,
+    has_child_impl, profile_imports,
 };
 profile_imports!();
 
@@ -33,7 +33,6 @@ impl Default for CosineAdd
 impl DynamicalFamily for CosineAdd
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
@@ -60,27 +59,16 @@ impl DynamicalFamily for CosineAdd
     }
 
     #[inline]
-    fn extra_stop_condition(
-        &self,
-        z: Self::Var,
-        _c: &Self::Param,
-        iter: IterCount,
-    ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
+    fn escape_test(&self) -> EscapeTest
     {
-        if z.im.abs() > 350. {
-            Some(EscapeResult::Escaped {
-                iters: iter,
-                final_value: z,
-            })
-        } else if z.re.abs() > 1e15 {
-            Some(EscapeResult::Unknown)
-        } else {
-            None
+        EscapeTest::ImagPart {
+            bound: 350.,
+            overflow: 1e15,
         }
     }
 }
 
-impl FamilyDefaults for CosineAdd
+impl DisplayDefaults for CosineAdd
 {
     default_bounds!();
 }
@@ -119,7 +107,6 @@ impl Default for Cosine
 impl DynamicalFamily for Cosine
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, lambda: &Cplx) -> Cplx
@@ -148,22 +135,11 @@ impl DynamicalFamily for Cosine
     }
 
     #[inline]
-    fn extra_stop_condition(
-        &self,
-        z: Self::Var,
-        _c: &Self::Param,
-        iter: IterCount,
-    ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
+    fn escape_test(&self) -> EscapeTest
     {
-        if z.im.abs() > 350. {
-            Some(EscapeResult::Escaped {
-                iters: iter,
-                final_value: z,
-            })
-        } else if z.re.abs() > 1e15 {
-            Some(EscapeResult::Unknown)
-        } else {
-            None
+        EscapeTest::ImagPart {
+            bound: 350.,
+            overflow: 1e15,
         }
     }
 }
@@ -198,7 +174,6 @@ impl Default for SineWander
 impl DynamicalFamily for SineWander
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
@@ -225,27 +200,16 @@ impl DynamicalFamily for SineWander
     }
 
     #[inline]
-    fn extra_stop_condition(
-        &self,
-        z: Self::Var,
-        _c: &Self::Param,
-        iter: IterCount,
-    ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
+    fn escape_test(&self) -> EscapeTest
     {
-        if z.im.abs() > 350. {
-            Some(EscapeResult::Escaped {
-                iters: iter,
-                final_value: z,
-            })
-        } else if z.re.abs() > 1e15 {
-            Some(EscapeResult::Unknown)
-        } else {
-            None
+        EscapeTest::ImagPart {
+            bound: 350.,
+            overflow: 1e15,
         }
     }
 }
 
-impl FamilyDefaults for SineWander
+impl DisplayDefaults for SineWander
 {
     default_bounds!();
 
@@ -351,7 +315,6 @@ impl DynamicalFamily for CoshNewton
     type MetaParam = NoParam;
     type Deriv = Cplx;
     basic_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, _: &NoParam) -> Cplx
@@ -408,15 +371,6 @@ impl DynamicalFamily for CoshNewton
     {
         PlaneType::Dynamical
     }
-
-    #[inline]
-    fn internal_potential_coloring(&self) -> IncoloringAlgorithm
-    {
-        IncoloringAlgorithm::InternalPotential {
-            periodicity_tolerance: self.periodicity_tolerance(),
-            crit_degree: 3.3,
-        }
-    }
 }
 
 impl HasChild<Self> for CoshNewton
@@ -427,8 +381,17 @@ impl HasChild<Self> for CoshNewton
     }
 }
 
-impl FamilyDefaults for CoshNewton
+impl DisplayDefaults for CoshNewton
 {
+    #[inline]
+    fn internal_potential_coloring(&self) -> IncoloringAlgorithm
+    {
+        IncoloringAlgorithm::InternalPotential {
+            periodicity_tolerance: self.periodicity_tolerance(),
+            crit_degree: 3.3,
+        }
+    }
+
     default_bounds!();
     fn default_coloring(&self) -> Coloring
     {
@@ -22,6 +22,19 @@ impl Exponential
         max_y: 7.,
     };
 }
+/// Orbits escape to the right. Far to the left, the map is close to 0 whatever the imaginary
+/// part, so only large imaginary parts elsewhere cost precision.
+fn escape_verdict(z: Cplx) -> EscapeVerdict
+{
+    if z.re > 250. {
+        EscapeVerdict::Escaped
+    } else if z.re >= -50. && z.im.abs() > 1e15 {
+        EscapeVerdict::Unknown
+    } else {
+        EscapeVerdict::Running
+    }
+}
+
 impl Default for Exponential
 {
     fractal_impl!();
@@ -30,7 +43,6 @@ impl Default for Exponential
 impl DynamicalFamily for Exponential
 {
     parameter_plane_impl!();
-    default_name!();
 
     #[inline]
     fn map(&self, z: Cplx, lambda: &Cplx) -> Cplx
@@ -46,25 +58,9 @@ impl DynamicalFamily for Exponential
     }
 
     #[inline]
-    fn extra_stop_condition(
-        &self,
-        z: Self::Var,
-        _c: &Self::Param,
-        iter: IterCount,
-    ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
+    fn escape_test(&self) -> EscapeTest
     {
-        if z.re > 250. {
-            Some(EscapeResult::Escaped {
-                iters: iter,
-                final_value: z,
-            })
-        } else if z.re < -50. {
-            None
-        } else if z.im.abs() > 1e15 {
-            Some(EscapeResult::Unknown)
-        } else {
-            None
-        }
+        EscapeTest::Custom(escape_verdict)
     }
 
     #[inline]
@@ -80,9 +76,16 @@ impl DynamicalFamily for Exponential
     {
         ZERO
     }
+
+    /// With `z = exp(w)`, the map `z -> λ exp(z)` lifts to `w -> exp(w) + log λ`.
+    #[inline]
+    fn log_lift(&self, w: Cplx, lambda: &Cplx) -> Option<Cplx>
+    {
+        Some(w.exp() + lambda.ln())
+    }
 }
 
-impl FamilyDefaults for Exponential
+impl DisplayDefaults for Exponential
 {
     default_bounds!();
 }
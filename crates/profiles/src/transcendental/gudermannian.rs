@@ -1,8 +1,9 @@
 use dynamo_color::{Coloring, IncoloringAlgorithm};
+use dynamo_common::types::dual::Scalar;
 
 use crate::macros::{
-    default_bounds, default_bounds_impl, default_name, degree_impl_transcendental, fractal_impl,
-    has_child_impl, profile_imports,
+    auto_diff_impl, default_bounds, default_bounds_impl, degree_impl_transcendental,
+    fractal_impl, has_child_impl, profile_imports,
 };
 profile_imports!();
 
@@ -29,30 +30,20 @@ impl Default for Gudermannian
     fractal_impl!();
 }
 
-impl DynamicalFamily for Gudermannian
+impl GenericMap for Gudermannian
 {
-    parameter_plane_impl!();
-    default_name!();
-
     #[inline]
-    fn map(&self, z: Cplx, lambda: &Cplx) -> Cplx
+    fn map_generic<T: Scalar>(&self, z: T, lambda: T) -> T
     {
         z.sinh().atan() + lambda
     }
+}
 
-    #[inline]
-    fn map_and_multiplier(&self, z: Self::Var, lambda: &Self::Param) -> (Self::Var, Self::Deriv)
-    {
-        (z.sinh().atan() + lambda, z.cosh().inv())
-    }
+impl DynamicalFamily for Gudermannian
+{
+    parameter_plane_impl!();
 
-    #[inline]
-    fn gradient(&self, z: Self::Var, lambda: &Self::Param)
-        -> (Self::Var, Self::Deriv, Self::Deriv)
-    {
-        let gd = z.sinh().atan();
-        (gd + lambda, z.cosh().inv(), ONE)
-    }
+    auto_diff_impl!();
 
     #[inline]
     fn start_point(&self, _point: Cplx, lambda: &Self::Param) -> Self::Var
@@ -62,22 +53,11 @@ impl DynamicalFamily for Gudermannian
     }
 
     #[inline]
-    fn extra_stop_condition(
-        &self,
-        z: Self::Var,
-        _c: &Self::Param,
-        iter: IterCount,
-    ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
+    fn escape_test(&self) -> EscapeTest
     {
-        if z.im.abs() > 350. {
-            Some(EscapeResult::Escaped {
-                iters: iter,
-                final_value: z,
-            })
-        } else if z.re.abs() > 1e15 {
-            Some(EscapeResult::Unknown)
-        } else {
-            None
+        EscapeTest::ImagPart {
+            bound: 350.,
+            overflow: 1e15,
         }
     }
 }
@@ -1,9 +1,59 @@
 use crate::macros::{degree_impl_transcendental, profile_imports};
 use dynamo_color::{Coloring, IncoloringAlgorithm};
-use dynamo_common::math_utils::{riemann_xi, riemann_xi_d, riemann_xi_d2};
+use dynamo_common::math_utils::riemann_siegel::{use_riemann_siegel, RiemannSiegelTerms};
+use dynamo_common::math_utils::{
+    riemann_siegel_theta, riemann_xi, riemann_xi_d, riemann_xi_d2_with, riemann_zeta_d2,
+};
 use dynamo_core::dynamics::PlaneType;
+use std::cell::RefCell;
+use std::collections::VecDeque;
 profile_imports!();
 
+/// Number of heights whose Riemann–Siegel terms each thread remembers.
+const TERMS_CACHE_SIZE: usize = 64;
+
+/// Number of colors used to tell apart the zeros found by Newton's method.
+const NUM_ZERO_CLASSES: usize = 12;
+
+thread_local! {
+    /// Riemann–Siegel terms at recently visited heights, most recent last. The pixels of a row
+    /// start at the same height, and cycle detection revisits every point of the orbit.
+    static TERMS_CACHE: RefCell<VecDeque<RiemannSiegelTerms>> =
+        RefCell::new(VecDeque::with_capacity(TERMS_CACHE_SIZE));
+}
+
+/// Zeta and its first two derivatives, reusing the Riemann–Siegel terms at the height of `s`.
+fn riemann_zeta_d2_cached(s: Cplx) -> [Cplx; 3]
+{
+    if !use_riemann_siegel(s) {
+        return riemann_zeta_d2(s);
+    }
+    let height = s.im.abs();
+    let zeta: [Cplx; 3] = TERMS_CACHE.with_borrow_mut(|cache| {
+        let mut terms = cache
+            .iter()
+            .rposition(|terms| terms.height().to_bits() == height.to_bits())
+            .and_then(|index| cache.remove(index))
+            .unwrap_or_else(|| RiemannSiegelTerms::new(height));
+        let zeta = terms.zeta(s.re);
+        if cache.len() >= TERMS_CACHE_SIZE {
+            cache.pop_front();
+        }
+        cache.push_back(terms);
+        zeta
+    });
+    if s.im < 0. {
+        zeta.map(|z| z.conj())
+    } else {
+        zeta
+    }
+}
+
+fn riemann_xi_d2_cached(s: Cplx) -> [Cplx; 3]
+{
+    riemann_xi_d2_with(s, riemann_zeta_d2_cached)
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RiemannXi
@@ -70,14 +120,15 @@ impl DynamicalFamily for RiemannXi
     {
         (*c, ZERO, ONE)
     }
+}
+
+impl DisplayDefaults for RiemannXi
+{
     fn name(&self) -> String
     {
         "Riemann Xi".to_owned()
     }
-}
 
-impl FamilyDefaults for RiemannXi
-{
     default_bounds!();
 
     #[inline]
@@ -147,21 +198,22 @@ impl DynamicalFamily for RiemannXiNewton
 
     fn map(&self, s: Self::Var, c: &Self::Param) -> Self::Var
     {
-        let [z, dz] = riemann_xi_d(s);
+        let [z, dz, _] = riemann_xi_d2_cached(s);
         s - (z + c) / dz
     }
     fn map_and_multiplier(&self, s: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
     {
-        let [z, dz, d2z] = riemann_xi_d2(s);
-        let z = z + c;
-        (s - z / dz, z / d2z)
+        let (f, df_dz, _) = self.gradient(s, c);
+        (f, df_dz)
     }
     #[inline]
     fn gradient(&self, s: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
     {
-        let [z, dz, d2z] = riemann_xi_d2(s);
+        // Take the step itself from the same evaluation as `map`, so that the fixed points of
+        // both agree exactly and cycle detection succeeds on the first attempt.
+        let [z, dz, d2z] = riemann_xi_d2_cached(s);
         let z = z + c;
-        (s - z / dz, z / d2z, -dz.inv())
+        (s - z / dz, z * d2z / (dz * dz), -dz.inv())
     }
     fn param_map(&self, _point: Cplx) -> Self::Param
     {
@@ -175,14 +227,15 @@ impl DynamicalFamily for RiemannXiNewton
     {
         self.param
     }
+}
+
+impl DisplayDefaults for RiemannXiNewton
+{
     fn name(&self) -> String
     {
         "Riemann Xi Newton".to_owned()
     }
-}
 
-impl FamilyDefaults for RiemannXiNewton
-{
     fn default_bounds(&self) -> Bounds
     {
         Bounds::square(30., Cplx::new(0.5, 0.))
@@ -211,7 +264,37 @@ impl HasChild<Self> for RiemannXiNewton
 }
 
 impl MarkedPoints for RiemannXi {}
-impl MarkedPoints for RiemannXiNewton {}
+impl MarkedPoints for RiemannXiNewton
+{
+    fn num_marked_point_classes(&self) -> usize
+    {
+        NUM_ZERO_CLASSES
+    }
+
+    /// Color the basin of each zero of xi + c by the position of the zero in order of height.
+    /// By Gram's law, the n-th zero usually lies between the Gram points g_{n-2} and g_{n-1},
+    /// where theta(g_k) = k*pi, so neighboring zeros usually fall into different classes.
+    fn identify_marked_points(
+        &self,
+        s: Self::Var,
+        _c: &Self::Param,
+        info: PointInfoPeriodic<Self::Deriv>,
+    ) -> PointInfo<Self::Deriv>
+    {
+        if info.period != 1 {
+            return PointInfo::Periodic(info);
+        }
+        let height = s.im.abs().max(1.);
+        let index = (riemann_siegel_theta(height) / PI).floor() as i64;
+        // Stride coprime to the number of classes, so that neighboring zeros contrast
+        let class_id = (index * 5).rem_euclid(NUM_ZERO_CLASSES as i64) as usize;
+        PointInfo::MarkedPoint {
+            data: info,
+            class_id: class_id.into(),
+            num_point_classes: NUM_ZERO_CLASSES,
+        }
+    }
+}
 
 degree_impl_transcendental!(RiemannXi);
 degree_impl_transcendental!(RiemannXiNewton);
@@ -0,0 +1,349 @@
+//! Python bindings for dynamo.
+//!
+//! Build with `maturin develop` from this directory, then:
+//!
+//! ```python
+//! import dynamo
+//! plane = dynamo.Plane("mandelbrot")
+//! plane.point_grid = dynamo.PointGrid.by_height(512, -2.1, 0.55, -1.25, 1.25)
+//! result = plane.compute()
+//! result["potential"]  # NumPy array of shape (512, res_x)
+//! image = plane.render()  # NumPy array of RGB values, of shape (512, res_x, 3)
+//! ```
+
+pub mod plane;
+pub mod profiles;
+
+use dynamo_color::Coloring;
+use dynamo_common::prelude::*;
+use plane::DynPlane;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyByteArray, PyDict};
+
+/// Grid of complex points sampled by a plane, with a row for each imaginary part.
+#[pyclass(name = "PointGrid", module = "dynamo")]
+#[derive(Clone)]
+pub struct PyPointGrid(PointGrid);
+
+#[pymethods]
+impl PyPointGrid
+{
+    #[new]
+    #[allow(clippy::similar_names)]
+    fn new(res_x: usize, res_y: usize, min_x: Real, max_x: Real, min_y: Real, max_y: Real) -> Self
+    {
+        let bounds = Bounds {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        };
+        Self(PointGrid::new(res_x, res_y, bounds))
+    }
+
+    /// Grid with the given number of rows, and square pixels.
+    #[staticmethod]
+    fn by_height(res_y: usize, min_x: Real, max_x: Real, min_y: Real, max_y: Real) -> Self
+    {
+        let bounds = Bounds {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        };
+        Self(PointGrid::new_by_res_y(res_y, bounds))
+    }
+
+    #[getter]
+    const fn res_x(&self) -> usize
+    {
+        self.0.res_x
+    }
+
+    #[getter]
+    const fn res_y(&self) -> usize
+    {
+        self.0.res_y
+    }
+
+    /// Bounds as `(min_x, max_x, min_y, max_y)`.
+    #[getter]
+    const fn bounds(&self) -> (Real, Real, Real, Real)
+    {
+        let Bounds {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        } = self.0.bounds;
+        (min_x, max_x, min_y, max_y)
+    }
+
+    fn map_pixel(&self, x: usize, y: usize) -> Cplx
+    {
+        self.0.map_pixel(x, y)
+    }
+
+    fn locate_point(&self, z: Cplx) -> Option<(usize, usize)>
+    {
+        self.0.locate_point_safe(z)
+    }
+
+    fn __repr__(&self) -> String
+    {
+        let (min_x, max_x, min_y, max_y) = self.bounds();
+        format!(
+            "PointGrid({}, {}, {min_x}, {max_x}, {min_y}, {max_y})",
+            self.0.res_x, self.0.res_y
+        )
+    }
+}
+
+/// Parameter or dynamical plane of one of the built-in profiles.
+#[pyclass(name = "Plane", module = "dynamo")]
+pub struct PyPlane(Box<dyn DynPlane>);
+
+/// Reinterpret raw native-endian values as a NumPy array of the given dtype and shape.
+fn numpy_array<'py>(
+    numpy: &Bound<'py, PyModule>,
+    bytes: &[u8],
+    dtype: &str,
+    shape: (usize, usize),
+) -> PyResult<Bound<'py, PyAny>>
+{
+    let py = numpy.py();
+    let kwargs = [("dtype", dtype)].into_py_dict(py)?;
+    numpy
+        .call_method("frombuffer", (PyByteArray::new(py, bytes),), Some(&kwargs))?
+        .call_method1("reshape", (shape,))
+}
+
+#[pymethods]
+impl PyPlane
+{
+    /// Construct a profile by name; see `dynamo.profiles()`. If `julia` is given, construct the
+    /// dynamical plane of the parameter selected at that point instead.
+    #[new]
+    #[pyo3(signature = (profile, julia = None))]
+    fn new(profile: &str, julia: Option<Cplx>) -> PyResult<Self>
+    {
+        profiles::create_plane(profile, julia)
+            .map(Self)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown profile '{profile}'")))
+    }
+
+    #[getter]
+    fn name(&self) -> String
+    {
+        self.0.name()
+    }
+
+    #[getter]
+    fn point_grid(&self) -> PyPointGrid
+    {
+        PyPointGrid(self.0.point_grid().clone())
+    }
+
+    #[setter]
+    fn set_point_grid(&mut self, point_grid: PyPointGrid)
+    {
+        self.0.set_point_grid(point_grid.0);
+    }
+
+    #[getter]
+    fn max_iter(&self) -> IterCount
+    {
+        self.0.max_iter()
+    }
+
+    #[setter]
+    fn set_max_iter(&mut self, max_iter: IterCount)
+    {
+        self.0.set_max_iter(max_iter);
+    }
+
+    /// Adjustable meta-parameters of the family, such as its degree, as `(name, value)` pairs.
+    #[getter]
+    fn meta_params(&self) -> Vec<(String, Cplx)>
+    {
+        self.0.meta_params()
+    }
+
+    /// Set the meta-parameter called `name`, rounding it to an integer if it is one.
+    fn set_meta_param(&mut self, name: &str, value: Cplx) -> PyResult<()>
+    {
+        if self.0.set_meta_param(name, value) {
+            Ok(())
+        } else {
+            Err(PyValueError::new_err(format!("Unknown meta-parameter '{name}'")))
+        }
+    }
+
+    /// Compute every point of the grid, returning a dict of NumPy arrays of shape
+    /// `(res_y, res_x)`: `potential` (NaN for bounded orbits), `period` and `preperiod` (0 if no
+    /// cycle was detected).
+    fn compute<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>>
+    {
+        let output = py.allow_threads(|| self.0.compute());
+        let shape = (output.res_y, output.res_x);
+
+        let numpy = py.import("numpy")?;
+        let potential: Vec<u8> = output
+            .potential
+            .iter()
+            .flat_map(|x| x.to_ne_bytes())
+            .collect();
+        let period: Vec<u8> = output.period.iter().flat_map(|p| p.to_ne_bytes()).collect();
+        let preperiod: Vec<u8> = output
+            .preperiod
+            .iter()
+            .flat_map(|p| p.to_ne_bytes())
+            .collect();
+
+        let result = PyDict::new(py);
+        result.set_item(
+            "potential",
+            numpy_array(&numpy, &potential, "float64", shape)?,
+        )?;
+        result.set_item("period", numpy_array(&numpy, &period, "uint32", shape)?)?;
+        result.set_item(
+            "preperiod",
+            numpy_array(&numpy, &preperiod, "uint64", shape)?,
+        )?;
+        Ok(result)
+    }
+
+    /// Color the plane with the default palette, returning a NumPy array of shape
+    /// `(res_y, res_x, 3)` with the top row of the image first.
+    fn render<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>>
+    {
+        let image = py.allow_threads(|| self.0.render(&Coloring::default()));
+        let shape = (image.height() as usize, image.width() as usize, 3);
+
+        let numpy = py.import("numpy")?;
+        let kwargs = [("dtype", "uint8")].into_py_dict(py)?;
+        numpy
+            .call_method("frombuffer", (PyByteArray::new(py, &image),), Some(&kwargs))?
+            .call_method1("reshape", (shape,))
+    }
+
+    /// Color the plane with the default palette and save it to `path`, in the format given by
+    /// its extension.
+    fn save_image(&self, py: Python<'_>, path: &str) -> PyResult<()>
+    {
+        let image = py.allow_threads(|| self.0.render(&Coloring::default()));
+        image
+            .save(path)
+            .map_err(|e| PyValueError::new_err(format!("Could not save {path}: {e}")))
+    }
+
+    /// Trace the external ray at angle `numer/denom`, returning its points from far out towards
+    /// the landing point, or None if the ray could not be traced.
+    fn external_ray(
+        &self,
+        py: Python<'_>,
+        numer: AngleNum,
+        denom: AngleNum,
+    ) -> PyResult<Option<Vec<Cplx>>>
+    {
+        if denom == 0 {
+            return Err(PyValueError::new_err("Angle denominator must be nonzero"));
+        }
+        let angle = RationalAngle::new(numer, denom);
+        Ok(py.allow_threads(|| self.0.external_ray(angle)))
+    }
+
+    /// Critical points of the map, if the plane is dynamical.
+    fn critical_points(&self) -> Vec<Cplx>
+    {
+        self.0.critical_points()
+    }
+
+    /// Periodic points of the given period on a dynamical plane, or on a parameter plane the
+    /// parameters whose critical orbits have that period.
+    fn periodic_points(&self, py: Python<'_>, period: Period) -> Vec<Cplx>
+    {
+        py.allow_threads(|| self.0.cycles(period))
+    }
+
+    fn __repr__(&self) -> String
+    {
+        format!("Plane('{}')", self.0.name())
+    }
+}
+
+/// Names of the profiles that can be passed to `Plane`.
+#[pyfunction(name = "profiles")]
+fn profile_names() -> Vec<&'static str>
+{
+    profiles::PROFILE_NAMES.to_vec()
+}
+
+#[pymodule]
+fn dynamo(m: &Bound<'_, PyModule>) -> PyResult<()>
+{
+    m.add_class::<PyPointGrid>()?;
+    m.add_class::<PyPlane>()?;
+    m.add_function(wrap_pyfunction!(profile_names, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::profiles::{create_plane, PROFILE_NAMES};
+    use dynamo_common::prelude::*;
+
+    #[test]
+    fn compute_mandelbrot()
+    {
+        let mut plane = create_plane("mandelbrot", None).unwrap();
+        let bounds = Bounds::centered_square(2.);
+        plane.set_point_grid(PointGrid::new(5, 5, bounds));
+        let output = plane.compute();
+
+        // The center pixel is c = 0, which has a superattracting fixed point, while the corners
+        // escape.
+        let center = 2 * 5 + 2;
+        assert_eq!(output.period[center], 1);
+        assert!(output.potential[center].is_nan());
+        assert_eq!(output.period[0], 0);
+        assert!(output.potential[0].is_finite());
+    }
+
+    #[test]
+    fn render_image()
+    {
+        use dynamo_color::Coloring;
+
+        let mut plane = create_plane("mandelbrot", Some(ZERO)).unwrap();
+        plane.set_point_grid(PointGrid::new(6, 4, Bounds::centered_square(2.)));
+        let image = plane.render(&Coloring::default());
+        assert_eq!(image.dimensions(), (6, 4));
+    }
+
+    #[test]
+    fn profile_names()
+    {
+        for name in PROFILE_NAMES {
+            assert!(create_plane(name, None).is_some());
+            assert!(create_plane(name, Some(ZERO)).is_some());
+        }
+        assert!(create_plane("not a profile", None).is_none());
+    }
+
+    #[test]
+    fn set_degree()
+    {
+        let mut plane = create_plane("unicritical", None).unwrap();
+        assert!(plane.set_meta_param("d", Cplx::new(5., 0.)));
+        assert_eq!(plane.name(), "Unicritical(5)");
+        assert!(!plane.set_meta_param("λ", ONE));
+
+        let mut julia = create_plane("mcmullen", Some(ONE)).unwrap();
+        assert!(julia.set_meta_param("n", Cplx::new(4.4, 0.)));
+        assert_eq!(julia.name(), "Julia(McMullen Family (3, 4))");
+        assert_eq!(julia.meta_params()[1], ("n".to_owned(), Cplx::new(4., 0.)));
+    }
+}
@@ -0,0 +1,152 @@
+use dynamo_color::Coloring;
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use image::RgbImage;
+
+/// Per-pixel results of a computation, stored row by row. The imaginary part increases with the
+/// row index, and the real part with the column index.
+#[derive(Clone, Debug, Default)]
+pub struct ComputeOutput
+{
+    pub res_x: usize,
+    pub res_y: usize,
+    /// Smooth escape time of escaping points, and NaN elsewhere.
+    pub potential: Vec<IterCountSmooth>,
+    /// Period of the cycle that the orbit converges to, and 0 if none was detected.
+    pub period: Vec<Period>,
+    /// Number of iterations before the orbit reached its cycle, and 0 if none was detected.
+    pub preperiod: Vec<IterCount>,
+}
+
+impl ComputeOutput
+{
+    fn new<D>(iter_plane: &IterPlane<D>) -> Self
+    {
+        let PointGrid { res_x, res_y, .. } = iter_plane.point_grid;
+        let mut output = Self {
+            res_x,
+            res_y,
+            potential: Vec::with_capacity(res_x * res_y),
+            period: Vec::with_capacity(res_x * res_y),
+            preperiod: Vec::with_capacity(res_x * res_y),
+        };
+
+        for y in 0..res_y {
+            for x in 0..res_x {
+                let (potential, period, preperiod) = match &iter_plane.iter_counts[[x, y]] {
+                    PointInfo::Escaping { potential, .. } => (*potential, 0, 0),
+                    PointInfo::Periodic(data) | PointInfo::MarkedPoint { data, .. } => {
+                        (IterCountSmooth::NAN, data.period, data.preperiod)
+                    }
+                    PointInfo::PeriodicKnownPotential(data) => {
+                        (IterCountSmooth::NAN, data.period, 0)
+                    }
+                    _ => (IterCountSmooth::NAN, 0, 0),
+                };
+                output.potential.push(potential);
+                output.period.push(period);
+                output.preperiod.push(preperiod);
+            }
+        }
+        output
+    }
+}
+
+/// Object-safe view of a plane, so that any profile can be driven from Python.
+pub trait DynPlane: Send + Sync
+{
+    fn name(&self) -> String;
+    fn point_grid(&self) -> &PointGrid;
+    fn set_point_grid(&mut self, point_grid: PointGrid);
+    fn max_iter(&self) -> IterCount;
+    fn set_max_iter(&mut self, max_iter: IterCount);
+    fn meta_params(&self) -> Vec<(String, Cplx)>;
+    fn set_meta_param(&mut self, name: &str, value: Cplx) -> bool;
+    fn compute(&self) -> ComputeOutput;
+    fn render(&self, coloring: &Coloring) -> RgbImage;
+    fn external_ray(&self, angle: RationalAngle) -> Option<Vec<Cplx>>;
+    fn critical_points(&self) -> Vec<Cplx>;
+    fn cycles(&self, period: Period) -> Vec<Cplx>;
+}
+
+impl<P> DynPlane for P
+where
+    P: Displayable + Clone,
+    P::Deriv: Polar<Real>,
+{
+    fn name(&self) -> String
+    {
+        DisplayDefaults::name(self)
+    }
+
+    fn point_grid(&self) -> &PointGrid
+    {
+        DynamicalFamily::point_grid(self)
+    }
+
+    fn set_point_grid(&mut self, point_grid: PointGrid)
+    {
+        *self.point_grid_mut() = point_grid;
+    }
+
+    fn max_iter(&self) -> IterCount
+    {
+        DynamicalFamily::max_iter(self)
+    }
+
+    fn set_max_iter(&mut self, max_iter: IterCount)
+    {
+        DynamicalFamily::set_max_iter(self, max_iter);
+    }
+
+    fn meta_params(&self) -> Vec<(String, Cplx)>
+    {
+        self.get_meta_params()
+            .sliders()
+            .into_iter()
+            .map(|slider| (slider.name, slider.value))
+            .collect()
+    }
+
+    fn set_meta_param(&mut self, name: &str, value: Cplx) -> bool
+    {
+        let mut meta_params = self.get_meta_params();
+        let Some(idx) = meta_params.sliders().iter().position(|s| s.name == name) else {
+            return false;
+        };
+        meta_params.set_slider(idx, value);
+        DynamicalFamily::set_meta_param(self, meta_params);
+        true
+    }
+
+    fn compute(&self) -> ComputeOutput
+    {
+        ComputeOutput::new(&Computable::compute(self))
+    }
+
+    fn render(&self, coloring: &Coloring) -> RgbImage
+    {
+        dynamo_render::render_grid(self, DynamicalFamily::point_grid(self).clone(), coloring)
+    }
+
+    fn external_ray(&self, angle: RationalAngle) -> Option<Vec<Cplx>>
+    {
+        ExternalRays::external_ray(self, angle)
+    }
+
+    fn critical_points(&self) -> Vec<Cplx>
+    {
+        MarkedPoints::critical_points(self)
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    fn cycles(&self, period: Period) -> Vec<Cplx>
+    {
+        MarkedPoints::cycles(self, period)
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+}
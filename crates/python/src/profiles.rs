@@ -0,0 +1,57 @@
+use crate::plane::DynPlane;
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use dynamo_profiles::*;
+
+fn build<P>(plane: P, julia: Option<Cplx>) -> Box<dyn DynPlane>
+where
+    P: DynPlane + HasJulia + 'static,
+    JuliaSet<P>: DynPlane,
+{
+    match julia {
+        Some(selection) => {
+            let max_iter = plane.default_max_iter_child();
+            Box::new(JuliaSet::new(plane, selection, max_iter))
+        }
+        None => Box::new(plane),
+    }
+}
+
+macro_rules! profiles {
+    ($($name: literal => $profile: ty),* $(,)?) => {
+        /// Names of the profiles that can be constructed from Python.
+        pub const PROFILE_NAMES: &[&str] = &[$($name),*];
+
+        /// Construct a profile by name. If `julia` is given, construct the dynamical plane of the
+        /// parameter at that selection instead.
+        pub fn create_plane(name: &str, julia: Option<Cplx>) -> Option<Box<dyn DynPlane>>
+        {
+            match name {
+                $($name => Some(build(<$profile>::default(), julia)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+profiles! {
+    "mandelbrot" => Mandelbrot,
+    "cubic_unicritical" => Unicritical<3>,
+    "quartic_unicritical" => Unicritical<4>,
+    "unicritical" => UnicriticalDyn,
+    "chebyshev" => ChebyshevDyn,
+    "mcmullen" => McMullenFamilyDyn,
+    "odd_cubic" => OddCubic,
+    "cubic_per_1_lambda" => CubicPer1Lambda,
+    "cubic_per_2_lambda" => CubicPer2Lambda,
+    "quad_rat_per_1_lambda" => QuadRatPer1Lambda,
+    "quad_rat_per_2" => QuadRatPer2,
+    "quad_rat_per_2_lambda" => QuadRatPer2Lambda,
+    "quad_rat_per_3" => QuadRatPer3,
+    "quad_rat_per_4" => QuadRatPer4,
+    "newton_cubic" => NewtonCubic,
+    "exponential" => Exponential,
+    "cosine" => Cosine,
+    "burning_ship" => BurningShip<2>,
+    "tricorne" => Tricorne<2>,
+}
@@ -0,0 +1,158 @@
+//! Headless rendering of the dynamo profiles.
+//!
+//! Everything here works from a plane and a [`Coloring`] alone, with no GUI types involved, so
+//! that images can be produced from the command line, from scripts and bindings, from tests, or
+//! on a worker thread while the panes keep changing.
+
+use dynamo_color::prelude::*;
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use image::{DynamicImage, RgbImage};
+
+#[cfg(feature = "profiles")]
+pub mod profiles;
+
+/// Compute the profile over `bounds`, with `res_y` rows of square pixels, and color the result.
+#[must_use]
+pub fn render<P>(profile: &P, bounds: Bounds, res_y: usize, coloring: &Coloring) -> RgbImage
+where
+    P: Computable + Clone,
+    P::Deriv: Polar<Real>,
+{
+    render_grid(profile, PointGrid::new_by_res_y(res_y, bounds), coloring)
+}
+
+/// Compute the profile over the given grid and color the result.
+#[must_use]
+pub fn render_grid<P>(profile: &P, point_grid: PointGrid, coloring: &Coloring) -> RgbImage
+where
+    P: Computable + Clone,
+    P::Deriv: Polar<Real>,
+{
+    let mut profile = profile.clone();
+    *profile.point_grid_mut() = point_grid;
+    color_rgb(&profile.compute(), coloring)
+}
+
+/// Color a computed plane with no layers or lighting, as a plain RGB image.
+#[must_use]
+pub fn color_rgb<D>(iter_plane: &IterPlane<D>, coloring: &Coloring) -> RgbImage
+where
+    D: Polar<Real>,
+{
+    let res_x = u32::try_from(iter_plane.point_grid.res_x).unwrap_or(u32::MAX);
+    let res_y = u32::try_from(iter_plane.point_grid.res_y).unwrap_or(u32::MAX);
+    RgbImage::from_fn(res_x, res_y, |x, y| {
+        coloring.map(&iter_plane.iter_counts[(x as usize, (res_y - y - 1) as usize)])
+    })
+}
+
+/// Color a computed plane with the layers composited over the base coloring, in RGB, or in RGBA
+/// if some points are left transparent.
+#[must_use]
+pub fn color_layered<D>(
+    iter_plane: &IterPlane<D>,
+    coloring: &Coloring,
+    layers: &LayerStack,
+    transparency: Transparency,
+) -> DynamicImage
+where
+    D: Polar<Real>,
+{
+    if transparency == Transparency::Opaque {
+        iter_plane.write_image(coloring, layers).into()
+    } else {
+        iter_plane
+            .write_image_rgba(coloring, layers, transparency)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::*;
+    use dynamo_color::snapshot::*;
+    use dynamo_profiles::*;
+
+    #[test]
+    fn render_matches_compute()
+    {
+        let plane = Mandelbrot::default();
+        let bounds = plane.point_grid().bounds.clone();
+        assert_eq!(render(&plane, bounds, 24, &Coloring::default()).height(), 24);
+
+        let plane = plane.with_res_y(24);
+        let image = render_grid(&plane, plane.point_grid().clone(), &Coloring::default());
+        let iter_plane = plane.compute();
+        let layered = color_layered(
+            &iter_plane,
+            &Coloring::default(),
+            &LayerStack::default(),
+            Transparency::Opaque,
+        );
+        assert_eq!(layered.into_rgb8(), image);
+    }
+
+    /// Render small canonical views of the profiles with the default coloring, and compare their
+    /// hashes against those stored in `snapshots.txt`. Run with `UPDATE_SNAPSHOTS=1` to record
+    /// new hashes after an intended change to the output.
+    #[test]
+    fn image_snapshots()
+    {
+        fn hash<P>(plane: P) -> ImageHash
+        where
+            P: Computable + Clone,
+            P::Deriv: Polar<Real>,
+        {
+            let plane = plane.with_max_iter(256);
+            let bounds = plane.point_grid().bounds.clone();
+            ImageHash::of(&render(&plane, bounds, 48, &Coloring::default()))
+        }
+
+        let hashes = [
+            ("mandelbrot", hash(Mandelbrot::default())),
+            (
+                "mandelbrot_julia",
+                hash(JuliaSet::from(Mandelbrot::default()).with_param(Cplx::new(-0.12, 0.75))),
+            ),
+            ("unicritical_3", hash(Unicritical::<3>::default())),
+            ("biquadratic", hash(Biquadratic::default().with_param((-0.3).into()))),
+            ("odd_cubic", hash(OddCubic::default())),
+            ("cubic_marked_2_cycle", hash(CubicMarked2Cycle::default())),
+            ("burning_ship", hash(BurningShip::<2>::default())),
+            ("tricorne", hash(Tricorne::<2>::default())),
+            ("quad_rat_per_2", hash(QuadRatPer2::default())),
+            ("quad_rat_per_3", hash(QuadRatPer3::default())),
+            ("quad_rat_per_4", hash(QuadRatPer4::default())),
+            ("quad_rat_preper_2_1", hash(QuadRatPreper21::default())),
+            ("newton_cubic", hash(NewtonCubic::default())),
+            ("mcmullen_3_3", hash(McMullenFamily::<3, 3>::default())),
+            ("exponential", hash(Exponential::default())),
+            ("cosine", hash(Cosine::default())),
+        ];
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots.txt");
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            let mut snapshots = Snapshots::default();
+            for (name, hash) in hashes {
+                snapshots.insert(name, hash);
+            }
+            let header = "# Regenerate with `UPDATE_SNAPSHOTS=1 cargo test -p dynamo_render`\n";
+            std::fs::write(path, format!("{header}{snapshots}")).unwrap();
+            return;
+        }
+
+        let snapshots = Snapshots::parse(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let failures: Vec<String> = hashes
+            .iter()
+            .filter_map(|(name, hash)| {
+                snapshots
+                    .check(name, hash, SnapshotTolerance::default())
+                    .err()
+                    .map(|e| e.to_string())
+            })
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
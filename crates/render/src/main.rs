@@ -0,0 +1,121 @@
+//! Render a built-in profile to an image file, without opening a window.
+//!
+//! Usage: `dynamo_render <profile> [options]`, where the options are
+//! * `--julia <re>,<im>`: render the dynamical plane of the parameter at that point
+//! * `--bounds <min_x>,<max_x>,<min_y>,<max_y>`: region of the plane, defaulting to the
+//!   profile's own
+//! * `--height <rows>`: image height in pixels, with square pixels (default 1024)
+//! * `--max-iter <n>`: iteration limit, defaulting to the profile's own
+//! * `--output <path>`: image to write (default `<profile>.png`)
+
+use dynamo_color::Coloring;
+use dynamo_common::prelude::*;
+use dynamo_render::profiles::{create_plane, PROFILE_NAMES};
+use std::process::ExitCode;
+
+const DEFAULT_HEIGHT: usize = 1024;
+
+struct Options
+{
+    profile: String,
+    julia: Option<Cplx>,
+    bounds: Option<Bounds>,
+    height: usize,
+    max_iter: Option<IterCount>,
+    output: String,
+}
+
+fn parse_reals(arg: &str) -> Result<Vec<Real>, String>
+{
+    arg.split(',')
+        .map(|x| x.trim().parse().map_err(|_| format!("Invalid number: {x}")))
+        .collect()
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String>
+{
+    let (profile, mut rest) = args
+        .split_first()
+        .ok_or_else(|| format!("No profile given; choose from {}", PROFILE_NAMES.join(", ")))?;
+    let mut options = Options {
+        profile: profile.clone(),
+        julia: None,
+        bounds: None,
+        height: DEFAULT_HEIGHT,
+        max_iter: None,
+        output: format!("{profile}.png"),
+    };
+
+    while let [flag, value, tail @ ..] = rest {
+        match flag.as_str() {
+            "--julia" => match parse_reals(value)?[..] {
+                [re, im] => options.julia = Some(Cplx::new(re, im)),
+                _ => return Err("--julia takes <re>,<im>".to_owned()),
+            },
+            "--bounds" => match parse_reals(value)?[..] {
+                [min_x, max_x, min_y, max_y] => {
+                    options.bounds = Some(Bounds {
+                        min_x,
+                        max_x,
+                        min_y,
+                        max_y,
+                    });
+                }
+                _ => return Err("--bounds takes <min_x>,<max_x>,<min_y>,<max_y>".to_owned()),
+            },
+            "--height" => {
+                options.height = value.parse().map_err(|_| format!("Invalid height: {value}"))?;
+            }
+            "--max-iter" => {
+                let max_iter = value
+                    .parse()
+                    .map_err(|_| format!("Invalid iteration limit: {value}"))?;
+                options.max_iter = Some(max_iter);
+            }
+            "--output" => value.clone_into(&mut options.output),
+            _ => return Err(format!("Unknown option: {flag}")),
+        }
+        rest = tail;
+    }
+    if let [flag] = rest {
+        return Err(format!("Missing value for {flag}"));
+    }
+    Ok(options)
+}
+
+fn main() -> ExitCode
+{
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some(mut plane) = create_plane(&options.profile, options.julia) else {
+        eprintln!(
+            "Unknown profile '{}'; choose from {}",
+            options.profile,
+            PROFILE_NAMES.join(", ")
+        );
+        return ExitCode::FAILURE;
+    };
+    if let Some(max_iter) = options.max_iter {
+        plane.set_max_iter(max_iter);
+    }
+
+    let bounds = options
+        .bounds
+        .unwrap_or_else(|| plane.point_grid().bounds.clone());
+    let point_grid = PointGrid::new_by_res_y(options.height, bounds);
+    let image = plane.render(point_grid, &Coloring::default());
+
+    if let Err(e) = image.save(&options.output) {
+        eprintln!("Error saving {}: {e}", options.output);
+        return ExitCode::FAILURE;
+    }
+    println!("Image saved to {}", options.output);
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,94 @@
+//! The built-in profiles, constructed by name.
+
+use crate::render_grid;
+use dynamo_color::Coloring;
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use dynamo_profiles::*;
+use image::RgbImage;
+
+/// Object-safe view of a plane, so that any profile can be rendered once chosen at runtime.
+pub trait Renderable: Send + Sync
+{
+    fn name(&self) -> String;
+    fn point_grid(&self) -> &PointGrid;
+    fn set_max_iter(&mut self, max_iter: IterCount);
+    fn render(&self, point_grid: PointGrid, coloring: &Coloring) -> RgbImage;
+}
+
+impl<P> Renderable for P
+where
+    P: Displayable + Clone + Send + Sync,
+    P::Deriv: Polar<Real>,
+{
+    fn name(&self) -> String
+    {
+        DisplayDefaults::name(self)
+    }
+
+    fn point_grid(&self) -> &PointGrid
+    {
+        DynamicalFamily::point_grid(self)
+    }
+
+    fn set_max_iter(&mut self, max_iter: IterCount)
+    {
+        DynamicalFamily::set_max_iter(self, max_iter);
+    }
+
+    fn render(&self, point_grid: PointGrid, coloring: &Coloring) -> RgbImage
+    {
+        render_grid(self, point_grid, coloring)
+    }
+}
+
+fn build<P>(plane: P, julia: Option<Cplx>) -> Box<dyn Renderable>
+where
+    P: Renderable + Displayable + HasJulia + Clone + 'static,
+    JuliaSet<P>: Renderable,
+{
+    match julia {
+        Some(selection) => {
+            let max_iter = plane.default_max_iter_child();
+            Box::new(JuliaSet::new(plane, selection, max_iter))
+        }
+        None => Box::new(plane),
+    }
+}
+
+macro_rules! profiles {
+    ($($name: literal => $profile: ty),* $(,)?) => {
+        /// Names of the profiles that can be constructed with [`create_plane`].
+        pub const PROFILE_NAMES: &[&str] = &[$($name),*];
+
+        /// Construct a profile by name. If `julia` is given, construct the dynamical plane of the
+        /// parameter at that selection instead.
+        #[must_use]
+        pub fn create_plane(name: &str, julia: Option<Cplx>) -> Option<Box<dyn Renderable>>
+        {
+            match name {
+                $($name => Some(build(<$profile>::default(), julia)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+profiles! {
+    "mandelbrot" => Mandelbrot,
+    "cubic_unicritical" => Unicritical<3>,
+    "quartic_unicritical" => Unicritical<4>,
+    "odd_cubic" => OddCubic,
+    "cubic_per_1_lambda" => CubicPer1Lambda,
+    "cubic_per_2_lambda" => CubicPer2Lambda,
+    "quad_rat_per_1_lambda" => QuadRatPer1Lambda,
+    "quad_rat_per_2" => QuadRatPer2,
+    "quad_rat_per_2_lambda" => QuadRatPer2Lambda,
+    "quad_rat_per_3" => QuadRatPer3,
+    "quad_rat_per_4" => QuadRatPer4,
+    "newton_cubic" => NewtonCubic,
+    "exponential" => Exponential,
+    "cosine" => Cosine,
+    "burning_ship" => BurningShip<2>,
+    "tricorne" => Tricorne<2>,
+}
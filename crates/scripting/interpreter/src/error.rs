@@ -0,0 +1,46 @@
+#[derive(Debug)]
+pub enum InterpreterError
+{
+    UnexpectedCharacter(char),
+    UnexpectedToken(String),
+    UnexpectedEnd,
+    UndefinedSymbol(String),
+    UnknownFunction(String),
+    TooManyParameters(usize),
+    ExpressionTooDeep,
+    MalformedPeriod(String),
+    ErrorReadingToml(std::io::Error),
+    ErrorParsingToml(toml::de::Error),
+}
+
+impl std::fmt::Display for InterpreterError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::UnexpectedCharacter(c) => write!(f, "Unexpected character '{c}'"),
+            Self::UnexpectedToken(t) => write!(f, "Unexpected token '{t}'"),
+            Self::UnexpectedEnd => write!(f, "Unexpected end of expression"),
+            Self::UndefinedSymbol(s) => write!(f, "Undefined symbol '{s}'"),
+            Self::UnknownFunction(s) => write!(f, "Unknown function '{s}'"),
+            Self::TooManyParameters(n) => write!(f, "At most {n} parameters are supported"),
+            Self::ExpressionTooDeep => write!(f, "Expression is nested too deeply"),
+            Self::MalformedPeriod(s) => write!(f, "Malformed period '{s}'"),
+            Self::ErrorReadingToml(e) => write!(f, "Error reading script: {e}"),
+            Self::ErrorParsingToml(e) => write!(f, "Error parsing script: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for InterpreterError {}
+
+impl From<InterpreterError> for dynamo_core::error::DynamoError
+{
+    fn from(err: InterpreterError) -> Self
+    {
+        match err {
+            InterpreterError::ErrorReadingToml(e) => Self::Io(e),
+            err => Self::Script(err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use crate::error::InterpreterError;
+use dynamo_common::types::{Cplx, Real};
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token
+{
+    Num(Real),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    LParen,
+    RParen,
+}
+
+impl std::fmt::Display for Token
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Num(x) => write!(f, "{x}"),
+            Self::Ident(s) => write!(f, "{s}"),
+            Self::Plus => write!(f, "+"),
+            Self::Minus => write!(f, "-"),
+            Self::Star => write!(f, "*"),
+            Self::Slash => write!(f, "/"),
+            Self::Caret => write!(f, "^"),
+            Self::LParen => write!(f, "("),
+            Self::RParen => write!(f, ")"),
+        }
+    }
+}
+
+fn read_number(first: char, chars: &mut Peekable<Chars>) -> Result<Real, InterpreterError>
+{
+    let mut text = String::from(first);
+    while let Some(&c) = chars.peek() {
+        let exponent_sign = matches!(c, '+' | '-') && text.ends_with(['e', 'E']);
+        if c.is_ascii_digit() || c == '.' || exponent_sign {
+            text.push(c);
+        } else if matches!(c, 'e' | 'E') && !text.contains(['e', 'E']) {
+            // Only treat `e` as an exponent if a digit or sign follows, so that e.g. `2*exp(z)`
+            // is unaffected
+            let mut lookahead = chars.clone();
+            lookahead.next();
+            if !lookahead
+                .peek()
+                .is_some_and(|d| d.is_ascii_digit() || matches!(d, '+' | '-'))
+            {
+                break;
+            }
+            text.push(c);
+        } else {
+            break;
+        }
+        chars.next();
+    }
+    text.parse()
+        .map_err(|_| InterpreterError::UnexpectedToken(text))
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, InterpreterError>
+{
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => {
+                if chars.next_if_eq(&'*').is_some() {
+                    Token::Caret
+                } else {
+                    Token::Star
+                }
+            }
+            '/' => Token::Slash,
+            '^' => Token::Caret,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            c if c.is_ascii_digit() || c == '.' => {
+                tokens.push(Token::Num(read_number(c, &mut chars)?));
+                // Juxtaposition such as `2i` denotes a product, as in constants like "3-2i"
+                if chars.peek().is_some_and(|c| c.is_alphabetic()) {
+                    Token::Star
+                } else {
+                    continue;
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut name = String::from(c);
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+                    name.push(c);
+                }
+                Token::Ident(name)
+            }
+            c => return Err(InterpreterError::UnexpectedCharacter(c)),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinOp
+{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Func
+{
+    Exp,
+    Log,
+    Sqrt,
+    Sin,
+    Cos,
+    Tan,
+    Sinh,
+    Cosh,
+    Tanh,
+}
+impl Func
+{
+    fn from_name(name: &str) -> Option<Self>
+    {
+        match name {
+            "exp" => Some(Self::Exp),
+            "log" | "ln" => Some(Self::Log),
+            "sqrt" => Some(Self::Sqrt),
+            "sin" => Some(Self::Sin),
+            "cos" => Some(Self::Cos),
+            "tan" => Some(Self::Tan),
+            "sinh" => Some(Self::Sinh),
+            "cosh" => Some(Self::Cosh),
+            "tanh" => Some(Self::Tanh),
+            _ => None,
+        }
+    }
+}
+
+/// Names that always refer to built-in constants, matching the conventions of `sympy`.
+#[must_use]
+pub fn builtin_constant(name: &str) -> Option<Cplx>
+{
+    match name {
+        "i" | "I" => Some(Cplx::new(0., 1.)),
+        "pi" => Some(Cplx::new(std::f64::consts::PI, 0.)),
+        "E" => Some(Cplx::new(std::f64::consts::E, 0.)),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr
+{
+    Num(Cplx),
+    Symbol(String),
+    Neg(Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    Call(Func, Box<Expr>),
+}
+
+struct Parser
+{
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser
+{
+    fn peek(&self) -> Option<&Token>
+    {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, InterpreterError>
+    {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or(InterpreterError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<(), InterpreterError>
+    {
+        let token = self.next()?;
+        if &token == expected {
+            Ok(())
+        } else {
+            Err(InterpreterError::UnexpectedToken(token.to_string()))
+        }
+    }
+
+    fn sum(&mut self) -> Result<Expr, InterpreterError>
+    {
+        let mut lhs = self.product()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => BinOp::Add,
+                Some(Token::Minus) => BinOp::Sub,
+                _ => return Ok(lhs),
+            };
+            self.pos += 1;
+            let rhs = self.product()?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+    }
+
+    fn product(&mut self) -> Result<Expr, InterpreterError>
+    {
+        let mut lhs = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Star) => BinOp::Mul,
+                Some(Token::Slash) => BinOp::Div,
+                _ => return Ok(lhs),
+            };
+            self.pos += 1;
+            let rhs = self.unary()?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, InterpreterError>
+    {
+        match self.peek() {
+            Some(Token::Minus) => {
+                self.pos += 1;
+                Ok(Expr::Neg(Box::new(self.unary()?)))
+            }
+            Some(Token::Plus) => {
+                self.pos += 1;
+                self.unary()
+            }
+            _ => self.power(),
+        }
+    }
+
+    /// Exponentiation binds tighter than negation on its left, and is right-associative,
+    /// so `-z^2^3` is `-(z^(2^3))`.
+    fn power(&mut self) -> Result<Expr, InterpreterError>
+    {
+        let base = self.primary()?;
+        if self.peek() == Some(&Token::Caret) {
+            self.pos += 1;
+            let exponent = self.unary()?;
+            return Ok(Expr::Binary(BinOp::Pow, Box::new(base), Box::new(exponent)));
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<Expr, InterpreterError>
+    {
+        match self.next()? {
+            Token::Num(x) => Ok(Expr::Num(Cplx::new(x, 0.))),
+            Token::Ident(name) => {
+                if self.peek() != Some(&Token::LParen) {
+                    return Ok(Expr::Symbol(name));
+                }
+                let func = Func::from_name(&name).ok_or(InterpreterError::UnknownFunction(name))?;
+                self.pos += 1;
+                let arg = self.sum()?;
+                self.expect(&Token::RParen)?;
+                Ok(Expr::Call(func, Box::new(arg)))
+            }
+            Token::LParen => {
+                let inner = self.sum()?;
+                self.expect(&Token::RParen)?;
+                Ok(inner)
+            }
+            token => Err(InterpreterError::UnexpectedToken(token.to_string())),
+        }
+    }
+}
+
+/// Parse an expression written in the same syntax accepted by the script transpiler.
+pub fn parse(text: &str) -> Result<Expr, InterpreterError>
+{
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        pos: 0,
+    };
+    let expr = parser.sum()?;
+    if let Some(token) = parser.peek() {
+        return Err(InterpreterError::UnexpectedToken(token.to_string()));
+    }
+    Ok(expr)
+}
@@ -0,0 +1,175 @@
+//! Bytecode interpreter for user scripts.
+//!
+//! Unlike `script_loader`, this needs neither Python nor a Rust toolchain, and works in the wasm
+//! build, at the cost of evaluating the map more slowly.
+
+pub mod error;
+pub mod expr;
+pub mod plane;
+pub mod script;
+pub mod vm;
+
+use dynamo_core::prelude::*;
+use dynamo_gui::interface::{Interface, MainInterface};
+use error::InterpreterError;
+use plane::InterpretedPlane;
+use script::Script;
+use std::path::Path;
+
+fn interface_from_script(script: Script, image_height: usize) -> Box<dyn Interface>
+{
+    let parent = InterpretedPlane::new(script);
+    let child = JuliaSet::from(parent.clone());
+    Box::new(MainInterface::new(parent, child, image_height))
+}
+
+/// Build an interface from the contents of a user script.
+pub fn create_interface(
+    toml_text: &str,
+    image_height: usize,
+) -> Result<Box<dyn Interface>, InterpreterError>
+{
+    Ok(interface_from_script(
+        Script::from_toml_str(toml_text)?,
+        image_height,
+    ))
+}
+
+/// Build an interface from a user script on disk.
+pub fn create_interface_from_path(
+    toml_path: &Path,
+    image_height: usize,
+) -> Result<Box<dyn Interface>, InterpreterError>
+{
+    Ok(interface_from_script(
+        Script::from_toml_path(toml_path)?,
+        image_height,
+    ))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests
+{
+    use crate::expr::parse;
+    use crate::plane::InterpretedPlane;
+    use crate::script::Script;
+    use crate::vm::{Binding, Dual, Program, Scope};
+    use dynamo_common::prelude::*;
+    use dynamo_core::prelude::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn precedence()
+    {
+        let program =
+            Program::compile(&parse("-2^2 + 3*4/2 - 2**3**0").unwrap(), &Scope::new()).unwrap();
+        assert_eq!(program.eval(&[]).value, Cplx::new(0., 0.));
+        assert!(parse("(1 + 2").is_err());
+        assert!(parse("1 +* 2").is_err());
+
+        let program = Program::compile(&parse("3-2i").unwrap(), &Scope::new()).unwrap();
+        assert_eq!(program.eval(&[]).value, Cplx::new(3., -2.));
+    }
+
+    #[test]
+    fn derivative()
+    {
+        let mut scope = Scope::new();
+        scope.insert("z".to_owned(), Binding::Slot(0));
+        let program =
+            Program::compile(&parse("exp(z)/(z^2 + 1) + z^1.5").unwrap(), &scope).unwrap();
+
+        let z = Cplx::new(0.3, -0.7);
+        let h = 1e-6;
+        let f = program.eval(&[Dual::variable(z)]);
+        let f_h = program.eval(&[Dual::variable(z + h)]);
+        assert!(((f_h.value - f.value) / h - f.deriv).norm() < 1e-5);
+    }
+
+    #[test]
+    fn default_script()
+    {
+        let toml_path = PathBuf::from("..")
+            .join("..")
+            .join("..")
+            .join("user_scripts")
+            .join(".default.toml");
+        let plane = InterpretedPlane::new(Script::from_toml_path(&toml_path).unwrap());
+        assert_eq!(plane.name(), "QuadRat Per(2, λ)");
+
+        // Compare against the formulas in the script, with mu = 2 - i
+        let mu = Cplx::new(2., -1.);
+        let t = Cplx::new(0.4, 0.2);
+        let z = Cplx::new(-0.1, 0.5);
+        let a = t * t * (mu + mu * t - 4.) / 4.;
+        let b = t * (mu * t - 4.) / 4.;
+        let c = plane.param_map(t);
+        let (f, df_dz, _) = plane.gradient(z, &c);
+        assert!((f - (z * z + a) / (z * z + b)).norm() < 1e-12);
+        assert!((df_dz - 2. * z * (b - a) / (z * z + b).powi(2)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn meta_param_sliders()
+    {
+        let toml_path = PathBuf::from("..")
+            .join("..")
+            .join("..")
+            .join("user_scripts")
+            .join("examples")
+            .join("cubic_slider.toml");
+        let mut plane = InterpretedPlane::new(Script::from_toml_path(&toml_path).unwrap());
+        let mut meta_params = plane.get_meta_params();
+        let sliders = meta_params.sliders();
+        assert_eq!(sliders.len(), 1);
+        assert_eq!(sliders[0].value, Cplx::new(0.5, 0.));
+
+        let a = Cplx::new(-1., 0.25);
+        meta_params.set_slider(0, a);
+        plane.set_meta_param(meta_params);
+
+        let t = Cplx::new(0.1, 0.3);
+        let z = Cplx::new(-0.4, 0.2);
+        let c = plane.param_map(t);
+        assert!((plane.map(z, &c) - (z * z * z + a * z + t)).norm() < 1e-12);
+        assert!((plane.start_point(t, &c) - (-a / 3.).sqrt()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn marked_points()
+    {
+        let toml_path = PathBuf::from("..")
+            .join("..")
+            .join("..")
+            .join("user_scripts")
+            .join("examples")
+            .join("mandelbrot.toml");
+        let plane = InterpretedPlane::new(Script::from_toml_path(&toml_path).unwrap());
+        let c = plane.param_map(Cplx::new(-0.3, 0.6));
+        assert_eq!(plane.critical_points_child(&c), vec![ZERO]);
+        for period in 1..=2 {
+            let points = plane.cycles_child(&c, period);
+            assert_eq!(points.len(), 2);
+            for z in points {
+                let w = (0..period).fold(z, |w, _| plane.map(w, &c));
+                assert!((w - z).norm() < 1e-12);
+            }
+        }
+        assert!(plane.cycles_child(&c, 3).is_empty());
+    }
+
+    #[test]
+    fn example_scripts()
+    {
+        let examples_path = PathBuf::from("..")
+            .join("..")
+            .join("..")
+            .join("user_scripts")
+            .join("examples");
+        for entry in std::fs::read_dir(examples_path).unwrap() {
+            let path = entry.unwrap().path();
+            assert!(Script::from_toml_path(&path).is_ok(), "{}", path.display());
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::script::{Script, ScriptMetaParams, ScriptParams};
+use dynamo_common::prelude::*;
+use dynamo_core::prelude::*;
+use std::sync::Arc;
+
+impl Named for ScriptParams {}
+impl Describe for ScriptParams {}
+impl Named for ScriptMetaParams {}
+impl Describe for ScriptMetaParams {}
+impl ParamList for ScriptMetaParams
+{
+    type Param = Self;
+    fn local_param(&self) -> &Self::Param
+    {
+        self
+    }
+    fn into_local_param(self) -> Self::Param
+    {
+        self
+    }
+}
+
+/// Parameter plane of a user script, evaluated by the bytecode interpreter.
+#[derive(Clone, Debug)]
+pub struct InterpretedPlane
+{
+    point_grid: PointGrid,
+    max_iter: IterCount,
+    compute_mode: ComputeMode,
+    meta_params: ScriptMetaParams,
+    script: Arc<Script>,
+}
+
+impl InterpretedPlane
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds::centered_square(2.5);
+
+    #[must_use]
+    pub fn new(script: Script) -> Self
+    {
+        Self {
+            point_grid: PointGrid::new_by_res_y(1024, Self::DEFAULT_BOUNDS),
+            max_iter: 1024,
+            compute_mode: ComputeMode::default(),
+            meta_params: script.default_meta_params(),
+            script: Arc::new(script),
+        }
+    }
+}
+
+impl DynamicalFamily for InterpretedPlane
+{
+    type Param = ScriptParams;
+    type Var = Cplx;
+    type MetaParam = ScriptMetaParams;
+    type Deriv = Cplx;
+    basic_plane_impl!();
+
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        self.meta_params.clone()
+    }
+
+    fn set_meta_param(&mut self, value: Self::MetaParam)
+    {
+        self.meta_params = value;
+    }
+
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.meta_params.clone()
+    }
+
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {
+        self.meta_params = value;
+    }
+
+    fn param_map(&self, t: Cplx) -> Self::Param
+    {
+        self.script.param_map(t, &self.meta_params)
+    }
+
+    fn escape_radius(&self) -> Real
+    {
+        // Scripts do not declare a leading coefficient at infinity
+        escape_radius_for(self.degree_real(), ONE)
+    }
+
+    fn start_point(&self, t: Cplx, c: &Self::Param) -> Self::Var
+    {
+        self.script.start_d(t, c, &self.meta_params).value
+    }
+
+    fn start_point_d(&self, t: Cplx, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let start = self.script.start_d(t, c, &self.meta_params);
+        (start.value, start.deriv, ZERO)
+    }
+
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        self.script.map_d(z, c, &self.meta_params).value
+    }
+
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let f = self.script.map_d(z, c, &self.meta_params);
+        (f.value, f.deriv)
+    }
+
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let f = self.script.map_d(z, c, &self.meta_params);
+        let df_dt = self.script.map_dt(z, c, &self.meta_params).deriv;
+        (f.value, f.deriv, df_dt)
+    }
+}
+
+impl InfinityFirstReturnMap for InterpretedPlane
+{
+    #[inline]
+    fn degree(&self) -> AngleNum
+    {
+        self.script.degree
+    }
+
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        self.script.degree as Real
+    }
+
+    fn escaping_phase(&self) -> Period
+    {
+        self.script.escaping_phase
+    }
+
+    fn escaping_period(&self) -> Period
+    {
+        self.script.escaping_period
+    }
+}
+
+impl HasJulia for InterpretedPlane {}
+impl MarkedPoints for InterpretedPlane
+{
+    fn critical_points_child(&self, c: &Self::Param) -> Vec<Self::Var>
+    {
+        self.script.critical_points(c, &self.meta_params)
+    }
+
+    fn cycles_child(&self, c: &Self::Param, period: Period) -> Vec<Self::Var>
+    {
+        self.script.cycles(c, &self.meta_params, period)
+    }
+}
+
+impl EscapeEncoding for InterpretedPlane {}
+impl ExternalRays for InterpretedPlane {}
+impl DisplayDefaults for InterpretedPlane
+{
+    default_bounds!();
+
+    fn name(&self) -> String
+    {
+        self.script.name.clone()
+    }
+}
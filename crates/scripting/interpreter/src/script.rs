@@ -0,0 +1,436 @@
+use crate::error::InterpreterError;
+use crate::expr::parse;
+use crate::vm::{Binding, Dual, Program, Scope};
+use dynamo_common::types::{AngleNum, Cplx, HasSliders, MetaParamSlider, Period, Real};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Maximum number of parameters, and of meta-parameters, a script may define.
+pub const MAX_PARAMS: usize = 8;
+
+/// A constant or expression, which TOML allows to be written either as a number or as a string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Value
+{
+    Number(f64),
+    Text(String),
+}
+
+impl Value
+{
+    fn to_expr_string(&self) -> String
+    {
+        match self {
+            Self::Number(x) => x.to_string(),
+            Self::Text(s) => s.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Metadata
+{
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Names
+{
+    variable: String,
+    selection: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Functions
+{
+    map: Value,
+    start: Value,
+}
+
+const fn default_degree() -> AngleNum
+{
+    2
+}
+
+const fn default_period() -> Period
+{
+    1
+}
+
+#[derive(Debug, Deserialize)]
+struct EscapingReturnMapParams
+{
+    #[serde(default = "default_degree")]
+    degree: AngleNum,
+    #[serde(default = "default_period")]
+    escaping_period: Period,
+    #[serde(default = "default_period")]
+    escaping_phase: Period,
+}
+
+impl Default for EscapingReturnMapParams
+{
+    fn default() -> Self
+    {
+        Self {
+            degree: default_degree(),
+            escaping_period: default_period(),
+            escaping_phase: default_period(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct MetaParamRange
+{
+    min: Real,
+    max: Real,
+    default: Option<Value>,
+    #[serde(default)]
+    complex: bool,
+}
+
+/// Marked points of the dynamical plane in terms of the parameters. Cycles are keyed by period.
+#[derive(Debug, Default, Deserialize)]
+struct MarkedPointExprs
+{
+    #[serde(default)]
+    critical: Vec<Value>,
+    #[serde(default)]
+    cycles: BTreeMap<String, Vec<Value>>,
+}
+
+/// The same TOML format read by the script transpiler.
+#[derive(Debug, Deserialize)]
+struct UserInput
+{
+    metadata: Metadata,
+    #[serde(default)]
+    constants: HashMap<String, Value>,
+    #[serde(default)]
+    parameters: HashMap<String, String>,
+    #[serde(default)]
+    meta_parameters: BTreeMap<String, MetaParamRange>,
+    dynamics: Functions,
+    names: Names,
+    #[serde(default)]
+    optional: EscapingReturnMapParams,
+    #[serde(default)]
+    marked_points: MarkedPointExprs,
+}
+
+/// Parameter values of an interpreted script, along with their derivatives with respect to the
+/// selection.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScriptParams
+{
+    names: Arc<[String]>,
+    values: [Dual; MAX_PARAMS],
+}
+
+impl ScriptParams
+{
+    pub fn values(&self) -> impl Iterator<Item = Cplx> + '_
+    {
+        self.values[..self.names.len()].iter().map(|p| p.value)
+    }
+}
+
+impl std::fmt::Display for ScriptParams
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let entries: Vec<String> = self
+            .names
+            .iter()
+            .zip(self.values())
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect();
+        write!(f, "[{}]", entries.join(", "))
+    }
+}
+
+/// Values of the meta-parameters of an interpreted script, which are adjusted by sliders.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScriptMetaParams
+{
+    /// Names and ranges; the values stored here are the defaults.
+    sliders: Arc<[MetaParamSlider]>,
+    values: [Cplx; MAX_PARAMS],
+}
+
+impl std::fmt::Display for ScriptMetaParams
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let entries: Vec<String> = self
+            .sliders
+            .iter()
+            .zip(&self.values)
+            .map(|(slider, value)| format!("{}: {value}", slider.name))
+            .collect();
+        write!(f, "[{}]", entries.join(", "))
+    }
+}
+
+impl HasSliders for ScriptMetaParams
+{
+    fn sliders(&self) -> Vec<MetaParamSlider>
+    {
+        self.sliders
+            .iter()
+            .zip(&self.values)
+            .map(|(slider, value)| MetaParamSlider {
+                value: *value,
+                ..slider.clone()
+            })
+            .collect()
+    }
+
+    fn set_slider(&mut self, idx: usize, value: Cplx)
+    {
+        if idx < self.sliders.len() {
+            self.values[idx] = value;
+        }
+    }
+}
+
+/// A user script compiled to bytecode, evaluated without a Rust toolchain.
+#[derive(Clone, Debug)]
+pub struct Script
+{
+    pub name: String,
+    pub degree: AngleNum,
+    pub escaping_period: Period,
+    pub escaping_phase: Period,
+    param_names: Arc<[String]>,
+    meta_params: ScriptMetaParams,
+    /// Each parameter in terms of the selection, which is read from slot 0.
+    param_map: Vec<Program>,
+    /// Start point in terms of the selection (slot 0) and the parameters (slots 1..).
+    start: Program,
+    /// Map in terms of the variable (slot 0) and the parameters (slots 1..).
+    map: Program,
+    /// Critical points in terms of the parameters (slots 1..).
+    critical_points: Vec<Program>,
+    /// Periodic points of low periods in terms of the parameters (slots 1..).
+    cycles: BTreeMap<Period, Vec<Program>>,
+}
+
+impl Script
+{
+    pub fn from_toml_str(text: &str) -> Result<Self, InterpreterError>
+    {
+        let input: UserInput = toml::from_str(text).map_err(InterpreterError::ErrorParsingToml)?;
+        Self::new(input)
+    }
+
+    pub fn from_toml_path(path: &Path) -> Result<Self, InterpreterError>
+    {
+        let text = std::fs::read_to_string(path).map_err(InterpreterError::ErrorReadingToml)?;
+        Self::from_toml_str(&text)
+    }
+
+    fn new(input: UserInput) -> Result<Self, InterpreterError>
+    {
+        if input.parameters.len() > MAX_PARAMS || input.meta_parameters.len() > MAX_PARAMS {
+            return Err(InterpreterError::TooManyParameters(MAX_PARAMS));
+        }
+
+        let mut scope = Scope::new();
+        for (name, value) in &input.constants {
+            let program = Program::compile(&parse(&value.to_expr_string())?, &Scope::new())?;
+            scope.insert(name.clone(), Binding::Const(program.eval(&[]).value));
+        }
+
+        // Meta-parameters are read from the slots after the parameters
+        let mut meta_params = ScriptMetaParams::default();
+        let mut sliders = Vec::new();
+        for (idx, (name, range)) in input.meta_parameters.iter().enumerate() {
+            let value = match &range.default {
+                Some(value) => {
+                    Program::compile(&parse(&value.to_expr_string())?, &scope)?
+                        .eval(&[])
+                        .value
+                }
+                None => Cplx::new(0.0_f64.clamp(range.min, range.max), 0.),
+            };
+            meta_params.values[idx] = value;
+            sliders.push(MetaParamSlider {
+                name: name.clone(),
+                value,
+                min: range.min,
+                max: range.max,
+                complex: range.complex,
+                integer: false,
+            });
+        }
+        meta_params.sliders = sliders.into();
+        for (idx, name) in input.meta_parameters.keys().enumerate() {
+            scope.insert(name.clone(), Binding::Slot(1 + MAX_PARAMS + idx));
+        }
+
+        // Sort for a stable slot assignment and display order
+        let mut param_names: Vec<String> = input.parameters.keys().cloned().collect();
+        param_names.sort();
+
+        let mut selection_scope = scope.clone();
+        selection_scope.insert(input.names.selection.clone(), Binding::Slot(0));
+        let param_map = param_names
+            .iter()
+            .map(|name| Program::compile(&parse(&input.parameters[name])?, &selection_scope))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (idx, name) in param_names.iter().enumerate() {
+            selection_scope.insert(name.clone(), Binding::Slot(idx + 1));
+            scope.insert(name.clone(), Binding::Slot(idx + 1));
+        }
+        let start = Program::compile(
+            &parse(&input.dynamics.start.to_expr_string())?,
+            &selection_scope,
+        )?;
+
+        let compile_all = |exprs: &[Value]| {
+            exprs
+                .iter()
+                .map(|expr| Program::compile(&parse(&expr.to_expr_string())?, &scope))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let critical_points = compile_all(&input.marked_points.critical)?;
+        let cycles = input
+            .marked_points
+            .cycles
+            .iter()
+            .map(|(key, exprs)| {
+                let period = key
+                    .parse()
+                    .map_err(|_| InterpreterError::MalformedPeriod(key.clone()))?;
+                Ok((period, compile_all(exprs)?))
+            })
+            .collect::<Result<BTreeMap<_, _>, InterpreterError>>()?;
+
+        scope.insert(input.names.variable.clone(), Binding::Slot(0));
+        let map = Program::compile(&parse(&input.dynamics.map.to_expr_string())?, &scope)?;
+
+        Ok(Self {
+            name: input.metadata.name,
+            degree: input.optional.degree,
+            escaping_period: input.optional.escaping_period,
+            escaping_phase: input.optional.escaping_phase,
+            param_names: param_names.into(),
+            meta_params,
+            param_map,
+            start,
+            map,
+            critical_points,
+            cycles,
+        })
+    }
+
+    #[must_use]
+    pub fn default_meta_params(&self) -> ScriptMetaParams
+    {
+        self.meta_params.clone()
+    }
+
+    /// Environment for the bytecode, with `first` in slot 0 followed by the parameters, and then
+    /// the meta-parameters. If `param_derivs` is false, the parameters are treated as constants.
+    fn env(
+        first: Dual,
+        params: &[Dual; MAX_PARAMS],
+        meta_params: &ScriptMetaParams,
+        param_derivs: bool,
+    ) -> [Dual; 2 * MAX_PARAMS + 1]
+    {
+        let mut env = [Dual::default(); 2 * MAX_PARAMS + 1];
+        env[0] = first;
+        for (slot, param) in env[1..=MAX_PARAMS].iter_mut().zip(params) {
+            *slot = if param_derivs {
+                *param
+            } else {
+                Dual::constant(param.value)
+            };
+        }
+        for (slot, value) in env[MAX_PARAMS + 1..].iter_mut().zip(&meta_params.values) {
+            *slot = Dual::constant(*value);
+        }
+        env
+    }
+
+    #[must_use]
+    pub fn param_map(&self, t: Cplx, meta_params: &ScriptMetaParams) -> ScriptParams
+    {
+        let no_params = [Dual::default(); MAX_PARAMS];
+        let env = Self::env(Dual::variable(t), &no_params, meta_params, false);
+        let mut values = [Dual::default(); MAX_PARAMS];
+        for (value, program) in values.iter_mut().zip(&self.param_map) {
+            *value = program.eval(&env);
+        }
+        ScriptParams {
+            names: Arc::clone(&self.param_names),
+            values,
+        }
+    }
+
+    /// Start point and its derivative with respect to the selection.
+    #[must_use]
+    pub fn start_d(&self, t: Cplx, params: &ScriptParams, meta_params: &ScriptMetaParams) -> Dual
+    {
+        let env = Self::env(Dual::variable(t), &params.values, meta_params, true);
+        self.start.eval(&env)
+    }
+
+    /// Map and its derivative with respect to the variable.
+    #[must_use]
+    pub fn map_d(&self, z: Cplx, params: &ScriptParams, meta_params: &ScriptMetaParams) -> Dual
+    {
+        let env = Self::env(Dual::variable(z), &params.values, meta_params, false);
+        self.map.eval(&env)
+    }
+
+    fn eval_points(
+        programs: &[Program],
+        params: &ScriptParams,
+        meta_params: &ScriptMetaParams,
+    ) -> Vec<Cplx>
+    {
+        let env = Self::env(Dual::default(), &params.values, meta_params, false);
+        programs.iter().map(|p| p.eval(&env).value).collect()
+    }
+
+    #[must_use]
+    pub fn critical_points(
+        &self,
+        params: &ScriptParams,
+        meta_params: &ScriptMetaParams,
+    ) -> Vec<Cplx>
+    {
+        Self::eval_points(&self.critical_points, params, meta_params)
+    }
+
+    /// Points of exact period `period` declared by the script, if any.
+    #[must_use]
+    pub fn cycles(
+        &self,
+        params: &ScriptParams,
+        meta_params: &ScriptMetaParams,
+        period: Period,
+    ) -> Vec<Cplx>
+    {
+        self.cycles
+            .get(&period)
+            .map(|programs| Self::eval_points(programs, params, meta_params))
+            .unwrap_or_default()
+    }
+
+    /// Map and its derivative with respect to the selection, holding the variable fixed.
+    #[must_use]
+    pub fn map_dt(&self, z: Cplx, params: &ScriptParams, meta_params: &ScriptMetaParams) -> Dual
+    {
+        let env = Self::env(Dual::constant(z), &params.values, meta_params, true);
+        self.map.eval(&env)
+    }
+}
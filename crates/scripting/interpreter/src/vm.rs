@@ -0,0 +1,184 @@
+use crate::error::InterpreterError;
+use crate::expr::{builtin_constant, BinOp, Expr, Func};
+use dynamo_common::types::dual::Scalar;
+pub use dynamo_common::types::Dual;
+use dynamo_common::types::{Cplx, Real};
+use std::collections::HashMap;
+
+/// Maximum depth of the evaluation stack. Programs are evaluated on a fixed-size array, so that
+/// no allocation happens in the inner loop.
+pub const MAX_STACK: usize = 64;
+
+/// Apply a builtin function to a dual number.
+fn apply(z: Dual, func: Func) -> Dual
+{
+    match func {
+        Func::Exp => z.exp(),
+        Func::Log => z.ln(),
+        Func::Sqrt => z.sqrt(),
+        Func::Sin => z.sin(),
+        Func::Cos => z.cos(),
+        Func::Tan => z.tan(),
+        Func::Sinh => z.sinh(),
+        Func::Cosh => z.cosh(),
+        Func::Tanh => z.tanh(),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op
+{
+    Const(Cplx),
+    Load(usize),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+    PowI(i32),
+    Pow,
+    Call(Func),
+}
+
+/// What a name refers to when compiling an expression.
+#[derive(Clone, Copy, Debug)]
+pub enum Binding
+{
+    /// Index into the environment passed to `Program::eval`.
+    Slot(usize),
+    Const(Cplx),
+}
+
+pub type Scope = HashMap<String, Binding>;
+
+/// An expression compiled to a sequence of stack operations over dual numbers, so that a single
+/// evaluation yields both a value and its derivative.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Program
+{
+    ops: Vec<Op>,
+}
+
+fn integer_exponent(expr: &Expr) -> Option<i32>
+{
+    match expr {
+        Expr::Num(x) if x.im == 0. && x.re.fract() == 0. && x.re.abs() <= Real::from(i32::MAX) =>
+        {
+            #[allow(clippy::cast_possible_truncation)]
+            Some(x.re as i32)
+        }
+        Expr::Neg(inner) => integer_exponent(inner).map(|n| -n),
+        _ => None,
+    }
+}
+
+impl Program
+{
+    pub fn compile(expr: &Expr, scope: &Scope) -> Result<Self, InterpreterError>
+    {
+        let mut program = Self::default();
+        program.emit(expr, scope)?;
+        if program.max_depth() > MAX_STACK {
+            return Err(InterpreterError::ExpressionTooDeep);
+        }
+        Ok(program)
+    }
+
+    fn emit(&mut self, expr: &Expr, scope: &Scope) -> Result<(), InterpreterError>
+    {
+        match expr {
+            Expr::Num(x) => self.ops.push(Op::Const(*x)),
+            Expr::Symbol(name) => {
+                let op = match scope.get(name) {
+                    Some(Binding::Slot(idx)) => Op::Load(*idx),
+                    Some(Binding::Const(value)) => Op::Const(*value),
+                    None => Op::Const(
+                        builtin_constant(name)
+                            .ok_or_else(|| InterpreterError::UndefinedSymbol(name.clone()))?,
+                    ),
+                };
+                self.ops.push(op);
+            }
+            Expr::Neg(inner) => {
+                self.emit(inner, scope)?;
+                self.ops.push(Op::Neg);
+            }
+            Expr::Binary(BinOp::Pow, base, exponent) => {
+                self.emit(base, scope)?;
+                if let Some(n) = integer_exponent(exponent) {
+                    self.ops.push(Op::PowI(n));
+                } else {
+                    self.emit(exponent, scope)?;
+                    self.ops.push(Op::Pow);
+                }
+            }
+            Expr::Binary(op, lhs, rhs) => {
+                self.emit(lhs, scope)?;
+                self.emit(rhs, scope)?;
+                self.ops.push(match op {
+                    BinOp::Add => Op::Add,
+                    BinOp::Sub => Op::Sub,
+                    BinOp::Mul => Op::Mul,
+                    BinOp::Div => Op::Div,
+                    BinOp::Pow => Op::Pow,
+                });
+            }
+            Expr::Call(func, arg) => {
+                self.emit(arg, scope)?;
+                self.ops.push(Op::Call(*func));
+            }
+        }
+        Ok(())
+    }
+
+    fn max_depth(&self) -> usize
+    {
+        let mut depth: usize = 0;
+        let mut max_depth = 0;
+        for op in &self.ops {
+            match op {
+                Op::Const(_) | Op::Load(_) => depth += 1,
+                Op::Add | Op::Sub | Op::Mul | Op::Div | Op::Pow => depth -= 1,
+                Op::Neg | Op::PowI(_) | Op::Call(_) => {}
+            }
+            max_depth = max_depth.max(depth);
+        }
+        max_depth
+    }
+
+    /// Evaluate the program, reading variables from `env`.
+    #[must_use]
+    pub fn eval(&self, env: &[Dual]) -> Dual
+    {
+        let mut stack = [Dual::default(); MAX_STACK];
+        let mut top = 0;
+
+        for op in &self.ops {
+            match *op {
+                Op::Const(value) => {
+                    stack[top] = Dual::constant(value);
+                    top += 1;
+                }
+                Op::Load(idx) => {
+                    stack[top] = env[idx];
+                    top += 1;
+                }
+                Op::Neg => stack[top - 1] = -stack[top - 1],
+                Op::PowI(n) => stack[top - 1] = stack[top - 1].powi(n),
+                Op::Call(func) => stack[top - 1] = apply(stack[top - 1], func),
+                Op::Add | Op::Sub | Op::Mul | Op::Div | Op::Pow => {
+                    top -= 1;
+                    let (lhs, rhs) = (stack[top - 1], stack[top]);
+                    stack[top - 1] = match op {
+                        Op::Add => lhs + rhs,
+                        Op::Sub => lhs - rhs,
+                        Op::Mul => lhs * rhs,
+                        Op::Div => lhs / rhs,
+                        _ => lhs.powc(rhs),
+                    };
+                }
+            }
+        }
+        stack[0]
+    }
+}
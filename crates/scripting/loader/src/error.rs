@@ -5,6 +5,8 @@ pub enum ScriptError
 {
     MalformedConst,
     MalformedEquation,
+    MalformedMetaParam(String),
+    MalformedPeriod(String),
     UndefinedSymbol,
     ForbiddenKeyword,
     CompilationFailed,
@@ -12,6 +14,7 @@ pub enum ScriptError
     PythonError(PyErr),
     ErrorWritingFile(std::io::Error),
     ErrorReadingToml(std::io::Error),
+    ErrorReadingFile(std::io::Error),
     ErrorParsingToml(toml::de::Error),
     ErrorMovingLibrary(std::io::Error),
     ErrorLoadingLibrary(libloading::Error),
@@ -25,3 +28,24 @@ impl From<PyErr> for ScriptError
         Self::PythonError(err)
     }
 }
+
+impl std::fmt::Display for ScriptError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+impl From<ScriptError> for dynamo_core::error::DynamoError
+{
+    fn from(err: ScriptError) -> Self
+    {
+        match err {
+            ScriptError::ErrorReadingToml(e) | ScriptError::ErrorReadingFile(e) => Self::Io(e),
+            err => Self::Script(err.to_string()),
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub mod error;
 pub mod loader;
 pub mod parser;
 pub mod transpiler;
+pub mod watcher;
 pub use loader::Loader;
 
 #[cfg(test)]
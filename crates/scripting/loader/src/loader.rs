@@ -1,5 +1,7 @@
 use crate::error::ScriptError;
+use crate::parser::UnparsedUserInput;
 use crate::transpiler::Transpiler;
+use dynamo_common::types::Period;
 use dynamo_gui::interface::Interface;
 use dynamo_gui::interface_holder::InterfaceHolder;
 use libloading::{Library, Symbol};
@@ -27,7 +29,7 @@ mod config
     pub const LIB_PRE: &str = "lib";
 }
 
-fn file_hash<P: AsRef<Path>>(path: P) -> Result<String, std::io::Error>
+pub(crate) fn file_hash<P: AsRef<Path>>(path: P) -> Result<String, std::io::Error>
 {
     let bytes = std::fs::read(path.as_ref())?;
     Ok(sha256::digest(bytes))
@@ -39,6 +41,7 @@ pub struct Loader<'a>
     pub toml_path: &'a Path,
     pub output_path: PathBuf,
     pub image_height: usize,
+    marked_cycle: Option<Period>,
     lib_path: Option<PathBuf>,
 }
 
@@ -52,6 +55,7 @@ impl<'a> Loader<'a>
             toml_path,
             output_path,
             image_height,
+            marked_cycle: None,
             lib_path: None,
         }
     }
@@ -63,11 +67,46 @@ impl<'a> Loader<'a>
         self
     }
 
+    /// Load the marked-cycle cover of the given period declared by the script, instead of its
+    /// parameter plane.
+    #[must_use]
+    pub const fn with_marked_cycle_cover(mut self, period: Period) -> Self
+    {
+        self.marked_cycle = Some(period);
+        self
+    }
+
+    fn generated_dir(&self) -> PathBuf
+    {
+        self.output_path.join("src").join("generated")
+    }
+
     fn transpile_toml(&self) -> Result<(), ScriptError>
     {
         let transpiler = Transpiler::from_toml_path(self.toml_path)?;
-        let rust_path = self.output_path.join("src").join("generated");
-        transpiler.write(&rust_path)
+        transpiler.write(&self.generated_dir())
+    }
+
+    fn read_user_input(&self) -> Result<UnparsedUserInput, ScriptError>
+    {
+        let content =
+            std::fs::read_to_string(self.toml_path).map_err(ScriptError::ErrorReadingToml)?;
+        toml::from_str(&content).map_err(ScriptError::ErrorParsingToml)
+    }
+
+    /// Path of the Rust file that the script is transpiled into.
+    pub fn generated_rust_path(&self) -> Result<PathBuf, ScriptError>
+    {
+        let user_input = self.read_user_input()?;
+        Ok(self
+            .generated_dir()
+            .join(format!("{}.rs", user_input.metadata.short_name)))
+    }
+
+    /// Periods of the marked-cycle covers declared by the script.
+    pub fn marked_cycle_periods(&self) -> Result<Vec<Period>, ScriptError>
+    {
+        self.read_user_input()?.marked_cycle_periods()
     }
 
     fn base_dir(&self) -> PathBuf
@@ -96,16 +135,23 @@ impl<'a> Loader<'a>
         ))
     }
 
+    fn compiled_lib_path(output_path: &Path, hash: &str) -> PathBuf
+    {
+        let lib_id = hash.get(0..12).unwrap_or(hash);
+        output_path.join("..").join("compiled").join(format!(
+            "{}scripts_{}{}",
+            config::LIB_PRE,
+            lib_id,
+            config::LIB_EXT
+        ))
+    }
+
     fn dest_lib_path(&mut self) -> &PathBuf
     {
+        let output_path = &self.output_path;
+        let toml_path = self.toml_path;
         self.lib_path.get_or_insert_with(|| {
-            let lib_id = &file_hash(self.toml_path).unwrap_or_default()[0..12];
-            self.output_path.join("..").join("compiled").join(format!(
-                "{}scripts_{}{}",
-                config::LIB_PRE,
-                lib_id,
-                config::LIB_EXT
-            ))
+            Self::compiled_lib_path(output_path, &file_hash(toml_path).unwrap_or_default())
         })
     }
 
@@ -164,8 +210,12 @@ impl<'a> Loader<'a>
         let lib = Library::new(self.dest_lib_path()).map_err(ScriptError::ErrorLoadingLibrary)?;
 
         // Get the constructor function from the dynamic library
+        let symbol = match self.marked_cycle {
+            Some(period) => format!("create_marked_cycle_interface_{period}"),
+            None => "create_interface".to_owned(),
+        };
         let constructor: Symbol<Constructor> = lib
-            .get(b"create_interface")
+            .get(symbol.as_bytes())
             .map_err(ScriptError::ErrorLoadingLibrary)?;
 
         let mut interface = Box::from_raw(constructor());
@@ -203,6 +253,25 @@ impl<'a> Loader<'a>
         self.load()
     }
 
+    /// Same as `run`, but build the generated Rust as it currently stands instead of transpiling
+    /// the script again. This keeps any manual edits made to the generated file.
+    ///
+    /// # Safety
+    ///
+    /// See the safety notes for `run`. The library filename is derived from the generated Rust
+    /// rather than the script, so that an edited library is never confused with a cached one.
+    pub unsafe fn rebuild<'i>(mut self) -> Result<InterfaceHolder<'i>, ScriptError>
+    {
+        let hash = file_hash(self.generated_rust_path()?).map_err(ScriptError::ErrorReadingFile)?;
+        self.lib_path = Some(Self::compiled_lib_path(&self.output_path, &hash));
+
+        println!("\nBuilding script...");
+        self.build()?;
+
+        println!("\nLoading script...");
+        self.load()
+    }
+
     /// Same as `run`, but avoid recompiling if the script's hash matches an existing library file.
     ///
     /// # Safety
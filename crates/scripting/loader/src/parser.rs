@@ -1,13 +1,13 @@
 use dynamo_common::types::Period;
 use lazy_static::lazy_static;
 use num_complex::Complex64;
-use pyo3::{Python, ToPyObject};
+use pyo3::types::{IntoPyDict, PyAnyMethods};
+use pyo3::{IntoPyObject, Python};
 use regex::Regex;
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
-use pyo3::types::PyAnyMethods;
 
 mod defaults;
 
@@ -57,12 +57,89 @@ impl Default for EscapingReturnMapParams
     }
 }
 
+/// Range of a meta-parameter, which is adjusted by a slider in the UI.
+#[derive(Debug, Deserialize)]
+pub struct MetaParamRange
+{
+    pub min: f64,
+    pub max: f64,
+    /// Initial value, defaulting to the point of the range closest to zero.
+    pub default: Option<JsonValue>,
+    /// Whether the imaginary part is adjustable too.
+    #[serde(default)]
+    pub complex: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct MetaParameter
+{
+    pub name: String,
+    pub min: f64,
+    pub max: f64,
+    pub default: Complex64,
+    pub complex: bool,
+}
+
+impl MetaParameter
+{
+    fn new(name: String, range: &MetaParamRange) -> Result<Self, ScriptError>
+    {
+        if range.min.is_nan() || range.max.is_nan() || range.min > range.max {
+            return Err(ScriptError::MalformedMetaParam(name));
+        }
+        let default = match &range.default {
+            Some(value) => json_to_complex(value)?,
+            None => Complex64::new(0.0_f64.clamp(range.min, range.max), 0.),
+        };
+        Ok(Self {
+            name,
+            min: range.min,
+            max: range.max,
+            default,
+            complex: range.complex,
+        })
+    }
+}
+
+/// Marked points of the dynamical plane, in terms of the parameters.
+#[derive(Debug, Default, Deserialize)]
+pub struct MarkedPointExprs
+{
+    #[serde(default)]
+    pub critical: Vec<String>,
+    /// Periodic points, keyed by period.
+    #[serde(default)]
+    pub cycles: BTreeMap<String, Vec<String>>,
+}
+
+/// Covers of the parameter plane, each expressing the selection on the base family in terms of
+/// the selection on the cover.
+#[derive(Debug, Default, Deserialize)]
+pub struct Covers
+{
+    /// Marked-cycle covers, keyed by period.
+    #[serde(default)]
+    pub marked_cycle: BTreeMap<String, String>,
+}
+
+fn parse_period(key: &str) -> Result<Period, ScriptError>
+{
+    key.parse()
+        .map_err(|_| ScriptError::MalformedPeriod(key.to_owned()))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UnparsedUserInput
 {
     pub metadata: Metadata,
     pub constants: HashMap<String, JsonValue>,
     pub parameters: HashMap<String, String>,
+    #[serde(default)]
+    pub meta_parameters: BTreeMap<String, MetaParamRange>,
+    #[serde(default)]
+    pub marked_points: MarkedPointExprs,
+    #[serde(default)]
+    pub covers: Covers,
     pub dynamics: Functions,
     pub names: Names,
     pub optional: Option<EscapingReturnMapParams>,
@@ -74,8 +151,28 @@ pub struct PyParams
     pub param_map: String,
     pub map: String,
     pub map_d: String,
+    pub gradient: String,
     pub start: String,
     pub start_d: String,
+    /// Body of `critical_points_child`, if the script specifies critical points.
+    pub critical_points: Option<String>,
+    /// Bodies producing the periodic points of each given period.
+    pub cycles: Vec<(Period, String)>,
+    /// Bodies of the covering maps of each marked-cycle cover, by period.
+    pub marked_cycle_covers: Vec<(Period, String)>,
+}
+
+impl UnparsedUserInput
+{
+    /// Periods of the marked-cycle covers declared by the script.
+    pub fn marked_cycle_periods(&self) -> Result<Vec<Period>, ScriptError>
+    {
+        self.covers
+            .marked_cycle
+            .keys()
+            .map(|key| parse_period(key))
+            .collect()
+    }
 }
 
 pub struct ParsedUserInput
@@ -83,6 +180,7 @@ pub struct ParsedUserInput
     pub metadata: Metadata,
     pub constants: HashMap<String, Complex64>,
     pub param_names: Vec<String>,
+    pub meta_params: Vec<MetaParameter>,
     pub names: Names,
     pub optional: EscapingReturnMapParams,
     pub py_params: PyParams,
@@ -177,56 +275,78 @@ impl UnparsedUserInput
             .filter_map(Result::ok)
             .collect::<HashMap<String, Complex64>>();
 
+        let meta_params = self
+            .meta_parameters
+            .iter()
+            .map(|(name, range)| MetaParameter::new(name.clone(), range))
+            .collect::<Result<Vec<_>, _>>()?;
+
         let py_params = Python::with_gil(|py| {
-            let sys = py.import_bound("sys")?;
+            let sys = py.import("sys")?;
             sys.getattr("path")?.call_method1("append", ("python",))?;
             sys.getattr("path")?
                 .call_method1("append", ("crates/scripting/loader/python",))?;
 
-            // Convert to python types
-            let map_str = &json_to_string(&self.dynamics.map)
-                .replace('^', "**")
-                .to_object(py);
-            let start_str = &json_to_string(&self.dynamics.start)
-                .replace('^', "**")
-                .to_object(py);
-            let z_str = self.names.variable.to_object(py);
-            let t_str = self.names.selection.to_object(py);
-
-            let param_names_py = param_names.to_object(py);
-            let const_names_py = const_names.to_object(py);
+            let map_str = json_to_string(&self.dynamics.map).replace('^', "**");
+            let start_str = json_to_string(&self.dynamics.start).replace('^', "**");
+            let z_str = &self.names.variable;
+            let t_str = &self.names.selection;
 
             // Imports
-            let sympy = py.import_bound("sympy")?;
+            let sympy = py.import("sympy")?;
             let parse_expr = sympy.getattr("parse_expr")?;
+            let symbol = sympy.getattr("Symbol")?;
             let symbols = sympy.getattr("symbols")?;
             let cse = sympy.getattr("cse")?;
 
-            let oxidize = py.import_bound("oxidize")?;
+            let oxidize = py.import("oxidize")?;
             let oxidize_expr = oxidize.getattr("oxidize_expr")?;
             let oxidize_cse = oxidize.getattr("oxidize_cse")?;
+            let oxidize_cse_cplx = oxidize.getattr("oxidize_cse_cplx")?;
+            let oxidize_expr_cplx = oxidize.getattr("oxidize_expr_cplx")?;
             let oxidize_pmap = oxidize.getattr("oxidize_param_map_cplx")?;
 
             // Symbol declarations
-            symbols.call1(((&z_str, &t_str),))?;
-            symbols.call1((&param_names_py,))?;
-            symbols.call1((&const_names_py,))?;
+            symbols.call1(((z_str, t_str),))?;
+            symbols.call1((&param_names,))?;
+            symbols.call1((&const_names,))?;
+
+            let t_py = symbol.call1((t_str,))?;
 
             // Parsing
             let mut params_dict_py = HashMap::new();
+            // Each parameter is stored alongside its derivative with respect to the selection,
+            // which the generated `gradient` needs for the chain rule.
+            let mut param_map_dict_py = HashMap::new();
 
             self.parameters.iter().try_for_each(|(name, val)| {
                 let parsed_val = parse_expr.call1((val.replace('^', "**"),))?;
+                let parsed_val_d = parsed_val.call_method1("diff", (t_str,))?;
+                param_map_dict_py.insert(name.clone(), parsed_val.clone());
+                param_map_dict_py.insert(format!("{name}_dt"), parsed_val_d);
                 params_dict_py.insert(name, parsed_val);
                 Ok::<_, ScriptError>(())
             })?;
-            let params_dict_py = params_dict_py.to_object(py);
+            let params_dict_py_owned = params_dict_py.clone();
+            let params_dict_py = params_dict_py.into_pyobject(py)?;
+            let param_map_dict_py = param_map_dict_py.into_pyobject(py)?;
 
             let map_py = parse_expr.call1((map_str,))?;
             let map_d_py = map_py.call_method1("diff", (z_str,))?;
             let map_cse_py = cse.call1(([&map_py, &map_d_py],))?;
+
+            // Derivative of the map with respect to the selection, through the parameters
+            let mut map_dt_py = sympy.getattr("Integer")?.call1((0,))?;
+            for name in &param_names {
+                let partial = map_py.call_method1("diff", (symbol.call1((name,))?,))?;
+                let param_dt = symbol.call1((format!("{name}_dt"),))?;
+                map_dt_py = map_dt_py.add(partial.mul(param_dt)?)?;
+            }
+            let gradient_cse_py = cse.call1(([&map_py, &map_d_py, &map_dt_py],))?;
+
             let map = oxidize_expr.call1((map_py,))?.to_string();
             let map_d = oxidize_cse.call1((map_cse_py,))?.to_string();
+            let gradient = oxidize_cse_cplx.call1((gradient_cse_py,))?.to_string();
 
             let start_py = parse_expr.call1((start_str,))?;
             let start_py = start_py.call_method1("subs", (&params_dict_py,))?;
@@ -235,14 +355,66 @@ impl UnparsedUserInput
             let start = oxidize_expr.call1((&start_py,))?.to_string();
             let start_d = oxidize_cse.call1((start_cse_py,))?.to_string();
 
-            let param_map = oxidize_pmap.call1((params_dict_py,))?.to_string();
+            let param_map = oxidize_pmap.call1((param_map_dict_py,))?.to_string();
+
+            // Marked points, which are computed from the parameters directly
+            let oxidize_points = |exprs: &[String]| {
+                let points = exprs
+                    .iter()
+                    .map(|expr| {
+                        let expr_py = parse_expr.call1((expr.replace('^', "**"),))?;
+                        Ok(oxidize_expr_cplx.call1((expr_py,))?.to_string())
+                    })
+                    .collect::<Result<Vec<_>, ScriptError>>()?;
+                Ok::<_, ScriptError>(format!("vec![{}]", points.join(", ")))
+            };
+            let critical_points = if self.marked_points.critical.is_empty() {
+                None
+            } else {
+                Some(oxidize_points(&self.marked_points.critical)?)
+            };
+            let cycles = self
+                .marked_points
+                .cycles
+                .iter()
+                .map(|(key, exprs)| Ok((parse_period(key)?, oxidize_points(exprs)?)))
+                .collect::<Result<Vec<_>, ScriptError>>()?;
+
+            // Covering maps, obtained by substituting the cover's selection into the parameters
+            let marked_cycle_covers = self
+                .covers
+                .marked_cycle
+                .iter()
+                .map(|(key, expr)| {
+                    let selection_py = parse_expr.call1((expr.replace('^', "**"),))?;
+                    let mut cover_dict_py = HashMap::new();
+                    for (name, val) in &params_dict_py_owned {
+                        let cover_val = val.call_method1("subs", (&t_py, &selection_py))?;
+                        let cover_val_d = cover_val.call_method1("diff", (&t_py,))?;
+                        cover_dict_py.insert((*name).clone(), cover_val);
+                        cover_dict_py.insert(format!("{name}_dt"), cover_val_d);
+                    }
+                    let pmap = oxidize_pmap.call1((cover_dict_py.into_py_dict(py)?,))?;
+                    let selection_d_py = selection_py.call_method1("diff", (&t_py,))?;
+                    let body = format!(
+                        "let param = {{\n{}\n}};\n(param, {})",
+                        pmap,
+                        oxidize_expr_cplx.call1((selection_d_py,))?
+                    );
+                    Ok((parse_period(key)?, body))
+                })
+                .collect::<Result<Vec<_>, ScriptError>>()?;
 
             let py_params = PyParams {
                 param_map,
                 map,
                 map_d,
+                gradient,
                 start,
                 start_d,
+                critical_points,
+                cycles,
+                marked_cycle_covers,
             };
 
             Ok::<_, ScriptError>(py_params)
@@ -252,6 +424,7 @@ impl UnparsedUserInput
             metadata: self.metadata,
             constants,
             param_names,
+            meta_params,
             names: self.names,
             optional: self.optional.unwrap_or_default(),
             py_params,
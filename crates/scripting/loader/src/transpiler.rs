@@ -35,7 +35,7 @@ impl Transpiler
             .parsed_input
             .param_names
             .iter()
-            .map(|name| format!("{name}: Cplx,")) // adjust the format as needed
+            .map(|name| format!("{name}: Cplx,\n{name}_dt: Cplx,")) // adjust the format as needed
             .collect();
 
         let derives = "#[derive(Clone, Copy, Default, PartialEq, Debug)]";
@@ -91,6 +91,101 @@ impl Transpiler
         )
     }
 
+    fn meta_parameter_decl(&self) -> String
+    {
+        let meta_params = &self.parsed_input.meta_params;
+        if meta_params.is_empty() {
+            return "type MetaParameters = NoParam;".to_owned();
+        }
+
+        let member_decls: Vec<String> = meta_params
+            .iter()
+            .map(|param| format!("{}: Cplx,", param.name))
+            .collect();
+        let defaults: Vec<String> = meta_params
+            .iter()
+            .map(|param| {
+                format!(
+                    "{}: Cplx::new({:?}, {:?}),",
+                    param.name, param.default.re, param.default.im
+                )
+            })
+            .collect();
+        let names = meta_params
+            .iter()
+            .map(|param| format!("self.{}", param.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let names_and_values = meta_params
+            .iter()
+            .map(|param| format!("{}: {{}}", param.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sliders: Vec<String> = meta_params
+            .iter()
+            .map(|param| {
+                format!(
+                    "MetaParamSlider {{ name: \"{name}\".to_owned(), value: self.{name}, \
+                    min: {min:?}, max: {max:?}, complex: {complex}, integer: false }},",
+                    name = param.name,
+                    min = param.min,
+                    max = param.max,
+                    complex = param.complex,
+                )
+            })
+            .collect();
+        let setters: Vec<String> = meta_params
+            .iter()
+            .enumerate()
+            .map(|(idx, param)| format!("{idx} => self.{} = value,", param.name))
+            .collect();
+
+        format!(
+            "#[derive(Clone, Copy, PartialEq, Debug)]\n\
+            pub struct MetaParameters {{\n{member_decls}\n}}\n\
+            impl Default for MetaParameters {{
+                fn default() -> Self {{
+                    Self {{ {defaults} }}
+                }}
+            }}
+            impl std::fmt::Display for MetaParameters {{
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                    write!(f, \"[{names_and_values}]\", {names})
+                }}
+            }}
+            impl Named for MetaParameters {{}}
+            impl Describe for MetaParameters {{
+                fn describe(&self, conf: &DescriptionConf) -> Option<String> {{
+                    conf.is_enabled.then(|| self.to_string())
+                }}
+            }}
+            impl ParamList for MetaParameters {{
+                type Param = Self;
+                fn local_param(&self) -> &Self::Param {{
+                    self
+                }}
+                fn into_local_param(self) -> Self::Param {{
+                    self
+                }}
+            }}
+            impl HasSliders for MetaParameters {{
+                fn sliders(&self) -> Vec<MetaParamSlider> {{
+                    vec![{sliders}]
+                }}
+                fn set_slider(&mut self, idx: usize, value: Cplx) {{
+                    match idx {{
+                        {setters}
+                        _ => {{}}
+                    }}
+                }}
+            }}",
+            member_decls = member_decls.join("\n"),
+            defaults = defaults.join(" "),
+            sliders = sliders.join("\n"),
+            setters = setters.join("\n"),
+        )
+    }
+
     fn user_struct_decl(&self) -> String
     {
         let const_decls: Vec<String> = self
@@ -113,54 +208,124 @@ impl Transpiler
                 point_grid: PointGrid,\n\
                 max_iter: IterCount,\n\
                 compute_mode: ComputeMode,\n\
+                meta_params: MetaParameters,\n\
             }}\n\
             impl UserPlane {{\n\
                 const DEFAULT_BOUNDS: Bounds = Bounds::centered_square(2.5);\n\
             }}\n\
             impl Default for UserPlane {{\n\
-                fractal_impl!();\n\
+                fn default() -> Self {{\n\
+                    Self {{\n\
+                        point_grid: PointGrid::new_by_res_y(1024, Self::DEFAULT_BOUNDS),\n\
+                        max_iter: 1024,\n\
+                        compute_mode: ComputeMode::default(),\n\
+                        meta_params: MetaParameters::default(),\n\
+                    }}\n\
+                }}\n\
+            }}\n\
+            impl DisplayDefaults for UserPlane {{\n\
+                default_bounds!();\n\
+                fn name(&self) -> String {{\n\
+                    \"{name}\".to_owned()\n\
+                }}\n\
             }}\n\
-            default_bounds_impl!(UserPlane);\n\
             const i: Cplx = Cplx::new(0., 1.);\n\
             {}",
-            const_decls.join("\n")
+            const_decls.join("\n"),
+            name = self.parsed_input.metadata.name,
         )
     }
 
     fn destructure_param(&self) -> String
     {
+        if self.parsed_input.param_names.is_empty() {
+            return "Parameters {}".to_owned();
+        }
         format!(
-            "Parameters {{ {} }}",
+            "Parameters {{ {}, .. }}",
             self.parsed_input.param_names.join(", ")
         )
     }
 
+    /// Destructure the parameters together with their derivatives with respect to the selection.
+    fn destructure_param_d(&self) -> String
+    {
+        let fields: Vec<String> = self
+            .parsed_input
+            .param_names
+            .iter()
+            .map(|name| format!("{name}, {name}_dt"))
+            .collect();
+        format!("Parameters {{ {} }}", fields.join(", "))
+    }
+
+    /// Bind the meta-parameters to local variables, so that expressions can refer to them.
+    fn destructure_meta_params(&self) -> String
+    {
+        if self.parsed_input.meta_params.is_empty() {
+            return String::new();
+        }
+        let names: Vec<&str> = self
+            .parsed_input
+            .meta_params
+            .iter()
+            .map(|param| param.name.as_str())
+            .collect();
+        format!(
+            "let MetaParameters {{ {} }} = self.meta_params;",
+            names.join(", ")
+        )
+    }
+
     fn parameter_plane_impl(&self) -> String
     {
         format!(
             "impl DynamicalFamily for UserPlane {{
     type Param = Parameters;
     type Var = Cplx;
-    type MetaParam = NoParam;
+    type MetaParam = MetaParameters;
     type Deriv = Cplx;
     basic_plane_impl!();
 
+    fn get_meta_params(&self) -> Self::MetaParam
+    {{
+        self.meta_params
+    }}
+
+    fn set_meta_param(&mut self, value: Self::MetaParam)
+    {{
+        self.meta_params = value;
+    }}
+
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {{
+        self.meta_params
+    }}
+
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {{
+        self.meta_params = value;
+    }}
+
     fn param_map(&self, {t}: Cplx) -> Self::Param {{
+        {meta}
         {param_map}
     }}
 
     fn escape_radius(&self) -> Real
     {{
-        1e26
+        escape_radius_for(self.degree_real(), ONE)
     }}
 
     fn start_point(&self, {t}: Cplx, &{c}: &Self::Param) -> Self::Var
     {{
+        {meta}
         Self::Var::from({start})
     }}
 
     fn start_point_d(&self, {t}: Cplx, &{c}: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
     {{
+        {meta}
         let (z0, dz0_dt) = {{
             {start_d}
         }};
@@ -169,28 +334,149 @@ impl Transpiler
 
     fn map(&self, {z}: Self::Var, &{c}: &Self::Param) -> Self::Var
     {{
+        {meta}
         {map}
     }}
 
     fn map_and_multiplier(&self, {z}: Self::Var, &{c}: &Self::Param) -> (Self::Var, Self::Deriv)
     {{
+        {meta}
         {map_d}
     }}
 
-    fn name(&self) -> String
+    fn gradient(&self, {z}: Self::Var, &{c_d}: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
     {{
-        \"{name}\".to_owned()
+        {meta}
+        {gradient}
     }}
 }}",
             t = self.parsed_input.names.selection,
             z = self.parsed_input.names.variable,
             c = self.destructure_param(),
+            c_d = self.destructure_param_d(),
+            meta = self.destructure_meta_params(),
             param_map = self.parsed_input.py_params.param_map,
             map = self.parsed_input.py_params.map,
             map_d = self.parsed_input.py_params.map_d,
+            gradient = self.parsed_input.py_params.gradient,
             start = self.parsed_input.py_params.start,
             start_d = self.parsed_input.py_params.start_d,
-            name = self.parsed_input.metadata.name,
+        )
+    }
+
+    fn marked_points_impl(&self) -> String
+    {
+        let py_params = &self.parsed_input.py_params;
+        let critical_points =
+            py_params
+                .critical_points
+                .as_ref()
+                .map_or_else(String::new, |points| {
+                    format!(
+                        "fn critical_points_child(&self, &{c}: &Self::Param) -> Vec<Self::Var>
+    {{
+        {meta}
+        {points}
+    }}",
+                        c = self.destructure_param(),
+                        meta = self.destructure_meta_params(),
+                    )
+                });
+        let cycles = if py_params.cycles.is_empty() {
+            String::new()
+        } else {
+            let arms: Vec<String> = py_params
+                .cycles
+                .iter()
+                .map(|(period, points)| format!("{period} => {points},"))
+                .collect();
+            format!(
+                "fn cycles_child(&self, &{c}: &Self::Param, period: Period) -> Vec<Self::Var>
+    {{
+        {meta}
+        match period {{
+            {arms}
+            _ => vec![],
+        }}
+    }}",
+                c = self.destructure_param(),
+                meta = self.destructure_meta_params(),
+                arms = arms.join("\n"),
+            )
+        };
+        format!("impl MarkedPoints for UserPlane {{\n{critical_points}\n{cycles}\n}}")
+    }
+
+    /// Covering maps are plain functions, so they use the default values of any meta-parameters.
+    fn covers_impl(&self) -> String
+    {
+        let covers = &self.parsed_input.py_params.marked_cycle_covers;
+        if covers.is_empty() {
+            return String::new();
+        }
+        let meta = if self.parsed_input.meta_params.is_empty() {
+            String::new()
+        } else {
+            self.destructure_meta_params()
+                .replace("self.meta_params", "MetaParameters::default()")
+        };
+        let cover_fns: Vec<String> = covers
+            .iter()
+            .map(|(period, body)| {
+                format!(
+                    "fn marked_cycle_cover_{period}({t}: Cplx) -> (Parameters, Cplx) {{
+        {meta}
+        {body}
+    }}",
+                    t = self.parsed_input.names.selection,
+                    body = body.replace("Self::Param", "Parameters"),
+                )
+            })
+            .collect();
+        let arms: Vec<String> = covers
+            .iter()
+            .map(|(period, _)| {
+                format!("{period} => CoveringMap::new(self, Self::marked_cycle_cover_{period}),")
+            })
+            .collect();
+        let constructors: Vec<String> = covers
+            .iter()
+            .map(|(period, _)| {
+                format!(
+                    "#[no_mangle]
+pub unsafe fn create_marked_cycle_interface_{period}() -> *mut dyn Interface {{
+    let parent = UserPlane::default().marked_cycle_curve({period});
+    let child = JuliaSet::from(parent.clone());
+    let int = MainInterface::new(parent, child, 768);
+    Box::into_raw(Box::new(int))
+}}"
+                )
+            })
+            .collect();
+
+        format!(
+            "impl UserPlane {{
+    {cover_fns}
+}}
+
+impl HasDynamicalCovers for UserPlane {{
+    fn marked_cycle_curve(self, period: Period) -> CoveringMap<Self> {{
+        let bounds = self.point_grid().bounds.clone();
+        match period {{
+            {arms}
+            _ => {{
+                println!(\"Marked cycle has not been implemented; falling back to base curve!\");
+                CoveringMap::new(self, |_| (Parameters::default(), ONE))
+            }}
+        }}
+        .with_orig_bounds(bounds)
+    }}
+}}
+
+{constructors}",
+            cover_fns = cover_fns.join("\n\n"),
+            arms = arms.join("\n"),
+            constructors = constructors.join("\n\n"),
         )
     }
 
@@ -219,7 +505,6 @@ impl Transpiler
 }}
 
 impl HasJulia for UserPlane {{}}
-impl MarkedPoints for UserPlane {{}}
 impl EscapeEncoding for UserPlane {{}}
 impl ExternalRays for UserPlane {{}}
 ",
@@ -257,16 +542,22 @@ impl ExternalRays for UserPlane {{}}
             "{imports}\n\
             {param_decl}\n\
             {param_impls}\n\
+            {meta_param_decl}\n\
             {struct_decl}\n\
             {plane_impl}\n\
             {other_impls}\n\
+            {marked_points_impl}\n\
+            {covers_impl}\n\
             {constructor}",
             imports = Self::imports(),
             param_decl = self.parameter_decl(),
             param_impls = self.parameter_impls(),
+            meta_param_decl = self.meta_parameter_decl(),
             struct_decl = self.user_struct_decl(),
             plane_impl = self.parameter_plane_impl(),
             other_impls = self.other_impls(),
+            marked_points_impl = self.marked_points_impl(),
+            covers_impl = self.covers_impl(),
             constructor = Self::constructor()
         )
     }
@@ -0,0 +1,114 @@
+use crate::loader::file_hash;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the watched files are checked for modifications.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptChange
+{
+    /// The user's TOML script changed, so it must be transpiled again.
+    Script,
+    /// Only the generated Rust changed; it should be rebuilt as-is.
+    GeneratedRust,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Stamp
+{
+    modified: Option<SystemTime>,
+    hash: String,
+}
+impl Stamp
+{
+    fn read(path: &Path) -> Self
+    {
+        Self {
+            modified: std::fs::metadata(path).and_then(|m| m.modified()).ok(),
+            hash: file_hash(path).unwrap_or_default(),
+        }
+    }
+
+    /// Update the stamp from the file on disk, returning whether its contents changed.
+    /// Editors often touch a file without changing it, so the hash is only compared once the
+    /// modification time differs.
+    fn update(&mut self, path: &Path) -> bool
+    {
+        let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+        if modified == self.modified {
+            return false;
+        }
+        let new = Self::read(path);
+        let changed = new.hash != self.hash;
+        *self = new;
+        changed
+    }
+}
+
+/// Polls a user script and its generated Rust for changes, so that the running interface can
+/// be rebuilt without restarting the app.
+#[derive(Debug)]
+pub struct ScriptWatcher
+{
+    toml_path: PathBuf,
+    rust_path: Option<PathBuf>,
+    toml_stamp: Stamp,
+    rust_stamp: Stamp,
+    last_poll: Instant,
+}
+
+impl ScriptWatcher
+{
+    #[must_use]
+    pub fn new(toml_path: &Path, rust_path: Option<PathBuf>) -> Self
+    {
+        let rust_stamp = rust_path.as_deref().map(Stamp::read).unwrap_or_default();
+        Self {
+            toml_path: toml_path.to_owned(),
+            toml_stamp: Stamp::read(toml_path),
+            rust_path,
+            rust_stamp,
+            last_poll: Instant::now(),
+        }
+    }
+
+    #[must_use]
+    pub fn toml_path(&self) -> &Path
+    {
+        &self.toml_path
+    }
+
+    /// Forget any pending changes, e.g. after the script has been reloaded.
+    pub fn refresh(&mut self)
+    {
+        self.toml_stamp = Stamp::read(&self.toml_path);
+        if let Some(rust_path) = &self.rust_path {
+            self.rust_stamp = Stamp::read(rust_path);
+        }
+    }
+
+    /// Check the watched files, at most once per `POLL_INTERVAL`.
+    pub fn poll(&mut self) -> Option<ScriptChange>
+    {
+        if self.last_poll.elapsed() < POLL_INTERVAL {
+            return None;
+        }
+        self.last_poll = Instant::now();
+
+        // The TOML takes precedence, since transpiling it overwrites the generated Rust anyway.
+        let script_changed = self.toml_stamp.update(&self.toml_path);
+        let rust_changed = self
+            .rust_path
+            .as_deref()
+            .is_some_and(|path| self.rust_stamp.update(path));
+
+        if script_changed {
+            Some(ScriptChange::Script)
+        } else if rust_changed {
+            Some(ScriptChange::GeneratedRust)
+        } else {
+            None
+        }
+    }
+}
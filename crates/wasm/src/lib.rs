@@ -1,12 +1,18 @@
+//! Web build of the app. Points are computed in tiles, so that the page stays responsive. Builds
+//! with the `threads` feature compute the tiles on web workers once the page has called
+//! `initThreadPool`; otherwise they are computed on the UI thread, a few per frame.
+#[cfg(all(target_arch = "wasm32", feature = "threads"))]
+pub mod thread_pool;
+pub mod url_state;
+
+#[cfg(target_arch = "wasm32")]
+use web_sys::HtmlCanvasElement;
 #[cfg(target_arch = "wasm32")]
 use {
     dynamo_app::FractalApp,
     eframe::{WebLogger, WebOptions, WebRunner},
-    log,
     wasm_bindgen::prelude::*,
 };
-#[cfg(target_arch = "wasm32")]
-use web_sys::HtmlCanvasElement;
 
 #[cfg(target_arch = "wasm32")]
 #[derive(Clone)]
@@ -40,10 +46,85 @@ impl WebHandle
             .start(
                 canvas_id,
                 WebOptions::default(),
-                Box::new(|_cc| Ok(Box::new(FractalApp::default()))),
+                Box::new(|_cc| Ok(Box::new(WebApp::from_url()))),
             )
             .await
     }
+
+    /// Load a user script from its TOML contents. Scripts are evaluated by the bytecode
+    /// interpreter, since the web build cannot compile them.
+    #[wasm_bindgen]
+    pub fn load_script(&self, toml_text: &str) -> Result<(), wasm_bindgen::JsValue>
+    {
+        let mut web_app = self
+            .runner
+            .app_mut::<WebApp>()
+            .ok_or_else(|| JsValue::from_str("App is not running"))?;
+        web_app
+            .app
+            .load_interpreted_script(toml_text)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+/// Wrapper around the app that keeps the URL fragment in sync with the view, so that it can be
+/// shared as a link.
+#[cfg(target_arch = "wasm32")]
+struct WebApp
+{
+    app: FractalApp,
+    fragment: String,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl WebApp
+{
+    /// Start the app, showing the view described by the URL fragment if there is one.
+    fn from_url() -> Self
+    {
+        let mut app = FractalApp::default();
+        let fragment = web_sys::window()
+            .and_then(|window| window.location().hash().ok())
+            .map(|hash| hash.trim_start_matches('#').to_owned())
+            .unwrap_or_default();
+        if !fragment.is_empty() {
+            if let Err(e) = url_state::restore(&mut app, &fragment) {
+                log::warn!("Could not restore view from URL: {e}");
+            }
+        }
+        Self { app, fragment }
+    }
+
+    fn update_url(&mut self)
+    {
+        let Some((profile, view)) = self.app.view_state() else {
+            return;
+        };
+        let fragment = url_state::encode(&profile, &view);
+        if fragment == self.fragment {
+            return;
+        }
+        let url = format!("#{fragment}");
+        if let Some(history) = web_sys::window().and_then(|window| window.history().ok()) {
+            // Replace rather than push, so that panning does not flood the back button
+            if history
+                .replace_state_with_url(&JsValue::NULL, "", Some(url.as_str()))
+                .is_ok()
+            {
+                self.fragment = fragment;
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl eframe::App for WebApp
+{
+    fn update(&mut self, ctx: &eframe::egui::Context, frame: &mut eframe::Frame)
+    {
+        eframe::App::update(&mut self.app, ctx, frame);
+        self.update_url();
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -54,3 +135,56 @@ impl Default for WebHandle
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests
+{
+    use crate::url_state::{decode_profile, decode_view, encode, restore};
+    use dynamo_app::FractalApp;
+    use dynamo_common::prelude::*;
+
+    #[test]
+    fn url_round_trip()
+    {
+        let mut app = FractalApp::default();
+        let profile: Vec<String> = [
+            "Polynomial",
+            "Cubic Family",
+            "Cubic Per(1, 1)",
+            "Base Curve",
+        ]
+        .map(str::to_owned)
+        .to_vec();
+        assert!(app.set_profile(&profile));
+
+        let (_, mut view) = app.view_state().unwrap();
+        view.parent.bounds = Bounds {
+            min_x: -1.5,
+            max_x: 0.25,
+            min_y: -0.5,
+            max_y: 1.,
+        };
+        view.parent.selection = Cplx::new(-0.1, 0.7);
+        view.child.rays = vec![RationalAngle::new(1, 3), RationalAngle::new(2, 7)];
+        view.parent.palette = view.parent.palette.with_phases(0.1, 0.2, 0.3);
+        view.child.palette = view.parent.palette;
+
+        let fragment = encode(&profile, &view);
+        assert_eq!(decode_profile(&fragment).as_ref(), Some(&profile));
+
+        let (_, mut decoded) = app.view_state().unwrap();
+        decode_view(&fragment, &mut decoded).unwrap();
+        assert_eq!(decoded, view);
+
+        let mut fresh_app = FractalApp::default();
+        restore(&mut fresh_app, &fragment).unwrap();
+        let (restored_profile, restored) = fresh_app.view_state().unwrap();
+        assert_eq!(restored_profile, profile);
+        assert_eq!(restored.parent.bounds, view.parent.bounds);
+        assert_eq!(restored.parent.selection, view.parent.selection);
+        assert_eq!(restored.child.bounds, view.child.bounds);
+
+        assert!(decode_view("bounds=1,0,0,1", &mut decoded).is_err());
+        assert!(decode_view("julia_rays=1/0", &mut decoded).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+//! Pool of web workers for computing tiles off the UI thread. Each worker runs one rayon thread,
+//! sharing the module and memory of the page, so this needs a build with wasm threads served with
+//! the cross-origin isolation headers in `docs/_headers`.
+use js_sys::{Array, Promise};
+use rayon::ThreadBuilder;
+use wasm_bindgen::prelude::*;
+use web_sys::{Worker, WorkerOptions, WorkerType};
+
+/// Script run by each worker, relative to the page.
+const WORKER_SCRIPT: &str = "./dynamo_worker.js";
+
+/// Start `num_threads` workers and use them for background work. The returned promise resolves
+/// once every worker is running.
+#[wasm_bindgen(js_name = initThreadPool)]
+pub fn init_thread_pool(num_threads: usize) -> Result<Promise, JsValue>
+{
+    let mut started = Vec::with_capacity(num_threads);
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .spawn_handler(|thread| {
+            let ready = spawn_worker(thread)
+                .map_err(|e| std::io::Error::other(format!("Could not start worker: {e:?}")))?;
+            started.push(ready);
+            Ok(())
+        })
+        .build()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    dynamo_gui::pane::set_worker_pool(pool)
+        .map_err(|_| JsValue::from_str("The thread pool has already been started"))?;
+    Ok(Promise::all(&started.into_iter().collect::<Array>()))
+}
+
+/// Run a thread of the pool on the calling worker. Only the worker script calls this, with a
+/// thread handed to it by [`init_thread_pool`]; it does not return.
+#[wasm_bindgen(js_name = runPoolThread)]
+pub fn run_pool_thread(thread: usize)
+{
+    // SAFETY: the worker script passes on the pointer posted by `spawn_worker`, exactly once
+    let thread = unsafe { Box::from_raw(thread as *mut ThreadBuilder) };
+    thread.run();
+}
+
+/// Start a worker for `thread`, returning a promise that resolves once the worker has loaded the
+/// module and taken the thread.
+fn spawn_worker(thread: ThreadBuilder) -> Result<Promise, JsValue>
+{
+    let options = WorkerOptions::new();
+    options.set_type(WorkerType::Module);
+    let worker = Worker::new_with_options(WORKER_SCRIPT, &options)?;
+    let ready = Promise::new(&mut |resolve, reject| {
+        worker.set_onmessage(Some(&resolve));
+        worker.set_onerror(Some(&reject));
+    });
+
+    let thread = Box::into_raw(Box::new(thread));
+    let message = Array::of3(
+        &wasm_bindgen::module(),
+        &wasm_bindgen::memory(),
+        &JsValue::from(thread as usize),
+    );
+    if let Err(e) = worker.post_message(&message) {
+        // SAFETY: the worker never received the pointer, so this is its only owner
+        drop(unsafe { Box::from_raw(thread) });
+        return Err(e);
+    }
+    Ok(ready)
+}
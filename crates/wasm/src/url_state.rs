@@ -0,0 +1,167 @@
+//! Encoding of the shown fractal and view into a URL fragment, so that web users can share links
+//! to specific views. A fragment looks like
+//!
+//! ```text
+//! profile=Polynomial&profile=Quadratic+Family&profile=Base+Curve&bounds=-2.5,1,-1.5,1.5
+//!     &param=-0.75,0.1&rays=1/3,2/3&julia_bounds=...&julia_point=...&julia_rays=...&palette=...
+//! ```
+//!
+//! where `profile` lists the sidebar tiles leading to the fractal, and `palette` is the palette in
+//! JSON. Any field may be left out, in which case it keeps its default value.
+use dynamo_app::FractalApp;
+use dynamo_common::prelude::*;
+use dynamo_gui::view_state::{PaneView, ViewState};
+
+#[derive(Debug)]
+pub enum UrlStateError
+{
+    UnknownProfile(Vec<String>),
+    Malformed
+    {
+        key: String,
+        value: String,
+    },
+}
+
+impl std::fmt::Display for UrlStateError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::UnknownProfile(path) => write!(f, "No fractal found at {path:?}"),
+            Self::Malformed { key, value } => write!(f, "Malformed value for {key}: {value}"),
+        }
+    }
+}
+
+impl std::error::Error for UrlStateError {}
+
+/// Encode a profile and view as a URL fragment, without the leading `#`.
+#[must_use]
+pub fn encode(profile: &[String], view: &ViewState) -> String
+{
+    let mut fragment = form_urlencoded::Serializer::new(String::new());
+    for name in profile {
+        fragment.append_pair("profile", name);
+    }
+    append_pane(&mut fragment, "", &view.parent);
+    append_pane(&mut fragment, "julia_", &view.child);
+    if let Ok(palette) = serde_json::to_string(&view.parent.palette) {
+        fragment.append_pair("palette", &palette);
+    }
+    fragment.finish()
+}
+
+fn append_pane(fragment: &mut form_urlencoded::Serializer<String>, prefix: &str, pane: &PaneView)
+{
+    let Bounds {
+        min_x,
+        max_x,
+        min_y,
+        max_y,
+    } = pane.bounds;
+    fragment.append_pair(
+        &format!("{prefix}bounds"),
+        &format!("{min_x},{max_x},{min_y},{max_y}"),
+    );
+
+    let selection_key = if prefix.is_empty() {
+        "param".to_owned()
+    } else {
+        format!("{prefix}point")
+    };
+    let Cplx { re, im } = pane.selection;
+    fragment.append_pair(&selection_key, &format!("{re},{im}"));
+
+    if !pane.rays.is_empty() {
+        let rays: Vec<String> = pane.rays.iter().map(ToString::to_string).collect();
+        fragment.append_pair(&format!("{prefix}rays"), &rays.join(","));
+    }
+}
+
+/// The sidebar profile named in a fragment, if any.
+#[must_use]
+pub fn decode_profile(fragment: &str) -> Option<Vec<String>>
+{
+    let profile: Vec<String> = form_urlencoded::parse(fragment.as_bytes())
+        .filter(|(key, _)| key == "profile")
+        .map(|(_, name)| name.into_owned())
+        .collect();
+    (!profile.is_empty()).then_some(profile)
+}
+
+/// Overwrite the parts of `view` that are given in a fragment.
+pub fn decode_view(fragment: &str, view: &mut ViewState) -> Result<(), UrlStateError>
+{
+    for (key, value) in form_urlencoded::parse(fragment.as_bytes()) {
+        let malformed = || UrlStateError::Malformed {
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+        match &*key {
+            "bounds" => view.parent.bounds = parse_bounds(&value).ok_or_else(malformed)?,
+            "param" => view.parent.selection = parse_cplx(&value).ok_or_else(malformed)?,
+            "rays" => view.parent.rays = parse_rays(&value).ok_or_else(malformed)?,
+            "julia_bounds" => view.child.bounds = parse_bounds(&value).ok_or_else(malformed)?,
+            "julia_point" => view.child.selection = parse_cplx(&value).ok_or_else(malformed)?,
+            "julia_rays" => view.child.rays = parse_rays(&value).ok_or_else(malformed)?,
+            "palette" => {
+                let palette = serde_json::from_str(&value).map_err(|_| malformed())?;
+                view.parent.palette = palette;
+                view.child.palette = palette;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Show the profile and view described by a fragment in the focused tab of the app.
+pub fn restore(app: &mut FractalApp, fragment: &str) -> Result<(), UrlStateError>
+{
+    if let Some(profile) = decode_profile(fragment) {
+        if !app.set_profile(&profile) {
+            return Err(UrlStateError::UnknownProfile(profile));
+        }
+    }
+    let Some((_, mut view)) = app.view_state() else {
+        return Ok(());
+    };
+    decode_view(fragment, &mut view)?;
+    app.restore_view_state(&view);
+    Ok(())
+}
+
+fn parse_reals<const N: usize>(text: &str) -> Option<[Real; N]>
+{
+    let mut values = [0.; N];
+    let mut parts = text.split(',');
+    for value in &mut values {
+        *value = parts.next()?.trim().parse().ok()?;
+    }
+    parts.next().is_none().then_some(values)
+}
+
+fn parse_bounds(text: &str) -> Option<Bounds>
+{
+    let [min_x, max_x, min_y, max_y] = parse_reals(text)?;
+    (min_x < max_x && min_y < max_y).then_some(Bounds {
+        min_x,
+        max_x,
+        min_y,
+        max_y,
+    })
+}
+
+fn parse_cplx(text: &str) -> Option<Cplx>
+{
+    let [re, im] = parse_reals(text)?;
+    Some(Cplx::new(re, im))
+}
+
+fn parse_rays(text: &str) -> Option<Vec<RationalAngle>>
+{
+    text.split(',')
+        .map(|angle| parse_angle(angle.trim()).ok())
+        .collect()
+}
@@ -1,9 +1,18 @@
 #! /bin/sh
 
 # export RUSTFLAGS='--cfg getrandom_backend="wasm_js"'
+
+# Set THREADS=1 to compute tiles on web workers. This needs a nightly toolchain with rust-src, and
+# the page must be served with the headers in docs/_headers.
 (
     cd "crates/wasm" || exit
-    wasm-pack build --release --target web || return 1
+    if [ -n "$THREADS" ]; then
+        RUSTFLAGS='-C target-feature=+atomics,+bulk-memory' \
+            rustup run nightly wasm-pack build --release --target web -- \
+            --features threads -Z build-std=panic_abort,std || return 1
+    else
+        wasm-pack build --release --target web || return 1
+    fi
 )
 mv crates/wasm/pkg/dynamo_wasm.js docs
 mv crates/wasm/pkg/dynamo_wasm_bg.wasm docs
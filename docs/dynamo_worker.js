@@ -0,0 +1,9 @@
+// Worker running one thread of the pool started by `initThreadPool`, sharing the module and
+// memory of the page.
+import init, { runPoolThread } from "./dynamo_wasm.js";
+
+self.onmessage = async ({ data: [module, memory, thread] }) => {
+  await init({ module_or_path: module, memory });
+  postMessage("ready");
+  runPoolThread(thread);
+};